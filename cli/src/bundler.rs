@@ -2,28 +2,183 @@ use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 use codegen_js::{CodegenJs, CodegenOptions, ModuleFormat};
+use core::OverflowMode;
 use core::ast::Stmt;
 use lexer::Lexer;
 use parser::Parser;
 
+use crate::modules::{self, ModuleItems};
+use crate::namespaces::{self, ParsedModule};
+
 // Minimal JS runtime mapping Artcode builtins to browser/Node-compatible equivalents.
 pub const JS_RUNTIME: &str = r#"// Artcode JS runtime
-const println = (...args) => console.log(...args);
+// Int e BigInt; println mostra `5`, nao `5n`. Dentro de colecoes vira Number
+// quando cabe sem perda. So copia o que contem um BigInt.
+function __art_show(v, seen = new WeakSet()) {
+  if (typeof v === 'bigint') return Number.isSafeInteger(Number(v)) ? Number(v) : v;
+  if (v === null || typeof v !== 'object' || seen.has(v) || ArrayBuffer.isView(v)) return v;
+  seen.add(v);
+  if (Array.isArray(v)) {
+    const out = v.map((x) => __art_show(x, seen));
+    return out.some((x, i) => x !== v[i]) ? out : v;
+  }
+  if (v instanceof Map) return new Map([...v].map(([k, x]) => [__art_show(k, seen), __art_show(x, seen)]));
+  if (v instanceof Set) return new Set([...v].map((x) => __art_show(x, seen)));
+  const keys = Object.keys(v);
+  const shown = keys.map((k) => __art_show(v[k], seen));
+  if (shown.every((x, i) => x === v[keys[i]])) return v;
+  const copy = Object.create(Object.getPrototypeOf(v));
+  keys.forEach((k, i) => { copy[k] = shown[i]; });
+  return copy;
+}
+const __art_arg = (v) => (typeof v === 'bigint' ? String(v) : __art_show(v));
+const println = (...args) => console.log(...args.map(__art_arg));
 const print   = (...args) => (typeof process !== 'undefined'
   ? process.stdout.write(args.join(''))
-  : console.log(...args));
+  : console.log(...args.map(__art_arg)));
 const str_split      = (s, sep) => s.split(sep);
 const str_join       = (arr, sep) => arr.join(sep);
 const str_contains   = (s, sub) => s.includes(sub);
 const str_starts_with = (s, pre) => s.startsWith(pre);
 const str_replace    = (s, from, to) => s.split(from).join(to);
-const str_slice      = (s, start, end) => s.slice(start, end);
-const str_to_int     = (s) => { const n = parseInt(s, 10); return isNaN(n) ? { tag: 'Err', payload: 'not an integer' } : { tag: 'Ok', payload: n }; };
+const str_slice      = (s, start, end) => s.slice(Number(start), Number(end));
+const str_to_int     = (s) => { const t = s.trim(); const n = /^[+-]?\d+$/.test(t) ? BigInt(t) : null; return n === null || n !== BigInt.asIntN(64, n) ? { tag: 'Err', payload: 'not an integer' } : { tag: 'Ok', payload: n }; };
 const str_to_float   = (s) => { const n = parseFloat(s);   return isNaN(n) ? { tag: 'Err', payload: 'not a float'   } : { tag: 'Ok', payload: n }; };
-const len            = (v) => v.length ?? 0;
+const len            = (v) => BigInt(v.length ?? v.size ?? 0);
 const none           = null;
 const some           = (v) => v;
 
+// ── Collections ──────────────────────────────────────────────────────────────
+// Map e Set sao subclasses dos nativos com a semantica do interpretador: chaves
+// compostas (tuplas, arrays, structs, enums) valem pelo conteudo, via uma string
+// canonica, e o Map itera em ordem de chave. Deque e um array.
+function __art_key_text(k) {
+  if (typeof k === 'bigint') return `${k}n`;
+  if (typeof k === 'string') return JSON.stringify(k);
+  if (k == null) return 'null';
+  if (typeof k !== 'object') return Object.is(k, -0) ? '0' : String(k);
+  if (Array.isArray(k) || ArrayBuffer.isView(k)) return `[${[...k].map(__art_key_text).join(',')}]`;
+  const fields = Object.keys(k).sort().map((f) => `${f}:${__art_key_text(k[f])}`);
+  return `${k.constructor?.name ?? ''}{${fields.join(',')}}`;
+}
+function __art_key(k) {
+  if (typeof k === 'string') return k.startsWith('\0') ? '\0' + k : k;
+  if (k == null) return null;
+  if (typeof k !== 'object') return Object.is(k, -0) ? 0 : k;
+  return '\0' + __art_key_text(k);
+}
+// Mesma ordem de `MapKey`: Bool < Int < Float < String < None < tupla/array
+// < struct < enum; strings por code point, Float com NaN no fim.
+function __art_key_rank(k) {
+  if (typeof k === 'boolean') return 0;
+  if (typeof k === 'bigint') return 1;
+  if (typeof k === 'number') return 2;
+  if (typeof k === 'string') return 3;
+  if (k == null) return 4;
+  if (Array.isArray(k) || ArrayBuffer.isView(k)) return 5;
+  return Object.getPrototypeOf(k) === Object.prototype && 'tag' in k ? 7 : 6;
+}
+const __art_unit = (u) => (u >= 0xd800 ? (u >= 0xe000 ? u - 0x800 : u + 0x2000) : u);
+function __art_str_cmp(a, b) {
+  for (let i = 0; i < a.length && i < b.length; i++) {
+    const d = __art_unit(a.charCodeAt(i)) - __art_unit(b.charCodeAt(i));
+    if (d !== 0) return d;
+  }
+  return a.length - b.length;
+}
+function __art_seq_cmp(a, b) {
+  for (let i = 0; i < a.length && i < b.length; i++) {
+    const d = __art_key_cmp(a[i], b[i]);
+    if (d !== 0) return d;
+  }
+  return a.length - b.length;
+}
+function __art_key_cmp(a, b) {
+  const rank = __art_key_rank(a);
+  if (rank !== __art_key_rank(b)) return rank - __art_key_rank(b);
+  switch (rank) {
+    case 2:
+      if (Number.isNaN(a) || Number.isNaN(b)) return Number.isNaN(a) - Number.isNaN(b);
+      return a < b ? -1 : a > b ? 1 : 0;
+    case 3: return __art_str_cmp(a, b);
+    case 4: return 0;
+    case 5: return __art_seq_cmp(a, b);
+    case 6: {
+      const d = __art_str_cmp(a.constructor?.name ?? '', b.constructor?.name ?? '');
+      if (d !== 0) return d;
+      const fields = (o) => Object.keys(o).sort(__art_str_cmp).flatMap((f) => [f, o[f]]);
+      return __art_seq_cmp(fields(a), fields(b));
+    }
+    case 7: return __art_str_cmp(a.tag, b.tag) || __art_seq_cmp(a.payload ?? [], b.payload ?? []);
+    default: return a < b ? -1 : a > b ? 1 : 0;
+  }
+}
+class ArtMap extends Map {
+  #keys = new Map();
+  constructor(entries) { super(); for (const [k, v] of entries ?? []) this.set(k, v); }
+  has(k) { return this.#keys.has(__art_key(k)); }
+  get(k) { const c = __art_key(k); return this.#keys.has(c) ? super.get(this.#keys.get(c)) : undefined; }
+  set(k, v) {
+    const c = __art_key(k);
+    if (!this.#keys.has(c)) this.#keys.set(c, k ?? null);
+    return super.set(this.#keys.get(c), v);
+  }
+  delete(k) {
+    const c = __art_key(k);
+    if (!this.#keys.has(c)) return false;
+    super.delete(this.#keys.get(c));
+    return this.#keys.delete(c);
+  }
+  clear() { this.#keys.clear(); super.clear(); }
+  keys() { return [...super.keys()].sort(__art_key_cmp)[Symbol.iterator](); }
+  values() { return [...this.keys()].map((k) => super.get(k))[Symbol.iterator](); }
+  entries() { return [...this.keys()].map((k) => [k, super.get(k)])[Symbol.iterator](); }
+  [Symbol.iterator]() { return this.entries(); }
+  forEach(f, self) { for (const [k, v] of this) f.call(self, v, k, this); }
+}
+class ArtSet extends Set {
+  #keys = new Map();
+  constructor(items) { super(); for (const v of items ?? []) this.add(v); }
+  has(v) { return this.#keys.has(__art_key(v)); }
+  add(v) {
+    const c = __art_key(v);
+    if (!this.#keys.has(c)) { this.#keys.set(c, v ?? null); super.add(v ?? null); }
+    return this;
+  }
+  delete(v) {
+    const c = __art_key(v);
+    if (!this.#keys.has(c)) return false;
+    super.delete(this.#keys.get(c));
+    return this.#keys.delete(c);
+  }
+  clear() { this.#keys.clear(); super.clear(); }
+}
+const map_new          = () => new ArtMap();
+const map_set          = (m, k, v) => { m.set(k, v); return null; };
+const map_get          = (m, k) => (m.has(k) ? m.get(k) : null);
+const map_has          = (m, k) => m.has(k);
+const map_remove       = (m, k) => { if (!m.has(k)) return null; const v = m.get(k); m.delete(k); return v; };
+const map_keys         = (m) => [...m.keys()];
+const map_values       = (m) => [...m.values()];
+const map_entries      = (m) => [...m.entries()];
+const map_merge        = (a, b) => new ArtMap([...a, ...b]);
+const set_new          = () => new ArtSet();
+const set_add          = (s, v) => { s.add(v); return null; };
+const set_has          = (s, v) => s.has(v);
+const set_remove       = (s, v) => s.delete(v);
+const set_union        = (a, b) => new ArtSet([...a, ...b]);
+const set_intersection = (a, b) => new ArtSet([...a].filter((v) => b.has(v)));
+const set_difference   = (a, b) => new ArtSet([...a].filter((v) => !b.has(v)));
+const set_to_array     = (s) => [...s];
+const deque_new        = () => [];
+const deque_push_front = (d, v) => { d.unshift(v); return null; };
+const deque_push_back  = (d, v) => { d.push(v); return null; };
+const deque_pop_front  = (d) => (d.length ? d.shift() : null);
+const deque_pop_back   = (d) => (d.length ? d.pop() : null);
+const deque_len        = (d) => BigInt(d.length);
+const deque_get        = (d, i) => { const n = i < 0 ? d.length + Number(i) : Number(i); return n >= 0 && n < d.length ? d[n] : null; };
+const deque_to_array   = (d) => [...d];
+
 // ── Async scheduler ──────────────────────────────────────────────────────────
 const __pending = new Set();
 let __scheduled = false;
@@ -100,7 +255,7 @@ fn collect_imports(stmts: &[Stmt]) -> Vec<Vec<String>> {
     stmts
         .iter()
         .filter_map(|s| {
-            if let Stmt::Import { path } = s {
+            if let Stmt::Import { path, .. } = s {
                 Some(path.iter().map(|t| t.lexeme.clone()).collect())
             } else {
                 None
@@ -109,10 +264,39 @@ fn collect_imports(stmts: &[Stmt]) -> Vec<Vec<String>> {
         .collect()
 }
 
-fn compile_file(
+/// Checks the imports of a parsed module against its (already compiled)
+/// dependencies.
+fn check_module_imports(
+    path: &Path,
+    tokens: &[core::Token],
+    program: &[Stmt],
+    exports: &HashMap<PathBuf, ModuleItems>,
+) -> Result<(), Vec<String>> {
+    let base_dir = path.parent().unwrap_or(Path::new("."));
+    let imported: Vec<Option<&ModuleItems>> = collect_imports(program)
+        .iter()
+        .map(|parts| {
+            let dep = resolve_import_path(base_dir, parts);
+            dep.canonicalize().ok().and_then(|c| exports.get(&c))
+        })
+        .collect();
+    let diags = modules::check_imports(tokens, program, &imported);
+    if diags.is_empty() {
+        Ok(())
+    } else {
+        Err(diags
+            .iter()
+            .map(|d| format!("import error in '{}': {}", path.display(), d.message))
+            .collect())
+    }
+}
+
+/// Parses `path` and its dependencies into `ordered`, dependencies first.
+fn load_file(
     path: &Path,
     visited: &mut HashSet<PathBuf>,
-    ordered: &mut Vec<(PathBuf, String)>,
+    ordered: &mut Vec<ParsedModule>,
+    exports: &mut HashMap<PathBuf, ModuleItems>,
     errors: &mut Vec<String>,
 ) {
     let canon = match path.canonicalize() {
@@ -144,7 +328,8 @@ fn compile_file(
         }
     };
 
-    let (program, diags) = Parser::new(tokens).parse();
+    let mut parser = Parser::new(tokens.clone());
+    let (program, diags) = parser.parse();
     if !diags.is_empty() {
         for d in &diags {
             errors.push(format!(
@@ -159,24 +344,54 @@ fn compile_file(
     let base_dir = canon.parent().unwrap_or(Path::new("."));
     for import_parts in collect_imports(&program) {
         let dep_path = resolve_import_path(base_dir, &import_parts);
-        compile_file(&dep_path, visited, ordered, errors);
+        load_file(&dep_path, visited, ordered, exports, errors);
     }
 
-    // Emit JS without import statements (they are inlined)
+    if let Err(errs) = check_module_imports(&canon, &tokens, &program, exports) {
+        errors.extend(errs);
+        return;
+    }
+    exports.insert(canon.clone(), ModuleItems::collect(&program));
+    ordered.push(ParsedModule {
+        deps: import_keys(&canon, &program),
+        key: canon,
+        program,
+        qualified: parser.qualified_uses().to_vec(),
+    });
+}
+
+/// Canonical path of each module `program` (at `path`) imports.
+fn import_keys(path: &Path, program: &[Stmt]) -> Vec<PathBuf> {
+    let base_dir = path.parent().unwrap_or(Path::new("."));
+    collect_imports(program)
+        .iter()
+        .map(|parts| {
+            let dep = resolve_import_path(base_dir, parts);
+            dep.canonicalize().unwrap_or(dep)
+        })
+        .collect()
+}
+
+/// JS for one module, without import statements (they are inlined).
+fn compile_module(module: &ParsedModule, overflow: OverflowMode) -> String {
     let opts = CodegenOptions {
-        source_file: Some(canon.to_string_lossy().to_string()),
+        source_file: Some(module.key.to_string_lossy().to_string()),
         emit_source_map: false,
         module_format: ModuleFormat::Bundle,
+        overflow,
     };
-    let output = CodegenJs::new(opts).emit_program(&program);
-    ordered.push((canon, output.code));
+    CodegenJs::new(opts).emit_program(&module.program).code
 }
 
 pub struct BundleOutput {
     pub code: String,
 }
 
-pub fn bundle(entry: &str, emit_sourcemap: bool) -> Result<BundleOutput, Vec<String>> {
+pub fn bundle(
+    entry: &str,
+    emit_sourcemap: bool,
+    overflow: OverflowMode,
+) -> Result<BundleOutput, Vec<String>> {
     let entry_path = Path::new(entry);
     let entry_canon = match entry_path.canonicalize() {
         Ok(p) => p,
@@ -186,7 +401,7 @@ pub fn bundle(entry: &str, emit_sourcemap: bool) -> Result<BundleOutput, Vec<Str
     let _ = emit_sourcemap; // source map across bundled files is v0.5 scope
 
     let mut visited = HashSet::new();
-    let mut ordered: Vec<(PathBuf, String)> = Vec::new();
+    let mut ordered: Vec<ParsedModule> = Vec::new();
     let mut errors = Vec::new();
 
     // Pre-populate visited with entry so deps load first
@@ -201,44 +416,58 @@ pub fn bundle(entry: &str, emit_sourcemap: bool) -> Result<BundleOutput, Vec<Str
         Err(d) => return Err(vec![format!("lex error: {}", d.message)]),
     };
 
-    let (program, diags) = Parser::new(tokens).parse();
+    let mut parser = Parser::new(tokens.clone());
+    let (program, diags) = parser.parse();
     if !diags.is_empty() {
         return Err(diags.iter().map(|d| d.message.clone()).collect());
     }
 
     // Load dependencies first
+    let mut exports = HashMap::new();
     for import_parts in collect_imports(&program) {
         let dep_path = resolve_import_path(base_dir, &import_parts);
-        compile_file(&dep_path, &mut visited, &mut ordered, &mut errors);
+        load_file(
+            &dep_path,
+            &mut visited,
+            &mut ordered,
+            &mut exports,
+            &mut errors,
+        );
     }
 
     if !errors.is_empty() {
         return Err(errors);
     }
 
-    // Emit entry file (Bundle mode skips import stmts)
-    let opts = CodegenOptions {
-        source_file: Some(entry_canon.to_string_lossy().to_string()),
-        emit_source_map: false,
-        module_format: ModuleFormat::Bundle,
+    check_module_imports(&entry_canon, &tokens, &program, &exports)?;
+
+    ordered.push(ParsedModule {
+        deps: import_keys(&entry_canon, &program),
+        key: entry_canon,
+        program,
+        qualified: parser.qualified_uses().to_vec(),
+    });
+    namespaces::separate(&mut ordered);
+    let Some(entry) = ordered.pop() else {
+        return Err(vec!["entry module missing".to_string()]);
     };
-    let entry_js = CodegenJs::new(opts).emit_program(&program);
 
     let mut code = String::new();
     code.push_str(JS_RUNTIME);
     code.push('\n');
 
-    let mut seen_modules: HashMap<PathBuf, bool> = HashMap::new();
-    for (path, module_code) in &ordered {
-        if seen_modules.insert(path.clone(), true).is_none() {
-            code.push_str(&format!("// --- module: {} ---\n", path.display()));
-            code.push_str(module_code);
+    let mut seen_modules: HashSet<PathBuf> = HashSet::new();
+    for module in &ordered {
+        if seen_modules.insert(module.key.clone()) {
+            code.push_str(&format!("// --- module: {} ---\n", module.key.display()));
+            code.push_str(&compile_module(module, overflow));
             code.push('\n');
         }
     }
 
+    // Emit entry file (Bundle mode skips import stmts)
     code.push_str("// --- entry ---\n");
-    code.push_str(&entry_js.code);
+    code.push_str(&compile_module(&entry, overflow));
 
     Ok(BundleOutput { code })
 }
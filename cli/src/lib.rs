@@ -1,2 +1,4 @@
 pub mod bundler;
 pub mod formatter;
+pub mod modules;
+pub mod namespaces;
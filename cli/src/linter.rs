@@ -148,6 +148,10 @@ fn lint_stmt(
             declare_pattern_bindings(pattern, scopes, diagnostics);
             bind_ref_kind_from_pattern(pattern, ref_kind, scopes);
         }
+        Stmt::Const { name, value, .. } => {
+            lint_expr(value, scopes, diagnostics);
+            scopes.declare(&name.lexeme, name, diagnostics);
+        }
         Stmt::Function {
             name, params, body, ..
         } => {
@@ -173,6 +177,7 @@ fn lint_stmt(
             }
             scopes.pop();
         }
+        Stmt::Defer { body, .. } => lint_stmt(body, scopes, diagnostics, in_performant),
         Stmt::If {
             condition,
             then_branch,
@@ -210,14 +215,24 @@ fn lint_stmt(
             lint_stmt(catch_branch, scopes, diagnostics, in_performant);
             scopes.pop();
         }
-        Stmt::Expression(expr) | Stmt::Return { value: Some(expr) } => {
+        Stmt::Expression(expr)
+        | Stmt::Return { value: Some(expr) }
+        | Stmt::Yield { value: expr, .. } => {
             lint_expr(expr, scopes, diagnostics);
         }
+        Stmt::Assign { target, value, .. } => {
+            lint_expr(target, scopes, diagnostics);
+            lint_expr(value, scopes, diagnostics);
+        }
         Stmt::Return { value: None }
         | Stmt::Import { .. }
+        | Stmt::Break { .. }
+        | Stmt::Continue { .. }
         | Stmt::ShellCommand { .. }
         | Stmt::StructDecl { .. }
         | Stmt::EnumDecl { .. }
+        | Stmt::TypeAlias { .. }
+        | Stmt::Newtype { .. }
         | Stmt::ComponentBlock { .. }
         | Stmt::QualifiedBinding { .. } => {}
         Stmt::ImplBlock { methods, .. } => {
@@ -225,33 +240,23 @@ fn lint_stmt(
                 lint_stmt(method, scopes, diagnostics, in_performant);
             }
         }
-        Stmt::Match { expr, cases } => {
+        Stmt::TraitDecl { methods, .. } => {
+            for method in methods {
+                let Some(body) = &method.body else { continue };
+                scopes.push();
+                for param in &method.params {
+                    scopes.declare(&param.name.lexeme, &param.name, diagnostics);
+                }
+                lint_stmt(body, scopes, diagnostics, in_performant);
+                scopes.pop();
+            }
+        }
+        Stmt::Match { expr, cases, .. } => {
+            // Unreachable arms are reported by `typeck`'s exhaustiveness check.
             lint_expr(expr, scopes, diagnostics);
-            let mut irrefutable_found = false;
-
             for (pattern, guard, body) in cases {
-                if irrefutable_found {
-                    diagnostics.push(Diagnostic::new(
-                          DiagnosticKind::Lint,
-                          "Dead code: this match arm is unreachable because a previous arm catches all remaining cases.",
-                          Span::dummy(),
-                      ));
-                }
-
                 scopes.push();
                 declare_pattern_bindings(pattern, scopes, diagnostics);
-
-                match pattern {
-                    MatchPattern::Wildcard
-                    | MatchPattern::Binding(_)
-                    | MatchPattern::Variable(_)
-                        if guard.is_none() =>
-                    {
-                        irrefutable_found = true;
-                    }
-                    _ => {}
-                }
-
                 if let Some(g) = guard {
                     lint_expr(g, scopes, diagnostics);
                 }
@@ -259,7 +264,9 @@ fn lint_stmt(
                 scopes.pop();
             }
         }
-        Stmt::While { condition, body } => {
+        Stmt::While {
+            condition, body, ..
+        } => {
             lint_expr(condition, scopes, diagnostics);
             if !in_performant && stmt_contains_allocation(body) {
                 diagnostics.push(
@@ -275,8 +282,10 @@ fn lint_stmt(
         }
         Stmt::For {
             element,
+            pattern,
             iterator,
             body,
+            ..
         } => {
             lint_expr(iterator, scopes, diagnostics);
             if !in_performant && stmt_contains_allocation(body) {
@@ -290,7 +299,10 @@ fn lint_stmt(
                 );
             }
             scopes.push();
-            scopes.declare(&element.lexeme, element, diagnostics);
+            match pattern {
+                Some(p) => declare_pattern_bindings(p, scopes, diagnostics),
+                None => scopes.declare(&element.lexeme, element, diagnostics),
+            }
             lint_stmt(body, scopes, diagnostics, in_performant);
             scopes.pop();
         }
@@ -300,7 +312,11 @@ fn lint_stmt(
 fn stmt_contains_allocation(stmt: &Stmt) -> bool {
     match stmt {
         Stmt::Expression(expr) => expr_contains_allocation(expr),
-        Stmt::Let { initializer, .. } => expr_contains_allocation(initializer),
+        Stmt::Let { initializer, .. }
+        | Stmt::Const {
+            value: initializer, ..
+        } => expr_contains_allocation(initializer),
+        Stmt::Assign { value, .. } => expr_contains_allocation(value),
         Stmt::Block { statements }
         | Stmt::Performant { statements }
         | Stmt::SpawnActor { body: statements } => statements.iter().any(stmt_contains_allocation),
@@ -334,7 +350,7 @@ fn stmt_contains_allocation(stmt: &Stmt) -> bool {
             catch_branch,
             ..
         } => stmt_contains_allocation(try_branch) || stmt_contains_allocation(catch_branch),
-        Stmt::Match { expr, cases } => {
+        Stmt::Match { expr, cases, .. } => {
             expr_contains_allocation(expr)
                 || cases.iter().any(|(_, guard, body)| {
                     guard
@@ -344,9 +360,9 @@ fn stmt_contains_allocation(stmt: &Stmt) -> bool {
                         || stmt_contains_allocation(body)
                 })
         }
-        Stmt::While { condition, body } => {
-            expr_contains_allocation(condition) || stmt_contains_allocation(body)
-        }
+        Stmt::While {
+            condition, body, ..
+        } => expr_contains_allocation(condition) || stmt_contains_allocation(body),
         Stmt::For { iterator, body, .. } => {
             expr_contains_allocation(iterator) || stmt_contains_allocation(body)
         }
@@ -354,12 +370,19 @@ fn stmt_contains_allocation(stmt: &Stmt) -> bool {
             .as_ref()
             .map(expr_contains_allocation)
             .unwrap_or(false),
+        Stmt::Yield { value, .. } => expr_contains_allocation(value),
+        Stmt::Defer { body, .. } => stmt_contains_allocation(body),
         Stmt::StructDecl { .. }
         | Stmt::EnumDecl { .. }
+        | Stmt::TypeAlias { .. }
+        | Stmt::Newtype { .. }
         | Stmt::Function { .. }
         | Stmt::Import { .. }
+        | Stmt::Break { .. }
+        | Stmt::Continue { .. }
         | Stmt::ShellCommand { .. }
         | Stmt::ImplBlock { .. }
+        | Stmt::TraitDecl { .. }
         | Stmt::ComponentBlock { .. }
         | Stmt::QualifiedBinding { .. } => false,
     }
@@ -380,12 +403,19 @@ fn expr_contains_allocation(expr: &Expr) -> bool {
                 || expr_contains_allocation(callee)
                 || arguments.iter().any(expr_contains_allocation)
         }
-        Expr::Binary { left, right, .. } | Expr::Logical { left, right, .. } => {
+        Expr::Binary { left, right, .. }
+        | Expr::Logical { left, right, .. }
+        | Expr::Coalesce { left, right, .. } => {
             expr_contains_allocation(left) || expr_contains_allocation(right)
         }
+        // O intervalo é preguiçoso: só guarda os limites.
+        Expr::Range { start, end, .. } => {
+            expr_contains_allocation(start) || expr_contains_allocation(end)
+        }
         Expr::Unary { right, .. }
         | Expr::Grouping { expression: right }
         | Expr::Try(right)
+        | Expr::Await { value: right, .. }
         | Expr::Weak(right)
         | Expr::Unowned(right)
         | Expr::WeakUpgrade(right)
@@ -393,12 +423,24 @@ fn expr_contains_allocation(expr: &Expr) -> bool {
         Expr::FieldAccess { object, .. } | Expr::Cast { object, .. } => {
             expr_contains_allocation(object)
         }
+        Expr::OptionalChain {
+            object, arguments, ..
+        } => {
+            expr_contains_allocation(object)
+                || arguments.iter().flatten().any(expr_contains_allocation)
+        }
+        Expr::Index { object, index, .. } => {
+            expr_contains_allocation(object) || expr_contains_allocation(index)
+        }
+        // Fatiar copia os elementos para um novo valor.
+        Expr::Slice { .. } => true,
         Expr::InterpolatedString(parts) => parts.iter().any(|p| match p {
             InterpolatedPart::Literal(_) => false,
             InterpolatedPart::Expr { expr, .. } => expr_contains_allocation(expr),
         }),
         Expr::SpawnActor { body } => body.iter().any(stmt_contains_allocation),
-        Expr::Template(_) => false,
+        // Como `Stmt::Function`: o corpo só roda quando o lambda é chamado.
+        Expr::Template(_) | Expr::Lambda { .. } => false,
         Expr::Literal(_) | Expr::Variable { .. } => false,
     }
 }
@@ -424,17 +466,54 @@ fn declare_pattern_bindings(
                 }
             }
         }
-        MatchPattern::Literal(_) | MatchPattern::Wildcard => {}
+        MatchPattern::Struct { fields, .. } => {
+            for (_, part) in fields {
+                declare_pattern_bindings(part, scopes, diagnostics);
+            }
+        }
+        MatchPattern::Array {
+            prefix,
+            rest,
+            suffix,
+        } => {
+            for part in prefix.iter().chain(suffix) {
+                declare_pattern_bindings(part, scopes, diagnostics);
+            }
+            if let Some(Some(token)) = rest {
+                scopes.declare(&token.lexeme, token, diagnostics);
+            }
+        }
+        // Every alternative binds the same names; declare them once.
+        MatchPattern::Or(alternatives) => {
+            if let Some(first) = alternatives.first() {
+                declare_pattern_bindings(first, scopes, diagnostics);
+            }
+        }
+        MatchPattern::At { name, pattern } => {
+            scopes.declare(&name.lexeme, name, diagnostics);
+            declare_pattern_bindings(pattern, scopes, diagnostics);
+        }
+        MatchPattern::Literal(_) | MatchPattern::Range { .. } | MatchPattern::Wildcard => {}
     }
 }
 
 fn lint_expr(expr: &Expr, scopes: &mut ScopeStack, diagnostics: &mut Vec<Diagnostic>) {
     match expr {
-        Expr::Binary { left, right, .. } | Expr::Logical { left, right, .. } => {
+        Expr::Binary { left, right, .. }
+        | Expr::Logical { left, right, .. }
+        | Expr::Coalesce { left, right, .. }
+        | Expr::Range {
+            start: left,
+            end: right,
+            ..
+        } => {
             lint_expr(left, scopes, diagnostics);
             lint_expr(right, scopes, diagnostics);
         }
-        Expr::Unary { right, .. } | Expr::Grouping { expression: right } | Expr::Try(right) => {
+        Expr::Unary { right, .. }
+        | Expr::Grouping { expression: right }
+        | Expr::Try(right)
+        | Expr::Await { value: right, .. } => {
             lint_expr(right, scopes, diagnostics);
         }
         Expr::Weak(right) => {
@@ -504,6 +583,26 @@ fn lint_expr(expr: &Expr, scopes: &mut ScopeStack, diagnostics: &mut Vec<Diagnos
         Expr::FieldAccess { object, .. } | Expr::Cast { object, .. } => {
             lint_expr(object, scopes, diagnostics);
         }
+        Expr::OptionalChain {
+            object, arguments, ..
+        } => {
+            lint_expr(object, scopes, diagnostics);
+            for arg in arguments.iter().flatten() {
+                lint_expr(arg, scopes, diagnostics);
+            }
+        }
+        Expr::Index { object, index, .. } => {
+            lint_expr(object, scopes, diagnostics);
+            lint_expr(index, scopes, diagnostics);
+        }
+        Expr::Slice {
+            object, start, end, ..
+        } => {
+            lint_expr(object, scopes, diagnostics);
+            for bound in [start, end].into_iter().flatten() {
+                lint_expr(bound, scopes, diagnostics);
+            }
+        }
         Expr::Array(elements) => {
             for el in elements {
                 lint_expr(el, scopes, diagnostics);
@@ -514,10 +613,13 @@ fn lint_expr(expr: &Expr, scopes: &mut ScopeStack, diagnostics: &mut Vec<Diagnos
                 lint_expr(el, scopes, diagnostics);
             }
         }
-        Expr::StructInit { fields, .. } => {
+        Expr::StructInit { fields, base, .. } => {
             for (_, val) in fields {
                 lint_expr(val, scopes, diagnostics);
             }
+            if let Some(base) = base {
+                lint_expr(base, scopes, diagnostics);
+            }
         }
         Expr::EnumInit { values, .. } => {
             for val in values {
@@ -538,6 +640,14 @@ fn lint_expr(expr: &Expr, scopes: &mut ScopeStack, diagnostics: &mut Vec<Diagnos
             }
             scopes.pop();
         }
+        Expr::Lambda { params, body, .. } => {
+            scopes.push();
+            for param in params {
+                scopes.declare(&param.name.lexeme, &param.name, diagnostics);
+            }
+            lint_stmt(body, scopes, diagnostics, false);
+            scopes.pop();
+        }
         Expr::Template(_) => {}
         Expr::Literal(_) | Expr::Variable { .. } => {}
     }
@@ -23,8 +23,27 @@ const BUILTIN_NAMES: &[&str] = &[
     "set_new",
     "set_add",
     "set_has",
+    "map_remove",
+    "map_keys",
+    "map_values",
+    "map_entries",
+    "map_merge",
+    "set_remove",
+    "set_union",
+    "set_intersection",
+    "set_difference",
+    "set_to_array",
     "math_abs",
     "math_pow",
+    "wrapping_add",
+    "wrapping_sub",
+    "wrapping_mul",
+    "checked_add",
+    "checked_sub",
+    "checked_mul",
+    "saturating_add",
+    "saturating_sub",
+    "saturating_mul",
     "math_clamp",
     "dag_topo_sort",
     "time_now",
@@ -43,6 +62,14 @@ const BUILTIN_NAMES: &[&str] = &[
     "str_slice",
     "str_to_int",
     "str_to_float",
+    "deque_new",
+    "deque_push_front",
+    "deque_push_back",
+    "deque_pop_front",
+    "deque_pop_back",
+    "deque_len",
+    "deque_get",
+    "deque_to_array",
     "buffer_new",
     "serialize",
     "deserialize",
@@ -66,15 +93,22 @@ const BUILTIN_NAMES: &[&str] = &[
     "run_actors",
     "envelope",
     "make_envelope",
+    "sort",
+    "hash",
 ];
 
 const KEYWORDS: &[&str] = &[
     "let",
+    "var",
+    "const",
+    "pub",
     "if",
     "else",
     "true",
     "false",
     "struct",
+    "type",
+    "newtype",
     "enum",
     "and",
     "or",
@@ -89,6 +123,8 @@ const KEYWORDS: &[&str] = &[
     "while",
     "for",
     "in",
+    "break",
+    "continue",
     "try",
     "catch",
     "weak",
@@ -154,6 +190,27 @@ fn word_at_position(text: &str, line: usize, character: usize) -> Option<String>
     Some(chars[start..=end].iter().collect())
 }
 
+/// `h` when the word at the position is written `h.word`.
+fn qualifier_at(text: &str, line: usize, character: usize) -> Option<String> {
+    let chars = line_chars(text, line)?;
+    let mut start = character.min(chars.len().checked_sub(1)?);
+    while start > 0 && is_identifier_char(chars[start - 1]) {
+        start -= 1;
+    }
+    if start < 2 || chars[start - 1] != '.' {
+        return None;
+    }
+    let end = start - 1;
+    let mut q = end;
+    while q > 0 && is_identifier_char(chars[q - 1]) {
+        q -= 1;
+    }
+    if q == end || (q > 0 && chars[q - 1] == '.') {
+        return None;
+    }
+    Some(chars[q..end].iter().collect())
+}
+
 fn lsp_position_from_offset(text: &str, offset: usize) -> (usize, usize) {
     let mut line = 0usize;
     let mut col = 0usize;
@@ -183,7 +240,7 @@ fn collect_declarations(text: &str) -> HashMap<String, SymbolDecl> {
     while i < tokens.len() {
         let tok = &tokens[i];
         match tok.token_type {
-            TokenType::Let if i + 1 < tokens.len() => {
+            TokenType::Let | TokenType::Var if i + 1 < tokens.len() => {
                 let id = &tokens[i + 1];
                 if matches!(id.token_type, TokenType::Identifier) {
                     map.entry(id.lexeme.clone()).or_insert(SymbolDecl {
@@ -236,7 +293,13 @@ fn collect_declarations(text: &str) -> HashMap<String, SymbolDecl> {
                     });
                 }
             }
-            TokenType::Struct | TokenType::Enum if i + 1 < tokens.len() => {
+            TokenType::Struct
+            | TokenType::Enum
+            | TokenType::Type
+            | TokenType::Newtype
+            | TokenType::Const
+                if i + 1 < tokens.len() =>
+            {
                 let id = &tokens[i + 1];
                 if matches!(id.token_type, TokenType::Identifier) {
                     map.entry(id.lexeme.clone()).or_insert(SymbolDecl {
@@ -267,6 +330,7 @@ enum HoverKind {
     Function,
     Struct,
     Enum,
+    Type,
     Builtin,
 }
 
@@ -292,15 +356,20 @@ fn collect_hover_info(text: &str) -> HashMap<String, HoverInfo> {
     let mut i = 0usize;
     while i < tokens.len() {
         match &tokens[i].token_type {
-            TokenType::Let
-                // let name [: type] = expr
+            TokenType::Let | TokenType::Var | TokenType::Const
+                // let|var|const name [: type] = expr
                 if i + 1 < tokens.len() && matches!(tokens[i+1].token_type, TokenType::Identifier) => {
                     let name = tokens[i+1].lexeme.clone();
+                    let kw = match tokens[i].token_type {
+                        TokenType::Var => "var",
+                        TokenType::Const => "const",
+                        _ => "let",
+                    };
                     // Look for optional type annotation
-                    let mut detail = format!("let {}", name);
+                    let mut detail = format!("{} {}", kw, name);
                     if i + 2 < tokens.len() && matches!(tokens[i+2].token_type, TokenType::Colon)
                         && i + 3 < tokens.len() && matches!(tokens[i+3].token_type, TokenType::Identifier) {
-                            detail = format!("let {}: {}", name, tokens[i+3].lexeme);
+                            detail = format!("{} {}: {}", kw, name, tokens[i+3].lexeme);
                         }
                     map.entry(name.clone()).or_insert(HoverInfo {
                         kind: HoverKind::Variable,
@@ -389,6 +458,26 @@ fn collect_hover_info(text: &str) -> HashMap<String, HoverInfo> {
                         detail,
                     });
                 }
+            TokenType::Type | TokenType::Newtype
+                // type Nome = T | newtype Nome(T): a declaração inteira, até o fim da linha
+                if i + 1 < tokens.len() && matches!(tokens[i+1].token_type, TokenType::Identifier) => {
+                    let tname = tokens[i+1].lexeme.clone();
+                    let line = tokens[i].line;
+                    let rest: String = tokens[i + 2..]
+                        .iter()
+                        .take_while(|t| t.line == line && !matches!(t.token_type, TokenType::Semicolon | TokenType::Eof))
+                        .map(|t| t.lexeme.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    let detail = format!("{} {} {}", tokens[i].lexeme, tname, rest)
+                        .replace(" ( ", "(")
+                        .replace(" )", ")");
+                    map.entry(tname.clone()).or_insert(HoverInfo {
+                        kind: HoverKind::Type,
+                        name: tname,
+                        detail,
+                    });
+                }
             _ => {}
         }
         i += 1;
@@ -485,7 +574,8 @@ fn to_file_uri(path: &Path) -> Option<String> {
     }
 }
 
-fn parse_import_paths(text: &str) -> Vec<String> {
+/// Module path (`net/http`) and alias of every `import` in `text`.
+fn parse_imports(text: &str) -> Vec<(String, Option<String>)> {
     let mut out = Vec::new();
     let mut lexer = Lexer::new(text.to_string());
     let tokens = match lexer.scan_tokens() {
@@ -502,15 +592,27 @@ fn parse_import_paths(text: &str) -> Vec<String> {
 
         i += 1;
         let mut parts: Vec<String> = Vec::new();
+        let mut alias = None;
+        // `{...}` (selective) and `as` end the module path.
+        let mut in_path = true;
         while i < tokens.len() {
             match tokens[i].token_type {
-                TokenType::Identifier => {
+                TokenType::Identifier if in_path => {
                     parts.push(tokens[i].lexeme.clone());
                     i += 1;
                     if i < tokens.len() && matches!(tokens[i].token_type, TokenType::Dot) {
                         i += 1;
                     }
                 }
+                TokenType::As => {
+                    in_path = false;
+                    alias = tokens.get(i + 1).map(|t| t.lexeme.clone());
+                    i += 1;
+                }
+                TokenType::LeftBrace => {
+                    in_path = false;
+                    i += 1;
+                }
                 TokenType::Semicolon => {
                     i += 1;
                     break;
@@ -522,7 +624,7 @@ fn parse_import_paths(text: &str) -> Vec<String> {
         }
 
         if !parts.is_empty() {
-            out.push(parts.join("/"));
+            out.push((parts.join("/"), alias));
         }
     }
 
@@ -597,7 +699,7 @@ fn collect_import_graph_from_uri(
         .entry(uri.to_string())
         .or_insert(current_text.clone());
 
-    for module in parse_import_paths(&current_text) {
+    for (module, _) in parse_imports(&current_text) {
         let import_path = match resolve_import_candidate(&canon, &module) {
             Some(p) => p,
             None => continue,
@@ -629,16 +731,52 @@ fn resolve_definition_location(
     let text = documents.get(uri)?;
     let word = word_at_position(text, line, character)?;
 
+    // `h.get` with `import net.http as h`: only the aliased module counts.
+    let aliases: HashMap<String, String> = parse_imports(text)
+        .into_iter()
+        .filter_map(|(module, alias)| alias.map(|a| (a, module)))
+        .collect();
+    if let Some(module) = qualifier_at(text, line, character).and_then(|q| aliases.get(&q)) {
+        let (module_uri, module_text) = imported_document(documents, uri, module)?;
+        let d = collect_declarations(&module_text).get(&word)?.clone();
+        return Some((module_uri, d));
+    }
+
     let local_defs = collect_declarations(text);
     if let Some(d) = local_defs.get(&word) {
         return Some((uri.to_string(), d.clone()));
     }
 
+    if let Some(module) = aliases.get(&word) {
+        let (module_uri, _) = imported_document(documents, uri, module)?;
+        let start = SymbolDecl {
+            line: 0,
+            start_char: 0,
+            end_char: 0,
+        };
+        return Some((module_uri, start));
+    }
+
     let defs = collect_workspace_declarations(documents);
     defs.get(&word)
         .map(|loc| (loc.uri.clone(), loc.decl.clone()))
 }
 
+/// URI and text of `module` as imported from `uri`, preferring the open buffer.
+fn imported_document(
+    documents: &HashMap<String, String>,
+    uri: &str,
+    module: &str,
+) -> Option<(String, String)> {
+    let path = resolve_import_candidate(&decode_file_uri_path(uri)?, module)?;
+    let module_uri = to_file_uri(&path)?;
+    let text = documents
+        .get(&module_uri)
+        .cloned()
+        .or_else(|| std::fs::read_to_string(&path).ok())?;
+    Some((module_uri, text))
+}
+
 fn find_identifier_occurrences(text: &str, name: &str) -> Vec<(usize, usize)> {
     let mut out = Vec::new();
     let chars: Vec<char> = text.chars().collect();
@@ -724,17 +862,21 @@ fn token_class(t: &TokenType, prev: Option<&TokenType>) -> Option<usize> {
     let is_keyword = matches!(
         t,
         TokenType::Let
+            | TokenType::Var
             | TokenType::If
             | TokenType::Else
             | TokenType::True
             | TokenType::False
             | TokenType::Struct
             | TokenType::Enum
+            | TokenType::Type
+            | TokenType::Newtype
             | TokenType::And
             | TokenType::Or
             | TokenType::Match
             | TokenType::Case
             | TokenType::Import
+            | TokenType::Pub
             | TokenType::Func
             | TokenType::Performant
             | TokenType::Spawn
@@ -762,11 +904,15 @@ fn token_class(t: &TokenType, prev: Option<&TokenType>) -> Option<usize> {
             }
         }
         TokenType::String(_) | TokenType::InterpolatedString(_) => Some(3),
-        TokenType::Number(_) => Some(4),
+        TokenType::Int(_) | TokenType::Float(_) => Some(4),
         TokenType::Plus
         | TokenType::Minus
         | TokenType::Star
         | TokenType::Slash
+        | TokenType::PlusEqual
+        | TokenType::MinusEqual
+        | TokenType::StarEqual
+        | TokenType::SlashEqual
         | TokenType::Equal
         | TokenType::EqualEqual
         | TokenType::Bang
@@ -804,6 +950,51 @@ fn completion_items(text: &str) -> Vec<Value> {
     items
 }
 
+/// Builtin methods offered after `receiver.`, when the receiver's type is
+/// known (a literal or a top-level binding typed by `TypeInfer`). `None` when
+/// the cursor does not follow a `.` or the type is unknown, so the caller falls
+/// back to the regular completion list.
+fn member_completion_items(text: &str, line: usize, character: usize) -> Option<Vec<Value>> {
+    let line_text = text.lines().nth(line)?;
+    let before: String = line_text.chars().take(character).collect();
+    let before = before.trim_end_matches(is_identifier_char);
+    let receiver = before.strip_suffix('.')?;
+
+    let kind = if receiver.ends_with('"') {
+        "String"
+    } else if receiver.ends_with(']') {
+        "Array"
+    } else {
+        let name_start = receiver
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| is_identifier_char(*c))
+            .last()
+            .map(|(i, _)| i)?;
+        let name = &receiver[name_start..];
+        if name.chars().all(|c| c.is_ascii_digit()) {
+            "Int"
+        } else {
+            let ty = binding_type(text, name)?;
+            core::methods::type_kind(&ty)?
+        }
+    };
+    Some(
+        core::methods::methods_of(kind)
+            .map(|m| serde_json::json!({"label": m.name, "kind": 2, "detail": m.signature()}))
+            .collect(),
+    )
+}
+
+/// Type `TypeInfer` gives the top-level binding `name` in `text`.
+fn binding_type(text: &str, name: &str) -> Option<core::Type> {
+    let tokens = Lexer::new(text.to_string()).scan_tokens().ok()?;
+    let (program, _) = Parser::new(tokens).parse();
+    let mut tenv = TypeEnv::new();
+    let _ = TypeInfer::new(&mut tenv).run(&program);
+    tenv.get_var(name).cloned()
+}
+
 fn workspace_completion_items(documents: &HashMap<String, String>) -> Vec<Value> {
     let all_docs = collect_project_documents(documents);
 
@@ -832,7 +1023,7 @@ fn workspace_completion_items(documents: &HashMap<String, String>) -> Vec<Value>
             }
             let kind = match info.kind {
                 HoverKind::Function => 3,
-                HoverKind::Struct => 7,
+                HoverKind::Struct | HoverKind::Type => 7,
                 HoverKind::Enum => 13,
                 HoverKind::Builtin => 3,
                 HoverKind::Variable => 6,
@@ -986,6 +1177,7 @@ fn hover_markdown(
             HoverKind::Function => "function",
             HoverKind::Struct => "struct",
             HoverKind::Enum => "enum",
+            HoverKind::Type => "type",
             HoverKind::Builtin => "builtin",
             HoverKind::Variable => "variable",
         };
@@ -1048,12 +1240,26 @@ fn process_request(req: &Value, documents: &mut HashMap<String, String>) -> Opti
         }
 
         "textDocument/completion" => {
-            let uri = req
-                .get("params")
+            let params = req.get("params");
+            let uri = params
                 .and_then(|p| p.get("textDocument"))
                 .and_then(|d| d.get("uri"))
                 .and_then(|u| u.as_str());
-            let items = if uri.map(|u| documents.contains_key(u)).unwrap_or(false) {
+            let position = params.and_then(|p| p.get("position")).and_then(|pos| {
+                Some((
+                    pos.get("line")?.as_u64()? as usize,
+                    pos.get("character")?.as_u64()? as usize,
+                ))
+            });
+            let member = match (uri.and_then(|u| documents.get(u)), position) {
+                (Some(text), Some((line, character))) => {
+                    member_completion_items(text, line, character)
+                }
+                _ => None,
+            };
+            let items = if let Some(items) = member {
+                items
+            } else if uri.map(|u| documents.contains_key(u)).unwrap_or(false) {
                 workspace_completion_items(documents)
             } else {
                 completion_items("")
@@ -1381,6 +1587,38 @@ mod tests {
         assert_eq!(loc.1.line, 0);
     }
 
+    #[test]
+    fn definition_follows_import_alias() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let main_path = tmp.path().join("main.art");
+        let lib_path = tmp.path().join("lib.art");
+
+        std::fs::write(
+            &main_path,
+            "import lib as h;\nfunc get() { return 0; }\nprintln(h.get());\n",
+        )
+        .expect("write main");
+        std::fs::write(&lib_path, "let x = 1;\npub func get() { return 1; }\n").expect("write lib");
+
+        let main_uri = to_file_uri(&main_path).expect("main uri");
+        let lib_uri = to_file_uri(&lib_path).expect("lib uri");
+        let mut docs = HashMap::new();
+        docs.insert(
+            main_uri.clone(),
+            std::fs::read_to_string(&main_path).expect("read main"),
+        );
+
+        // `h.get` skips the local `get` and lands in lib.art.
+        let loc = resolve_definition_location(&docs, &main_uri, 2, 10).expect("qualified item");
+        assert_eq!(loc.0, lib_uri);
+        assert_eq!(loc.1.line, 1);
+
+        // The alias itself jumps to the module file.
+        let loc = resolve_definition_location(&docs, &main_uri, 2, 8).expect("alias");
+        assert_eq!(loc.0, lib_uri);
+        assert_eq!(loc.1.line, 0);
+    }
+
     #[test]
     fn rename_updates_imported_file_not_open() {
         let tmp = tempfile::tempdir().expect("tempdir");
@@ -1451,6 +1689,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn completion_after_dot_lists_builtin_methods_of_the_receiver() {
+        let mut docs = HashMap::new();
+        docs.insert(
+            "file:///m.art".to_string(),
+            "let s = \"a,b\"\nlet parts = s.split(\",\")\ns.\nparts.jo".to_string(),
+        );
+        let labels_at = |docs: &mut HashMap<String, String>, line: u64, character: u64| {
+            let req = make_req(
+                5,
+                "textDocument/completion",
+                serde_json::json!({ "textDocument": { "uri": "file:///m.art" }, "position": { "line": line, "character": character } }),
+            );
+            let resp = process_request(&req, docs).expect("completion must return a response");
+            resp["result"]["items"]
+                .as_array()
+                .expect("items array")
+                .iter()
+                .map(|i| {
+                    (
+                        i["label"].as_str().unwrap_or("").to_string(),
+                        i["detail"].clone(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let items = labels_at(&mut docs, 2, 2);
+        let split = items
+            .iter()
+            .find(|(l, _)| l == "split")
+            .expect("split offered");
+        assert_eq!(split.1, "split(sep: String) -> [String]");
+        assert!(
+            !items
+                .iter()
+                .any(|(l, _)| l == "push_back" || l == "println")
+        );
+
+        let items = labels_at(&mut docs, 3, 8);
+        assert!(items.iter().any(|(l, _)| l == "join"), "{:?}", items);
+        assert!(!items.iter().any(|(l, _)| l == "split"));
+    }
+
     #[test]
     fn smoke_hover_shows_function_signature() {
         let src = "func greet(name: String) -> String { return name }";
@@ -1468,6 +1750,17 @@ mod tests {
         assert!(md.contains("greet"), "hover should mention function name");
     }
 
+    #[test]
+    fn hover_and_definition_cover_type_declarations() {
+        let src = "type UserId = Int\nnewtype Meters(Float)\nlet d = Meters(1.0);";
+        let info = collect_hover_info(src);
+        assert_eq!(info["UserId"].detail, "type UserId = Int");
+        assert_eq!(info["Meters"].detail, "newtype Meters(Float)");
+        let defs = collect_declarations(src);
+        assert_eq!(defs["Meters"].line, 1);
+        assert_eq!(defs["Meters"].start_char, 8);
+    }
+
     #[test]
     fn smoke_definition_finds_let_binding() {
         let src = "let answer = 42;\nprintln(answer);";
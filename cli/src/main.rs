@@ -1,4 +1,6 @@
 use codegen_js::{CodegenJs, CodegenOptions, ModuleFormat};
+use core::OverflowMode;
+use core::const_eval::fold_consts;
 use diagnostics::format_diagnostic;
 use typeck::TypeChecker;
 mod resolver;
@@ -15,12 +17,26 @@ mod docgen;
 mod formatter;
 mod linter;
 mod lsp;
+mod modules;
+mod namespaces;
 mod std_doc;
 use std::env;
 use std::fs;
 use std::io::{self, IsTerminal, Write};
 use std::process;
 
+/// Errors from `TypeChecker` that must stop a run. `TypeInfer` covers the
+/// runtime-facing rules; this pass adds declaration checks such as assignment
+/// to immutable bindings. Lints are left to `art lint`.
+fn checker_type_errors(program: &[core::ast::Stmt]) -> Vec<diagnostics::Diagnostic> {
+    TypeChecker::new()
+        .check(program)
+        .iter()
+        .filter(|d| matches!(d.kind, diagnostics::DiagnosticKind::Type))
+        .cloned()
+        .collect()
+}
+
 fn run_with_source(
     name: &str,
     source: String,
@@ -54,6 +70,13 @@ fn run_with_source(
         }
         return;
     }
+    let checker_errors = checker_type_errors(&program);
+    if !checker_errors.is_empty() {
+        for d in &checker_errors {
+            eprintln!("{}", format_diagnostic(&source, d));
+        }
+        return;
+    }
     // If emit_ir requested, lower functions and write/print IR before interpretation
     if let Some(path) = emit_ir {
         // If path == "-" print to stdout.
@@ -104,8 +127,9 @@ fn run_with_source(
             "[emit-ir] found {} function nodes via recursive collect",
             found.len()
         );
+        let consts = fold_consts(&program);
         for fs in found {
-            if let Some(irfn) = ir::lower_stmt(fs) {
+            if let Some(irfn) = ir::lower_stmt_with_consts(fs, &consts) {
                 let txt = irfn.emit_text();
                 functions.push((irfn.name.clone(), txt));
                 out.push_str(&format!(
@@ -183,6 +207,7 @@ fn run_file(
     profile: Option<&str>,
     emit_ir: Option<&str>,
     pure_mode: bool,
+    overflow: OverflowMode,
     record_file: Option<&str>,
 ) {
     // Use resolver to expand imports
@@ -197,6 +222,13 @@ fn run_file(
                 }
                 return;
             }
+            let checker_errors = checker_type_errors(&program);
+            if !checker_errors.is_empty() {
+                for d in &checker_errors {
+                    eprintln!("{}", format_diagnostic(&main_source, d));
+                }
+                return;
+            }
             // If emit_ir requested, lower functions and write/print IR before interpretation
             if let Some(path) = emit_ir {
                 let mut out = String::new();
@@ -229,8 +261,9 @@ fn run_file(
                     found.len()
                 );
                 // Try proper lowering; if that fails create a conservative fallback IR
+                let consts = fold_consts(&program);
                 for fs in found {
-                    let irfn_opt = ir::lower_stmt(fs);
+                    let irfn_opt = ir::lower_stmt_with_consts(fs, &consts);
                     let irfn = if let Some(f) = irfn_opt {
                         f
                     } else {
@@ -318,6 +351,7 @@ fn run_file(
                                 body: _,
                                 method_owner: _,
                                 is_async: _,
+                                public: _,
                             } => name.lexeme.clone(),
                             _ => "anon".to_string(),
                         };
@@ -390,6 +424,7 @@ fn run_file(
 
             let mut interpreter = Interpreter::with_prelude();
             interpreter.set_pure_mode(pure_mode);
+            interpreter.set_overflow_mode(overflow);
             if let Some(rf) = record_file
                 && let Err(e) = interpreter.enable_tracer(rf)
             {
@@ -415,7 +450,22 @@ fn run_file(
     }
 }
 
-fn run_aot(path: &str, out: Option<&str>, wasm: bool, llvm: bool, emit_llvm_ir: bool) {
+/// Valor de `--overflow=`; sai com 64 se nao for `wrap` ou `trap`.
+fn parse_overflow_flag(mode: &str) -> OverflowMode {
+    OverflowMode::parse(mode).unwrap_or_else(|| {
+        eprintln!("error: invalid --overflow={} (expected wrap or trap)", mode);
+        process::exit(64);
+    })
+}
+
+fn run_aot(
+    path: &str,
+    out: Option<&str>,
+    wasm: bool,
+    llvm: bool,
+    emit_llvm_ir: bool,
+    overflow: OverflowMode,
+) {
     match crate::resolver::resolve(path) {
         Ok((program, main_source)) => {
             let mut tenv = TypeEnv::new();
@@ -426,6 +476,14 @@ fn run_aot(path: &str, out: Option<&str>, wasm: bool, llvm: bool, emit_llvm_ir:
                 }
                 return;
             }
+            // Inclui os `const` que não dobram em tempo de compilação.
+            let checker_errors = checker_type_errors(&program);
+            if !checker_errors.is_empty() {
+                for d in &checker_errors {
+                    eprintln!("{}", format_diagnostic(&main_source, d));
+                }
+                return;
+            }
 
             let mut found: Vec<&core::ast::Stmt> = Vec::new();
             fn collect_functions<'a>(
@@ -449,9 +507,10 @@ fn run_aot(path: &str, out: Option<&str>, wasm: bool, llvm: bool, emit_llvm_ir:
                 collect_functions(s, &mut found);
             }
 
+            let consts = fold_consts(&program);
             let mut funcs = Vec::new();
             for fs in found {
-                if let Some(irfn) = ir::lower_stmt(fs) {
+                if let Some(irfn) = ir::lower_stmt_with_consts(fs, &consts) {
                     funcs.push(irfn);
                 } else {
                     eprintln!(
@@ -467,7 +526,7 @@ fn run_aot(path: &str, out: Option<&str>, wasm: bool, llvm: bool, emit_llvm_ir:
 
             // ── LLVM backend: emit textual IR and compile with clang ─────────
             if llvm || emit_llvm_ir {
-                let ll_code = ir::llvm_emitter::emit_llvm_module(&funcs, "main");
+                let ll_code = ir::llvm_emitter::emit_llvm_module(&funcs, "main", overflow);
 
                 if emit_llvm_ir {
                     let out_ll = out.map(|s| s.to_string()).unwrap_or_else(|| {
@@ -539,7 +598,7 @@ fn run_aot(path: &str, out: Option<&str>, wasm: bool, llvm: bool, emit_llvm_ir:
                 return;
             }
 
-            let c_code = ir::c_emitter::emit_c_program(&funcs, "main");
+            let c_code = ir::c_emitter::emit_c_program(&funcs, "main", overflow);
 
             use sha2::{Digest, Sha256};
             let mut hasher = Sha256::new();
@@ -713,7 +772,10 @@ fn run_debug_repl(script_path: &str, replay_path: &str) {
                 println!("  debugger exited.");
                 break;
             }
-            Err(RuntimeError::Return(_)) | Ok(_) => {
+            Err(RuntimeError::Return(_))
+            | Err(RuntimeError::Break(_))
+            | Err(RuntimeError::Continue(_))
+            | Ok(_) => {
                 println!(
                     "  program reached end normally (tick {}).",
                     interpreter.executed_statements
@@ -1087,9 +1149,9 @@ fn real_main() {
         let mut wasm = false;
         let mut llvm = false;
         let mut emit_llvm_ir = false;
+        let mut overflow = OverflowMode::default();
 
-        const USAGE: &str =
-            "Usage: art build-aot <script.art> [--out <path>] [--wasm | --llvm | --emit-llvm-ir]";
+        const USAGE: &str = "Usage: art build-aot <script.art> [--out <path>] [--overflow=wrap|trap] [--wasm | --llvm | --emit-llvm-ir]";
 
         let mut j = 2usize;
         while j < args.len() {
@@ -1103,6 +1165,9 @@ fn real_main() {
             } else if a == "--emit-llvm-ir" {
                 emit_llvm_ir = true;
                 j += 1;
+            } else if let Some(mode) = a.strip_prefix("--overflow=") {
+                overflow = parse_overflow_flag(mode);
+                j += 1;
             } else if a == "--out" && j + 1 < args.len() {
                 out = Some(args[j + 1].clone());
                 j += 2;
@@ -1118,7 +1183,7 @@ fn real_main() {
             eprintln!("{}", USAGE);
             process::exit(64);
         };
-        run_aot(&file, out.as_deref(), wasm, llvm, emit_llvm_ir);
+        run_aot(&file, out.as_deref(), wasm, llvm, emit_llvm_ir, overflow);
         return;
     }
 
@@ -1139,9 +1204,14 @@ fn real_main() {
         let mut out_dir = "dist".to_string();
         let mut emit_sourcemap = false;
         let mut bundle = false;
+        let mut overflow = OverflowMode::default();
         let mut i = 2usize;
         while i < args.len() {
             match args[i].as_str() {
+                a if a.starts_with("--overflow=") => {
+                    overflow = parse_overflow_flag(&a["--overflow=".len()..]);
+                    i += 1;
+                }
                 "--target" if i + 1 < args.len() => {
                     target = match args[i + 1].as_str() {
                         "wasm" => "wasm",
@@ -1180,7 +1250,7 @@ fn real_main() {
             Some(f) => f,
             None => {
                 eprintln!(
-                    "Usage: art build <file.art> --target js [--out dist/] [--sourcemap] [--bundle]"
+                    "Usage: art build <file.art> --target js [--out dist/] [--sourcemap] [--bundle] [--overflow=wrap|trap]"
                 );
                 process::exit(64);
             }
@@ -1211,7 +1281,9 @@ fn real_main() {
 
         // Type checking pass
         let type_diags = TypeChecker::new().check(&program).to_vec();
-        let has_type_errors = !type_diags.is_empty();
+        let has_type_errors = type_diags
+            .iter()
+            .any(|d| matches!(d.kind, diagnostics::DiagnosticKind::Type));
         for d in &type_diags {
             eprintln!("{}", format_diagnostic(&src, d));
         }
@@ -1231,7 +1303,7 @@ fn real_main() {
         let js_path = format!("{}/{}.js", out_dir, stem);
 
         if bundle {
-            match bundler::bundle(&input, emit_sourcemap) {
+            match bundler::bundle(&input, emit_sourcemap, overflow) {
                 Ok(b) => {
                     if let Err(e) = std::fs::write(&js_path, &b.code) {
                         eprintln!("error: cannot write '{}': {}", js_path, e);
@@ -1251,6 +1323,7 @@ fn real_main() {
                 source_file: Some(input.clone()),
                 emit_source_map: emit_sourcemap,
                 module_format: ModuleFormat::Esm,
+                overflow,
             };
             let output = CodegenJs::new(opts).emit_program(&program);
             if let Err(e) = std::fs::write(&js_path, &output.code) {
@@ -1384,6 +1457,7 @@ fn real_main() {
 
     if args[1] == "run" {
         let mut pure_mode = false;
+        let mut overflow = OverflowMode::default();
         let mut file: Option<String> = None;
         let mut record_file: Option<String> = None;
         let mut j = 2usize;
@@ -1392,6 +1466,9 @@ fn real_main() {
             if a == "--pure" {
                 pure_mode = true;
                 j += 1;
+            } else if let Some(mode) = a.strip_prefix("--overflow=") {
+                overflow = parse_overflow_flag(mode);
+                j += 1;
             } else if a == "--record" && j + 1 < args.len() {
                 record_file = Some(args[j + 1].clone());
                 j += 2;
@@ -1399,12 +1476,14 @@ fn real_main() {
                 file = Some(a.clone());
                 j += 1;
             } else {
-                eprintln!("Usage: art run [--pure] [--record <file>] <script>");
+                eprintln!(
+                    "Usage: art run [--pure] [--overflow=wrap|trap] [--record <file>] <script>"
+                );
                 process::exit(64);
             }
         }
         let Some(file) = file else {
-            eprintln!("Usage: art run [--pure] [--record <file>] <script>");
+            eprintln!("Usage: art run [--pure] [--overflow=wrap|trap] [--record <file>] <script>");
             process::exit(64);
         };
         run_file(
@@ -1412,6 +1491,7 @@ fn real_main() {
             gen_profile.as_deref(),
             emit_ir.as_deref(),
             pure_mode,
+            overflow,
             record_file.as_deref(),
         );
         if startup_bench {
@@ -1957,7 +2037,14 @@ fn real_main() {
                     process::exit(65);
                 }
 
-                let lint_diags = linter::lint_ast(&program);
+                let mut lint_diags = linter::lint_ast(&program);
+                lint_diags.extend(
+                    TypeChecker::new()
+                        .check(&program)
+                        .iter()
+                        .filter(|d| matches!(d.kind, diagnostics::DiagnosticKind::Lint))
+                        .cloned(),
+                );
                 if lint_diags.is_empty() {
                     println!("No lint warnings found.");
                 } else {
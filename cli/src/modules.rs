@@ -0,0 +1,266 @@
+//! Module visibility, shared by the resolver and the JS bundler.
+//!
+//! Both merge every module into one program, so imports are checked here
+//! before the merge: selective imports and alias-qualified names (which the
+//! parser turns into the import's `items`) must name `pub` items. Names that
+//! more than one module declares are then kept apart by `namespaces`.
+
+use std::collections::{HashMap, HashSet};
+
+use core::ast::Stmt;
+use core::{Token, TokenType};
+use diagnostics::{Diagnostic, DiagnosticKind, Span};
+
+/// Top-level items of a module and whether each one is exported.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleItems {
+    items: HashMap<String, bool>,
+}
+
+impl ModuleItems {
+    /// `func`, `struct`, `enum` and `const` are private unless marked `pub`;
+    /// top-level `let`s and type aliases have no modifier and are always
+    /// exported. A module without any `pub` predates visibility and keeps
+    /// exporting everything.
+    pub fn collect(program: &[Stmt]) -> Self {
+        let mut items = HashMap::new();
+        let mut explicit = false;
+        for stmt in program {
+            match stmt {
+                Stmt::Function {
+                    name,
+                    method_owner: None,
+                    public,
+                    ..
+                }
+                | Stmt::StructDecl { name, public, .. }
+                | Stmt::EnumDecl { name, public, .. }
+                | Stmt::Const { name, public, .. } => {
+                    explicit |= *public;
+                    items.insert(name.lexeme.clone(), *public);
+                }
+                Stmt::TypeAlias { name, .. } | Stmt::Newtype { name, .. } => {
+                    items.insert(name.lexeme.clone(), true);
+                }
+                Stmt::Let { pattern, .. } => {
+                    for b in pattern.bindings() {
+                        items.insert(b.lexeme.clone(), true);
+                    }
+                }
+                _ => {}
+            }
+        }
+        if !explicit {
+            items.values_mut().for_each(|p| *p = true);
+        }
+        ModuleItems { items }
+    }
+
+    /// `Some(public)` for a declared item, `None` when there is no such item.
+    pub fn visibility(&self, name: &str) -> Option<bool> {
+        self.items.get(name).copied()
+    }
+
+    /// Every top-level item, exported or not.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.items.keys().map(String::as_str)
+    }
+
+    /// Items visible to importers.
+    pub fn exported(&self) -> impl Iterator<Item = &str> {
+        self.items
+            .iter()
+            .filter(|(_, p)| **p)
+            .map(|(n, _)| n.as_str())
+    }
+
+    fn private_names(&self) -> impl Iterator<Item = &String> {
+        self.items.iter().filter(|(_, p)| !**p).map(|(n, _)| n)
+    }
+}
+
+/// Checks the imports of `program` (parsed from `tokens`) against the modules
+/// they name. `imported[i]` holds the items of the module named by the i-th
+/// `import`, or `None` if it is not known yet (an import cycle).
+pub fn check_imports(
+    tokens: &[Token],
+    program: &[Stmt],
+    imported: &[Option<&ModuleItems>],
+) -> Vec<Diagnostic> {
+    let mut errors = Vec::new();
+    // Private names of modules imported whole, with the module they belong to.
+    let mut hidden: HashMap<&str, String> = HashMap::new();
+    let mut visible: HashSet<&str> = HashSet::new();
+
+    let imports = program.iter().filter_map(|s| match s {
+        Stmt::Import { path, items, alias } => Some((path, items, alias)),
+        _ => None,
+    });
+    for ((path, items, alias), module) in imports.zip(imported) {
+        let Some(module) = module else { continue };
+        let name = path
+            .iter()
+            .map(|t| t.lexeme.as_str())
+            .collect::<Vec<_>>()
+            .join(".");
+        match items {
+            Some(items) => {
+                for item in items {
+                    check_item(&name, module, item, &mut errors);
+                    visible.insert(&item.lexeme);
+                }
+            }
+            // An alias that is never used imports nothing.
+            None if alias.is_some() => {}
+            None => {
+                for private in module.private_names() {
+                    hidden.insert(private, name.clone());
+                }
+                visible.extend(module.exported());
+            }
+        }
+    }
+
+    if !hidden.is_empty() {
+        let mut bound = HashSet::new();
+        bound_names(program, &mut bound);
+        let mut in_import = false;
+        for (i, tok) in tokens.iter().enumerate() {
+            match tok.token_type {
+                TokenType::Import => in_import = true,
+                TokenType::Semicolon => in_import = false,
+                TokenType::Identifier if !in_import => {
+                    let after_dot = i > 0 && tokens[i - 1].token_type == TokenType::Dot;
+                    let is_label = tokens
+                        .get(i + 1)
+                        .is_some_and(|t| t.token_type == TokenType::Colon);
+                    if let Some(module) = hidden.get(tok.lexeme.as_str())
+                        && !after_dot
+                        && !is_label
+                        && !bound.contains(&tok.lexeme)
+                        && !visible.contains(tok.lexeme.as_str())
+                    {
+                        errors.push(private_error(tok, module));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    errors
+}
+
+fn check_item(module: &str, items: &ModuleItems, item: &Token, errors: &mut Vec<Diagnostic>) {
+    match items.visibility(&item.lexeme) {
+        Some(true) => {}
+        Some(false) => errors.push(private_error(item, module)),
+        None => errors.push(error(
+            item,
+            format!("Module '{}' has no item '{}'", module, item.lexeme),
+        )),
+    }
+}
+
+fn private_error(item: &Token, module: &str) -> Diagnostic {
+    error(
+        item,
+        format!("'{}' is private to module '{}'", item.lexeme, module),
+    )
+}
+
+fn error(tok: &Token, message: String) -> Diagnostic {
+    Diagnostic::new(
+        DiagnosticKind::Parse,
+        message,
+        Span::new(tok.start, tok.end, tok.line, tok.col),
+    )
+}
+
+/// Every name the module binds itself, at any depth. Shadowing is not
+/// tracked: a local with the same name as a private item hides it everywhere.
+fn bound_names(stmts: &[Stmt], out: &mut HashSet<String>) {
+    for stmt in stmts {
+        match stmt {
+            Stmt::Let { pattern, .. } => {
+                out.extend(pattern.bindings().into_iter().map(|t| t.lexeme.clone()));
+            }
+            Stmt::IfLet {
+                pattern,
+                then_branch,
+                else_branch,
+                ..
+            } => {
+                out.extend(pattern.bindings().into_iter().map(|t| t.lexeme.clone()));
+                bound_names(std::slice::from_ref(then_branch), out);
+                if let Some(e) = else_branch {
+                    bound_names(std::slice::from_ref(e), out);
+                }
+            }
+            Stmt::Const { name, .. }
+            | Stmt::StructDecl { name, .. }
+            | Stmt::EnumDecl { name, .. }
+            | Stmt::TypeAlias { name, .. }
+            | Stmt::Newtype { name, .. } => {
+                out.insert(name.lexeme.clone());
+            }
+            Stmt::Function {
+                name, params, body, ..
+            } => {
+                out.insert(name.lexeme.clone());
+                out.extend(params.iter().map(|p| p.name.lexeme.clone()));
+                bound_names(std::slice::from_ref(body.as_ref()), out);
+            }
+            Stmt::Block { statements }
+            | Stmt::Performant { statements }
+            | Stmt::SpawnActor { body: statements }
+            | Stmt::ImplBlock {
+                methods: statements,
+                ..
+            } => bound_names(statements, out),
+            Stmt::If {
+                then_branch,
+                else_branch,
+                ..
+            } => {
+                bound_names(std::slice::from_ref(then_branch), out);
+                if let Some(e) = else_branch {
+                    bound_names(std::slice::from_ref(e), out);
+                }
+            }
+            Stmt::While { body, .. } | Stmt::Defer { body, .. } => {
+                bound_names(std::slice::from_ref(body), out);
+            }
+            Stmt::For {
+                element,
+                pattern,
+                body,
+                ..
+            } => {
+                match pattern {
+                    Some(p) => out.extend(p.bindings().into_iter().map(|t| t.lexeme.clone())),
+                    None => {
+                        out.insert(element.lexeme.clone());
+                    }
+                }
+                bound_names(std::slice::from_ref(body), out);
+            }
+            Stmt::TryCatch {
+                try_branch,
+                catch_name,
+                catch_branch,
+            } => {
+                out.insert(catch_name.lexeme.clone());
+                bound_names(std::slice::from_ref(try_branch), out);
+                bound_names(std::slice::from_ref(catch_branch), out);
+            }
+            Stmt::Match { cases, .. } => {
+                for (pattern, _, body) in cases {
+                    out.extend(pattern.bindings().into_iter().map(|t| t.lexeme.clone()));
+                    bound_names(std::slice::from_ref(body), out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
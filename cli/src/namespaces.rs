@@ -0,0 +1,591 @@
+//! Keeps module items apart after the resolver and the JS bundler merge every
+//! module into one program.
+//!
+//! A name declared at the top level of more than one module would otherwise
+//! collapse into a single global, with the last module winning: `x.get()`
+//! could run `y`'s `get`, and a private helper could be replaced by another
+//! module's helper of the same name. Before the merge, every such name gets a
+//! module-qualified global (`util$parse`) in its own module and at each use
+//! that refers to it: bare names follow the module's own declarations and its
+//! selective/whole imports, and `alias.item` follows the alias's module.
+//! Names declared by a single module are left untouched, as is everything in
+//! the entry file's own declarations.
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use core::Token;
+use core::ast::{
+    Expr, FunctionParam, InterpolatedPart, MatchPattern, Program, Stmt, TemplateAttrValue,
+    TemplateNode,
+};
+
+use crate::modules::ModuleItems;
+
+/// A parsed module waiting to be merged.
+pub struct ParsedModule {
+    pub key: PathBuf,
+    pub program: Program,
+    /// Resolved module of each `import`, in source order.
+    pub deps: Vec<PathBuf>,
+    /// `(alias, item)` for every `alias.item` read by the parser.
+    pub qualified: Vec<(String, Token)>,
+}
+
+/// Renames colliding top-level names in `modules`, which are in merge order
+/// with the entry file last.
+pub fn separate(modules: &mut [ParsedModule]) {
+    let items: Vec<ModuleItems> = modules
+        .iter()
+        .map(|m| ModuleItems::collect(&m.program))
+        .collect();
+    let mut declared_by: HashMap<&str, usize> = HashMap::new();
+    for module in &items {
+        for name in module.names() {
+            *declared_by.entry(name).or_default() += 1;
+        }
+    }
+    let clashing: HashSet<String> = declared_by
+        .into_iter()
+        .filter(|(_, n)| *n > 1)
+        .map(|(name, _)| name.to_string())
+        .collect();
+    if clashing.is_empty() {
+        return;
+    }
+
+    let labels = labels(modules);
+    let entry = modules.len() - 1;
+    let global = |module: usize, name: &str| {
+        if module == entry || !clashing.contains(name) {
+            name.to_string()
+        } else {
+            format!("{}${}", labels[module], name)
+        }
+    };
+    let index: HashMap<PathBuf, usize> = modules
+        .iter()
+        .enumerate()
+        .map(|(i, m)| (m.key.clone(), i))
+        .collect();
+
+    let renamers: Vec<Renamer> = modules
+        .iter()
+        .enumerate()
+        .map(|(f, module)| {
+            let mut names = HashMap::new();
+            let mut aliases = HashMap::new();
+            let imports = module.program.iter().filter_map(|s| match s {
+                Stmt::Import { items, alias, .. } => Some((items, alias)),
+                _ => None,
+            });
+            for ((imported, alias), dep) in imports.zip(&module.deps) {
+                let Some(&d) = index.get(dep) else { continue };
+                match (imported, alias) {
+                    (_, Some(alias)) => {
+                        aliases.insert(alias.lexeme.clone(), d);
+                    }
+                    (Some(imported), None) => {
+                        for item in imported {
+                            names.insert(item.lexeme.clone(), global(d, &item.lexeme));
+                        }
+                    }
+                    (None, None) => {
+                        for name in items[d].exported() {
+                            names.insert(name.to_string(), global(d, name));
+                        }
+                    }
+                }
+            }
+            for name in items[f].names() {
+                names.insert(name.to_string(), global(f, name));
+            }
+            names.retain(|name, _| clashing.contains(name));
+
+            let mut at = HashMap::new();
+            let mut types = names.clone();
+            for (alias, item) in &module.qualified {
+                if let Some(&d) = aliases.get(alias)
+                    && clashing.contains(&item.lexeme)
+                {
+                    let target = global(d, &item.lexeme);
+                    at.insert((item.start, item.lexeme.clone()), target.clone());
+                    // Type annotations are strings, without positions: the
+                    // first alias that names the item decides.
+                    types.entry(item.lexeme.clone()).or_insert(target);
+                }
+            }
+            Renamer { names, types, at }
+        })
+        .collect();
+
+    for (module, renamer) in modules.iter_mut().zip(renamers) {
+        for stmt in &mut module.program {
+            renamer.stmt(stmt);
+        }
+    }
+}
+
+/// File stem of each module, made unique and usable as an identifier prefix.
+fn labels(modules: &[ParsedModule]) -> Vec<String> {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    modules
+        .iter()
+        .map(|m| {
+            let stem: String = m
+                .key
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default()
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect();
+            let n = seen.entry(stem.clone()).or_default();
+            *n += 1;
+            if *n == 1 {
+                stem
+            } else {
+                format!("{}{}", stem, n)
+            }
+        })
+        .collect()
+}
+
+/// Renames the uses of colliding names inside one module. The rename is
+/// uniform, so locals that reuse one of those names keep shadowing correctly;
+/// field names, method names and labels live in other namespaces and are not
+/// touched.
+struct Renamer {
+    names: HashMap<String, String>,
+    types: HashMap<String, String>,
+    /// `alias.item` uses, by the item token's position.
+    at: HashMap<(usize, String), String>,
+}
+
+impl Renamer {
+    fn token(&self, tok: &mut Token) {
+        let target = self
+            .at
+            .get(&(tok.start, tok.lexeme.clone()))
+            .or_else(|| self.names.get(&tok.lexeme));
+        if let Some(target) = target {
+            tok.lexeme = target.clone();
+        }
+    }
+
+    /// Renames the identifiers inside a type annotation (`[Point]`, `Option<Id>`).
+    fn ty(&self, ty: &mut String) {
+        let mut out = String::with_capacity(ty.len());
+        let mut word = String::new();
+        for c in ty.chars().chain(std::iter::once(' ')) {
+            if c.is_alphanumeric() || c == '_' {
+                word.push(c);
+                continue;
+            }
+            out.push_str(self.types.get(&word).unwrap_or(&word));
+            word.clear();
+            out.push(c);
+        }
+        out.pop();
+        *ty = out;
+    }
+
+    fn opt_ty(&self, ty: &mut Option<String>) {
+        if let Some(ty) = ty {
+            self.ty(ty);
+        }
+    }
+
+    fn params(&self, params: &mut [FunctionParam]) {
+        for param in params {
+            self.token(&mut param.name);
+            self.opt_ty(&mut param.ty);
+        }
+    }
+
+    fn body(&self, body: &mut Rc<Stmt>) {
+        self.stmt(Rc::make_mut(body));
+    }
+
+    fn stmts(&self, stmts: &mut [Stmt]) {
+        for stmt in stmts {
+            self.stmt(stmt);
+        }
+    }
+
+    fn stmt(&self, stmt: &mut Stmt) {
+        match stmt {
+            Stmt::Expression(e) => self.expr(e),
+            Stmt::Let {
+                pattern,
+                ty,
+                initializer,
+                ..
+            } => {
+                self.pattern(pattern);
+                self.opt_ty(ty);
+                self.expr(initializer);
+            }
+            Stmt::Const {
+                name, ty, value, ..
+            } => {
+                self.token(name);
+                self.ty(ty);
+                self.expr(value);
+            }
+            Stmt::Assign { target, value, .. } => {
+                self.expr(target);
+                self.expr(value);
+            }
+            Stmt::Block { statements }
+            | Stmt::Performant { statements }
+            | Stmt::SpawnActor { body: statements } => self.stmts(statements),
+            Stmt::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                self.expr(condition);
+                self.stmt(then_branch);
+                if let Some(e) = else_branch {
+                    self.stmt(e);
+                }
+            }
+            Stmt::IfLet {
+                pattern,
+                value,
+                then_branch,
+                else_branch,
+            } => {
+                self.pattern(pattern);
+                self.expr(value);
+                self.stmt(then_branch);
+                if let Some(e) = else_branch {
+                    self.stmt(e);
+                }
+            }
+            Stmt::TryCatch {
+                try_branch,
+                catch_name,
+                catch_branch,
+            } => {
+                self.stmt(try_branch);
+                self.token(catch_name);
+                self.stmt(catch_branch);
+            }
+            Stmt::StructDecl { name, fields, .. } => {
+                self.token(name);
+                for field in fields {
+                    self.ty(&mut field.ty);
+                    if let Some(default) = &mut field.default {
+                        self.expr(default);
+                    }
+                }
+            }
+            Stmt::EnumDecl { name, variants, .. } => {
+                self.token(name);
+                for (_, payload) in variants {
+                    for ty in payload.iter_mut().flatten() {
+                        self.ty(ty);
+                    }
+                }
+            }
+            Stmt::TypeAlias { name, target } => {
+                self.token(name);
+                self.ty(target);
+            }
+            Stmt::Newtype { name, inner } => {
+                self.token(name);
+                self.ty(inner);
+            }
+            Stmt::Match { expr, cases, .. } => {
+                self.expr(expr);
+                for (pattern, guard, body) in cases {
+                    self.pattern(pattern);
+                    if let Some(guard) = guard {
+                        self.expr(guard);
+                    }
+                    self.stmt(body);
+                }
+            }
+            Stmt::Function {
+                name,
+                type_params,
+                params,
+                return_type,
+                body,
+                method_owner,
+                ..
+            } => {
+                match method_owner {
+                    Some(owner) => self.ty(owner),
+                    None => self.token(name),
+                }
+                for (_, bound) in type_params.iter_mut().flatten() {
+                    self.opt_ty(bound);
+                }
+                self.params(params);
+                self.opt_ty(return_type);
+                self.body(body);
+            }
+            Stmt::While {
+                condition, body, ..
+            } => {
+                self.expr(condition);
+                self.stmt(body);
+            }
+            Stmt::For {
+                element,
+                pattern,
+                iterator,
+                body,
+                ..
+            } => {
+                match pattern {
+                    Some(p) => self.pattern(p),
+                    None => self.token(element),
+                }
+                self.expr(iterator);
+                self.stmt(body);
+            }
+            Stmt::Return { value } => {
+                if let Some(v) = value {
+                    self.expr(v);
+                }
+            }
+            Stmt::Yield { value, .. } => self.expr(value),
+            Stmt::Defer { body, .. } => self.stmt(body),
+            Stmt::ImplBlock {
+                type_name, methods, ..
+            } => {
+                self.ty(type_name);
+                self.stmts(methods);
+            }
+            Stmt::TraitDecl { methods, .. } => {
+                for method in methods {
+                    self.params(&mut method.params);
+                    self.opt_ty(&mut method.return_type);
+                    if let Some(body) = &mut method.body {
+                        self.body(body);
+                    }
+                }
+            }
+            Stmt::ComponentBlock { bindings, view, .. } => {
+                self.stmts(bindings);
+                self.template(view);
+            }
+            Stmt::QualifiedBinding {
+                name,
+                type_ann,
+                value,
+                ..
+            } => {
+                self.token(name);
+                self.opt_ty(type_ann);
+                if let Some(v) = value {
+                    self.expr(v);
+                }
+            }
+            Stmt::Break { .. }
+            | Stmt::Continue { .. }
+            | Stmt::ShellCommand { .. }
+            | Stmt::Import { .. } => {}
+        }
+    }
+
+    fn exprs(&self, exprs: &mut [Expr]) {
+        for e in exprs {
+            self.expr(e);
+        }
+    }
+
+    fn expr(&self, expr: &mut Expr) {
+        match expr {
+            Expr::Binary { left, right, .. }
+            | Expr::Logical { left, right, .. }
+            | Expr::Coalesce { left, right, .. } => {
+                self.expr(left);
+                self.expr(right);
+            }
+            Expr::Unary { right: e, .. }
+            | Expr::Grouping { expression: e }
+            | Expr::FieldAccess { object: e, .. }
+            | Expr::Try(e)
+            | Expr::Weak(e)
+            | Expr::Unowned(e)
+            | Expr::WeakUpgrade(e)
+            | Expr::UnownedAccess(e)
+            | Expr::Await { value: e, .. } => self.expr(e),
+            Expr::Literal(_) => {}
+            Expr::Call {
+                callee,
+                type_args,
+                arguments,
+            } => {
+                self.expr(callee);
+                for ty in type_args.iter_mut().flatten() {
+                    self.ty(ty);
+                }
+                self.exprs(arguments);
+            }
+            Expr::Variable { name } => self.token(name),
+            Expr::StructInit { name, fields, base } => {
+                self.token(name);
+                for (_, value) in fields {
+                    self.expr(value);
+                }
+                if let Some(base) = base {
+                    self.expr(base);
+                }
+            }
+            Expr::EnumInit { name, values, .. } => {
+                if let Some(name) = name {
+                    self.token(name);
+                }
+                self.exprs(values);
+            }
+            Expr::Index { object, index, .. } => {
+                self.expr(object);
+                self.expr(index);
+            }
+            Expr::Slice {
+                object, start, end, ..
+            } => {
+                self.expr(object);
+                for bound in [start, end].into_iter().flatten() {
+                    self.expr(bound);
+                }
+            }
+            Expr::Range { start, end, .. } => {
+                self.expr(start);
+                self.expr(end);
+            }
+            Expr::Array(items) | Expr::Tuple(items) => self.exprs(items),
+            Expr::Cast {
+                object,
+                target_type,
+            } => {
+                self.expr(object);
+                self.ty(target_type);
+            }
+            Expr::InterpolatedString(parts) => {
+                for part in parts {
+                    if let InterpolatedPart::Expr { expr, .. } = part {
+                        self.expr(expr);
+                    }
+                }
+            }
+            Expr::OptionalChain {
+                object, arguments, ..
+            } => {
+                self.expr(object);
+                if let Some(args) = arguments {
+                    self.exprs(args);
+                }
+            }
+            Expr::SpawnActor { body } => self.stmts(body),
+            Expr::Template(nodes) => self.template(nodes),
+            Expr::Lambda {
+                params,
+                return_type,
+                body,
+                ..
+            } => {
+                self.params(params);
+                self.opt_ty(return_type);
+                self.body(body);
+            }
+        }
+    }
+
+    fn pattern(&self, pattern: &mut MatchPattern) {
+        match pattern {
+            MatchPattern::EnumVariant {
+                enum_name, params, ..
+            } => {
+                if let Some(name) = enum_name {
+                    self.token(name);
+                }
+                for p in params.iter_mut().flatten() {
+                    self.pattern(p);
+                }
+            }
+            MatchPattern::Variable(tok) | MatchPattern::Binding(tok) => self.token(tok),
+            MatchPattern::Tuple(items) | MatchPattern::Or(items) => {
+                for p in items {
+                    self.pattern(p);
+                }
+            }
+            MatchPattern::Struct { name, fields } => {
+                self.token(name);
+                for (_, p) in fields {
+                    self.pattern(p);
+                }
+            }
+            MatchPattern::Array {
+                prefix,
+                rest,
+                suffix,
+            } => {
+                for p in prefix.iter_mut().chain(suffix.iter_mut()) {
+                    self.pattern(p);
+                }
+                if let Some(Some(tok)) = rest {
+                    self.token(tok);
+                }
+            }
+            MatchPattern::At { name, pattern } => {
+                self.token(name);
+                self.pattern(pattern);
+            }
+            MatchPattern::Literal(_) | MatchPattern::Wildcard | MatchPattern::Range { .. } => {}
+        }
+    }
+
+    fn template(&self, nodes: &mut [TemplateNode]) {
+        for node in nodes {
+            match node {
+                TemplateNode::Element {
+                    attrs, children, ..
+                }
+                | TemplateNode::Component {
+                    attrs, children, ..
+                } => {
+                    for attr in attrs {
+                        if let TemplateAttrValue::Dynamic(e) | TemplateAttrValue::EventHandler(e) =
+                            &mut attr.value
+                        {
+                            self.expr(e);
+                        }
+                    }
+                    self.template(children);
+                }
+                TemplateNode::Expr(e) => self.expr(e),
+                TemplateNode::If {
+                    cond,
+                    then_children,
+                    else_children,
+                } => {
+                    self.expr(cond);
+                    self.template(then_children);
+                    self.template(else_children);
+                }
+                TemplateNode::For {
+                    items,
+                    key,
+                    children,
+                    ..
+                } => {
+                    self.expr(items);
+                    if let Some(key) = key {
+                        self.expr(key);
+                    }
+                    self.template(children);
+                }
+                TemplateNode::Slot { children, .. } => self.template(children),
+                TemplateNode::Text(_) => {}
+            }
+        }
+    }
+}
@@ -8,6 +8,9 @@ use lexer::lexer::Lexer;
 use parser::parser::Parser;
 use rayon::prelude::*;
 
+use crate::modules::{self, ModuleItems};
+use crate::namespaces::{self, ParsedModule};
+
 /// Directory holding the local package cache (`<home>/.artcode/cache`).
 ///
 /// Resolution order is `ARTCODE_HOME`, then `HOME`, then the platform home
@@ -188,9 +191,10 @@ fn normalize_module_path(path: &Path) -> Result<(PathBuf, PathBuf), String> {
     Ok((actual_path, file_key))
 }
 
-fn parse_program(
-    path: &Path,
-) -> Result<(String, core::Program), Vec<(String, diagnostics::Diagnostic)>> {
+/// Source, program and `alias.item` uses (see [`Parser::qualified_uses`]) of `path`.
+type ParsedFile = (String, core::Program, Vec<(String, core::Token)>);
+
+fn parse_program(path: &Path) -> Result<ParsedFile, Vec<(String, diagnostics::Diagnostic)>> {
     let source = match fs::read_to_string(path) {
         Ok(s) => s,
         Err(e) => {
@@ -205,19 +209,39 @@ fn parse_program(
         }
     };
 
-    let mut lexer = Lexer::new(source.clone());
-    let tokens = match lexer.scan_tokens() {
-        Ok(t) => t,
-        Err(diag) => return Err(vec![(source.clone(), diag)]),
-    };
-
+    let tokens = lex_source(&source)?;
     let mut parser = Parser::new(tokens);
     let (program, diags) = parser.parse();
     if !diags.is_empty() {
         return Err(diags.into_iter().map(|d| (source.clone(), d)).collect());
     }
+    let qualified = parser.qualified_uses().to_vec();
 
-    Ok((source, program))
+    Ok((source, program, qualified))
+}
+
+fn lex_source(source: &str) -> Result<Vec<core::Token>, Vec<(String, diagnostics::Diagnostic)>> {
+    let mut lexer = Lexer::new(source.to_string());
+    lexer
+        .scan_tokens()
+        .map_err(|diag| vec![(source.to_string(), diag)])
+}
+
+/// Checks a module's imports against the items of its dependencies.
+fn check_module_imports(
+    source: &str,
+    program: &core::Program,
+    deps: &[PathBuf],
+    exports: &HashMap<PathBuf, ModuleItems>,
+) -> Result<(), Vec<(String, diagnostics::Diagnostic)>> {
+    let imported: Vec<Option<&ModuleItems>> = deps.iter().map(|d| exports.get(d)).collect();
+    let tokens = lex_source(source)?;
+    let diags = modules::check_imports(&tokens, program, &imported);
+    if diags.is_empty() {
+        Ok(())
+    } else {
+        Err(diags.into_iter().map(|d| (source.to_string(), d)).collect())
+    }
 }
 
 fn collect_module_graph(path: PathBuf, ctx: &Arc<ResolveContext>) {
@@ -247,7 +271,7 @@ fn collect_module_graph(path: PathBuf, ctx: &Arc<ResolveContext>) {
         visited.insert(key.clone());
     }
 
-    let (source, program) = match parse_program(&actual_path) {
+    let (source, program, _) = match parse_program(&actual_path) {
         Ok(v) => v,
         Err(diags) => {
             if let Ok(mut errors) = ctx.errors.lock() {
@@ -261,7 +285,7 @@ fn collect_module_graph(path: PathBuf, ctx: &Arc<ResolveContext>) {
     let mut imports: Vec<PathBuf> = Vec::new();
 
     for stmt in &program {
-        if let core::Stmt::Import { path } = stmt {
+        if let core::Stmt::Import { path, .. } = stmt {
             let parts: Vec<String> = path.iter().map(|t| t.lexeme.clone()).collect();
             let rel = parts.join("/");
 
@@ -298,29 +322,33 @@ fn collect_module_graph(path: PathBuf, ctx: &Arc<ResolveContext>) {
         .for_each(|dep| collect_module_graph(dep, ctx));
 }
 
+/// Parses `key` and its dependencies, dependencies first, checking each
+/// module's imports on the way.
 fn emit_module(
     key: &PathBuf,
     deps: &HashMap<PathBuf, Vec<PathBuf>>,
     emitted: &mut HashSet<PathBuf>,
-    out: &mut core::Program,
+    exports: &mut HashMap<PathBuf, ModuleItems>,
+    out: &mut Vec<ParsedModule>,
 ) -> Result<(), Vec<(String, diagnostics::Diagnostic)>> {
     if emitted.contains(key) {
         return Ok(());
     }
 
-    if let Some(children) = deps.get(key) {
-        for dep in children {
-            emit_module(dep, deps, emitted, out)?;
-        }
+    let children = deps.get(key).map(Vec::as_slice).unwrap_or_default();
+    for dep in children {
+        emit_module(dep, deps, emitted, exports, out)?;
     }
 
-    let (_source, program) = parse_program(key)?;
-    for stmt in program {
-        if let core::Stmt::Import { .. } = stmt {
-            continue;
-        }
-        out.push(stmt);
-    }
+    let (source, program, qualified) = parse_program(key)?;
+    check_module_imports(&source, &program, children, exports)?;
+    exports.insert(key.clone(), ModuleItems::collect(&program));
+    out.push(ParsedModule {
+        key: key.clone(),
+        program,
+        deps: children.to_vec(),
+        qualified,
+    });
     emitted.insert(key.clone());
     Ok(())
 }
@@ -393,9 +421,23 @@ pub fn resolve(
         }
     };
 
-    let mut out_program: core::Program = Vec::new();
+    let mut modules: Vec<ParsedModule> = Vec::new();
     let mut emitted: HashSet<PathBuf> = HashSet::new();
-    emit_module(&entry_key, &deps_map, &mut emitted, &mut out_program)?;
+    let mut exports: HashMap<PathBuf, ModuleItems> = HashMap::new();
+    emit_module(
+        &entry_key,
+        &deps_map,
+        &mut emitted,
+        &mut exports,
+        &mut modules,
+    )?;
+    namespaces::separate(&mut modules);
+
+    let out_program: core::Program = modules
+        .into_iter()
+        .flat_map(|m| m.program)
+        .filter(|stmt| !matches!(stmt, core::Stmt::Import { .. }))
+        .collect();
 
     Ok((out_program, main_source))
 }
@@ -180,17 +180,17 @@ fn std_doc_meta(name: &str) -> Option<StdDocMeta> {
         }),
         "map_set" => Some(StdDocMeta {
             category: "Collections",
-            signature: "map_set(map: Map, key: String, value: Any)",
+            signature: "map_set(map: Map, key: Hash, value: Any)",
             description: "Define chave no mapa.",
         }),
         "map_get" => Some(StdDocMeta {
             category: "Collections",
-            signature: "map_get(map: Map, key: String)",
+            signature: "map_get(map: Map, key: Hash)",
             description: "Busca valor por chave e retorna Optional.",
         }),
         "map_has" => Some(StdDocMeta {
             category: "Collections",
-            signature: "map_has(map: Map, key: String)",
+            signature: "map_has(map: Map, key: Hash)",
             description: "Verifica existencia de chave no mapa.",
         }),
         "set_new" => Some(StdDocMeta {
@@ -200,14 +200,104 @@ fn std_doc_meta(name: &str) -> Option<StdDocMeta> {
         }),
         "set_add" => Some(StdDocMeta {
             category: "Collections",
-            signature: "set_add(set: Set, value: Any)",
+            signature: "set_add(set: Set, value: Hash)",
             description: "Insere valor no conjunto.",
         }),
         "set_has" => Some(StdDocMeta {
             category: "Collections",
-            signature: "set_has(set: Set, value: Any)",
+            signature: "set_has(set: Set, value: Hash)",
             description: "Verifica existencia de valor no conjunto.",
         }),
+        "map_remove" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "map_remove(map: Map, key: Hash)",
+            description: "Remove chave do mapa e retorna o valor antigo como Optional.",
+        }),
+        "map_keys" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "map_keys(map: Map)",
+            description: "Array com as chaves do mapa, em ordem de chave.",
+        }),
+        "map_values" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "map_values(map: Map)",
+            description: "Array com os valores do mapa, em ordem de chave.",
+        }),
+        "map_entries" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "map_entries(map: Map)",
+            description: "Array de tuplas `(chave, valor)`, em ordem de chave.",
+        }),
+        "map_merge" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "map_merge(a: Map, b: Map)",
+            description: "Mapa novo com as entradas de `a` e `b`; em chave repetida vale `b`.",
+        }),
+        "set_remove" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "set_remove(set: Set, value: Hash)",
+            description: "Remove valor do conjunto; retorna se ele existia.",
+        }),
+        "set_union" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "set_union(a: Set, b: Set)",
+            description: "Conjunto novo com os elementos de `a` e de `b`.",
+        }),
+        "set_intersection" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "set_intersection(a: Set, b: Set)",
+            description: "Conjunto novo com os elementos presentes em `a` e em `b`.",
+        }),
+        "set_difference" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "set_difference(a: Set, b: Set)",
+            description: "Conjunto novo com os elementos de `a` ausentes em `b`.",
+        }),
+        "set_to_array" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "set_to_array(set: Set)",
+            description: "Array com os elementos do conjunto, em ordem de insercao.",
+        }),
+        "deque_new" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "deque_new()",
+            description: "Cria fila dupla vazia.",
+        }),
+        "deque_push_front" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "deque_push_front(deque: Deque, value: Any)",
+            description: "Insere valor no inicio da fila.",
+        }),
+        "deque_push_back" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "deque_push_back(deque: Deque, value: Any)",
+            description: "Insere valor no fim da fila.",
+        }),
+        "deque_pop_front" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "deque_pop_front(deque: Deque)",
+            description: "Remove do inicio e retorna Optional.",
+        }),
+        "deque_pop_back" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "deque_pop_back(deque: Deque)",
+            description: "Remove do fim e retorna Optional.",
+        }),
+        "deque_len" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "deque_len(deque: Deque)",
+            description: "Numero de elementos da fila.",
+        }),
+        "deque_get" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "deque_get(deque: Deque, index: Int)",
+            description: "Elemento na posicao (negativo conta do fim) como Optional.",
+        }),
+        "deque_to_array" => Some(StdDocMeta {
+            category: "Collections",
+            signature: "deque_to_array(deque: Deque)",
+            description: "Array com os elementos da fila, do inicio ao fim.",
+        }),
         "math_abs" => Some(StdDocMeta {
             category: "Math",
             signature: "math_abs(value: Int|Float)",
@@ -218,6 +308,51 @@ fn std_doc_meta(name: &str) -> Option<StdDocMeta> {
             signature: "math_pow(base: Number, exp: Number)",
             description: "Potenciacao numerica.",
         }),
+        "wrapping_add" => Some(StdDocMeta {
+            category: "Math",
+            signature: "wrapping_add(a: Int, b: Int)",
+            description: "`a + b` com volta em complemento de dois (nunca estoura).",
+        }),
+        "wrapping_sub" => Some(StdDocMeta {
+            category: "Math",
+            signature: "wrapping_sub(a: Int, b: Int)",
+            description: "`a - b` com volta em complemento de dois (nunca estoura).",
+        }),
+        "wrapping_mul" => Some(StdDocMeta {
+            category: "Math",
+            signature: "wrapping_mul(a: Int, b: Int)",
+            description: "`a * b` com volta em complemento de dois (nunca estoura).",
+        }),
+        "checked_add" => Some(StdDocMeta {
+            category: "Math",
+            signature: "checked_add(a: Int, b: Int)",
+            description: "`a + b` como Option.Some, ou Option.None se estourar Int.",
+        }),
+        "checked_sub" => Some(StdDocMeta {
+            category: "Math",
+            signature: "checked_sub(a: Int, b: Int)",
+            description: "`a - b` como Option.Some, ou Option.None se estourar Int.",
+        }),
+        "checked_mul" => Some(StdDocMeta {
+            category: "Math",
+            signature: "checked_mul(a: Int, b: Int)",
+            description: "`a * b` como Option.Some, ou Option.None se estourar Int.",
+        }),
+        "saturating_add" => Some(StdDocMeta {
+            category: "Math",
+            signature: "saturating_add(a: Int, b: Int)",
+            description: "`a + b` limitado ao menor/maior Int em vez de estourar.",
+        }),
+        "saturating_sub" => Some(StdDocMeta {
+            category: "Math",
+            signature: "saturating_sub(a: Int, b: Int)",
+            description: "`a - b` limitado ao menor/maior Int em vez de estourar.",
+        }),
+        "saturating_mul" => Some(StdDocMeta {
+            category: "Math",
+            signature: "saturating_mul(a: Int, b: Int)",
+            description: "`a * b` limitado ao menor/maior Int em vez de estourar.",
+        }),
         "math_clamp" => Some(StdDocMeta {
             category: "Math",
             signature: "math_clamp(value: Number, min: Number, max: Number)",
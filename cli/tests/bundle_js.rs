@@ -106,3 +106,129 @@ fn bundle_no_duplicate_modules() {
         "module util was inlined {count} times, expected 1"
     );
 }
+
+#[test]
+fn bundle_checks_visibility_and_resolves_aliases() {
+    let work = TempDir::new().expect("tempdir");
+    let out_dir = work.path().join("dist");
+
+    std::fs::write(
+        work.path().join("util.art"),
+        "pub func shout(s) { return bang(s); }\nfunc bang(s) { return s + \"!\"; }",
+    )
+    .expect("write util");
+    std::fs::write(
+        work.path().join("main.art"),
+        "import util as u;\nprintln(u.shout(\"hi\"));",
+    )
+    .expect("write main");
+    std::fs::write(
+        work.path().join("bad.art"),
+        "import util.{bang};\nprintln(bang(\"hi\"));",
+    )
+    .expect("write bad");
+
+    let build = |entry: &str| {
+        let mut cmd = Command::cargo_bin("art").expect("binary");
+        cmd.args([
+            "build",
+            work.path().join(entry).to_str().unwrap(),
+            "--target",
+            "js",
+            "--bundle",
+            "--out",
+            out_dir.to_str().unwrap(),
+        ]);
+        cmd.assert()
+    };
+
+    build("main.art").success();
+    let js = std::fs::read_to_string(out_dir.join("main.js")).expect("read output");
+    assert!(js.contains("println(shout(\"hi\"))"), "{}", js);
+    assert!(!js.contains("export"), "bundles have no exports: {}", js);
+
+    build("bad.art").failure().stderr(predicates::str::contains(
+        "'bang' is private to module 'util'",
+    ));
+}
+
+#[test]
+fn bundle_keeps_same_named_items_of_aliased_modules_apart() {
+    let work = TempDir::new().expect("tempdir");
+    let out_dir = work.path().join("dist");
+
+    std::fs::write(
+        work.path().join("a.art"),
+        "pub func get() { return \"from a\"; }",
+    )
+    .expect("write a");
+    std::fs::write(
+        work.path().join("b.art"),
+        "pub func get() { return \"from b\"; }",
+    )
+    .expect("write b");
+    std::fs::write(
+        work.path().join("main.art"),
+        "import a as x;\nimport b as y;\nprintln(x.get());\nprintln(y.get());",
+    )
+    .expect("write main");
+
+    let mut cmd = Command::cargo_bin("art").expect("binary");
+    cmd.args([
+        "build",
+        work.path().join("main.art").to_str().unwrap(),
+        "--target",
+        "js",
+        "--bundle",
+        "--out",
+        out_dir.to_str().unwrap(),
+    ]);
+    cmd.assert().success();
+
+    let js = std::fs::read_to_string(out_dir.join("main.js")).expect("read output");
+    assert!(js.contains("function a$get"), "{}", js);
+    assert!(js.contains("function b$get"), "{}", js);
+    assert!(js.contains("println(a$get())"), "{}", js);
+    assert!(js.contains("println(b$get())"), "{}", js);
+}
+
+#[test]
+fn bundle_runtime_defines_collection_builtins() {
+    let work = TempDir::new().expect("tempdir");
+    let out_dir = work.path().join("dist");
+    let script = work.path().join("sets.art");
+
+    std::fs::write(
+        &script,
+        "let s = set_new();\nset_add(s, 1);\nprintln(set_to_array(s));",
+    )
+    .expect("write script");
+
+    let mut cmd = Command::cargo_bin("art").expect("binary");
+    cmd.args([
+        "build",
+        script.to_str().unwrap(),
+        "--target",
+        "js",
+        "--bundle",
+        "--out",
+        out_dir.to_str().unwrap(),
+    ]);
+    cmd.assert().success();
+
+    let js = std::fs::read_to_string(out_dir.join("sets.js")).expect("read output");
+    for name in [
+        "map_remove",
+        "map_merge",
+        "set_union",
+        "set_difference",
+        "set_to_array",
+        "deque_get",
+        "deque_to_array",
+    ] {
+        assert!(
+            js.contains(&format!("const {name}")),
+            "runtime should define {name}"
+        );
+    }
+}
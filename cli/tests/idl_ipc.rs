@@ -29,7 +29,7 @@ fn run_idl_ipc_detects_invalid_payload_type() {
     let mut tmp = tempfile::NamedTempFile::new().expect("create tmp file");
     write!(
         tmp,
-        "struct BootMsg {{ service: String, retries: Int }}\nlet bad = BootMsg {{ service: \"nexus\", retries: \"oops\" }}\nlet ok = idl_validate(bad, \"BootMsg\")\nprintln(f\"ok={{ok}}\")\n"
+        "struct BootMsg {{ service: String, retries: Int }}\nfunc boot(retries) {{ let m = BootMsg {{ service: \"nexus\", retries: retries }}\n    return m\n}}\nlet bad = boot(\"oops\")\nlet ok = idl_validate(bad, \"BootMsg\")\nprintln(f\"ok={{ok}}\")\n"
     )
     .expect("write script");
 
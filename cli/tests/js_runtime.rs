@@ -0,0 +1,138 @@
+//! Runs bundled JS output under Node and compares it with the interpreter's
+//! semantics. Skips when `node` is not installed.
+
+use assert_cmd::Command;
+use tempfile::TempDir;
+
+fn node_available() -> bool {
+    std::process::Command::new("node")
+        .arg("--version")
+        .output()
+        .is_ok()
+}
+
+/// Bundles `src` with `art build --target js --bundle` and runs it with Node.
+/// Returns `(stdout, stderr)`, or `None` when Node is unavailable.
+fn run_js(args: &[&str], src: &str) -> Option<(String, String)> {
+    if !node_available() {
+        eprintln!("skipping: node not available");
+        return None;
+    }
+    let work = TempDir::new().expect("tempdir");
+    let out_dir = work.path().join("dist");
+    let script = work.path().join("main.art");
+    std::fs::write(&script, src).expect("write script");
+
+    let mut cmd = Command::cargo_bin("art").expect("binary");
+    cmd.args([
+        "build",
+        script.to_str().unwrap(),
+        "--target",
+        "js",
+        "--bundle",
+    ])
+    .args(args)
+    .args(["--out", out_dir.to_str().unwrap()]);
+    cmd.assert().success();
+
+    let output = std::process::Command::new("node")
+        .arg(out_dir.join("main.js"))
+        .output()
+        .expect("run node");
+    Some((
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    ))
+}
+
+#[test]
+fn int_overflows_at_i64_max() {
+    let src = "let max = 9223372036854775807;\nprintln(max - 1 + 1);\nprintln(max + 1);\n";
+    if let Some((stdout, stderr)) = run_js(&[], src) {
+        assert_eq!(stdout, "9223372036854775807\n", "stderr={}", stderr);
+        assert!(
+            stderr.contains("Integer overflow: 9223372036854775807 + 1 does not fit in Int."),
+            "stderr={}",
+            stderr
+        );
+    }
+    if let Some((stdout, stderr)) = run_js(&["--overflow=wrap"], src) {
+        assert_eq!(
+            stdout, "9223372036854775807\n-9223372036854775808\n",
+            "stderr={}",
+            stderr
+        );
+    }
+}
+
+#[test]
+fn int_stays_exact_past_2_pow_53() {
+    let src = "println(9007199254740993 + 2);\nprintln(-7 / 2);\nprintln(-7 % 3);\n";
+    if let Some((stdout, stderr)) = run_js(&[], src) {
+        assert_eq!(stdout, "9007199254740995\n-3\n-1\n", "stderr={}", stderr);
+    }
+}
+
+#[test]
+fn floats_through_untyped_params_stay_floats() {
+    let src = "func add(a, b) {\n    return a + b;\n}\nfunc div(a, b) {\n    return a / b;\n}\nprintln(add(1e20, 1.0));\nprintln(div(7.0, 2.0));\nprintln(add(1, 2));\n";
+    if let Some((stdout, stderr)) = run_js(&[], src) {
+        assert_eq!(
+            stdout, "100000000000000000000\n3.5\n3\n",
+            "stderr={}",
+            stderr
+        );
+    }
+}
+
+#[test]
+fn float_division_and_remainder_are_native() {
+    let src = "func halff(a: Float, b: Float) -> Float {\n    return a / b;\n}\nfunc remf(a: Float, b: Float) -> Float {\n    return a % b;\n}\nprintln(halff(7.0, 2.0));\nprintln(remf(7.5, 2.0));\nprintln(remf(1.0, 0.0));\nprintln(halff(1.0, 0.0));\nprintln(7 / 2);\n";
+    if let Some((stdout, stderr)) = run_js(&[], src) {
+        assert_eq!(stdout, "3.5\n1.5\nNaN\nInfinity\n3\n", "stderr={}", stderr);
+    }
+}
+
+#[test]
+fn index_compound_assignment_evaluates_the_index_once() {
+    let src = "let calls = [0];\nfunc bump() {\n    calls[0] += 1;\n    return 1;\n}\nlet a = [10, 20];\na[bump()] += 5;\nprintln(a[1]);\nprintln(calls[0]);\n";
+    if let Some((stdout, stderr)) = run_js(&[], src) {
+        assert_eq!(stdout, "25\n1\n", "stderr={}", stderr);
+    }
+}
+
+#[test]
+fn compound_assignment_uses_operator_overloads() {
+    let src = "struct V { x: Int }\nimpl V {\n    func add(self, o: V) -> V {\n        let v = V { x: self.x + o.x };\n        return v;\n    }\n}\nvar d = V { x: 1 };\nlet b = V { x: 2 };\nd += b;\nprintln(d.x);\n";
+    if let Some((stdout, stderr)) = run_js(&[], src) {
+        assert_eq!(stdout, "3\n", "stderr={}", stderr);
+    }
+}
+
+#[test]
+fn optional_chain_calls_builtin_methods() {
+    let src = "let s = Option.Some(\"abc\");\nlet n = Option.None;\nprintln(s?.len() ?? 0);\nprintln(n?.len() ?? -1);\n";
+    if let Some((stdout, stderr)) = run_js(&[], src) {
+        assert_eq!(stdout, "3\n-1\n", "stderr={}", stderr);
+    }
+}
+
+#[test]
+fn maps_iterate_in_key_order_and_key_composites_by_value() {
+    let src = "let m = map_new();\nfor i in 0..8 {\n    map_set(m, f\"k{7 - i}\", i);\n}\nprintln(m.keys().join(\",\"));\nlet n = map_new();\nn[10] = \"a\";\nn[-3] = \"b\";\nn[2] = \"c\";\nfor (k, v) in n {\n    println(f\"{k}={v}\");\n}\nlet t = map_new();\nt[(2, 1)] = \"x\";\nt[(1, 5)] = \"y\";\nprintln(t[(1, 5)]);\nfor v in map_values(t) {\n    println(v);\n}\nlet s = set_new();\nset_add(s, (1, 2));\nset_add(s, (1, 2));\nprintln(len(s));\n";
+    let expected = "k0,k1,k2,k3,k4,k5,k6,k7\n-3=b\n2=c\n10=a\ny\ny\nx\n1\n";
+
+    let work = TempDir::new().expect("tempdir");
+    let script = work.path().join("main.art");
+    std::fs::write(&script, src).expect("write script");
+    let out = Command::cargo_bin("art")
+        .expect("binary")
+        .args(["run", script.to_str().unwrap()])
+        .output()
+        .expect("run art");
+    assert_eq!(String::from_utf8_lossy(&out.stdout), expected);
+
+    if let Some((stdout, stderr)) = run_js(&[], src) {
+        assert_eq!(stdout, expected, "stderr={}", stderr);
+    }
+}
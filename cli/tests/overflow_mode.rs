@@ -0,0 +1,44 @@
+use assert_cmd::Command;
+use tempfile::TempDir;
+
+fn run_art(args: &[&str], src: &str) -> std::process::Output {
+    let work = TempDir::new().expect("workdir");
+    let script = work.path().join("overflow.art");
+    std::fs::write(&script, src).expect("write script");
+
+    let mut cmd = Command::cargo_bin("art").expect("binary");
+    cmd.arg("run").args(args).arg(script.to_str().unwrap());
+    cmd.output().expect("run art")
+}
+
+const SRC: &str = "println(9223372036854775807 + 1);\n";
+
+#[test]
+fn run_traps_on_overflow_by_default() {
+    let out = run_art(&[], SRC);
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("Integer overflow: 9223372036854775807 + 1 does not fit in Int."),
+        "stderr={}",
+        stderr
+    );
+}
+
+#[test]
+fn run_overflow_wrap_flag_wraps() {
+    let out = run_art(&["--overflow=wrap"], SRC);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.contains("-9223372036854775808"),
+        "stdout={} stderr={}",
+        stdout,
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+#[test]
+fn run_rejects_unknown_overflow_mode() {
+    let out = run_art(&["--overflow=saturate"], SRC);
+    assert_eq!(out.status.code(), Some(64));
+    assert!(String::from_utf8_lossy(&out.stderr).contains("invalid --overflow=saturate"));
+}
@@ -20,3 +20,135 @@ fn resolver_handles_simple_import() {
         "art exited non-zero for resolver test"
     );
 }
+
+fn run_art(dir: &std::path::Path, files: &[(&str, &str)]) -> std::process::Output {
+    for (name, src) in files {
+        let path = dir.join(name);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).expect("mkdir");
+        }
+        std::fs::write(path, src).expect("write module");
+    }
+    let mut cmd = Command::cargo_bin("art").expect("binary present");
+    cmd.arg("run").arg(dir.join("main.art").to_str().unwrap());
+    cmd.output().expect("run art")
+}
+
+const UTIL: &str = "pub func parse(s) { return helper(s); }\nfunc helper(s) { return s + \"!\"; }\npub const LIMIT: Int = 3;";
+
+#[test]
+fn resolver_supports_selective_and_aliased_imports() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let out = run_art(
+        dir.path(),
+        &[
+            ("util.art", UTIL),
+            (
+                "net/http.art",
+                "pub func get(url) { return \"GET \" + url; }",
+            ),
+            (
+                "main.art",
+                "import util.{parse, LIMIT};\nimport net.http as h;\nprintln(parse(\"a\"));\nprintln(h.get(\"/x\"));\nprintln(LIMIT);",
+            ),
+        ],
+    );
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        out.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert_eq!(
+        stdout.lines().collect::<Vec<_>>(),
+        vec!["a!", "GET /x", "3"]
+    );
+}
+
+#[test]
+fn resolver_rejects_private_and_missing_items() {
+    let cases = [
+        (
+            "import util.{helper};",
+            "'helper' is private to module 'util'",
+        ),
+        ("import util.{nope};", "Module 'util' has no item 'nope'"),
+        (
+            "import util as u;\nprintln(u.helper(\"x\"));",
+            "'helper' is private to module 'util'",
+        ),
+        (
+            "import util;\nprintln(helper(\"x\"));",
+            "'helper' is private to module 'util'",
+        ),
+        (
+            "import util as u;\nprintln(u.nope(\"x\"));",
+            "Module 'util' has no item 'nope'",
+        ),
+    ];
+    for (main, expected) in cases {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let out = run_art(dir.path(), &[("util.art", UTIL), ("main.art", main)]);
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert!(stderr.contains(expected), "{}: {}", main, stderr);
+        assert!(out.stdout.is_empty(), "{} should not run", main);
+    }
+}
+
+#[test]
+fn resolver_lets_a_module_shadow_a_private_name() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let out = run_art(
+        dir.path(),
+        &[
+            ("util.art", UTIL),
+            (
+                "main.art",
+                "import util;\nfunc helper(s) { return s; }\nprintln(parse(helper(\"b\")));",
+            ),
+        ],
+    );
+    assert!(
+        out.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+}
+
+#[test]
+fn aliases_keep_same_named_items_apart() {
+    let module = |name: &str| {
+        format!(
+            "pub struct Item {{ v: Int }}\npub func get() {{ return tag(); }}\n\
+             pub func make() -> Item {{ let i = Item {{ v: {} }}; return i; }}\n\
+             func tag() {{ return \"from {}\"; }}",
+            name.len(),
+            name
+        )
+    };
+    let dir = tempfile::tempdir().expect("tempdir");
+    let out = run_art(
+        dir.path(),
+        &[
+            ("a.art", &module("a")),
+            ("bb.art", &module("bb")),
+            (
+                "main.art",
+                "import a as x;\nimport bb as y;\nprintln(x.get());\nprintln(y.get());\n\
+                 let i: x.Item = x.make();\nlet j = y.Item { v: 7 };\nprintln(i.v + y.make().v + j.v);\n\
+                 func tag() { return \"main\"; }\nprintln(tag());",
+            ),
+        ],
+    );
+    assert!(
+        out.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .collect::<Vec<_>>(),
+        vec!["from a", "from bb", "10", "main"]
+    );
+}
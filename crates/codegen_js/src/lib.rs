@@ -1,14 +1,23 @@
 mod sourcemap;
 pub use sourcemap::SourceMapBuilder;
 
+use core::OverflowMode;
+use core::TokenType;
 use core::ast::{
-    ArtValue, Expr, InterpolatedPart, MatchPattern, Stmt, TemplateAttrValue, TemplateNode,
+    ArtValue, Expr, InterpolatedPart, MatchPattern, Stmt, StructField, TemplateAttrValue,
+    TemplateNode,
 };
+use core::const_eval::{ConstEnv, fold_consts};
+use core::methods::{self, BUILTIN_METHODS};
+use core::operators::{NEG_METHOD, OPERATOR_METHODS, TO_STRING_METHOD, binary_method};
+use core::types::TypeDecls;
 
 pub struct CodegenOptions {
     pub source_file: Option<String>,
     pub emit_source_map: bool,
     pub module_format: ModuleFormat,
+    /// Politica de overflow de `Int` aplicada por `__art_arith`.
+    pub overflow: OverflowMode,
 }
 
 #[derive(Default, PartialEq)]
@@ -26,6 +35,7 @@ impl Default for CodegenOptions {
             source_file: None,
             emit_source_map: false,
             module_format: ModuleFormat::Esm,
+            overflow: OverflowMode::Trap,
         }
     }
 }
@@ -48,8 +58,330 @@ pub struct CodegenJs {
     reactive_txt_nodes: Vec<(String, String)>,
     /// counter for unique text node variable names
     txt_node_counter: usize,
+    /// `state` binding names of the component being emitted; assignments to
+    /// them are routed through the generated `set_X` setter
+    state_names: std::collections::HashSet<String>,
+    /// runtime helpers referenced by the emitted code (appended once at the end)
+    used_helpers: std::collections::BTreeSet<&'static str>,
+    /// counter for the temporaries holding a destructured `let` value
+    destructure_counter: usize,
+    /// top-level `const` values; scalar ones are inlined where they are read
+    consts: ConstEnv,
+    /// `newtype` names; wrapping one is the identity, so calls emit the argument
+    newtypes: std::collections::HashSet<String>,
+    /// operator methods some `impl` (or trait default) defines; only those
+    /// operators pay for the dispatch through `__art_binop`/`__art_neg`/`__art_str`
+    overloads: std::collections::HashSet<String>,
+    /// declared structs, by name: constructors take the fields in declaration
+    /// order, so initializers are reordered and completed with defaults
+    structs: std::collections::HashMap<String, StructDecl>,
+    /// `type` and `newtype` declarations, seen through in numeric annotations
+    types: TypeDecls,
+    /// numeric kind of the bindings in scope, from literals and `Int`/`Float`
+    /// annotations; arithmetic on a known `Float` uses the native operator
+    num_kinds: std::collections::HashMap<String, NumKind>,
+    /// numeric kind returned by each top-level function, from `-> Int`/`-> Float`
+    fn_kinds: std::collections::HashMap<String, NumKind>,
 }
 
+/// How a number is represented in the emitted JS: `Int` is a `BigInt`, `Float`
+/// a JS number.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum NumKind {
+    Int,
+    Float,
+}
+
+/// Fields of a `struct` declaration, and whether it is a tuple struct.
+#[derive(Clone)]
+struct StructDecl {
+    fields: Vec<StructField>,
+    tuple: bool,
+}
+
+/// `a[i]` with negative indexes; strings index by code point, `Map` by key.
+/// Buffer bytes are read back as `Int` (`BigInt`).
+const HELPER_INDEX: &str = r#"function __art_index(o, i) {
+    if (o instanceof Map) {
+        if (!o.has(i)) throw new RangeError(`Key '${i}' not found in map.`);
+        return o.get(i);
+    }
+    const items = typeof o === "string" ? [...o] : o;
+    const n = i < 0 ? items.length + Number(i) : Number(i);
+    if (n < 0 || n >= items.length) throw new RangeError(`Index ${i} out of range for length ${items.length}.`);
+    return o instanceof Uint8Array ? BigInt(items[n]) : items[n];
+}"#;
+
+/// `a[i] = v`; returns `v`.
+const HELPER_SET_INDEX: &str = r#"function __art_set_index(o, i, v) {
+    if (o instanceof Map) {
+        o.set(i, v);
+        return v;
+    }
+    const n = i < 0 ? o.length + Number(i) : Number(i);
+    if (n < 0 || n >= o.length) throw new RangeError(`Index ${i} out of range for length ${o.length}.`);
+    o[n] = o instanceof Uint8Array ? Number(v) : v;
+    return v;
+}"#;
+
+/// `a[s..e]` with optional, possibly negative bounds.
+const HELPER_SLICE: &str = r#"function __art_slice(o, s, e) {
+    const items = typeof o === "string" ? [...o] : o;
+    const len = items.length;
+    const from = s === undefined ? 0 : s < 0 ? len + Number(s) : Number(s);
+    const to = e === undefined ? len : e < 0 ? len + Number(e) : Number(e);
+    if (from < 0 || to > len || from > to) throw new RangeError(`Slice ${from}..${to} out of range for length ${len}.`);
+    const out = items.slice(from, to);
+    return typeof o === "string" ? out.join("") : out;
+}"#;
+
+/// `s..e` / `s..=e`: a lazy iterable that can be traversed more than once.
+const HELPER_RANGE: &str = r#"function __art_range(s, e, inclusive) {
+    const end = inclusive ? BigInt(e) + 1n : BigInt(e);
+    return { *[Symbol.iterator]() { for (let i = BigInt(s); i < end; i++) yield i; } };
+}"#;
+
+/// Bitwise operators on Art's 64-bit `Int`, wrapped back to `i64` after the
+/// shift. Being a call, it also avoids JS's `&` binding looser than `==`.
+const HELPER_BITS: &str = r#"function __art_bits(op, a, b) {
+    const x = BigInt(a);
+    const y = b === undefined ? 0n : BigInt(b);
+    if ((op === "<<" || op === ">>") && (y < 0n || y > 63n)) throw new RangeError(`Shift amount ${b} out of range 0..=63.`);
+    let r;
+    switch (op) {
+        case "&": r = x & y; break;
+        case "|": r = x | y; break;
+        case "^": r = x ^ y; break;
+        case "<<": r = x << y; break;
+        case ">>": r = x >> y; break;
+        default: r = ~x;
+    }
+    return BigInt.asIntN(64, r);
+}"#;
+
+/// `+`, `-` and `*` under Art's `Int` overflow policy. `Int` is a `BigInt`, so
+/// the exact result is checked against `i64`; a `Float` (a JS number) mixed with
+/// an `Int` promotes it, as in the interpreter. Strings use the native operator.
+const HELPER_ARITH_TRAP: &str = r#"function __art_arith(op, a, b) {
+    if (typeof a === "bigint" && typeof b === "bigint") {
+        const r = op === "+" ? a + b : op === "-" ? a - b : a * b;
+        if (r !== BigInt.asIntN(64, r)) throw new RangeError(`Integer overflow: ${a} ${op} ${b} does not fit in Int.`);
+        return r;
+    }
+    if (typeof a === "bigint" && typeof b === "number") a = Number(a);
+    if (typeof b === "bigint" && typeof a === "number") b = Number(b);
+    return op === "+" ? a + b : op === "-" ? a - b : a * b;
+}"#;
+
+/// `__art_arith` for `--overflow=wrap`: the exact result is wrapped to `i64`.
+const HELPER_ARITH_WRAP: &str = r#"function __art_arith(op, a, b) {
+    if (typeof a === "bigint" && typeof b === "bigint") {
+        return BigInt.asIntN(64, op === "+" ? a + b : op === "-" ? a - b : a * b);
+    }
+    if (typeof a === "bigint" && typeof b === "number") a = Number(a);
+    if (typeof b === "bigint" && typeof a === "number") b = Number(b);
+    return op === "+" ? a + b : op === "-" ? a - b : a * b;
+}"#;
+
+/// `/` and `%` on `Int`: `BigInt` division truncates toward zero, a zero
+/// divisor throws as in the interpreter and `MIN / -1` leaves `i64`. A `Float`
+/// operand takes the native operator (`x / 0.0` is `Infinity`, not an error).
+const HELPER_DIV_TRAP: &str = r#"function __art_div(op, a, b) {
+    if (typeof a === "bigint" && typeof b === "bigint") {
+        if (b === 0n) throw new RangeError(`Division by zero: ${a} ${op} 0.`);
+        const r = op === "/" ? a / b : a % b;
+        if (r !== BigInt.asIntN(64, r)) throw new RangeError(`Integer overflow: ${a} ${op} ${b} does not fit in Int.`);
+        return r;
+    }
+    if (typeof a === "bigint") a = Number(a);
+    if (typeof b === "bigint") b = Number(b);
+    return op === "/" ? a / b : a % b;
+}"#;
+
+/// `__art_div` for `--overflow=wrap`: `MIN / -1` wraps to `MIN`.
+const HELPER_DIV_WRAP: &str = r#"function __art_div(op, a, b) {
+    if (typeof a === "bigint" && typeof b === "bigint") {
+        if (b === 0n) throw new RangeError(`Division by zero: ${a} ${op} 0.`);
+        return BigInt.asIntN(64, op === "/" ? a / b : a % b);
+    }
+    if (typeof a === "bigint") a = Number(a);
+    if (typeof b === "bigint") b = Number(b);
+    return op === "/" ? a / b : a % b;
+}"#;
+
+/// `true` when `v` is an instance with method `m` (an operator overload).
+const HELPER_HAS_METHOD: &str = r#"function __art_has_method(v, m) {
+    return v !== null && typeof v === "object" && typeof v[m] === "function";
+}"#;
+
+/// `a op b` when some type overloads `op` through method `m`; other operands
+/// take the operator's usual path.
+const HELPER_BINOP: &str = r#"function __art_binop(op, m, a, b) {
+    if (__art_has_method(a, m)) {
+        const r = a[m](b);
+        switch (op) {
+            case "!=": return !r;
+            case "<": return r < 0;
+            case "<=": return r <= 0;
+            case ">": return r > 0;
+            case ">=": return r >= 0;
+            default: return r;
+        }
+    }
+    switch (op) {
+        case "+": case "-": case "*": return __art_arith(op, a, b);
+        case "/": return __art_div(op, a, b);
+        case "==": return a === b;
+        case "!=": return a !== b;
+        case "<": return a < b;
+        case "<=": return a <= b;
+        case ">": return a > b;
+        default: return a >= b;
+    }
+}"#;
+
+/// `eq` generated by `@derive(Eq)`: same class and equal fields. Nested
+/// instances use their own `eq`; arrays and enum values compare by content.
+const HELPER_DERIVE_EQ: &str = r#"function __art_eq(a, b) {
+    if (a === b) return true;
+    if (a === null || b === null || typeof a !== "object" || typeof b !== "object") return false;
+    if (Object.getPrototypeOf(a) !== Object.getPrototypeOf(b)) return false;
+    const keys = Object.keys(a);
+    if (keys.length !== Object.keys(b).length) return false;
+    const field = (x, y) => {
+        if (typeof x?.eq === "function") return x.eq(y);
+        const plain = x !== null && typeof x === "object" && (Array.isArray(x) || Object.getPrototypeOf(x) === Object.prototype);
+        return plain ? __art_eq(x, y) : x === y;
+    };
+    return keys.every((k) => field(a[k], b[k]));
+}"#;
+
+/// `cmp` generated by `@derive(Ord)`: fields in declaration order (the
+/// constructor's assignment order), arrays lexicographically.
+const HELPER_DERIVE_CMP: &str = r#"function __art_cmp(a, b) {
+    for (const k of Object.keys(a)) {
+        const x = a[k], y = b[k];
+        const c = typeof x?.cmp === "function" ? x.cmp(y)
+            : Array.isArray(x) ? __art_cmp(x, y)
+            : x < y ? -1n : x > y ? 1n : 0n;
+        if (c !== 0n) return c;
+    }
+    return Array.isArray(a) ? BigInt(a.length - b.length) : 0n;
+}"#;
+
+/// `clone` generated by `@derive(Clone)`: a new instance of the same class;
+/// arrays are copied and nested instances cloned when they can be.
+const HELPER_DERIVE_CLONE: &str = r#"function __art_clone(v) {
+    const field = (x) => (typeof x?.clone === "function" ? x.clone() : Array.isArray(x) ? x.map(field) : x);
+    const copy = Object.create(Object.getPrototypeOf(v));
+    for (const k of Object.keys(v)) copy[k] = field(v[k]);
+    return copy;
+}"#;
+
+/// `-a` with `neg` overloaded.
+const HELPER_NEG: &str = r#"function __art_neg(a) {
+    return __art_has_method(a, "neg") ? a.neg() : __art_arith("-", 0n, a);
+}"#;
+
+/// Interpolated value with `to_string` overloaded.
+const HELPER_STR: &str = r#"function __art_str(v) {
+    return __art_has_method(v, "to_string") ? v.to_string() : v;
+}"#;
+
+/// Receiver of `?.` / left side of `??`: `Option.Some(v)` becomes `v` and
+/// `Option.None` becomes `undefined`; `none` is already `null`.
+const HELPER_OPT: &str = r#"function __art_opt(v) {
+    if (v !== null && typeof v === "object" && (v.tag === "Some" || v.tag === "None")) {
+        return v.tag === "Some" ? v.payload[0] : undefined;
+    }
+    if (typeof WeakRef !== "undefined" && v instanceof WeakRef) return v.deref();
+    return v;
+}"#;
+
+/// `v.m(args)` when `m` is a builtin method of some type (`core::methods`):
+/// strings, arrays, `Map`, `Set`, buffers and numbers use the table, anything
+/// else (class instances) calls its own method. The table is built on first use
+/// because helpers are appended after the code that calls them.
+static HELPER_METHOD: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| {
+    let mut receivers: Vec<&str> = Vec::new();
+    for m in BUILTIN_METHODS.iter().filter(|m| !m.js.is_empty()) {
+        if !receivers.contains(&m.receiver) {
+            receivers.push(m.receiver);
+        }
+    }
+    let table: Vec<String> = receivers
+        .iter()
+        .map(|receiver| {
+            let entries: Vec<String> = methods::methods_of(receiver)
+                .filter(|m| !m.js.is_empty())
+                .map(|m| format!("            {}: {},", m.name, m.js))
+                .collect();
+            format!(
+                "        {}: {{\n{}\n        }},",
+                receiver,
+                entries.join("\n")
+            )
+        })
+        .collect();
+    format!(
+        r#"function __art_method(v, m, args) {{
+    const table = __art_method.table ??= {{
+{}
+    }};
+    const kinds = typeof v === "string" ? ["String"]
+        : typeof v === "bigint" ? ["Int"]
+        : typeof v === "number" ? ["Float"]
+        : Array.isArray(v) ? ["Array", "Tuple", "Deque"]
+        : v instanceof Map ? ["Map"]
+        : v instanceof Set ? ["Set"]
+        : v instanceof Uint8Array ? ["Buffer"]
+        : [];
+    for (const k of kinds) {{
+        const f = table[k]?.[m];
+        if (f) return f(v, ...args);
+    }}
+    return v[m](...args);
+}}"#,
+        table.join("\n")
+    )
+});
+
+/// `wrapping_*`, `checked_*` and `saturating_*` builtins, computed on `BigInt`.
+const HELPER_INT_OPS: &str = r#"function __art_int_exact(op, a, b) {
+    const x = BigInt(a), y = BigInt(b);
+    return op === "add" ? x + y : op === "sub" ? x - y : x * y;
+}
+function __art_int_op(policy, op, a, b) {
+    const v = __art_int_exact(op, a, b);
+    const w = BigInt.asIntN(64, v);
+    if (policy === "wrapping") return w;
+    if (policy === "checked") return v === w ? { tag: "Some", payload: [v] } : { tag: "None" };
+    const max = (1n << 63n) - 1n, min = -(1n << 63n);
+    return v > max ? max : v < min ? min : v;
+}
+function wrapping_add(a, b) { return __art_int_op("wrapping", "add", a, b); }
+function wrapping_sub(a, b) { return __art_int_op("wrapping", "sub", a, b); }
+function wrapping_mul(a, b) { return __art_int_op("wrapping", "mul", a, b); }
+function checked_add(a, b) { return __art_int_op("checked", "add", a, b); }
+function checked_sub(a, b) { return __art_int_op("checked", "sub", a, b); }
+function checked_mul(a, b) { return __art_int_op("checked", "mul", a, b); }
+function saturating_add(a, b) { return __art_int_op("saturating", "add", a, b); }
+function saturating_sub(a, b) { return __art_int_op("saturating", "sub", a, b); }
+function saturating_mul(a, b) { return __art_int_op("saturating", "mul", a, b); }"#;
+
+/// Builtins provided by [`HELPER_INT_OPS`].
+const INT_OP_BUILTINS: &[&str] = &[
+    "wrapping_add",
+    "wrapping_sub",
+    "wrapping_mul",
+    "checked_add",
+    "checked_sub",
+    "checked_mul",
+    "saturating_add",
+    "saturating_sub",
+    "saturating_mul",
+];
+
 impl CodegenJs {
     pub fn new(options: CodegenOptions) -> Self {
         Self {
@@ -62,12 +394,60 @@ impl CodegenJs {
             reactive_names: std::collections::HashSet::new(),
             reactive_txt_nodes: Vec::new(),
             txt_node_counter: 0,
+            state_names: std::collections::HashSet::new(),
+            used_helpers: std::collections::BTreeSet::new(),
+            destructure_counter: 0,
+            consts: ConstEnv::new(),
+            newtypes: std::collections::HashSet::new(),
+            overloads: std::collections::HashSet::new(),
+            structs: std::collections::HashMap::new(),
+            types: TypeDecls::default(),
+            num_kinds: std::collections::HashMap::new(),
+            fn_kinds: std::collections::HashMap::new(),
         }
     }
 
     pub fn emit_program(mut self, program: &[Stmt]) -> JsOutput {
-        for stmt in program {
-            self.emit_stmt(stmt);
+        self.consts = fold_consts(program);
+        self.types = TypeDecls::collect(program);
+        self.newtypes = self.types.newtypes.keys().cloned().collect();
+        self.overloads = operator_overloads(program);
+        self.fn_kinds = program
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Stmt::Function {
+                    name,
+                    return_type: Some(ty),
+                    method_owner: None,
+                    ..
+                } => Some((name.lexeme.clone(), self.annotation_kind(ty)?)),
+                _ => None,
+            })
+            .collect();
+        self.structs = program
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Stmt::StructDecl {
+                    name,
+                    fields,
+                    tuple,
+                    ..
+                } => Some((
+                    name.lexeme.clone(),
+                    StructDecl {
+                        fields: fields.clone(),
+                        tuple: *tuple,
+                    },
+                )),
+                _ => None,
+            })
+            .collect();
+        self.emit_stmts(program);
+        // Helpers are function declarations, so they are hoisted; appending them
+        // keeps the source map of the user code untouched.
+        for helper in std::mem::take(&mut self.used_helpers) {
+            self.write(helper);
+            self.newline();
         }
         let source_map = if self.options.emit_source_map {
             let name = self.options.source_file.as_deref().unwrap_or("input.art");
@@ -83,6 +463,219 @@ impl CodegenJs {
 
     // ── internal helpers ─────────────────────────────────────────────────────
 
+    /// `l op r`; `+`, `-` and `*` go through `__art_arith` so `Int` follows the
+    /// overflow policy, `/` and `%` through `__art_div`.
+    fn emit_arith(&mut self, op: &str, l: &str, r: &str) -> String {
+        match op {
+            "+" | "-" | "*" => self.use_arith_helper(),
+            "/" | "%" => {
+                self.use_div_helper();
+                return format!("__art_div(\"{}\", {}, {})", op, l, r);
+            }
+            _ => return format!("{} {} {}", l, Self::map_operator(op), r),
+        }
+        format!("__art_arith(\"{}\", {}, {})", op, l, r)
+    }
+
+    fn use_arith_helper(&mut self) {
+        self.used_helpers.insert(match self.options.overflow {
+            OverflowMode::Trap => HELPER_ARITH_TRAP,
+            OverflowMode::Wrap => HELPER_ARITH_WRAP,
+        });
+    }
+
+    fn use_div_helper(&mut self) {
+        self.used_helpers.insert(match self.options.overflow {
+            OverflowMode::Trap => HELPER_DIV_TRAP,
+            OverflowMode::Wrap => HELPER_DIV_WRAP,
+        });
+    }
+
+    /// `true` if the program overloads `method` and `operand` may be an
+    /// instance (literals never are).
+    fn overloaded(&self, method: &str, operand: &Expr) -> bool {
+        self.overloads.contains(method)
+            && !matches!(operand, Expr::Literal(_) | Expr::InterpolatedString(_))
+    }
+
+    /// Call of an overload helper; pulls in the helpers it relies on.
+    fn use_overload_helper(&mut self, helper: &'static str) {
+        self.used_helpers.insert(HELPER_HAS_METHOD);
+        self.used_helpers.insert(helper);
+        if helper != HELPER_STR {
+            self.use_arith_helper();
+        }
+        if helper == HELPER_BINOP {
+            self.use_div_helper();
+        }
+    }
+
+    /// Runtime kind of a value annotated `ty`, through aliases and newtypes.
+    fn annotation_kind(&self, ty: &str) -> Option<NumKind> {
+        match self.types.erase(ty).as_str() {
+            "Int" => Some(NumKind::Int),
+            "Float" => Some(NumKind::Float),
+            _ => None,
+        }
+    }
+
+    /// Numeric kind of `expr` when it is known without running it.
+    fn num_kind(&self, expr: &Expr) -> Option<NumKind> {
+        match expr {
+            Expr::Literal(ArtValue::Int(_)) => Some(NumKind::Int),
+            Expr::Literal(ArtValue::Float(_)) => Some(NumKind::Float),
+            Expr::Variable { name } => match self.consts.get(&name.lexeme) {
+                Some(ArtValue::Int(_)) => Some(NumKind::Int),
+                Some(ArtValue::Float(_)) => Some(NumKind::Float),
+                Some(_) => None,
+                None => self.num_kinds.get(&name.lexeme).copied(),
+            },
+            Expr::Grouping { expression } => self.num_kind(expression),
+            Expr::Unary { operator, right } if operator.lexeme == "-" => {
+                if self.overloaded(NEG_METHOD, right) {
+                    return None;
+                }
+                self.num_kind(right)
+            }
+            Expr::Binary {
+                left,
+                operator,
+                right,
+            } => {
+                if binary_method(&operator.token_type).is_some_and(|m| self.overloaded(m, left)) {
+                    return None;
+                }
+                match operator.lexeme.as_str() {
+                    "&" | "|" | "^" | "<<" | ">>" => Some(NumKind::Int),
+                    "+" | "-" | "*" | "/" | "%" => {
+                        match (self.num_kind(left), self.num_kind(right)) {
+                            (Some(NumKind::Float), _) | (_, Some(NumKind::Float)) => {
+                                Some(NumKind::Float)
+                            }
+                            (Some(NumKind::Int), Some(NumKind::Int)) => Some(NumKind::Int),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                }
+            }
+            Expr::Call { callee, .. } => match &**callee {
+                Expr::Variable { name } => self.fn_kinds.get(&name.lexeme).copied(),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Records the kind of a new binding; an unknown kind forgets the entry so
+    /// a shadowed outer binding doesn't leak into its scope.
+    fn bind_kind(&mut self, name: &str, kind: Option<NumKind>) {
+        match kind {
+            Some(k) => {
+                self.num_kinds.insert(name.to_string(), k);
+            }
+            None => {
+                self.num_kinds.remove(name);
+            }
+        }
+    }
+
+    /// Records the kinds of annotated parameters; the others are unknown.
+    fn bind_params(&mut self, params: &[core::ast::FunctionParam]) {
+        for p in params {
+            let kind = p.ty.as_deref().and_then(|ty| self.annotation_kind(ty));
+            self.bind_kind(&p.name.lexeme, kind);
+        }
+    }
+
+    /// Forgets the kinds of the names bound by `pattern`.
+    fn forget_kinds(&mut self, pattern: &MatchPattern) {
+        for name in pattern.bindings() {
+            self.num_kinds.remove(&name.lexeme);
+        }
+    }
+
+    /// `l op r` for arithmetic. When either side is known to be a `Float` the
+    /// result is a `Float`: the other side is promoted from `BigInt` and the
+    /// native operator applies. Otherwise the helpers decide at runtime.
+    fn emit_numeric(
+        &mut self,
+        op: &str,
+        (l, lk): (&str, Option<NumKind>),
+        (r, rk): (&str, Option<NumKind>),
+    ) -> String {
+        let float = Some(NumKind::Float);
+        if matches!(op, "+" | "-" | "*" | "/" | "%") && (lk == float || rk == float) {
+            // An `Int` literal is promoted in place: `2n` becomes `2`.
+            let num = |js: &str, k: Option<NumKind>| match js.strip_suffix('n') {
+                _ if k == float => js.to_string(),
+                Some(digits) if digits.parse::<i64>().is_ok() => digits.to_string(),
+                _ => format!("Number({})", js),
+            };
+            return format!("{} {} {}", num(l, lk), op, num(r, rk));
+        }
+        self.emit_arith(op, l, r)
+    }
+
+    /// `l op r` for a binary operator whose operands are `left` and `right`,
+    /// already emitted: overloads, bitwise helpers, strings and numbers.
+    fn emit_binary(
+        &mut self,
+        token_type: &TokenType,
+        op: &str,
+        (left, l): (&Expr, &str),
+        (right, r): (&Expr, &str),
+    ) -> String {
+        if let Some(method) = binary_method(token_type)
+            && self.overloaded(method, left)
+        {
+            self.use_overload_helper(HELPER_BINOP);
+            return format!("__art_binop(\"{}\", \"{}\", {}, {})", op, method, l, r);
+        }
+        if matches!(op, "&" | "|" | "^" | "<<" | ">>") {
+            self.used_helpers.insert(HELPER_BITS);
+            return format!("__art_bits(\"{}\", {}, {})", op, l, r);
+        }
+        // A string operand can never overflow `Int`.
+        let non_int = |e: &Expr| {
+            matches!(
+                e,
+                Expr::Literal(ArtValue::String(_)) | Expr::InterpolatedString(_)
+            )
+        };
+        if non_int(left) || non_int(right) {
+            return format!("{} {} {}", l, Self::map_operator(op), r);
+        }
+        let (lk, rk) = (self.num_kind(left), self.num_kind(right));
+        self.emit_numeric(op, (l, lk), (r, rk))
+    }
+
+    /// New value of `target op= value`, dispatched like `target op value`;
+    /// `current` reads the target and `rhs` is the emitted value.
+    fn emit_compound(
+        &mut self,
+        op: &str,
+        (target, current): (&Expr, &str),
+        (value, rhs): (&Expr, &str),
+    ) -> String {
+        let token_type = match op {
+            "+" => TokenType::Plus,
+            "-" => TokenType::Minus,
+            "*" => TokenType::Star,
+            "/" => TokenType::Slash,
+            _ => return format!("{} {} ({})", current, op, rhs),
+        };
+        // A `Float` value may be emitted as a native operator, which the outer
+        // one would split: `x *= a + b` must stay `x * (a + b)`.
+        let native = self.num_kind(value) == Some(NumKind::Float);
+        let rhs = if native && matches!(value, Expr::Binary { .. }) {
+            format!("({})", rhs)
+        } else {
+            rhs.to_string()
+        };
+        self.emit_binary(&token_type, op, (target, current), (value, &rhs))
+    }
+
     fn write(&mut self, s: &str) {
         self.output.push_str(s);
         self.gen_col += s.len() as u32;
@@ -115,6 +708,15 @@ impl CodegenJs {
         }
     }
 
+    /// `export ` for `pub` items when emitting an ES module.
+    fn export_kw(&self, public: bool) -> &'static str {
+        if public && self.options.module_format == ModuleFormat::Esm {
+            "export "
+        } else {
+            ""
+        }
+    }
+
     fn js_ident(name: &str) -> String {
         // Map reserved JS words that collide with Artcode identifiers
         match name {
@@ -129,6 +731,18 @@ impl CodegenJs {
         }
     }
 
+    /// Rótulo de loop Art (`'outer`) como rótulo JS (`outer`).
+    fn js_label(label: &core::Token) -> String {
+        Self::js_ident(label.lexeme.trim_start_matches('\''))
+    }
+
+    /// Prefixo `outer: ` para loops rotulados; vazio caso contrário.
+    fn js_label_prefix(label: Option<&core::Token>) -> String {
+        label
+            .map(|l| format!("{}: ", Self::js_label(l)))
+            .unwrap_or_default()
+    }
+
     fn escape_string(s: &str) -> String {
         let mut out = String::with_capacity(s.len());
         for c in s.chars() {
@@ -146,6 +760,29 @@ impl CodegenJs {
 
     // ── statements ───────────────────────────────────────────────────────────
 
+    /// Emite uma sequência de statements. Cada `defer` embrulha o resto da
+    /// sequência em `try { ... } finally { corpo }`, o que dá a ordem LIFO.
+    fn emit_stmts(&mut self, statements: &[Stmt]) {
+        for (i, stmt) in statements.iter().enumerate() {
+            if let Stmt::Defer { keyword, body } = stmt {
+                let ind = self.indent_str();
+                self.write(&ind);
+                self.record(keyword.line, keyword.col);
+                self.write("try {");
+                self.newline();
+                self.indent += 1;
+                self.emit_stmts(&statements[i + 1..]);
+                self.indent -= 1;
+                let ind = self.indent_str();
+                self.write(&format!("{}}} finally ", ind));
+                self.emit_stmt_inline(body);
+                self.newline();
+                return;
+            }
+            self.emit_stmt(stmt);
+        }
+    }
+
     fn emit_stmt(&mut self, stmt: &Stmt) {
         match stmt {
             Stmt::Expression(expr) => {
@@ -159,17 +796,30 @@ impl CodegenJs {
 
             Stmt::Let {
                 pattern,
+                ty,
+                mutable,
                 initializer,
-                ..
             } => {
                 let ind = self.indent_str();
                 self.write(&ind);
+                let decl_kw = if *mutable { "let" } else { "const" };
+                if !matches!(
+                    pattern,
+                    MatchPattern::Variable(_) | MatchPattern::Binding(_)
+                ) {
+                    self.forget_kinds(pattern);
+                }
                 match pattern {
                     MatchPattern::Variable(tok) | MatchPattern::Binding(tok) => {
                         self.record(tok.line, tok.col);
                         let name = Self::js_ident(&tok.lexeme);
                         let rhs = self.emit_expr(initializer);
-                        self.write(&format!("const {} = {};", name, rhs));
+                        self.write(&format!("{} {} = {};", decl_kw, name, rhs));
+                        let kind = match ty {
+                            Some(ty) => self.annotation_kind(ty),
+                            None => self.num_kind(initializer),
+                        };
+                        self.bind_kind(&tok.lexeme, kind);
                     }
                     MatchPattern::Tuple(pats) => {
                         let names: Vec<String> = pats
@@ -182,7 +832,18 @@ impl CodegenJs {
                             })
                             .collect();
                         let rhs = self.emit_expr(initializer);
-                        self.write(&format!("const [{}] = {};", names.join(", "), rhs));
+                        self.write(&format!("{} [{}] = {};", decl_kw, names.join(", "), rhs));
+                    }
+                    MatchPattern::Struct { .. }
+                    | MatchPattern::Array { .. }
+                    | MatchPattern::At { .. } => {
+                        let rhs = self.emit_expr(initializer);
+                        let tmp = format!("__destructure_{}", self.destructure_counter);
+                        self.destructure_counter += 1;
+                        self.write(&format!("const {} = {};", tmp, rhs));
+                        for (name, value) in self.match_binding_exprs(pattern, &tmp) {
+                            self.write(&format!(" {} {} = {};", decl_kw, name, value));
+                        }
                     }
                     _ => {
                         let rhs = self.emit_expr(initializer);
@@ -192,15 +853,104 @@ impl CodegenJs {
                 self.newline();
             }
 
+            // Leituras de constantes escalares já saem inlinadas; a declaração fica
+            // para as compostas e para quem importa o módulo, com o valor já
+            // dobrado quando é escalar.
+            Stmt::Const {
+                name,
+                value,
+                public,
+                ..
+            } => {
+                let ind = self.indent_str();
+                self.write(&ind);
+                self.record(name.line, name.col);
+                let rhs = match self.consts.get(&name.lexeme) {
+                    Some(
+                        folded @ (ArtValue::Int(_)
+                        | ArtValue::Float(_)
+                        | ArtValue::Bool(_)
+                        | ArtValue::String(_)),
+                    ) => Self::emit_value_static(folded),
+                    _ => self.emit_expr(value),
+                };
+                self.write(&format!(
+                    "{}const {} = {};",
+                    self.export_kw(*public),
+                    Self::js_ident(&name.lexeme),
+                    rhs
+                ));
+                self.newline();
+            }
+
+            Stmt::Assign {
+                target,
+                operator,
+                value,
+            } => {
+                let ind = self.indent_str();
+                self.write(&ind);
+                self.record(operator.line, operator.col);
+                let rhs = self.emit_expr(value);
+                // Inside a component, writing a `state` binding must go through its
+                // setter so dependent memos and text nodes are refreshed.
+                let state_target = match target {
+                    Expr::Variable { name } if self.state_names.contains(&name.lexeme) => {
+                        Some(Self::js_ident(&name.lexeme))
+                    }
+                    _ => None,
+                };
+                if let Expr::Index { object, index, .. } = target {
+                    self.used_helpers.insert(HELPER_SET_INDEX);
+                    let obj = self.emit_expr(object);
+                    let idx = self.emit_expr(index);
+                    match operator.lexeme.strip_suffix('=') {
+                        // The read and the write share the container and the index,
+                        // evaluated once into block-scoped temporaries.
+                        Some(op) if !op.is_empty() => {
+                            self.used_helpers.insert(HELPER_INDEX);
+                            let new_value = self.emit_compound(
+                                op,
+                                (target, "__art_index(__o, __i)"),
+                                (value, &rhs),
+                            );
+                            self.write(&format!(
+                                "{{ const __o = {}, __i = {}; __art_set_index(__o, __i, {}); }}",
+                                obj, idx, new_value
+                            ));
+                        }
+                        _ => self.write(&format!("__art_set_index({}, {}, {});", obj, idx, rhs)),
+                    }
+                } else if let Some(js_state) = state_target {
+                    let new_value = match operator.lexeme.strip_suffix('=') {
+                        Some(op) if !op.is_empty() => {
+                            self.emit_compound(op, (target, &js_state), (value, &rhs))
+                        }
+                        _ => rhs,
+                    };
+                    self.write(&format!("set_{}({});", js_state, new_value));
+                } else {
+                    let lhs = self.emit_expr(target);
+                    let new_value = match operator.lexeme.strip_suffix('=') {
+                        Some(op) if !op.is_empty() => {
+                            self.emit_compound(op, (target, &lhs), (value, &rhs))
+                        }
+                        _ => rhs,
+                    };
+                    self.write(&format!("{} = {};", lhs, new_value));
+                }
+                self.newline();
+            }
+
             Stmt::Block { statements } => {
                 let ind = self.indent_str();
                 self.write(&ind);
                 self.write("{");
                 self.newline();
                 self.indent += 1;
-                for s in statements {
-                    self.emit_stmt(s);
-                }
+                let kinds = self.num_kinds.clone();
+                self.emit_stmts(statements);
+                self.num_kinds = kinds;
                 self.indent -= 1;
                 let ind = self.indent_str();
                 self.write(&ind);
@@ -248,7 +998,10 @@ impl CodegenJs {
                     self.write(&bindings);
                     self.newline();
                 }
+                let kinds = self.num_kinds.clone();
+                self.forget_kinds(pattern);
                 self.emit_stmt(then_branch);
+                self.num_kinds = kinds;
                 self.indent -= 1;
                 let ind2 = self.indent_str();
                 self.write(&ind2);
@@ -272,7 +1025,10 @@ impl CodegenJs {
                 self.emit_stmt_inline(try_branch);
                 let cn = Self::js_ident(&catch_name.lexeme);
                 self.write(&format!(" catch ({}) ", cn));
+                let kinds = self.num_kinds.clone();
+                self.bind_kind(&catch_name.lexeme, None);
                 self.emit_stmt_inline(catch_branch);
+                self.num_kinds = kinds;
                 self.newline();
             }
 
@@ -283,6 +1039,7 @@ impl CodegenJs {
                 method_owner,
                 type_params: _,
                 is_async,
+                public,
                 ..
             } => {
                 let ind = self.indent_str();
@@ -294,48 +1051,84 @@ impl CodegenJs {
                     .map(|p| Self::js_ident(&p.name.lexeme))
                     .collect();
                 let async_kw = if *is_async { "async " } else { "" };
+                // Funções com `yield` viram generators do JS.
+                let star = if body.contains_yield() { "*" } else { "" };
                 if let Some(owner) = method_owner {
                     let owner_js = Self::js_ident(owner);
                     self.write(&format!(
-                        "{}{}_{}.prototype.{} = function({}) ",
+                        "{}{}_{}.prototype.{} = function{}({}) ",
                         async_kw,
                         owner_js,
                         owner_js,
                         fname,
+                        star,
                         pnames.join(", ")
                     ));
                 } else {
                     self.write(&format!(
-                        "{}function {}({}) ",
+                        "{}{}function{} {}({}) ",
+                        self.export_kw(*public),
                         async_kw,
+                        star,
                         fname,
                         pnames.join(", ")
                     ));
                 }
-                self.emit_stmt_inline(body);
+                self.emit_body(params, body);
                 self.newline();
             }
 
-            Stmt::While { condition, body } => {
+            Stmt::While {
+                condition,
+                body,
+                label,
+            } => {
                 let ind = self.indent_str();
                 self.write(&ind);
                 let cond = self.emit_expr(condition);
-                self.write(&format!("while ({}) ", cond));
+                let label = Self::js_label_prefix(label.as_ref());
+                self.write(&format!("{}while ({}) ", label, cond));
                 self.emit_stmt_inline(body);
                 self.newline();
             }
 
             Stmt::For {
                 element,
+                pattern,
                 iterator,
                 body,
+                label,
             } => {
                 let ind = self.indent_str();
                 self.write(&ind);
-                let elem = Self::js_ident(&element.lexeme);
+                let elem = match pattern {
+                    Some(p) => Self::js_destructure(p),
+                    None => Self::js_ident(&element.lexeme),
+                };
                 let iter = self.emit_expr(iterator);
-                self.write(&format!("for (const {} of {}) ", elem, iter));
+                let label = Self::js_label_prefix(label.as_ref());
+                self.write(&format!("{}for (const {} of {}) ", label, elem, iter));
+                let kinds = self.num_kinds.clone();
+                match pattern {
+                    Some(p) => self.forget_kinds(p),
+                    None => self.bind_kind(&element.lexeme, None),
+                }
                 self.emit_stmt_inline(body);
+                self.num_kinds = kinds;
+                self.newline();
+            }
+
+            Stmt::Break { keyword, label } | Stmt::Continue { keyword, label } => {
+                let ind = self.indent_str();
+                self.write(&ind);
+                self.record(keyword.line, keyword.col);
+                match label {
+                    Some(l) => {
+                        let name = Self::js_label(l);
+                        self.write(&format!("{} {};", keyword.lexeme, name));
+                    }
+                    None => self.write(&format!("{};", keyword.lexeme)),
+                }
                 self.newline();
             }
 
@@ -352,16 +1145,42 @@ impl CodegenJs {
                 self.newline();
             }
 
-            Stmt::StructDecl { name, fields } => {
+            // Fora de uma sequência (ex.: corpo de um `case`) o escopo termina logo
+            // depois do `defer`.
+            Stmt::Defer { body, .. } => self.emit_stmt(body),
+
+            Stmt::Yield { keyword, value } => {
+                let ind = self.indent_str();
+                self.write(&ind);
+                self.record(keyword.line, keyword.col);
+                let js = self.emit_expr(value);
+                self.write(&format!("yield {};", js));
+                self.newline();
+            }
+
+            Stmt::StructDecl {
+                name,
+                fields,
+                tuple,
+                derives,
+                public,
+            } => {
                 let ind = self.indent_str();
                 self.write(&ind);
                 self.record(name.line, name.col);
                 let cname = &name.lexeme;
+                // Tuple fields are positions: `constructor(_0, _1)` sets `this[0]`.
                 let field_names: Vec<String> = fields
                     .iter()
-                    .map(|(f, _)| Self::js_ident(&f.lexeme))
+                    .map(|f| {
+                        if *tuple {
+                            format!("_{}", f.name.lexeme)
+                        } else {
+                            Self::js_ident(&f.name.lexeme)
+                        }
+                    })
                     .collect();
-                self.write(&format!("class {} {{\n", cname));
+                self.write(&format!("{}class {} {{\n", self.export_kw(*public), cname));
                 self.indent += 1;
                 let ind2 = self.indent_str();
                 self.write(&format!(
@@ -370,24 +1189,66 @@ impl CodegenJs {
                     field_names.join(", ")
                 ));
                 self.indent += 1;
-                for fname in &field_names {
+                for (field, fname) in fields.iter().zip(&field_names) {
                     let ind3 = self.indent_str();
-                    self.write(&format!("{}this.{} = {};\n", ind3, fname, fname));
+                    let target = if *tuple {
+                        format!("this[{}]", field.name.lexeme)
+                    } else {
+                        format!("this.{}", fname)
+                    };
+                    self.write(&format!("{}{} = {};\n", ind3, target, fname));
                 }
                 self.indent -= 1;
                 let ind2 = self.indent_str();
                 self.write(&format!("{}}}\n", ind2));
+                // `Debug` and `Hash` have no JS counterpart.
+                for derive in derives {
+                    let (method, helper) = match derive.lexeme.as_str() {
+                        "Eq" => ("eq(o) { return __art_eq(this, o); }", HELPER_DERIVE_EQ),
+                        "Ord" => ("cmp(o) { return __art_cmp(this, o); }", HELPER_DERIVE_CMP),
+                        "Clone" => ("clone() { return __art_clone(this); }", HELPER_DERIVE_CLONE),
+                        _ => continue,
+                    };
+                    self.used_helpers.insert(helper);
+                    self.write(&format!("{}{}\n", ind2, method));
+                }
                 self.indent -= 1;
                 let ind = self.indent_str();
                 self.write(&format!("{}}}", ind));
                 self.newline();
             }
 
-            Stmt::EnumDecl { name, variants } => {
+            // Aliases only exist for the type checker.
+            Stmt::TypeAlias { .. } => {}
+
+            // Same-module wraps are inlined; the identity stays for imports and
+            // for `Nome` passed as a value.
+            Stmt::Newtype { name, .. } => {
+                let ind = self.indent_str();
+                self.record(name.line, name.col);
+                self.write(&format!(
+                    "{}const {} = (v) => v;",
+                    ind,
+                    Self::js_ident(&name.lexeme)
+                ));
+                self.newline();
+            }
+
+            Stmt::EnumDecl {
+                name,
+                variants,
+                public,
+                ..
+            } => {
                 let ind = self.indent_str();
                 self.record(name.line, name.col);
                 let ename = &name.lexeme;
-                self.write(&format!("{}const {} = {{\n", ind, ename));
+                self.write(&format!(
+                    "{}{}const {} = {{\n",
+                    ind,
+                    self.export_kw(*public),
+                    ename
+                ));
                 self.indent += 1;
                 for (variant, payload_types) in variants {
                     let vname = &variant.lexeme;
@@ -416,11 +1277,15 @@ impl CodegenJs {
                 self.newline();
             }
 
-            Stmt::Match { expr, cases } => {
+            Stmt::Match { expr, cases, .. } => {
                 self.emit_match(expr, cases);
             }
 
-            Stmt::ImplBlock { type_name, methods } => {
+            Stmt::ImplBlock {
+                type_name,
+                trait_name,
+                methods,
+            } => {
                 for method in methods {
                     if let Stmt::Function {
                         name,
@@ -440,17 +1305,63 @@ impl CodegenJs {
                             .map(|p| Self::js_ident(&p.name.lexeme))
                             .collect();
                         let async_kw = if *is_async { "async " } else { "" };
+                        let star = if body.contains_yield() { "*" } else { "" };
                         self.write(&format!(
-                            "{}.prototype.{} = {}function({}) ",
+                            "{}.prototype.{} = {}function{}({}) ",
                             tname,
                             mname,
                             async_kw,
+                            star,
                             pnames.join(", ")
                         ));
-                        self.emit_stmt_inline(body);
+                        self.emit_body(params, body);
                         self.newline();
                     }
                 }
+                // Defaults do trait entram só onde o tipo não define o método.
+                if let Some(t) = trait_name {
+                    let ind = self.indent_str();
+                    let tname = Self::js_ident(type_name);
+                    let trait_js = Self::js_ident(&t.lexeme);
+                    self.write(&format!(
+                        "{}for (const k of Object.keys({})) {{ if (!(k in {}.prototype)) {}.prototype[k] = {}[k]; }}",
+                        ind, trait_js, tname, tname, trait_js
+                    ));
+                    self.newline();
+                }
+            }
+
+            Stmt::TraitDecl { name, methods } => {
+                // Trait = objeto com as implementações default.
+                let ind = self.indent_str();
+                self.write(&ind);
+                self.record(name.line, name.col);
+                self.write(&format!("const {} = {{", Self::js_ident(&name.lexeme)));
+                self.newline();
+                self.indent += 1;
+                for method in methods {
+                    let Some(body) = &method.body else { continue };
+                    let ind2 = self.indent_str();
+                    let pnames: Vec<String> = method
+                        .params
+                        .iter()
+                        .filter(|p| p.name.lexeme != "self")
+                        .map(|p| Self::js_ident(&p.name.lexeme))
+                        .collect();
+                    self.write(&format!(
+                        "{}{}: function({}) ",
+                        ind2,
+                        Self::js_ident(&method.name.lexeme),
+                        pnames.join(", ")
+                    ));
+                    self.emit_body(&method.params, body);
+                    self.write(",");
+                    self.newline();
+                }
+                self.indent -= 1;
+                let ind = self.indent_str();
+                self.write(&format!("{}}};", ind));
+                self.newline();
             }
 
             Stmt::Performant { statements } => {
@@ -459,9 +1370,7 @@ impl CodegenJs {
                 self.write("(() => {");
                 self.newline();
                 self.indent += 1;
-                for s in statements {
-                    self.emit_stmt(s);
-                }
+                self.emit_stmts(statements);
                 self.indent -= 1;
                 let ind = self.indent_str();
                 self.write(&ind);
@@ -479,8 +1388,16 @@ impl CodegenJs {
                 self.indent += 1;
                 let mut inner = CodegenJs::new(CodegenOptions {
                     emit_source_map: false,
+                    overflow: self.options.overflow,
                     ..Default::default()
                 });
+                inner.consts = self.consts.clone();
+                inner.newtypes = self.newtypes.clone();
+                inner.structs = self.structs.clone();
+                inner.overloads = self.overloads.clone();
+                inner.types = self.types.clone();
+                inner.num_kinds = self.num_kinds.clone();
+                inner.fn_kinds = self.fn_kinds.clone();
                 for s in body {
                     inner.emit_stmt(s);
                 }
@@ -494,7 +1411,7 @@ impl CodegenJs {
                 self.newline();
             }
 
-            Stmt::Import { path } => {
+            Stmt::Import { path, items, alias } => {
                 // Bundle mode: imports are inlined by the bundler; suppress the statement.
                 if self.options.module_format == ModuleFormat::Bundle {
                     return;
@@ -503,8 +1420,21 @@ impl CodegenJs {
                 self.write(&ind);
                 let parts: Vec<String> = path.iter().map(|t| t.lexeme.clone()).collect();
                 let module_path = format!("./{}.js", parts.join("/"));
-                let symbol = Self::js_ident(&parts.last().cloned().unwrap_or_default());
-                self.write(&format!("import * as {} from \"{}\";", symbol, module_path));
+                if let Some(items) = items {
+                    let names: Vec<String> =
+                        items.iter().map(|t| Self::js_ident(&t.lexeme)).collect();
+                    self.write(&format!(
+                        "import {{ {} }} from \"{}\";",
+                        names.join(", "),
+                        module_path
+                    ));
+                } else {
+                    let symbol = match alias {
+                        Some(a) => Self::js_ident(&a.lexeme),
+                        None => Self::js_ident(&parts.last().cloned().unwrap_or_default()),
+                    };
+                    self.write(&format!("import * as {} from \"{}\";", symbol, module_path));
+                }
                 self.newline();
             }
 
@@ -563,15 +1493,24 @@ impl CodegenJs {
         }
     }
 
+    /// Body of a function or method, with the numeric kinds of its annotated
+    /// parameters in scope.
+    fn emit_body(&mut self, params: &[core::ast::FunctionParam], body: &Stmt) {
+        let kinds = self.num_kinds.clone();
+        self.bind_params(params);
+        self.emit_stmt_inline(body);
+        self.num_kinds = kinds;
+    }
+
     fn emit_stmt_inline(&mut self, stmt: &Stmt) {
         match stmt {
             Stmt::Block { statements } => {
                 self.write("{");
                 self.newline();
                 self.indent += 1;
-                for s in statements {
-                    self.emit_stmt(s);
-                }
+                let kinds = self.num_kinds.clone();
+                self.emit_stmts(statements);
+                self.num_kinds = kinds;
                 self.indent -= 1;
                 let ind = self.indent_str();
                 self.write(&ind);
@@ -607,9 +1546,16 @@ impl CodegenJs {
         self.reactive_names.clear();
         self.reactive_txt_nodes.clear();
         self.txt_node_counter = 0;
+        self.state_names.clear();
         for b in bindings {
-            if let S::QualifiedBinding { name: n, .. } = b {
+            if let S::QualifiedBinding {
+                name: n, qualifier, ..
+            } = b
+            {
                 self.reactive_names.insert(n.lexeme.clone());
+                if *qualifier == BindingQualifier::State {
+                    self.state_names.insert(n.lexeme.clone());
+                }
             }
         }
 
@@ -784,6 +1730,13 @@ impl CodegenJs {
             }
         }
 
+        // Component-local handlers; they close over the bindings and setters above
+        for b in bindings {
+            if matches!(b, S::Function { .. }) {
+                self.emit_stmt(b);
+            }
+        }
+
         // Expose component reference and call on_mount after DOM is ready
         let comp_ref = format!("{}_component", Self::js_ident(name));
         self.write(&format!(
@@ -817,6 +1770,7 @@ impl CodegenJs {
         // Clear reactive context
         self.reactive_names.clear();
         self.reactive_txt_nodes.clear();
+        self.state_names.clear();
 
         self.indent -= 1;
         self.write(&format!("{}}}\n", ind));
@@ -940,6 +1894,8 @@ impl CodegenJs {
         let mut first = true;
         for (pattern, guard, body) in cases {
             let ind = self.indent_str();
+            let kinds = self.num_kinds.clone();
+            self.forget_kinds(pattern);
             let cond = self.emit_match_condition(pattern, tmp);
             let preamble = self.emit_match_bindings(pattern, tmp);
             let guard_str = guard
@@ -964,6 +1920,7 @@ impl CodegenJs {
                 self.write(&format!("{}{}\n", ind2, preamble));
             }
             self.emit_stmt(body);
+            self.num_kinds = kinds;
             self.indent -= 1;
             let ind = self.indent_str();
             self.write(&format!("{}}}", ind));
@@ -1019,40 +1976,182 @@ impl CodegenJs {
                     sub_conds.join(" && ")
                 }
             }
+            MatchPattern::Struct { name, fields } => {
+                let mut conds = vec![format!(
+                    "{} instanceof {}",
+                    subject,
+                    Self::js_ident(&name.lexeme)
+                )];
+                for (field, p) in fields {
+                    let sub = format!("{}.{}", subject, Self::js_ident(&field.lexeme));
+                    let c = self.emit_match_condition(p, &sub);
+                    if c != "true" {
+                        conds.push(c);
+                    }
+                }
+                conds.join(" && ")
+            }
+            MatchPattern::Array {
+                prefix,
+                rest,
+                suffix,
+            } => {
+                let fixed = prefix.len() + suffix.len();
+                let op = if rest.is_some() { ">=" } else { "===" };
+                let mut conds = vec![format!(
+                    "Array.isArray({s}) && {s}.length {op} {fixed}",
+                    s = subject
+                )];
+                for (sub, p) in Self::array_pattern_subjects(prefix, suffix, subject) {
+                    let c = self.emit_match_condition(p, &sub);
+                    if c != "true" {
+                        conds.push(c);
+                    }
+                }
+                conds.join(" && ")
+            }
+            MatchPattern::Or(alts) => {
+                let conds: Vec<String> = alts
+                    .iter()
+                    .map(|p| format!("({})", self.emit_match_condition(p, subject)))
+                    .collect();
+                format!("({})", conds.join(" || "))
+            }
+            MatchPattern::Range {
+                start,
+                end,
+                inclusive,
+            } => {
+                let op = if *inclusive { "<=" } else { "<" };
+                format!(
+                    "({s} >= {} && {s} {op} {})",
+                    Self::emit_value_static(start),
+                    Self::emit_value_static(end),
+                    s = subject
+                )
+            }
+            MatchPattern::At { pattern, .. } => self.emit_match_condition(pattern, subject),
         }
     }
 
+    /// Subject expressions of the fixed elements of an array pattern:
+    /// the prefix by index, the suffix counted from the end.
+    fn array_pattern_subjects<'p>(
+        prefix: &'p [MatchPattern],
+        suffix: &'p [MatchPattern],
+        subject: &str,
+    ) -> Vec<(String, &'p MatchPattern)> {
+        let head = prefix
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (format!("{}[{}]", subject, i), p));
+        let tail = suffix.iter().enumerate().map(|(i, p)| {
+            (
+                format!("{s}[{s}.length - {}]", suffix.len() - i, s = subject),
+                p,
+            )
+        });
+        head.chain(tail).collect()
+    }
+
     fn emit_match_bindings(&self, pattern: &MatchPattern, subject: &str) -> String {
+        self.match_binding_exprs(pattern, subject)
+            .into_iter()
+            .map(|(name, value)| format!("const {} = {};", name, value))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// `(name, expression)` pairs bound by `pattern` when matched against `subject`.
+    fn match_binding_exprs(&self, pattern: &MatchPattern, subject: &str) -> Vec<(String, String)> {
         match pattern {
             MatchPattern::Variable(tok) | MatchPattern::Binding(tok) => {
-                let name = Self::js_ident(&tok.lexeme);
-                format!("const {} = {};", name, subject)
+                vec![(Self::js_ident(&tok.lexeme), subject.to_string())]
             }
             MatchPattern::EnumVariant {
                 params: Some(pats), ..
+            } => pats
+                .iter()
+                .enumerate()
+                .flat_map(|(i, p)| {
+                    let sub = format!("{}.payload[{}]", subject, i);
+                    self.match_binding_exprs(p, &sub)
+                })
+                .collect(),
+            MatchPattern::Tuple(pats) => pats
+                .iter()
+                .enumerate()
+                .flat_map(|(i, p)| {
+                    let sub = format!("{}[{}]", subject, i);
+                    self.match_binding_exprs(p, &sub)
+                })
+                .collect(),
+            MatchPattern::Struct { fields, .. } => fields
+                .iter()
+                .flat_map(|(field, p)| {
+                    let sub = format!("{}.{}", subject, Self::js_ident(&field.lexeme));
+                    self.match_binding_exprs(p, &sub)
+                })
+                .collect(),
+            MatchPattern::Array {
+                prefix,
+                rest,
+                suffix,
             } => {
-                let bindings: Vec<String> = pats
-                    .iter()
-                    .enumerate()
-                    .map(|(i, p)| {
-                        let sub = format!("{}.payload[{}]", subject, i);
-                        self.emit_match_bindings(p, &sub)
-                    })
-                    .filter(|s| !s.is_empty())
-                    .collect();
-                bindings.join(" ")
+                let mut out: Vec<(String, String)> =
+                    Self::array_pattern_subjects(prefix, suffix, subject)
+                        .into_iter()
+                        .flat_map(|(sub, p)| self.match_binding_exprs(p, &sub))
+                        .collect();
+                if let Some(Some(name)) = rest {
+                    let slice = if suffix.is_empty() {
+                        format!("{}.slice({})", subject, prefix.len())
+                    } else {
+                        format!(
+                            "{s}.slice({}, {s}.length - {})",
+                            prefix.len(),
+                            suffix.len(),
+                            s = subject
+                        )
+                    };
+                    out.push((Self::js_ident(&name.lexeme), slice));
+                }
+                out
             }
-            MatchPattern::Tuple(pats) => {
-                let bindings: Vec<String> = pats
-                    .iter()
-                    .enumerate()
-                    .map(|(i, p)| {
-                        let sub = format!("{}[{}]", subject, i);
-                        self.emit_match_bindings(p, &sub)
-                    })
-                    .filter(|s| !s.is_empty())
-                    .collect();
-                bindings.join(" ")
+            MatchPattern::Or(alts) => {
+                // Todas as alternativas ligam os mesmos nomes (o parser garante);
+                // o valor vem da primeira alternativa que casar.
+                let Some((last, init)) = alts.split_last() else {
+                    return Vec::new();
+                };
+                let mut out = self.match_binding_exprs(last, subject);
+                for alt in init.iter().rev() {
+                    let cond = self.emit_match_condition(alt, subject);
+                    let exprs = self.match_binding_exprs(alt, subject);
+                    for (name, value) in out.iter_mut() {
+                        if let Some((_, e)) = exprs.iter().find(|(n, _)| n == name) {
+                            *value = format!("({}) ? {} : {}", cond, e, value);
+                        }
+                    }
+                }
+                out
+            }
+            MatchPattern::At { name, pattern } => {
+                let mut out = vec![(Self::js_ident(&name.lexeme), subject.to_string())];
+                out.extend(self.match_binding_exprs(pattern, subject));
+                out
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Binding target of `for (k, v) in ...`: `[k, v]`, with `_` elided.
+    fn js_destructure(pattern: &MatchPattern) -> String {
+        match pattern {
+            MatchPattern::Variable(t) | MatchPattern::Binding(t) => Self::js_ident(&t.lexeme),
+            MatchPattern::Tuple(items) => {
+                let parts: Vec<String> = items.iter().map(Self::js_destructure).collect();
+                format!("[{}]", parts.join(", "))
             }
             _ => String::new(),
         }
@@ -1072,13 +2171,42 @@ impl CodegenJs {
         match expr {
             Expr::Literal(val) => Self::emit_value_static(val),
 
-            Expr::Variable { name } => Self::js_ident(&name.lexeme),
+            // Em métodos (`impl`, defaults de trait) o receptor é o `this` do JS.
+            Expr::Variable { name } if name.lexeme == "self" => "this".to_string(),
+            Expr::Variable { name } => match self.consts.get(&name.lexeme) {
+                Some(
+                    value @ (ArtValue::Int(_)
+                    | ArtValue::Float(_)
+                    | ArtValue::Bool(_)
+                    | ArtValue::String(_)),
+                ) => {
+                    let js = Self::emit_value_static(value);
+                    if js.starts_with('-') {
+                        format!("({})", js)
+                    } else {
+                        js
+                    }
+                }
+                _ => Self::js_ident(&name.lexeme),
+            },
 
             Expr::Grouping { expression } => {
                 let inner = self.emit_expr(expression);
                 format!("({})", inner)
             }
 
+            Expr::Range {
+                start,
+                end,
+                inclusive,
+                ..
+            } => {
+                let s = self.emit_expr(start);
+                let e = self.emit_expr(end);
+                self.used_helpers.insert(HELPER_RANGE);
+                format!("__art_range({}, {}, {})", s, e, inclusive)
+            }
+
             Expr::Binary {
                 left,
                 operator,
@@ -1086,8 +2214,12 @@ impl CodegenJs {
             } => {
                 let l = self.emit_expr(left);
                 let r = self.emit_expr(right);
-                let op = Self::map_operator(&operator.lexeme);
-                format!("{} {} {}", l, op, r)
+                self.emit_binary(
+                    &operator.token_type,
+                    &operator.lexeme,
+                    (left, &l),
+                    (right, &r),
+                )
             }
 
             Expr::Logical {
@@ -1107,6 +2239,20 @@ impl CodegenJs {
 
             Expr::Unary { operator, right } => {
                 let r = self.emit_expr(right);
+                if operator.lexeme == "~" {
+                    self.used_helpers.insert(HELPER_BITS);
+                    return format!("__art_bits(\"~\", {})", r);
+                }
+                if operator.lexeme == "-" && self.overloaded(NEG_METHOD, right) {
+                    self.use_overload_helper(HELPER_NEG);
+                    return format!("__art_neg({})", r);
+                }
+                if operator.lexeme == "-" && self.num_kind(right) == Some(NumKind::Float) {
+                    return format!("-{}", r);
+                }
+                if operator.lexeme == "-" && !matches!(**right, Expr::Literal(_)) {
+                    return self.emit_arith("-", "0n", &r);
+                }
                 let op = match operator.lexeme.as_str() {
                     "not" => "!",
                     other => other,
@@ -1117,6 +2263,38 @@ impl CodegenJs {
             Expr::Call {
                 callee, arguments, ..
             } => {
+                if let Expr::Variable { name } = &**callee
+                    && self.newtypes.contains(&name.lexeme)
+                    && let [inner] = arguments.as_slice()
+                {
+                    return format!("({})", self.emit_expr(inner));
+                }
+                if let Expr::Variable { name } = &**callee
+                    && self.structs.get(&name.lexeme).is_some_and(|d| d.tuple)
+                {
+                    let args: Vec<String> = arguments.iter().map(|a| self.emit_expr(a)).collect();
+                    return format!("new {}({})", name.lexeme, args.join(", "));
+                }
+                if let Expr::Variable { name } = &**callee
+                    && INT_OP_BUILTINS.contains(&name.lexeme.as_str())
+                {
+                    self.used_helpers.insert(HELPER_INT_OPS);
+                }
+                if let Expr::FieldAccess { object, field } = &**callee
+                    && BUILTIN_METHODS
+                        .iter()
+                        .any(|m| m.name == field.lexeme && !m.js.is_empty())
+                {
+                    self.used_helpers.insert(HELPER_METHOD.as_str());
+                    let obj = self.emit_expr(object);
+                    let args: Vec<String> = arguments.iter().map(|a| self.emit_expr(a)).collect();
+                    return format!(
+                        "__art_method({}, \"{}\", [{}])",
+                        obj,
+                        field.lexeme,
+                        args.join(", ")
+                    );
+                }
                 let fn_expr = self.emit_expr(callee);
                 let args: Vec<String> = arguments.iter().map(|a| self.emit_expr(a)).collect();
                 format!("{}({})", fn_expr, args.join(", "))
@@ -1124,10 +2302,77 @@ impl CodegenJs {
 
             Expr::FieldAccess { object, field } => {
                 let obj = self.emit_expr(object);
+                // `t.0` on tuples (arrays) and tuple structs.
+                if field.lexeme.parse::<usize>().is_ok() {
+                    return format!("{}[{}]", obj, field.lexeme);
+                }
                 let f = Self::js_ident(&field.lexeme);
                 format!("{}.{}", obj, f)
             }
 
+            Expr::OptionalChain {
+                object,
+                field,
+                arguments,
+            } => {
+                self.used_helpers.insert(HELPER_OPT);
+                let obj = self.emit_expr(object);
+                let f = Self::js_ident(&field.lexeme);
+                match arguments {
+                    // A builtin method (`s?.len()`) has no JS method of that name:
+                    // it dispatches through `__art_method` once the receiver is there.
+                    Some(arguments)
+                        if BUILTIN_METHODS
+                            .iter()
+                            .any(|m| m.name == field.lexeme && !m.js.is_empty()) =>
+                    {
+                        self.used_helpers.insert(HELPER_METHOD.as_str());
+                        let args: Vec<String> =
+                            arguments.iter().map(|a| self.emit_expr(a)).collect();
+                        format!(
+                            "((__t) => (__t == null ? undefined : __art_method(__t, \"{}\", [{}])))(__art_opt({}))",
+                            field.lexeme,
+                            args.join(", "),
+                            obj
+                        )
+                    }
+                    Some(arguments) => {
+                        let args: Vec<String> =
+                            arguments.iter().map(|a| self.emit_expr(a)).collect();
+                        format!("__art_opt({})?.{}({})", obj, f, args.join(", "))
+                    }
+                    None => format!("__art_opt({})?.{}", obj, f),
+                }
+            }
+
+            Expr::Coalesce { left, right, .. } => {
+                self.used_helpers.insert(HELPER_OPT);
+                let l = self.emit_expr(left);
+                let r = self.emit_expr(right);
+                format!("(__art_opt({}) ?? {})", l, r)
+            }
+
+            Expr::Index { object, index, .. } => {
+                self.used_helpers.insert(HELPER_INDEX);
+                let obj = self.emit_expr(object);
+                let idx = self.emit_expr(index);
+                format!("__art_index({}, {})", obj, idx)
+            }
+
+            Expr::Slice {
+                object, start, end, ..
+            } => {
+                self.used_helpers.insert(HELPER_SLICE);
+                let obj = self.emit_expr(object);
+                let bound = |this: &mut Self, b: &Option<Box<Expr>>| match b {
+                    Some(e) => this.emit_expr(e),
+                    None => "undefined".to_string(),
+                };
+                let from = bound(self, start);
+                let to = bound(self, end);
+                format!("__art_slice({}, {}, {})", obj, from, to)
+            }
+
             Expr::Array(items) => {
                 let elems: Vec<String> = items.iter().map(|e| self.emit_expr(e)).collect();
                 format!("[{}]", elems.join(", "))
@@ -1138,11 +2383,50 @@ impl CodegenJs {
                 format!("[{}]", elems.join(", "))
             }
 
-            Expr::StructInit { name, fields } => {
+            Expr::StructInit { name, fields, base } => {
                 let cname = &name.lexeme;
-                let field_args: Vec<String> =
-                    fields.iter().map(|(_, v)| self.emit_expr(v)).collect();
-                format!("new {}({})", cname, field_args.join(", "))
+                let base = base.as_ref().map(|b| self.emit_expr(b));
+                let Some(decl) = self.structs.get(cname).cloned() else {
+                    // Declared in another module: fields in the order written.
+                    let field_args: Vec<String> =
+                        fields.iter().map(|(_, v)| self.emit_expr(v)).collect();
+                    return match base {
+                        Some(b) => {
+                            let assigns: Vec<String> = fields
+                                .iter()
+                                .zip(&field_args)
+                                .map(|((f, _), v)| format!("{}: {}", Self::js_ident(&f.lexeme), v))
+                                .collect();
+                            format!(
+                                "Object.assign(Object.create(Object.getPrototypeOf({b})), {b}, {{ {} }})",
+                                assigns.join(", "),
+                                b = b
+                            )
+                        }
+                        None => format!("new {}({})", cname, field_args.join(", ")),
+                    };
+                };
+                // Constructor order; omitted fields come from `..base` or the default.
+                let field_args: Vec<String> = decl
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let given = fields.iter().find(|(f, _)| f.lexeme == field.name.lexeme);
+                        match (given, &base, &field.default) {
+                            (Some((_, v)), _, _) => self.emit_expr(v),
+                            (None, Some(_), _) => {
+                                format!("__base.{}", Self::js_ident(&field.name.lexeme))
+                            }
+                            (None, None, Some(default)) => self.emit_expr(default),
+                            (None, None, None) => "undefined".to_string(),
+                        }
+                    })
+                    .collect();
+                let init = format!("new {}({})", cname, field_args.join(", "));
+                match base {
+                    Some(b) => format!("((__base) => {})({})", init, b),
+                    None => init,
+                }
             }
 
             Expr::EnumInit {
@@ -1165,7 +2449,11 @@ impl CodegenJs {
                             content.push_str(&s.replace('`', "\\`").replace("${", "\\${"));
                         }
                         InterpolatedPart::Expr { expr, .. } => {
-                            let inner = self.emit_expr(expr);
+                            let mut inner = self.emit_expr(expr);
+                            if self.overloaded(TO_STRING_METHOD, expr) {
+                                self.use_overload_helper(HELPER_STR);
+                                inner = format!("__art_str({})", inner);
+                            }
                             content.push_str(&format!("${{{}}}", inner));
                         }
                     }
@@ -1190,11 +2478,21 @@ impl CodegenJs {
 
             Expr::UnownedAccess(inner) => self.emit_expr(inner),
 
+            Expr::Await { value, .. } => format!("await {}", self.emit_expr(value)),
+
             Expr::SpawnActor { body } => {
                 let mut inner = CodegenJs::new(CodegenOptions {
                     emit_source_map: false,
+                    overflow: self.options.overflow,
                     ..Default::default()
                 });
+                inner.consts = self.consts.clone();
+                inner.newtypes = self.newtypes.clone();
+                inner.structs = self.structs.clone();
+                inner.overloads = self.overloads.clone();
+                inner.types = self.types.clone();
+                inner.num_kinds = self.num_kinds.clone();
+                inner.fn_kinds = self.fn_kinds.clone();
                 for s in body {
                     inner.emit_stmt(s);
                 }
@@ -1206,7 +2504,53 @@ impl CodegenJs {
             }
 
             Expr::Template(nodes) => self.emit_template_iife(nodes),
+
+            Expr::Lambda { params, body, .. } => self.emit_lambda(params, body),
+        }
+    }
+
+    /// Lambdas become arrow functions. A `|x| expr` body (desugared to a lone
+    /// `return`) stays a concise arrow; block bodies go through a nested
+    /// generator, like `spawn actor`, at the current indentation.
+    fn emit_lambda(&mut self, params: &[core::ast::FunctionParam], body: &Stmt) -> String {
+        let pnames: Vec<String> = params
+            .iter()
+            .map(|p| Self::js_ident(&p.name.lexeme))
+            .collect();
+        let head = format!("({}) =>", pnames.join(", "));
+        if let Stmt::Block { statements } = body
+            && let [Stmt::Return { value: Some(value) }] = statements.as_slice()
+        {
+            let kinds = self.num_kinds.clone();
+            self.bind_params(params);
+            let js = self.emit_expr(value);
+            self.num_kinds = kinds;
+            // A leading `{` would open a block instead of an object literal.
+            return if js.starts_with('{') {
+                format!("{} ({})", head, js)
+            } else {
+                format!("{} {}", head, js)
+            };
         }
+        let mut inner = CodegenJs::new(CodegenOptions {
+            emit_source_map: false,
+            overflow: self.options.overflow,
+            ..Default::default()
+        });
+        inner.consts = self.consts.clone();
+        inner.newtypes = self.newtypes.clone();
+        inner.structs = self.structs.clone();
+        inner.overloads = self.overloads.clone();
+        inner.types = self.types.clone();
+        inner.num_kinds = self.num_kinds.clone();
+        inner.fn_kinds = self.fn_kinds.clone();
+        inner.bind_params(params);
+        inner.indent = self.indent;
+        inner.state_names = self.state_names.clone();
+        inner.reactive_names = self.reactive_names.clone();
+        inner.emit_stmt_inline(body);
+        self.used_helpers.append(&mut inner.used_helpers);
+        format!("{} {}", head, inner.output)
     }
 
     // ── ArtML template codegen ────────────────────────────────────────────────
@@ -1498,7 +2842,7 @@ impl CodegenJs {
 
     fn emit_value_static(val: &ArtValue) -> String {
         match val {
-            ArtValue::Int(n) => n.to_string(),
+            ArtValue::Int(n) => format!("{}n", n),
             ArtValue::Float(f) => {
                 if f.fract() == 0.0 {
                     format!("{:.1}", f)
@@ -1546,6 +2890,44 @@ impl CodegenJs {
     }
 }
 
+/// Operator methods defined by the program's `impl` blocks and trait defaults.
+fn operator_overloads(program: &[Stmt]) -> std::collections::HashSet<String> {
+    let is_operator = |name: &core::Token| OPERATOR_METHODS.contains(&name.lexeme.as_str());
+    let mut found = std::collections::HashSet::new();
+    for stmt in program {
+        match stmt {
+            Stmt::ImplBlock { methods, .. } => {
+                for m in methods {
+                    if let Stmt::Function { name, .. } = m
+                        && is_operator(name)
+                    {
+                        found.insert(name.lexeme.clone());
+                    }
+                }
+            }
+            Stmt::TraitDecl { methods, .. } => {
+                for m in methods {
+                    if m.body.is_some() && is_operator(&m.name) {
+                        found.insert(m.name.lexeme.clone());
+                    }
+                }
+            }
+            // `@derive(Eq)` and `@derive(Ord)` generate `eq` and `cmp`.
+            Stmt::StructDecl { derives, .. } => {
+                for derive in derives {
+                    match derive.lexeme.as_str() {
+                        "Eq" => found.insert("eq".to_string()),
+                        "Ord" => found.insert("cmp".to_string()),
+                        _ => continue,
+                    };
+                }
+            }
+            _ => {}
+        }
+    }
+    found
+}
+
 impl Default for CodegenJs {
     fn default() -> Self {
         Self::new(CodegenOptions::default())
@@ -1574,10 +2956,11 @@ mod tests {
         let stmts = vec![Stmt::Let {
             pattern: MatchPattern::Variable(tok("x")),
             ty: None,
+            mutable: false,
             initializer: Expr::Literal(ArtValue::Int(42)),
         }];
         let js = emit(stmts);
-        assert!(js.contains("const x = 42;"), "got: {}", js);
+        assert!(js.contains("const x = 42n;"), "got: {}", js);
     }
 
     #[test]
@@ -1585,6 +2968,7 @@ mod tests {
         let stmts = vec![Stmt::Let {
             pattern: MatchPattern::Variable(tok("greeting")),
             ty: None,
+            mutable: false,
             initializer: Expr::Literal(ArtValue::String(std::sync::Arc::from("hello"))),
         }];
         let js = emit(stmts);
@@ -1609,10 +2993,11 @@ mod tests {
             body: Rc::new(body),
             method_owner: None,
             is_async: false,
+            public: false,
         }];
         let js = emit(stmts);
         assert!(js.contains("function greet(name)"), "got: {}", js);
-        assert!(js.contains("return 1;"), "got: {}", js);
+        assert!(js.contains("return 1n;"), "got: {}", js);
     }
 
     #[test]
@@ -1630,18 +3015,52 @@ mod tests {
     fn for_loop() {
         let stmts = vec![Stmt::For {
             element: tok("item"),
+            pattern: None,
             iterator: Expr::Variable { name: tok("items") },
             body: Box::new(Stmt::Block { statements: vec![] }),
+            label: None,
         }];
         let js = emit(stmts);
         assert!(js.contains("for (const item of items)"), "got: {}", js);
     }
 
+    #[test]
+    fn labeled_loop_with_break_and_continue() {
+        let stmts = vec![Stmt::While {
+            condition: Expr::Literal(ArtValue::Bool(true)),
+            body: Box::new(Stmt::Block {
+                statements: vec![
+                    Stmt::Continue {
+                        keyword: tok("continue"),
+                        label: None,
+                    },
+                    Stmt::Break {
+                        keyword: tok("break"),
+                        label: Some(tok("'outer")),
+                    },
+                ],
+            }),
+            label: Some(tok("'outer")),
+        }];
+        let js = emit(stmts);
+        assert!(js.contains("outer: while (true)"), "got: {}", js);
+        assert!(js.contains("continue;"), "got: {}", js);
+        assert!(js.contains("break outer;"), "got: {}", js);
+    }
+
     #[test]
     fn struct_decl() {
+        let field = |name: &str| StructField {
+            name: tok(name),
+            ty: "Int".to_string(),
+            default: None,
+        };
         let stmts = vec![Stmt::StructDecl {
             name: tok("Point"),
-            fields: vec![(tok("x"), "Int".to_string()), (tok("y"), "Int".to_string())],
+            fields: vec![field("x"), field("y")],
+            tuple: false,
+            derives: Vec::new(),
+            public: false,
         }];
         let js = emit(stmts);
         assert!(js.contains("class Point"), "got: {}", js);
@@ -1657,6 +3076,8 @@ mod tests {
                 (tok("Ok"), Some(vec!["Int".to_string()])),
                 (tok("Err"), None),
             ],
+            derives: Vec::new(),
+            public: false,
         }];
         let js = emit(stmts);
         assert!(js.contains("const Status"), "got: {}", js);
@@ -1681,13 +3102,34 @@ mod tests {
         let stmts = vec![Stmt::Let {
             pattern: MatchPattern::Variable(tok("arr")),
             ty: None,
+            mutable: false,
             initializer: Expr::Array(vec![
                 Expr::Literal(ArtValue::Int(1)),
                 Expr::Literal(ArtValue::Int(2)),
             ]),
         }];
         let js = emit(stmts);
-        assert!(js.contains("const arr = [1, 2];"), "got: {}", js);
+        assert!(js.contains("const arr = [1n, 2n];"), "got: {}", js);
+    }
+
+    #[test]
+    fn var_emits_let_and_assignment() {
+        let stmts = vec![
+            Stmt::Let {
+                pattern: MatchPattern::Variable(tok("n")),
+                ty: None,
+                mutable: true,
+                initializer: Expr::Literal(ArtValue::Int(0)),
+            },
+            Stmt::Assign {
+                target: Expr::Variable { name: tok("n") },
+                operator: tok("+="),
+                value: Expr::Literal(ArtValue::Int(2)),
+            },
+        ];
+        let js = emit(stmts);
+        assert!(js.contains("let n = 0n;"), "got: {}", js);
+        assert!(js.contains("n = __art_arith(\"+\", n, 2n);"), "got: {}", js);
     }
 
     #[test]
@@ -1701,6 +3143,7 @@ mod tests {
         let stmts = vec![Stmt::Let {
             pattern: MatchPattern::Variable(tok("x")),
             ty: None,
+            mutable: false,
             initializer: Expr::Literal(ArtValue::Int(1)),
         }];
         let out = CodegenJs::new(CodegenOptions {
@@ -0,0 +1,28 @@
+use codegen_js::{CodegenJs, CodegenOptions};
+use lexer::Lexer;
+use parser::Parser;
+
+fn compile(src: &str) -> String {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    CodegenJs::new(CodegenOptions::default())
+        .emit_program(&program)
+        .code
+}
+
+#[test]
+fn async_functions_emit_native_async_await() {
+    let js = compile(
+        "async func load(ms) { await sleep(ms); let x = await fetch_one(); return x; }\nstruct Api { n: Int }\nimpl Api { async func get(self) { return await load(self.n); } }",
+    );
+    assert!(js.contains("async function load(ms) "), "got: {}", js);
+    assert!(js.contains("await sleep(ms);"), "got: {}", js);
+    assert!(js.contains("const x = await fetch_one();"), "got: {}", js);
+    assert!(
+        js.contains("Api.prototype.get = async function() "),
+        "got: {}",
+        js
+    );
+    assert!(js.contains("return await load(this.n);"), "got: {}", js);
+}
@@ -0,0 +1,30 @@
+use codegen_js::{CodegenJs, CodegenOptions};
+use lexer::Lexer;
+use parser::Parser;
+
+fn compile(src: &str) -> String {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    CodegenJs::new(CodegenOptions::default())
+        .emit_program(&program)
+        .code
+}
+
+#[test]
+fn scalar_constants_are_inlined() {
+    let js = compile(
+        "const N: Int = 2 - 5;\nconst S: String = \"a\" + \"b\";\nfunc f(x) { return x * N; }\nprintln(S);",
+    );
+    assert!(js.contains("const N = -3n;"), "{}", js);
+    assert!(js.contains("const S = \"ab\";"), "{}", js);
+    assert!(js.contains("(-3n)"), "{}", js);
+    assert!(js.contains("println(\"ab\")"), "{}", js);
+}
+
+#[test]
+fn aggregate_constants_are_referenced_by_name() {
+    let js = compile("const P: [Int] = [1, 2];\nprintln(P[0]);");
+    assert!(js.contains("const P = [1n, 2n];"), "{}", js);
+    assert!(js.contains("__art_index(P, 0n)"), "{}", js);
+}
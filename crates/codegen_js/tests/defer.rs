@@ -0,0 +1,26 @@
+use codegen_js::{CodegenJs, CodegenOptions};
+use lexer::Lexer;
+use parser::Parser;
+
+fn compile(src: &str) -> String {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    CodegenJs::new(CodegenOptions::default())
+        .emit_program(&program)
+        .code
+}
+
+#[test]
+fn defer_wraps_the_rest_of_the_block_in_try_finally() {
+    let js =
+        compile("func f(h) { open(h); defer { close(h); } defer { flush(h); } return read(h); }");
+    let compact: String = js.split_whitespace().collect::<Vec<_>>().join(" ");
+    assert!(
+        compact.contains(
+            "open(h); try { try { return read(h); } finally { flush(h); } } finally { close(h); }"
+        ),
+        "got: {}",
+        js
+    );
+}
@@ -0,0 +1,36 @@
+use codegen_js::{CodegenJs, CodegenOptions};
+use lexer::Lexer;
+use parser::Parser;
+
+fn compile(src: &str) -> String {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    CodegenJs::new(CodegenOptions::default())
+        .emit_program(&program)
+        .code
+}
+
+#[test]
+fn functions_with_yield_become_js_generators() {
+    let js = compile(
+        "func counter(limit) { var i = 0; while i < limit { i += 1; yield i; } }\nfor x in counter(3) { println(x); }",
+    );
+    assert!(js.contains("function* counter(limit) "), "got: {}", js);
+    assert!(js.contains("yield i;"), "got: {}", js);
+    assert!(js.contains("for (const x of counter(3n))"), "got: {}", js);
+}
+
+#[test]
+fn only_the_function_that_yields_is_a_generator() {
+    let js = compile(
+        "func outer() { func inner() { yield 1; } return inner; }\nstruct Bag { n: Int }\nimpl Bag { func items(self) { yield self.n; } }",
+    );
+    assert!(js.contains("function outer() "), "got: {}", js);
+    assert!(js.contains("function* inner() "), "got: {}", js);
+    assert!(
+        js.contains("Bag.prototype.items = function*() "),
+        "got: {}",
+        js
+    );
+}
@@ -0,0 +1,47 @@
+use codegen_js::{CodegenJs, CodegenOptions};
+use lexer::Lexer;
+use parser::Parser;
+
+fn compile(src: &str) -> String {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    CodegenJs::new(CodegenOptions::default())
+        .emit_program(&program)
+        .code
+}
+
+#[test]
+fn index_uses_runtime_helper_emitted_once() {
+    let js = compile("let a = [1, 2];\nlet x = a[0];\nlet y = a[-1];");
+    assert!(js.contains("const x = __art_index(a, 0n);"), "got: {}", js);
+    assert!(js.contains("const y = __art_index(a, -1n);"), "got: {}", js);
+    assert_eq!(
+        js.matches("function __art_index(").count(),
+        1,
+        "got: {}",
+        js
+    );
+    assert!(!js.contains("function __art_slice("), "got: {}", js);
+}
+
+#[test]
+fn slice_passes_undefined_for_open_bounds() {
+    let js = compile("let s = a[..2];");
+    assert!(js.contains("__art_slice(a, undefined, 2n)"), "got: {}", js);
+    assert!(js.contains("function __art_slice("), "got: {}", js);
+}
+
+#[test]
+fn index_assignment_goes_through_setter_helper() {
+    let js = compile("a[1] = 5;\na[next()] += 2;");
+    assert!(js.contains("__art_set_index(a, 1n, 5n);"), "got: {}", js);
+    // Container e indice avaliados uma vez so, para a leitura e a escrita.
+    assert!(
+        js.contains("{ const __o = a, __i = next(); __art_set_index(__o, __i, __art_arith(\"+\", __art_index(__o, __i), 2n)); }"),
+        "got: {}",
+        js
+    );
+    assert_eq!(js.matches("next()").count(), 1, "got: {}", js);
+    assert!(js.contains("function __art_set_index("), "got: {}", js);
+}
@@ -0,0 +1,64 @@
+use codegen_js::{CodegenJs, CodegenOptions};
+use lexer::Lexer;
+use parser::Parser;
+
+fn compile(src: &str) -> String {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    CodegenJs::new(CodegenOptions::default())
+        .emit_program(&program)
+        .code
+}
+
+#[test]
+fn expression_lambda_is_a_concise_arrow() {
+    let js = compile("let f = |a, b| a + b;\nlet g = || 1;");
+    assert!(
+        js.contains("const f = (a, b) => __art_arith(\"+\", a, b);"),
+        "got: {}",
+        js
+    );
+    assert!(js.contains("const g = () => 1n;"), "got: {}", js);
+}
+
+#[test]
+fn block_lambda_keeps_its_statements() {
+    let js = compile("let f = func(x) {\n    let y = x * 2;\n    return y;\n};");
+    assert!(
+        js.contains(
+            "const f = (x) => {\n    const y = __art_arith(\"*\", x, 2n);\n    return y;\n};"
+        ),
+        "got: {}",
+        js
+    );
+}
+
+#[test]
+fn lambda_body_helpers_are_emitted() {
+    let js = compile("let last = |a| a[-1];\nlet bits = |a| { return a & 1; };");
+    assert!(js.contains("(a) => __art_index(a, -1n)"), "got: {}", js);
+    assert!(js.contains("function __art_index("), "got: {}", js);
+    assert!(js.contains("function __art_bits("), "got: {}", js);
+}
+
+#[test]
+fn inline_handler_assigns_state_through_setter() {
+    let src = r#"
+component Counter {
+    state n: Int = 0
+    view { <button on:click={|| { n += 1; }}>{n}</button> }
+}
+"#;
+    let js = compile(src);
+    assert!(
+        js.contains("set_n(__art_arith(\"+\", n, 1n));"),
+        "got: {}",
+        js
+    );
+    assert!(
+        js.contains("addEventListener(\"click\", () => {"),
+        "got: {}",
+        js
+    );
+}
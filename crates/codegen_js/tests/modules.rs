@@ -0,0 +1,48 @@
+use codegen_js::{CodegenJs, CodegenOptions, ModuleFormat};
+use lexer::Lexer;
+use parser::Parser;
+
+fn compile(src: &str, module_format: ModuleFormat) -> String {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let opts = CodegenOptions {
+        module_format,
+        ..CodegenOptions::default()
+    };
+    CodegenJs::new(opts).emit_program(&program).code
+}
+
+#[test]
+fn esm_exports_only_pub_items() {
+    let src = "pub func f() { return 1; }\nfunc g() { return 2; }\npub struct P { x: Int }\npub enum E { A }\npub const N: Int = 3;";
+    let js = compile(src, ModuleFormat::Esm);
+    assert!(js.contains("export function f()"), "{}", js);
+    assert!(js.contains("\nfunction g()"), "{}", js);
+    assert!(js.contains("export class P"), "{}", js);
+    assert!(js.contains("export const E = {"), "{}", js);
+    assert!(js.contains("export const N = 3n;"), "{}", js);
+
+    let bundled = compile(src, ModuleFormat::Bundle);
+    assert!(!bundled.contains("export"), "{}", bundled);
+}
+
+#[test]
+fn esm_imports_name_the_items_in_use() {
+    let js = compile(
+        "import util.{parse, Token};\nimport net.http as h;\nimport lib;\nprintln(h.get(parse(\"x\")));",
+        ModuleFormat::Esm,
+    );
+    assert!(
+        js.contains("import { parse, Token } from \"./util.js\";"),
+        "{}",
+        js
+    );
+    assert!(
+        js.contains("import { get } from \"./net/http.js\";"),
+        "{}",
+        js
+    );
+    assert!(js.contains("import * as lib from \"./lib.js\";"), "{}", js);
+    assert!(js.contains("println(get(parse(\"x\")))"), "{}", js);
+}
@@ -0,0 +1,234 @@
+use codegen_js::{CodegenJs, CodegenOptions};
+use core::OverflowMode;
+use lexer::Lexer;
+use parser::Parser;
+
+fn compile(src: &str) -> String {
+    compile_with(src, OverflowMode::Trap)
+}
+
+fn compile_with(src: &str, overflow: OverflowMode) -> String {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    CodegenJs::new(CodegenOptions {
+        overflow,
+        ..Default::default()
+    })
+    .emit_program(&program)
+    .code
+}
+
+#[test]
+fn bitwise_operators_go_through_int64_helper() {
+    let js = compile("let x = a & b == 0;\nlet y = ~a << 2;");
+    // A call keeps Art precedence: JS would parse `a & b == 0` as `a & (b == 0)`.
+    assert!(
+        js.contains("const x = __art_bits(\"&\", a, b) === 0n;"),
+        "got: {}",
+        js
+    );
+    assert!(
+        js.contains("const y = __art_bits(\"<<\", __art_bits(\"~\", a), 2n);"),
+        "got: {}",
+        js
+    );
+    assert_eq!(js.matches("function __art_bits(").count(), 1, "got: {}", js);
+    assert!(js.contains("BigInt.asIntN(64"), "got: {}", js);
+}
+
+#[test]
+fn int_division_and_remainder_go_through_div_helper() {
+    let js = compile("let q = a / b;\nlet r = a % 3;\nlet f = a / 2.0;\nvar n = 9;\nn /= 2;");
+    assert!(
+        js.contains("const q = __art_div(\"/\", a, b);"),
+        "got: {}",
+        js
+    );
+    assert!(
+        js.contains("const r = __art_div(\"%\", a, 3n);"),
+        "got: {}",
+        js
+    );
+    assert!(js.contains("n = __art_div(\"/\", n, 2n);"), "got: {}", js);
+    // Um literal Float nunca eh Int: divisao nativa.
+    assert!(js.contains("const f = Number(a) / 2.0;"), "got: {}", js);
+    assert_eq!(js.matches("function __art_div(").count(), 1, "got: {}", js);
+    assert!(js.contains("b === 0n"), "got: {}", js);
+    assert!(
+        js.contains("throw new RangeError(`Division by zero"),
+        "got: {}",
+        js
+    );
+    assert!(!js.contains("__art_bits"), "got: {}", js);
+
+    let wrapped = compile_with("let q = a / b;", OverflowMode::Wrap);
+    assert!(wrapped.contains("Division by zero"), "got: {}", wrapped);
+    assert!(!wrapped.contains("Integer overflow"), "got: {}", wrapped);
+}
+
+#[test]
+fn float_operands_are_picked_from_annotations() {
+    let js = compile(
+        "func halve(a: Float, b: Float) -> Float {\n    return a / b;\n}\nfunc rem(a: Float, n: Int) {\n    return a % n;\n}\nfunc idiv(a: Int, b: Int) {\n    return a / b;\n}\nlet h = halve(7.0, 2.0) * 2;\nvar acc: Float = 1.0;\nacc /= 4;\nlet x = -acc;",
+    );
+    assert!(js.contains("return a / b;\n}\nfunction rem"), "got: {}", js);
+    assert!(js.contains("return a % Number(n);"), "got: {}", js);
+    assert!(js.contains("return __art_div(\"/\", a, b);"), "got: {}", js);
+    // Retorno `-> Float` e literal `Int` promovido no lugar.
+    assert!(js.contains("const h = halve(7.0, 2.0) * 2;"), "got: {}", js);
+    assert!(js.contains("acc = acc / 4;"), "got: {}", js);
+    assert!(js.contains("const x = -acc;"), "got: {}", js);
+    // Parametros sem anotacao nao tem tipo conhecido: o helper decide em runtime.
+    let js = compile("func f(a: Float) {\n    return a;\n}\nfunc g(a) {\n    return a / 2;\n}");
+    assert!(
+        js.contains("return __art_div(\"/\", a, 2n);"),
+        "got: {}",
+        js
+    );
+}
+
+#[test]
+fn int_arithmetic_goes_through_overflow_helper() {
+    let js = compile("let x = a + b * c;\nlet y = -a;\nlet s = \"n=\" + a;\nlet f = a * 0.5;");
+    assert!(
+        js.contains("const x = __art_arith(\"+\", a, __art_arith(\"*\", b, c));"),
+        "got: {}",
+        js
+    );
+    assert!(
+        js.contains("const y = __art_arith(\"-\", 0n, a);"),
+        "got: {}",
+        js
+    );
+    // Um literal String/Float nunca eh Int: operador nativo.
+    assert!(js.contains("const s = \"n=\" + a;"), "got: {}", js);
+    assert!(js.contains("const f = Number(a) * 0.5;"), "got: {}", js);
+    assert!(
+        js.contains("throw new RangeError(`Integer overflow"),
+        "got: {}",
+        js
+    );
+
+    let wrapped = compile_with("let x = a + 1;", OverflowMode::Wrap);
+    assert!(
+        wrapped.contains("return BigInt.asIntN(64,"),
+        "got: {}",
+        wrapped
+    );
+    assert!(!wrapped.contains("Integer overflow"), "got: {}", wrapped);
+}
+
+#[test]
+fn arithmetic_builtins_emit_their_runtime() {
+    let js = compile("let a = checked_add(x, 1);\nlet b = saturating_mul(x, 2);");
+    assert_eq!(
+        js.matches("function checked_add(").count(),
+        1,
+        "got: {}",
+        js
+    );
+    assert!(js.contains("function saturating_mul("), "got: {}", js);
+    assert!(!compile("let a = x;").contains("function checked_add("));
+}
+
+const MONEY: &str = "struct Money { cents: Int }\nimpl Money {\n    func add(self, o: Money) -> Money { return o; }\n    func cmp(self, o: Money) -> Int { return 0; }\n    func to_string(self) -> String { return \"m\"; }\n}\n";
+
+#[test]
+fn compound_assignment_dispatches_like_the_binary_operator() {
+    let js = compile(&format!("{}var d = a;\nd += b;\nxs[0] += b;", MONEY));
+    assert!(
+        js.contains("d = __art_binop(\"+\", \"add\", d, b);"),
+        "got: {}",
+        js
+    );
+    assert!(
+        js.contains("__art_binop(\"+\", \"add\", __art_index(__o, __i), b)"),
+        "got: {}",
+        js
+    );
+    // O valor fica agrupado quando o operador externo eh nativo.
+    let js = compile("var f: Float = 1.0;\nf *= f + 1.0;");
+    assert!(js.contains("f = f * (f + 1.0);"), "got: {}", js);
+}
+
+#[test]
+fn overloaded_operators_dispatch_through_helpers() {
+    let js = compile(&format!(
+        "{}let s = a + b;\nlet lt = a < b;\nlet n = -a;\nlet t = f\"{{a}}!\";\nlet k = 1 + 2;",
+        MONEY
+    ));
+    assert!(
+        js.contains("const s = __art_binop(\"+\", \"add\", a, b);"),
+        "got: {}",
+        js
+    );
+    assert!(
+        js.contains("const lt = __art_binop(\"<\", \"cmp\", a, b);"),
+        "got: {}",
+        js
+    );
+    // `neg` is not overloaded, so `-a` keeps the plain path.
+    assert!(
+        js.contains("const n = __art_arith(\"-\", 0n, a);"),
+        "got: {}",
+        js
+    );
+    assert!(js.contains("const t = `${__art_str(a)}!`;"), "got: {}", js);
+    // A literal left operand is never an instance.
+    assert!(
+        js.contains("const k = __art_arith(\"+\", 1n, 2n);"),
+        "got: {}",
+        js
+    );
+    assert!(js.contains("function __art_binop(op, m, a, b)"));
+    assert!(js.contains("function __art_has_method(v, m)"));
+    assert!(js.contains("function __art_div(op, a, b)"));
+}
+
+#[test]
+fn programs_without_overloads_emit_plain_operators() {
+    let js = compile("let s = a + b;\nlet lt = a < b;\nlet t = f\"{a}\";");
+    assert!(!js.contains("__art_binop"), "got: {}", js);
+    assert!(!js.contains("__art_str"), "got: {}", js);
+    assert!(js.contains("const lt = a < b;"), "got: {}", js);
+}
+
+#[test]
+fn optional_chaining_and_coalescing_map_to_js_operators() {
+    let js = compile("let city = user?.address?.city ?? \"?\";\nlet hi = user?.greet(1);");
+    assert!(
+        js.contains(
+            "const city = (__art_opt(__art_opt(__art_opt(user)?.address)?.city) ?? \"?\");"
+        ),
+        "got: {}",
+        js
+    );
+    assert!(
+        js.contains("const hi = __art_opt(user)?.greet(1n);"),
+        "got: {}",
+        js
+    );
+    assert_eq!(js.matches("function __art_opt(").count(), 1, "got: {}", js);
+
+    // Metodo embutido: passa por `__art_method` quando o receptor existe.
+    let js = compile("let n = name?.len();\nlet p = s?.split(\",\");");
+    assert!(
+        js.contains("const n = ((__t) => (__t == null ? undefined : __art_method(__t, \"len\", [])))(__art_opt(name));"),
+        "got: {}",
+        js
+    );
+    assert!(
+        js.contains("__art_method(__t, \"split\", [\",\"])"),
+        "got: {}",
+        js
+    );
+}
+
+#[test]
+fn builtin_methods_go_through_method_helper() {
+    let js = compile("let s = \"a,b\";\nlet parts = s.split(\",\");\nlet n = parts.len();\n");
+    assert!(js.contains("__art_method(s, \"split\", [\",\"])"), "{}", js);
+    assert!(js.contains("__art_method(parts, \"len\", [])"), "{}", js);
+    assert_eq!(js.matches("function __art_method(").count(), 1);
+}
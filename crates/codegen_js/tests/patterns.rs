@@ -0,0 +1,75 @@
+use codegen_js::{CodegenJs, CodegenOptions};
+use lexer::Lexer;
+use parser::Parser;
+
+fn compile(src: &str) -> String {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    CodegenJs::new(CodegenOptions::default())
+        .emit_program(&program)
+        .code
+}
+
+#[test]
+fn struct_and_array_patterns() {
+    let js = compile(
+        "match v { case Point { x, y: 0 }: println(x) case [first, ..rest, last]: println(rest) }",
+    );
+    assert!(
+        js.contains("__match_val instanceof Point && __match_val.y === 0"),
+        "got: {}",
+        js
+    );
+    assert!(js.contains("const x = __match_val.x;"), "got: {}", js);
+    assert!(
+        js.contains("Array.isArray(__match_val) && __match_val.length >= 2"),
+        "got: {}",
+        js
+    );
+    assert!(
+        js.contains("const last = __match_val[__match_val.length - 1];"),
+        "got: {}",
+        js
+    );
+    assert!(
+        js.contains("const rest = __match_val.slice(1, __match_val.length - 1);"),
+        "got: {}",
+        js
+    );
+}
+
+#[test]
+fn or_range_and_at_patterns() {
+    let js = compile(
+        "match n { case 0 | 1: println(n) case d @ 2..=9: println(d) case (0, x) | (x, 0): println(x) }",
+    );
+    assert!(
+        js.contains("((__match_val === 0n) || (__match_val === 1n))"),
+        "got: {}",
+        js
+    );
+    assert!(
+        js.contains("(__match_val >= 2n && __match_val <= 9n)"),
+        "got: {}",
+        js
+    );
+    assert!(js.contains("const d = __match_val;"), "got: {}", js);
+    assert!(
+        js.contains("const x = (__match_val[0] === 0n) ? __match_val[1] : __match_val[0];"),
+        "got: {}",
+        js
+    );
+}
+
+#[test]
+fn let_destructures_arrays_through_a_temporary() {
+    let js = compile("let [a, ..r] = xs;");
+    assert!(
+        js.contains(
+            "const __destructure_0 = xs; const a = __destructure_0[0]; const r = __destructure_0.slice(1);"
+        ),
+        "got: {}",
+        js
+    );
+}
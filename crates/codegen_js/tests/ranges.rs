@@ -0,0 +1,47 @@
+use codegen_js::{CodegenJs, CodegenOptions};
+use lexer::Lexer;
+use parser::Parser;
+
+fn compile(src: &str) -> String {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    CodegenJs::new(CodegenOptions::default())
+        .emit_program(&program)
+        .code
+}
+
+#[test]
+fn range_uses_lazy_helper_emitted_once() {
+    let js = compile("for i in 0..n { }\nlet r = 1..=3;");
+    assert!(
+        js.contains("for (const i of __art_range(0n, n, false))"),
+        "got: {}",
+        js
+    );
+    assert!(
+        js.contains("const r = __art_range(1n, 3n, true);"),
+        "got: {}",
+        js
+    );
+    assert_eq!(
+        js.matches("function __art_range(").count(),
+        1,
+        "got: {}",
+        js
+    );
+}
+
+#[test]
+fn for_tuple_pattern_destructures() {
+    let js = compile("for (k, v) in m { }\nfor (_, (a, b)) in xs { }");
+    assert!(js.contains("for (const [k, v] of m)"), "got: {}", js);
+    assert!(js.contains("for (const [, [a, b]] of xs)"), "got: {}", js);
+}
+
+#[test]
+fn plain_for_is_unchanged() {
+    let js = compile("for x in xs { }");
+    assert!(js.contains("for (const x of xs)"), "got: {}", js);
+    assert!(!js.contains("__art_range"), "got: {}", js);
+}
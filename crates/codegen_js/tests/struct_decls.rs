@@ -0,0 +1,58 @@
+use codegen_js::{CodegenJs, CodegenOptions};
+use lexer::Lexer;
+use parser::Parser;
+
+fn compile(src: &str) -> String {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    CodegenJs::new(CodegenOptions::default())
+        .emit_program(&program)
+        .code
+}
+
+#[test]
+fn initializers_follow_declaration_order_and_fill_defaults() {
+    let js = compile(
+        "struct Config { host: String = \"localhost\", port: Int = 80 }\nlet a = Config { port: 8080 };\nlet b = Config { host: \"x\", ..a };\n",
+    );
+    assert!(js.contains("constructor(host, port)"), "{}", js);
+    assert!(
+        js.contains("const a = new Config(\"localhost\", 8080n);"),
+        "{}",
+        js
+    );
+    assert!(
+        js.contains("const b = ((__base) => new Config(\"x\", __base.port))(a);"),
+        "{}",
+        js
+    );
+}
+
+#[test]
+fn tuple_structs_are_constructed_with_new_and_indexed() {
+    let js = compile("struct Rgb(Int, Int, Int)\nlet red = Rgb(255, 0, 0);\nlet r = red.0;\n");
+    assert!(js.contains("constructor(_0, _1, _2)"), "{}", js);
+    assert!(js.contains("this[0] = _0;"), "{}", js);
+    assert!(js.contains("const red = new Rgb(255n, 0n, 0n);"), "{}", js);
+    assert!(js.contains("const r = red[0];"), "{}", js);
+}
+
+#[test]
+fn derives_emit_eq_cmp_and_clone_methods() {
+    let js = compile(
+        "@derive(Eq, Ord, Clone, Debug)\nstruct P { x: Int }\nlet a = P { x: 1 };\nlet b = a == a.clone();\nlet c = a < a;\n",
+    );
+    assert!(js.contains("eq(o) { return __art_eq(this, o); }"), "{}", js);
+    assert!(
+        js.contains("cmp(o) { return __art_cmp(this, o); }"),
+        "{}",
+        js
+    );
+    assert!(
+        js.contains("clone() { return __art_clone(this); }"),
+        "{}",
+        js
+    );
+    assert!(js.contains("function __art_eq("), "{}", js);
+}
@@ -90,3 +90,16 @@ fn test_slot_generates_slot_element() {
         js
     );
 }
+
+#[test]
+fn test_state_assignment_in_component_handler_uses_setter() {
+    let js = compile(
+        "component Counter {\n  state count: Int = 0\n  func inc() { count += 1 }\n  view { <p>{count}</p> }\n}",
+    );
+    assert!(js.contains("function inc()"), "got: {}", js);
+    assert!(
+        js.contains("set_count(__art_arith(\"+\", count, 1n));"),
+        "got: {}",
+        js
+    );
+}
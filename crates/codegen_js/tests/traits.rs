@@ -0,0 +1,43 @@
+use codegen_js::{CodegenJs, CodegenOptions};
+use lexer::Lexer;
+use parser::Parser;
+
+fn compile(src: &str) -> String {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    CodegenJs::new(CodegenOptions::default())
+        .emit_program(&program)
+        .code
+}
+
+const SRC: &str = r#"
+trait Show {
+    func show(self) -> String
+    func shout(self) -> String { return self.show() + "!"; }
+}
+struct Point { x: Int }
+impl Show for Point {
+    func show(self) -> String { return "p"; }
+}
+"#;
+
+#[test]
+fn trait_becomes_an_object_of_default_methods() {
+    let js = compile(SRC);
+    assert!(
+        js.contains("const Show = {\n    shout: function() {\n        return this.show() + \"!\";\n    },\n};"),
+        "got: {}",
+        js
+    );
+}
+
+#[test]
+fn impl_for_mixes_in_defaults_not_defined_by_the_type() {
+    let js = compile(SRC);
+    let own = js.find("Point.prototype.show = function()").expect(&js);
+    let mixin = js
+        .find("for (const k of Object.keys(Show)) { if (!(k in Point.prototype)) Point.prototype[k] = Show[k]; }")
+        .expect(&js);
+    assert!(own < mixin, "own methods must be installed first: {}", js);
+}
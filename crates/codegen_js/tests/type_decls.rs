@@ -0,0 +1,26 @@
+use codegen_js::{CodegenJs, CodegenOptions};
+use lexer::Lexer;
+use parser::Parser;
+
+fn compile(src: &str) -> String {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    CodegenJs::new(CodegenOptions::default())
+        .emit_program(&program)
+        .code
+}
+
+#[test]
+fn newtype_wraps_emit_the_bare_value() {
+    let js = compile("type UserId = Int\nnewtype Meters(Float)\nlet d = Meters(1.5);\nprintln(d);");
+    assert!(!js.contains("UserId"), "{}", js);
+    assert!(js.contains("const Meters = (v) => v;"), "{}", js);
+    assert!(js.contains("const d = (1.5);"), "{}", js);
+}
+
+#[test]
+fn newtype_wraps_inside_lambdas_are_inlined() {
+    let js = compile("newtype Meters(Float)\nlet f = |x| Meters(x * 2.0);");
+    assert!(!js.contains("Meters(x"), "{}", js);
+}
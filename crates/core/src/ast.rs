@@ -20,8 +20,27 @@ pub enum Stmt {
     Let {
         pattern: MatchPattern,
         ty: Option<String>,
+        /// `true` para `var`: o binding aceita reatribuição (`x = ...`, `x += ...`).
+        mutable: bool,
         initializer: Expr,
     },
+    /// `const NOME: Tipo = expr`, só no topo do arquivo. `value` é avaliado em
+    /// tempo de compilação (`const_eval`) e os backends inlinam o resultado.
+    Const {
+        name: Token,
+        ty: String,
+        value: Expr,
+        /// `pub const`: visível para quem importa o módulo.
+        public: bool,
+    },
+    /// `target = value` ou forma composta (`+=`, `-=`, `*=`, `/=`).
+    /// `target` é um `Variable`, `FieldAccess` ou `Index`; `operator` guarda o token
+    /// original para diagnósticos e para escolher a operação composta.
+    Assign {
+        target: Expr,
+        operator: Token,
+        value: Expr,
+    },
     Block {
         statements: Vec<Stmt>,
     },
@@ -43,14 +62,37 @@ pub enum Stmt {
     },
     StructDecl {
         name: Token,
-        fields: Vec<(Token, String)>,
+        fields: Vec<StructField>,
+        /// `struct Rgb(Int, Int, Int)`: campos posicionais, construída com
+        /// `Rgb(1, 2, 3)` e lida com `.0`.
+        tuple: bool,
+        /// `@derive(Eq, Hash, ...)` antes da declaração, na ordem escrita.
+        derives: Vec<Token>,
+        public: bool,
     },
     EnumDecl {
         name: Token,
         variants: Vec<(Token, Option<Vec<String>>)>,
+        derives: Vec<Token>,
+        public: bool,
+    },
+    /// `type Nome = T`: outro nome para `T`, sem tipo novo. `target` é a
+    /// anotação crua, como em `Let.ty`.
+    TypeAlias {
+        name: Token,
+        target: String,
+    },
+    /// `newtype Nome(T)`: tipo distinto de `T` no type checker, com a mesma
+    /// representação em tempo de execução. `Nome(x)` embrulha e `m as T` desembrulha.
+    Newtype {
+        name: Token,
+        inner: String,
     },
     Match {
         expr: Expr,
+        /// Primeiro token de `expr`: dá posição aos diagnósticos de escrutínios
+        /// sem token próprio, como literais (`match true { ... }`).
+        start: Token,
         cases: Vec<(MatchPattern, Option<Expr>, Stmt)>, // (pattern, guard, body)
     },
     Function {
@@ -61,15 +103,33 @@ pub enum Stmt {
         body: Rc<Stmt>,
         method_owner: Option<String>,
         is_async: bool,
+        /// `pub func`: exportada pelo módulo (ver `Stmt::Import`).
+        public: bool,
     },
     While {
         condition: Expr,
         body: Box<Stmt>,
+        /// Rótulo opcional (`'outer: while ...`) alvo de `break`/`continue` rotulados.
+        label: Option<Token>,
     },
     For {
         element: Token,
+        /// `for (k, v) in m`: padrão desestruturado; `element` é então o `(`.
+        pattern: Option<MatchPattern>,
         iterator: Expr,
         body: Box<Stmt>,
+        label: Option<Token>,
+    },
+    /// `break` / `break 'rotulo`. O parser garante que há um loop envolvente
+    /// (e, se rotulado, que o rótulo existe) na mesma função.
+    Break {
+        keyword: Token,
+        label: Option<Token>,
+    },
+    /// `continue` / `continue 'rotulo`.
+    Continue {
+        keyword: Token,
+        label: Option<Token>,
     },
     Performant {
         statements: Vec<Stmt>,
@@ -79,6 +139,18 @@ pub enum Stmt {
     Return {
         value: Option<Expr>,
     },
+    /// `yield expr`: suspende o generator e entrega `value` a quem chamou `next()`.
+    /// Uma função cujo corpo contém `yield` é um generator (ver `Stmt::contains_yield`).
+    Yield {
+        keyword: Token,
+        value: Expr,
+    },
+    /// `defer { ... }`: registra `body` no escopo corrente; roda quando o escopo
+    /// termina, em ordem LIFO, inclusive por `return`, `break` ou erro.
+    Defer {
+        keyword: Token,
+        body: Box<Stmt>,
+    },
     SpawnActor {
         body: Vec<Stmt>,
     },
@@ -86,13 +158,25 @@ pub enum Stmt {
         program: String,
         args: Vec<String>,
     },
+    /// `import a.b;`, `import a.b.{x, y};` ou `import a.b as m;`.
+    /// `items` lista a importação seletiva. Com `alias`, o parser lê `m.x` como
+    /// `x` e guarda em `items` os nomes usados assim.
     Import {
         path: Vec<Token>,
+        items: Option<Vec<Token>>,
+        alias: Option<Token>,
     },
+    /// `impl Tipo { ... }` ou `impl Trait for Tipo { ... }`.
     ImplBlock {
         type_name: String,
+        trait_name: Option<Token>,
         methods: Vec<Stmt>,
     },
+    /// `trait Nome { func m(self) -> T  func d(self) { ... } }`.
+    TraitDecl {
+        name: Token,
+        methods: Vec<TraitMethod>,
+    },
     ComponentBlock {
         name: String,
         bindings: Vec<Stmt>,
@@ -106,6 +190,94 @@ pub enum Stmt {
     },
 }
 
+impl Stmt {
+    /// `true` se há um `yield` neste statement, sem descer em funções aninhadas
+    /// (um `yield` ali pertence à função interna).
+    pub fn contains_yield(&self) -> bool {
+        self.any_nested(&|s| matches!(s, Stmt::Yield { .. }))
+    }
+
+    /// `true` se há um `await` neste statement, sem descer em funções aninhadas.
+    pub fn contains_await(&self) -> bool {
+        self.any_nested(&|s| s.awaited().is_some())
+    }
+
+    /// O `await` que é o valor inteiro deste statement (`let x = await e;`,
+    /// `x = await e;`, `return await e;`, `await e;`), único lugar onde o parser o aceita.
+    pub fn awaited(&self) -> Option<&Expr> {
+        match self {
+            Stmt::Expression(e)
+            | Stmt::Let { initializer: e, .. }
+            | Stmt::Assign { value: e, .. }
+            | Stmt::Return { value: Some(e) } => match e {
+                Expr::Await { .. } => Some(e),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Troca o `await` de `awaited()` por `value`, já resolvido.
+    pub fn with_awaited(self, value: ArtValue) -> Stmt {
+        let value = Expr::Literal(value);
+        match self {
+            Stmt::Expression(_) => Stmt::Expression(value),
+            Stmt::Let {
+                pattern,
+                ty,
+                mutable,
+                ..
+            } => Stmt::Let {
+                pattern,
+                ty,
+                mutable,
+                initializer: value,
+            },
+            Stmt::Assign {
+                target, operator, ..
+            } => Stmt::Assign {
+                target,
+                operator,
+                value,
+            },
+            Stmt::Return { .. } => Stmt::Return { value: Some(value) },
+            other => other,
+        }
+    }
+
+    fn any_nested(&self, leaf: &impl Fn(&Stmt) -> bool) -> bool {
+        if leaf(self) {
+            return true;
+        }
+        match self {
+            Stmt::Block { statements } | Stmt::Performant { statements } => {
+                statements.iter().any(|s| s.any_nested(leaf))
+            }
+            Stmt::If {
+                then_branch,
+                else_branch,
+                ..
+            }
+            | Stmt::IfLet {
+                then_branch,
+                else_branch,
+                ..
+            } => {
+                then_branch.any_nested(leaf)
+                    || else_branch.as_ref().is_some_and(|e| e.any_nested(leaf))
+            }
+            Stmt::TryCatch {
+                try_branch,
+                catch_branch,
+                ..
+            } => try_branch.any_nested(leaf) || catch_branch.any_nested(leaf),
+            Stmt::Match { cases, .. } => cases.iter().any(|(_, _, body)| body.any_nested(leaf)),
+            Stmt::While { body, .. } | Stmt::For { body, .. } => body.any_nested(leaf),
+            _ => false,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum BindingQualifier {
     State,
@@ -114,6 +286,25 @@ pub enum BindingQualifier {
     Ref,
 }
 
+/// Assinatura de método em um `trait`; com `body` é uma implementação default,
+/// usada pelos tipos que implementam o trait sem sobrescrevê-la.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraitMethod {
+    pub name: Token,
+    pub params: Vec<FunctionParam>,
+    pub return_type: Option<String>,
+    pub body: Option<Rc<Stmt>>,
+}
+
+/// Campo de `struct`. Numa struct tupla o nome é a posição (`0`, `1`, ...).
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructField {
+    pub name: Token,
+    pub ty: String,
+    /// `porta: Int = 8080`: avaliado a cada `StructInit` que omite o campo.
+    pub default: Option<Expr>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct FunctionParam {
     pub name: Token,
@@ -151,6 +342,8 @@ pub enum Expr {
     StructInit {
         name: Token,
         fields: Vec<(Token, Expr)>,
+        /// `Config { porta: 8080, ..base }`: campos omitidos vêm de `base`.
+        base: Option<Box<Expr>>,
     },
     EnumInit {
         name: Option<Token>,
@@ -161,6 +354,27 @@ pub enum Expr {
         object: Box<Expr>,
         field: Token,
     },
+    /// `object[index]`. Índices `Int` negativos contam a partir do fim.
+    /// `bracket` é o `[` de abertura, usado como span dos diagnósticos.
+    Index {
+        object: Box<Expr>,
+        index: Box<Expr>,
+        bracket: Token,
+    },
+    /// `object[start..end]` (limites opcionais, `end` exclusivo).
+    Slice {
+        object: Box<Expr>,
+        start: Option<Box<Expr>>,
+        end: Option<Box<Expr>>,
+        bracket: Token,
+    },
+    /// `start..end` ou `start..=end`: intervalo de inteiros avaliado sob demanda.
+    Range {
+        start: Box<Expr>,
+        end: Box<Expr>,
+        inclusive: bool,
+        operator: Token,
+    },
     Try(Box<Expr>),
     Array(Vec<Expr>),
     Tuple(Vec<Expr>),
@@ -173,10 +387,40 @@ pub enum Expr {
     Unowned(Box<Expr>),       // açúcar: unowned expr -> builtin unowned()
     WeakUpgrade(Box<Expr>),   // açúcar: expr?  (onde expr avalia para WeakRef)
     UnownedAccess(Box<Expr>), // açúcar: expr! (onde expr avalia para UnownedRef)
+    /// `object?.field` ou `object?.field(args)`: `none`/`Option.None` em
+    /// `object` curto-circuita (os argumentos nem são avaliados).
+    OptionalChain {
+        object: Box<Expr>,
+        field: Token,
+        arguments: Option<Vec<Expr>>,
+    },
+    /// `left ?? right`: `right` só é avaliado se `left` for ausente.
+    Coalesce {
+        left: Box<Expr>,
+        operator: Token,
+        right: Box<Expr>,
+    },
     SpawnActor {
         body: Vec<Stmt>,
     },
+    /// `await expr`: suspende a task `async` corrente até `expr` terminar. Numa
+    /// `async func` o parser só o aceita como valor inteiro de um `let`, atribuição,
+    /// `return` ou expression statement (ver `Stmt::awaited`); no topo do arquivo ele
+    /// bloqueia e vale em qualquer posição.
+    Await {
+        keyword: Token,
+        value: Box<Expr>,
+    },
     Template(Vec<TemplateNode>),
+    /// Função anônima: `|a, b| expr`, `|a| { ... }` ou `func(a) { ... }`.
+    /// A forma com expressão é dessugarizada em `{ return expr; }`, então `body`
+    /// é sempre um `Stmt::Block`. `keyword` é o `|`/`func` de abertura (spans).
+    Lambda {
+        keyword: Token,
+        params: Vec<FunctionParam>,
+        return_type: Option<String>,
+        body: Rc<Stmt>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -243,6 +487,8 @@ pub struct Function {
     pub closure: Weak<RefCell<Environment>>,
     // Retentor opcional para ambientes criados ao "bindar" métodos (garante que não cai no nada imediatamente)
     pub retained_env: Option<Rc<RefCell<Environment>>>,
+    /// `async func`: a chamada cria uma task no scheduler em vez de executar o corpo.
+    pub is_async: bool,
 }
 
 impl fmt::Debug for Function {
@@ -258,16 +504,240 @@ impl PartialEq for Function {
     }
 }
 
+/// Chave de `Map` e elemento de `Set`: cópia estrutural de um valor hashable.
+/// Instâncias entram com os campos ordenados por nome, então duas instâncias
+/// iguais campo a campo são a mesma chave, qualquer que seja o objeto no heap.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum MapKey {
+    Bool(bool),
+    Int(i64),
+    Float(KeyFloat),
+    String(Arc<str>),
+    Optional(Option<Box<MapKey>>),
+    Tuple(Vec<MapKey>),
+    Array(Vec<MapKey>),
+    Struct {
+        name: String,
+        fields: Vec<(String, MapKey)>,
+    },
+    Enum {
+        name: String,
+        variant: String,
+        values: Vec<MapKey>,
+    },
+}
+
+/// `Float` usado como chave. `0.0` e `-0.0` são a mesma chave (como em `==`)
+/// e a ordem é a de `f64::total_cmp`.
+#[derive(Debug, Clone, Copy)]
+pub struct KeyFloat(pub f64);
+
+impl KeyFloat {
+    fn normalized(self) -> f64 {
+        if self.0 == 0.0 { 0.0 } else { self.0 }
+    }
+}
+
+impl PartialEq for KeyFloat {
+    fn eq(&self, other: &Self) -> bool {
+        self.normalized().to_bits() == other.normalized().to_bits()
+    }
+}
+
+impl Eq for KeyFloat {}
+
+impl std::hash::Hash for KeyFloat {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.normalized().to_bits().hash(state);
+    }
+}
+
+impl PartialOrd for KeyFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for KeyFloat {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.normalized().total_cmp(&other.normalized())
+    }
+}
+
+impl MapKey {
+    /// Chave de um valor já fora do heap (ex.: vindo de `decode_val`). Não
+    /// confere derives: quem monta chaves a partir de valores do programa é o
+    /// interpretador, que resolve o heap e exige `derive(Hash)`.
+    pub fn from_value(value: &ArtValue) -> Option<MapKey> {
+        let all = |items: &[ArtValue]| {
+            items
+                .iter()
+                .map(MapKey::from_value)
+                .collect::<Option<Vec<_>>>()
+        };
+        Some(match value {
+            ArtValue::Bool(b) => MapKey::Bool(*b),
+            ArtValue::Int(n) => MapKey::Int(*n),
+            ArtValue::Float(f) => MapKey::Float(KeyFloat(*f)),
+            ArtValue::String(s) => MapKey::String(s.clone()),
+            ArtValue::Optional(opt) => match &**opt {
+                Some(inner) => MapKey::Optional(Some(Box::new(MapKey::from_value(inner)?))),
+                None => MapKey::Optional(None),
+            },
+            ArtValue::Tuple(items) => MapKey::Tuple(all(items)?),
+            ArtValue::Array(items) => MapKey::Array(all(items)?),
+            ArtValue::StructInstance {
+                struct_name,
+                fields,
+            } => {
+                let fields = fields
+                    .iter()
+                    .map(|(k, v)| Some((k.clone(), MapKey::from_value(v)?)))
+                    .collect::<Option<Vec<_>>>()?;
+                MapKey::struct_key(struct_name, fields)
+            }
+            ArtValue::EnumInstance {
+                enum_name,
+                variant,
+                values,
+            } => MapKey::Enum {
+                name: enum_name.clone(),
+                variant: variant.clone(),
+                values: all(values)?,
+            },
+            _ => return None,
+        })
+    }
+
+    /// `MapKey::Struct` com os campos na ordem canônica (por nome).
+    pub fn struct_key(name: &str, mut fields: Vec<(String, MapKey)>) -> MapKey {
+        fields.sort_by(|a, b| a.0.cmp(&b.0));
+        MapKey::Struct {
+            name: name.to_string(),
+            fields,
+        }
+    }
+
+    /// O valor que a chave representa, fora do heap.
+    pub fn to_value(&self) -> ArtValue {
+        let all = |items: &[MapKey]| items.iter().map(MapKey::to_value).collect();
+        match self {
+            MapKey::Bool(b) => ArtValue::Bool(*b),
+            MapKey::Int(n) => ArtValue::Int(*n),
+            MapKey::Float(f) => ArtValue::Float(f.0),
+            MapKey::String(s) => ArtValue::String(s.clone()),
+            MapKey::Optional(opt) => {
+                ArtValue::Optional(Box::new(opt.as_ref().map(|k| k.to_value())))
+            }
+            MapKey::Tuple(items) => ArtValue::Tuple(all(items)),
+            MapKey::Array(items) => ArtValue::Array(all(items)),
+            MapKey::Struct { name, fields } => ArtValue::StructInstance {
+                struct_name: name.clone(),
+                fields: fields
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.to_value()))
+                    .collect(),
+            },
+            MapKey::Enum {
+                name,
+                variant,
+                values,
+            } => ArtValue::EnumInstance {
+                enum_name: name.clone(),
+                variant: variant.clone(),
+                values: all(values),
+            },
+        }
+    }
+}
+
+impl From<&str> for MapKey {
+    fn from(s: &str) -> Self {
+        MapKey::String(Arc::from(s))
+    }
+}
+
+impl fmt::Display for MapKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_value())
+    }
+}
+
 #[derive(Debug, Clone)]
-pub struct MapRef(pub Arc<std::sync::Mutex<std::collections::HashMap<String, ArtValue>>>);
+pub struct MapRef(pub Arc<std::sync::Mutex<std::collections::HashMap<MapKey, ArtValue>>>);
 impl PartialEq for MapRef {
     fn eq(&self, other: &Self) -> bool {
         Arc::ptr_eq(&self.0, &other.0)
     }
 }
 
+impl MapRef {
+    /// Cópia das entradas em ordem de chave, a ordem de `for` e de `map_keys`.
+    pub fn sorted_entries(&self) -> Vec<(MapKey, ArtValue)> {
+        let mut entries: Vec<(MapKey, ArtValue)> = self
+            .0
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries
+    }
+}
+
+/// Elementos de um `Set`: ordem de inserção para a iteração e índice por
+/// chave para `set_has` em O(1).
+#[derive(Debug, Clone, Default)]
+pub struct KeySet {
+    items: Vec<MapKey>,
+    index: std::collections::HashMap<MapKey, usize>,
+}
+
+impl KeySet {
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn contains(&self, key: &MapKey) -> bool {
+        self.index.contains_key(key)
+    }
+
+    /// `false` se a chave já estava no conjunto.
+    pub fn insert(&mut self, key: MapKey) -> bool {
+        if self.index.contains_key(&key) {
+            return false;
+        }
+        self.index.insert(key.clone(), self.items.len());
+        self.items.push(key);
+        true
+    }
+
+    /// `false` se a chave não estava no conjunto. Reindexa os elementos
+    /// seguintes para manter a ordem de inserção, então é O(n).
+    pub fn remove(&mut self, key: &MapKey) -> bool {
+        let Some(at) = self.index.remove(key) else {
+            return false;
+        };
+        self.items.remove(at);
+        for (i, item) in self.items.iter().enumerate().skip(at) {
+            self.index.insert(item.clone(), i);
+        }
+        true
+    }
+
+    /// Elementos na ordem de inserção.
+    pub fn iter(&self) -> std::slice::Iter<'_, MapKey> {
+        self.items.iter()
+    }
+}
+
 #[derive(Debug, Clone)]
-pub struct SetRef(pub Arc<std::sync::Mutex<Vec<ArtValue>>>);
+pub struct SetRef(pub Arc<std::sync::Mutex<KeySet>>);
 impl PartialEq for SetRef {
     fn eq(&self, other: &Self) -> bool {
         Arc::ptr_eq(&self.0, &other.0)
@@ -345,6 +815,8 @@ pub enum BuiltinFn {
     ActorYield,           // actor_yield()
     ActorSetMailboxLimit, // actor_set_mailbox_limit(actor, limit)
     RunActors,            // run_actors([max_steps]) -> drive scheduler until idle or max_steps
+    ActorAsk,             // actor_ask(actor, value [, priority]) -> task with the reply
+    Sleep,                // sleep(ms) -> task that completes after ms milliseconds
     // Concurrency primitives (prototype): Mutex and AtomicInt
     AtomicNew,         // atomic_new(initial:Int)
     AtomicLoad,        // atomic_load(atomic)
@@ -373,6 +845,15 @@ pub enum BuiltinFn {
     SetHas,
     MathAbs,
     MathPow,
+    WrappingAdd,
+    WrappingSub,
+    WrappingMul,
+    CheckedAdd,
+    CheckedSub,
+    CheckedMul,
+    SaturatingAdd,
+    SaturatingSub,
+    SaturatingMul,
     MathClamp,
     DagTopoSort,
     TimeNow,
@@ -405,6 +886,24 @@ pub enum BuiltinFn {
     DequePopFront,  // deque_pop_front(d) -> Option<T>
     DequePopBack,   // deque_pop_back(d) -> Option<T>
     DequeLen,       // deque_len(d) -> Int
+    DequeGet,       // deque_get(d, i) -> Option<T>
+    DequeToArray,   // deque_to_array(d) -> Array<T>
+
+    // Map e Set: remoção, enumeração e álgebra de conjuntos
+    MapRemove,       // map_remove(m, k) -> Option<V>
+    MapKeys,         // map_keys(m) -> Array<K>
+    MapValues,       // map_values(m) -> Array<V>
+    MapEntries,      // map_entries(m) -> Array<(K, V)>
+    MapMerge,        // map_merge(a, b) -> Map
+    SetRemove,       // set_remove(s, v) -> Bool
+    SetUnion,        // set_union(a, b) -> Set
+    SetIntersection, // set_intersection(a, b) -> Set
+    SetDifference,   // set_difference(a, b) -> Set
+    SetToArray,      // set_to_array(s) -> Array<T>
+
+    // Derives: ordem e hash estruturais
+    Sort, // sort(arr) -> Array
+    Hash, // hash(v) -> Int
 
     // Built-in methods internally bound to Enum structs
     EnumIsOk(Box<ArtValue>),
@@ -432,6 +931,8 @@ impl fmt::Debug for BuiltinFn {
             BuiltinFn::ActorYield => write!(f, "<builtin actor_yield>"),
             BuiltinFn::ActorSetMailboxLimit => write!(f, "<builtin actor_set_mailbox_limit>"),
             BuiltinFn::RunActors => write!(f, "<builtin run_actors>"),
+            BuiltinFn::ActorAsk => write!(f, "<builtin actor_ask>"),
+            BuiltinFn::Sleep => write!(f, "<builtin sleep>"),
             BuiltinFn::AtomicNew => write!(f, "<builtin atomic_new>"),
             BuiltinFn::AtomicLoad => write!(f, "<builtin atomic_load>"),
             BuiltinFn::AtomicStore => write!(f, "<builtin atomic_store>"),
@@ -458,6 +959,15 @@ impl fmt::Debug for BuiltinFn {
             BuiltinFn::SetHas => write!(f, "<builtin set_has>"),
             BuiltinFn::MathAbs => write!(f, "<builtin math_abs>"),
             BuiltinFn::MathPow => write!(f, "<builtin math_pow>"),
+            BuiltinFn::WrappingAdd => write!(f, "<builtin wrapping_add>"),
+            BuiltinFn::WrappingSub => write!(f, "<builtin wrapping_sub>"),
+            BuiltinFn::WrappingMul => write!(f, "<builtin wrapping_mul>"),
+            BuiltinFn::CheckedAdd => write!(f, "<builtin checked_add>"),
+            BuiltinFn::CheckedSub => write!(f, "<builtin checked_sub>"),
+            BuiltinFn::CheckedMul => write!(f, "<builtin checked_mul>"),
+            BuiltinFn::SaturatingAdd => write!(f, "<builtin saturating_add>"),
+            BuiltinFn::SaturatingSub => write!(f, "<builtin saturating_sub>"),
+            BuiltinFn::SaturatingMul => write!(f, "<builtin saturating_mul>"),
             BuiltinFn::MathClamp => write!(f, "<builtin math_clamp>"),
             BuiltinFn::DagTopoSort => write!(f, "<builtin dag_topo_sort>"),
             BuiltinFn::TimeNow => write!(f, "<builtin time_now>"),
@@ -487,6 +997,20 @@ impl fmt::Debug for BuiltinFn {
             BuiltinFn::DequePopFront => write!(f, "<builtin deque_pop_front>"),
             BuiltinFn::DequePopBack => write!(f, "<builtin deque_pop_back>"),
             BuiltinFn::DequeLen => write!(f, "<builtin deque_len>"),
+            BuiltinFn::DequeGet => write!(f, "<builtin deque_get>"),
+            BuiltinFn::DequeToArray => write!(f, "<builtin deque_to_array>"),
+            BuiltinFn::MapRemove => write!(f, "<builtin map_remove>"),
+            BuiltinFn::MapKeys => write!(f, "<builtin map_keys>"),
+            BuiltinFn::MapValues => write!(f, "<builtin map_values>"),
+            BuiltinFn::MapEntries => write!(f, "<builtin map_entries>"),
+            BuiltinFn::MapMerge => write!(f, "<builtin map_merge>"),
+            BuiltinFn::SetRemove => write!(f, "<builtin set_remove>"),
+            BuiltinFn::SetUnion => write!(f, "<builtin set_union>"),
+            BuiltinFn::SetIntersection => write!(f, "<builtin set_intersection>"),
+            BuiltinFn::SetDifference => write!(f, "<builtin set_difference>"),
+            BuiltinFn::SetToArray => write!(f, "<builtin set_to_array>"),
+            BuiltinFn::Sort => write!(f, "<builtin sort>"),
+            BuiltinFn::Hash => write!(f, "<builtin hash>"),
             BuiltinFn::EnumIsOk(_)
             | BuiltinFn::EnumIsErr(_)
             | BuiltinFn::EnumUnwrap(_)
@@ -522,10 +1046,41 @@ impl fmt::Display for ArtValue {
                 let elems: Vec<String> = tup.iter().map(|item| item.to_string()).collect();
                 write!(f, "({})", elems.join(", "))
             }
+            ArtValue::StructInstance {
+                struct_name,
+                fields,
+            } if struct_name == "__Range" => {
+                let bound =
+                    |name: &str| fields.get(name).map(|v| v.to_string()).unwrap_or_default();
+                let op = if matches!(fields.get("inclusive"), Some(ArtValue::Bool(true))) {
+                    "..="
+                } else {
+                    ".."
+                };
+                write!(f, "{}{}{}", bound("start"), op, bound("end"))
+            }
+            ArtValue::StructInstance {
+                struct_name,
+                fields,
+            } if struct_name == "__Generator" => match fields.get("name") {
+                Some(name) => write!(f, "<generator {}>", name),
+                None => write!(f, "<generator>"),
+            },
+            ArtValue::StructInstance {
+                struct_name,
+                fields,
+            } if struct_name == "__Task" => match fields.get("name") {
+                Some(name) => write!(f, "<task {}>", name),
+                None => write!(f, "<task>"),
+            },
             ArtValue::StructInstance {
                 struct_name,
                 fields,
             } => {
+                if let Some(items) = tuple_struct_fields(fields) {
+                    let elems: Vec<String> = items.iter().map(|v| v.to_string()).collect();
+                    return write!(f, "{}({})", struct_name, elems.join(", "));
+                }
                 let field_strs: Vec<String> = fields
                     .iter()
                     .map(|(k, v)| format!("{}: {}", k, v))
@@ -565,6 +1120,8 @@ impl fmt::Display for ArtValue {
                 BuiltinFn::ActorYield => write!(f, "<builtin actor_yield>"),
                 BuiltinFn::ActorSetMailboxLimit => write!(f, "<builtin actor_set_mailbox_limit>"),
                 BuiltinFn::RunActors => write!(f, "<builtin run_actors>"),
+                BuiltinFn::ActorAsk => write!(f, "<builtin actor_ask>"),
+                BuiltinFn::Sleep => write!(f, "<builtin sleep>"),
                 BuiltinFn::AtomicNew => write!(f, "<builtin atomic_new>"),
                 BuiltinFn::AtomicLoad => write!(f, "<builtin atomic_load>"),
                 BuiltinFn::AtomicStore => write!(f, "<builtin atomic_store>"),
@@ -591,6 +1148,15 @@ impl fmt::Display for ArtValue {
                 BuiltinFn::SetHas => write!(f, "<builtin set_has>"),
                 BuiltinFn::MathAbs => write!(f, "<builtin math_abs>"),
                 BuiltinFn::MathPow => write!(f, "<builtin math_pow>"),
+                BuiltinFn::WrappingAdd => write!(f, "<builtin wrapping_add>"),
+                BuiltinFn::WrappingSub => write!(f, "<builtin wrapping_sub>"),
+                BuiltinFn::WrappingMul => write!(f, "<builtin wrapping_mul>"),
+                BuiltinFn::CheckedAdd => write!(f, "<builtin checked_add>"),
+                BuiltinFn::CheckedSub => write!(f, "<builtin checked_sub>"),
+                BuiltinFn::CheckedMul => write!(f, "<builtin checked_mul>"),
+                BuiltinFn::SaturatingAdd => write!(f, "<builtin saturating_add>"),
+                BuiltinFn::SaturatingSub => write!(f, "<builtin saturating_sub>"),
+                BuiltinFn::SaturatingMul => write!(f, "<builtin saturating_mul>"),
                 BuiltinFn::MathClamp => write!(f, "<builtin math_clamp>"),
                 BuiltinFn::DagTopoSort => write!(f, "<builtin dag_topo_sort>"),
                 BuiltinFn::TimeNow => write!(f, "<builtin time_now>"),
@@ -620,6 +1186,20 @@ impl fmt::Display for ArtValue {
                 BuiltinFn::DequePopFront => write!(f, "<builtin deque_pop_front>"),
                 BuiltinFn::DequePopBack => write!(f, "<builtin deque_pop_back>"),
                 BuiltinFn::DequeLen => write!(f, "<builtin deque_len>"),
+                BuiltinFn::DequeGet => write!(f, "<builtin deque_get>"),
+                BuiltinFn::DequeToArray => write!(f, "<builtin deque_to_array>"),
+                BuiltinFn::MapRemove => write!(f, "<builtin map_remove>"),
+                BuiltinFn::MapKeys => write!(f, "<builtin map_keys>"),
+                BuiltinFn::MapValues => write!(f, "<builtin map_values>"),
+                BuiltinFn::MapEntries => write!(f, "<builtin map_entries>"),
+                BuiltinFn::MapMerge => write!(f, "<builtin map_merge>"),
+                BuiltinFn::SetRemove => write!(f, "<builtin set_remove>"),
+                BuiltinFn::SetUnion => write!(f, "<builtin set_union>"),
+                BuiltinFn::SetIntersection => write!(f, "<builtin set_intersection>"),
+                BuiltinFn::SetDifference => write!(f, "<builtin set_difference>"),
+                BuiltinFn::SetToArray => write!(f, "<builtin set_to_array>"),
+                BuiltinFn::Sort => write!(f, "<builtin sort>"),
+                BuiltinFn::Hash => write!(f, "<builtin hash>"),
                 BuiltinFn::EnumIsOk(_)
                 | BuiltinFn::EnumIsErr(_)
                 | BuiltinFn::EnumUnwrap(_)
@@ -634,9 +1214,11 @@ impl fmt::Display for ArtValue {
             ArtValue::Mutex(h) => write!(f, "<mutex {}>", h.0),
             ArtValue::Actor(id) => write!(f, "<actor {}>", id),
             ArtValue::Map(m) => {
-                let map = m.0.lock().unwrap_or_else(|e| e.into_inner());
-                let field_strs: Vec<String> =
-                    map.iter().map(|(k, v)| format!("{}: {}", k, v)).collect();
+                let field_strs: Vec<String> = m
+                    .sorted_entries()
+                    .iter()
+                    .map(|(k, v)| format!("{}: {}", k, v))
+                    .collect();
                 write!(f, "Map {{ {} }}", field_strs.join(", "))
             }
             ArtValue::Set(s) => {
@@ -674,6 +1256,19 @@ impl From<i64> for ArtValue {
     }
 }
 
+/// Campos de uma instância de struct tupla, na ordem (`"0"`, `"1"`, ...);
+/// `None` quando a struct tem campos nomeados.
+pub fn tuple_struct_fields(
+    fields: &std::collections::HashMap<String, ArtValue>,
+) -> Option<Vec<&ArtValue>> {
+    if fields.is_empty() {
+        return None;
+    }
+    (0..fields.len())
+        .map(|i| fields.get(&i.to_string()))
+        .collect()
+}
+
 impl ArtValue {
     #[inline]
     pub fn none() -> ArtValue {
@@ -689,6 +1284,15 @@ impl ArtValue {
             ArtValue::Optional(_) => "Optional".to_string(),
             ArtValue::Array(_) => "Array".to_string(),
             ArtValue::Tuple(_) => "Tuple".to_string(),
+            ArtValue::StructInstance { struct_name, .. } if struct_name == "__Range" => {
+                "Range".to_string()
+            }
+            ArtValue::StructInstance { struct_name, .. } if struct_name == "__Generator" => {
+                "Generator".to_string()
+            }
+            ArtValue::StructInstance { struct_name, .. } if struct_name == "__Task" => {
+                "Task".to_string()
+            }
             ArtValue::StructInstance { struct_name, .. } => struct_name.clone(),
             ArtValue::EnumInstance { enum_name, .. } => enum_name.clone(),
             ArtValue::HeapComposite(_) => "HeapComposite".to_string(),
@@ -721,4 +1325,85 @@ pub enum MatchPattern {
     Binding(Token),
     Wildcard,
     Tuple(Vec<MatchPattern>),
+    /// `Point { x, y: 0 }`: `x` sozinho equivale a `x: x`; campos omitidos são ignorados.
+    Struct {
+        name: Token,
+        fields: Vec<(Token, MatchPattern)>,
+    },
+    /// `[a, b]`, `[first, ..rest]`, `[.., last]`. Sem `rest` o tamanho é exato;
+    /// `rest` é `Some(None)` para `..` e `Some(Some(nome))` para `..nome`.
+    Array {
+        prefix: Vec<MatchPattern>,
+        rest: Option<Option<Token>>,
+        suffix: Vec<MatchPattern>,
+    },
+    /// `A | B`: casa se alguma alternativa casar; todas ligam os mesmos nomes.
+    Or(Vec<MatchPattern>),
+    /// `1..=9` / `0..10` sobre Int ou Float.
+    Range {
+        start: ArtValue,
+        end: ArtValue,
+        inclusive: bool,
+    },
+    /// `nome @ padrão`: liga o valor inteiro e também casa o padrão.
+    At {
+        name: Token,
+        pattern: Box<MatchPattern>,
+    },
+}
+
+impl MatchPattern {
+    /// Nomes ligados pelo padrão, na ordem em que aparecem. Num `Or`, os da
+    /// primeira alternativa (o parser garante que todas ligam os mesmos).
+    pub fn bindings(&self) -> Vec<&Token> {
+        let mut out = Vec::new();
+        self.collect_bindings(&mut out);
+        out
+    }
+
+    fn collect_bindings<'a>(&'a self, out: &mut Vec<&'a Token>) {
+        match self {
+            MatchPattern::Variable(t) | MatchPattern::Binding(t) => out.push(t),
+            MatchPattern::EnumVariant { params, .. } => {
+                for p in params.iter().flatten() {
+                    p.collect_bindings(out);
+                }
+            }
+            MatchPattern::Tuple(items) => {
+                for p in items {
+                    p.collect_bindings(out);
+                }
+            }
+            MatchPattern::Struct { fields, .. } => {
+                for (_, p) in fields {
+                    p.collect_bindings(out);
+                }
+            }
+            MatchPattern::Array {
+                prefix,
+                rest,
+                suffix,
+            } => {
+                for p in prefix {
+                    p.collect_bindings(out);
+                }
+                if let Some(Some(t)) = rest {
+                    out.push(t);
+                }
+                for p in suffix {
+                    p.collect_bindings(out);
+                }
+            }
+            MatchPattern::Or(alts) => {
+                if let Some(first) = alts.first() {
+                    first.collect_bindings(out);
+                }
+            }
+            MatchPattern::At { name, pattern } => {
+                out.push(name);
+                pattern.collect_bindings(out);
+            }
+            MatchPattern::Literal(_) | MatchPattern::Wildcard | MatchPattern::Range { .. } => {}
+        }
+    }
 }
@@ -0,0 +1,305 @@
+//! Avaliação em tempo de compilação de `const NOME: Tipo = expr`.
+//!
+//! Cobre o subconjunto puro de `Expr`: literais, aritmética, bits, comparações e
+//! lógica, concatenação de strings, tuplas, arrays, literais de struct, indexação,
+//! acesso a campo e referências a constantes declaradas antes. Qualquer outra
+//! forma (chamadas, variáveis comuns, lambdas, ...) é um [`ConstError`].
+//!
+//! As regras seguem as do interpretador, exceto o overflow de `Int`, que aqui é
+//! sempre erro: o valor não depende de `--overflow`.
+
+use crate::ast::{ArtValue, Expr, Stmt};
+use crate::{Token, TokenType};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Constantes já avaliadas, por nome.
+pub type ConstEnv = HashMap<String, ArtValue>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstError {
+    pub message: String,
+    /// Token mais próximo do problema; `None` para literais.
+    pub token: Option<Token>,
+}
+
+impl ConstError {
+    fn new(message: impl Into<String>, token: Option<&Token>) -> Self {
+        ConstError {
+            message: message.into(),
+            token: token.cloned(),
+        }
+    }
+}
+
+type ConstResult = Result<ArtValue, ConstError>;
+
+/// Avalia `expr` usando as constantes de `env`.
+pub fn eval_const(expr: &Expr, env: &ConstEnv) -> ConstResult {
+    match expr {
+        Expr::Literal(value) => Ok(value.clone()),
+        Expr::Variable { name } => env.get(&name.lexeme).cloned().ok_or_else(|| {
+            ConstError::new(format!("'{}' is not a constant", name.lexeme), Some(name))
+        }),
+        Expr::Grouping { expression } => eval_const(expression, env),
+        Expr::Unary { operator, right } => unary(operator, eval_const(right, env)?),
+        Expr::Binary {
+            left,
+            operator,
+            right,
+        } => binary(operator, eval_const(left, env)?, eval_const(right, env)?),
+        Expr::Logical {
+            left,
+            operator,
+            right,
+        } => match (eval_const(left, env)?, eval_const(right, env)?) {
+            (ArtValue::Bool(l), ArtValue::Bool(r)) => {
+                Ok(ArtValue::Bool(if operator.token_type == TokenType::And {
+                    l && r
+                } else {
+                    l || r
+                }))
+            }
+            (l, r) => Err(mismatch(operator, &l, &r)),
+        },
+        Expr::Array(items) => items
+            .iter()
+            .map(|e| eval_const(e, env))
+            .collect::<Result<_, _>>()
+            .map(ArtValue::Array),
+        Expr::Tuple(items) => items
+            .iter()
+            .map(|e| eval_const(e, env))
+            .collect::<Result<_, _>>()
+            .map(ArtValue::Tuple),
+        Expr::StructInit { name, fields, base } => {
+            let mut values = match base.as_deref().map(|b| eval_const(b, env)).transpose()? {
+                Some(ArtValue::StructInstance {
+                    struct_name,
+                    fields,
+                }) if struct_name == name.lexeme => fields,
+                Some(other) => {
+                    return Err(ConstError::new(
+                        format!(
+                            "'..' base of '{}' must be a '{}', got {}",
+                            name.lexeme,
+                            name.lexeme,
+                            other.type_name()
+                        ),
+                        Some(name),
+                    ));
+                }
+                None => HashMap::new(),
+            };
+            for (field, e) in fields {
+                values.insert(field.lexeme.clone(), eval_const(e, env)?);
+            }
+            Ok(ArtValue::StructInstance {
+                struct_name: name.lexeme.clone(),
+                fields: values,
+            })
+        }
+        Expr::Index {
+            object,
+            index,
+            bracket,
+        } => {
+            let object = eval_const(object, env)?;
+            let (ArtValue::Array(items) | ArtValue::Tuple(items)) = &object else {
+                return Err(ConstError::new(
+                    format!(
+                        "cannot index {} in a constant expression",
+                        object.type_name()
+                    ),
+                    Some(bracket),
+                ));
+            };
+            let ArtValue::Int(i) = eval_const(index, env)? else {
+                return Err(ConstError::new("index must be an Int", Some(bracket)));
+            };
+            let pos = if i < 0 { i + items.len() as i64 } else { i };
+            usize::try_from(pos)
+                .ok()
+                .and_then(|p| items.get(p))
+                .cloned()
+                .ok_or_else(|| {
+                    ConstError::new(
+                        format!("index {} out of range for length {}", i, items.len()),
+                        Some(bracket),
+                    )
+                })
+        }
+        Expr::FieldAccess { object, field } => match eval_const(object, env)? {
+            ArtValue::StructInstance { fields, .. } if fields.contains_key(&field.lexeme) => {
+                Ok(fields[&field.lexeme].clone())
+            }
+            other => Err(ConstError::new(
+                format!("{} has no field '{}'", other.type_name(), field.lexeme),
+                Some(field),
+            )),
+        },
+        Expr::Call { callee, .. } => Err(ConstError::new(
+            "function calls are not allowed in a constant expression",
+            expr_token(callee),
+        )),
+        other => Err(ConstError::new(
+            "expression is not allowed in a constant expression",
+            expr_token(other),
+        )),
+    }
+}
+
+/// Avalia os `const` do topo de `program`, em ordem. Os que falham ficam de
+/// fora; quem reporta o erro é o type checker.
+pub fn fold_consts(program: &[Stmt]) -> ConstEnv {
+    let mut env = ConstEnv::new();
+    for stmt in program {
+        if let Stmt::Const { name, value, .. } = stmt
+            && let Ok(v) = eval_const(value, &env)
+        {
+            env.insert(name.lexeme.clone(), v);
+        }
+    }
+    env
+}
+
+fn expr_token(expr: &Expr) -> Option<&Token> {
+    match expr {
+        Expr::Variable { name } => Some(name),
+        Expr::Binary { operator, .. }
+        | Expr::Logical { operator, .. }
+        | Expr::Coalesce { operator, .. }
+        | Expr::Unary { operator, .. } => Some(operator),
+        Expr::Grouping { expression } => expr_token(expression),
+        Expr::Call { callee, .. } => expr_token(callee),
+        Expr::FieldAccess { field, .. } | Expr::OptionalChain { field, .. } => Some(field),
+        Expr::Index { bracket, .. } | Expr::Slice { bracket, .. } => Some(bracket),
+        Expr::StructInit { name, .. } => Some(name),
+        Expr::Range { operator, .. } => Some(operator),
+        Expr::Lambda { keyword, .. } | Expr::Await { keyword, .. } => Some(keyword),
+        _ => None,
+    }
+}
+
+fn mismatch(operator: &Token, l: &ArtValue, r: &ArtValue) -> ConstError {
+    ConstError::new(
+        format!(
+            "operator '{}' cannot be applied to {} and {}",
+            operator.lexeme,
+            l.type_name(),
+            r.type_name()
+        ),
+        Some(operator),
+    )
+}
+
+fn overflow(operator: &Token) -> ConstError {
+    ConstError::new("integer overflow in constant expression", Some(operator))
+}
+
+fn unary(operator: &Token, value: ArtValue) -> ConstResult {
+    match (operator.token_type.clone(), value) {
+        (TokenType::Minus, ArtValue::Int(n)) => {
+            n.checked_neg().map(ArtValue::Int).ok_or(overflow(operator))
+        }
+        (TokenType::Minus, ArtValue::Float(f)) => Ok(ArtValue::Float(-f)),
+        (TokenType::Bang, ArtValue::Bool(b)) => Ok(ArtValue::Bool(!b)),
+        (TokenType::Tilde, ArtValue::Int(n)) => Ok(ArtValue::Int(!n)),
+        (_, value) => Err(ConstError::new(
+            format!(
+                "operator '{}' cannot be applied to {}",
+                operator.lexeme,
+                value.type_name()
+            ),
+            Some(operator),
+        )),
+    }
+}
+
+fn binary(operator: &Token, l: ArtValue, r: ArtValue) -> ConstResult {
+    use ArtValue::{Bool, Float, Int};
+    let tt = operator.token_type.clone();
+    match tt {
+        TokenType::EqualEqual => return Ok(Bool(l == r)),
+        TokenType::BangEqual => return Ok(Bool(l != r)),
+        TokenType::Slash | TokenType::Percent
+            if matches!(r, Int(0)) || matches!(r, Float(f) if f == 0.0) =>
+        {
+            return Err(ConstError::new(
+                "division by zero in constant expression",
+                Some(operator),
+            ));
+        }
+        _ => {}
+    }
+    match (&l, &r) {
+        (Int(a), Int(b)) => {
+            let (a, b) = (*a, *b);
+            let checked = match tt {
+                TokenType::Plus => a.checked_add(b),
+                TokenType::Minus => a.checked_sub(b),
+                TokenType::Star => a.checked_mul(b),
+                TokenType::Slash => a.checked_div(b),
+                // `menor Int % -1` é 0, como no interpretador.
+                TokenType::Percent => Some(a.wrapping_rem(b)),
+                TokenType::Ampersand => Some(a & b),
+                TokenType::Pipe => Some(a | b),
+                TokenType::Caret => Some(a ^ b),
+                TokenType::LessLess | TokenType::GreaterGreater if !(0..64).contains(&b) => {
+                    return Err(ConstError::new(
+                        format!("shift amount {} out of range 0..=63", b),
+                        Some(operator),
+                    ));
+                }
+                TokenType::LessLess => Some(a.wrapping_shl(b as u32)),
+                TokenType::GreaterGreater => Some(a >> b),
+                _ => return compare(operator, &l, &r),
+            };
+            checked.map(Int).ok_or(overflow(operator))
+        }
+        (Int(_) | Float(_), Int(_) | Float(_)) => {
+            let (a, b) = (as_f64(&l), as_f64(&r));
+            Ok(Float(match tt {
+                TokenType::Plus => a + b,
+                TokenType::Minus => a - b,
+                TokenType::Star => a * b,
+                TokenType::Slash => a / b,
+                TokenType::Percent => a % b,
+                _ => return compare(operator, &l, &r),
+            }))
+        }
+        (ArtValue::String(a), ArtValue::String(b)) if tt == TokenType::Plus => {
+            Ok(ArtValue::String(Arc::from(format!("{}{}", a, b))))
+        }
+        _ => compare(operator, &l, &r),
+    }
+}
+
+fn compare(operator: &Token, l: &ArtValue, r: &ArtValue) -> ConstResult {
+    let ordering = match (l, r) {
+        (ArtValue::String(a), ArtValue::String(b)) => Some(a.cmp(b)),
+        (ArtValue::Int(a), ArtValue::Int(b)) => Some(a.cmp(b)),
+        (ArtValue::Int(_) | ArtValue::Float(_), ArtValue::Int(_) | ArtValue::Float(_)) => {
+            as_f64(l).partial_cmp(&as_f64(r))
+        }
+        _ => None,
+    };
+    let result = ordering.and_then(|o| match operator.token_type {
+        TokenType::Less => Some(o.is_lt()),
+        TokenType::LessEqual => Some(o.is_le()),
+        TokenType::Greater => Some(o.is_gt()),
+        TokenType::GreaterEqual => Some(o.is_ge()),
+        _ => None,
+    });
+    result
+        .map(ArtValue::Bool)
+        .ok_or_else(|| mismatch(operator, l, r))
+}
+
+fn as_f64(v: &ArtValue) -> f64 {
+    match v {
+        ArtValue::Int(n) => *n as f64,
+        ArtValue::Float(f) => *f,
+        _ => f64::NAN,
+    }
+}
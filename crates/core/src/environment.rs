@@ -1,4 +1,4 @@
-use crate::ast::{ArtValue, ObjHandle};
+use crate::ast::{ArtValue, ObjHandle, Stmt};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -22,6 +22,8 @@ pub struct Environment {
     pub strong_handles: Vec<ObjHandle>, // rastreia HeapComposite definidos neste escopo
     pub depth: usize,
     pub associated_arena: Option<u32>,
+    /// Corpos de `defer` registrados neste escopo, na ordem em que apareceram.
+    pub deferred: Vec<Stmt>,
 }
 
 impl Environment {
@@ -36,6 +38,7 @@ impl Environment {
             strong_handles: Vec::new(),
             depth,
             associated_arena,
+            deferred: Vec::new(),
         }
     }
 
@@ -51,6 +54,7 @@ impl Environment {
             strong_handles: Vec::new(),
             depth,
             associated_arena,
+            deferred: Vec::new(),
         }
     }
 
@@ -77,6 +81,31 @@ impl Environment {
         }
     }
 
+    /// Reatribui `name` no escopo mais próximo que o define, preservando o
+    /// rastreamento de `strong_handles` daquele escopo. Devolve `false` se o
+    /// nome não existe em nenhum escopo da cadeia.
+    pub fn assign(&mut self, name: &str, value: ArtValue) -> bool {
+        if self.values.contains_key(name) {
+            self.define(name, value);
+            return true;
+        }
+        match &self.enclosing {
+            Some(enclosing) => enclosing.borrow_mut().assign(name, value),
+            None => false,
+        }
+    }
+
+    /// Arena do escopo que define `name`: `None` se o nome não existe,
+    /// `Some(None)` se o escopo não pertence a nenhuma arena.
+    pub fn defining_arena(&self, name: &str) -> Option<Option<u32>> {
+        if self.values.contains_key(name) {
+            return Some(self.associated_arena);
+        }
+        self.enclosing
+            .as_ref()
+            .and_then(|e| e.borrow().defining_arena(name))
+    }
+
     pub fn has_locally(&self, name: &str) -> bool {
         self.values.contains_key(name)
     }
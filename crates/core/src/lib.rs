@@ -1,7 +1,10 @@
 pub mod ast;
+pub mod const_eval;
 pub mod environment;
 pub mod ffi;
 pub mod interner;
+pub mod methods;
+pub mod operators;
 pub mod token;
 pub mod types;
 
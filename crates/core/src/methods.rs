@@ -0,0 +1,507 @@
+//! Métodos dos valores embutidos: strings, coleções, números e handles.
+//!
+//! `s.split(",")` é açúcar para `str_split(s, ",")`: cada entrada liga um
+//! método de um tipo de receptor à função livre que o implementa, chamada com
+//! o receptor como primeiro argumento. A mesma tabela serve o interpretador,
+//! o type checker (tipo de retorno e aridade), a completação da LSP depois de
+//! `.` e o codegen JS.
+
+use crate::ast::ArtValue;
+use crate::types::Type;
+
+/// Tipo de retorno de um método, quando não depende dos argumentos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MethodReturn {
+    Int,
+    Bool,
+    String,
+    None,
+    StringArray,
+    /// Array de elementos não rastreados (`keys`, `to_array`).
+    Array,
+    /// O mesmo tipo do receptor (`abs`, `pow`, `clamp`).
+    Receiver,
+    /// Elemento opcional (`none` quando ausente), de tipo não rastreado.
+    Option,
+    ResultInt,
+    ResultFloat,
+}
+
+impl MethodReturn {
+    pub fn to_type(self, receiver: &Type) -> Type {
+        let result = |ok: Type| Type::EnumInstance("Result".to_string(), vec![ok, Type::String]);
+        match self {
+            MethodReturn::Int => Type::Int,
+            MethodReturn::Bool => Type::Bool,
+            MethodReturn::String => Type::String,
+            MethodReturn::None => Type::None,
+            MethodReturn::StringArray => Type::Array(Box::new(Type::String)),
+            MethodReturn::Array => Type::Array(Box::new(Type::Unknown)),
+            MethodReturn::Receiver => receiver.clone(),
+            MethodReturn::Option => Type::EnumInstance("Option".to_string(), vec![Type::Unknown]),
+            MethodReturn::ResultInt => result(Type::Int),
+            MethodReturn::ResultFloat => result(Type::Float),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct BuiltinMethod {
+    /// Nome do tipo do receptor, como em `ArtValue::type_name`.
+    pub receiver: &'static str,
+    pub name: &'static str,
+    /// Função livre chamada com o receptor na frente dos argumentos.
+    pub builtin: &'static str,
+    /// Parâmetros além do receptor, só para exibição (`"sep: String"`).
+    pub params: &'static [&'static str],
+    pub returns: MethodReturn,
+    /// Implementação no runtime JS, com o receptor como primeiro parâmetro;
+    /// vazia quando o backend JS não representa o tipo.
+    pub js: &'static str,
+}
+
+impl BuiltinMethod {
+    /// `split(sep: String) -> [String]`, para completação e hover.
+    pub fn signature(&self) -> String {
+        let receiver = match self.receiver {
+            "String" => Type::String,
+            "Int" => Type::Int,
+            "Float" => Type::Float,
+            other => Type::Struct(other.to_string()),
+        };
+        format!(
+            "{}({}) -> {}",
+            self.name,
+            self.params.join(", "),
+            self.returns.to_type(&receiver).name()
+        )
+    }
+}
+
+const fn method(
+    receiver: &'static str,
+    name: &'static str,
+    builtin: &'static str,
+    params: &'static [&'static str],
+    returns: MethodReturn,
+    js: &'static str,
+) -> BuiltinMethod {
+    BuiltinMethod {
+        receiver,
+        name,
+        builtin,
+        params,
+        returns,
+        js,
+    }
+}
+
+use MethodReturn as R;
+
+pub const BUILTIN_METHODS: &[BuiltinMethod] = &[
+    // String
+    method(
+        "String",
+        "len",
+        "len",
+        &[],
+        R::Int,
+        "(s) => BigInt([...s].length)",
+    ),
+    method(
+        "String",
+        "split",
+        "str_split",
+        &["sep: String"],
+        R::StringArray,
+        "(s, sep) => s.split(sep)",
+    ),
+    method(
+        "String",
+        "contains",
+        "str_contains",
+        &["sub: String"],
+        R::Bool,
+        "(s, sub) => s.includes(sub)",
+    ),
+    method(
+        "String",
+        "starts_with",
+        "str_starts_with",
+        &["prefix: String"],
+        R::Bool,
+        "(s, prefix) => s.startsWith(prefix)",
+    ),
+    method(
+        "String",
+        "replace",
+        "str_replace",
+        &["from: String", "to: String"],
+        R::String,
+        "(s, from, to) => s.split(from).join(to)",
+    ),
+    method(
+        "String",
+        "slice",
+        "str_slice",
+        &["start: Int", "end: Int"],
+        R::String,
+        "(s, start, end) => [...s].slice(Number(start), Number(end)).join(\"\")",
+    ),
+    method(
+        "String",
+        "to_int",
+        "str_to_int",
+        &[],
+        R::ResultInt,
+        "(s) => { const t = s.trim(); const n = /^[+-]?\\d+$/.test(t) ? BigInt(t) : null; return n === null || n !== BigInt.asIntN(64, n) ? { tag: \"Err\", payload: [\"not an integer\"] } : { tag: \"Ok\", payload: [n] }; }",
+    ),
+    method(
+        "String",
+        "to_float",
+        "str_to_float",
+        &[],
+        R::ResultFloat,
+        "(s) => { const n = parseFloat(s); return isNaN(n) ? { tag: \"Err\", payload: [\"not a float\"] } : { tag: \"Ok\", payload: [n] }; }",
+    ),
+    // Array e Tuple
+    method(
+        "Array",
+        "len",
+        "len",
+        &[],
+        R::Int,
+        "(a) => BigInt(a.length)",
+    ),
+    method(
+        "Array",
+        "join",
+        "str_join",
+        &["sep: String"],
+        R::String,
+        "(a, sep) => a.join(sep)",
+    ),
+    method(
+        "Array",
+        "sort",
+        "sort",
+        &[],
+        R::Receiver,
+        "(a) => [...a].sort((x, y) => (typeof x?.cmp === \"function\" ? Number(x.cmp(y)) : x < y ? -1 : x > y ? 1 : 0))",
+    ),
+    method(
+        "Tuple",
+        "len",
+        "len",
+        &[],
+        R::Int,
+        "(t) => BigInt(t.length)",
+    ),
+    // Map
+    method("Map", "len", "len", &[], R::Int, "(m) => BigInt(m.size)"),
+    method(
+        "Map",
+        "get",
+        "map_get",
+        &["key"],
+        R::Option,
+        "(m, k) => (m.has(k) ? m.get(k) : null)",
+    ),
+    method(
+        "Map",
+        "set",
+        "map_set",
+        &["key", "value"],
+        R::None,
+        "(m, k, v) => { m.set(k, v); return null; }",
+    ),
+    method(
+        "Map",
+        "has",
+        "map_has",
+        &["key"],
+        R::Bool,
+        "(m, k) => m.has(k)",
+    ),
+    method(
+        "Map",
+        "remove",
+        "map_remove",
+        &["key"],
+        R::Option,
+        "(m, k) => { if (!m.has(k)) return null; const v = m.get(k); m.delete(k); return v; }",
+    ),
+    method(
+        "Map",
+        "keys",
+        "map_keys",
+        &[],
+        R::Array,
+        "(m) => [...m.keys()]",
+    ),
+    method(
+        "Map",
+        "values",
+        "map_values",
+        &[],
+        R::Array,
+        "(m) => [...m.values()]",
+    ),
+    method(
+        "Map",
+        "entries",
+        "map_entries",
+        &[],
+        R::Array,
+        "(m) => [...m.entries()]",
+    ),
+    method(
+        "Map",
+        "merge",
+        "map_merge",
+        &["other: Map"],
+        R::Receiver,
+        "(a, b) => new a.constructor([...a, ...b])",
+    ),
+    // Set
+    method("Set", "len", "len", &[], R::Int, "(s) => BigInt(s.size)"),
+    method(
+        "Set",
+        "add",
+        "set_add",
+        &["value"],
+        R::None,
+        "(s, v) => { s.add(v); return null; }",
+    ),
+    method(
+        "Set",
+        "has",
+        "set_has",
+        &["value"],
+        R::Bool,
+        "(s, v) => s.has(v)",
+    ),
+    method(
+        "Set",
+        "remove",
+        "set_remove",
+        &["value"],
+        R::Bool,
+        "(s, v) => s.delete(v)",
+    ),
+    method(
+        "Set",
+        "union",
+        "set_union",
+        &["other: Set"],
+        R::Receiver,
+        "(a, b) => new a.constructor([...a, ...b])",
+    ),
+    method(
+        "Set",
+        "intersection",
+        "set_intersection",
+        &["other: Set"],
+        R::Receiver,
+        "(a, b) => new a.constructor([...a].filter((v) => b.has(v)))",
+    ),
+    method(
+        "Set",
+        "difference",
+        "set_difference",
+        &["other: Set"],
+        R::Receiver,
+        "(a, b) => new a.constructor([...a].filter((v) => !b.has(v)))",
+    ),
+    method(
+        "Set",
+        "to_array",
+        "set_to_array",
+        &[],
+        R::Array,
+        "(s) => [...s]",
+    ),
+    // Deque (no JS, um array)
+    method(
+        "Deque",
+        "len",
+        "deque_len",
+        &[],
+        R::Int,
+        "(d) => BigInt(d.length)",
+    ),
+    method(
+        "Deque",
+        "push_front",
+        "deque_push_front",
+        &["value"],
+        R::None,
+        "(d, v) => { d.unshift(v); return null; }",
+    ),
+    method(
+        "Deque",
+        "push_back",
+        "deque_push_back",
+        &["value"],
+        R::None,
+        "(d, v) => { d.push(v); return null; }",
+    ),
+    method(
+        "Deque",
+        "pop_front",
+        "deque_pop_front",
+        &[],
+        R::Option,
+        "(d) => (d.length ? d.shift() : null)",
+    ),
+    method(
+        "Deque",
+        "pop_back",
+        "deque_pop_back",
+        &[],
+        R::Option,
+        "(d) => (d.length ? d.pop() : null)",
+    ),
+    method(
+        "Deque",
+        "get",
+        "deque_get",
+        &["index: Int"],
+        R::Option,
+        "(d, i) => { const n = i < 0 ? d.length + Number(i) : Number(i); return n >= 0 && n < d.length ? d[n] : null; }",
+    ),
+    method(
+        "Deque",
+        "to_array",
+        "deque_to_array",
+        &[],
+        R::Array,
+        "(d) => [...d]",
+    ),
+    // Buffer
+    method(
+        "Buffer",
+        "len",
+        "len",
+        &[],
+        R::Int,
+        "(b) => BigInt(b.length)",
+    ),
+    // Int e Float
+    // No JS, `Int` é um `BigInt`: um resultado fora de `i64` lança, como no
+    // interpretador com `--overflow=trap`.
+    method(
+        "Int",
+        "abs",
+        "math_abs",
+        &[],
+        R::Receiver,
+        "(n) => { if (n === -(1n << 63n)) throw new RangeError(`Integer overflow: math_abs(${n}) does not fit in Int.`); return n < 0n ? -n : n; }",
+    ),
+    method(
+        "Int",
+        "pow",
+        "math_pow",
+        &["exp: Int"],
+        R::Receiver,
+        "(n, e) => { if (typeof e === \"number\") return Number(n) ** e; if (e < 0n) return null; const r = (n < -1n || n > 1n) && e > 64n ? 1n << 64n : n ** e; if (r !== BigInt.asIntN(64, r)) throw new RangeError(`Integer overflow: math_pow(${n}, ${e}) does not fit in Int.`); return r; }",
+    ),
+    method(
+        "Int",
+        "clamp",
+        "math_clamp",
+        &["min: Int", "max: Int"],
+        R::Receiver,
+        "(n, min, max) => (n < min ? min : n > max ? max : n)",
+    ),
+    method("Float", "abs", "math_abs", &[], R::Receiver, "Math.abs"),
+    method(
+        "Float",
+        "pow",
+        "math_pow",
+        &["exp: Float"],
+        R::Receiver,
+        "(n, e) => n ** Number(e)",
+    ),
+    method(
+        "Float",
+        "clamp",
+        "math_clamp",
+        &["min: Float", "max: Float"],
+        R::Receiver,
+        "(n, min, max) => Math.min(Math.max(n, min), max)",
+    ),
+    // Atomic, Mutex e Capability (sem representação no JS)
+    method("Atomic", "load", "atomic_load", &[], R::Int, ""),
+    method(
+        "Atomic",
+        "store",
+        "atomic_store",
+        &["value: Int"],
+        R::Bool,
+        "",
+    ),
+    method("Atomic", "add", "atomic_add", &["delta: Int"], R::Int, ""),
+    method("Mutex", "lock", "mutex_lock", &[], R::Bool, ""),
+    method("Mutex", "unlock", "mutex_unlock", &[], R::Bool, ""),
+    method("Capability", "kind", "capability_kind", &[], R::String, ""),
+];
+
+/// Método `name` do tipo `receiver`.
+pub fn lookup(receiver: &str, name: &str) -> Option<&'static BuiltinMethod> {
+    BUILTIN_METHODS
+        .iter()
+        .find(|m| m.receiver == receiver && m.name == name)
+}
+
+/// Métodos do tipo `receiver`, na ordem da tabela.
+pub fn methods_of(receiver: &str) -> impl Iterator<Item = &'static BuiltinMethod> + '_ {
+    BUILTIN_METHODS
+        .iter()
+        .filter(move |m| m.receiver == receiver)
+}
+
+/// `true` se algum tipo embutido tem um método com esse nome.
+pub fn is_method_name(name: &str) -> bool {
+    BUILTIN_METHODS.iter().any(|m| m.name == name)
+}
+
+/// Tipo de receptor de um valor já resolvido (fora do heap). Instâncias de
+/// struct e enum não entram: os métodos delas vêm do `impl`.
+pub fn receiver_kind(value: &ArtValue) -> Option<&'static str> {
+    Some(match value {
+        ArtValue::String(_) => "String",
+        ArtValue::Array(_) => "Array",
+        ArtValue::Tuple(_) => "Tuple",
+        ArtValue::Map(_) => "Map",
+        ArtValue::Set(_) => "Set",
+        ArtValue::Deque(_) => "Deque",
+        ArtValue::Buffer(_) => "Buffer",
+        ArtValue::Int(_) => "Int",
+        ArtValue::Float(_) => "Float",
+        ArtValue::Atomic(_) => "Atomic",
+        ArtValue::Mutex(_) => "Mutex",
+        ArtValue::Capability { .. } => "Capability",
+        _ => return None,
+    })
+}
+
+/// Tipo de receptor de um tipo estático, quando o type checker o conhece.
+pub fn type_kind(ty: &Type) -> Option<&'static str> {
+    Some(match ty {
+        Type::String => "String",
+        Type::Array(_) => "Array",
+        Type::Tuple(_) => "Tuple",
+        Type::Buffer => "Buffer",
+        Type::Int => "Int",
+        Type::Float => "Float",
+        Type::Struct(name) => match name.as_str() {
+            "Map" => "Map",
+            "Set" => "Set",
+            "Deque" => "Deque",
+            _ if name.starts_with("Capability[") => "Capability",
+            _ => return None,
+        },
+        _ => return None,
+    })
+}
@@ -0,0 +1,38 @@
+//! Sobrecarga de operadores por métodos de nome conhecido.
+//!
+//! Um `impl` que define um destes métodos faz o operador chamá-lo quando o
+//! operando da esquerda (ou o único, em `-x`) é instância do tipo: `a + b`
+//! vira `a.add(b)`, `-a` vira `a.neg()` e `f"{a}"` usa `a.to_string()`.
+//! `!=` nega `eq`; `<`, `<=`, `>` e `>=` comparam com zero o `Int` de `cmp`.
+
+use crate::token::TokenType;
+
+pub const NEG_METHOD: &str = "neg";
+pub const TO_STRING_METHOD: &str = "to_string";
+
+/// Todos os métodos de operador, para quem precisa reconhecê-los num `impl`.
+pub const OPERATOR_METHODS: &[&str] = &[
+    "add",
+    "sub",
+    "mul",
+    "div",
+    "eq",
+    "cmp",
+    NEG_METHOD,
+    TO_STRING_METHOD,
+];
+
+/// Método chamado por um operador binário, se ele for sobrecarregável.
+pub fn binary_method(op: &TokenType) -> Option<&'static str> {
+    Some(match op {
+        TokenType::Plus => "add",
+        TokenType::Minus => "sub",
+        TokenType::Star => "mul",
+        TokenType::Slash => "div",
+        TokenType::EqualEqual | TokenType::BangEqual => "eq",
+        TokenType::Less | TokenType::LessEqual | TokenType::Greater | TokenType::GreaterEqual => {
+            "cmp"
+        }
+        _ => return None,
+    })
+}
@@ -15,11 +15,25 @@ pub enum TokenType {
     Colon,
     ColonColon,
     Dot,
+    DotDot,
+    DotDotEqual,
     Arrow,
     Minus,
     Plus,
     Star,
     Slash,
+    Percent,
+    Ampersand,
+    Pipe,
+    At,
+    Caret,
+    Tilde,
+    LessLess,
+    GreaterGreater,
+    PlusEqual,
+    MinusEqual,
+    StarEqual,
+    SlashEqual,
     Bang,
     BangEqual,
     Equal,
@@ -29,12 +43,17 @@ pub enum TokenType {
     Less,
     LessEqual,
     Let,
+    Const,
+    Var,
     If,
     Else,
     True,
     False,
     Struct,
     Enum,
+    Type,
+    Newtype,
+    Pub,
     And,
     Or,
     Match,
@@ -42,17 +61,23 @@ pub enum TokenType {
     Underscore,
     Import,
     Func,
+    Async,
+    Await,
     Performant,
     Spawn,
     Actor,
     Return,
     Yield,
+    Defer,
     While,
     For,
+    Break,
+    Continue,
     In,
     Try,
     Catch,
     Impl,
+    Trait,
     Weak,      // keyword 'weak' (açúcar)
     Unowned,   // keyword 'unowned' (açúcar)
     Component, // keyword 'component'
@@ -61,12 +86,16 @@ pub enum TokenType {
     Prop,      // binding qualifier 'prop'
     Memo,      // binding qualifier 'memo'
     Ref,       // binding qualifier 'ref'
+    Label,     // rótulo de loop: `'outer` (lexema inclui o apóstrofo)
     Identifier,
     String(String),
     InterpolatedString(String), // <<< NOSSO NOVO TOKEN
-    Number(f64),
+    Int(i64),
+    Float(f64),
     None,
     Question,
+    QuestionDot,
+    QuestionQuestion,
     As,
     Eof,
 }
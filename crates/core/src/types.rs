@@ -1,3 +1,33 @@
+use crate::ast::Stmt;
+use std::collections::HashMap;
+
+/// Traits embutidos e os primitivos que os implementam. Eram os bounds
+/// genéricos aceitos antes de `trait`; continuam valendo sem declaração.
+pub const BUILTIN_TRAITS: &[(&str, &[&str])] = &[
+    ("Numeric", &["Int", "Float"]),
+    ("Eq", &["Int", "Float", "String", "Bool"]),
+    ("Hash", &["Int", "Float", "String", "Bool"]),
+    ("Comparable", &["Int", "Float", "String"]),
+];
+
+/// Comportamentos que `@derive(...)` gera para `struct` e `enum`, com o trait
+/// embutido que cada um satisfaz em bounds genéricos (`Ord` vale `Comparable`).
+pub const DERIVES: &[(&str, Option<&str>)] = &[
+    ("Eq", Some("Eq")),
+    ("Hash", Some("Hash")),
+    ("Ord", Some("Comparable")),
+    ("Debug", None),
+    ("Clone", None),
+];
+
+/// `Some(bound)` para um derive conhecido; `None` se o nome não é derivável.
+pub fn derive_bound(derive: &str) -> Option<Option<&'static str>> {
+    DERIVES
+        .iter()
+        .find(|(name, _)| *name == derive)
+        .map(|(_, bound)| *bound)
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Type {
     Int,
@@ -13,6 +43,8 @@ pub enum Type {
     GenericParam(String),
     Function(Vec<Type>, Box<Type>),
     Tuple(Vec<Type>),
+    /// `newtype Nome(T)`: nome e tipo de representação. Só é compatível consigo mesmo.
+    Newtype(String, Box<Type>),
     Unknown,
 }
 
@@ -41,7 +73,138 @@ impl Type {
                 let ts: Vec<String> = types.iter().map(|t| t.name()).collect();
                 format!("({})", ts.join(", "))
             }
+            Type::Newtype(n, _) => n.clone(),
             Type::Unknown => "_".into(),
         }
     }
 }
+
+/// `type` e `newtype` declarados num programa, por nome, com a anotação crua do
+/// alvo. Aliases somem na resolução: `UserId` resolve para o `Type` de `Int`.
+#[derive(Debug, Clone, Default)]
+pub struct TypeDecls {
+    pub aliases: HashMap<String, String>,
+    pub newtypes: HashMap<String, String>,
+}
+
+impl TypeDecls {
+    /// Colhe as declarações do topo de `program`.
+    pub fn collect(program: &[Stmt]) -> Self {
+        let mut decls = TypeDecls::default();
+        for stmt in program {
+            decls.declare(stmt);
+        }
+        decls
+    }
+
+    /// Registra `stmt` se for um `type` ou `newtype`.
+    pub fn declare(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::TypeAlias { name, target } => {
+                self.aliases.insert(name.lexeme.clone(), target.clone());
+            }
+            Stmt::Newtype { name, inner } => {
+                self.newtypes.insert(name.lexeme.clone(), inner.clone());
+            }
+            _ => {}
+        }
+    }
+
+    /// `annotation` com os aliases trocados pelos alvos. Um alias que chega a si
+    /// mesmo fica como está.
+    pub fn expand(&self, annotation: &str) -> String {
+        self.rewrite(annotation, false, &mut Vec::new())
+    }
+
+    /// Anotação do valor em tempo de execução: além dos aliases, cada newtype
+    /// vira o seu tipo de representação.
+    pub fn erase(&self, annotation: &str) -> String {
+        self.rewrite(annotation, true, &mut Vec::new())
+    }
+
+    /// `true` se `name` aparece, direta ou indiretamente, no próprio alvo.
+    pub fn is_recursive(&self, name: &str) -> bool {
+        let mut pending: Vec<&str> = self.target(name).into_iter().collect();
+        let mut seen: Vec<&str> = Vec::new();
+        while let Some(annotation) = pending.pop() {
+            for ident in identifiers(annotation) {
+                if ident == name {
+                    return true;
+                }
+                if !seen.contains(&ident)
+                    && let Some(target) = self.target(ident)
+                {
+                    seen.push(ident);
+                    pending.push(target);
+                }
+            }
+        }
+        false
+    }
+
+    fn target(&self, name: &str) -> Option<&str> {
+        self.aliases
+            .get(name)
+            .or_else(|| self.newtypes.get(name))
+            .map(String::as_str)
+    }
+
+    fn rewrite(&self, annotation: &str, erase: bool, active: &mut Vec<String>) -> String {
+        let mut out = String::new();
+        let mut rest = annotation;
+        while let Some(start) = rest.find(is_ident_char) {
+            out.push_str(&rest[..start]);
+            let len = rest[start..]
+                .find(|c| !is_ident_char(c))
+                .unwrap_or(rest.len() - start);
+            let ident = &rest[start..start + len];
+            let target = self
+                .aliases
+                .get(ident)
+                .or_else(|| self.newtypes.get(ident).filter(|_| erase));
+            match target {
+                Some(target) if !active.iter().any(|a| a == ident) => {
+                    active.push(ident.to_string());
+                    out.push_str(&self.rewrite(target, erase, active));
+                    active.pop();
+                }
+                _ => out.push_str(ident),
+            }
+            rest = &rest[start + len..];
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn identifiers(annotation: &str) -> impl Iterator<Item = &str> {
+    annotation
+        .split(|c: char| !is_ident_char(c))
+        .filter(|s| !s.is_empty())
+}
+
+/// Politica de overflow da aritmetica de `Int` (`--overflow=wrap|trap`).
+/// Interpretador, emissores C/LLVM e codegen JS seguem a mesma politica.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowMode {
+    /// Overflow eh diagnostico `Runtime` (padrao).
+    #[default]
+    Trap,
+    /// Aritmetica modular em complemento de dois.
+    Wrap,
+}
+
+impl OverflowMode {
+    /// Le o valor de `--overflow=`.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "trap" => Some(OverflowMode::Trap),
+            "wrap" => Some(OverflowMode::Wrap),
+            _ => None,
+        }
+    }
+}
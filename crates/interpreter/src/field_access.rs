@@ -5,17 +5,26 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
+/// `receiver` é o valor ligado a `self` quando `field` resolve para um método.
+/// Passar o handle (`HeapComposite`) em vez da instância copiada permite que o
+/// método altere o objeto original via `self.campo = ...`.
 pub fn struct_field_or_method(
     struct_name: &str,
     fields: &HashMap<String, ArtValue>,
     field: &core::Token,
     type_registry: &TypeRegistry,
+    receiver: &ArtValue,
 ) -> Option<ArtValue> {
     if let Some(v) = fields.get(&field.lexeme) {
         return Some(v.clone());
     }
-    if let Some(sdef) = type_registry.structs.get(struct_name)
-        && let Some(m) = sdef.methods.get(&field.lexeme)
+    // Métodos próprios (inclusive os de `impl Trait for`) têm precedência sobre
+    // os defaults dos traits implementados.
+    if let Some(m) = type_registry
+        .structs
+        .get(struct_name)
+        .and_then(|sdef| sdef.methods.get(&field.lexeme))
+        .or_else(|| type_registry.trait_default(struct_name, &field.lexeme))
     {
         let mut new_params = m.params.clone();
         let drop_self = new_params
@@ -34,13 +43,7 @@ pub fn struct_field_or_method(
             base_env.borrow().depth + 1,
             None,
         )));
-        bound_env.borrow_mut().define(
-            "self",
-            ArtValue::StructInstance {
-                struct_name: struct_name.to_string(),
-                fields: fields.clone(),
-            },
-        );
+        bound_env.borrow_mut().define("self", receiver.clone());
         let bound_fn = Function {
             name: m.name.clone(),
             type_params: m.type_params.clone(),
@@ -48,6 +51,7 @@ pub fn struct_field_or_method(
             body: m.body.clone(),
             closure: Rc::downgrade(&bound_env),
             retained_env: Some(bound_env),
+            is_async: m.is_async,
         };
         return Some(ArtValue::Function(Rc::new(bound_fn)));
     }
@@ -93,8 +97,11 @@ pub fn enum_method(
         }
     }
 
-    if let Some(edef) = type_registry.enums.get(enum_name)
-        && let Some(m) = edef.methods.get(&field.lexeme)
+    if let Some(m) = type_registry
+        .enums
+        .get(enum_name)
+        .and_then(|edef| edef.methods.get(&field.lexeme))
+        .or_else(|| type_registry.trait_default(enum_name, &field.lexeme))
     {
         let mut new_params = m.params.clone();
         let drop_self = new_params
@@ -132,6 +139,7 @@ pub fn enum_method(
             body: m.body.clone(),
             closure: Rc::downgrade(&bound_env),
             retained_env: Some(bound_env),
+            is_async: m.is_async,
         };
         return Some(ArtValue::Function(Rc::new(bound_fn)));
     }
@@ -1,9 +1,12 @@
 use crate::values::Result;
 use core::ast::{ArtValue, Expr, InterpolatedPart};
 
+/// `eval` recebe cada expressão interpolada com o formato pedido (`{x:debug}`
+/// → `Some("debug")`) e devolve o valor a exibir; para `debug`, já a string
+/// final.
 pub fn eval_fstring(
     parts: Vec<InterpolatedPart>,
-    mut eval: impl FnMut(Expr) -> Result<ArtValue>,
+    mut eval: impl FnMut(Expr, Option<&str>) -> Result<ArtValue>,
 ) -> Result<ArtValue> {
     let cap: usize = parts
         .iter()
@@ -17,16 +20,14 @@ pub fn eval_fstring(
         match part {
             InterpolatedPart::Literal(s) => result.push_str(&s),
             InterpolatedPart::Expr { expr, format } => {
-                let val = eval(*expr)?;
+                let val = eval(*expr, format.as_deref())?;
                 let mut seg = val.to_string();
                 if let Some(spec) = format {
                     match spec.as_str() {
                         "upper" => seg = seg.to_uppercase(),
                         "lower" => seg = seg.to_lowercase(),
                         "trim" => seg = seg.trim().to_string(),
-                        "debug" => {
-                            seg = format!("{:?}", val.clone());
-                        }
+                        "debug" => {}
                         s if s.starts_with("pad") => {
                             if let Ok(width) = s[3..].parse::<usize>()
                                 && seg.len() < width
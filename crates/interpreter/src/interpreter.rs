@@ -1,5 +1,6 @@
 use crate::type_registry::TypeRegistry;
 use crate::values::{Result, RuntimeError};
+use core::OverflowMode;
 use core::ast::{ArtValue, Expr, Function, ObjHandle, Program};
 use core::environment::Environment;
 use diagnostics::{Diagnostic, DiagnosticKind, Span};
@@ -57,11 +58,21 @@ fn did_you_mean<'a>(target: &str, candidates: impl Iterator<Item = &'a str>) ->
 pub mod actors;
 pub use actors::{ActorState, Mailbox, decode_val, encode_val};
 pub mod builtins;
+pub mod collections;
 pub mod cycle_detection;
 pub use cycle_detection::{CycleDetectionResult, CycleInfo, CycleReport};
+pub mod derives;
 pub mod eval;
 pub mod exec;
 pub mod gc;
+pub mod generators;
+pub mod generics;
+pub mod indexing;
+pub mod iteration;
+pub mod operators;
+pub mod optional;
+pub mod overloading;
+pub mod tasks;
 
 #[cfg(test)]
 pub mod test_helpers;
@@ -70,6 +81,11 @@ pub struct Interpreter {
     environment: Rc<RefCell<Environment>>,
     type_registry: TypeRegistry,
     pure_mode: bool,
+    overflow: OverflowMode,
+    /// Valores dos `const` já executados, para avaliar os seguintes.
+    consts: core::const_eval::ConstEnv,
+    /// `type`/`newtype` já executados; anotações são comparadas já apagadas.
+    type_decls: core::types::TypeDecls,
     pub diagnostics: Vec<Diagnostic>,
     pub last_value: Option<ArtValue>,
     pub handled_errors: usize,
@@ -130,14 +146,22 @@ pub struct Interpreter {
     // that need to access the running actor can find it even while the actor is
     // removed from `actors` to avoid mutable borrow conflicts.
     pub executing_actor: Option<ActorState>,
+    // Generators suspensos, por id do objeto `__Generator`
+    generators: HashMap<u64, generators::Coroutine>,
+    next_generator_id: u64,
+    // Tasks do scheduler (`async func`, `sleep`, `actor_ask`), por id do objeto `__Task`
+    tasks: std::collections::BTreeMap<u64, tasks::TaskState>,
+    next_task_id: u64,
     // Random State (LCG)
     pub rng_state: u64,
     // Recursion depth guard for evaluate() — prevents stack overflow on pathological AST inputs
     eval_depth: usize,
     // Pilha de arenas para ARC Adaptativo Implícito
     pub arena_stack: Vec<u32>,
-    // Span of the most recent field-access call site, used by builtins for error reporting
+    // Span of the most recent call site, used by builtins and generic checks for error reporting
     pub call_span: Span,
+    // Funções genéricas já instanciadas (uma entrada por função e tupla de tipos)
+    specializations: HashMap<generics::SpecializationKey, generics::Specialization>,
 }
 
 #[cfg(test)]
@@ -175,6 +199,7 @@ mod tests {
                     body: Rc::new(Stmt::Block { statements: vec![] }),
                     closure: std::rc::Weak::new(),
                     retained_env: None,
+                    is_async: false,
                 }),
             );
         }
@@ -188,6 +213,7 @@ mod tests {
                     body: Rc::new(Stmt::Block { statements: vec![] }),
                     closure: std::rc::Weak::new(),
                     retained_env: None,
+                    is_async: false,
                 }),
             );
         }
@@ -285,6 +311,8 @@ impl Interpreter {
         "envelope",
         "make_envelope",
         "run_actors",
+        "actor_ask",
+        "sleep",
         "atomic_new",
         "atomic_load",
         "atomic_store",
@@ -311,6 +339,15 @@ impl Interpreter {
         "set_has",
         "math_abs",
         "math_pow",
+        "wrapping_add",
+        "wrapping_sub",
+        "wrapping_mul",
+        "checked_add",
+        "checked_sub",
+        "checked_mul",
+        "saturating_add",
+        "saturating_sub",
+        "saturating_mul",
         "math_clamp",
         "dag_topo_sort",
         "time_now",
@@ -339,6 +376,20 @@ impl Interpreter {
         "deque_pop_front",
         "deque_pop_back",
         "deque_len",
+        "deque_get",
+        "deque_to_array",
+        "map_remove",
+        "map_keys",
+        "map_values",
+        "map_entries",
+        "map_merge",
+        "set_remove",
+        "set_union",
+        "set_intersection",
+        "set_difference",
+        "set_to_array",
+        "sort",
+        "hash",
     ];
 
     #[inline]
@@ -361,6 +412,8 @@ impl Interpreter {
             "envelope" => BuiltinFn::EnvelopeNew,
             "make_envelope" => BuiltinFn::MakeEnvelope,
             "run_actors" => BuiltinFn::RunActors,
+            "actor_ask" => BuiltinFn::ActorAsk,
+            "sleep" => BuiltinFn::Sleep,
             "atomic_new" => BuiltinFn::AtomicNew,
             "atomic_load" => BuiltinFn::AtomicLoad,
             "atomic_store" => BuiltinFn::AtomicStore,
@@ -387,6 +440,15 @@ impl Interpreter {
             "set_has" => BuiltinFn::SetHas,
             "math_abs" => BuiltinFn::MathAbs,
             "math_pow" => BuiltinFn::MathPow,
+            "wrapping_add" => BuiltinFn::WrappingAdd,
+            "wrapping_sub" => BuiltinFn::WrappingSub,
+            "wrapping_mul" => BuiltinFn::WrappingMul,
+            "checked_add" => BuiltinFn::CheckedAdd,
+            "checked_sub" => BuiltinFn::CheckedSub,
+            "checked_mul" => BuiltinFn::CheckedMul,
+            "saturating_add" => BuiltinFn::SaturatingAdd,
+            "saturating_sub" => BuiltinFn::SaturatingSub,
+            "saturating_mul" => BuiltinFn::SaturatingMul,
             "math_clamp" => BuiltinFn::MathClamp,
             "dag_topo_sort" => BuiltinFn::DagTopoSort,
             "time_now" => BuiltinFn::TimeNow,
@@ -415,6 +477,20 @@ impl Interpreter {
             "deque_pop_front" => BuiltinFn::DequePopFront,
             "deque_pop_back" => BuiltinFn::DequePopBack,
             "deque_len" => BuiltinFn::DequeLen,
+            "deque_get" => BuiltinFn::DequeGet,
+            "deque_to_array" => BuiltinFn::DequeToArray,
+            "map_remove" => BuiltinFn::MapRemove,
+            "map_keys" => BuiltinFn::MapKeys,
+            "map_values" => BuiltinFn::MapValues,
+            "map_entries" => BuiltinFn::MapEntries,
+            "map_merge" => BuiltinFn::MapMerge,
+            "set_remove" => BuiltinFn::SetRemove,
+            "set_union" => BuiltinFn::SetUnion,
+            "set_intersection" => BuiltinFn::SetIntersection,
+            "set_difference" => BuiltinFn::SetDifference,
+            "set_to_array" => BuiltinFn::SetToArray,
+            "sort" => BuiltinFn::Sort,
+            "hash" => BuiltinFn::Hash,
             _ => unreachable!("Unknown builtin name: {}", name),
         }
     }
@@ -432,6 +508,9 @@ impl Interpreter {
             environment: global_env,
             type_registry: TypeRegistry::new(),
             pure_mode: false,
+            overflow: OverflowMode::Trap,
+            consts: core::const_eval::ConstEnv::new(),
+            type_decls: core::types::TypeDecls::default(),
             diagnostics: Vec::new(),
             last_value: None,
             handled_errors: 0,
@@ -476,10 +555,15 @@ impl Interpreter {
             current_actor: None,
             actor_mailbox_limit: 1000,
             executing_actor: None,
+            generators: HashMap::new(),
+            next_generator_id: 1,
+            tasks: std::collections::BTreeMap::new(),
+            next_task_id: 1,
             rng_state: 0x12345678, // deterministic for v0.2.0 testing
             eval_depth: 0,
             arena_stack: Vec::new(),
             call_span: Span::new(0, 0, 0, 0),
+            specializations: HashMap::new(),
         }
     }
 
@@ -493,6 +577,11 @@ impl Interpreter {
         self.pure_mode = pure;
     }
 
+    /// Politica de overflow de `Int` (`--overflow=wrap|trap`).
+    pub fn set_overflow_mode(&mut self, mode: OverflowMode) {
+        self.overflow = mode;
+    }
+
     fn ensure_pure_allowed(&mut self, op_name: &str) -> bool {
         if self.pure_mode {
             self.diagnostics.push(Diagnostic::new(
@@ -616,7 +705,7 @@ impl Interpreter {
         let mut map = std::collections::HashMap::new();
         for (field_name, field_ty) in &struct_def.fields {
             map.insert(
-                field_name.clone(),
+                core::ast::MapKey::from(field_name.as_str()),
                 ArtValue::String(Arc::from(field_ty.clone())),
             );
         }
@@ -760,10 +849,11 @@ impl Interpreter {
         Ok(result)
     }
 
+    /// `(source, ops)` de um `__Stream`; `source` é um array ou um generator.
     fn decode_stream_value(
         &self,
         value: ArtValue,
-    ) -> std::result::Result<(Vec<ArtValue>, Vec<ArtValue>), String> {
+    ) -> std::result::Result<(ArtValue, Vec<ArtValue>), String> {
         let resolved = self.resolve_composite(&value).clone();
         if let ArtValue::StructInstance {
             struct_name,
@@ -774,7 +864,8 @@ impl Interpreter {
                 return Err("Expected stream value".to_string());
             }
             let source = match fields.get("source") {
-                Some(ArtValue::Array(v)) => v.clone(),
+                Some(v @ ArtValue::Array(_)) => v.clone(),
+                Some(v) if self.generator_id(v).is_some() => v.clone(),
                 _ => return Err("Malformed stream: missing source array".to_string()),
             };
             let ops = match fields.get("ops") {
@@ -787,9 +878,9 @@ impl Interpreter {
         }
     }
 
-    fn build_stream_value(&self, source: Vec<ArtValue>, ops: Vec<ArtValue>) -> ArtValue {
+    fn build_stream_value(&self, source: ArtValue, ops: Vec<ArtValue>) -> ArtValue {
         let mut fields = HashMap::new();
-        fields.insert("source".to_string(), ArtValue::Array(source));
+        fields.insert("source".to_string(), source);
         fields.insert("ops".to_string(), ArtValue::Array(ops));
         ArtValue::StructInstance {
             struct_name: "__Stream".to_string(),
@@ -824,62 +915,73 @@ impl Interpreter {
         }
     }
 
+    /// Drena o stream inteiro (`collect`/`count`).
     fn run_stream_pipeline(
         &mut self,
-        source: Vec<ArtValue>,
+        source: ArtValue,
         ops: Vec<ArtValue>,
     ) -> Result<Vec<ArtValue>> {
+        let mut stream = self.stream_source(source, ops);
         let mut out = Vec::new();
-        'outer: for mut item in source {
-            for op in &ops {
-                match op {
-                    ArtValue::Tuple(parts) if parts.len() == 2 => {
-                        let op_name = match &parts[0] {
-                            ArtValue::String(s) => s.as_ref(),
-                            _ => {
-                                self.diagnostics.push(Diagnostic::new(
-                                    DiagnosticKind::Runtime,
-                                    "Malformed stream op: invalid operation name".to_string(),
-                                    Span::new(0, 0, 0, 0),
-                                ));
-                                return Ok(Vec::new());
-                            }
-                        };
-                        let callable = parts[1].clone();
-                        match op_name {
-                            "map" => {
-                                item = self.invoke_callable_with_values(callable, vec![item])?;
-                            }
-                            "filter" => {
-                                let keep =
-                                    self.invoke_callable_with_values(callable, vec![item.clone()])?;
-                                if !self.is_truthy(&keep) {
-                                    continue 'outer;
-                                }
-                            }
-                            _ => {
-                                self.diagnostics.push(Diagnostic::new(
-                                    DiagnosticKind::Runtime,
-                                    format!("Unsupported stream operation '{}'", op_name),
-                                    Span::new(0, 0, 0, 0),
-                                ));
-                                return Ok(Vec::new());
+        while let Some(item) = self.for_source_next(&mut stream, Span::new(0, 0, 0, 0))? {
+            out.push(item);
+        }
+        Ok(out)
+    }
+
+    /// Passa `item` pelas operações do stream; `None` se algum `filter` o descartou.
+    fn apply_stream_ops(
+        &mut self,
+        ops: &[ArtValue],
+        mut item: ArtValue,
+    ) -> Result<Option<ArtValue>> {
+        for op in ops {
+            match op {
+                ArtValue::Tuple(parts) if parts.len() == 2 => {
+                    let op_name = match &parts[0] {
+                        ArtValue::String(s) => s.as_ref(),
+                        _ => {
+                            self.diagnostics.push(Diagnostic::new(
+                                DiagnosticKind::Runtime,
+                                "Malformed stream op: invalid operation name".to_string(),
+                                Span::new(0, 0, 0, 0),
+                            ));
+                            return Ok(None);
+                        }
+                    };
+                    let callable = parts[1].clone();
+                    match op_name {
+                        "map" => {
+                            item = self.invoke_callable_with_values(callable, vec![item])?;
+                        }
+                        "filter" => {
+                            let keep =
+                                self.invoke_callable_with_values(callable, vec![item.clone()])?;
+                            if !self.is_truthy(&keep) {
+                                return Ok(None);
                             }
                         }
+                        _ => {
+                            self.diagnostics.push(Diagnostic::new(
+                                DiagnosticKind::Runtime,
+                                format!("Unsupported stream operation '{}'", op_name),
+                                Span::new(0, 0, 0, 0),
+                            ));
+                            return Ok(None);
+                        }
                     }
-                    _ => {
-                        self.diagnostics.push(Diagnostic::new(
-                            DiagnosticKind::Runtime,
-                            "Malformed stream operation payload".to_string(),
-                            Span::new(0, 0, 0, 0),
-                        ));
-                        return Ok(Vec::new());
-                    }
+                }
+                _ => {
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::Runtime,
+                        "Malformed stream operation payload".to_string(),
+                        Span::new(0, 0, 0, 0),
+                    ));
+                    return Ok(None);
                 }
             }
-            out.push(item);
         }
-        Ok(out)
+        Ok(Some(item))
     }
 
     /// Exposto para testes / prototipagem: registra struct dinâmica.
@@ -895,6 +997,11 @@ impl Interpreter {
                 break;
             }
         }
+        // `defer` no topo do arquivo roda no fim do programa, sem mudar o valor final.
+        let last_value = self.last_value.take();
+        let global = self.environment.clone();
+        let _ = self.run_deferred(&global, Ok(()));
+        self.last_value = last_value;
         Ok(())
     }
     pub fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
@@ -1369,19 +1476,72 @@ impl Interpreter {
                 )))
             }
             ArtValue::Set(set_ref) => {
+                // Elementos já são cópias fora do heap.
                 let set = set_ref.0.lock().unwrap_or_else(|e| e.into_inner());
-                let out: Vec<ArtValue> = set
-                    .iter()
-                    .map(|v| self.normalize_for_serialization(v))
-                    .collect();
                 ArtValue::Set(core::ast::SetRef(std::sync::Arc::new(
-                    std::sync::Mutex::new(out),
+                    std::sync::Mutex::new(set.clone()),
                 )))
             }
             _ => resolved.clone(),
         }
     }
 
+    /// Completa, depois de `deserialize`, os campos com default que a mensagem
+    /// não traz (ela pode vir de uma versão anterior da struct).
+    fn fill_struct_defaults(&mut self, value: ArtValue) -> Result<ArtValue> {
+        Ok(match value {
+            ArtValue::StructInstance {
+                struct_name,
+                mut fields,
+            } => {
+                for (k, v) in std::mem::take(&mut fields) {
+                    fields.insert(k, self.fill_struct_defaults(v)?);
+                }
+                if let Some(def) = self.type_registry.get_struct(&struct_name).cloned() {
+                    for (name, default) in def.defaults {
+                        if let std::collections::hash_map::Entry::Vacant(slot) = fields.entry(name)
+                        {
+                            slot.insert(self.evaluate(default)?);
+                        }
+                    }
+                }
+                ArtValue::StructInstance {
+                    struct_name,
+                    fields,
+                }
+            }
+            ArtValue::Array(items) => ArtValue::Array(
+                items
+                    .into_iter()
+                    .map(|v| self.fill_struct_defaults(v))
+                    .collect::<Result<_>>()?,
+            ),
+            ArtValue::Tuple(items) => ArtValue::Tuple(
+                items
+                    .into_iter()
+                    .map(|v| self.fill_struct_defaults(v))
+                    .collect::<Result<_>>()?,
+            ),
+            ArtValue::Optional(inner) => match *inner {
+                Some(v) => ArtValue::Optional(Box::new(Some(self.fill_struct_defaults(v)?))),
+                None => ArtValue::none(),
+            },
+            ArtValue::EnumInstance {
+                enum_name,
+                variant,
+                values,
+            } => ArtValue::EnumInstance {
+                enum_name,
+                variant,
+                values: values
+                    .into_iter()
+                    .map(|v| self.fill_struct_defaults(v))
+                    .collect::<Result<_>>()?,
+            },
+            other => other,
+        })
+    }
+
     /// Test helper: define valor no ambiente global
     pub fn debug_define_global(&mut self, name: &str, val: ArtValue) {
         // Mimic the real `let` semantics: if a previous value exists, decrement its heap refs
@@ -1703,17 +1863,15 @@ impl Interpreter {
     }
 
     fn is_equal(&self, a: &ArtValue, b: &ArtValue) -> bool {
-        a == b
+        self.values_equal(a, b)
     }
 
+    /// `-` e `*` com algum `Float`; `Int op Int` já foi resolvido por `eval_int_arith`.
     fn binary_num_op<F>(&self, left: ArtValue, right: ArtValue, op: F) -> Result<ArtValue>
     where
         F: Fn(f64, f64) -> f64,
     {
         match (left, right) {
-            (ArtValue::Int(l), ArtValue::Int(r)) => {
-                Ok(ArtValue::Int(op(l as f64, r as f64) as i64))
-            }
             (ArtValue::Float(l), ArtValue::Float(r)) => Ok(ArtValue::Float(op(l, r))),
             (ArtValue::Int(l), ArtValue::Float(r)) => Ok(ArtValue::Float(op(l as f64, r))),
             (ArtValue::Float(l), ArtValue::Int(r)) => Ok(ArtValue::Float(op(l, r as f64))),
@@ -1727,17 +1885,23 @@ impl Interpreter {
         }
     }
 
+    /// Compara pela ordenação; `NaN` não satisfaz nenhuma comparação. Fora dos
+    /// números vale `compare_values` (strings, tuplas, `derive(Ord)`).
     fn binary_cmp_op<F>(&self, left: ArtValue, right: ArtValue, op: F) -> Result<ArtValue>
     where
-        F: Fn(f64, f64) -> bool,
+        F: Fn(std::cmp::Ordering) -> bool,
     {
-        match (left, right) {
-            (ArtValue::Int(l), ArtValue::Int(r)) => Ok(ArtValue::Bool(op(l as f64, r as f64))),
-            (ArtValue::Float(l), ArtValue::Float(r)) => Ok(ArtValue::Bool(op(l, r))),
-            (ArtValue::Int(l), ArtValue::Float(r)) => Ok(ArtValue::Bool(op(l as f64, r))),
-            (ArtValue::Float(l), ArtValue::Int(r)) => Ok(ArtValue::Bool(op(l, r as f64))),
-            _ => Ok(ArtValue::none()),
-        }
+        let ordering = match (left, right) {
+            (ArtValue::Int(l), ArtValue::Int(r)) => Some(l.cmp(&r)),
+            (ArtValue::Float(l), ArtValue::Float(r)) => l.partial_cmp(&r),
+            (ArtValue::Int(l), ArtValue::Float(r)) => (l as f64).partial_cmp(&r),
+            (ArtValue::Float(l), ArtValue::Int(r)) => l.partial_cmp(&(r as f64)),
+            (l, r) => match self.compare_values(&l, &r) {
+                Some(ordering) => Some(ordering),
+                None => return Ok(ArtValue::none()),
+            },
+        };
+        Ok(ArtValue::Bool(ordering.is_some_and(op)))
     }
 }
 
@@ -2,7 +2,7 @@ use std::cell::RefCell;
 use std::collections::{BTreeMap, VecDeque};
 use std::rc::Rc;
 
-use core::ast::{ArtValue, Stmt};
+use core::ast::{ArtValue, MapKey, Stmt};
 use core::environment::Environment;
 
 use super::Interpreter;
@@ -184,12 +184,19 @@ pub fn encode_val(val: &ArtValue, out: &mut Vec<u8>) -> std::result::Result<(),
         }
         ArtValue::Map(m) => {
             let map = m.0.lock().unwrap_or_else(|e| e.into_inner());
-            out.push(8);
+            // Só chaves String: o formato original (tag 8), que traces antigos
+            // usam. Outras chaves vão como valores completos (tag 15).
+            let string_keys = map.keys().all(|k| matches!(k, MapKey::String(_)));
+            out.push(if string_keys { 8 } else { 15 });
             out.extend_from_slice(&(map.len() as u32).to_le_bytes());
             for (k, v) in map.iter() {
-                let k_bytes = k.as_bytes();
-                out.extend_from_slice(&(k_bytes.len() as u32).to_le_bytes());
-                out.extend_from_slice(k_bytes);
+                match k {
+                    MapKey::String(s) if string_keys => {
+                        out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+                        out.extend_from_slice(s.as_bytes());
+                    }
+                    _ => encode_val(&k.to_value(), out)?,
+                }
                 encode_val(v, out)?;
             }
         }
@@ -198,7 +205,7 @@ pub fn encode_val(val: &ArtValue, out: &mut Vec<u8>) -> std::result::Result<(),
             out.push(9);
             out.extend_from_slice(&(set.len() as u32).to_le_bytes());
             for item in set.iter() {
-                encode_val(item, out)?;
+                encode_val(&item.to_value(), out)?;
             }
         }
         ArtValue::Buffer(buf) => {
@@ -210,11 +217,19 @@ pub fn encode_val(val: &ArtValue, out: &mut Vec<u8>) -> std::result::Result<(),
             struct_name,
             fields,
         } => {
-            out.push(11);
+            // Struct tupla: só os valores, em ordem, sem os nomes `0`, `1`, ...
+            let positional = core::ast::tuple_struct_fields(fields);
+            out.push(if positional.is_some() { 14 } else { 11 });
             let n_bytes = struct_name.as_bytes();
             out.extend_from_slice(&(n_bytes.len() as u32).to_le_bytes());
             out.extend_from_slice(n_bytes);
             out.extend_from_slice(&(fields.len() as u32).to_le_bytes());
+            if let Some(items) = positional {
+                for v in items {
+                    encode_val(v, out)?;
+                }
+                return Ok(());
+            }
             for (k, v) in fields {
                 let k_bytes = k.as_bytes();
                 out.extend_from_slice(&(k_bytes.len() as u32).to_le_bytes());
@@ -251,6 +266,12 @@ pub fn encode_val(val: &ArtValue, out: &mut Vec<u8>) -> std::result::Result<(),
     Ok(())
 }
 
+/// Chave de `Map` (tag 15) ou elemento de `Set`, gravados como valores.
+fn decode_key(cur: &mut std::io::Cursor<&[u8]>) -> std::result::Result<MapKey, String> {
+    let value = decode_val(cur)?;
+    MapKey::from_value(&value).ok_or_else(|| format!("Cannot use {} as a map key", value))
+}
+
 pub fn decode_val(cur: &mut std::io::Cursor<&[u8]>) -> std::result::Result<ArtValue, String> {
     use std::io::Read;
     let mut tag = [0u8; 1];
@@ -303,7 +324,20 @@ pub fn decode_val(cur: &mut std::io::Cursor<&[u8]>) -> std::result::Result<ArtVa
                 let mut k_b = vec![0u8; u32::from_le_bytes(lb) as usize];
                 cur.read_exact(&mut k_b).map_err(|_| "EOF map k")?;
                 let k_str = String::from_utf8(k_b).map_err(|_| "UTF8 error")?;
-                map.insert(k_str, decode_val(cur)?);
+                map.insert(MapKey::from(k_str.as_str()), decode_val(cur)?);
+            }
+            Ok(ArtValue::Map(core::ast::MapRef(std::sync::Arc::new(
+                std::sync::Mutex::new(map),
+            ))))
+        }
+        15 => {
+            let mut b = [0u8; 4];
+            cur.read_exact(&mut b).map_err(|_| "EOF map len")?;
+            let len = u32::from_le_bytes(b) as usize;
+            let mut map = std::collections::HashMap::with_capacity(len);
+            for _ in 0..len {
+                let key = decode_key(cur)?;
+                map.insert(key, decode_val(cur)?);
             }
             Ok(ArtValue::Map(core::ast::MapRef(std::sync::Arc::new(
                 std::sync::Mutex::new(map),
@@ -313,9 +347,9 @@ pub fn decode_val(cur: &mut std::io::Cursor<&[u8]>) -> std::result::Result<ArtVa
             let mut b = [0u8; 4];
             cur.read_exact(&mut b).map_err(|_| "EOF set len")?;
             let len = u32::from_le_bytes(b) as usize;
-            let mut set = Vec::with_capacity(len);
+            let mut set = core::ast::KeySet::default();
             for _ in 0..len {
-                set.push(decode_val(cur)?);
+                set.insert(decode_key(cur)?);
             }
             Ok(ArtValue::Set(core::ast::SetRef(std::sync::Arc::new(
                 std::sync::Mutex::new(set),
@@ -387,13 +421,54 @@ pub fn decode_val(cur: &mut std::io::Cursor<&[u8]>) -> std::result::Result<ArtVa
             }
             Ok(ArtValue::Tuple(tup))
         }
+        14 => {
+            let mut lb = [0u8; 4];
+            cur.read_exact(&mut lb).map_err(|_| "EOF struct name len")?;
+            let mut sn_b = vec![0u8; u32::from_le_bytes(lb) as usize];
+            cur.read_exact(&mut sn_b).map_err(|_| "EOF struct name")?;
+            let struct_name = String::from_utf8(sn_b).map_err(|_| "UTF8 error")?;
+            let mut fb = [0u8; 4];
+            cur.read_exact(&mut fb).map_err(|_| "EOF fields len")?;
+            let f_len = u32::from_le_bytes(fb) as usize;
+            let mut fields = std::collections::HashMap::with_capacity(f_len);
+            for i in 0..f_len {
+                fields.insert(i.to_string(), decode_val(cur)?);
+            }
+            Ok(ArtValue::StructInstance {
+                struct_name,
+                fields,
+            })
+        }
         t => Err(format!("Unknown tag {}", t)),
     }
 }
 
 impl Interpreter {
-    pub fn run_actors_round_robin(&mut self, max_steps: usize) {
+    /// Entrega `envelope` na caixa do ator `aid`, inclusive do que está executando
+    /// agora, e o desestaciona. `None` se o ator não existe; `Some(false)` se a
+    /// caixa está cheia.
+    pub(super) fn deliver_to_actor(
+        &mut self,
+        aid: u32,
+        envelope: core::ast::ValueEnvelope,
+    ) -> Option<bool> {
+        let actor = match self.actors.get_mut(&aid) {
+            Some(actor) => actor,
+            None => self.executing_actor.as_mut().filter(|a| a.id == aid)?,
+        };
+        if actor.mailbox.len() >= actor.mailbox_limit {
+            return Some(false);
+        }
+        actor.mailbox.insert(envelope);
+        actor.parked = false;
+        Some(true)
+    }
+
+    /// Roda os atores, um statement por vez, até ficarem ociosos ou até `max_steps`.
+    /// Devolve quantos statements foram executados.
+    pub fn run_actors_round_robin(&mut self, max_steps: usize) -> usize {
         let mut steps = 0usize;
+        let mut executed = 0usize;
         let mut actor_ids: Vec<u32> = self.actors.keys().cloned().collect();
         actor_ids.sort_unstable();
         let mut idx = 0usize;
@@ -491,8 +566,19 @@ impl Interpreter {
                         self.environment = actor_env_before_stmt;
                     }
 
+                    // O corpo do ator terminou: os `defer` do topo dele rodam agora.
+                    if self
+                        .executing_actor
+                        .as_ref()
+                        .is_some_and(|a| a.body.is_empty() && !a.parked)
+                    {
+                        let actor_env = self.environment.clone();
+                        let _ = self.run_deferred(&actor_env, Ok(()));
+                    }
+
                     // Mark that we made progress this rotation (executed a statement)
                     rotation_progress = true;
+                    executed += 1;
                     // restore env
                     if let Some(act) = &mut self.executing_actor {
                         act.env = self.environment.clone();
@@ -528,5 +614,6 @@ impl Interpreter {
         for id in finished_ids {
             self.actors.remove(&id);
         }
+        executed
     }
 }
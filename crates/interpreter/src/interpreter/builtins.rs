@@ -81,73 +81,14 @@ impl Interpreter {
             core::ast::BuiltinFn::MapNew => Ok(ArtValue::Map(core::ast::MapRef(
                 std::sync::Arc::new(std::sync::Mutex::new(std::collections::HashMap::new())),
             ))),
-            core::ast::BuiltinFn::MapSet => {
-                let mut args = arguments.into_iter();
-                if let (Some(map_expr), Some(key_expr), Some(val_expr)) =
-                    (args.next(), args.next(), args.next())
-                {
-                    let map_val = self.evaluate(map_expr)?;
-                    let key_val = self.evaluate(key_expr)?;
-                    let v = self.evaluate(val_expr)?;
-                    if let (ArtValue::Map(m), ArtValue::String(k)) = (map_val, key_val) {
-                        m.0.lock()
-                            .unwrap_or_else(|e| e.into_inner())
-                            .insert(k.to_string(), v);
-                        Ok(ArtValue::none())
-                    } else {
-                        self.diagnostics.push(Diagnostic::new(
-                            DiagnosticKind::Runtime,
-                            "map_set: invalid arguments".to_string(),
-                            self.call_span,
-                        ));
-                        Ok(ArtValue::none())
-                    }
-                } else {
-                    Ok(ArtValue::none())
-                }
-            }
-            core::ast::BuiltinFn::MapGet => {
-                let mut args = arguments.into_iter();
-                if let (Some(map_expr), Some(key_expr)) = (args.next(), args.next()) {
-                    let map_val = self.evaluate(map_expr)?;
-                    let key_val = self.evaluate(key_expr)?;
-                    if let (ArtValue::Map(m), ArtValue::String(k)) = (map_val, key_val) {
-                        let map = m.0.lock().unwrap_or_else(|e| e.into_inner());
-                        if let Some(v) = map.get(k.as_ref()) {
-                            Ok(ArtValue::Optional(Box::new(Some(v.clone()))))
-                        } else {
-                            Ok(ArtValue::none())
-                        }
-                    } else {
-                        Ok(ArtValue::none())
-                    }
-                } else {
-                    Ok(ArtValue::none())
-                }
-            }
-            core::ast::BuiltinFn::MapHas => {
-                let mut args = arguments.into_iter();
-                if let (Some(map_expr), Some(key_expr)) = (args.next(), args.next()) {
-                    let map_val = self.evaluate(map_expr)?;
-                    let key_val = self.evaluate(key_expr)?;
-                    if let (ArtValue::Map(m), ArtValue::String(k)) = (map_val, key_val) {
-                        Ok(ArtValue::Bool(
-                            m.0.lock()
-                                .unwrap_or_else(|e| e.into_inner())
-                                .contains_key(k.as_ref()),
-                        ))
-                    } else {
-                        Ok(ArtValue::Bool(false))
-                    }
-                } else {
-                    Ok(ArtValue::Bool(false))
-                }
-            }
+            core::ast::BuiltinFn::MapSet => self.call_map_set(arguments),
+            core::ast::BuiltinFn::MapGet => self.call_map_get(arguments),
+            core::ast::BuiltinFn::MapHas => self.call_map_has(arguments),
             core::ast::BuiltinFn::StreamNew => {
                 if arguments.len() != 1 {
                     self.diagnostics.push(Diagnostic::new(
                         DiagnosticKind::Runtime,
-                        "stream expects exactly one array argument".to_string(),
+                        "stream expects exactly one array or generator argument".to_string(),
                         self.call_span,
                     ));
                     return Ok(ArtValue::none());
@@ -155,11 +96,14 @@ impl Interpreter {
                 let input = self.evaluate(arguments[0].clone())?;
                 let resolved = self.resolve_composite(&input).clone();
                 match resolved {
-                    ArtValue::Array(source) => Ok(self.build_stream_value(source, Vec::new())),
+                    ArtValue::Array(_) => Ok(self.build_stream_value(resolved, Vec::new())),
+                    _ if self.generator_id(&input).is_some() => {
+                        Ok(self.build_stream_value(input, Vec::new()))
+                    }
                     _ => {
                         self.diagnostics.push(Diagnostic::new(
                             DiagnosticKind::Runtime,
-                            "stream expects an array argument".to_string(),
+                            "stream expects an array or generator argument".to_string(),
                             self.call_span,
                         ));
                         Ok(ArtValue::none())
@@ -269,46 +213,37 @@ impl Interpreter {
                 }
             }
             core::ast::BuiltinFn::SetNew => Ok(ArtValue::Set(core::ast::SetRef(
-                std::sync::Arc::new(std::sync::Mutex::new(Vec::new())),
+                std::sync::Arc::new(std::sync::Mutex::new(core::ast::KeySet::default())),
             ))),
-            core::ast::BuiltinFn::SetAdd => {
-                let mut args = arguments.into_iter();
-                if let (Some(set_expr), Some(val_expr)) = (args.next(), args.next()) {
-                    let set_val = self.evaluate(set_expr)?;
-                    let v = self.evaluate(val_expr)?;
-                    if let ArtValue::Set(s) = set_val {
-                        let mut set = s.0.lock().unwrap_or_else(|e| e.into_inner());
-                        if !set.contains(&v) {
-                            set.push(v);
-                        }
-                        Ok(ArtValue::none())
-                    } else {
-                        Ok(ArtValue::none())
-                    }
-                } else {
-                    Ok(ArtValue::none())
-                }
-            }
-            core::ast::BuiltinFn::SetHas => {
-                let mut args = arguments.into_iter();
-                if let (Some(set_expr), Some(val_expr)) = (args.next(), args.next()) {
-                    let set_val = self.evaluate(set_expr)?;
-                    let v = self.evaluate(val_expr)?;
-                    if let ArtValue::Set(s) = set_val {
-                        Ok(ArtValue::Bool(
-                            s.0.lock().unwrap_or_else(|e| e.into_inner()).contains(&v),
-                        ))
-                    } else {
-                        Ok(ArtValue::Bool(false))
-                    }
-                } else {
-                    Ok(ArtValue::Bool(false))
-                }
-            }
+            core::ast::BuiltinFn::SetAdd => self.call_set_add(arguments),
+            core::ast::BuiltinFn::SetHas => self.call_set_has(arguments),
+            core::ast::BuiltinFn::MapRemove => self.call_map_remove(arguments),
+            core::ast::BuiltinFn::MapKeys => self.call_map_view("map_keys", arguments),
+            core::ast::BuiltinFn::MapValues => self.call_map_view("map_values", arguments),
+            core::ast::BuiltinFn::MapEntries => self.call_map_view("map_entries", arguments),
+            core::ast::BuiltinFn::MapMerge => self.call_map_merge(arguments),
+            core::ast::BuiltinFn::SetRemove => self.call_set_remove(arguments),
+            core::ast::BuiltinFn::SetUnion => self.call_set_algebra("set_union", arguments),
+            core::ast::BuiltinFn::SetIntersection => {
+                self.call_set_algebra("set_intersection", arguments)
+            }
+            core::ast::BuiltinFn::SetDifference => {
+                self.call_set_algebra("set_difference", arguments)
+            }
+            core::ast::BuiltinFn::SetToArray => self.call_set_to_array(arguments),
+            core::ast::BuiltinFn::DequeGet => self.call_deque_get(arguments),
+            core::ast::BuiltinFn::DequeToArray => self.call_deque_to_array(arguments),
             core::ast::BuiltinFn::MathAbs => {
                 if let Some(first) = arguments.into_iter().next() {
                     match self.evaluate(first)? {
-                        ArtValue::Int(i) => Ok(ArtValue::Int(i.abs())),
+                        ArtValue::Int(i) => Ok(match i.checked_abs() {
+                            Some(v) => ArtValue::Int(v),
+                            None => self.int_overflow(
+                                self.call_span,
+                                i.wrapping_abs(),
+                                format!("math_abs({})", i),
+                            ),
+                        }),
                         ArtValue::Float(f) => Ok(ArtValue::Float(f.abs())),
                         _ => Ok(ArtValue::none()),
                     }
@@ -316,16 +251,40 @@ impl Interpreter {
                     Ok(ArtValue::none())
                 }
             }
+            core::ast::BuiltinFn::WrappingAdd => self.call_int_builtin("wrapping_add", arguments),
+            core::ast::BuiltinFn::Sort => self.call_sort(arguments),
+            core::ast::BuiltinFn::Hash => self.call_hash(arguments),
+            core::ast::BuiltinFn::WrappingSub => self.call_int_builtin("wrapping_sub", arguments),
+            core::ast::BuiltinFn::WrappingMul => self.call_int_builtin("wrapping_mul", arguments),
+            core::ast::BuiltinFn::CheckedAdd => self.call_int_builtin("checked_add", arguments),
+            core::ast::BuiltinFn::CheckedSub => self.call_int_builtin("checked_sub", arguments),
+            core::ast::BuiltinFn::CheckedMul => self.call_int_builtin("checked_mul", arguments),
+            core::ast::BuiltinFn::SaturatingAdd => {
+                self.call_int_builtin("saturating_add", arguments)
+            }
+            core::ast::BuiltinFn::SaturatingSub => {
+                self.call_int_builtin("saturating_sub", arguments)
+            }
+            core::ast::BuiltinFn::SaturatingMul => {
+                self.call_int_builtin("saturating_mul", arguments)
+            }
             core::ast::BuiltinFn::MathPow => {
                 let mut args = arguments.into_iter();
                 if let (Some(base_expr), Some(exp_expr)) = (args.next(), args.next()) {
                     match (self.evaluate(base_expr)?, self.evaluate(exp_expr)?) {
                         (ArtValue::Int(base), ArtValue::Int(exp)) => {
-                            if exp >= 0 {
-                                Ok(ArtValue::Int(base.pow(exp as u32)))
-                            } else {
-                                Ok(ArtValue::none())
+                            if exp < 0 {
+                                return Ok(ArtValue::none());
                             }
+                            let exp = u32::try_from(exp).unwrap_or(u32::MAX);
+                            Ok(match base.checked_pow(exp) {
+                                Some(v) => ArtValue::Int(v),
+                                None => self.int_overflow(
+                                    self.call_span,
+                                    base.wrapping_pow(exp),
+                                    format!("math_pow({}, {})", base, exp),
+                                ),
+                            })
                         }
                         (ArtValue::Float(base), ArtValue::Float(exp)) => {
                             Ok(ArtValue::Float(base.powf(exp)))
@@ -738,15 +697,20 @@ impl Interpreter {
             core::ast::BuiltinFn::Len => {
                 if let Some(first) = arguments.into_iter().next() {
                     let val = self.evaluate(first)?;
-                    let n = match val {
-                        ArtValue::String(ref s) => s.len() as i64,
-                        ArtValue::Array(ref a) => a.len() as i64,
-                        ArtValue::Map(ref m) => {
+                    // Strings contam caracteres, como a indexação `s[i]`.
+                    let n = match self.resolve_composite(&val) {
+                        ArtValue::String(s) => s.chars().count() as i64,
+                        ArtValue::Array(a) | ArtValue::Tuple(a) => a.len() as i64,
+                        ArtValue::Buffer(b) => b.len() as i64,
+                        ArtValue::Map(m) => {
                             m.0.lock().unwrap_or_else(|e| e.into_inner()).len() as i64
                         }
-                        ArtValue::Set(ref s) => {
+                        ArtValue::Set(s) => {
                             s.0.lock().unwrap_or_else(|e| e.into_inner()).len() as i64
                         }
+                        ArtValue::Deque(d) => {
+                            d.0.lock().unwrap_or_else(|e| e.into_inner()).len() as i64
+                        }
                         _ => {
                             self.diagnostics.push(Diagnostic::new(
                                 DiagnosticKind::Runtime,
@@ -998,48 +962,20 @@ impl Interpreter {
                 if let Some(aid) = aid_opt {
                     // Promoção antecipada: o payload escapa para outro ator (global heap)
                     self.promote_if_escaping(None, &mut msg_val);
-
-                    if let Some(actor) = self.actors.get_mut(&aid) {
-                        let limit = actor.mailbox_limit;
-                        if actor.mailbox.len() >= limit {
-                            // mailbox full: signal backpressure (return false)
-                            return Ok(ArtValue::Bool(false));
-                        }
-
-                        let env = core::ast::ValueEnvelope {
-                            sender: self.current_actor,
-                            payload: msg_val,
-                            priority,
-                        };
-                        actor.mailbox.insert(env);
-                        if actor.parked {
-                            actor.parked = false;
-                        }
-                        return Ok(ArtValue::Bool(true));
-                    } else if let Some(exec) = &mut self.executing_actor {
-                        if exec.id == aid {
-                            let limit = exec.mailbox_limit;
-                            if exec.mailbox.len() >= limit {
-                                return Ok(ArtValue::Bool(false));
-                            }
-                            let env = core::ast::ValueEnvelope {
-                                sender: self.current_actor,
-                                payload: msg_val,
-                                priority,
-                            };
-                            exec.mailbox.insert(env);
-                            if exec.parked {
-                                exec.parked = false;
-                            }
-                            return Ok(ArtValue::Bool(true));
-                        }
-                    } else {
-                        self.diagnostics.push(Diagnostic::new(
-                            DiagnosticKind::Runtime,
-                            format!("actor_send: unknown actor id {}", aid),
-                            self.call_span,
-                        ));
+                    let env = core::ast::ValueEnvelope {
+                        sender: self.current_actor,
+                        payload: msg_val,
+                        priority,
+                    };
+                    // mailbox full: signal backpressure (return false)
+                    if let Some(accepted) = self.deliver_to_actor(aid, env) {
+                        return Ok(ArtValue::Bool(accepted));
                     }
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::Runtime,
+                        format!("actor_send: unknown actor id {}", aid),
+                        self.call_span,
+                    ));
                 } else {
                     self.diagnostics.push(Diagnostic::new(
                         DiagnosticKind::Runtime,
@@ -1272,9 +1208,62 @@ impl Interpreter {
                 } else {
                     usize::MAX
                 };
-                self.run_actors_round_robin(max_steps);
+                self.run_scheduler(max_steps, None);
                 Ok(ArtValue::none())
             }
+            core::ast::BuiltinFn::ActorAsk => {
+                // actor_ask(actor_id, value [, priority]) -> task com a resposta
+                if arguments.len() < 2 || arguments.len() > 3 {
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::Runtime,
+                        "actor_ask expects 2 or 3 args".to_string(),
+                        self.call_span,
+                    ));
+                    return Ok(ArtValue::none());
+                }
+                let aid_val = self.evaluate(arguments[0].clone())?;
+                let mut msg_val = self.evaluate(arguments[1].clone())?;
+                let priority = if arguments.len() == 3 {
+                    match self.evaluate(arguments[2].clone())? {
+                        ArtValue::Int(n) => n as i32,
+                        _ => 0,
+                    }
+                } else {
+                    0
+                };
+                let aid = match aid_val {
+                    ArtValue::Actor(id) => id,
+                    ArtValue::Int(n) => n as u32,
+                    _ => {
+                        self.diagnostics.push(Diagnostic::new(
+                            DiagnosticKind::Runtime,
+                            "actor_ask: actor id must be Int".to_string(),
+                            self.call_span,
+                        ));
+                        return Ok(ArtValue::none());
+                    }
+                };
+                self.promote_if_escaping(None, &mut msg_val);
+                Ok(self.ask_actor(aid, msg_val, priority))
+            }
+            core::ast::BuiltinFn::Sleep => {
+                // sleep(ms) -> task que termina depois de ms milissegundos
+                let ms = match arguments.as_slice() {
+                    [arg] => self.evaluate(arg.clone())?,
+                    _ => ArtValue::none(),
+                };
+                match ms {
+                    ArtValue::Int(ms) if ms >= 0 => Ok(self.new_timer(ms as u64)),
+                    _ => {
+                        self.diagnostics.push(Diagnostic::new(
+                            DiagnosticKind::Runtime,
+                            "sleep expects one non-negative Int (milliseconds)".to_string(),
+                            self.call_span,
+                        ));
+                        Ok(ArtValue::none())
+                    }
+                }
+            }
             // Prototype atomic/mutex builtins for performant blocks (single-threaded semantics)
             core::ast::BuiltinFn::AtomicNew => {
                 if arguments.len() != 1 {
@@ -1588,6 +1577,10 @@ impl Interpreter {
 
                 for (field_name, expected_ty) in &struct_def.fields {
                     let Some(field_val) = fields.get(field_name) else {
+                        // Um campo com default pode faltar: `deserialize` o completa.
+                        if struct_def.defaults.contains_key(field_name) {
+                            continue;
+                        }
                         self.diagnostics.push(Diagnostic::new(
                             DiagnosticKind::Runtime,
                             format!(
@@ -1677,7 +1670,7 @@ impl Interpreter {
                     ArtValue::Buffer(buf) => {
                         let mut cur = std::io::Cursor::new(buf.as_ref());
                         match crate::interpreter::decode_val(&mut cur) {
-                            Ok(val) => Ok(val),
+                            Ok(val) => self.fill_struct_defaults(val),
                             Err(e) => {
                                 self.diagnostics.push(Diagnostic::new(
                                     DiagnosticKind::Runtime,
@@ -0,0 +1,262 @@
+//! Builtins de `Map`, `Set` e `Deque`.
+//!
+//! Chaves e elementos são guardados como `MapKey`, a cópia estrutural de um
+//! valor hashable (`Int`, `Float`, `Bool`, `String`, tuplas, arrays e
+//! instâncias com `@derive(Hash)`). Duas chaves iguais por `==` caem no mesmo
+//! slot, e `set_has` / `map_has` são O(1).
+//!
+//! Enumerações (`map_keys`, `set_to_array`, ...) devolvem arrays novos, na
+//! ordem de `for`: chave para `Map`, inserção para `Set`, frente para trás
+//! para `Deque`. `map_merge` e a álgebra de conjuntos devolvem coleções novas
+//! e não alteram os argumentos.
+
+use super::Interpreter;
+use crate::values::Result;
+use core::ast::{ArtValue, Expr, KeySet, MapKey, MapRef, SetRef};
+use diagnostics::{Diagnostic, DiagnosticKind};
+use std::sync::{Arc, Mutex};
+
+impl Interpreter {
+    /// `MapKey` de `value`, ou diagnóstico `Runtime` citando `builtin`.
+    pub(super) fn key_or_report(&mut self, builtin: &str, value: &ArtValue) -> Option<MapKey> {
+        let key = self.map_key(value);
+        if key.is_none() {
+            let label = self.runtime_type_label(value);
+            self.diagnostics.push(Diagnostic::new(
+                DiagnosticKind::Runtime,
+                format!(
+                    "{}: values of type {} are not hashable; add @derive(Hash).",
+                    builtin, label
+                ),
+                self.call_span,
+            ));
+        }
+        key
+    }
+
+    fn eval_args(&mut self, arguments: Vec<Expr>) -> Result<Vec<ArtValue>> {
+        let mut values = Vec::with_capacity(arguments.len());
+        for arg in arguments {
+            values.push(self.evaluate(arg)?);
+        }
+        Ok(values)
+    }
+
+    pub(super) fn call_map_set(&mut self, arguments: Vec<Expr>) -> Result<ArtValue> {
+        let args = self.eval_args(arguments)?;
+        let [ArtValue::Map(m), key, value] = args.as_slice() else {
+            self.diagnostics.push(Diagnostic::new(
+                DiagnosticKind::Runtime,
+                "map_set: invalid arguments".to_string(),
+                self.call_span,
+            ));
+            return Ok(ArtValue::none());
+        };
+        if let Some(key) = self.key_or_report("map_set", key) {
+            m.0.lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(key, value.clone());
+        }
+        Ok(ArtValue::none())
+    }
+
+    pub(super) fn call_map_get(&mut self, arguments: Vec<Expr>) -> Result<ArtValue> {
+        let args = self.eval_args(arguments)?;
+        let [ArtValue::Map(m), key] = args.as_slice() else {
+            return Ok(ArtValue::none());
+        };
+        let Some(key) = self.key_or_report("map_get", key) else {
+            return Ok(ArtValue::none());
+        };
+        let map = m.0.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(ArtValue::Optional(Box::new(map.get(&key).cloned())))
+    }
+
+    pub(super) fn call_map_has(&mut self, arguments: Vec<Expr>) -> Result<ArtValue> {
+        let args = self.eval_args(arguments)?;
+        let [ArtValue::Map(m), key] = args.as_slice() else {
+            return Ok(ArtValue::Bool(false));
+        };
+        let found = self.key_or_report("map_has", key).is_some_and(|key| {
+            m.0.lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .contains_key(&key)
+        });
+        Ok(ArtValue::Bool(found))
+    }
+
+    pub(super) fn call_set_add(&mut self, arguments: Vec<Expr>) -> Result<ArtValue> {
+        let args = self.eval_args(arguments)?;
+        let [ArtValue::Set(s), value] = args.as_slice() else {
+            return Ok(ArtValue::none());
+        };
+        if let Some(key) = self.key_or_report("set_add", value) {
+            s.0.lock().unwrap_or_else(|e| e.into_inner()).insert(key);
+        }
+        Ok(ArtValue::none())
+    }
+
+    pub(super) fn call_set_has(&mut self, arguments: Vec<Expr>) -> Result<ArtValue> {
+        let args = self.eval_args(arguments)?;
+        let [ArtValue::Set(s), value] = args.as_slice() else {
+            return Ok(ArtValue::Bool(false));
+        };
+        let found = self
+            .key_or_report("set_has", value)
+            .is_some_and(|key| s.0.lock().unwrap_or_else(|e| e.into_inner()).contains(&key));
+        Ok(ArtValue::Bool(found))
+    }
+
+    pub(super) fn call_map_remove(&mut self, arguments: Vec<Expr>) -> Result<ArtValue> {
+        let args = self.eval_args(arguments)?;
+        let [ArtValue::Map(m), key] = args.as_slice() else {
+            return Ok(self.expects("map_remove", "a Map and a key"));
+        };
+        let Some(key) = self.key_or_report("map_remove", key) else {
+            return Ok(ArtValue::none());
+        };
+        let removed = m.0.lock().unwrap_or_else(|e| e.into_inner()).remove(&key);
+        Ok(ArtValue::Optional(Box::new(removed)))
+    }
+
+    /// `map_keys`, `map_values` e `map_entries`, em ordem de chave.
+    pub(super) fn call_map_view(
+        &mut self,
+        builtin: &str,
+        arguments: Vec<Expr>,
+    ) -> Result<ArtValue> {
+        let args = self.eval_args(arguments)?;
+        let [ArtValue::Map(m)] = args.as_slice() else {
+            return Ok(self.expects(builtin, "a Map"));
+        };
+        let items = m
+            .sorted_entries()
+            .into_iter()
+            .map(|(k, v)| match builtin {
+                "map_keys" => k.to_value(),
+                "map_values" => v,
+                _ => ArtValue::Tuple(vec![k.to_value(), v]),
+            })
+            .collect();
+        Ok(self.array_value(items))
+    }
+
+    /// `map_merge(a, b)`: Map novo com as entradas de `a` e `b`; em chave
+    /// repetida vale a de `b`.
+    pub(super) fn call_map_merge(&mut self, arguments: Vec<Expr>) -> Result<ArtValue> {
+        let args = self.eval_args(arguments)?;
+        let [ArtValue::Map(a), ArtValue::Map(b)] = args.as_slice() else {
+            return Ok(self.expects("map_merge", "two Maps"));
+        };
+        let mut merged = a.0.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        for (k, v) in b.0.lock().unwrap_or_else(|e| e.into_inner()).iter() {
+            merged.insert(k.clone(), v.clone());
+        }
+        Ok(ArtValue::Map(MapRef(Arc::new(Mutex::new(merged)))))
+    }
+
+    pub(super) fn call_set_remove(&mut self, arguments: Vec<Expr>) -> Result<ArtValue> {
+        let args = self.eval_args(arguments)?;
+        let [ArtValue::Set(s), value] = args.as_slice() else {
+            self.expects("set_remove", "a Set and a value");
+            return Ok(ArtValue::Bool(false));
+        };
+        let removed = self
+            .key_or_report("set_remove", value)
+            .is_some_and(|key| s.0.lock().unwrap_or_else(|e| e.into_inner()).remove(&key));
+        Ok(ArtValue::Bool(removed))
+    }
+
+    /// `set_union`, `set_intersection` e `set_difference`: Set novo, na ordem
+    /// de inserção de `a` (na união, seguida dos elementos novos de `b`).
+    pub(super) fn call_set_algebra(
+        &mut self,
+        builtin: &str,
+        arguments: Vec<Expr>,
+    ) -> Result<ArtValue> {
+        let args = self.eval_args(arguments)?;
+        let [ArtValue::Set(a), ArtValue::Set(b)] = args.as_slice() else {
+            return Ok(self.expects(builtin, "two Sets"));
+        };
+        let a = a.0.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        let b = b.0.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        let mut out = KeySet::default();
+        for key in a.iter() {
+            let keep = match builtin {
+                "set_intersection" => b.contains(key),
+                "set_difference" => !b.contains(key),
+                _ => true,
+            };
+            if keep {
+                out.insert(key.clone());
+            }
+        }
+        if builtin == "set_union" {
+            for key in b.iter() {
+                out.insert(key.clone());
+            }
+        }
+        Ok(ArtValue::Set(SetRef(Arc::new(Mutex::new(out)))))
+    }
+
+    pub(super) fn call_set_to_array(&mut self, arguments: Vec<Expr>) -> Result<ArtValue> {
+        let args = self.eval_args(arguments)?;
+        let [ArtValue::Set(s)] = args.as_slice() else {
+            return Ok(self.expects("set_to_array", "a Set"));
+        };
+        let items =
+            s.0.lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .iter()
+                .map(MapKey::to_value)
+                .collect();
+        Ok(self.array_value(items))
+    }
+
+    /// `deque_get(d, i)`: elemento `i` (negativo conta do fim) ou `none`.
+    pub(super) fn call_deque_get(&mut self, arguments: Vec<Expr>) -> Result<ArtValue> {
+        let args = self.eval_args(arguments)?;
+        let [ArtValue::Deque(d), ArtValue::Int(i)] = args.as_slice() else {
+            return Ok(self.expects("deque_get", "a Deque and an Int"));
+        };
+        let items = d.0.lock().unwrap_or_else(|e| e.into_inner());
+        let at = if *i < 0 { items.len() as i64 + i } else { *i };
+        let item = usize::try_from(at)
+            .ok()
+            .and_then(|at| items.get(at))
+            .cloned();
+        Ok(ArtValue::Optional(Box::new(item)))
+    }
+
+    pub(super) fn call_deque_to_array(&mut self, arguments: Vec<Expr>) -> Result<ArtValue> {
+        let args = self.eval_args(arguments)?;
+        let [ArtValue::Deque(d)] = args.as_slice() else {
+            return Ok(self.expects("deque_to_array", "a Deque"));
+        };
+        let items =
+            d.0.lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .iter()
+                .cloned()
+                .collect();
+        Ok(self.array_value(items))
+    }
+
+    /// Array novo no heap, como os literais.
+    fn array_value(&mut self, items: Vec<ArtValue>) -> ArtValue {
+        for item in &items {
+            self.note_composite_child(item);
+        }
+        self.heapify_composite(ArtValue::Array(items))
+    }
+
+    /// Diagnóstico de argumentos inválidos; devolve `none`.
+    fn expects(&mut self, builtin: &str, what: &str) -> ArtValue {
+        self.diagnostics.push(Diagnostic::new(
+            DiagnosticKind::Runtime,
+            format!("{} expects {}.", builtin, what),
+            self.call_span,
+        ));
+        ArtValue::none()
+    }
+}
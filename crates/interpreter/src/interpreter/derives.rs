@@ -0,0 +1,410 @@
+//! Comportamentos gerados por `@derive(...)` (ver `core::types::DERIVES`).
+//!
+//! `Eq` troca a identidade de `==` pela comparação campo a campo; `Ord`
+//! ordena pelos campos na ordem declarada (enums: pela ordem das variantes e
+//! depois pelo payload); `Hash` resume a mesma estrutura num `Int` com
+//! `hash(x)`; `Debug` dá a forma de `{x:debug}`; `Clone` faz `x.clone()`
+//! copiar a instância em profundidade. Um método próprio (`eq`, `cmp`,
+//! `clone`) continua tendo precedência sobre o derive.
+
+use super::Interpreter;
+use crate::values::Result;
+use core::ast::{ArtValue, Expr, KeyFloat, MapKey, tuple_struct_fields};
+use diagnostics::{Diagnostic, DiagnosticKind};
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+impl Interpreter {
+    /// `true` se `value` é instância de struct ou enum com `derive` declarado.
+    pub(super) fn has_derive(&self, value: &ArtValue, derive: &str) -> bool {
+        match self.resolve_composite(value) {
+            ArtValue::StructInstance { struct_name, .. } => {
+                self.type_registry.derives(struct_name, derive)
+            }
+            ArtValue::EnumInstance { enum_name, .. } => {
+                self.type_registry.derives(enum_name, derive)
+            }
+            _ => false,
+        }
+    }
+
+    /// `a == b` sem `eq` sobrecarregado: estrutural quando o tipo deriva `Eq`
+    /// (também dentro de tuplas e opcionais), identidade para as demais
+    /// instâncias no heap.
+    pub(super) fn values_equal(&self, a: &ArtValue, b: &ArtValue) -> bool {
+        match (a, b) {
+            (ArtValue::Tuple(xs), ArtValue::Tuple(ys)) => {
+                xs.len() == ys.len() && xs.iter().zip(ys).all(|(x, y)| self.values_equal(x, y))
+            }
+            (ArtValue::Optional(x), ArtValue::Optional(y)) => match (&**x, &**y) {
+                (Some(x), Some(y)) => self.values_equal(x, y),
+                (x, y) => x.is_none() && y.is_none(),
+            },
+            _ if self.has_derive(a, "Eq") => self.structural_eq(a, b),
+            _ => a == b,
+        }
+    }
+
+    /// Igualdade de valores dentro de uma instância com `derive(Eq)`: arrays
+    /// comparam elemento a elemento; instâncias sem `Eq`, por identidade.
+    fn structural_eq(&self, a: &ArtValue, b: &ArtValue) -> bool {
+        match (self.resolve_composite(a), self.resolve_composite(b)) {
+            (
+                ArtValue::StructInstance {
+                    struct_name: n1,
+                    fields: f1,
+                },
+                ArtValue::StructInstance {
+                    struct_name: n2,
+                    fields: f2,
+                },
+            ) if self.type_registry.derives(n1, "Eq") => {
+                n1 == n2
+                    && f1.len() == f2.len()
+                    && f1
+                        .iter()
+                        .all(|(k, v)| f2.get(k).is_some_and(|w| self.structural_eq(v, w)))
+            }
+            (
+                ArtValue::EnumInstance {
+                    enum_name: e1,
+                    variant: v1,
+                    values: x1,
+                },
+                ArtValue::EnumInstance {
+                    enum_name: e2,
+                    variant: v2,
+                    values: x2,
+                },
+            ) if self.type_registry.derives(e1, "Eq") || is_prelude_enum(e1) => {
+                e1 == e2
+                    && v1 == v2
+                    && x1.len() == x2.len()
+                    && x1.iter().zip(x2).all(|(x, y)| self.structural_eq(x, y))
+            }
+            (ArtValue::Array(xs), ArtValue::Array(ys))
+            | (ArtValue::Tuple(xs), ArtValue::Tuple(ys)) => {
+                xs.len() == ys.len() && xs.iter().zip(ys).all(|(x, y)| self.structural_eq(x, y))
+            }
+            (ArtValue::Optional(x), ArtValue::Optional(y)) => match (&**x, &**y) {
+                (Some(x), Some(y)) => self.structural_eq(x, y),
+                (x, y) => x.is_none() && y.is_none(),
+            },
+            (ArtValue::StructInstance { .. }, _) | (ArtValue::EnumInstance { .. }, _) => a == b,
+            (x, y) => x == y,
+        }
+    }
+
+    /// Ordem natural: números, strings e `Bool`; arrays e tuplas
+    /// lexicograficamente; instâncias com `derive(Ord)` pelos campos na ordem
+    /// declarada. `None` quando os valores não se ordenam.
+    pub(super) fn compare_values(&self, a: &ArtValue, b: &ArtValue) -> Option<Ordering> {
+        match (self.resolve_composite(a), self.resolve_composite(b)) {
+            (ArtValue::Int(l), ArtValue::Int(r)) => Some(l.cmp(r)),
+            (ArtValue::Float(l), ArtValue::Float(r)) => l.partial_cmp(r),
+            (ArtValue::Int(l), ArtValue::Float(r)) => (*l as f64).partial_cmp(r),
+            (ArtValue::Float(l), ArtValue::Int(r)) => l.partial_cmp(&(*r as f64)),
+            (ArtValue::String(l), ArtValue::String(r)) => Some(l.cmp(r)),
+            (ArtValue::Bool(l), ArtValue::Bool(r)) => Some(l.cmp(r)),
+            (ArtValue::Array(xs), ArtValue::Array(ys))
+            | (ArtValue::Tuple(xs), ArtValue::Tuple(ys)) => self.compare_seq(xs, ys),
+            (
+                ArtValue::StructInstance {
+                    struct_name: n1,
+                    fields: f1,
+                },
+                ArtValue::StructInstance {
+                    struct_name: n2,
+                    fields: f2,
+                },
+            ) if n1 == n2 && self.type_registry.derives(n1, "Ord") => {
+                let def = self.type_registry.get_struct(n1)?;
+                for (field, _) in &def.fields {
+                    match self.compare_values(f1.get(field)?, f2.get(field)?)? {
+                        Ordering::Equal => continue,
+                        other => return Some(other),
+                    }
+                }
+                Some(Ordering::Equal)
+            }
+            (
+                ArtValue::EnumInstance {
+                    enum_name: e1,
+                    variant: v1,
+                    values: x1,
+                },
+                ArtValue::EnumInstance {
+                    enum_name: e2,
+                    variant: v2,
+                    values: x2,
+                },
+            ) if e1 == e2 && self.type_registry.derives(e1, "Ord") => {
+                let variants = &self.type_registry.get_enum(e1)?.variants;
+                let index = |v: &str| variants.iter().position(|(name, _)| name == v);
+                match index(v1)?.cmp(&index(v2)?) {
+                    Ordering::Equal => self.compare_seq(x1, x2),
+                    other => Some(other),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn compare_seq(&self, xs: &[ArtValue], ys: &[ArtValue]) -> Option<Ordering> {
+        for (x, y) in xs.iter().zip(ys) {
+            match self.compare_values(x, y)? {
+                Ordering::Equal => continue,
+                other => return Some(other),
+            }
+        }
+        Some(xs.len().cmp(&ys.len()))
+    }
+
+    /// Ordem usada por `sort`: o `cmp` do `impl`, quando existe, senão
+    /// `compare_values`.
+    fn order_values(&mut self, a: &ArtValue, b: &ArtValue) -> Result<Option<Ordering>> {
+        if let Some(method) = self.operator_method(a, "cmp") {
+            return Ok(
+                match self.invoke_callable_with_values(method, vec![b.clone()])? {
+                    ArtValue::Int(n) => Some(n.cmp(&0)),
+                    _ => None,
+                },
+            );
+        }
+        Ok(self.compare_values(a, b))
+    }
+
+    /// `sort(xs)`: nova lista ordenada (estável); `xs` não muda.
+    pub(super) fn call_sort(&mut self, arguments: Vec<Expr>) -> Result<ArtValue> {
+        let mut values = Vec::with_capacity(arguments.len());
+        for arg in arguments {
+            values.push(self.evaluate(arg)?);
+        }
+        let items = match values.as_slice() {
+            [list] => match self.resolve_composite(list) {
+                ArtValue::Array(items) => Some(items.clone()),
+                _ => None,
+            },
+            _ => None,
+        };
+        let Some(items) = items else {
+            self.diagnostics.push(Diagnostic::new(
+                DiagnosticKind::Runtime,
+                "sort expects an Array.".to_string(),
+                self.call_span,
+            ));
+            return Ok(ArtValue::none());
+        };
+        // Insertion sort: the comparison may run user code (`cmp`), which
+        // `slice::sort_by` cannot propagate errors from.
+        let mut sorted: Vec<ArtValue> = Vec::with_capacity(items.len());
+        for item in items {
+            let mut at = sorted.len();
+            while at > 0 {
+                match self.order_values(&sorted[at - 1], &item)? {
+                    Some(Ordering::Greater) => at -= 1,
+                    Some(_) => break,
+                    None => {
+                        let label = self.runtime_type_label(&item);
+                        self.diagnostics.push(Diagnostic::new(
+                            DiagnosticKind::Runtime,
+                            format!(
+                                "sort: values of type {} are not ordered; add @derive(Ord) or define 'cmp'.",
+                                label
+                            ),
+                            self.call_span,
+                        ));
+                        return Ok(ArtValue::none());
+                    }
+                }
+            }
+            sorted.insert(at, item);
+        }
+        for item in &sorted {
+            self.note_composite_child(item);
+        }
+        Ok(self.heapify_composite(ArtValue::Array(sorted)))
+    }
+
+    /// `hash(x)`: `Int` estável para valores iguais por `==` sob `derive(Eq)`.
+    pub(super) fn call_hash(&mut self, arguments: Vec<Expr>) -> Result<ArtValue> {
+        let mut values = Vec::with_capacity(arguments.len());
+        for arg in arguments {
+            values.push(self.evaluate(arg)?);
+        }
+        let [value] = values.as_slice() else {
+            self.diagnostics.push(Diagnostic::new(
+                DiagnosticKind::Runtime,
+                "hash expects exactly one argument.".to_string(),
+                self.call_span,
+            ));
+            return Ok(ArtValue::none());
+        };
+        if let Some(key) = self.map_key(value) {
+            let mut hasher = DefaultHasher::new();
+            key.hash(&mut hasher);
+            return Ok(ArtValue::Int(hasher.finish() as i64));
+        }
+        let label = self.runtime_type_label(value);
+        self.diagnostics.push(Diagnostic::new(
+            DiagnosticKind::Runtime,
+            format!(
+                "hash: values of type {} are not hashable; add @derive(Hash).",
+                label
+            ),
+            self.call_span,
+        ));
+        Ok(ArtValue::none())
+    }
+
+    /// Forma hashable de `value`, usada por `hash` e pelas chaves de `Map` e
+    /// `Set`; `None` se algum valor alcançado não é hashable. Instâncias
+    /// precisam de `derive(Hash)` (`Option` e `Result` valem pelo conteúdo).
+    pub(super) fn map_key(&self, value: &ArtValue) -> Option<MapKey> {
+        let all = |items: &[ArtValue]| {
+            items
+                .iter()
+                .map(|v| self.map_key(v))
+                .collect::<Option<Vec<_>>>()
+        };
+        Some(match self.resolve_composite(value) {
+            ArtValue::Int(n) => MapKey::Int(*n),
+            ArtValue::Float(f) => MapKey::Float(KeyFloat(*f)),
+            ArtValue::String(s) => MapKey::String(s.clone()),
+            ArtValue::Bool(b) => MapKey::Bool(*b),
+            ArtValue::Optional(opt) => match &**opt {
+                Some(inner) => MapKey::Optional(Some(Box::new(self.map_key(inner)?))),
+                None => MapKey::Optional(None),
+            },
+            ArtValue::Tuple(items) => MapKey::Tuple(all(items)?),
+            ArtValue::Array(items) => MapKey::Array(all(items)?),
+            ArtValue::StructInstance {
+                struct_name,
+                fields,
+            } if self.type_registry.derives(struct_name, "Hash") => {
+                let fields = fields
+                    .iter()
+                    .map(|(name, v)| Some((name.clone(), self.map_key(v)?)))
+                    .collect::<Option<Vec<_>>>()?;
+                MapKey::struct_key(struct_name, fields)
+            }
+            ArtValue::EnumInstance {
+                enum_name,
+                variant,
+                values,
+            } if self.type_registry.derives(enum_name, "Hash") || is_prelude_enum(enum_name) => {
+                MapKey::Enum {
+                    name: enum_name.clone(),
+                    variant: variant.clone(),
+                    values: all(values)?,
+                }
+            }
+            _ => return None,
+        })
+    }
+
+    /// Forma de `{x:debug}`: strings entre aspas e instâncias com os campos na
+    /// ordem declarada. Tipos sem `derive(Debug)` mostram só o nome.
+    pub(super) fn debug_string(&self, value: &ArtValue) -> String {
+        let list = |items: &[ArtValue]| {
+            items
+                .iter()
+                .map(|v| self.debug_string(v))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match self.resolve_composite(value) {
+            ArtValue::String(s) => format!("{:?}", s),
+            ArtValue::Optional(opt) => match &**opt {
+                Some(inner) => format!("Some({})", self.debug_string(inner)),
+                None => "None".to_string(),
+            },
+            ArtValue::Array(items) => format!("[{}]", list(items)),
+            ArtValue::Tuple(items) => format!("({})", list(items)),
+            ArtValue::StructInstance {
+                struct_name,
+                fields,
+            } => {
+                let Some(def) = self.type_registry.get_struct(struct_name) else {
+                    return value.to_string();
+                };
+                if !self.type_registry.derives(struct_name, "Debug") {
+                    return format!("{} {{ .. }}", struct_name);
+                }
+                if def.tuple
+                    && let Some(items) = tuple_struct_fields(fields)
+                {
+                    let items: Vec<ArtValue> = items.into_iter().cloned().collect();
+                    return format!("{}({})", struct_name, list(&items));
+                }
+                let shown: Vec<String> = def
+                    .fields
+                    .iter()
+                    .filter_map(|(field, _)| {
+                        let v = fields.get(field)?;
+                        Some(format!("{}: {}", field, self.debug_string(v)))
+                    })
+                    .collect();
+                format!("{} {{ {} }}", struct_name, shown.join(", "))
+            }
+            ArtValue::EnumInstance {
+                enum_name,
+                variant,
+                values,
+            } => {
+                if values.is_empty() {
+                    format!("{}.{}", enum_name, variant)
+                } else if self.type_registry.derives(enum_name, "Debug")
+                    || is_prelude_enum(enum_name)
+                {
+                    format!("{}.{}({})", enum_name, variant, list(values))
+                } else {
+                    format!("{}.{}(..)", enum_name, variant)
+                }
+            }
+            other => other.to_string(),
+        }
+    }
+
+    /// `x.clone()` de uma instância com `derive(Clone)`: cópia nova no heap,
+    /// descendo por arrays e por instâncias que também derivam `Clone`.
+    pub(super) fn deep_clone(&mut self, value: &ArtValue) -> ArtValue {
+        let resolved = self.resolve_composite(value).clone();
+        let copy = match resolved {
+            ArtValue::Array(items) => {
+                ArtValue::Array(items.iter().map(|v| self.deep_clone(v)).collect())
+            }
+            ArtValue::Tuple(items) => {
+                return ArtValue::Tuple(items.iter().map(|v| self.deep_clone(v)).collect());
+            }
+            ArtValue::StructInstance {
+                struct_name,
+                fields,
+            } if self.type_registry.derives(&struct_name, "Clone") => ArtValue::StructInstance {
+                fields: fields
+                    .iter()
+                    .map(|(k, v)| (k.clone(), self.deep_clone(v)))
+                    .collect(),
+                struct_name,
+            },
+            ArtValue::EnumInstance {
+                enum_name,
+                variant,
+                values,
+            } if self.type_registry.derives(&enum_name, "Clone") => ArtValue::EnumInstance {
+                values: values.iter().map(|v| self.deep_clone(v)).collect(),
+                enum_name,
+                variant,
+            },
+            // Instâncias sem `Clone` e valores imutáveis são compartilhados.
+            _ => return value.clone(),
+        };
+        self.heapify_composite(copy)
+    }
+}
+
+/// `Option` e `Result` se comportam como se derivassem tudo.
+fn is_prelude_enum(name: &str) -> bool {
+    name == "Option" || name == "Result"
+}
@@ -7,6 +7,8 @@ use core::ast::{ArtValue, Expr, Function};
 use core::environment::Environment;
 use diagnostics::{Diagnostic, DiagnosticKind, Span};
 use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
 use std::collections::{HashMap, VecDeque};
 use std::rc::Rc;
 
@@ -32,11 +34,22 @@ impl Interpreter {
         match expr {
             Expr::InterpolatedString(parts) => {
                 use crate::fstring::eval_fstring;
-                eval_fstring(parts, |e| self.evaluate(e))
+                eval_fstring(parts, |e, format| {
+                    let value = self.evaluate(e)?;
+                    if format == Some("debug") {
+                        Ok(ArtValue::String(std::sync::Arc::from(
+                            self.debug_string(&value),
+                        )))
+                    } else {
+                        self.display_value(value)
+                    }
+                })
             }
             Expr::Try(inner) => {
                 // Com a introdução de weak/unowned, Try original de Result permanece como compat.
                 let result_val = self.evaluate(*inner)?;
+                // Um `Result` passado como argumento chega heapificado.
+                let result_val = self.resolve_composite(&result_val).clone();
                 match result_val {
                     ArtValue::EnumInstance {
                         enum_name,
@@ -115,9 +128,14 @@ impl Interpreter {
             }
             Expr::Unary { operator, right } => {
                 let right_val = self.evaluate(*right)?;
+                if operator.token_type == core::TokenType::Minus
+                    && let Some(value) = self.eval_overloaded_neg(&right_val)?
+                {
+                    return Ok(value);
+                }
                 match operator.token_type {
                     core::TokenType::Minus => match right_val {
-                        ArtValue::Int(n) => Ok(ArtValue::Int(-n)),
+                        ArtValue::Int(n) => Ok(self.eval_int_neg(&operator, n)),
                         ArtValue::Float(f) => Ok(ArtValue::Float(-f)),
                         _ => {
                             self.diagnostics.push(Diagnostic::new(
@@ -134,6 +152,7 @@ impl Interpreter {
                         }
                     },
                     core::TokenType::Bang => Ok(ArtValue::Bool(!self.is_truthy(&right_val))),
+                    core::TokenType::Tilde => Ok(self.eval_bit_not(&operator, right_val)),
                     _ => {
                         self.diagnostics.push(Diagnostic::new(
                             DiagnosticKind::Runtime,
@@ -166,9 +185,18 @@ impl Interpreter {
             } => {
                 let left_val = self.evaluate(*left)?;
                 let right_val = self.evaluate(*right)?;
+                if let Some(value) =
+                    self.eval_overloaded_binary(&operator, &left_val, &right_val)?
+                {
+                    return Ok(value);
+                }
+                if let (ArtValue::Int(l), ArtValue::Int(r)) = (&left_val, &right_val)
+                    && let Some(value) = self.eval_int_arith(&operator, *l, *r)
+                {
+                    return Ok(value);
+                }
                 match operator.token_type {
                     core::TokenType::Plus => match (&left_val, &right_val) {
-                        (ArtValue::Int(l), ArtValue::Int(r)) => Ok(ArtValue::Int(l + r)),
                         (ArtValue::Float(l), ArtValue::Float(r)) => Ok(ArtValue::Float(l + r)),
                         (ArtValue::String(l), ArtValue::String(r)) => Ok(ArtValue::String(
                             std::sync::Arc::from(format!("{}{}", l, r)),
@@ -195,34 +223,27 @@ impl Interpreter {
                     },
                     core::TokenType::Minus => self.binary_num_op(left_val, right_val, |a, b| a - b),
                     core::TokenType::Star => self.binary_num_op(left_val, right_val, |a, b| a * b),
-                    core::TokenType::Slash => {
-                        let div_by_zero = matches!(right_val, ArtValue::Int(0))
-                            || matches!(right_val, ArtValue::Float(f) if f == 0.0);
-                        if div_by_zero {
-                            self.diagnostics.push(Diagnostic::new(
-                                DiagnosticKind::Runtime,
-                                "Division by zero".to_string(),
-                                Span::new(
-                                    operator.start,
-                                    operator.end,
-                                    operator.line,
-                                    operator.col,
-                                ),
-                            ));
-                            Ok(ArtValue::none())
-                        } else {
-                            self.binary_num_op(left_val, right_val, |a, b| a / b)
-                        }
+                    core::TokenType::Slash | core::TokenType::Percent => {
+                        Ok(self.eval_division(&operator, left_val, right_val))
+                    }
+                    core::TokenType::Ampersand
+                    | core::TokenType::Pipe
+                    | core::TokenType::Caret
+                    | core::TokenType::LessLess
+                    | core::TokenType::GreaterGreater => {
+                        Ok(self.eval_bitwise(&operator, left_val, right_val))
                     }
                     core::TokenType::Greater => {
-                        self.binary_cmp_op(left_val, right_val, |a, b| a > b)
+                        self.binary_cmp_op(left_val, right_val, Ordering::is_gt)
                     }
                     core::TokenType::GreaterEqual => {
-                        self.binary_cmp_op(left_val, right_val, |a, b| a >= b)
+                        self.binary_cmp_op(left_val, right_val, Ordering::is_ge)
+                    }
+                    core::TokenType::Less => {
+                        self.binary_cmp_op(left_val, right_val, Ordering::is_lt)
                     }
-                    core::TokenType::Less => self.binary_cmp_op(left_val, right_val, |a, b| a < b),
                     core::TokenType::LessEqual => {
-                        self.binary_cmp_op(left_val, right_val, |a, b| a <= b)
+                        self.binary_cmp_op(left_val, right_val, Ordering::is_le)
                     }
                     core::TokenType::BangEqual => {
                         Ok(ArtValue::Bool(!self.is_equal(&left_val, &right_val)))
@@ -255,7 +276,7 @@ impl Interpreter {
                 // Tuple are heap allocated like arrays for passing by reference
                 Ok(self.heapify_composite(ArtValue::Tuple(evaluated_elements)))
             }
-            Expr::StructInit { name, fields } => {
+            Expr::StructInit { name, fields, base } => {
                 let struct_def = match self.type_registry.get_struct(&name.lexeme) {
                     Some(def) => def.clone(),
                     None => {
@@ -273,15 +294,51 @@ impl Interpreter {
                     self.note_composite_child(&value);
                     field_values.insert(field_name.lexeme, value);
                 }
+                // `..base`: os campos não escritos são copiados de `base`.
+                if let Some(base) = base {
+                    let base_value = self.evaluate(*base)?;
+                    let base_fields = match self.resolve_composite(&base_value) {
+                        ArtValue::StructInstance {
+                            struct_name,
+                            fields,
+                        } if *struct_name == name.lexeme => fields.clone(),
+                        _ => {
+                            self.diagnostics.push(Diagnostic::new(
+                                DiagnosticKind::Runtime,
+                                format!(
+                                    "Update base of '{}' must be a '{}', got {}.",
+                                    name.lexeme,
+                                    name.lexeme,
+                                    self.runtime_type_label(&base_value)
+                                ),
+                                Span::new(name.start, name.end, name.line, name.col),
+                            ));
+                            return Ok(ArtValue::none());
+                        }
+                    };
+                    for (field_name, value) in base_fields {
+                        if let Entry::Vacant(slot) = field_values.entry(field_name) {
+                            self.note_composite_child(&value);
+                            slot.insert(value);
+                        }
+                    }
+                }
                 for (field_name, _field_type) in &struct_def.fields {
-                    if !field_values.contains_key(field_name) {
-                        self.diagnostics.push(Diagnostic::new(
-                            DiagnosticKind::Runtime,
-                            format!("Missing field '{}'.", field_name),
-                            Span::new(name.start, name.end, name.line, name.col),
-                        ));
-                        return Ok(ArtValue::none().clone());
+                    if field_values.contains_key(field_name) {
+                        continue;
                     }
+                    if let Some(default) = struct_def.defaults.get(field_name) {
+                        let value = self.evaluate(default.clone())?;
+                        self.note_composite_child(&value);
+                        field_values.insert(field_name.clone(), value);
+                        continue;
+                    }
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::Runtime,
+                        format!("Missing field '{}'.", field_name),
+                        Span::new(name.start, name.end, name.line, name.col),
+                    ));
+                    return Ok(ArtValue::none().clone());
                 }
                 Ok(self.heapify_composite(ArtValue::StructInstance {
                     struct_name: name.lexeme,
@@ -421,10 +478,29 @@ impl Interpreter {
                 match obj_value {
                     ArtValue::Array(arr) => match field.lexeme.as_str() {
                         "sum" => {
-                            let mut sum = 0;
+                            let mut sum: i64 = 0;
                             for val in arr.iter() {
                                 if let ArtValue::Int(n) = val {
-                                    sum += n;
+                                    sum = match sum.checked_add(*n) {
+                                        Some(v) => v,
+                                        None => {
+                                            let span = Span::new(
+                                                field.start,
+                                                field.end,
+                                                field.line,
+                                                field.col,
+                                            );
+                                            let expr = format!("{} + {}", sum, n);
+                                            match self.int_overflow(
+                                                span,
+                                                sum.wrapping_add(*n),
+                                                expr,
+                                            ) {
+                                                ArtValue::Int(v) => v,
+                                                other => return Ok(other),
+                                            }
+                                        }
+                                    };
                                 } else {
                                     self.diagnostics.push(Diagnostic::new(
                                         DiagnosticKind::Runtime,
@@ -446,6 +522,28 @@ impl Interpreter {
                             Ok(ArtValue::none())
                         }
                     },
+                    ArtValue::Tuple(items) => {
+                        match field
+                            .lexeme
+                            .parse::<usize>()
+                            .ok()
+                            .and_then(|i| items.get(i))
+                        {
+                            Some(v) => Ok(v.clone()),
+                            None => {
+                                self.diagnostics.push(Diagnostic::new(
+                                    DiagnosticKind::Runtime,
+                                    format!(
+                                        "No field '{}' on tuple of length {}.",
+                                        field.lexeme,
+                                        items.len()
+                                    ),
+                                    Span::new(field.start, field.end, field.line, field.col),
+                                ));
+                                Ok(ArtValue::none())
+                            }
+                        }
+                    }
                     ArtValue::StructInstance {
                         struct_name,
                         fields,
@@ -455,6 +553,7 @@ impl Interpreter {
                             &fields,
                             &field,
                             &self.type_registry,
+                            &evaluated,
                         ) {
                             Ok(v)
                         } else {
@@ -547,6 +646,12 @@ impl Interpreter {
                 };
                 self.evaluate(expr)
             }
+            Expr::OptionalChain {
+                object,
+                field,
+                arguments,
+            } => self.eval_optional_chain(*object, field, arguments),
+            Expr::Coalesce { left, right, .. } => self.eval_coalesce(*left, *right),
             Expr::WeakUpgrade(inner) => {
                 // Açúcar: expr? -> weak_get(expr)
                 let expr = Expr::Call {
@@ -570,6 +675,23 @@ impl Interpreter {
                 self.evaluate(expr)
             }
             Expr::Cast { object, .. } => self.evaluate(*object),
+            Expr::Index {
+                object,
+                index,
+                bracket,
+            } => self.eval_index(*object, *index, bracket),
+            Expr::Slice {
+                object,
+                start,
+                end,
+                bracket,
+            } => self.eval_slice(*object, start.map(|e| *e), end.map(|e| *e), bracket),
+            Expr::Range {
+                start,
+                end,
+                inclusive,
+                operator,
+            } => self.eval_range(*start, *end, inclusive, operator),
             Expr::Array(elements) => {
                 let mut evaluated_elements = Vec::new();
                 for element in elements {
@@ -601,12 +723,31 @@ impl Interpreter {
                 Ok(ArtValue::Actor(aid))
             }
 
+            // Dentro de uma `async func` o `await` é tratado por `generators.rs`;
+            // aqui ele está no topo do arquivo e bloqueia.
+            Expr::Await { keyword, value } => {
+                let value = self.evaluate(*value)?;
+                self.block_on(value, &keyword)
+            }
+
             Expr::Template(_) => {
                 // ArtML templates are not evaluated by the interpreter — they target the JS codegen.
                 Err(RuntimeError::TypeError(
                     "ArtML templates can only be used with `art build --target js`".to_string(),
                 ))
             }
+
+            // Mesma captura léxica de um `func` aninhado: o ambiente atual fica como
+            // closure (Weak) e a escape analysis de `call_function` o retém no retorno.
+            Expr::Lambda { params, body, .. } => Ok(ArtValue::Function(Rc::new(Function {
+                name: None,
+                type_params: None,
+                params,
+                body,
+                closure: Rc::downgrade(&self.environment),
+                retained_env: None,
+                is_async: false,
+            }))),
         }
     }
 
@@ -618,6 +759,51 @@ impl Interpreter {
     ) -> Result<ArtValue> {
         if let Expr::Variable { name } = &callee {
             let is_defined = self.environment.borrow().get(&name.lexeme).is_some();
+            // `Nome(x)` de um newtype não cria nada: o valor já é a representação.
+            if !is_defined && self.type_decls.newtypes.contains_key(&name.lexeme) {
+                let Ok([inner]) = <[Expr; 1]>::try_from(arguments) else {
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::Runtime,
+                        format!("Newtype '{}' takes exactly one argument.", name.lexeme),
+                        Span::new(name.start, name.end, name.line, name.col),
+                    ));
+                    return Ok(ArtValue::none());
+                };
+                return self.evaluate(inner);
+            }
+            // `Rgb(1, 2, 3)` constrói a struct tupla com os campos `0`, `1`, ...
+            if !is_defined
+                && let Some(def) = self.type_registry.get_struct(&name.lexeme)
+                && def.tuple
+            {
+                if arguments.len() != def.fields.len() {
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::Runtime,
+                        format!(
+                            "Tuple struct '{}' takes {} argument(s), got {}.",
+                            name.lexeme,
+                            def.fields.len(),
+                            arguments.len()
+                        ),
+                        Span::new(name.start, name.end, name.line, name.col),
+                    ));
+                    return Ok(ArtValue::none());
+                }
+                let fields = arguments
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, value)| {
+                        let mut field = name.clone();
+                        field.lexeme = i.to_string();
+                        (field, value)
+                    })
+                    .collect();
+                return self.evaluate(Expr::StructInit {
+                    name: name.clone(),
+                    fields,
+                    base: None,
+                });
+            }
             if !is_defined {
                 // Também checamos nos builtins antes de cair para o shell.
                 // Como não estão mais no environment (otimização de cold-start),
@@ -629,8 +815,64 @@ impl Interpreter {
             }
         }
 
-        if let Expr::FieldAccess { field, .. } = &callee {
-            self.call_span = Span::new(field.start, field.end, field.line, field.col);
+        match &callee {
+            Expr::FieldAccess { field: name, .. } | Expr::Variable { name } => {
+                self.call_span = Span::new(name.start, name.end, name.line, name.col);
+            }
+            _ => {}
+        }
+
+        // `g.next()` sobre um generator retoma a execução dele.
+        let mut callee = callee;
+        if let Expr::FieldAccess { object, field } = &callee
+            && field.lexeme == "next"
+            && arguments.is_empty()
+        {
+            let receiver = self.evaluate((**object).clone())?;
+            if let Some(id) = self.generator_id(&receiver) {
+                return self.generator_next(id);
+            }
+            callee = Expr::FieldAccess {
+                object: Box::new(Expr::Literal(receiver)),
+                field: field.clone(),
+            };
+        }
+
+        // `x.clone()` de uma instância com `derive(Clone)` e sem `clone` próprio.
+        if let Expr::FieldAccess { object, field } = &callee
+            && field.lexeme == "clone"
+            && arguments.is_empty()
+        {
+            let receiver = self.evaluate((**object).clone())?;
+            if self.has_derive(&receiver, "Clone")
+                && self.operator_method(&receiver, "clone").is_none()
+            {
+                return Ok(self.deep_clone(&receiver));
+            }
+            callee = Expr::FieldAccess {
+                object: Box::new(Expr::Literal(receiver)),
+                field: field.clone(),
+            };
+        }
+
+        // `valor.metodo(args)` sobre string, coleção, número ou handle chama o
+        // builtin da tabela `core::methods`, com o receptor como 1º argumento.
+        if let Expr::FieldAccess { object, field } = &callee
+            && core::methods::is_method_name(&field.lexeme)
+        {
+            let receiver = self.evaluate((**object).clone())?;
+            let method = core::methods::receiver_kind(self.resolve_composite(&receiver))
+                .and_then(|kind| core::methods::lookup(kind, &field.lexeme));
+            if let Some(method) = method {
+                let mut args = Vec::with_capacity(arguments.len() + 1);
+                args.push(Expr::Literal(receiver));
+                args.extend(arguments);
+                return self.call_builtin(Self::name_to_builtin(method.builtin), args);
+            }
+            callee = Expr::FieldAccess {
+                object: Box::new(Expr::Literal(receiver)),
+                field: field.clone(),
+            };
         }
 
         let original_expr = callee.clone();
@@ -647,6 +889,27 @@ impl Interpreter {
         }
     }
 
+    /// `expr` lê um valor que já tem dono (variável, campo, elemento ou valor
+    /// pré-avaliado) em vez de criar um temporário.
+    fn reads_existing_value(expr: &Expr) -> bool {
+        match expr {
+            Expr::Variable { .. }
+            | Expr::FieldAccess { .. }
+            | Expr::Index { .. }
+            | Expr::OptionalChain {
+                arguments: None, ..
+            }
+            | Expr::Literal(_)
+            | Expr::WeakUpgrade(_)
+            | Expr::UnownedAccess(_) => true,
+            Expr::Grouping { expression } => Self::reads_existing_value(expression),
+            Expr::Coalesce { left, right, .. } => {
+                Self::reads_existing_value(left) || Self::reads_existing_value(right)
+            }
+            _ => false,
+        }
+    }
+
     pub(super) fn call_function(
         &mut self,
         func: Rc<Function>,
@@ -672,6 +935,8 @@ impl Interpreter {
                 .or_insert(1);
         }
         self.fn_stack.push(callee_name_opt.clone());
+        // Capturado antes dos argumentos: chamadas aninhadas sobrescrevem `call_span`.
+        let call_span = self.call_span;
 
         let argc = arguments.len();
         if func.params.len() != argc {
@@ -685,59 +950,16 @@ impl Interpreter {
         }
 
         // Avalia argumentos
+        let borrowed: Vec<bool> = arguments.iter().map(Self::reads_existing_value).collect();
         let mut evaluated_args = Vec::with_capacity(argc);
         for arg in arguments {
             evaluated_args.push(self.evaluate(arg)?);
         }
 
-        // Generic type parameter validation
-        if let Some(type_params) = &func.type_params
-            && !type_params.is_empty()
-        {
-            // Infer concrete types: explicit type_args take priority, else infer from values
-            let concrete: Vec<String> = if let Some(explicit) = &type_args {
-                explicit.clone()
-            } else {
-                // For each type param, find the first function param annotated with that name
-                // and use the runtime type of the corresponding argument.
-                type_params
-                    .iter()
-                    .map(|(tname, _)| {
-                        func.params
-                            .iter()
-                            .position(|p| p.ty.as_deref() == Some(tname.as_str()))
-                            .and_then(|idx| evaluated_args.get(idx))
-                            .map(|v| v.type_name())
-                            .unwrap_or_else(|| "Unknown".to_string())
-                    })
-                    .collect()
-            };
-            // Validate constraints
-            for (i, (tname, constraint)) in type_params.iter().enumerate() {
-                if let Some(bound) = constraint {
-                    let concrete_ty = concrete.get(i).map(String::as_str).unwrap_or("Unknown");
-                    let ok = match bound.as_str() {
-                        "Numeric" => matches!(concrete_ty, "Int" | "Float"),
-                        "Eq" | "Hash" => {
-                            matches!(concrete_ty, "Int" | "Float" | "String" | "Bool")
-                        }
-                        "Comparable" => {
-                            matches!(concrete_ty, "Int" | "Float" | "String")
-                        }
-                        _ => true,
-                    };
-                    if !ok {
-                        self.diagnostics.push(Diagnostic::new(
-                                DiagnosticKind::Runtime,
-                                format!(
-                                    "Type '{}' does not satisfy constraint '{}' for type parameter '{}'",
-                                    concrete_ty, bound, tname
-                                ),
-                                self.call_span,
-                            ));
-                    }
-                }
-            }
+        // Monomorfização: instancia os type params e valida a assinatura.
+        if !self.instantiate_generics(&func, type_args.as_deref(), &evaluated_args, call_span) {
+            self.fn_stack.pop();
+            return Ok(ArtValue::none());
         }
 
         let previous_env = self.environment.clone();
@@ -753,6 +975,26 @@ impl Interpreter {
             }
         };
 
+        // Uma função com `yield` não executa agora: devolve um generator com o
+        // ambiente da chamada já ligado aos argumentos. Uma `async func` também
+        // não: vira uma task no scheduler.
+        if func.is_async || func.body.contains_yield() {
+            let call_env = Rc::new(RefCell::new(Environment::new(
+                Some(base_env.clone()),
+                base_env.borrow().depth + 1,
+                None,
+            )));
+            for (param, mut value) in func.params.iter().zip(evaluated_args) {
+                self.promote_if_escaping(None, &mut value);
+                call_env.borrow_mut().define(&param.name.lexeme, value);
+            }
+            self.fn_stack.pop();
+            if func.is_async {
+                return Ok(self.new_async_task(&func, call_env));
+            }
+            return Ok(self.new_generator(&func, call_env));
+        }
+
         // Arenas Implícitas para Funções (Adaptive ARC)
         let mut pushed_arena = false;
         let call_arena = if self.arena_with_active {
@@ -771,9 +1013,16 @@ impl Interpreter {
         self.environment = call_env.clone();
 
         // Bind parâmetros
-        for (param, mut value) in func.params.iter().zip(evaluated_args) {
+        for ((param, mut value), borrowed) in func.params.iter().zip(evaluated_args).zip(borrowed) {
             let target_aid = call_env.borrow().associated_arena;
             self.promote_if_escaping(target_aid, &mut value);
+            // Valor de outro dono (variável, campo, elemento): o parâmetro é uma
+            // referência a mais, solta por `drop_scope_heap_objects` no fim da
+            // chamada sem derrubar o objeto de quem chamou. Um temporário já
+            // chega com a única referência, que passa a ser do parâmetro.
+            if borrowed && let ArtValue::HeapComposite(h) = &value {
+                self.inc_heap_strong(h.0);
+            }
             self.environment
                 .borrow_mut()
                 .define(&param.name.lexeme, value);
@@ -809,6 +1058,7 @@ impl Interpreter {
                 body: f.body.clone(),
                 closure: f.closure.clone(),
                 retained_env: Some(captured_env),
+                is_async: f.is_async,
             };
             return_val = ArtValue::Function(Rc::new(escaped));
         }
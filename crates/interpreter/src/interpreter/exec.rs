@@ -1,15 +1,42 @@
 use super::Interpreter;
 use super::actors::{ActorState, Mailbox};
+use super::iteration::ForSource;
 use crate::values::{Result, RuntimeError};
 use core::Token;
 use core::ast::{ArtValue, Function, MatchPattern, Stmt};
 use core::environment::Environment;
 use diagnostics::{Diagnostic, DiagnosticKind, Span};
 use std::cell::RefCell;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::rc::Rc;
 use std::sync::Arc;
 
+/// O que o loop faz depois de uma iteração.
+enum LoopStep {
+    Next,
+    Exit,
+}
+
+/// Consome `break`/`continue` endereçados a este loop (sem rótulo, ou com o
+/// rótulo dele); os demais sinais e erros seguem propagando.
+fn loop_step(result: Result<()>, label: Option<&Token>) -> Result<LoopStep> {
+    let targets_me = |target: &Option<String>| loop_targeted(target, label);
+    match result {
+        Ok(()) => Ok(LoopStep::Next),
+        Err(RuntimeError::Break(target)) if targets_me(&target) => Ok(LoopStep::Exit),
+        Err(RuntimeError::Continue(target)) if targets_me(&target) => Ok(LoopStep::Next),
+        Err(e) => Err(e),
+    }
+}
+
+/// `break`/`continue` com `target` sai deste loop? Sem rótulo, sempre o mais interno.
+pub(super) fn loop_targeted(target: &Option<String>, label: Option<&Token>) -> bool {
+    match target {
+        None => true,
+        Some(t) => label.is_some_and(|l| &l.lexeme == t),
+    }
+}
+
 impl Interpreter {
     pub(super) fn bind_value_to_pattern(
         &mut self,
@@ -71,6 +98,20 @@ impl Interpreter {
                     )))
                 }
             }
+            core::ast::MatchPattern::Struct { .. }
+            | core::ast::MatchPattern::Array { .. }
+            | core::ast::MatchPattern::At { .. } => {
+                let Some(bindings) = self.pattern_matches(pattern, &value) else {
+                    return Err(RuntimeError::TypeError(format!(
+                        "Value '{}' does not match the destructuring pattern",
+                        self.resolve_composite(&value)
+                    )));
+                };
+                for (name, v) in bindings {
+                    self.environment.borrow_mut().define(&name, v);
+                }
+                Ok(())
+            }
             _ => {
                 // Ignore other patterns for `let` declarations for now (or throw error if unsupported)
                 Ok(())
@@ -78,6 +119,22 @@ impl Interpreter {
         }
     }
 
+    /// Liga o item da iteração ao elemento do `for` (`x` ou `(k, v)`).
+    pub(super) fn bind_loop_element(
+        &mut self,
+        element: &Token,
+        pattern: Option<&MatchPattern>,
+        value: ArtValue,
+    ) -> Result<()> {
+        match pattern {
+            Some(p) => self.bind_value_to_pattern(p, value),
+            None => {
+                self.environment.borrow_mut().define(&element.lexeme, value);
+                Ok(())
+            }
+        }
+    }
+
     fn stmt_approx_line(stmt: &core::ast::Stmt) -> usize {
         match stmt {
             Stmt::Expression(e) => Self::expr_approx_line(e),
@@ -86,13 +143,20 @@ impl Interpreter {
                 ..
             } => t.line,
             Stmt::Let { .. } => 0,
+            Stmt::Const { name, .. } => name.line,
+            Stmt::Assign { target, .. } => Self::expr_approx_line(target),
             Stmt::Function { name, .. } => name.line,
             Stmt::If { condition, .. } => Self::expr_approx_line(condition),
             Stmt::While { condition, .. } => Self::expr_approx_line(condition),
             Stmt::For { element, .. } => element.line,
             Stmt::Return { value: Some(e) } => Self::expr_approx_line(e),
+            Stmt::Yield { keyword, .. } | Stmt::Defer { keyword, .. } => keyword.line,
+            Stmt::Break { keyword, .. } | Stmt::Continue { keyword, .. } => keyword.line,
             Stmt::Match { expr, .. } => Self::expr_approx_line(expr),
-            Stmt::StructDecl { name, .. } | Stmt::EnumDecl { name, .. } => name.line,
+            Stmt::StructDecl { name, .. }
+            | Stmt::EnumDecl { name, .. }
+            | Stmt::TypeAlias { name, .. }
+            | Stmt::Newtype { name, .. } => name.line,
             _ => 0,
         }
     }
@@ -101,8 +165,14 @@ impl Interpreter {
         match expr {
             core::ast::Expr::Variable { name } => name.line,
             core::ast::Expr::Call { callee, .. } => Self::expr_approx_line(callee),
-            core::ast::Expr::FieldAccess { field, .. } => field.line,
-            core::ast::Expr::Binary { left, .. } => Self::expr_approx_line(left),
+            core::ast::Expr::FieldAccess { field, .. }
+            | core::ast::Expr::OptionalChain { field, .. } => field.line,
+            core::ast::Expr::Index { bracket, .. } | core::ast::Expr::Slice { bracket, .. } => {
+                bracket.line
+            }
+            core::ast::Expr::Binary { left, .. } | core::ast::Expr::Coalesce { left, .. } => {
+                Self::expr_approx_line(left)
+            }
             _ => 0,
         }
     }
@@ -245,22 +315,296 @@ impl Interpreter {
                 };
                 format!("let {}", name)
             }
+            Stmt::Const { name, .. } => format!("const {}", name.lexeme),
+            Stmt::Assign {
+                target, operator, ..
+            } => match target {
+                core::ast::Expr::Variable { name } => {
+                    format!("{} {}", name.lexeme, operator.lexeme)
+                }
+                core::ast::Expr::FieldAccess { field, .. } => {
+                    format!(".{} {}", field.lexeme, operator.lexeme)
+                }
+                core::ast::Expr::Index { .. } => format!("[] {}", operator.lexeme),
+                _ => "assign".to_string(),
+            },
             Stmt::Function { name, .. } => format!("func {}", name.lexeme),
             Stmt::If { .. } => "if".to_string(),
             Stmt::While { .. } => "while".to_string(),
             Stmt::For { element, .. } => format!("for {}", element.lexeme),
             Stmt::Return { .. } => "return".to_string(),
+            Stmt::Yield { .. } => "yield".to_string(),
+            Stmt::Defer { .. } => "defer".to_string(),
+            Stmt::Break { keyword, label } | Stmt::Continue { keyword, label } => match label {
+                Some(l) => format!("{} {}", keyword.lexeme, l.lexeme),
+                None => keyword.lexeme.clone(),
+            },
             Stmt::Match { .. } => "match".to_string(),
             Stmt::Block { .. } => "block".to_string(),
             Stmt::StructDecl { name, .. } => format!("struct {}", name.lexeme),
             Stmt::EnumDecl { name, .. } => format!("enum {}", name.lexeme),
+            Stmt::TypeAlias { name, .. } => format!("type {}", name.lexeme),
+            Stmt::Newtype { name, .. } => format!("newtype {}", name.lexeme),
+            Stmt::ImplBlock {
+                type_name,
+                trait_name: Some(t),
+                ..
+            } => format!("impl {} for {}", t.lexeme, type_name),
             Stmt::ImplBlock { type_name, .. } => format!("impl {}", type_name),
+            Stmt::TraitDecl { name, .. } => format!("trait {}", name.lexeme),
             Stmt::SpawnActor { .. } => "spawn actor".to_string(),
             Stmt::Performant { .. } => "performant".to_string(),
             _ => "stmt".to_string(),
         }
     }
 
+    /// Valida `impl Trait for Tipo` contra a declaração do trait e, se completo,
+    /// registra a implementação (usada por bounds genéricos e métodos default).
+    fn check_trait_impl(&mut self, trait_name: &Token, type_name: &str, method_names: &[String]) {
+        let span = Span::new(
+            trait_name.start,
+            trait_name.end,
+            trait_name.line,
+            trait_name.col,
+        );
+        let Some(tdef) = self.type_registry.get_trait(&trait_name.lexeme) else {
+            self.diagnostics.push(Diagnostic::new(
+                DiagnosticKind::Runtime,
+                format!("Unknown trait '{}'.", trait_name.lexeme),
+                span,
+            ));
+            return;
+        };
+        if !self.type_registry.has_struct(type_name) && !self.type_registry.has_enum(type_name) {
+            // Com métodos, o registro de cada um já acusou o tipo desconhecido.
+            if method_names.is_empty() {
+                self.diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Runtime,
+                    format!("Unknown type '{}' for impl.", type_name),
+                    span,
+                ));
+            }
+            return;
+        }
+        let mut missing: Vec<&String> = tdef
+            .methods
+            .keys()
+            .filter(|m| !tdef.defaults.contains_key(*m) && !method_names.contains(m))
+            .collect();
+        missing.sort();
+        let mut messages: Vec<String> = missing
+            .into_iter()
+            .map(|m| {
+                format!(
+                    "Type '{}' does not implement method '{}' required by trait '{}'.",
+                    type_name, m, trait_name.lexeme
+                )
+            })
+            .collect();
+        let complete = messages.is_empty();
+        for m in method_names {
+            if !tdef.methods.contains_key(m) {
+                messages.push(format!(
+                    "Method '{}' is not a member of trait '{}'.",
+                    m, trait_name.lexeme
+                ));
+            }
+        }
+        for msg in messages {
+            self.diagnostics
+                .push(Diagnostic::new(DiagnosticKind::Runtime, msg, span));
+        }
+        if complete {
+            self.type_registry
+                .register_impl(&trait_name.lexeme, type_name);
+        }
+    }
+
+    /// `alvo = valor` e as formas compostas. A forma composta é reescrita como
+    /// `alvo = alvo op valor` para reaproveitar a semântica de `Expr::Binary`
+    /// (promoção Int/Float, concatenação, divisão por zero); em `a[i] op= v`
+    /// o container e o índice, e em `o.f op= v` o receptor, são avaliados
+    /// antes, uma única vez.
+    pub(super) fn execute_assign(
+        &mut self,
+        target: core::ast::Expr,
+        operator: Token,
+        value: core::ast::Expr,
+    ) -> Result<()> {
+        use core::TokenType;
+        let compound = match operator.token_type {
+            TokenType::PlusEqual => Some((TokenType::Plus, "+")),
+            TokenType::MinusEqual => Some((TokenType::Minus, "-")),
+            TokenType::StarEqual => Some((TokenType::Star, "*")),
+            TokenType::SlashEqual => Some((TokenType::Slash, "/")),
+            _ => None,
+        };
+        let is_compound = compound.is_some();
+        let rhs = match compound {
+            Some((tt, lexeme)) => core::ast::Expr::Binary {
+                left: Box::new(target.clone()),
+                operator: Token {
+                    token_type: tt,
+                    lexeme: lexeme.to_string(),
+                    ..operator.clone()
+                },
+                right: Box::new(value),
+            },
+            None => value,
+        };
+
+        match target {
+            core::ast::Expr::Variable { name } => {
+                let mut new_value = self.evaluate(rhs)?;
+                if self
+                    .executing_actor
+                    .as_ref()
+                    .map(|a| a.parked)
+                    .unwrap_or(false)
+                {
+                    return Ok(());
+                }
+                let defining_arena = self.environment.borrow().defining_arena(&name.lexeme);
+                let Some(target_aid) = defining_arena else {
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::Runtime,
+                        format!("Undefined variable '{}'.", name.lexeme),
+                        Span::new(name.start, name.end, name.line, name.col),
+                    ));
+                    return Ok(());
+                };
+                self.promote_if_escaping(target_aid, &mut new_value);
+                let old = self.environment.borrow().get(&name.lexeme);
+                // `x = x` não pode soltar o objeto que está sendo reatribuído.
+                if let Some(old) = &old
+                    && !Self::same_heap_handle(old, &new_value)
+                {
+                    self.dec_value_if_heap(old);
+                }
+                self.environment
+                    .borrow_mut()
+                    .assign(&name.lexeme, new_value);
+                Ok(())
+            }
+            core::ast::Expr::FieldAccess { object, field } => {
+                let receiver = self.evaluate(*object)?;
+                // `f().x += v` chama `f` uma vez só: a leitura do campo parte do
+                // receptor já avaliado.
+                let rhs = match rhs {
+                    core::ast::Expr::Binary {
+                        operator: op,
+                        right,
+                        ..
+                    } if is_compound => core::ast::Expr::Binary {
+                        left: Box::new(core::ast::Expr::FieldAccess {
+                            object: Box::new(core::ast::Expr::Literal(receiver.clone())),
+                            field: field.clone(),
+                        }),
+                        operator: op,
+                        right,
+                    },
+                    rhs => rhs,
+                };
+                let mut new_value = self.evaluate(rhs)?;
+                let ArtValue::HeapComposite(h) = receiver else {
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::Runtime,
+                        format!(
+                            "Cannot assign to field '{}' of a non-struct value.",
+                            field.lexeme
+                        ),
+                        Span::new(field.start, field.end, field.line, field.col),
+                    ));
+                    return Ok(());
+                };
+                let owner_arena = self.heap_objects.get(&h.0).and_then(|o| o.arena_id);
+                self.promote_if_escaping(owner_arena, &mut new_value);
+                let stored = new_value.clone();
+                let outcome = match self.heap_objects.get_mut(&h.0).map(|o| &mut o.value) {
+                    Some(ArtValue::StructInstance {
+                        struct_name,
+                        fields,
+                    }) => match fields.get_mut(&field.lexeme) {
+                        Some(slot) => Ok(std::mem::replace(slot, new_value)),
+                        None => Err(format!(
+                            "Missing field '{}' on struct '{}'.",
+                            field.lexeme, struct_name
+                        )),
+                    },
+                    _ => Err(format!(
+                        "Cannot assign to field '{}' of a non-struct value.",
+                        field.lexeme
+                    )),
+                };
+                match outcome {
+                    Ok(old) => {
+                        // O objeto dono mantém uma referência forte para cada filho.
+                        if !Self::same_heap_handle(&old, &stored) {
+                            if let ArtValue::HeapComposite(nh) = &stored {
+                                self.inc_heap_strong(nh.0);
+                            }
+                            self.dec_value_if_heap(&old);
+                        }
+                    }
+                    Err(msg) => {
+                        self.diagnostics.push(Diagnostic::new(
+                            DiagnosticKind::Runtime,
+                            msg,
+                            Span::new(field.start, field.end, field.line, field.col),
+                        ));
+                    }
+                }
+                Ok(())
+            }
+            core::ast::Expr::Index {
+                object,
+                index,
+                bracket,
+            } => {
+                if !is_compound {
+                    let new_value = self.evaluate(rhs)?;
+                    return self.assign_index(*object, *index, bracket, new_value);
+                }
+                let core::ast::Expr::Binary {
+                    operator: op,
+                    right,
+                    ..
+                } = rhs
+                else {
+                    unreachable!("a forma composta foi reescrita como Binary acima")
+                };
+                // `a[f()] += v` avalia `a` e `f()` uma vez só: a leitura e a
+                // escrita reusam os mesmos valores.
+                let container = self.evaluate((*object).clone())?;
+                let index = self.evaluate(*index)?;
+                let before = self.diagnostics.len();
+                let current = self.index_value(&container, &index, &bracket);
+                if self.diagnostics.len() > before {
+                    return Ok(());
+                }
+                let combined = core::ast::Expr::Binary {
+                    left: Box::new(core::ast::Expr::Literal(current)),
+                    operator: op,
+                    right,
+                };
+                let new_value = self.evaluate(combined)?;
+                self.store_index(*object, &container, &index, bracket, new_value)
+            }
+            _ => {
+                self.diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Runtime,
+                    "Invalid assignment target.".to_string(),
+                    Span::new(operator.start, operator.end, operator.line, operator.col),
+                ));
+                Ok(())
+            }
+        }
+    }
+
+    pub(super) fn same_heap_handle(a: &ArtValue, b: &ArtValue) -> bool {
+        matches!((a, b), (ArtValue::HeapComposite(x), ArtValue::HeapComposite(y)) if x.0 == y.0)
+    }
+
     pub(super) fn execute(&mut self, stmt: Stmt) -> Result<()> {
         let stmt_line = Self::stmt_approx_line(&stmt);
         if stmt_line > 0 {
@@ -304,6 +648,7 @@ impl Interpreter {
             Stmt::Let {
                 pattern,
                 ty: _,
+                mutable: _,
                 initializer,
             } => {
                 let value = self.evaluate(initializer)?;
@@ -356,6 +701,33 @@ impl Interpreter {
                 self.bind_value_to_pattern(&pattern, promoted_value)?;
                 Ok(())
             }
+            Stmt::Const { name, value, .. } => {
+                // O type checker recusa o que não é constante; sem ele o erro vem daqui.
+                match core::const_eval::eval_const(&value, &self.consts) {
+                    Ok(folded) => {
+                        self.consts.insert(name.lexeme.clone(), folded);
+                        let value = self.evaluate(value)?;
+                        self.bind_value_to_pattern(&MatchPattern::Variable(name), value)?;
+                    }
+                    Err(err) => {
+                        let at = err.token.as_ref().unwrap_or(&name);
+                        self.diagnostics.push(Diagnostic::new(
+                            DiagnosticKind::Runtime,
+                            format!(
+                                "'{}' is not a compile-time constant: {}.",
+                                name.lexeme, err.message
+                            ),
+                            Span::new(at.start, at.end, at.line, at.col),
+                        ));
+                    }
+                }
+                Ok(())
+            }
+            Stmt::Assign {
+                target,
+                operator,
+                value,
+            } => self.execute_assign(target, operator, value),
             Stmt::Block { statements } => {
                 self.execute_block(statements, Some(self.environment.clone()))
             }
@@ -406,78 +778,56 @@ impl Interpreter {
                     Ok(())
                 }
             }
-            Stmt::StructDecl { name, fields } => {
-                self.type_registry.register_struct(name, fields);
+            Stmt::StructDecl {
+                name,
+                fields,
+                tuple,
+                derives,
+                ..
+            } => {
+                self.type_registry.register_derives(&name.lexeme, &derives);
+                self.type_registry.register_struct_decl(name, fields, tuple);
                 Ok(())
             }
-            Stmt::EnumDecl { name, variants } => {
+            Stmt::EnumDecl {
+                name,
+                variants,
+                derives,
+                ..
+            } => {
+                self.type_registry.register_derives(&name.lexeme, &derives);
                 self.type_registry.register_enum(name, variants);
                 Ok(())
             }
-            Stmt::Match { expr, cases } => {
+            decl @ (Stmt::TypeAlias { .. } | Stmt::Newtype { .. }) => {
+                self.type_decls.declare(&decl);
+                Ok(())
+            }
+            Stmt::Match { expr, cases, .. } => {
                 let match_value = self.evaluate(expr)?;
-                for (pattern, guard, stmt) in cases {
-                    if let Some(bindings) = self.pattern_matches(&pattern, &match_value) {
-                        let (p_depth, p_arena) = {
-                            let b = self.environment.borrow();
-                            (b.depth, b.associated_arena)
-                        };
-                        // Avaliar guard (se existir) em ambiente com bindings temporário
-                        if let Some(gexpr) = guard {
-                            let previous_env = self.environment.clone();
-                            let temp_env = Rc::new(RefCell::new(Environment::new(
-                                Some(previous_env.clone()),
-                                p_depth + 1,
-                                p_arena,
-                            )));
-                            self.environment = temp_env.clone();
-                            for (name, value) in bindings.iter() {
-                                self.environment.borrow_mut().define(name, value.clone());
-                            }
-                            let guard_passed = self
-                                .evaluate(gexpr)
-                                .map(|v| self.is_truthy(&v))
-                                .unwrap_or(false);
-                            // Garantir que handles fortes do ambiente temporário do guard sejam decrementados
-                            self.drop_scope_heap_objects(&temp_env);
-                            self.environment = previous_env;
-                            if !guard_passed {
-                                continue;
-                            }
-                        }
-                        let (p_depth, p_arena) = {
-                            let b = self.environment.borrow();
-                            (b.depth, b.associated_arena)
-                        };
-                        let new_env_struct =
-                            Environment::new(Some(self.environment.clone()), p_depth + 1, p_arena);
-                        let new_env = Rc::new(RefCell::new(new_env_struct));
-                        let previous = self.environment.clone();
-                        self.environment = new_env.clone();
-                        for (name, mut value) in bindings {
-                            let target_aid = new_env.borrow().associated_arena;
-                            self.promote_if_escaping(target_aid, &mut value);
-                            new_env.borrow_mut().define(&name, value);
-                        }
-                        // Executar o corpo e garantir que mesmo em erro o escopo temporário seja limpo
-                        let result = self.execute(stmt);
-                        // Drop handles do env de bindings antes de restaurar
-                        self.drop_scope_heap_objects(&new_env);
-                        self.environment = previous;
-                        return result;
-                    }
+                let Some((bindings, stmt)) = self.select_match_arm(&match_value, cases) else {
+                    return Ok(());
+                };
+                let (p_depth, p_arena) = {
+                    let b = self.environment.borrow();
+                    (b.depth, b.associated_arena)
+                };
+                let new_env_struct =
+                    Environment::new(Some(self.environment.clone()), p_depth + 1, p_arena);
+                let new_env = Rc::new(RefCell::new(new_env_struct));
+                let previous = self.environment.clone();
+                self.environment = new_env.clone();
+                for (name, mut value) in bindings {
+                    let target_aid = new_env.borrow().associated_arena;
+                    self.promote_if_escaping(target_aid, &mut value);
+                    new_env.borrow_mut().define(&name, value);
                 }
-
-                // Se chegou aqui, nenhum pattern casou (Non-exhaustive pattern match no Runtime)
-                self.diagnostics.push(Diagnostic::new(
-                    DiagnosticKind::Runtime,
-                    format!(
-                        "Non-exhaustive match: no pattern matched the value '{:?}'",
-                        match_value
-                    ),
-                    Span::new(0, 0, 0, 0), // Idealmente teríamos o span do Stmt::Match
-                ));
-                Ok(())
+                // Executar o corpo e garantir que mesmo em erro o escopo temporário seja limpo
+                let result = self.execute(stmt);
+                // Drop handles do env de bindings antes de restaurar
+                let result = self.exit_scope(&new_env, result);
+                self.environment = previous;
+                return result;
             }
             Stmt::TryCatch {
                 try_branch,
@@ -486,7 +836,9 @@ impl Interpreter {
             } => match self.execute(*try_branch) {
                 Ok(()) => Ok(()),
                 Err(RuntimeError::Return(v)) => Err(RuntimeError::Return(v)),
-                Err(e @ RuntimeError::DebugStepBack)
+                Err(e @ RuntimeError::Break(_))
+                | Err(e @ RuntimeError::Continue(_))
+                | Err(e @ RuntimeError::DebugStepBack)
                 | Err(e @ RuntimeError::DebugQuit)
                 | Err(e @ RuntimeError::DebugJumpTo(_)) => Err(e),
                 Err(RuntimeError::TypeError(msg)) => {
@@ -508,7 +860,7 @@ impl Interpreter {
 
                     let result = self.execute(*catch_branch);
 
-                    self.drop_scope_heap_objects(&catch_env);
+                    let result = self.exit_scope(&catch_env, result);
                     self.environment = previous_env;
 
                     result
@@ -521,7 +873,8 @@ impl Interpreter {
                 return_type: _,
                 body,
                 method_owner,
-                is_async: _,
+                is_async,
+                public: _,
             } => {
                 let fn_rc = Rc::new(Function {
                     name: Some(name.lexeme.clone()),
@@ -530,6 +883,7 @@ impl Interpreter {
                     body: body.clone(),
                     closure: Rc::downgrade(&self.environment),
                     retained_env: None,
+                    is_async,
                 });
                 if let Some(owner) = method_owner {
                     if let Some(sdef) = self.type_registry.structs.get_mut(&owner) {
@@ -559,10 +913,54 @@ impl Interpreter {
                 }
                 Ok(())
             }
-            Stmt::ImplBlock { methods, .. } => {
+            Stmt::ImplBlock {
+                type_name,
+                trait_name,
+                methods,
+            } => {
+                let method_names: Vec<String> = methods
+                    .iter()
+                    .filter_map(|m| match m {
+                        Stmt::Function { name, .. } => Some(name.lexeme.clone()),
+                        _ => None,
+                    })
+                    .collect();
                 for method in methods {
                     self.execute(method)?;
                 }
+                if let Some(trait_name) = trait_name {
+                    self.check_trait_impl(&trait_name, &type_name, &method_names);
+                }
+                Ok(())
+            }
+            Stmt::TraitDecl { name, methods } => {
+                let mut signatures = HashMap::new();
+                let mut defaults = HashMap::new();
+                for method in methods {
+                    let param_types = method
+                        .params
+                        .iter()
+                        .filter(|p| p.name.lexeme != "self")
+                        .map(|p| p.ty.clone().unwrap_or_else(|| "Any".to_string()))
+                        .collect();
+                    signatures.insert(method.name.lexeme.clone(), param_types);
+                    if let Some(body) = method.body {
+                        defaults.insert(
+                            method.name.lexeme.clone(),
+                            Function {
+                                name: Some(method.name.lexeme.clone()),
+                                type_params: None,
+                                params: method.params,
+                                body,
+                                closure: Rc::downgrade(&self.environment),
+                                retained_env: None,
+                                is_async: false,
+                            },
+                        );
+                    }
+                }
+                self.type_registry
+                    .register_trait(name, signatures, defaults);
                 Ok(())
             }
             Stmt::Return { value } => {
@@ -589,6 +987,20 @@ impl Interpreter {
                 }
                 Err(RuntimeError::Return(return_value))
             }
+            // Dentro de um generator o `yield` é tratado por `generators.rs`; aqui ele
+            // só chega fora de uma função (ou dentro de um bloco `performant`).
+            Stmt::Yield { keyword, .. } => {
+                self.diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Runtime,
+                    "'yield' outside of a generator function.".to_string(),
+                    Span::new(keyword.start, keyword.end, keyword.line, keyword.col),
+                ));
+                Ok(())
+            }
+            Stmt::Defer { body, .. } => {
+                self.environment.borrow_mut().deferred.push(*body);
+                Ok(())
+            }
             Stmt::Performant { statements } => {
                 // Criar arena ID e frame léxico
                 let aid = self.next_arena_id;
@@ -612,26 +1024,23 @@ impl Interpreter {
                 let scope_env = self.environment.clone();
 
                 // Executar statements
+                let mut result = Ok(());
                 for s in statements {
-                    if let Err(e) = self.execute(s) {
-                        self.drop_scope_heap_objects(&scope_env);
-                        self.finalize_arena(aid);
-                        self.current_arena = prev_arena;
-                        self.in_performant_block = prev_performant;
-                        self.environment = previous;
-                        return Err(e);
+                    result = self.execute(s);
+                    if result.is_err() {
+                        break;
                     }
                 }
 
-                // Cleanup
-                self.drop_scope_heap_objects(&scope_env);
+                // Cleanup: os `defer` rodam ainda dentro da arena, antes de ela ser finalizada.
+                let result = self.exit_scope(&scope_env, result);
                 self.finalize_arena(aid);
                 self.current_arena = prev_arena;
                 self.in_performant_block = prev_performant;
                 self.environment = previous;
-                Ok(())
+                result
             }
-            Stmt::Import { path: _ } => {
+            Stmt::Import { .. } => {
                 // Import is a compile-time / resolver concern; runtime no-op for now.
                 Ok(())
             }
@@ -681,7 +1090,11 @@ impl Interpreter {
                     }
                 }
             }
-            Stmt::While { condition, body } => {
+            Stmt::While {
+                condition,
+                body,
+                label,
+            } => {
                 loop {
                     let cond_val = self.evaluate(condition.clone())?;
                     if !self.is_truthy(&cond_val) {
@@ -690,328 +1103,68 @@ impl Interpreter {
                     let _aid = self.push_implicit_arena();
                     let res = self.execute(*body.clone());
                     self.pop_implicit_arena();
-                    res?
+                    if let LoopStep::Exit = loop_step(res, label.as_ref())? {
+                        break;
+                    }
                 }
                 Ok(())
             }
+            Stmt::Break { label, .. } => Err(RuntimeError::Break(label.map(|l| l.lexeme))),
+            Stmt::Continue { label, .. } => Err(RuntimeError::Continue(label.map(|l| l.lexeme))),
             Stmt::For {
                 element,
+                pattern,
                 iterator,
                 body,
+                label,
             } => {
                 let iter_val = self.evaluate(iterator)?;
+                let span = Span::new(element.start, element.end, element.line, element.col);
 
-                // Support: arrays, stream pipelines, or iterator protocols (callable returning Option).
-                // This allows generators to be implemented as closures returning Option.None.
-                enum IterSource {
-                    Array(Vec<ArtValue>),
-                    Iterator,
-                }
-
-                let iter_source = match iter_val.clone() {
-                    ArtValue::Array(arr) => IterSource::Array(arr),
-                    ArtValue::StructInstance {
-                        ref struct_name, ..
-                    } if struct_name == "__Stream" => {
-                        match self.decode_stream_value(iter_val.clone()) {
-                            Ok((source, ops)) => {
-                                IterSource::Array(self.run_stream_pipeline(source, ops)?)
-                            }
-                            Err(msg) => {
-                                self.diagnostics.push(Diagnostic::new(
-                                    DiagnosticKind::Runtime,
-                                    msg,
-                                    Span::new(
-                                        element.start,
-                                        element.end,
-                                        element.line,
-                                        element.col,
-                                    ),
-                                ));
-                                return Ok(());
-                            }
-                        }
-                    }
-                    ArtValue::HeapComposite(h) => {
-                        match self.heap_objects.get(&h.0).map(|obj| obj.value.clone()) {
-                            Some(ArtValue::Array(arr)) => IterSource::Array(arr),
-                            Some(
-                                ref v @ ArtValue::StructInstance {
-                                    ref struct_name, ..
-                                },
-                            ) if struct_name == "__Stream" => {
-                                match self.decode_stream_value(v.clone()) {
-                                    Ok((source, ops)) => {
-                                        IterSource::Array(self.run_stream_pipeline(source, ops)?)
-                                    }
-                                    Err(msg) => {
-                                        self.diagnostics.push(Diagnostic::new(
-                                            DiagnosticKind::Runtime,
-                                            msg,
-                                            Span::new(
-                                                element.start,
-                                                element.end,
-                                                element.line,
-                                                element.col,
-                                            ),
-                                        ));
-                                        return Ok(());
-                                    }
-                                }
-                            }
-                            Some(_) => IterSource::Iterator,
-                            None => {
-                                self.diagnostics.push(Diagnostic::new(
-                                    DiagnosticKind::Runtime,
-                                    "Cannot iterate over dangling heap handle.".to_string(),
-                                    Span::new(
-                                        element.start,
-                                        element.end,
-                                        element.line,
-                                        element.col,
-                                    ),
-                                ));
-                                return Ok(());
-                            }
-                        }
-                    }
-                    ArtValue::Function(_) | ArtValue::Builtin(_) => IterSource::Iterator,
-                    _ => {
-                        self.diagnostics.push(Diagnostic::new(
-                            DiagnosticKind::Runtime,
-                            format!("Cannot iterate over unsupported type: {:?}", iter_val),
-                            Span::new(element.start, element.end, element.line, element.col),
-                        ));
-                        return Ok(());
-                    }
-                };
-
-                // Helper to call iterator `next()` and return the next value (or None).
-                let call_next = |me: &mut Interpreter, iter_val: &ArtValue| -> Result<ArtValue> {
-                    match iter_val {
-                        ArtValue::Function(func) => {
-                            me.call_function(func.clone(), None, Vec::new())
-                        }
-                        ArtValue::Builtin(b) => me.call_builtin(b.clone(), Vec::new()),
-                        ArtValue::StructInstance {
-                            struct_name,
-                            fields,
-                        } => {
-                            let token = Token::dummy("next");
-                            if let Some(callable) = crate::field_access::struct_field_or_method(
-                                struct_name,
-                                fields,
-                                &token,
-                                &me.type_registry,
-                            ) {
-                                match callable {
-                                    ArtValue::Function(func) => {
-                                        me.call_function(func, None, Vec::new())
-                                    }
-                                    ArtValue::Builtin(b) => me.call_builtin(b, Vec::new()),
-                                    other => {
-                                        me.diagnostics.push(Diagnostic::new(
-                                            DiagnosticKind::Runtime,
-                                            format!(
-                                                "Iterator 'next' must be callable, got: {:?}",
-                                                other
-                                            ),
-                                            Span::new(
-                                                element.start,
-                                                element.end,
-                                                element.line,
-                                                element.col,
-                                            ),
-                                        ));
-                                        Ok(ArtValue::none())
-                                    }
-                                }
-                            } else {
-                                me.diagnostics.push(Diagnostic::new(
-                                    DiagnosticKind::Runtime,
-                                    "Iterator object does not implement 'next()' method."
-                                        .to_string(),
-                                    Span::new(
-                                        element.start,
-                                        element.end,
-                                        element.line,
-                                        element.col,
-                                    ),
-                                ));
-                                Ok(ArtValue::none())
-                            }
-                        }
-                        ArtValue::HeapComposite(_) => {
-                            let resolved = me.resolve_composite(iter_val).clone();
-                            if let ArtValue::StructInstance {
-                                struct_name,
-                                fields,
-                            } = resolved
-                            {
-                                let token = Token::dummy("next");
-                                if let Some(callable) = crate::field_access::struct_field_or_method(
-                                    &struct_name,
-                                    &fields,
-                                    &token,
-                                    &me.type_registry,
-                                ) {
-                                    match callable {
-                                        ArtValue::Function(func) => {
-                                            me.call_function(func, None, Vec::new())
-                                        }
-                                        ArtValue::Builtin(b) => me.call_builtin(b, Vec::new()),
-                                        other => {
-                                            me.diagnostics.push(Diagnostic::new(
-                                                DiagnosticKind::Runtime,
-                                                format!(
-                                                    "Iterator 'next' must be callable, got: {:?}",
-                                                    other
-                                                ),
-                                                Span::new(
-                                                    element.start,
-                                                    element.end,
-                                                    element.line,
-                                                    element.col,
-                                                ),
-                                            ));
-                                            Ok(ArtValue::none())
-                                        }
-                                    }
-                                } else {
-                                    me.diagnostics.push(Diagnostic::new(
-                                        DiagnosticKind::Runtime,
-                                        "Iterator object does not implement 'next()' method."
-                                            .to_string(),
-                                        Span::new(
-                                            element.start,
-                                            element.end,
-                                            element.line,
-                                            element.col,
-                                        ),
-                                    ));
-                                    Ok(ArtValue::none())
-                                }
-                            } else {
-                                me.diagnostics.push(Diagnostic::new(
-                                    DiagnosticKind::Runtime,
-                                    format!("Cannot iterate over unsupported type: {:?}", iter_val),
-                                    Span::new(
-                                        element.start,
-                                        element.end,
-                                        element.line,
-                                        element.col,
-                                    ),
-                                ));
-                                Ok(ArtValue::none())
-                            }
-                        }
-                        _ => {
-                            me.diagnostics.push(Diagnostic::new(
-                                DiagnosticKind::Runtime,
-                                format!("Cannot iterate over unsupported type: {:?}", iter_val),
-                                Span::new(element.start, element.end, element.line, element.col),
-                            ));
-                            Ok(ArtValue::none())
-                        }
-                    }
+                // Coleções e intervalos, streams, generators ou o protocolo `next()`
+                // (callable ou struct devolvendo Option; `Option.None` encerra).
+                let Some(mut source) = self.for_source(&iter_val, span)? else {
+                    return Ok(());
                 };
+                // Itens vindos de `next()` são criados a cada passo: cada iteração
+                // ganha sua própria arena implícita.
+                let arena_per_item = matches!(source, ForSource::Next(_));
 
-                match iter_source {
-                    IterSource::Array(array_elements) => {
-                        for mut val in array_elements {
-                            let previous_env = self.environment.clone();
-                            let (p_depth, p_arena) = {
-                                let b = previous_env.borrow();
-                                (b.depth, b.associated_arena)
-                            };
-                            let loop_env = Rc::new(RefCell::new(Environment::new(
-                                Some(previous_env.clone()),
-                                p_depth + 1,
-                                p_arena,
-                            )));
-                            self.environment = loop_env.clone();
-
-                            let target_aid = loop_env.borrow().associated_arena;
-                            self.promote_if_escaping(target_aid, &mut val);
-                            self.environment.borrow_mut().define(&element.lexeme, val);
-
-                            let result = self.execute(*body.clone());
+                while let Some(mut item) = self.for_source_next(&mut source, span)? {
+                    let previous_env = self.environment.clone();
+                    let (p_depth, p_arena) = {
+                        let b = previous_env.borrow();
+                        (b.depth, b.associated_arena)
+                    };
+                    let loop_arena = if arena_per_item {
+                        Some(self.push_implicit_arena())
+                    } else {
+                        p_arena
+                    };
+                    let loop_env = Rc::new(RefCell::new(Environment::new(
+                        Some(previous_env.clone()),
+                        p_depth + 1,
+                        loop_arena,
+                    )));
+                    self.environment = loop_env.clone();
 
-                            self.drop_scope_heap_objects(&loop_env);
-                            self.environment = previous_env;
+                    let target_aid = loop_env.borrow().associated_arena;
+                    self.promote_if_escaping(target_aid, &mut item);
+                    let result = self
+                        .bind_loop_element(&element, pattern.as_ref(), item)
+                        .and_then(|()| self.execute(*body.clone()));
 
-                            result?
-                        }
-                        Ok(())
+                    let result = self.exit_scope(&loop_env, result);
+                    if arena_per_item {
+                        self.pop_implicit_arena();
                     }
-                    IterSource::Iterator => {
-                        let iter_val = iter_val;
-                        loop {
-                            let next_val = call_next(self, &iter_val)?;
-                            let next_val = self.resolve_composite(&next_val).clone();
-                            let mut item = match next_val {
-                                ArtValue::Optional(boxed) => match *boxed {
-                                    Some(v) => v,
-                                    None => break,
-                                },
-                                ArtValue::EnumInstance {
-                                    enum_name,
-                                    variant,
-                                    values,
-                                } if enum_name == "Option" => {
-                                    if variant == "Some" {
-                                        values.into_iter().next().unwrap_or(ArtValue::none())
-                                    } else {
-                                        break;
-                                    }
-                                }
-                                other => {
-                                    self.diagnostics.push(Diagnostic::new(
-                                        DiagnosticKind::Runtime,
-                                        format!(
-                                            "Iterator protocol expected Optional, got: {:?}",
-                                            other
-                                        ),
-                                        Span::new(
-                                            element.start,
-                                            element.end,
-                                            element.line,
-                                            element.col,
-                                        ),
-                                    ));
-                                    break;
-                                }
-                            };
-
-                            let previous_env = self.environment.clone();
-                            let (p_depth, _p_arena) = {
-                                let b = previous_env.borrow();
-                                (b.depth, b.associated_arena)
-                            };
-                            // Iniciamos uma arena para o corpo do loop
-                            let _aid = self.push_implicit_arena();
-                            let loop_env = Rc::new(RefCell::new(Environment::new(
-                                Some(previous_env.clone()),
-                                p_depth + 1,
-                                Some(_aid),
-                            )));
-                            self.environment = loop_env.clone();
-
-                            let target_aid = loop_env.borrow().associated_arena;
-                            self.promote_if_escaping(target_aid, &mut item);
-                            self.environment.borrow_mut().define(&element.lexeme, item);
-
-                            let result = self.execute(*body.clone());
-
-                            self.drop_scope_heap_objects(&loop_env);
-                            self.pop_implicit_arena();
-                            self.environment = previous_env;
+                    self.environment = previous_env;
 
-                            result?
-                        }
-                        Ok(())
+                    if let LoopStep::Exit = loop_step(result, label.as_ref())? {
+                        break;
                     }
                 }
+                Ok(())
             }
             Stmt::ComponentBlock { .. } | Stmt::QualifiedBinding { .. } => {
                 // Component blocks are a compile-time / codegen concern; runtime no-op.
@@ -1131,10 +1284,162 @@ impl Interpreter {
                     }
                 }
             }
+            (MatchPattern::Tuple(pats), ArtValue::Tuple(values)) if pats.len() == values.len() => {
+                let values = values.clone();
+                let mut all_bindings = Vec::new();
+                for (p, v) in pats.iter().zip(&values) {
+                    all_bindings.extend(self.pattern_matches(p, v)?);
+                }
+                Some(all_bindings)
+            }
+            (
+                MatchPattern::Struct { name, fields },
+                ArtValue::StructInstance {
+                    struct_name,
+                    fields: values,
+                },
+            ) if &name.lexeme == struct_name => {
+                let values = values.clone();
+                let mut all_bindings = Vec::new();
+                for (field, p) in fields {
+                    let Some(v) = values.get(&field.lexeme) else {
+                        self.diagnostics.push(Diagnostic::new(
+                            DiagnosticKind::Runtime,
+                            format!(
+                                "Struct '{}' has no field '{}' in pattern",
+                                name.lexeme, field.lexeme
+                            ),
+                            Span::new(field.start, field.end, field.line, field.col),
+                        ));
+                        return None;
+                    };
+                    all_bindings.extend(self.pattern_matches(p, v)?);
+                }
+                Some(all_bindings)
+            }
+            (
+                MatchPattern::Array {
+                    prefix,
+                    rest,
+                    suffix,
+                },
+                ArtValue::Array(items),
+            ) => {
+                let fixed = prefix.len() + suffix.len();
+                let fits = match rest {
+                    Some(_) => items.len() >= fixed,
+                    None => items.len() == fixed,
+                };
+                if !fits {
+                    return None;
+                }
+                let items = items.clone();
+                let tail_start = items.len() - suffix.len();
+                let mut all_bindings = Vec::new();
+                for (p, v) in prefix.iter().zip(&items) {
+                    all_bindings.extend(self.pattern_matches(p, v)?);
+                }
+                if let Some(Some(name)) = rest {
+                    let middle = items[prefix.len()..tail_start].to_vec();
+                    all_bindings.push((name.lexeme.clone(), ArtValue::Array(middle)));
+                }
+                for (p, v) in suffix.iter().zip(&items[tail_start..]) {
+                    all_bindings.extend(self.pattern_matches(p, v)?);
+                }
+                Some(all_bindings)
+            }
+            (MatchPattern::Or(alts), _) => {
+                alts.iter().find_map(|alt| self.pattern_matches(alt, value))
+            }
+            (
+                MatchPattern::Range {
+                    start,
+                    end,
+                    inclusive,
+                },
+                v,
+            ) => {
+                let as_f64 = |v: &ArtValue| match v {
+                    ArtValue::Int(n) => Some(*n as f64),
+                    ArtValue::Float(f) => Some(*f),
+                    _ => None,
+                };
+                let in_range = match (start, end, v) {
+                    (ArtValue::Int(lo), ArtValue::Int(hi), ArtValue::Int(n)) => {
+                        *lo <= *n && if *inclusive { *n <= *hi } else { *n < *hi }
+                    }
+                    _ => match (as_f64(start), as_f64(end), as_f64(v)) {
+                        (Some(lo), Some(hi), Some(n)) => {
+                            lo <= n && if *inclusive { n <= hi } else { n < hi }
+                        }
+                        _ => false,
+                    },
+                };
+                in_range.then(Vec::new)
+            }
+            (MatchPattern::At { name, pattern }, val) => {
+                let val = val.clone();
+                let mut bindings = vec![(name.lexeme.clone(), val)];
+                bindings.extend(self.pattern_matches(pattern, value)?);
+                Some(bindings)
+            }
             _ => None,
         }
     }
 
+    /// Primeiro braço do `match` cujo padrão casa com `value` e cuja guarda passa,
+    /// com os bindings do padrão. Sem braço, registra o diagnóstico de match não exaustivo.
+    pub(super) fn select_match_arm(
+        &mut self,
+        value: &ArtValue,
+        cases: Vec<(MatchPattern, Option<core::ast::Expr>, Stmt)>,
+    ) -> Option<(Vec<(String, ArtValue)>, Stmt)> {
+        for (pattern, guard, stmt) in cases {
+            let Some(bindings) = self.pattern_matches(&pattern, value) else {
+                continue;
+            };
+            // Avaliar guard (se existir) em ambiente com bindings temporário
+            if let Some(gexpr) = guard {
+                let (p_depth, p_arena) = {
+                    let b = self.environment.borrow();
+                    (b.depth, b.associated_arena)
+                };
+                let previous_env = self.environment.clone();
+                let temp_env = Rc::new(RefCell::new(Environment::new(
+                    Some(previous_env.clone()),
+                    p_depth + 1,
+                    p_arena,
+                )));
+                self.environment = temp_env.clone();
+                for (name, value) in bindings.iter() {
+                    self.environment.borrow_mut().define(name, value.clone());
+                }
+                let guard_passed = self
+                    .evaluate(gexpr)
+                    .map(|v| self.is_truthy(&v))
+                    .unwrap_or(false);
+                // Garantir que handles fortes do ambiente temporário do guard sejam decrementados
+                self.drop_scope_heap_objects(&temp_env);
+                self.environment = previous_env;
+                if !guard_passed {
+                    continue;
+                }
+            }
+            return Some((bindings, stmt));
+        }
+
+        // Se chegou aqui, nenhum pattern casou (Non-exhaustive pattern match no Runtime)
+        self.diagnostics.push(Diagnostic::new(
+            DiagnosticKind::Runtime,
+            format!(
+                "Non-exhaustive match: no pattern matched the value '{:?}'",
+                value
+            ),
+            Span::new(0, 0, 0, 0), // Idealmente teríamos o span do Stmt::Match
+        ));
+        None
+    }
+
     pub(super) fn execute_block(
         &mut self,
         statements: Vec<Stmt>,
@@ -1185,23 +1490,28 @@ impl Interpreter {
                                 body: f.body.clone(),
                                 closure: f.closure.clone(),
                                 retained_env: Some(scope_env.clone()),
+                                is_async: f.is_async,
                             };
                             let mut rv = ArtValue::Function(Rc::new(escaped));
                             let aid = previous.borrow().associated_arena;
                             self.promote_if_escaping(aid, &mut rv);
-                            return Err(RuntimeError::Return(rv));
+                            // O escopo sobrevive na closure, mas os `defer` rodam agora.
+                            let result =
+                                self.run_deferred(&scope_env, Err(RuntimeError::Return(rv)));
+                            self.environment = previous;
+                            return result;
                         }
                         RuntimeError::Return(rv)
                     }
                     other => other,
                 };
-                self.drop_scope_heap_objects(&scope_env);
+                let result = self.exit_scope(&scope_env, Err(transformed));
                 self.environment = previous;
-                return Err(transformed);
+                return result;
             }
         }
-        self.drop_scope_heap_objects(&scope_env);
+        let result = self.exit_scope(&scope_env, Ok(()));
         self.environment = previous;
-        Ok(())
+        result
     }
 }
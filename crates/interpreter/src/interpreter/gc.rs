@@ -1,4 +1,5 @@
 use super::Interpreter;
+use crate::values::{Result, RuntimeError};
 use core::ast::{ArtValue, Stmt};
 use core::environment::Environment;
 use diagnostics::{Diagnostic, DiagnosticKind, Span};
@@ -6,6 +7,43 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 impl Interpreter {
+    /// Fecha o escopo `env`: roda seus `defer` e só então solta os handles do
+    /// escopo, para que os corpos adiados ainda vejam os locais antes de os
+    /// finalizers deles rodarem.
+    pub(super) fn exit_scope(
+        &mut self,
+        env: &Rc<RefCell<Environment>>,
+        result: Result<()>,
+    ) -> Result<()> {
+        let result = self.run_deferred(env, result);
+        self.drop_scope_heap_objects(env);
+        result
+    }
+
+    /// Roda os `defer` de `env` em ordem LIFO, no próprio escopo. Todos rodam; o
+    /// erro de um deles substitui `result`, a menos que o escopo já esteja saindo
+    /// por erro (o original vence).
+    pub(super) fn run_deferred(
+        &mut self,
+        env: &Rc<RefCell<Environment>>,
+        mut result: Result<()>,
+    ) -> Result<()> {
+        let deferred = std::mem::take(&mut env.borrow_mut().deferred);
+        if deferred.is_empty() {
+            return result;
+        }
+        let previous = std::mem::replace(&mut self.environment, env.clone());
+        for body in deferred.into_iter().rev() {
+            if let Err(e) = self.execute(body)
+                && !matches!(result, Err(RuntimeError::TypeError(_)))
+            {
+                result = Err(e);
+            }
+        }
+        self.environment = previous;
+        result
+    }
+
     pub(super) fn drop_scope_heap_objects(&mut self, env: &Rc<RefCell<Environment>>) {
         let handles = env.borrow().strong_handles.clone();
         for h in handles {
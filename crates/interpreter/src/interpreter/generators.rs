@@ -0,0 +1,532 @@
+//! Generators: funções cujo corpo contém `yield`.
+//!
+//! Chamar um generator não executa o corpo: devolve um objeto `__Generator`
+//! cujo estado (ambiente da chamada e uma pilha de frames) fica em
+//! `Interpreter::generators`. Cada `next()` retoma a execução de onde ela
+//! parou até o próximo `yield`, com os locais preservados.
+//!
+//! Statements sem `yield` rodam de uma vez via `execute`. Os que contêm
+//! `yield` (blocos, `if`, `if let`, `match`, loops e `try`) viram frames que
+//! podem ser suspensos no meio; `break`, `continue`, `return` e erros
+//! desempilham frames até quem os trata.
+//!
+//! O mesmo executor (`Coroutine`) roda o corpo das `async func` (ver `tasks.rs`),
+//! que suspendem em `await` em vez de `yield`.
+
+use super::Interpreter;
+use super::exec::loop_targeted;
+use super::iteration::ForSource;
+use crate::values::{Result, RuntimeError};
+use core::Token;
+use core::ast::{ArtValue, Expr, Function, MatchPattern, Stmt};
+use core::environment::Environment;
+use diagnostics::{Diagnostic, DiagnosticKind, Span};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+
+pub(super) const GENERATOR_STRUCT: &str = "__Generator";
+
+type Scope = (Rc<RefCell<Environment>>, Rc<RefCell<Environment>>);
+
+pub struct Coroutine {
+    name: Option<String>,
+    /// Ambiente corrente no ponto de suspensão.
+    env: Rc<RefCell<Environment>>,
+    frames: Vec<Frame>,
+    done: bool,
+    /// Statement suspenso num `await`; roda de novo com o valor esperado no lugar.
+    awaiting: Option<Stmt>,
+}
+
+/// Onde a execução de uma `Coroutine` parou.
+pub(super) enum Suspend {
+    Yield(ArtValue),
+    /// `await` do valor avaliado (task, timer ou valor pronto).
+    Await(ArtValue),
+    /// Terminou com o valor do `return` (ou `none`).
+    Done(ArtValue),
+}
+
+struct Frame {
+    kind: FrameKind,
+    /// `(anterior, aberto)`: escopo aberto pelo frame, fechado quando ele sai da pilha.
+    scope: Option<Scope>,
+}
+
+enum FrameKind {
+    Block {
+        statements: Vec<Stmt>,
+        next: usize,
+    },
+    While {
+        condition: Expr,
+        body: Stmt,
+        label: Option<Token>,
+    },
+    For {
+        source: ForSource,
+        element: Token,
+        pattern: Option<MatchPattern>,
+        body: Stmt,
+        label: Option<Token>,
+    },
+    /// Marca um `try` em andamento; o corpo está nos frames acima.
+    Try {
+        catch_name: Token,
+        catch_branch: Stmt,
+    },
+}
+
+impl Frame {
+    fn block(statements: Vec<Stmt>, scope: Option<Scope>) -> Self {
+        Frame {
+            kind: FrameKind::Block {
+                statements,
+                next: 0,
+            },
+            scope,
+        }
+    }
+
+    fn unscoped(kind: FrameKind) -> Self {
+        Frame { kind, scope: None }
+    }
+
+    fn loop_label(&self) -> Option<Option<&Token>> {
+        match &self.kind {
+            FrameKind::While { label, .. } | FrameKind::For { label, .. } => Some(label.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl Coroutine {
+    /// Corpo de `func` pronto para rodar em `env`, que já tem os parâmetros ligados.
+    pub(super) fn new(func: &Function, env: Rc<RefCell<Environment>>) -> Self {
+        let body = Rc::as_ref(&func.body).clone();
+        Coroutine {
+            name: func.name.clone(),
+            env,
+            frames: vec![Frame::block(vec![body], None)],
+            done: false,
+            awaiting: None,
+        }
+    }
+}
+
+impl Interpreter {
+    /// Cria o objeto generator de uma chamada a `func`; `env` já tem os parâmetros ligados.
+    pub(super) fn new_generator(
+        &mut self,
+        func: &Function,
+        env: Rc<RefCell<Environment>>,
+    ) -> ArtValue {
+        let id = self.next_generator_id;
+        self.next_generator_id += 1;
+        self.generators.insert(id, Coroutine::new(func, env));
+        let mut fields = HashMap::new();
+        fields.insert("id".to_string(), ArtValue::Int(id as i64));
+        fields.insert(
+            "name".to_string(),
+            ArtValue::String(Arc::from(func.name.as_deref().unwrap_or("<anonymous>"))),
+        );
+        ArtValue::StructInstance {
+            struct_name: GENERATOR_STRUCT.to_string(),
+            fields,
+        }
+    }
+
+    pub(super) fn generator_id(&self, value: &ArtValue) -> Option<u64> {
+        match self.resolve_composite(value) {
+            ArtValue::StructInstance {
+                struct_name,
+                fields,
+            } if struct_name == GENERATOR_STRUCT => match fields.get("id") {
+                Some(ArtValue::Int(id)) => Some(*id as u64),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// `g.next()`: `Option.Some(valor)` a cada `yield`, `Option.None` ao terminar.
+    pub(super) fn generator_next(&mut self, id: u64) -> Result<ArtValue> {
+        let (variant, values) = match self.resume_generator(id)? {
+            Some(value) => ("Some", vec![value]),
+            None => ("None", Vec::new()),
+        };
+        Ok(ArtValue::EnumInstance {
+            enum_name: "Option".to_string(),
+            variant: variant.to_string(),
+            values,
+        })
+    }
+
+    /// Executa o generator até o próximo `yield` (`Some`) ou até o fim (`None`).
+    pub(super) fn resume_generator(&mut self, id: u64) -> Result<Option<ArtValue>> {
+        // Fora do mapa enquanto roda: um `next()` dele mesmo cai aqui.
+        let Some(mut state) = self.generators.remove(&id) else {
+            self.diagnostics.push(Diagnostic::new(
+                DiagnosticKind::Runtime,
+                "Generator resumed while it is already running.".to_string(),
+                self.call_span,
+            ));
+            return Ok(None);
+        };
+        if state.done {
+            self.generators.insert(id, state);
+            return Ok(None);
+        }
+        let result = match self.resume_coroutine(&mut state, None) {
+            Ok(Suspend::Yield(mut value)) => {
+                let target_aid = self.environment.borrow().associated_arena;
+                self.promote_if_escaping(target_aid, &mut value);
+                Ok(Some(value))
+            }
+            // O parser rejeita `await` em generators; sobra só o fim.
+            Ok(Suspend::Await(_) | Suspend::Done(_)) => Ok(None),
+            Err(e) => Err(e),
+        };
+        self.generators.insert(id, state);
+        result
+    }
+
+    /// Retoma `state` até o próximo `yield`/`await` ou até o fim. `input` é o
+    /// resultado do `await` em que ele parou, se parou num.
+    pub(super) fn resume_coroutine(
+        &mut self,
+        state: &mut Coroutine,
+        input: Option<Result<ArtValue>>,
+    ) -> Result<Suspend> {
+        // O corpo roda no ambiente da chamada, sob ARC: os locais sobrevivem entre
+        // retomadas, então não podem pertencer à arena de quem está consumindo.
+        let previous_env = std::mem::replace(&mut self.environment, state.env.clone());
+        let previous_arena = self.current_arena.take();
+        let previous_performant = std::mem::replace(&mut self.in_performant_block, false);
+        self.fn_stack.push(state.name.clone());
+
+        let result = self.run_coroutine(state, input);
+
+        self.fn_stack.pop();
+        state.env = std::mem::replace(&mut self.environment, previous_env);
+        self.current_arena = previous_arena;
+        self.in_performant_block = previous_performant;
+
+        match &result {
+            Ok(Suspend::Done(_)) => {
+                state.done = true;
+                self.drop_scope_heap_objects(&state.env);
+            }
+            Err(_) => state.done = true,
+            Ok(_) => {}
+        }
+        result
+    }
+
+    fn run_coroutine(
+        &mut self,
+        state: &mut Coroutine,
+        input: Option<Result<ArtValue>>,
+    ) -> Result<Suspend> {
+        if let Some(input) = input {
+            let step = match (state.awaiting.take(), input) {
+                (Some(stmt), Ok(value)) => self.coroutine_stmt(state, stmt.with_awaited(value)),
+                (_, Err(e)) => Err(e),
+                (None, Ok(_)) => Ok(None),
+            };
+            match step {
+                Ok(Some(suspend)) => return Ok(suspend),
+                Ok(None) => {}
+                Err(e) => {
+                    if let Some(value) = self.unwind_coroutine(state, e)? {
+                        return Ok(Suspend::Done(value));
+                    }
+                }
+            }
+        }
+        loop {
+            let Some(top) = state.frames.last_mut() else {
+                return Ok(Suspend::Done(ArtValue::none()));
+            };
+            let step = match &mut top.kind {
+                FrameKind::Block { statements, next } => match statements.get(*next).cloned() {
+                    Some(stmt) => {
+                        *next += 1;
+                        self.coroutine_stmt(state, stmt)
+                    }
+                    None => self.pop_frame(state).map(|()| None),
+                },
+                FrameKind::While {
+                    condition, body, ..
+                } => {
+                    let (condition, body) = (condition.clone(), body.clone());
+                    self.evaluate(condition).and_then(|value| {
+                        if self.is_truthy(&value) {
+                            state.frames.push(Frame::block(vec![body], None));
+                            Ok(None)
+                        } else {
+                            self.pop_frame(state).map(|()| None)
+                        }
+                    })
+                }
+                FrameKind::For {
+                    source,
+                    element,
+                    pattern,
+                    body,
+                    ..
+                } => {
+                    let span = Span::new(element.start, element.end, element.line, element.col);
+                    match self.for_source_next(source, span) {
+                        Ok(Some(mut item)) => {
+                            let (element, pattern, body) =
+                                (element.clone(), pattern.clone(), body.clone());
+                            let scope = self.open_scope();
+                            self.promote_if_escaping(None, &mut item);
+                            state.frames.push(Frame::block(vec![body], Some(scope)));
+                            self.bind_loop_element(&element, pattern.as_ref(), item)
+                                .map(|()| None)
+                        }
+                        Ok(None) => self.pop_frame(state).map(|()| None),
+                        Err(e) => Err(e),
+                    }
+                }
+                // O corpo do `try` terminou sem erro.
+                FrameKind::Try { .. } => self.pop_frame(state).map(|()| None),
+            };
+            match step {
+                Ok(Some(suspend)) => return Ok(suspend),
+                Ok(None) => {}
+                Err(e) => {
+                    if let Some(value) = self.unwind_coroutine(state, e)? {
+                        return Ok(Suspend::Done(value));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Executa `stmt` dentro da coroutine; `Some` quando ela suspende num `yield`
+    /// ou `await`.
+    fn coroutine_stmt(&mut self, state: &mut Coroutine, stmt: Stmt) -> Result<Option<Suspend>> {
+        let yields = stmt.contains_yield();
+        if !yields && !stmt.contains_await() {
+            self.execute(stmt)?;
+            return Ok(None);
+        }
+        if let Some(Expr::Await { value, .. }) = stmt.awaited() {
+            let value = self.evaluate((**value).clone())?;
+            state.awaiting = Some(stmt);
+            return Ok(Some(Suspend::Await(value)));
+        }
+        match stmt {
+            Stmt::Yield { value, .. } => {
+                let value = self.evaluate(value)?;
+                // O escopo em que o valor foi criado pode ser fechado antes de o
+                // consumidor terminar de usá-lo (mesmo cuidado de `return`).
+                if let ArtValue::HeapComposite(h) = &value {
+                    self.inc_heap_strong(h.0);
+                }
+                return Ok(Some(Suspend::Yield(value)));
+            }
+            Stmt::Block { statements } => {
+                let scope = self.open_scope();
+                state.frames.push(Frame::block(statements, Some(scope)));
+            }
+            Stmt::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                let condition = self.evaluate(condition)?;
+                let branch = if self.is_truthy(&condition) {
+                    Some(*then_branch)
+                } else {
+                    else_branch.map(|b| *b)
+                };
+                if let Some(branch) = branch {
+                    state.frames.push(Frame::block(vec![branch], None));
+                }
+            }
+            Stmt::IfLet {
+                pattern,
+                value,
+                then_branch,
+                else_branch,
+            } => {
+                let value = self.evaluate(value)?;
+                if let Some(bindings) = self.pattern_matches(&pattern, &value) {
+                    let scope = self.define_in_new_scope(bindings);
+                    state
+                        .frames
+                        .push(Frame::block(vec![*then_branch], Some(scope)));
+                } else if let Some(else_branch) = else_branch {
+                    state.frames.push(Frame::block(vec![*else_branch], None));
+                }
+            }
+            Stmt::Match { expr, cases, .. } => {
+                let value = self.evaluate(expr)?;
+                if let Some((bindings, body)) = self.select_match_arm(&value, cases) {
+                    let scope = self.define_in_new_scope(bindings);
+                    state.frames.push(Frame::block(vec![body], Some(scope)));
+                }
+            }
+            Stmt::While {
+                condition,
+                body,
+                label,
+            } => state.frames.push(Frame::unscoped(FrameKind::While {
+                condition,
+                body: *body,
+                label,
+            })),
+            Stmt::For {
+                element,
+                pattern,
+                iterator,
+                body,
+                label,
+            } => {
+                let value = self.evaluate(iterator)?;
+                let span = Span::new(element.start, element.end, element.line, element.col);
+                if let Some(source) = self.for_source(&value, span)? {
+                    state.frames.push(Frame::unscoped(FrameKind::For {
+                        source,
+                        element,
+                        pattern,
+                        body: *body,
+                        label,
+                    }));
+                }
+            }
+            Stmt::TryCatch {
+                try_branch,
+                catch_name,
+                catch_branch,
+            } => {
+                state.frames.push(Frame::unscoped(FrameKind::Try {
+                    catch_name,
+                    catch_branch: *catch_branch,
+                }));
+                state.frames.push(Frame::block(vec![*try_branch], None));
+            }
+            _ => {
+                let keyword = if yields { "yield" } else { "await" };
+                return Err(RuntimeError::TypeError(format!(
+                    "'{keyword}' cannot suspend inside a performant block."
+                )));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Desempilha frames até quem trata `err`: o loop alvo de `break`/`continue`
+    /// ou o `try` mais interno para erros. `return` encerra a coroutine e devolve
+    /// o valor (descartado pelos generators). Um `defer` que falha no caminho
+    /// passa a ser o erro desempilhado, como em `Interpreter::run_deferred`.
+    fn unwind_coroutine(
+        &mut self,
+        state: &mut Coroutine,
+        err: RuntimeError,
+    ) -> Result<Option<ArtValue>> {
+        match err {
+            RuntimeError::Break(ref target) | RuntimeError::Continue(ref target) => {
+                let is_break = matches!(err, RuntimeError::Break(_));
+                while let Some(frame) = state.frames.last() {
+                    if let Some(label) = frame.loop_label()
+                        && loop_targeted(target, label)
+                    {
+                        if is_break && let Err(e) = self.pop_frame(state) {
+                            return self.unwind_coroutine(state, e);
+                        }
+                        return Ok(None);
+                    }
+                    if let Err(e) = self.pop_frame(state) {
+                        return self.unwind_coroutine(state, e);
+                    }
+                }
+                Err(err)
+            }
+            RuntimeError::TypeError(msg) => {
+                while let Some(frame) = state.frames.pop() {
+                    if let FrameKind::Try {
+                        catch_name,
+                        catch_branch,
+                    } = frame.kind
+                    {
+                        let message = ArtValue::String(Arc::from(msg));
+                        let scope = self.define_in_new_scope(vec![(catch_name.lexeme, message)]);
+                        state
+                            .frames
+                            .push(Frame::block(vec![catch_branch], Some(scope)));
+                        return Ok(None);
+                    }
+                    if let Some(scope) = frame.scope {
+                        // O erro original vence o de um `defer`.
+                        let _ = self.close_scope(scope);
+                    }
+                }
+                Err(RuntimeError::TypeError(msg))
+            }
+            RuntimeError::Return(value) => {
+                // Sobrevive ao fechamento dos escopos abaixo (mesmo cuidado de `yield`).
+                if let ArtValue::HeapComposite(h) = &value {
+                    self.inc_heap_strong(h.0);
+                }
+                while !state.frames.is_empty() {
+                    if let Err(e) = self.pop_frame(state) {
+                        return self.unwind_coroutine(state, e);
+                    }
+                }
+                Ok(Some(value))
+            }
+            other => {
+                while !state.frames.is_empty() {
+                    let _ = self.pop_frame(state);
+                }
+                Err(other)
+            }
+        }
+    }
+
+    fn pop_frame(&mut self, state: &mut Coroutine) -> Result<()> {
+        match state.frames.pop() {
+            Some(Frame {
+                scope: Some(scope), ..
+            }) => self.close_scope(scope),
+            _ => Ok(()),
+        }
+    }
+
+    fn open_scope(&mut self) -> Scope {
+        let previous = self.environment.clone();
+        let (depth, arena) = {
+            let b = previous.borrow();
+            (b.depth, b.associated_arena)
+        };
+        let scope = Rc::new(RefCell::new(Environment::new(
+            Some(previous.clone()),
+            depth + 1,
+            arena,
+        )));
+        self.environment = scope.clone();
+        (previous, scope)
+    }
+
+    fn define_in_new_scope(&mut self, bindings: Vec<(String, ArtValue)>) -> Scope {
+        let scope = self.open_scope();
+        for (name, mut value) in bindings {
+            self.promote_if_escaping(None, &mut value);
+            scope.1.borrow_mut().define(&name, value);
+        }
+        scope
+    }
+
+    fn close_scope(&mut self, (previous, scope): Scope) -> Result<()> {
+        let result = self.exit_scope(&scope, Ok(()));
+        self.environment = previous;
+        result
+    }
+}
@@ -0,0 +1,260 @@
+//! Monomorfização de funções genéricas na chamada.
+//!
+//! Cada chamada de uma função com type params resolve os tipos concretos a
+//! partir de `f::<Int>(...)` ou, sem type args, unificando as anotações dos
+//! parâmetros (`T`, `[T]`, `Array<T>`) com os valores recebidos. A assinatura
+//! instanciada é validada (bounds e tipos dos argumentos) e guardada em cache,
+//! uma especialização por função e tupla de tipos.
+
+use super::Interpreter;
+use core::ast::{ArtValue, Function, Stmt};
+use diagnostics::{Diagnostic, DiagnosticKind, Span};
+use std::rc::Rc;
+
+/// Chave de cache: identidade do corpo da função + tipos concretos.
+pub(crate) type SpecializationKey = (usize, Vec<String>);
+
+/// Assinatura de uma função genérica instanciada para uma tupla de tipos.
+#[derive(Debug, Clone)]
+pub struct Specialization {
+    pub function: Option<String>,
+    pub type_args: Vec<String>,
+    /// Anotação de cada parâmetro com os type params substituídos.
+    pub param_types: Vec<Option<String>>,
+    // Mantém o corpo vivo enquanto a entrada existir, para que o endereço
+    // usado na chave não seja reaproveitado por outra função.
+    _body: Rc<Stmt>,
+}
+
+/// `[X]` ou `Array<X>` -> `X`.
+fn array_element(ty: &str) -> Option<&str> {
+    ty.strip_prefix('[')
+        .and_then(|t| t.strip_suffix(']'))
+        .or_else(|| ty.strip_prefix("Array<").and_then(|t| t.strip_suffix('>')))
+}
+
+/// Substitui os type params por seus tipos concretos numa anotação.
+fn substitute(ty: &str, names: &[String], concrete: &[String]) -> String {
+    let mut out = String::new();
+    let mut ident = String::new();
+    let flush = |ident: &mut String, out: &mut String| {
+        match names.iter().position(|n| n == ident) {
+            Some(i) => out.push_str(&concrete[i]),
+            None => out.push_str(ident),
+        }
+        ident.clear();
+    };
+    for c in ty.chars() {
+        if c.is_alphanumeric() || c == '_' {
+            ident.push(c);
+        } else {
+            flush(&mut ident, &mut out);
+            out.push(c);
+        }
+    }
+    flush(&mut ident, &mut out);
+    out
+}
+
+impl Interpreter {
+    /// Nome do tipo de um valor como aparece em anotações (`Option`, não `Optional`).
+    fn annotation_type_name(&self, value: &ArtValue) -> String {
+        match self.resolve_composite(value) {
+            ArtValue::Optional(_) => "Option".to_string(),
+            other => other.type_name(),
+        }
+    }
+
+    /// Tipo que `value` atribui a `tname` quando passado a um parâmetro anotado com `ty`.
+    fn unify(&self, ty: &str, tname: &str, value: &ArtValue) -> Option<String> {
+        if ty == tname {
+            return Some(self.annotation_type_name(value));
+        }
+        let elem_ty = array_element(ty)?;
+        match self.resolve_composite(value) {
+            ArtValue::Array(items) => items.first().and_then(|v| self.unify(elem_ty, tname, v)),
+            _ => None,
+        }
+    }
+
+    /// `value` é aceito por um parâmetro anotado com o tipo (já instanciado) `ty`?
+    fn value_matches(&self, ty: &str, value: &ArtValue) -> bool {
+        let erased = self.type_decls.erase(ty);
+        if erased != ty {
+            return self.value_matches(&erased, value);
+        }
+        if matches!(ty, "Any" | "Unknown") {
+            return true;
+        }
+        if let Some(elem_ty) = array_element(ty) {
+            return match self.resolve_composite(value) {
+                ArtValue::Array(items) => items.iter().all(|v| self.value_matches(elem_ty, v)),
+                _ => false,
+            };
+        }
+        if ty.starts_with('(') {
+            return matches!(self.resolve_composite(value), ArtValue::Tuple(_));
+        }
+        // Genéricos nominais (`Option<Int>`, `Map<String, Int>`) comparam só o nome base.
+        let base = ty.split('<').next().unwrap_or(ty);
+        base == self.annotation_type_name(value)
+    }
+
+    /// Instancia os type params de `func` para uma chamada e valida a assinatura.
+    /// Devolve `false` (com diagnóstico em `span`) quando a chamada deve ser rejeitada.
+    pub(super) fn instantiate_generics(
+        &mut self,
+        func: &Function,
+        type_args: Option<&[String]>,
+        args: &[ArtValue],
+        span: Span,
+    ) -> bool {
+        let Some(type_params) = func.type_params.as_ref().filter(|tp| !tp.is_empty()) else {
+            return true;
+        };
+        let fname = func.name.as_deref().unwrap_or("<lambda>");
+        let names: Vec<String> = type_params.iter().map(|(n, _)| n.clone()).collect();
+
+        let concrete: Vec<String> = match type_args {
+            Some(explicit) if explicit.len() != names.len() => {
+                return self.reject(
+                    format!(
+                        "Expected {} type argument(s) for '{}', found {}",
+                        names.len(),
+                        fname,
+                        explicit.len()
+                    ),
+                    span,
+                );
+            }
+            Some(explicit) => explicit.to_vec(),
+            None => {
+                let mut inferred = Vec::with_capacity(names.len());
+                for tname in &names {
+                    let mut found: Option<String> = None;
+                    for (param, value) in func.params.iter().zip(args) {
+                        let Some(ty) = param.ty.as_deref() else {
+                            continue;
+                        };
+                        let Some(t) = self.unify(ty, tname, value) else {
+                            continue;
+                        };
+                        match &found {
+                            Some(prev) if *prev != t => {
+                                return self.reject(
+                                    format!(
+                                        "Conflicting types for type parameter '{}' in call to '{}': {} and {}",
+                                        tname, fname, prev, t
+                                    ),
+                                    span,
+                                );
+                            }
+                            Some(_) => {}
+                            None => found = Some(t),
+                        }
+                    }
+                    inferred.push(found.unwrap_or_else(|| "Unknown".to_string()));
+                }
+                inferred
+            }
+        };
+
+        let key: SpecializationKey = (Rc::as_ptr(&func.body) as usize, concrete.clone());
+        let param_types = match self.specializations.get(&key) {
+            Some(spec) => spec.param_types.clone(),
+            None => {
+                for ((tname, bound), concrete_ty) in type_params.iter().zip(&concrete) {
+                    let Some(bound) = bound else { continue };
+                    if self.type_registry.get_trait(bound).is_none() {
+                        return self.reject(
+                            format!(
+                                "Unknown trait '{}' in bound of type parameter '{}'",
+                                bound, tname
+                            ),
+                            span,
+                        );
+                    }
+                    // Sem tipo concreto inferível não há o que checar.
+                    if concrete_ty != "Unknown"
+                        && !self.type_registry.implements(concrete_ty, bound)
+                    {
+                        return self.reject(
+                            format!(
+                                "Type '{}' does not satisfy constraint '{}' for type parameter '{}'",
+                                concrete_ty, bound, tname
+                            ),
+                            span,
+                        );
+                    }
+                }
+                // `T` que ficou sem tipo concreto aceita qualquer valor.
+                let substituted: Vec<String> = concrete
+                    .iter()
+                    .map(|c| {
+                        if c == "Unknown" {
+                            "Any".to_string()
+                        } else {
+                            c.clone()
+                        }
+                    })
+                    .collect();
+                let param_types: Vec<Option<String>> = func
+                    .params
+                    .iter()
+                    .map(|p| {
+                        p.ty.as_deref()
+                            .map(|ty| substitute(ty, &names, &substituted))
+                    })
+                    .collect();
+                self.specializations.insert(
+                    key,
+                    Specialization {
+                        function: func.name.clone(),
+                        type_args: concrete,
+                        param_types: param_types.clone(),
+                        _body: func.body.clone(),
+                    },
+                );
+                param_types
+            }
+        };
+
+        for ((param, ty), value) in func.params.iter().zip(&param_types).zip(args) {
+            let Some(ty) = ty else { continue };
+            if self.value_matches(ty, value) {
+                continue;
+            }
+            let found = self.annotation_type_name(value);
+            return self.reject(
+                format!(
+                    "Argument '{}' of '{}' expects {}, found {}",
+                    param.name.lexeme, fname, ty, found
+                ),
+                span,
+            );
+        }
+        true
+    }
+
+    fn reject(&mut self, message: String, span: Span) -> bool {
+        self.diagnostics
+            .push(Diagnostic::new(DiagnosticKind::Runtime, message, span));
+        false
+    }
+
+    /// Especializações já instanciadas, como `(função, type args)`, em ordem.
+    pub fn specializations(&self) -> Vec<(String, Vec<String>)> {
+        let mut out: Vec<_> = self
+            .specializations
+            .values()
+            .map(|s| {
+                (
+                    s.function.clone().unwrap_or_else(|| "<lambda>".to_string()),
+                    s.type_args.clone(),
+                )
+            })
+            .collect();
+        out.sort();
+        out
+    }
+}
@@ -0,0 +1,315 @@
+//! Indexação (`a[i]`), fatiamento (`a[i..j]`) e atribuição por índice
+//! (`a[i] = v`) sobre Array, Tuple, String, Map, Deque e Buffer.
+//!
+//! Índices `Int` negativos contam a partir do fim (`a[-1]` é o último).
+//! Strings são indexadas por caractere, não por byte. Acessos fora do
+//! intervalo emitem um diagnóstico `Runtime` no `[` e avaliam para `none`.
+
+use super::Interpreter;
+use crate::values::Result;
+use core::Token;
+use core::ast::{ArtValue, Expr};
+use diagnostics::{Diagnostic, DiagnosticKind, Span};
+
+/// Converte um índice possivelmente negativo em posição válida em `0..len`.
+fn normalize_index(i: i64, len: usize) -> Option<usize> {
+    let idx = if i < 0 { i + len as i64 } else { i };
+    (0..len as i64).contains(&idx).then_some(idx as usize)
+}
+
+/// Como `normalize_index`, mas para limites de fatia, que podem valer `len`.
+fn normalize_bound(i: i64, len: usize) -> Option<usize> {
+    let idx = if i < 0 { i + len as i64 } else { i };
+    (0..=len as i64).contains(&idx).then_some(idx as usize)
+}
+
+impl Interpreter {
+    fn unhashable_key_message(&self, key: &ArtValue) -> String {
+        format!(
+            "Map keys must be hashable, got {}; add @derive(Hash).",
+            self.runtime_type_label(key)
+        )
+    }
+
+    fn index_error(&mut self, bracket: &Token, message: String) -> ArtValue {
+        self.diagnostics.push(Diagnostic::new(
+            DiagnosticKind::Runtime,
+            message,
+            Span::new(bracket.start, bracket.end, bracket.line, bracket.col),
+        ));
+        ArtValue::none()
+    }
+
+    fn int_index(&mut self, bracket: &Token, index: &ArtValue) -> Option<i64> {
+        match index {
+            ArtValue::Int(i) => Some(*i),
+            other => {
+                self.index_error(
+                    bracket,
+                    format!("Index must be an Int, got {}.", other.type_name()),
+                );
+                None
+            }
+        }
+    }
+
+    fn position(&mut self, bracket: &Token, index: &ArtValue, len: usize) -> Option<usize> {
+        let i = self.int_index(bracket, index)?;
+        let pos = normalize_index(i, len);
+        if pos.is_none() {
+            self.index_error(
+                bracket,
+                format!("Index {} out of range for length {}.", i, len),
+            );
+        }
+        pos
+    }
+
+    pub(super) fn eval_index(
+        &mut self,
+        object: Expr,
+        index: Expr,
+        bracket: Token,
+    ) -> Result<ArtValue> {
+        let container = self.evaluate(object)?;
+        let index = self.evaluate(index)?;
+        Ok(self.index_value(&container, &index, &bracket))
+    }
+
+    /// Lê `container[index]` com os dois lados já avaliados; erros viram
+    /// diagnóstico e o resultado é `none`.
+    pub(super) fn index_value(
+        &mut self,
+        container: &ArtValue,
+        index: &ArtValue,
+        bracket: &Token,
+    ) -> ArtValue {
+        let container = self.resolve_composite(container).clone();
+        let value = match &container {
+            ArtValue::Array(items) | ArtValue::Tuple(items) => self
+                .position(bracket, index, items.len())
+                .map(|p| items[p].clone()),
+            ArtValue::String(s) => {
+                let len = s.chars().count();
+                self.position(bracket, index, len).and_then(|p| {
+                    s.chars()
+                        .nth(p)
+                        .map(|c| ArtValue::String(core::intern_arc(&c.to_string())))
+                })
+            }
+            ArtValue::Buffer(bytes) => self
+                .position(bracket, index, bytes.len())
+                .map(|p| ArtValue::Int(bytes[p] as i64)),
+            ArtValue::Deque(d) => {
+                let items = d.0.lock().unwrap_or_else(|e| e.into_inner()).clone();
+                self.position(bracket, index, items.len())
+                    .map(|p| items[p].clone())
+            }
+            ArtValue::Map(m) => {
+                let Some(key) = self.map_key(index) else {
+                    let msg = self.unhashable_key_message(index);
+                    return self.index_error(bracket, msg);
+                };
+                let found =
+                    m.0.lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .get(&key)
+                        .cloned();
+                if found.is_none() {
+                    self.index_error(bracket, format!("Key '{}' not found in map.", key));
+                }
+                found
+            }
+            other => {
+                return self.index_error(
+                    bracket,
+                    format!("Cannot index into a value of type {}.", other.type_name()),
+                );
+            }
+        };
+        value.unwrap_or_else(ArtValue::none)
+    }
+
+    pub(super) fn eval_slice(
+        &mut self,
+        object: Expr,
+        start: Option<Expr>,
+        end: Option<Expr>,
+        bracket: Token,
+    ) -> Result<ArtValue> {
+        let container = self.evaluate(object)?;
+        let start = match start {
+            Some(e) => Some(self.evaluate(e)?),
+            None => None,
+        };
+        let end = match end {
+            Some(e) => Some(self.evaluate(e)?),
+            None => None,
+        };
+        let container = self.resolve_composite(&container).clone();
+        let len = match &container {
+            ArtValue::Array(items) | ArtValue::Tuple(items) => items.len(),
+            ArtValue::String(s) => s.chars().count(),
+            ArtValue::Buffer(bytes) => bytes.len(),
+            ArtValue::Deque(d) => d.0.lock().unwrap_or_else(|e| e.into_inner()).len(),
+            other => {
+                return Ok(self.index_error(
+                    &bracket,
+                    format!("Cannot slice a value of type {}.", other.type_name()),
+                ));
+            }
+        };
+        let mut bound = |v: Option<ArtValue>, default: usize| -> Option<usize> {
+            let Some(v) = v else {
+                return Some(default);
+            };
+            let i = self.int_index(&bracket, &v)?;
+            let pos = normalize_bound(i, len);
+            if pos.is_none() {
+                self.index_error(
+                    &bracket,
+                    format!("Slice bound {} out of range for length {}.", i, len),
+                );
+            }
+            pos
+        };
+        let (Some(from), Some(to)) = (bound(start, 0), bound(end, len)) else {
+            return Ok(ArtValue::none());
+        };
+        if from > to {
+            return Ok(self.index_error(
+                &bracket,
+                format!("Slice start {} is greater than end {}.", from, to),
+            ));
+        }
+        Ok(match container {
+            ArtValue::Array(items) => {
+                self.heapify_composite(ArtValue::Array(items[from..to].to_vec()))
+            }
+            ArtValue::Tuple(items) => ArtValue::Tuple(items[from..to].to_vec()),
+            ArtValue::String(s) => {
+                let sliced: String = s.chars().skip(from).take(to - from).collect();
+                ArtValue::String(core::intern_arc(&sliced))
+            }
+            ArtValue::Buffer(bytes) => ArtValue::Buffer(bytes[from..to].into()),
+            ArtValue::Deque(d) => {
+                let items = d.0.lock().unwrap_or_else(|e| e.into_inner());
+                let sliced = items.range(from..to).cloned().collect();
+                ArtValue::Deque(core::ast::DequeRef(std::sync::Arc::new(
+                    std::sync::Mutex::new(sliced),
+                )))
+            }
+            _ => unreachable!("tipos não fatiáveis retornam acima"),
+        })
+    }
+
+    /// `object[index] = value`. Arrays no heap, Maps e Deques são mutados no
+    /// lugar; Buffers são imutáveis por valor (`Arc<[u8]>`), então a cópia
+    /// alterada é reatribuída ao próprio `object`.
+    pub(super) fn assign_index(
+        &mut self,
+        object: Expr,
+        index: Expr,
+        bracket: Token,
+        value: ArtValue,
+    ) -> Result<()> {
+        let container = self.evaluate(object.clone())?;
+        let index = self.evaluate(index)?;
+        self.store_index(object, &container, &index, bracket, value)
+    }
+
+    /// Escrita de `assign_index` com o container e o índice já avaliados, para
+    /// que `a[i] op= v` avalie cada um uma única vez. `object` só é reavaliado
+    /// como alvo ao reatribuir um Buffer.
+    pub(super) fn store_index(
+        &mut self,
+        object: Expr,
+        container: &ArtValue,
+        index: &ArtValue,
+        bracket: Token,
+        mut value: ArtValue,
+    ) -> Result<()> {
+        match container {
+            ArtValue::HeapComposite(h) => {
+                let h = *h;
+                let len = match self.heap_objects.get(&h.0).map(|o| &o.value) {
+                    Some(ArtValue::Array(items)) => items.len(),
+                    Some(other) => {
+                        let msg = format!("Cannot assign by index into {}.", other.type_name());
+                        self.index_error(&bracket, msg);
+                        return Ok(());
+                    }
+                    None => return Ok(()),
+                };
+                let Some(pos) = self.position(&bracket, index, len) else {
+                    return Ok(());
+                };
+                let owner_arena = self.heap_objects.get(&h.0).and_then(|o| o.arena_id);
+                self.promote_if_escaping(owner_arena, &mut value);
+                let stored = value.clone();
+                let old = match self.heap_objects.get_mut(&h.0).map(|o| &mut o.value) {
+                    Some(ArtValue::Array(items)) => std::mem::replace(&mut items[pos], value),
+                    _ => return Ok(()),
+                };
+                // O array dono mantém uma referência forte para cada elemento.
+                if !Self::same_heap_handle(&old, &stored) {
+                    if let ArtValue::HeapComposite(nh) = &stored {
+                        self.inc_heap_strong(nh.0);
+                    }
+                    self.dec_value_if_heap(&old);
+                }
+            }
+            ArtValue::Deque(d) => {
+                let len = d.0.lock().unwrap_or_else(|e| e.into_inner()).len();
+                if let Some(pos) = self.position(&bracket, index, len) {
+                    d.0.lock().unwrap_or_else(|e| e.into_inner())[pos] = value;
+                }
+            }
+            ArtValue::Map(m) => match self.map_key(index) {
+                Some(key) => {
+                    m.0.lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .insert(key, value);
+                }
+                None => {
+                    let msg = self.unhashable_key_message(index);
+                    self.index_error(&bracket, msg);
+                }
+            },
+            ArtValue::Buffer(bytes) => {
+                let Some(pos) = self.position(&bracket, index, bytes.len()) else {
+                    return Ok(());
+                };
+                let byte = match value {
+                    ArtValue::Int(b @ 0..=255) => b as u8,
+                    other => {
+                        let msg = format!("Buffer elements must be Int in 0..=255, got {}.", other);
+                        self.index_error(&bracket, msg);
+                        return Ok(());
+                    }
+                };
+                let mut updated = bytes.to_vec();
+                updated[pos] = byte;
+                let eq = Token {
+                    token_type: core::TokenType::Equal,
+                    lexeme: "=".to_string(),
+                    ..bracket
+                };
+                let new_buffer = Expr::Literal(ArtValue::Buffer(updated.into()));
+                return self.execute_assign(object, eq, new_buffer);
+            }
+            ArtValue::String(_) | ArtValue::Tuple(_) => {
+                let msg = format!(
+                    "Cannot assign by index: {} values are immutable.",
+                    container.type_name()
+                );
+                self.index_error(&bracket, msg);
+            }
+            other => {
+                let msg = format!("Cannot index into a value of type {}.", other.type_name());
+                self.index_error(&bracket, msg);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,280 @@
+//! Intervalos (`a..b`, `a..=b`) e o protocolo geral de iteração do `for`.
+//!
+//! Um intervalo avalia para um `__Range` preguiçoso (só guarda os limites);
+//! o `for` o percorre sem materializar um array. Map produz tuplas
+//! `(chave, valor)` ordenadas pela chave, Set segue a ordem de inserção,
+//! Deque vai da frente para trás, String produz caracteres e Buffer, bytes.
+//! Streams, generators e o protocolo `next()` são puxados um item por vez
+//! (`ForSource`).
+
+use super::Interpreter;
+use crate::values::Result;
+use core::Token;
+use core::ast::{ArtValue, Expr, MapKey};
+use diagnostics::{Diagnostic, DiagnosticKind, Span};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub(super) type ValueIter = Box<dyn Iterator<Item = ArtValue>>;
+
+/// De onde um `for` (ou um stream) tira o próximo item.
+pub(super) enum ForSource {
+    /// Coleções e intervalos: os itens já existem.
+    Values(ValueIter),
+    /// Generator, callable sem argumentos ou struct com `next()`, chamado a cada item.
+    Next(ArtValue),
+    /// Stream: cada item da fonte passa pelos `map`/`filter` no momento em que é puxado.
+    Stream {
+        source: Box<ForSource>,
+        ops: Vec<ArtValue>,
+    },
+}
+
+impl Interpreter {
+    pub(super) fn eval_range(
+        &mut self,
+        start: Expr,
+        end: Expr,
+        inclusive: bool,
+        operator: Token,
+    ) -> Result<ArtValue> {
+        let start = self.evaluate(start)?;
+        let end = self.evaluate(end)?;
+        let (ArtValue::Int(s), ArtValue::Int(e)) = (&start, &end) else {
+            self.diagnostics.push(Diagnostic::new(
+                DiagnosticKind::Runtime,
+                format!(
+                    "Range bounds must be Int, found {} and {}.",
+                    start.type_name(),
+                    end.type_name()
+                ),
+                Span::new(operator.start, operator.end, operator.line, operator.col),
+            ));
+            return Ok(ArtValue::none());
+        };
+        let mut fields = HashMap::new();
+        fields.insert("start".to_string(), ArtValue::Int(*s));
+        fields.insert("end".to_string(), ArtValue::Int(*e));
+        fields.insert("inclusive".to_string(), ArtValue::Bool(inclusive));
+        Ok(ArtValue::StructInstance {
+            struct_name: "__Range".to_string(),
+            fields,
+        })
+    }
+
+    /// Valores produzidos por `for x in value`, ou `None` se `value` não é
+    /// uma coleção (streams e o protocolo `next()` são tratados à parte).
+    pub(super) fn iterable_values(&self, value: &ArtValue) -> Option<ValueIter> {
+        match self.resolve_composite(value) {
+            ArtValue::Array(items) => Some(Box::new(items.clone().into_iter())),
+            ArtValue::StructInstance {
+                struct_name,
+                fields,
+            } if struct_name == "__Range" => {
+                let bound = |name: &str| match fields.get(name) {
+                    Some(ArtValue::Int(n)) => *n,
+                    _ => 0,
+                };
+                let (s, e) = (bound("start"), bound("end"));
+                if matches!(fields.get("inclusive"), Some(ArtValue::Bool(true))) {
+                    Some(Box::new((s..=e).map(ArtValue::Int)))
+                } else {
+                    Some(Box::new((s..e).map(ArtValue::Int)))
+                }
+            }
+            ArtValue::Map(m) => Some(Box::new(
+                m.sorted_entries()
+                    .into_iter()
+                    .map(|(k, v)| ArtValue::Tuple(vec![k.to_value(), v])),
+            )),
+            ArtValue::Set(s) => {
+                let items: Vec<ArtValue> =
+                    s.0.lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .iter()
+                        .map(MapKey::to_value)
+                        .collect();
+                Some(Box::new(items.into_iter()))
+            }
+            ArtValue::Deque(d) => {
+                let items: Vec<ArtValue> =
+                    d.0.lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .iter()
+                        .cloned()
+                        .collect();
+                Some(Box::new(items.into_iter()))
+            }
+            ArtValue::String(s) => {
+                let chars: Vec<ArtValue> = s
+                    .chars()
+                    .map(|c| ArtValue::String(Arc::from(c.to_string().as_str())))
+                    .collect();
+                Some(Box::new(chars.into_iter()))
+            }
+            ArtValue::Buffer(bytes) => {
+                let bytes: Vec<ArtValue> = bytes.iter().map(|b| ArtValue::Int(*b as i64)).collect();
+                Some(Box::new(bytes.into_iter()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Fonte de `for x in value`; `None` (com diagnóstico) se `value` não é iterável.
+    pub(super) fn for_source(&mut self, value: &ArtValue, span: Span) -> Result<Option<ForSource>> {
+        if let Some(values) = self.iterable_values(value) {
+            return Ok(Some(ForSource::Values(values)));
+        }
+        if let ArtValue::HeapComposite(h) = value
+            && !self.heap_objects.contains_key(&h.0)
+        {
+            self.diagnostics.push(Diagnostic::new(
+                DiagnosticKind::Runtime,
+                "Cannot iterate over dangling heap handle.".to_string(),
+                span,
+            ));
+            return Ok(None);
+        }
+        let resolved = self.resolve_composite(value).clone();
+        match resolved {
+            ArtValue::StructInstance {
+                ref struct_name, ..
+            } if struct_name == "__Stream" => match self.decode_stream_value(resolved) {
+                Ok((source, ops)) => Ok(Some(self.stream_source(source, ops))),
+                Err(msg) => {
+                    self.diagnostics
+                        .push(Diagnostic::new(DiagnosticKind::Runtime, msg, span));
+                    Ok(None)
+                }
+            },
+            _ if self.generator_id(value).is_some() => Ok(Some(ForSource::Next(value.clone()))),
+            // `for x in gen` com `gen` sendo a própria função generator: inicia uma execução.
+            ArtValue::Function(func) if func.params.is_empty() && func.body.contains_yield() => {
+                let generator = self.call_function(func, None, Vec::new())?;
+                Ok(Some(ForSource::Next(generator)))
+            }
+            _ if matches!(
+                value,
+                ArtValue::Function(_) | ArtValue::Builtin(_) | ArtValue::HeapComposite(_)
+            ) =>
+            {
+                Ok(Some(ForSource::Next(value.clone())))
+            }
+            _ => {
+                self.diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Runtime,
+                    format!("Cannot iterate over unsupported type: {:?}", value),
+                    span,
+                ));
+                Ok(None)
+            }
+        }
+    }
+
+    /// Stream sobre `source` (array ou generator) com as operações `ops`.
+    pub(super) fn stream_source(&self, source: ArtValue, ops: Vec<ArtValue>) -> ForSource {
+        let source = match source {
+            ArtValue::Array(items) => ForSource::Values(Box::new(items.into_iter())),
+            generator => ForSource::Next(generator),
+        };
+        ForSource::Stream {
+            source: Box::new(source),
+            ops,
+        }
+    }
+
+    /// Próximo item de `source`, ou `None` quando ela se esgota.
+    pub(super) fn for_source_next(
+        &mut self,
+        source: &mut ForSource,
+        span: Span,
+    ) -> Result<Option<ArtValue>> {
+        match source {
+            ForSource::Values(values) => Ok(values.next()),
+            ForSource::Next(iterator) => {
+                let iterator = iterator.clone();
+                self.iterator_next(&iterator, span)
+            }
+            ForSource::Stream { source, ops } => loop {
+                let Some(item) = self.for_source_next(source, span)? else {
+                    return Ok(None);
+                };
+                if let Some(item) = self.apply_stream_ops(ops, item)? {
+                    return Ok(Some(item));
+                }
+            },
+        }
+    }
+
+    /// Protocolo de iteração: chama `next()` e desembrulha o `Option` devolvido.
+    fn iterator_next(&mut self, iterator: &ArtValue, span: Span) -> Result<Option<ArtValue>> {
+        if let Some(id) = self.generator_id(iterator) {
+            return self.resume_generator(id);
+        }
+        let next_val = match iterator {
+            ArtValue::Function(func) => self.call_function(func.clone(), None, Vec::new())?,
+            ArtValue::Builtin(b) => self.call_builtin(b.clone(), Vec::new())?,
+            _ => {
+                let resolved = self.resolve_composite(iterator).clone();
+                let ArtValue::StructInstance {
+                    struct_name,
+                    fields,
+                } = resolved
+                else {
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::Runtime,
+                        format!("Cannot iterate over unsupported type: {:?}", iterator),
+                        span,
+                    ));
+                    return Ok(None);
+                };
+                let token = Token::dummy("next");
+                match crate::field_access::struct_field_or_method(
+                    &struct_name,
+                    &fields,
+                    &token,
+                    &self.type_registry,
+                    iterator,
+                ) {
+                    Some(ArtValue::Function(func)) => self.call_function(func, None, Vec::new())?,
+                    Some(ArtValue::Builtin(b)) => self.call_builtin(b, Vec::new())?,
+                    Some(other) => {
+                        self.diagnostics.push(Diagnostic::new(
+                            DiagnosticKind::Runtime,
+                            format!("Iterator 'next' must be callable, got: {:?}", other),
+                            span,
+                        ));
+                        return Ok(None);
+                    }
+                    None => {
+                        self.diagnostics.push(Diagnostic::new(
+                            DiagnosticKind::Runtime,
+                            "Iterator object does not implement 'next()' method.".to_string(),
+                            span,
+                        ));
+                        return Ok(None);
+                    }
+                }
+            }
+        };
+        match self.resolve_composite(&next_val).clone() {
+            ArtValue::Optional(boxed) => Ok(*boxed),
+            ArtValue::EnumInstance {
+                enum_name,
+                variant,
+                values,
+            } if enum_name == "Option" => {
+                Ok((variant == "Some")
+                    .then(|| values.into_iter().next().unwrap_or(ArtValue::none())))
+            }
+            other => {
+                self.diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Runtime,
+                    format!("Iterator protocol expected Optional, got: {:?}", other),
+                    span,
+                ));
+                Ok(None)
+            }
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Aritmética de `Int` (`+ - * / %`), operadores de bits (`&`, `|`, `^`,
+//! `<<`, `>>`, `~`) e os builtins `wrapping_*`, `checked_*`, `saturating_*`.
+//!
+//! `Int op Int` é calculado em `i64` exato, sem passar por `f64`: `/` trunca
+//! em direção a zero e `%` tem o sinal do dividendo. Um resultado fora de
+//! `i64` segue a política de overflow (`--overflow=wrap|trap`): em `trap`
+//! (padrão) vira diagnóstico `Runtime`, em `wrap` dá a volta em complemento
+//! de dois. Operadores de bits só aceitam `Int`; `>>` é aritmético (propaga
+//! o sinal). Divisor zero, shift fora de `0..=63` e operandos inválidos
+//! emitem diagnóstico `Runtime` no operador e avaliam para `none`.
+
+use super::Interpreter;
+use crate::values::Result;
+use core::ast::{ArtValue, Expr};
+use core::{OverflowMode, Token, TokenType};
+use diagnostics::{Diagnostic, DiagnosticKind, Span};
+
+impl Interpreter {
+    fn operator_error(&mut self, operator: &Token, message: String) -> ArtValue {
+        self.diagnostics.push(Diagnostic::new(
+            DiagnosticKind::Runtime,
+            message,
+            Span::new(operator.start, operator.end, operator.line, operator.col),
+        ));
+        ArtValue::none()
+    }
+
+    /// Resultado de uma operação Int que estourou, conforme a política de overflow.
+    pub(super) fn int_overflow(&mut self, span: Span, wrapped: i64, expr: String) -> ArtValue {
+        match self.overflow {
+            OverflowMode::Wrap => ArtValue::Int(wrapped),
+            OverflowMode::Trap => {
+                self.diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Runtime,
+                    format!("Integer overflow: {} does not fit in Int.", expr),
+                    span,
+                ));
+                ArtValue::none()
+            }
+        }
+    }
+
+    /// `+`, `-` e `*` entre Int; `None` para os demais operadores.
+    pub(super) fn eval_int_arith(&mut self, operator: &Token, l: i64, r: i64) -> Option<ArtValue> {
+        let (checked, wrapped) = match operator.token_type {
+            TokenType::Plus => (l.checked_add(r), l.wrapping_add(r)),
+            TokenType::Minus => (l.checked_sub(r), l.wrapping_sub(r)),
+            TokenType::Star => (l.checked_mul(r), l.wrapping_mul(r)),
+            _ => return None,
+        };
+        Some(match checked {
+            Some(v) => ArtValue::Int(v),
+            None => {
+                let span = Span::new(operator.start, operator.end, operator.line, operator.col);
+                self.int_overflow(span, wrapped, format!("{} {} {}", l, operator.lexeme, r))
+            }
+        })
+    }
+
+    /// `-x` sobre Int: só `-(menor Int)` estoura.
+    pub(super) fn eval_int_neg(&mut self, operator: &Token, n: i64) -> ArtValue {
+        match n.checked_neg() {
+            Some(v) => ArtValue::Int(v),
+            None => {
+                let span = Span::new(operator.start, operator.end, operator.line, operator.col);
+                self.int_overflow(span, n.wrapping_neg(), format!("-({})", n))
+            }
+        }
+    }
+
+    /// `wrapping_*`, `checked_*` (devolve `Option`) e `saturating_*` sobre dois Int.
+    pub(super) fn call_int_builtin(
+        &mut self,
+        name: &str,
+        arguments: Vec<Expr>,
+    ) -> Result<ArtValue> {
+        let mut values = Vec::with_capacity(arguments.len());
+        for arg in arguments {
+            values.push(self.evaluate(arg)?);
+        }
+        let (l, r) = match values.as_slice() {
+            [ArtValue::Int(l), ArtValue::Int(r)] => (*l, *r),
+            _ => {
+                self.diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Runtime,
+                    format!("{} expects two Int arguments.", name),
+                    self.call_span,
+                ));
+                return Ok(ArtValue::none());
+            }
+        };
+        let (policy, op) = name.split_once('_').unwrap_or((name, ""));
+        let (checked, wrapped, saturated) = match op {
+            "add" => (l.checked_add(r), l.wrapping_add(r), l.saturating_add(r)),
+            "sub" => (l.checked_sub(r), l.wrapping_sub(r), l.saturating_sub(r)),
+            _ => (l.checked_mul(r), l.wrapping_mul(r), l.saturating_mul(r)),
+        };
+        Ok(match policy {
+            "wrapping" => ArtValue::Int(wrapped),
+            "saturating" => ArtValue::Int(saturated),
+            _ => {
+                let (variant, values) = match checked {
+                    Some(v) => ("Some", vec![ArtValue::Int(v)]),
+                    None => ("None", Vec::new()),
+                };
+                ArtValue::EnumInstance {
+                    enum_name: "Option".to_string(),
+                    variant: variant.to_string(),
+                    values,
+                }
+            }
+        })
+    }
+
+    /// `/` e `%` sobre Int/Float.
+    pub(super) fn eval_division(
+        &mut self,
+        operator: &Token,
+        left: ArtValue,
+        right: ArtValue,
+    ) -> ArtValue {
+        let div_by_zero =
+            matches!(right, ArtValue::Int(0)) || matches!(right, ArtValue::Float(f) if f == 0.0);
+        if div_by_zero {
+            return self.operator_error(operator, "Division by zero".to_string());
+        }
+        let rem = operator.token_type == TokenType::Percent;
+        let (l, r) = match (&left, &right) {
+            // `menor Int % -1` é 0; só `menor Int / -1` estoura.
+            (ArtValue::Int(l), ArtValue::Int(r)) if rem => {
+                return ArtValue::Int(l.wrapping_rem(*r));
+            }
+            (ArtValue::Int(l), ArtValue::Int(r)) => {
+                return match l.checked_div(*r) {
+                    Some(v) => ArtValue::Int(v),
+                    None => {
+                        let span =
+                            Span::new(operator.start, operator.end, operator.line, operator.col);
+                        self.int_overflow(span, l.wrapping_div(*r), format!("{} / {}", l, r))
+                    }
+                };
+            }
+            (ArtValue::Float(l), ArtValue::Float(r)) => (*l, *r),
+            (ArtValue::Int(l), ArtValue::Float(r)) => (*l as f64, *r),
+            (ArtValue::Float(l), ArtValue::Int(r)) => (*l, *r as f64),
+            _ => return self.operator_error(operator, "Type mismatch.".to_string()),
+        };
+        ArtValue::Float(if rem { l % r } else { l / r })
+    }
+
+    /// `&`, `|`, `^`, `<<` e `>>` sobre Int.
+    pub(super) fn eval_bitwise(
+        &mut self,
+        operator: &Token,
+        left: ArtValue,
+        right: ArtValue,
+    ) -> ArtValue {
+        let (l, r) = match (&left, &right) {
+            (ArtValue::Int(l), ArtValue::Int(r)) => (*l, *r),
+            _ => {
+                let message = format!(
+                    "Operator '{}' requires Int operands, got {} and {}.",
+                    operator.lexeme,
+                    left.type_name(),
+                    right.type_name()
+                );
+                return self.operator_error(operator, message);
+            }
+        };
+        let shift = matches!(
+            operator.token_type,
+            TokenType::LessLess | TokenType::GreaterGreater
+        );
+        if shift && !(0..64).contains(&r) {
+            let message = format!("Shift amount {} out of range 0..=63.", r);
+            return self.operator_error(operator, message);
+        }
+        ArtValue::Int(match operator.token_type {
+            TokenType::Ampersand => l & r,
+            TokenType::Pipe => l | r,
+            TokenType::Caret => l ^ r,
+            TokenType::LessLess => l.wrapping_shl(r as u32),
+            _ => l >> r,
+        })
+    }
+
+    /// `~x`: complemento bit a bit.
+    pub(super) fn eval_bit_not(&mut self, operator: &Token, value: ArtValue) -> ArtValue {
+        match value {
+            ArtValue::Int(n) => ArtValue::Int(!n),
+            other => {
+                let message = format!(
+                    "Operator '~' requires an Int operand, got {}.",
+                    other.type_name()
+                );
+                self.operator_error(operator, message)
+            }
+        }
+    }
+}
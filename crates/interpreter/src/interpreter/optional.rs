@@ -0,0 +1,122 @@
+//! Encadeamento opcional (`a?.b`, `a?.m(x)`) e coalescência (`a ?? b`).
+//!
+//! Ausente é `none` (`Optional(None)`) ou `Option.None`. Sobre um valor
+//! presente, `?.` acessa o campo (ou chama o método) no valor desembrulhado e
+//! devolve o resultado no mesmo tipo de opcional do receptor, sem aninhar: um
+//! campo que já é opcional volta como está. Receptor não opcional se comporta
+//! como `.`, e um `WeakRef` é promovido antes, como em `w?`.
+
+use super::Interpreter;
+use crate::values::Result;
+use core::Token;
+use core::ast::{ArtValue, Expr};
+
+/// Como o receptor representava a opcionalidade.
+#[derive(Clone, Copy)]
+enum OptionalKind {
+    /// `none` / `Optional(..)` interno.
+    Optional,
+    /// `Option.Some(..)` / `Option.None`.
+    OptionEnum,
+    /// Valor comum, sempre presente.
+    Plain,
+}
+
+impl Interpreter {
+    pub(super) fn eval_optional_chain(
+        &mut self,
+        object: Expr,
+        field: Token,
+        arguments: Option<Vec<Expr>>,
+    ) -> Result<ArtValue> {
+        let value = self.evaluate(object)?;
+        let (kind, inner) = self.split_optional(value)?;
+        let Some(inner) = inner else {
+            return Ok(absent(kind));
+        };
+        let access = Expr::FieldAccess {
+            object: Box::new(Expr::Literal(inner)),
+            field,
+        };
+        let result = match arguments {
+            Some(arguments) => self.evaluate(Expr::Call {
+                callee: Box::new(access),
+                type_args: None,
+                arguments,
+            })?,
+            None => self.evaluate(access)?,
+        };
+        if self.is_optional(&result) {
+            return Ok(result);
+        }
+        Ok(match kind {
+            OptionalKind::Optional => ArtValue::Optional(Box::new(Some(result))),
+            OptionalKind::OptionEnum => option_enum(Some(result)),
+            OptionalKind::Plain => result,
+        })
+    }
+
+    pub(super) fn eval_coalesce(&mut self, left: Expr, right: Expr) -> Result<ArtValue> {
+        let value = self.evaluate(left)?;
+        match self.split_optional(value)? {
+            (_, Some(inner)) => Ok(inner),
+            (_, None) => self.evaluate(right),
+        }
+    }
+
+    /// Separa o valor do seu invólucro opcional; `None` quando ausente.
+    fn split_optional(&mut self, value: ArtValue) -> Result<(OptionalKind, Option<ArtValue>)> {
+        let value = if matches!(value, ArtValue::WeakRef(_)) {
+            self.evaluate(Expr::Call {
+                callee: Box::new(Expr::Variable {
+                    name: Token::dummy("weak_get"),
+                }),
+                type_args: None,
+                arguments: vec![Expr::Literal(value)],
+            })?
+        } else {
+            value
+        };
+        Ok(match self.resolve_composite(&value) {
+            ArtValue::Optional(inner) => (OptionalKind::Optional, (**inner).clone()),
+            ArtValue::EnumInstance {
+                enum_name,
+                variant,
+                values,
+            } if enum_name == "Option" => {
+                let inner = (variant == "Some")
+                    .then(|| values.first().cloned())
+                    .flatten();
+                (OptionalKind::OptionEnum, inner)
+            }
+            _ => (OptionalKind::Plain, Some(value)),
+        })
+    }
+
+    fn is_optional(&self, value: &ArtValue) -> bool {
+        match self.resolve_composite(value) {
+            ArtValue::Optional(_) => true,
+            ArtValue::EnumInstance { enum_name, .. } => enum_name == "Option",
+            _ => false,
+        }
+    }
+}
+
+fn absent(kind: OptionalKind) -> ArtValue {
+    match kind {
+        OptionalKind::OptionEnum => option_enum(None),
+        OptionalKind::Optional | OptionalKind::Plain => ArtValue::none(),
+    }
+}
+
+fn option_enum(value: Option<ArtValue>) -> ArtValue {
+    let (variant, values) = match value {
+        Some(v) => ("Some", vec![v]),
+        None => ("None", Vec::new()),
+    };
+    ArtValue::EnumInstance {
+        enum_name: "Option".to_string(),
+        variant: variant.to_string(),
+        values,
+    }
+}
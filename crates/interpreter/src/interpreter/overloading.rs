@@ -0,0 +1,112 @@
+//! Sobrecarga de operadores (ver `core::operators`).
+//!
+//! Só o operando da esquerda decide: `a + b` chama `a.add(b)` quando `a` é
+//! instância de um tipo que define `add`; `2 * v` continua sendo aritmética
+//! comum. Sem o método, o operador segue o caminho de sempre — `==` entre
+//! instâncias sem `eq` compara identidade.
+
+use super::Interpreter;
+use crate::field_access::{enum_method, struct_field_or_method};
+use crate::values::Result;
+use core::ast::ArtValue;
+use core::operators::{NEG_METHOD, TO_STRING_METHOD, binary_method};
+use core::{Token, TokenType};
+use diagnostics::{Diagnostic, DiagnosticKind, Span};
+use std::collections::HashMap;
+
+impl Interpreter {
+    /// Método `name` ligado a `value`, se `value` for instância de struct ou
+    /// enum que o define (no próprio `impl` ou como default de trait). Campos
+    /// com o mesmo nome não contam.
+    pub(super) fn operator_method(&self, value: &ArtValue, name: &str) -> Option<ArtValue> {
+        let token = Token::dummy(name);
+        match self.resolve_composite(value) {
+            ArtValue::StructInstance { struct_name, .. } => struct_field_or_method(
+                struct_name,
+                &HashMap::new(),
+                &token,
+                &self.type_registry,
+                value,
+            ),
+            ArtValue::EnumInstance {
+                enum_name,
+                variant,
+                values,
+            } => enum_method(enum_name, variant, values, &token, &self.type_registry),
+            _ => None,
+        }
+    }
+
+    /// `a op b` sobrecarregado; `None` quando `a` não define o método.
+    pub(super) fn eval_overloaded_binary(
+        &mut self,
+        operator: &Token,
+        left: &ArtValue,
+        right: &ArtValue,
+    ) -> Result<Option<ArtValue>> {
+        let Some(name) = binary_method(&operator.token_type) else {
+            return Ok(None);
+        };
+        let Some(method) = self.operator_method(left, name) else {
+            return Ok(None);
+        };
+        let result = self.invoke_callable_with_values(method, vec![right.clone()])?;
+        let value = match (&operator.token_type, result) {
+            (TokenType::EqualEqual, ArtValue::Bool(b)) => ArtValue::Bool(b),
+            (TokenType::BangEqual, ArtValue::Bool(b)) => ArtValue::Bool(!b),
+            (TokenType::EqualEqual | TokenType::BangEqual, other) => {
+                self.overload_error(operator, "eq", "Bool", &other)
+            }
+            (TokenType::Less, ArtValue::Int(n)) => ArtValue::Bool(n < 0),
+            (TokenType::LessEqual, ArtValue::Int(n)) => ArtValue::Bool(n <= 0),
+            (TokenType::Greater, ArtValue::Int(n)) => ArtValue::Bool(n > 0),
+            (TokenType::GreaterEqual, ArtValue::Int(n)) => ArtValue::Bool(n >= 0),
+            (
+                TokenType::Less
+                | TokenType::LessEqual
+                | TokenType::Greater
+                | TokenType::GreaterEqual,
+                other,
+            ) => self.overload_error(operator, "cmp", "Int", &other),
+            (_, other) => other,
+        };
+        Ok(Some(value))
+    }
+
+    /// `-a` sobrecarregado; `None` quando `a` não define `neg`.
+    pub(super) fn eval_overloaded_neg(&mut self, value: &ArtValue) -> Result<Option<ArtValue>> {
+        match self.operator_method(value, NEG_METHOD) {
+            Some(method) => self
+                .invoke_callable_with_values(method, Vec::new())
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Valor exibido numa interpolação: o resultado de `to_string()` quando a
+    /// instância o define, senão o próprio valor.
+    pub(super) fn display_value(&mut self, value: ArtValue) -> Result<ArtValue> {
+        match self.operator_method(&value, TO_STRING_METHOD) {
+            Some(method) => self.invoke_callable_with_values(method, Vec::new()),
+            None => Ok(value),
+        }
+    }
+
+    fn overload_error(
+        &mut self,
+        operator: &Token,
+        method: &str,
+        expected: &str,
+        found: &ArtValue,
+    ) -> ArtValue {
+        self.diagnostics.push(Diagnostic::new(
+            DiagnosticKind::Runtime,
+            format!(
+                "Operator '{}' expects '{}' to return {}, got {}.",
+                operator.lexeme, method, expected, found
+            ),
+            Span::new(operator.start, operator.end, operator.line, operator.col),
+        ));
+        ArtValue::none()
+    }
+}
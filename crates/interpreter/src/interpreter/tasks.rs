@@ -0,0 +1,298 @@
+//! Tasks: o lado `async` do scheduler cooperativo de `actors.rs`.
+//!
+//! Chamar uma `async func` não executa o corpo: cria uma task (objeto `__Task`)
+//! com o corpo numa `Coroutine` (ver `generators.rs`). `sleep(ms)` e
+//! `actor_ask(ator, msg)` criam tasks que só esperam: um prazo ou a resposta do
+//! ator. `run_scheduler` alterna atores e tasks; uma task parada num `await` só
+//! volta a rodar quando o que ela espera termina, sem bloquear as demais.
+//!
+//! Fora de uma `async func` (no topo do arquivo), `await` bloqueia: roda o
+//! scheduler até a task esperada terminar.
+
+use super::Interpreter;
+use super::actors::{ActorState, Mailbox};
+use super::generators::{Coroutine, Suspend};
+use crate::values::{Result, RuntimeError};
+use core::Token;
+use core::ast::{ArtValue, Function, ValueEnvelope};
+use core::environment::Environment;
+use diagnostics::{Diagnostic, DiagnosticKind, Span};
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+pub(super) const TASK_STRUCT: &str = "__Task";
+
+pub struct TaskState {
+    kind: TaskKind,
+    /// Preenchido quando a task termina: o valor ou a mensagem do erro.
+    outcome: Option<std::result::Result<ArtValue, String>>,
+}
+
+enum TaskKind {
+    /// Corpo de uma `async func`. `body` fica vazio enquanto ele roda; `waiting` é a
+    /// task em cujo `await` ele parou e `ready` o valor de um `await` já resolvido.
+    Coroutine {
+        body: Option<Box<Coroutine>>,
+        waiting: Option<u64>,
+        ready: Option<ArtValue>,
+    },
+    /// `sleep(ms)`.
+    Timer { deadline: Instant },
+    /// `actor_ask`: a resposta chega na caixa do ator oculto `inbox`.
+    Reply { inbox: u32 },
+}
+
+impl Interpreter {
+    /// Cria a task de uma chamada à `async func`; `env` já tem os parâmetros ligados.
+    pub(super) fn new_async_task(
+        &mut self,
+        func: &Function,
+        env: Rc<RefCell<Environment>>,
+    ) -> ArtValue {
+        let kind = TaskKind::Coroutine {
+            body: Some(Box::new(Coroutine::new(func, env))),
+            waiting: None,
+            ready: None,
+        };
+        self.new_task(func.name.as_deref().unwrap_or("<anonymous>"), kind)
+    }
+
+    /// `sleep(ms)`: task que termina (com `none`) depois de `ms` milissegundos.
+    pub(super) fn new_timer(&mut self, ms: u64) -> ArtValue {
+        let deadline = Instant::now() + Duration::from_millis(ms);
+        self.new_task("sleep", TaskKind::Timer { deadline })
+    }
+
+    /// `actor_ask(ator, msg)`: envia `msg` com um ator oculto como remetente e
+    /// devolve a task que termina com o primeiro valor enviado a ele.
+    pub(super) fn ask_actor(&mut self, aid: u32, payload: ArtValue, priority: i32) -> ArtValue {
+        let inbox = self.next_actor_id;
+        self.next_actor_id += 1;
+        // Estacionado e sem corpo: o scheduler nunca o executa nem o encerra.
+        let actor = ActorState {
+            id: inbox,
+            mailbox: Mailbox::new(),
+            body: VecDeque::new(),
+            env: self.environment.clone(),
+            finished: false,
+            parked: true,
+            mailbox_limit: 1,
+        };
+        self.actors.insert(inbox, actor);
+        let envelope = ValueEnvelope {
+            sender: Some(inbox),
+            payload,
+            priority,
+        };
+        let message = match self.deliver_to_actor(aid, envelope) {
+            Some(true) => return self.new_task("actor_ask", TaskKind::Reply { inbox }),
+            Some(false) => format!("actor_ask: mailbox of actor {} is full", aid),
+            None => format!("actor_ask: unknown actor id {}", aid),
+        };
+        self.actors.remove(&inbox);
+        self.diagnostics.push(Diagnostic::new(
+            DiagnosticKind::Runtime,
+            message,
+            self.call_span,
+        ));
+        ArtValue::none()
+    }
+
+    fn new_task(&mut self, name: &str, kind: TaskKind) -> ArtValue {
+        let id = self.next_task_id;
+        self.next_task_id += 1;
+        self.tasks.insert(
+            id,
+            TaskState {
+                kind,
+                outcome: None,
+            },
+        );
+        let mut fields = HashMap::new();
+        fields.insert("id".to_string(), ArtValue::Int(id as i64));
+        fields.insert("name".to_string(), ArtValue::String(Arc::from(name)));
+        ArtValue::StructInstance {
+            struct_name: TASK_STRUCT.to_string(),
+            fields,
+        }
+    }
+
+    pub(super) fn task_id(&self, value: &ArtValue) -> Option<u64> {
+        match self.resolve_composite(value) {
+            ArtValue::StructInstance {
+                struct_name,
+                fields,
+            } if struct_name == TASK_STRUCT => match fields.get("id") {
+                Some(ArtValue::Int(id)) => Some(*id as u64),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn task_outcome(&self, id: u64) -> Option<Result<ArtValue>> {
+        let outcome = self.tasks.get(&id)?.outcome.clone()?;
+        Some(outcome.map_err(RuntimeError::TypeError))
+    }
+
+    /// `await` fora de uma `async func`: roda o scheduler até a task terminar.
+    /// Um valor que não é task é devolvido como está.
+    pub(super) fn block_on(&mut self, value: ArtValue, keyword: &Token) -> Result<ArtValue> {
+        let Some(id) = self.task_id(&value) else {
+            return Ok(value);
+        };
+        self.run_scheduler(usize::MAX, Some(id));
+        match self.task_outcome(id) {
+            Some(outcome) => outcome,
+            None => {
+                self.diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Runtime,
+                    "'await' on a task that can never finish: every task and actor is blocked."
+                        .to_string(),
+                    Span::new(keyword.start, keyword.end, keyword.line, keyword.col),
+                ));
+                Ok(ArtValue::none())
+            }
+        }
+    }
+
+    /// Alterna atores e tasks até não haver trabalho, até `max_steps` passos ou,
+    /// com `until`, até essa task terminar. Sem nada pronto para rodar, dorme até
+    /// o próximo timer.
+    pub fn run_scheduler(&mut self, max_steps: usize, until: Option<u64>) {
+        let mut steps = 0usize;
+        while steps < max_steps {
+            if until.is_some_and(|id| self.task_outcome(id).is_some()) {
+                return;
+            }
+            // Uma volta pelos atores e uma pelas tasks, para ninguém monopolizar.
+            let rotation = self.actors.len().clamp(1, max_steps - steps);
+            let progressed = self.run_actors_round_robin(rotation) + self.step_tasks();
+            steps = steps.saturating_add(progressed);
+            if progressed == 0 {
+                match self.next_deadline() {
+                    Some(deadline) => {
+                        std::thread::sleep(deadline.saturating_duration_since(Instant::now()))
+                    }
+                    None => return,
+                }
+            }
+        }
+    }
+
+    fn next_deadline(&self) -> Option<Instant> {
+        self.tasks
+            .values()
+            .filter(|t| t.outcome.is_none())
+            .filter_map(|t| match t.kind {
+                TaskKind::Timer { deadline } => Some(deadline),
+                _ => None,
+            })
+            .min()
+    }
+
+    /// Uma passada pelas tasks pendentes; devolve quantas avançaram.
+    fn step_tasks(&mut self) -> usize {
+        let pending: Vec<u64> = self
+            .tasks
+            .iter()
+            .filter(|(_, t)| t.outcome.is_none())
+            .map(|(id, _)| *id)
+            .collect();
+        pending.into_iter().filter(|id| self.step_task(*id)).count()
+    }
+
+    fn step_task(&mut self, id: u64) -> bool {
+        let outcome = match self.tasks.get(&id).map(|t| &t.kind) {
+            Some(TaskKind::Timer { deadline }) if Instant::now() >= *deadline => {
+                Ok(ArtValue::none())
+            }
+            Some(TaskKind::Reply { inbox }) => {
+                let inbox = *inbox;
+                let Some(envelope) = self
+                    .actors
+                    .get_mut(&inbox)
+                    .and_then(|a| a.mailbox.pop_front())
+                else {
+                    return false;
+                };
+                self.actors.remove(&inbox);
+                Ok(envelope.payload)
+            }
+            Some(TaskKind::Coroutine { .. }) => return self.step_coroutine(id),
+            _ => return false,
+        };
+        self.finish_task(id, outcome);
+        true
+    }
+
+    /// Retoma a `async func` da task `id` se o que ela espera já terminou.
+    fn step_coroutine(&mut self, id: u64) -> bool {
+        let waiting = match self.tasks.get(&id).map(|t| &t.kind) {
+            Some(TaskKind::Coroutine {
+                body: Some(_),
+                waiting,
+                ..
+            }) => *waiting,
+            _ => return false,
+        };
+        let awaited = match waiting {
+            Some(other) => match self.task_outcome(other) {
+                Some(outcome) => Some(outcome),
+                None => return false,
+            },
+            None => None,
+        };
+        let Some(TaskKind::Coroutine {
+            body,
+            waiting,
+            ready,
+        }) = self.tasks.get_mut(&id).map(|t| &mut t.kind)
+        else {
+            return false;
+        };
+        let mut coroutine = body.take().expect("checked above");
+        *waiting = None;
+        let input = awaited.or_else(|| ready.take().map(Ok));
+
+        let result = self.resume_coroutine(&mut coroutine, input);
+
+        let outcome = match result {
+            Ok(Suspend::Await(value)) => {
+                let awaited_task = self.task_id(&value);
+                if let Some(TaskKind::Coroutine {
+                    body,
+                    waiting,
+                    ready,
+                }) = self.tasks.get_mut(&id).map(|t| &mut t.kind)
+                {
+                    *body = Some(coroutine);
+                    *waiting = awaited_task;
+                    // Um valor que não é task fica pronto para a próxima passada.
+                    *ready = awaited_task.is_none().then_some(value);
+                }
+                return true;
+            }
+            Ok(Suspend::Done(mut value)) => {
+                // O resultado sobrevive à task: pode ser lido por qualquer `await`.
+                self.promote_if_escaping(None, &mut value);
+                Ok(value)
+            }
+            // O parser rejeita `yield` em `async func`.
+            Ok(Suspend::Yield(_)) => Ok(ArtValue::none()),
+            Err(RuntimeError::TypeError(msg)) => Err(msg),
+            Err(other) => Err(other.to_string()),
+        };
+        self.finish_task(id, outcome);
+        true
+    }
+
+    fn finish_task(&mut self, id: u64, outcome: std::result::Result<ArtValue, String>) {
+        if let Some(task) = self.tasks.get_mut(&id) {
+            task.outcome = Some(outcome);
+        }
+    }
+}
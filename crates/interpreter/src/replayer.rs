@@ -1,5 +1,5 @@
 use crate::interpreter::decode_val;
-use core::ast::ArtValue;
+use core::ast::{ArtValue, MapKey};
 use std::collections::VecDeque;
 use std::fs::File;
 use std::io::Read;
@@ -53,11 +53,11 @@ impl Replayer {
         while let Some(event) = self.events.front() {
             if let ArtValue::Map(mapref) = event {
                 let map = mapref.0.lock().unwrap();
-                let e_type = match map.get("type") {
+                let e_type = match map.get(&MapKey::from("type")) {
                     Some(ArtValue::String(s)) => s.as_ref(),
                     _ => "",
                 };
-                let e_tick = match map.get("tick") {
+                let e_tick = match map.get(&MapKey::from("tick")) {
                     Some(ArtValue::Int(t)) => *t as usize,
                     _ => 0,
                 };
@@ -70,7 +70,10 @@ impl Replayer {
                 }
 
                 if e_tick == current_tick && e_type == expected_type {
-                    let payload = map.get("payload").cloned().unwrap_or_else(ArtValue::none);
+                    let payload = map
+                        .get(&MapKey::from("payload"))
+                        .cloned()
+                        .unwrap_or_else(ArtValue::none);
                     drop(map);
                     self.events.pop_front();
                     return Ok(Some(payload));
@@ -86,17 +89,17 @@ impl Replayer {
         for event in self.events.iter() {
             if let ArtValue::Map(mapref) = event {
                 let map = mapref.0.lock().unwrap();
-                let e_type = match map.get("type") {
+                let e_type = match map.get(&MapKey::from("type")) {
                     Some(ArtValue::String(s)) => s.as_ref(),
                     _ => "",
                 };
-                let e_tick = match map.get("tick") {
+                let e_tick = match map.get(&MapKey::from("tick")) {
                     Some(ArtValue::Int(t)) => *t as usize,
                     _ => 0,
                 };
                 if e_type == "checkpoint"
                     && e_tick <= tick
-                    && let Some(payload) = map.get("payload")
+                    && let Some(payload) = map.get(&MapKey::from("payload"))
                 {
                     candidate = Some((e_tick, payload.clone()));
                 }
@@ -1,5 +1,5 @@
 use crate::interpreter::encode_val;
-use core::ast::{ArtValue, MapRef};
+use core::ast::{ArtValue, MapKey, MapRef};
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
@@ -31,9 +31,9 @@ impl Tracer {
         payload: ArtValue,
     ) -> Result<(), String> {
         let mut map = std::collections::HashMap::new();
-        map.insert("type".to_string(), ArtValue::String(event_type.into()));
-        map.insert("tick".to_string(), ArtValue::Int(tick as i64));
-        map.insert("payload".to_string(), payload);
+        map.insert("type".into(), ArtValue::String(event_type.into()));
+        map.insert("tick".into(), ArtValue::Int(tick as i64));
+        map.insert("payload".into(), payload);
 
         let event = ArtValue::Map(MapRef(Arc::new(std::sync::Mutex::new(map))));
 
@@ -78,9 +78,13 @@ impl Tracer {
 
         let count = changed.len();
         let mut payload_map = HashMap::new();
-        payload_map.insert("tick".to_string(), ArtValue::Int(tick as i64));
+        payload_map.insert("tick".into(), ArtValue::Int(tick as i64));
+        let changed: HashMap<MapKey, ArtValue> = changed
+            .into_iter()
+            .map(|(k, v)| (MapKey::from(k.as_str()), v))
+            .collect();
         payload_map.insert(
-            "changed".to_string(),
+            "changed".into(),
             ArtValue::Map(MapRef(Arc::new(Mutex::new(changed)))),
         );
 
@@ -98,7 +102,7 @@ impl Tracer {
     pub fn record_checkpoint(&mut self, tick: usize, rng_state: u64) -> Result<(), String> {
         let mut payload = std::collections::HashMap::new();
         payload.insert(
-            "rng_state".to_string(),
+            "rng_state".into(),
             ArtValue::String(rng_state.to_string().into()),
         );
 
@@ -1,10 +1,15 @@
 use core::Token;
-use std::collections::HashMap;
+use core::ast::{Expr, StructField};
+use std::collections::{BTreeSet, HashMap};
 
 #[derive(Debug, Clone)]
 pub struct StructDef {
     pub name: String,
     pub fields: Vec<(String, String)>,
+    /// Valores default (`porta: Int = 8080`), avaliados a cada construção.
+    pub defaults: HashMap<String, Expr>,
+    /// Struct tupla (`struct Rgb(Int, Int, Int)`): campos `0`, `1`, ...
+    pub tuple: bool,
     pub methods: std::collections::HashMap<String, core::ast::Function>,
 }
 
@@ -18,7 +23,10 @@ pub struct EnumDef {
 #[derive(Debug, Clone)]
 pub struct TraitDef {
     pub name: String,
+    /// Método -> tipos anotados dos parâmetros (sem `self`).
     pub methods: std::collections::HashMap<String, Vec<String>>,
+    /// Implementações default, copiadas para quem não as sobrescreve.
+    pub defaults: std::collections::HashMap<String, core::ast::Function>,
 }
 
 #[derive(Debug, Clone)]
@@ -26,24 +34,58 @@ pub struct TypeRegistry {
     pub structs: HashMap<String, StructDef>,
     pub enums: HashMap<String, EnumDef>,
     pub traits: HashMap<String, TraitDef>,
+    /// Tipo -> traits implementados. `BTreeSet` para que a busca de métodos
+    /// default seja determinística quando dois traits definem o mesmo nome.
+    pub impls: HashMap<String, BTreeSet<String>>,
+    /// Tipo -> nomes em `@derive(...)`.
+    pub derives: HashMap<String, BTreeSet<String>>,
 }
 
 impl TypeRegistry {
     pub fn new() -> Self {
-        TypeRegistry {
+        let mut registry = TypeRegistry {
             structs: HashMap::new(),
             enums: HashMap::new(),
             traits: HashMap::new(),
+            impls: HashMap::new(),
+            derives: HashMap::new(),
+        };
+        for (name, types) in core::types::BUILTIN_TRAITS {
+            registry.register_trait(Token::dummy(name), HashMap::new(), HashMap::new());
+            for ty in *types {
+                registry.register_impl(name, ty);
+            }
         }
+        registry
     }
 
     pub fn register_struct(&mut self, name: Token, fields: Vec<(Token, String)>) {
+        let fields = fields
+            .into_iter()
+            .map(|(name, ty)| StructField {
+                name,
+                ty,
+                default: None,
+            })
+            .collect();
+        self.register_struct_decl(name, fields, false);
+    }
+
+    /// Registra um `struct` do programa, com defaults e forma tupla.
+    pub fn register_struct_decl(&mut self, name: Token, fields: Vec<StructField>, tuple: bool) {
+        let mut defaults = HashMap::new();
+        let mut field_types = Vec::with_capacity(fields.len());
+        for field in fields {
+            if let Some(default) = field.default {
+                defaults.insert(field.name.lexeme.clone(), default);
+            }
+            field_types.push((field.name.lexeme, field.ty));
+        }
         let struct_def = StructDef {
             name: name.lexeme.clone(),
-            fields: fields
-                .into_iter()
-                .map(|(token, ty)| (token.lexeme, ty))
-                .collect(),
+            fields: field_types,
+            defaults,
+            tuple,
             methods: std::collections::HashMap::new(),
         };
         self.structs.insert(name.lexeme, struct_def);
@@ -73,10 +115,12 @@ impl TypeRegistry {
         &mut self,
         name: Token,
         methods: std::collections::HashMap<String, Vec<String>>,
+        defaults: std::collections::HashMap<String, core::ast::Function>,
     ) {
         let trait_def = TraitDef {
             name: name.lexeme.clone(),
             methods,
+            defaults,
         };
         self.traits.insert(name.lexeme, trait_def);
     }
@@ -85,6 +129,48 @@ impl TypeRegistry {
         self.traits.get(name)
     }
 
+    pub fn register_impl(&mut self, trait_name: &str, type_name: &str) {
+        self.impls
+            .entry(type_name.to_string())
+            .or_default()
+            .insert(trait_name.to_string());
+    }
+
+    pub fn implements(&self, type_name: &str, trait_name: &str) -> bool {
+        self.impls
+            .get(type_name)
+            .is_some_and(|traits| traits.contains(trait_name))
+    }
+
+    /// Guarda o `@derive(...)` de `type_name` e registra os traits embutidos
+    /// que cada derive satisfaz.
+    pub fn register_derives(&mut self, type_name: &str, derives: &[Token]) {
+        for derive in derives {
+            if let Some(Some(bound)) = core::types::derive_bound(&derive.lexeme) {
+                self.register_impl(bound, type_name);
+            }
+            self.derives
+                .entry(type_name.to_string())
+                .or_default()
+                .insert(derive.lexeme.clone());
+        }
+    }
+
+    /// `true` se `type_name` tem `derive` no seu `@derive(...)`.
+    pub fn derives(&self, type_name: &str, derive: &str) -> bool {
+        self.derives
+            .get(type_name)
+            .is_some_and(|ds| ds.contains(derive))
+    }
+
+    /// Método default de algum trait implementado por `type_name`.
+    pub fn trait_default(&self, type_name: &str, method: &str) -> Option<&core::ast::Function> {
+        self.impls
+            .get(type_name)?
+            .iter()
+            .find_map(|t| self.traits.get(t)?.defaults.get(method))
+    }
+
     pub fn has_enum(&self, name: &str) -> bool {
         self.enums.contains_key(name)
     }
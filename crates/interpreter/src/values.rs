@@ -4,6 +4,10 @@ use std::fmt;
 #[derive(Debug, Clone, PartialEq)]
 pub enum RuntimeError {
     Return(ArtValue),
+    /// `break` em voo até o loop alvo; carrega o rótulo (`'outer`) se houver.
+    Break(Option<String>),
+    /// `continue` em voo até o loop alvo.
+    Continue(Option<String>),
     TypeError(String),
     DebugStepBack,
     DebugQuit,
@@ -14,6 +18,8 @@ impl fmt::Display for RuntimeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             RuntimeError::Return(val) => write!(f, "Function returned: {}", val),
+            RuntimeError::Break(_) => write!(f, "'break' outside of a loop"),
+            RuntimeError::Continue(_) => write!(f, "'continue' outside of a loop"),
             RuntimeError::TypeError(msg) => write!(f, "Type error: {}", msg),
             RuntimeError::DebugStepBack => write!(f, "Debug step back requested"),
             RuntimeError::DebugQuit => write!(f, "Debug quit"),
@@ -244,6 +244,7 @@ fn actor_mailbox_fifo_and_backpressure_and_scheduler() {
     let body = vec![Stmt::Let {
         pattern: core::ast::MatchPattern::Variable(core::Token::dummy("m")),
         ty: None,
+        mutable: false,
         initializer: Expr::Call {
             type_args: None,
             callee: Box::new(Expr::Variable {
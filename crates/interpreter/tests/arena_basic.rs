@@ -9,11 +9,13 @@ fn performant_block_allocates_in_arena_and_finalizes() {
             Stmt::Let {
                 pattern: core::ast::MatchPattern::Variable(core::Token::dummy("a")),
                 ty: None,
+                mutable: false,
                 initializer: Expr::Array(vec![Expr::Literal(core::ast::ArtValue::Int(1))]),
             },
             Stmt::Let {
                 pattern: core::ast::MatchPattern::Variable(core::Token::dummy("b")),
                 ty: None,
+                mutable: false,
                 initializer: Expr::Array(vec![Expr::Literal(core::ast::ArtValue::Int(2))]),
             },
         ],
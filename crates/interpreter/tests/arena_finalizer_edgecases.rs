@@ -23,10 +23,12 @@ fn finalizer_promotes_handles_from_arena() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("promoted")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Literal(ArtValue::Int(42)),
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         Stmt::Expression(Expr::Call {
             type_args: None,
@@ -75,10 +77,12 @@ fn finalizer_allocation_inside_arena_does_not_break_finalize() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("tmp")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Array(vec![Expr::Literal(ArtValue::Int(7))]),
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         Stmt::Expression(Expr::Call {
             type_args: None,
@@ -20,10 +20,12 @@ fn finalizer_promotions_counted_per_arena() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("promoted2")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Array(vec![Expr::Literal(ArtValue::Int(99))]),
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         Stmt::Expression(Expr::Call {
             type_args: None,
@@ -79,10 +81,12 @@ fn finalize_arena_idempotent_and_safe() {
             statements: vec![Stmt::Let {
                 pattern: core::ast::MatchPattern::Variable(core::Token::dummy("tmpx")),
                 ty: None,
+                mutable: false,
                 initializer: Expr::Array(vec![Expr::Literal(ArtValue::Int(5))]),
             }],
         }),
         method_owner: None,
+        public: false,
     }];
     assert!(
         interp.interpret(program).is_ok(),
@@ -22,10 +22,12 @@ fn finalizer_promote_other_survives() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("survivor")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Literal(ArtValue::HeapComposite(core::ast::ObjHandle(b))),
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         // attach finalize to a
         Stmt::Expression(Expr::Call {
@@ -84,10 +86,12 @@ fn mutual_finalizers_promote_each_other_safe() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("x")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Literal(ArtValue::HeapComposite(core::ast::ObjHandle(a))),
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         Stmt::Function {
             type_params: None,
@@ -99,10 +103,12 @@ fn mutual_finalizers_promote_each_other_safe() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("y")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Literal(ArtValue::HeapComposite(core::ast::ObjHandle(b))),
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         // attach promote_b to a and promote_a to b
         Stmt::Expression(Expr::Call {
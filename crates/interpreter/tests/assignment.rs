@@ -0,0 +1,142 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn run(src: &str) -> Interpreter {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    let mut p = Parser::new(tokens);
+    let (program, diags) = p.parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    interp
+}
+
+fn run_value(src: &str) -> Option<ArtValue> {
+    let mut interp = run(src);
+    let diags = interp.take_diagnostics();
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    interp.last_value
+}
+
+#[test]
+fn var_counter_in_while_loop() {
+    let src = r#"
+var i = 0;
+var sum = 0;
+while i < 4 {
+    sum += i;
+    i = i + 1;
+}
+sum
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(6)));
+}
+
+#[test]
+fn compound_assignment_operators_on_int() {
+    let src = r#"
+var x = 10;
+x -= 4;
+x *= 3;
+x /= 2;
+x
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(9)));
+}
+
+#[test]
+fn plus_equal_concatenates_strings() {
+    let src = r#"
+var s = "a";
+s += "b";
+s
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::String("ab".into())));
+}
+
+#[test]
+fn struct_field_assignment_and_method_mutating_self() {
+    let src = r#"
+struct Counter { n: Int }
+impl Counter {
+    func bump(self) {
+        self.n += 1;
+    }
+}
+let c = Counter { n: 10 };
+c.n = 20;
+c.bump();
+c.bump();
+c.n
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(22)));
+}
+
+#[test]
+fn compound_field_assignment_evaluates_receiver_once() {
+    let src = r#"
+struct P { a: Int }
+let p = P { a: 1 };
+var calls = 0;
+func pick() -> P {
+    calls += 1;
+    return p;
+}
+pick().a += 5;
+pick().a *= 2;
+calls * 100 + p.a
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(212)));
+}
+
+#[test]
+fn closure_assigns_outer_var() {
+    let src = r#"
+var total = 0;
+func add(n) {
+    total += n;
+}
+add(2);
+add(5);
+total
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(7)));
+}
+
+#[test]
+fn assign_to_undefined_variable_reports_diagnostic() {
+    let mut interp = run("missing = 1;");
+    let diags = interp.take_diagnostics();
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.message.contains("Undefined variable 'missing'")),
+        "{:?}",
+        diags
+    );
+}
+
+#[test]
+fn assign_to_missing_field_reports_diagnostic() {
+    let src = r#"
+struct P { x: Int }
+let p = P { x: 1 };
+p.y = 2;
+"#;
+    let mut interp = run(src);
+    let diags = interp.take_diagnostics();
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.message.contains("Missing field 'y' on struct 'P'")),
+        "{:?}",
+        diags
+    );
+}
@@ -0,0 +1,145 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn run(src: &str) -> (Option<ArtValue>, Vec<String>) {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    let diags = interp
+        .take_diagnostics()
+        .into_iter()
+        .map(|d| d.message)
+        .collect();
+    (interp.last_value, diags)
+}
+
+fn run_value(src: &str) -> Option<ArtValue> {
+    let (value, diags) = run(src);
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    value
+}
+
+fn string(s: &str) -> Option<ArtValue> {
+    Some(ArtValue::String(std::sync::Arc::from(s)))
+}
+
+#[test]
+fn calling_an_async_function_creates_a_task() {
+    let src = r#"
+var log = "";
+async func work() {
+    log += "ran ";
+    return 7;
+}
+let t = work();
+log += f"{t} ";
+let v = await t;
+log += f"{v} {await 3}";
+log
+"#;
+    assert_eq!(run_value(src), string("<task work> ran 7 3"));
+}
+
+#[test]
+fn tasks_interleave_at_each_await() {
+    let src = r#"
+var log = "";
+async func worker(name, n) {
+    var i = 0;
+    while i < n {
+        log += f"{name}{i} ";
+        await sleep(0);
+        i += 1;
+    }
+    return n;
+}
+let a = worker("a", 3);
+let b = worker("b", 2);
+let total = await a;
+log += f"= {total + await b}";
+log
+"#;
+    assert_eq!(run_value(src), string("a0 b0 a1 b1 a2 = 5"));
+}
+
+#[test]
+fn awaiting_an_actor_reply_suspends_only_that_task() {
+    // O ator só responde à primeira pergunta depois de receber a segunda, que
+    // vem de outra task: ela precisa rodar enquanto a primeira espera.
+    let src = r#"
+let gate = spawn actor {
+    let first = actor_receive_envelope();
+    let second = actor_receive_envelope();
+    actor_send(first.sender, first.payload + second.payload);
+    actor_send(second.sender, "ok");
+};
+var order = "";
+async func asker() {
+    order += "ask ";
+    let sum = await actor_ask(gate, 40);
+    order += f"got {sum} ";
+}
+async func other() {
+    order += "other ";
+    let reply = await actor_ask(gate, 2);
+    order += f"other {reply}";
+}
+let t = asker();
+let u = other();
+run_actors();
+order
+"#;
+    assert_eq!(run_value(src), string("ask other got 42 other ok"));
+}
+
+#[test]
+fn errors_cross_await_into_try_catch() {
+    let src = r#"
+async func fails() {
+    await sleep(1);
+    let (p, q) = 10;
+    return 1;
+}
+async func catcher() {
+    for i in 0..2 {
+        try {
+            let v = await fails();
+            return "not reached";
+        } catch e {
+            if i == 1 {
+                return "caught twice";
+            }
+        }
+    }
+}
+await catcher()
+"#;
+    assert_eq!(run_value(src), string("caught twice"));
+}
+
+#[test]
+fn awaiting_a_task_that_never_finishes_is_reported() {
+    let src = r#"
+let silent = spawn actor {
+    let m = actor_receive();
+};
+async func waits() {
+    return await actor_ask(silent, 1);
+}
+await waits();
+"#;
+    let (_, diags) = run(src);
+    assert_eq!(
+        diags,
+        vec!["'await' on a task that can never finish: every task and actor is blocked."]
+    );
+}
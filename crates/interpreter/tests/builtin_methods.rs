@@ -0,0 +1,70 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+use std::sync::Arc;
+
+fn run_value(src: &str) -> Option<ArtValue> {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    let diags = interp.take_diagnostics();
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    interp.last_value
+}
+
+fn string(s: &str) -> Option<ArtValue> {
+    Some(ArtValue::String(Arc::from(s)))
+}
+
+#[test]
+fn string_and_array_methods_call_the_builtins() {
+    let src = r#"
+let csv = "a,b,c";
+let sep = ",";
+let dash = "-";
+let parts = csv.split(sep);
+let joined = parts.join(dash);
+let b = "b";
+f"{parts.len()} {joined} {csv.contains(b)} {csv.len()} {joined.replace(dash, sep)}";
+"#;
+    assert_eq!(run_value(src), string("3 a-b-c true 5 a,b,c"));
+}
+
+#[test]
+fn collection_methods_mutate_and_read_the_receiver() {
+    let src = r#"
+let m = map_new();
+let k = "k";
+m.set(k, 1);
+let got = m.get(k) ?? 0;
+let s = set_new();
+s.add(3);
+s.add(3);
+let d = deque_new();
+d.push_back(1);
+d.push_front(0);
+let first = d.pop_front() ?? 9;
+f"{got} {m.has(k)} {m.len()} {s.len()} {s.has(3)} {first} {d.len()}";
+"#;
+    assert_eq!(run_value(src), string("1 true 1 1 true 0 1"));
+}
+
+#[test]
+fn numeric_methods_and_impl_methods_coexist() {
+    let src = r#"
+struct Bag { items: Int }
+impl Bag {
+    func len(self) -> Int { return self.items; }
+}
+let bag = Bag { items: 7 };
+let n = 0 - 4;
+f"{n.abs()} {2.pow(10)} {n.clamp(0, 3)} {bag.len()}";
+"#;
+    assert_eq!(run_value(src), string("4 1024 0 7"));
+}
@@ -0,0 +1,178 @@
+use core::ast::{ArtValue, MapKey};
+use interpreter::interpreter::{Interpreter, decode_val, encode_val};
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+use std::sync::Arc;
+
+fn run(src: &str) -> (Option<ArtValue>, Vec<String>) {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    let messages = interp
+        .take_diagnostics()
+        .into_iter()
+        .map(|d| d.message)
+        .collect();
+    (interp.last_value, messages)
+}
+
+fn value(src: &str) -> Option<ArtValue> {
+    let (value, diags) = run(src);
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    value
+}
+
+fn string(s: &str) -> Option<ArtValue> {
+    Some(ArtValue::String(Arc::from(s)))
+}
+
+const POS: &str = "@derive(Eq, Hash, Debug)\nstruct Pos { x: Int, y: Int }\n";
+
+#[test]
+fn maps_accept_any_hashable_key() {
+    let src = format!(
+        "{}let m = map_new();\n\
+         map_set(m, (1, 2), \"tuple\");\n\
+         map_set(m, 7, \"int\");\n\
+         map_set(m, false, \"bool\");\n\
+         let p = Pos {{ x: 1, y: 2 }};\n\
+         m[p] = \"pos\";\n\
+         let q = Pos {{ x: 1, y: 2 }};\n\
+         let t = map_get(m, (1, 2)) ?? \"-\";\n\
+         f\"{{t}} {{m[7]}} {{m[false]}} {{m[q]}} {{map_has(m, (2, 1))}} {{len(m)}}\"",
+        POS
+    );
+    assert_eq!(value(&src), string("tuple int bool pos false 4"));
+}
+
+#[test]
+fn sets_deduplicate_by_value_and_keep_insertion_order() {
+    let src = format!(
+        "{}let s = set_new();\n\
+         let a = Pos {{ x: 3, y: 0 }};\n\
+         let b = Pos {{ x: 3, y: 0 }};\n\
+         set_add(s, (2, \"b\"));\n\
+         set_add(s, a);\n\
+         set_add(s, b);\n\
+         set_add(s, (2, \"b\"));\n\
+         var out = \"\";\n\
+         for item in s {{ out = out + f\"{{item:debug}};\"; }}\n\
+         f\"{{len(s)}} {{set_has(s, b)}} {{set_has(s, 3)}} {{out}}\"",
+        POS
+    );
+    assert_eq!(
+        value(&src),
+        string("2 true false (2, \"b\");Pos { x: 3, y: 0 };")
+    );
+}
+
+#[test]
+fn keys_without_hash_are_runtime_errors() {
+    let src = "struct Plain { x: Int }\nlet p = Plain { x: 1 };\n\
+               let m = map_new();\nmap_set(m, p, 1);\nm[[p]] = 2;\n\
+               let s = set_new();\nset_add(s, p);\nlen(m) + len(s)";
+    let (value, diags) = run(src);
+    assert_eq!(value, Some(ArtValue::Int(0)));
+    assert_eq!(
+        diags,
+        vec![
+            "map_set: values of type Plain are not hashable; add @derive(Hash).",
+            "Map keys must be hashable, got Array; add @derive(Hash).",
+            "set_add: values of type Plain are not hashable; add @derive(Hash).",
+        ]
+    );
+}
+
+#[test]
+fn serialize_round_trips_structured_keys() {
+    let src = format!(
+        "{}let m = map_new();\n\
+         let p = Pos {{ x: 1, y: 2 }};\n\
+         map_set(m, p, \"pos\");\n\
+         map_set(m, (0, true), \"tuple\");\n\
+         let s = set_new();\n\
+         set_add(s, p);\n\
+         let m2 = deserialize(serialize(m));\n\
+         let s2 = deserialize(serialize(s));\n\
+         let found = map_get(m2, p) ?? \"-\";\n\
+         f\"{{found}} {{m2[(0, true)]}} {{set_has(s2, p)}}\"",
+        POS
+    );
+    assert_eq!(value(&src), string("pos tuple true"));
+}
+
+#[test]
+fn encode_keeps_the_string_key_format() {
+    let src = "let named = map_new();\nmap_set(named, \"k\", 1);\n\
+               let keyed = map_new();\nmap_set(keyed, (1, \"a\"), 2);\n\
+               let members = set_new();\nset_add(members, (1, \"a\"));";
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex ok");
+    let (program, _) = Parser::new(tokens).parse();
+    let mut interp = Interpreter::with_prelude();
+    interp.interpret(program).expect("run");
+    let global = |name: &str| interp.debug_get_global(name).expect(name);
+
+    let mut bytes = Vec::new();
+    encode_val(&global("named"), &mut bytes).expect("encode");
+    assert_eq!(bytes[0], 8, "Maps with String keys keep the original tag");
+    assert_eq!(&bytes[5..10], &[1, 0, 0, 0, b'k']);
+
+    let key = MapKey::Tuple(vec![MapKey::Int(1), MapKey::from("a")]);
+    for name in ["keyed", "members"] {
+        let mut bytes = Vec::new();
+        encode_val(&global(name), &mut bytes).expect("encode");
+        match decode_val(&mut std::io::Cursor::new(bytes.as_slice())).expect("decode") {
+            ArtValue::Map(m) => {
+                assert_eq!(m.0.lock().unwrap().get(&key), Some(&ArtValue::Int(2)))
+            }
+            ArtValue::Set(s) => assert!(s.0.lock().unwrap().contains(&key)),
+            other => panic!("expected Map or Set, got {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn map_views_follow_key_order_and_merge_prefers_the_right_side() {
+    let src = "let m = map_new();\nmap_set(m, \"b\", 2);\nmap_set(m, \"a\", 1);\n\
+               map_set(m, \"c\", 3);\n\
+               let gone = map_remove(m, \"c\");\nlet missing = map_remove(m, \"zz\");\n\
+               let o = map_new();\nmap_set(o, \"a\", 10);\nmap_set(o, \"d\", 4);\n\
+               let merged = m.merge(o);\n\
+               let keys = m.keys();\nlet values = map_values(m);\n\
+               let entries = map_entries(merged);\n\
+               f\"{gone:debug} {missing:debug} {keys:debug} {values:debug} {entries:debug} {len(m)}\"";
+    assert_eq!(
+        value(src),
+        string(
+            "Some(3) None [\"a\", \"b\"] [1, 2] \
+             [(\"a\", 10), (\"b\", 2), (\"d\", 4)] 2"
+        )
+    );
+}
+
+#[test]
+fn set_algebra_returns_new_sets_in_insertion_order() {
+    let src = "let s = set_new();\nset_add(s, 3);\nset_add(s, 1);\nset_add(s, 2);\n\
+               let t = set_new();\nset_add(t, 2);\nset_add(t, 5);\n\
+               let u = set_to_array(set_union(s, t));\n\
+               let i = set_intersection(s, t).to_array();\n\
+               let d = set_to_array(s.difference(t));\n\
+               let first = set_remove(s, 1);\nlet again = s.remove(1);\n\
+               f\"{u:debug} {i:debug} {d:debug} {first} {again} {len(s)} {len(t)}\"";
+    assert_eq!(value(src), string("[3, 1, 2, 5] [2] [3, 1] true false 2 2"));
+}
+
+#[test]
+fn deques_support_indexing_iteration_and_snapshots() {
+    let src = "let d = deque_new();\nd.push_back(1);\nd.push_back(2);\nd.push_front(0);\n\
+               d[1] = 9;\nvar sum = 0;\nfor x in d { sum = sum + x; }\n\
+               let last = deque_get(d, -1);\nlet out = d.get(5);\n\
+               let items = d.to_array();\n\
+               f\"{d[0]} {sum} {last:debug} {out:debug} {items:debug}\"";
+    assert_eq!(value(src), string("0 11 Some(2) None [0, 9, 2]"));
+}
@@ -0,0 +1,65 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn run(src: &str) -> Interpreter {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    interp
+}
+
+fn value(src: &str) -> Option<ArtValue> {
+    let mut interp = run(src);
+    let diags = interp.take_diagnostics();
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    interp.last_value
+}
+
+#[test]
+fn constants_fold_and_reference_earlier_constants() {
+    let src = "const A: Int = 1 << 4;\nconst B: Int = A * 2 + 1;\nfunc f() { return B - A; }\nf()";
+    assert_eq!(value(src), Some(ArtValue::Int(17)));
+    assert_eq!(
+        value("const PRIMES: [Int] = [2, 3, 5];\nPRIMES[-1]"),
+        Some(ArtValue::Int(5))
+    );
+    assert_eq!(
+        value("struct P { x: Int, y: Int }\nconst O: P = P { x: -1, y: 2 };\nO.x + O.y"),
+        Some(ArtValue::Int(1))
+    );
+}
+
+#[test]
+fn non_constant_initializers_are_reported() {
+    for (src, needle) in [
+        (
+            "func g() { return 1; }\nconst N: Int = g();",
+            "function calls",
+        ),
+        ("let x = 1;\nconst N: Int = x + 1;", "'x' is not a constant"),
+        (
+            "const N: Int = 9223372036854775807 + 1;",
+            "integer overflow",
+        ),
+        ("const N: Int = 1 / 0;", "division by zero"),
+    ] {
+        let mut interp = run(src);
+        let diags = interp.take_diagnostics();
+        assert!(
+            diags
+                .iter()
+                .any(|d| d.message.contains("is not a compile-time constant")
+                    && d.message.contains(needle)),
+            "{}: {:?}",
+            src,
+            diags
+        );
+    }
+}
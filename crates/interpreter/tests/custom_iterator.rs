@@ -11,10 +11,10 @@ let iter_state = map_new();
 map_set(iter_state, "i", 0);
 
 func gen() {
-    // Increment stored counter and yield up to 3.
+    // Increment stored counter and return it up to 3.
     map_set(iter_state, "i", map_get(iter_state, "i").unwrap_or(0) + 1);
     if map_get(iter_state, "i").unwrap_or(0) <= 3 {
-        yield map_get(iter_state, "i").unwrap_or(0);
+        return Option.Some(map_get(iter_state, "i").unwrap_or(0));
     }
     return Option.None;
 }
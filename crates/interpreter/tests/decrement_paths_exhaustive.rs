@@ -17,21 +17,25 @@ fn rebind_decrements_and_updates_weak_unowned() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("rebound_flag")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Literal(ArtValue::Int(7)),
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         // let a = [0]
         Stmt::Let {
             pattern: core::ast::MatchPattern::Variable(core::Token::dummy("a")),
             ty: None,
+            mutable: false,
             initializer: Expr::Array(vec![Expr::Literal(ArtValue::Int(0))]),
         },
         // let w = weak(a)
         Stmt::Let {
             pattern: core::ast::MatchPattern::Variable(core::Token::dummy("w")),
             ty: None,
+            mutable: false,
             initializer: Expr::Call {
                 type_args: None,
                 callee: Box::new(Expr::Variable {
@@ -46,6 +50,7 @@ fn rebind_decrements_and_updates_weak_unowned() {
         Stmt::Let {
             pattern: core::ast::MatchPattern::Variable(core::Token::dummy("u")),
             ty: None,
+            mutable: false,
             initializer: Expr::Call {
                 type_args: None,
                 callee: Box::new(Expr::Variable {
@@ -75,6 +80,7 @@ fn rebind_decrements_and_updates_weak_unowned() {
         Stmt::Let {
             pattern: core::ast::MatchPattern::Variable(core::Token::dummy("a")),
             ty: None,
+            mutable: false,
             initializer: Expr::Array(vec![]),
         },
     ];
@@ -142,11 +148,13 @@ fn returns_do_not_allow_arena_escape() {
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         // call ret_a and bind to global g
         Stmt::Let {
             pattern: core::ast::MatchPattern::Variable(core::Token::dummy("g")),
             ty: None,
+            mutable: false,
             initializer: Expr::Call {
                 type_args: None,
                 callee: Box::new(Expr::Variable {
@@ -201,10 +209,12 @@ fn field_assignment_triggers_decrement_and_finalizer() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("field_flag")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Literal(ArtValue::Int(123)),
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         // on_finalize(x, fin_field)
         Stmt::Expression(Expr::Call {
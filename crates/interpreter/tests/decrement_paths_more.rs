@@ -22,16 +22,19 @@ fn finalizer_promotes_multiple_temporaries_to_root() {
                     Stmt::Let {
                         pattern: core::ast::MatchPattern::Variable(core::Token::dummy("kept1")),
                         ty: None,
+                        mutable: false,
                         initializer: Expr::Array(vec![Expr::Literal(ArtValue::Int(11))]),
                     },
                     Stmt::Let {
                         pattern: core::ast::MatchPattern::Variable(core::Token::dummy("kept2")),
                         ty: None,
+                        mutable: false,
                         initializer: Expr::Array(vec![Expr::Literal(ArtValue::Int(22))]),
                     },
                 ],
             }),
             method_owner: None,
+            public: false,
         },
         // Registrar finalizer
         Stmt::Expression(Expr::Call {
@@ -91,6 +94,7 @@ fn nested_performant_return_emits_diagnostic() {
                         Stmt::Let {
                             pattern: core::ast::MatchPattern::Variable(core::Token::dummy("a")),
                             ty: None,
+                            mutable: false,
                             initializer: Expr::Literal(ArtValue::HeapComposite(
                                 core::ast::ObjHandle(id),
                             )),
@@ -104,11 +108,13 @@ fn nested_performant_return_emits_diagnostic() {
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         // Chamar e atribuir
         Stmt::Let {
             pattern: core::ast::MatchPattern::Variable(core::Token::dummy("r")),
             ty: None,
+            mutable: false,
             initializer: Expr::Call {
                 type_args: None,
                 callee: Box::new(Expr::Variable {
@@ -181,10 +187,12 @@ fn field_assign_prev_arena_triggers_finalizer() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("prev_flag")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Literal(ArtValue::Int(55)),
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         // on_finalize(child_arena, fin_prev)
         Stmt::Expression(Expr::Call {
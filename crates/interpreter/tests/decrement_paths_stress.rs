@@ -26,10 +26,12 @@ fn stress_finalizer_mass_promotion() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("promoted")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Array(vec![Expr::Literal(ArtValue::Int(100 + i as i64))]),
                 }],
             }),
             method_owner: None,
+            public: false,
         };
         assert!(
             interp.interpret(vec![fin]).is_ok(),
@@ -132,10 +134,12 @@ fn stress_chained_finalizers_cross_arena() {
             statements: vec![Stmt::Let {
                 pattern: core::ast::MatchPattern::Variable(core::Token::dummy("from_fin1")),
                 ty: None,
+                mutable: false,
                 initializer: Expr::Array(vec![Expr::Literal(ArtValue::Int(1))]),
             }],
         }),
         method_owner: None,
+        public: false,
     };
     // finalizer for id2 creates a global object
     let fin2 = Stmt::Function {
@@ -148,10 +152,12 @@ fn stress_chained_finalizers_cross_arena() {
             statements: vec![Stmt::Let {
                 pattern: core::ast::MatchPattern::Variable(core::Token::dummy("from_fin2")),
                 ty: None,
+                mutable: false,
                 initializer: Expr::Array(vec![Expr::Literal(ArtValue::Int(2))]),
             }],
         }),
         method_owner: None,
+        public: false,
     };
     assert!(
         interp.interpret(vec![fin1, fin2]).is_ok(),
@@ -0,0 +1,137 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn run_value(src: &str) -> Option<ArtValue> {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    let diags = interp.take_diagnostics();
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    interp.last_value
+}
+
+fn string(s: &str) -> Option<ArtValue> {
+    Some(ArtValue::String(std::sync::Arc::from(s)))
+}
+
+#[test]
+fn deferred_blocks_run_lifo_on_every_exit() {
+    let src = r#"
+var log = "";
+func work(n) {
+    defer { log += "a "; }
+    defer { log += "b "; }
+    if n > 0 {
+        return n;
+    }
+    log += "end ";
+    return 0;
+}
+let r = work(1) + work(0);
+for i in 0..3 {
+    defer { log += f"d{i} "; }
+    if i == 1 { break; }
+    log += f"i{i} ";
+}
+log += f"= {r}";
+log
+"#;
+    assert_eq!(run_value(src), string("b a end b a i0 d0 d1 = 1"));
+}
+
+#[test]
+fn deferred_blocks_run_when_errors_are_caught() {
+    let src = r#"
+var log = "";
+func fails() {
+    defer { log += "cleanup "; }
+    let (p, q) = 10;
+    log += "unreached ";
+}
+func bad_cleanup() {
+    defer { let (p, q) = 1; }
+    return 5;
+}
+try { fails(); } catch e { log += "caught "; }
+try { bad_cleanup(); } catch e { log += "from defer"; }
+log
+"#;
+    assert_eq!(run_value(src), string("cleanup caught from defer"));
+}
+
+#[test]
+fn deferred_blocks_run_before_finalizers_and_arena_teardown() {
+    let src = r#"
+var log = "";
+func fin() { log += "fin "; }
+{
+    let x = [1, 2];
+    on_finalize(x, fin);
+    defer { log += f"defer {len(x)} "; }
+}
+performant {
+    defer { fin(); }
+}
+log
+"#;
+    assert_eq!(run_value(src), string("defer 2 fin fin "));
+}
+
+#[test]
+fn suspended_generator_scopes_run_their_defers() {
+    let src = r#"
+var log = "";
+func gen() {
+    defer { log += "gen-done "; }
+    yield 1;
+    yield 2;
+}
+for x in gen() { log += f"{x} "; }
+log
+"#;
+    assert_eq!(run_value(src), string("1 2 gen-done "));
+}
+
+#[test]
+fn try_propagation_runs_deferred_blocks() {
+    use core::ast::{Expr, Stmt};
+    let src = r#"
+var log = "";
+func first(r) {
+    defer { log += "cleanup "; }
+    let v = r;
+    log += "after ";
+    return v;
+}
+let out = first(Result.Err("boom"));
+log += f"{out}";
+log
+"#;
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex ok");
+    let (mut program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    // Sem sintaxe própria para `Expr::Try`: `let v = r;` vira `let v = r?;`.
+    let Stmt::Function { body, .. } = &mut program[1] else {
+        panic!("expected function");
+    };
+    let Stmt::Block { statements } = std::rc::Rc::make_mut(body) else {
+        panic!("expected block body");
+    };
+    let Stmt::Let { initializer, .. } = &mut statements[1] else {
+        panic!("expected let");
+    };
+    *initializer = Expr::Try(Box::new(initializer.clone()));
+
+    let mut interp = Interpreter::with_prelude();
+    assert!(interp.interpret(program).is_ok());
+    assert_eq!(interp.last_value, string("cleanup boom"));
+}
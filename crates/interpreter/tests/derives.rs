@@ -0,0 +1,137 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+use std::sync::Arc;
+
+fn run(src: &str) -> (Option<ArtValue>, Vec<String>) {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    let messages = interp
+        .take_diagnostics()
+        .into_iter()
+        .map(|d| d.message)
+        .collect();
+    (interp.last_value, messages)
+}
+
+fn value(src: &str) -> Option<ArtValue> {
+    let (value, diags) = run(src);
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    value
+}
+
+fn string(s: &str) -> Option<ArtValue> {
+    Some(ArtValue::String(Arc::from(s)))
+}
+
+const TYPES: &str = r#"
+@derive(Eq, Hash, Ord, Debug, Clone)
+struct Point { x: Int, y: Int }
+@derive(Eq, Ord, Debug)
+enum Shape { Dot, Circle(Float), Rect(Int, Int) }
+@derive(Debug)
+struct Rgb(Int, Int, Int)
+struct Plain { x: Int }
+let a = Point { x: 1, y: 2 };
+let b = Point { x: 1, y: 2 };
+let c = Point { x: 0, y: 9 };
+"#;
+
+#[test]
+fn derived_eq_compares_fields_and_plain_structs_keep_identity() {
+    let src = format!(
+        "{}let p = Plain {{ x: 1 }};\nlet q = Plain {{ x: 1 }};\nlet r = Shape.Rect(1, 2);\nlet t = (a, 1);\nlet u = (b, 1);\nf\"{{a == b}} {{a != c}} {{p == q}} {{r == Shape.Rect(1, 2)}} {{r == Shape.Dot}} {{t == u}}\"",
+        TYPES
+    );
+    assert_eq!(value(&src), string("true true false true false true"));
+}
+
+#[test]
+fn derived_ord_compares_fields_then_variants_in_declaration_order() {
+    let src = format!(
+        "{}let dot = Shape.Dot;\nlet big = Shape.Circle(9.0);\nlet small = Shape.Rect(1, 1);\nf\"{{c < a}} {{a <= b}} {{a > c}} {{dot < big}} {{big < small}} {{Shape.Rect(1, 2) > small}}\"",
+        TYPES
+    );
+    assert_eq!(value(&src), string("true true true true true true"));
+}
+
+#[test]
+fn sort_uses_derived_order_and_user_cmp() {
+    let src = format!(
+        "{}let d = Point {{ x: 1, y: 0 }};\nlet s = [a, c, d].sort();\nlet words = sort([\"pear\", \"apple\", \"fig\"]);\nf\"{{s:debug}} {{words:debug}}\"",
+        TYPES
+    );
+    assert_eq!(
+        value(&src),
+        string(
+            "[Point { x: 0, y: 9 }, Point { x: 1, y: 0 }, Point { x: 1, y: 2 }] [\"apple\", \"fig\", \"pear\"]"
+        )
+    );
+    let by_cmp = r#"
+struct Job { prio: Int }
+impl Job {
+    func cmp(self, o: Job) -> Int { return o.prio - self.prio; }
+}
+let j1 = Job { prio: 1 };
+let j2 = Job { prio: 5 };
+let s = sort([j1, j2]);
+s[0].prio
+"#;
+    assert_eq!(value(by_cmp), Some(ArtValue::Int(5)));
+    let (_, diags) = run(&format!(
+        "{}let p = Plain {{ x: 1 }};\nsort([p, p]);",
+        TYPES
+    ));
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.contains("sort: values of type Plain are not ordered")),
+        "{:?}",
+        diags
+    );
+}
+
+#[test]
+fn hash_agrees_with_derived_eq() {
+    let src = format!(
+        "{}let s = \"x\";\nlet t = \"x\";\nf\"{{hash(a) == hash(b)}} {{hash(a) == hash(c)}} {{hash(s) == hash(t)}}\"",
+        TYPES
+    );
+    assert_eq!(value(&src), string("true false true"));
+    let (_, diags) = run(&format!("{}let p = Plain {{ x: 1 }};\nhash(p);", TYPES));
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.contains("hash: values of type Plain are not hashable")),
+        "{:?}",
+        diags
+    );
+}
+
+#[test]
+fn debug_format_lists_fields_in_declaration_order() {
+    let src = format!(
+        "{}let r = Shape.Rect(2, 3);\nlet rgb = Rgb(1, 2, 3);\nlet p = Plain {{ x: 1 }};\nlet name = \"ok\";\nf\"{{a:debug}} {{r:debug}} {{rgb:debug}} {{p:debug}} {{name:debug}}\"",
+        TYPES
+    );
+    assert_eq!(
+        value(&src),
+        string("Point { x: 1, y: 2 } Shape.Rect(2, 3) Rgb(1, 2, 3) Plain { .. } \"ok\"")
+    );
+}
+
+#[test]
+fn derived_clone_copies_the_instance() {
+    let src = format!(
+        "{}let d = a.clone();\nd.x = 7;\nf\"{{a.x}} {{d.x}} {{d == a}}\"",
+        TYPES
+    );
+    assert_eq!(value(&src), string("1 7 false"));
+}
@@ -48,10 +48,12 @@ fn field_mutation_runs_finalizer_and_decrements() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("old_gone")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Literal(core::ast::ArtValue::Int(1)),
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         // on_finalize(p.left, fin)
         Stmt::Expression(Expr::Call {
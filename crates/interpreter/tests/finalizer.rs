@@ -19,11 +19,13 @@ fn finalizer_runs_on_drop() {
                     Stmt::Let {
                         pattern: core::ast::MatchPattern::Variable(core::Token::dummy("flag")),
                         ty: None,
+                        mutable: false,
                         initializer: Expr::Literal(core::ast::ArtValue::Int(1)),
                     },
                 ],
             }),
             method_owner: None,
+            public: false,
         },
         // bloco cria 'x' e registra finalizer; ao sair dele strong ref cai a zero
         Stmt::Block {
@@ -31,6 +33,7 @@ fn finalizer_runs_on_drop() {
                 Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("x")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Array(vec![]),
                 },
                 Stmt::Expression(Expr::Call {
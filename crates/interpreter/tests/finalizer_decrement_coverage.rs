@@ -17,15 +17,18 @@ fn rebind_triggers_finalizer_and_clears_handles() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("flag")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Literal(ArtValue::Int(1)),
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         // let a = [1]
         Stmt::Let {
             pattern: core::ast::MatchPattern::Variable(core::Token::dummy("a")),
             ty: None,
+            mutable: false,
             initializer: Expr::Array(vec![Expr::Literal(ArtValue::Int(1))]),
         },
         // on_finalize(a, fin)
@@ -47,6 +50,7 @@ fn rebind_triggers_finalizer_and_clears_handles() {
         Stmt::Let {
             pattern: core::ast::MatchPattern::Variable(core::Token::dummy("a")),
             ty: None,
+            mutable: false,
             initializer: Expr::Array(vec![]),
         },
     ];
@@ -87,10 +91,12 @@ fn return_of_arena_object_is_reported() {
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         Stmt::Let {
             pattern: core::ast::MatchPattern::Variable(core::Token::dummy("g")),
             ty: None,
+            mutable: false,
             initializer: Expr::Call {
                 type_args: None,
                 callee: Box::new(Expr::Variable {
@@ -144,10 +150,12 @@ fn field_mutation_runs_finalizer_on_previous_value() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("ff")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Literal(ArtValue::Int(7)),
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         Stmt::Expression(Expr::Call {
             type_args: None,
@@ -213,10 +221,12 @@ fn performant_arena_finalization_promotes_and_cleans() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("promoted")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Literal(ArtValue::Int(42)),
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         Stmt::Expression(Expr::Call {
             type_args: None,
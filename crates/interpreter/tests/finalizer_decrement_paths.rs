@@ -12,6 +12,7 @@ fn return_arena_escape_diagnostic() {
     let program = vec![Stmt::Let {
         pattern: core::ast::MatchPattern::Variable(core::Token::dummy("b")),
         ty: None,
+        mutable: false,
         initializer: Expr::Literal(ArtValue::HeapComposite(core::ast::ObjHandle(id))),
     }];
 
@@ -59,11 +60,13 @@ fn finalizer_allocs_temporary_promoted() {
                     Stmt::Let {
                         pattern: core::ast::MatchPattern::Variable(core::Token::dummy("temp")),
                         ty: None,
+                        mutable: false,
                         initializer: Expr::Array(vec![Expr::Literal(ArtValue::Int(42))]),
                     },
                     Stmt::Let {
                         pattern: core::ast::MatchPattern::Variable(core::Token::dummy("kept")),
                         ty: None,
+                        mutable: false,
                         initializer: Expr::Variable {
                             name: core::Token::dummy("temp"),
                         },
@@ -71,6 +74,7 @@ fn finalizer_allocs_temporary_promoted() {
                 ],
             }),
             method_owner: None,
+            public: false,
         },
         Stmt::Expression(Expr::Call {
             type_args: None,
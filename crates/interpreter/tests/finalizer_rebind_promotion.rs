@@ -20,6 +20,7 @@ fn finalizer_promotes_handles_to_root_during_execution() {
                     Stmt::Let {
                         pattern: core::ast::MatchPattern::Variable(core::Token::dummy("promoted")),
                         ty: None,
+                        mutable: false,
                         initializer: Expr::Variable {
                             name: core::Token::dummy("outside"),
                         },
@@ -27,11 +28,13 @@ fn finalizer_promotes_handles_to_root_during_execution() {
                 ],
             }),
             method_owner: None,
+            public: false,
         },
         // define an outside root object that finalizer will promote
         Stmt::Let {
             pattern: core::ast::MatchPattern::Variable(core::Token::dummy("outside")),
             ty: None,
+            mutable: false,
             initializer: Expr::Array(vec![Expr::Literal(core::ast::ArtValue::Int(7))]),
         },
         // create block where x is created and finalizer registered, then rebind x
@@ -40,6 +43,7 @@ fn finalizer_promotes_handles_to_root_during_execution() {
                 Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("x")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Array(vec![Expr::Literal(core::ast::ArtValue::Int(1))]),
                 },
                 // register finalizer
@@ -61,6 +65,7 @@ fn finalizer_promotes_handles_to_root_during_execution() {
                 Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("x")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Array(vec![]),
                 },
             ],
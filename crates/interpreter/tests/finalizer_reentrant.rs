@@ -20,11 +20,13 @@ fn finalizer_reentrant_alloc_and_release_is_stable() {
                     Stmt::Let {
                         pattern: core::ast::MatchPattern::Variable(core::Token::dummy("_tmp")),
                         ty: None,
+                        mutable: false,
                         initializer: Expr::Array(vec![Expr::Literal(core::ast::ArtValue::Int(99))]),
                     },
                 ],
             }),
             method_owner: None,
+            public: false,
         },
         // block creating x and registering finalizer, then removing root to trigger finalizer
         Stmt::Block {
@@ -32,6 +34,7 @@ fn finalizer_reentrant_alloc_and_release_is_stable() {
                 Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("x")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Array(vec![Expr::Literal(core::ast::ArtValue::Int(1))]),
                 },
                 Stmt::Expression(Expr::Call {
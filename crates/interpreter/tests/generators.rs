@@ -0,0 +1,149 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn run(src: &str) -> (Option<ArtValue>, Vec<String>) {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    let diags = interp
+        .take_diagnostics()
+        .into_iter()
+        .map(|d| d.message)
+        .collect();
+    (interp.last_value, diags)
+}
+
+fn run_value(src: &str) -> Option<ArtValue> {
+    let (value, diags) = run(src);
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    value
+}
+
+fn string(s: &str) -> Option<ArtValue> {
+    Some(ArtValue::String(std::sync::Arc::from(s)))
+}
+
+const COUNTER: &str = r#"
+func counter(limit) {
+    var i = 0;
+    while i < limit {
+        i += 1;
+        yield i;
+    }
+}
+"#;
+
+#[test]
+fn next_resumes_where_the_generator_stopped() {
+    let src = format!(
+        "{}let g = counter(2);\nlet h = counter(1);\nlet a = g.next();\nlet b = h.next();\nlet c = g.next();\nlet d = g.next();\nlet e = g.next();\nf\"{{a.unwrap_or(0)}} {{b.unwrap_or(0)}} {{c.unwrap_or(0)}} {{d.unwrap_or(-1)}} {{e.unwrap_or(-1)}}\"",
+        COUNTER
+    );
+    assert_eq!(run_value(&src), string("1 1 2 -1 -1"));
+}
+
+#[test]
+fn for_loops_consume_generators() {
+    let src = format!(
+        "{}var total = 0;\nfor x in counter(4) {{ total += x; }}\nfunc three() {{ yield 100; yield 20; yield 3; }}\nfor y in three {{ total += y; }}\ntotal",
+        COUNTER
+    );
+    assert_eq!(run_value(&src), Some(ArtValue::Int(133)));
+}
+
+#[test]
+fn infinite_generators_stop_at_break() {
+    let src = r#"
+func fib() {
+    var a = 0;
+    var b = 1;
+    while true {
+        yield a;
+        let next = a + b;
+        a = b;
+        b = next;
+    }
+}
+var out = "";
+for n in fib() {
+    if n > 20 { break; }
+    out += f"{n} ";
+}
+out
+"#;
+    assert_eq!(run_value(src), string("0 1 1 2 3 5 8 13 "));
+}
+
+#[test]
+fn generators_feed_stream_pipelines() {
+    let collected = format!(
+        "{}let xs = counter(6) |> stream |> map(|x| x * 10) |> filter(|x| x > 30) |> collect;\nf\"{{xs}}\"",
+        COUNTER
+    );
+    assert_eq!(run_value(&collected), string("[40, 50, 60]"));
+
+    // `for` puxa o stream item a item: funciona sobre um generator infinito.
+    let lazy = r#"
+func naturals() {
+    var n = 0;
+    while true { n += 1; yield n; }
+}
+var seen = "";
+for x in naturals() |> stream |> filter(|x| x > 2) {
+    if x > 5 { break; }
+    seen += f"{x} ";
+}
+seen
+"#;
+    assert_eq!(run_value(lazy), string("3 4 5 "));
+}
+
+#[test]
+fn yield_suspends_inside_nested_control_flow() {
+    let src = r#"
+func walk(rows) {
+    for row in rows {
+        for x in row {
+            match x {
+                case 0: continue;
+                case n if n < 0: return;
+                case n: yield n;
+            }
+        }
+        try {
+            yield 100;
+            let (p, q) = 10;
+        } catch e {
+            yield 999;
+        }
+    }
+    yield 7;
+}
+var out = "";
+for v in walk([[1, 0, 2], [3, -1, 4]]) { out += f"{v} "; }
+out
+"#;
+    let (value, _) = run(src);
+    assert_eq!(value, string("1 2 100 999 3 "));
+}
+
+#[test]
+fn yield_outside_a_function_is_reported() {
+    let (_, diags) = run("yield 1;");
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.contains("'yield' outside of a generator function")),
+        "{:?}",
+        diags
+    );
+}
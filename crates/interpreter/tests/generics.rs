@@ -0,0 +1,102 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn run(src: &str) -> (Interpreter, Vec<diagnostics::Diagnostic>) {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    let diags = interp.take_diagnostics();
+    (interp, diags)
+}
+
+fn messages(src: &str) -> Vec<String> {
+    run(src).1.into_iter().map(|d| d.message).collect()
+}
+
+#[test]
+fn bound_violation_rejects_the_call_at_its_span() {
+    let src = "func foo<T: Numeric>(x: T) -> T { return x + x; }\nlet r = foo(\"a\");\nr";
+    let (interp, diags) = run(src);
+    assert_eq!(diags.len(), 1, "{:?}", diags);
+    assert_eq!(
+        diags[0].message,
+        "Type 'String' does not satisfy constraint 'Numeric' for type parameter 'T'"
+    );
+    assert_eq!((diags[0].span.line, diags[0].span.col), (2, 9));
+    // O corpo não roda: a chamada avalia para `none`.
+    assert_eq!(interp.last_value, Some(ArtValue::none()));
+}
+
+#[test]
+fn type_params_are_inferred_from_arguments() {
+    let src = "func foo<T: Numeric>(x: T) -> T { return x + x; }\nfunc first<T>(xs: [T]) -> T { return xs[0]; }\nfoo(21) + first([1, 2])";
+    let (interp, diags) = run(src);
+    assert!(diags.is_empty(), "{:?}", diags);
+    assert_eq!(interp.last_value, Some(ArtValue::Int(43)));
+}
+
+#[test]
+fn one_specialization_is_cached_per_type_tuple() {
+    let src = r#"
+func id<T>(x: T) -> T { return x; }
+id(1);
+id(2);
+id("a");
+id::<Float>(1.5);
+"#;
+    let (interp, diags) = run(src);
+    assert!(diags.is_empty(), "{:?}", diags);
+    assert_eq!(
+        interp.specializations(),
+        vec![
+            ("id".to_string(), vec!["Float".to_string()]),
+            ("id".to_string(), vec!["Int".to_string()]),
+            ("id".to_string(), vec!["String".to_string()]),
+        ]
+    );
+}
+
+#[test]
+fn arguments_are_checked_against_the_instantiated_signature() {
+    assert_eq!(
+        messages("func id<T>(x: T) -> T { return x; }\nid::<Int>(\"a\");"),
+        vec!["Argument 'x' of 'id' expects Int, found String"]
+    );
+    assert_eq!(
+        messages("func sum<T>(xs: [T]) -> Int { return 0; }\nsum::<Int>([1, \"b\"]);"),
+        vec!["Argument 'xs' of 'sum' expects [Int], found Array"]
+    );
+    assert_eq!(
+        messages("func pair<T>(a: T, b: T) { }\npair(1, \"a\");"),
+        vec!["Conflicting types for type parameter 'T' in call to 'pair': Int and String"]
+    );
+    assert_eq!(
+        messages("func id<T>(x: T) -> T { return x; }\nid::<Int, Int>(1);"),
+        vec!["Expected 1 type argument(s) for 'id', found 2"]
+    );
+}
+
+#[test]
+fn struct_arguments_satisfy_trait_bounds() {
+    let src = r#"
+trait Named { func name(self) -> String }
+struct Cat { n: String }
+impl Named for Cat { func name(self) -> String { return self.n; } }
+func greet<T: Named>(x: T) -> String { return "hi " + x.name(); }
+let c = Cat { n: "tom" };
+greet(c)
+"#;
+    let (interp, diags) = run(src);
+    assert!(diags.is_empty(), "{:?}", diags);
+    assert_eq!(
+        interp.last_value,
+        Some(ArtValue::String(std::sync::Arc::from("hi tom")))
+    );
+}
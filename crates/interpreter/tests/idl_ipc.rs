@@ -1,4 +1,4 @@
-use core::ast::ArtValue;
+use core::ast::{ArtValue, MapKey};
 use interpreter::Interpreter;
 use lexer::Lexer;
 use parser::Parser;
@@ -33,8 +33,14 @@ fn idl_schema_returns_struct_field_map() {
     };
     let map = map_ref.0.lock().unwrap_or_else(|e| e.into_inner());
 
-    assert_eq!(map.get("service"), Some(&ArtValue::String("String".into())));
-    assert_eq!(map.get("retries"), Some(&ArtValue::String("Int".into())));
+    assert_eq!(
+        map.get(&MapKey::from("service")),
+        Some(&ArtValue::String("String".into()))
+    );
+    assert_eq!(
+        map.get(&MapKey::from("retries")),
+        Some(&ArtValue::String("Int".into()))
+    );
 
     let diags = interp.take_diagnostics();
     assert!(
@@ -0,0 +1,199 @@
+use core::ast::ArtValue;
+use diagnostics::DiagnosticKind;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn run(src: &str) -> Interpreter {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    let mut p = Parser::new(tokens);
+    let (program, diags) = p.parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    interp
+}
+
+fn run_value(src: &str) -> Option<ArtValue> {
+    let mut interp = run(src);
+    let diags = interp.take_diagnostics();
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    interp.last_value
+}
+
+fn string(s: &str) -> Option<ArtValue> {
+    Some(ArtValue::String(s.into()))
+}
+
+#[test]
+fn array_index_and_negative_index() {
+    assert_eq!(
+        run_value("let a = [10, 20, 30];\na[0] + a[-1]"),
+        Some(ArtValue::Int(40))
+    );
+}
+
+#[test]
+fn array_index_assignment_and_compound() {
+    let src = r#"
+let a = [1, 2, 3];
+a[0] = 10;
+a[-1] += 5;
+a[0] + a[1] + a[2]
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(20)));
+}
+
+#[test]
+fn compound_index_assignment_evaluates_target_once() {
+    let src = r#"
+var calls = 0;
+var rows = 0;
+func bump() {
+    calls += 1;
+    return calls - 1;
+}
+let a = [1, 2, 3];
+a[bump()] += 10;
+let grid = [[1, 2], [3, 4]];
+func row() {
+    rows += 1;
+    return grid[1];
+}
+row()[bump()] *= 5;
+calls * 100000 + rows * 10000 + a[0] * 100 + grid[1][1]
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(211120)));
+}
+
+#[test]
+fn nested_array_assignment() {
+    let src = r#"
+let grid = [[1, 2], [3, 4]];
+grid[1][0] = 30;
+grid[1][0]
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(30)));
+}
+
+#[test]
+fn array_slice_copies_range() {
+    let src = r#"
+let a = [1, 2, 3, 4, 5];
+let s = a[1..4];
+s[0] = 100;
+len(s) * 1000 + a[1]
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(3002)));
+    assert_eq!(
+        run_value("let a = [1, 2, 3, 4];\nlen(a[..2]) + len(a[-1..])"),
+        Some(ArtValue::Int(3))
+    );
+}
+
+#[test]
+fn tuple_index() {
+    assert_eq!(run_value("let t = (1, \"x\", true);\nt[1]"), string("x"));
+    assert_eq!(
+        run_value("let t = (1, \"x\", true);\nt[-1]"),
+        Some(ArtValue::Bool(true))
+    );
+}
+
+#[test]
+fn string_index_and_slice_by_character() {
+    assert_eq!(run_value("let s = \"héllo\";\ns[1]"), string("é"));
+    assert_eq!(run_value("let s = \"héllo\";\ns[1..4]"), string("éll"));
+    assert_eq!(run_value("let s = \"héllo\";\ns[-3..]"), string("llo"));
+    assert_eq!(
+        run_value("let s = \"héllo\";\nlen(s)"),
+        Some(ArtValue::Int(5))
+    );
+}
+
+#[test]
+fn map_index_read_and_insert() {
+    let src = r#"
+let m = map_new();
+m["a"] = 1;
+m["a"] += 41;
+m["a"]
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(42)));
+}
+
+#[test]
+fn deque_index_and_assignment() {
+    let src = r#"
+let d = deque_new();
+deque_push_back(d, 1);
+deque_push_back(d, 2);
+d[-1] = 9;
+d[0] + d[1]
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(10)));
+}
+
+#[test]
+fn buffer_index_assignment_writes_back() {
+    let src = r#"
+var b = buffer_new(4);
+b[1] = 255;
+b[1] + len(b[2..])
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(257)));
+}
+
+fn runtime_messages(src: &str) -> Vec<(String, usize, usize)> {
+    run(src)
+        .take_diagnostics()
+        .into_iter()
+        .filter(|d| d.kind == DiagnosticKind::Runtime)
+        .map(|d| (d.message, d.span.line, d.span.col))
+        .collect()
+}
+
+#[test]
+fn out_of_range_index_reports_runtime_diagnostic_at_bracket() {
+    let diags = runtime_messages("let a = [1, 2];\nlet x = a[5];");
+    assert_eq!(diags.len(), 1, "{:?}", diags);
+    assert!(diags[0].0.contains("Index 5 out of range for length 2"));
+    assert_eq!((diags[0].1, diags[0].2), (2, 10));
+}
+
+#[test]
+fn invalid_index_operations_report_diagnostics() {
+    let cases = [
+        (
+            "let m = map_new();\nlet x = m[\"missing\"];",
+            "Key 'missing' not found",
+        ),
+        ("let a = [1];\nlet x = a[\"0\"];", "Index must be an Int"),
+        (
+            "let s = \"abc\";\nlet x = s[2..1];",
+            "Slice start 2 is greater than end 1",
+        ),
+        (
+            "let s = \"abc\";\ns[0] = \"x\";",
+            "String values are immutable",
+        ),
+        (
+            "let n = 3;\nlet x = n[0];",
+            "Cannot index into a value of type Int",
+        ),
+    ];
+    for (src, expected) in cases {
+        let diags = runtime_messages(src);
+        assert!(
+            diags.iter().any(|(m, _, _)| m.contains(expected)),
+            "{}: {:?}",
+            src,
+            diags
+        );
+    }
+}
@@ -30,12 +30,14 @@ fn integration_example_14_finalizer_examples() {
                         statements: vec![core::ast::Stmt::Let {
                             pattern: core::ast::MatchPattern::Variable(core::Token::dummy("saved")),
                             ty: None,
+                            mutable: false,
                             initializer: core::ast::Expr::Variable {
                                 name: core::Token::dummy("owner"),
                             },
                         }],
                     }),
                     method_owner: None,
+                    public: false,
                 },
                 core::ast::Stmt::Expression(core::ast::Expr::Call {
                     type_args: None,
@@ -92,12 +94,14 @@ fn integration_example_14_finalizer_examples() {
                                 "promoted"
                             )),
                             ty: None,
+                            mutable: false,
                             initializer: core::ast::Expr::Variable {
                                 name: core::Token::dummy("outside"),
                             },
                         }],
                     }),
                     method_owner: None,
+                    public: false,
                 },
                 core::ast::Stmt::Expression(core::ast::Expr::Call {
                     type_args: None,
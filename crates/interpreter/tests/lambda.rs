@@ -0,0 +1,100 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn run_value(src: &str) -> Option<ArtValue> {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    let mut p = Parser::new(tokens);
+    let (program, diags) = p.parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    let diags = interp.take_diagnostics();
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    interp.last_value
+}
+
+#[test]
+fn expression_lambda_is_callable() {
+    assert_eq!(
+        run_value("let double = |x| x * 2;\ndouble(21)"),
+        Some(ArtValue::Int(42))
+    );
+    assert_eq!(run_value("(|a, b| a - b)(10, 3)"), Some(ArtValue::Int(7)));
+}
+
+#[test]
+fn func_lambda_with_block_body() {
+    let src = r#"
+let clamp = func(x: Int) -> Int {
+    if x > 10 { return 10; }
+    return x;
+};
+clamp(3) + clamp(99)
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(13)));
+}
+
+#[test]
+fn returned_lambda_keeps_captured_scope() {
+    let src = r#"
+func make_adder(n) {
+    return |x| x + n;
+}
+let add5 = make_adder(5);
+let add7 = make_adder(7);
+add5(1) * 100 + add7(1)
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(608)));
+}
+
+#[test]
+fn lambda_mutates_captured_var() {
+    let src = r#"
+var total = 0;
+let bump = |by| { total += by; };
+bump(2);
+bump(3);
+total
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(5)));
+}
+
+#[test]
+fn lambda_passed_as_callback() {
+    let src = r#"
+func apply_twice(f, v) {
+    return f(f(v));
+}
+apply_twice(|s| s + "!", "hi")
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::String("hi!!".into())));
+}
+
+#[test]
+fn lambda_captures_loop_variable_per_iteration() {
+    let src = r#"
+var sum = 0;
+for i in [1, 2, 3] {
+    let times = |x| x * i;
+    sum += times(10);
+}
+sum
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(60)));
+}
+
+#[test]
+fn lambdas_in_stream_pipeline() {
+    let src = r#"
+let xs = [1, 2, 3, 4, 5] |> stream |> map(|x| x * 10) |> filter(|x| x > 20) |> collect;
+len(xs) * 1000 + xs[0]
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(3030)));
+}
@@ -0,0 +1,108 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn run_value(src: &str) -> Option<ArtValue> {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    let mut p = Parser::new(tokens);
+    let (program, diags) = p.parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    let diags = interp.take_diagnostics();
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    interp.last_value
+}
+
+#[test]
+fn break_and_continue_in_while() {
+    let src = r#"
+var i = 0;
+var sum = 0;
+while true {
+    i += 1;
+    if i == 3 { continue; }
+    if i > 5 { break; }
+    sum += i;
+}
+sum
+"#;
+    // 1 + 2 + 4 + 5
+    assert_eq!(run_value(src), Some(ArtValue::Int(12)));
+}
+
+#[test]
+fn break_and_continue_in_for() {
+    let src = r#"
+var seen = 0;
+for x in [1, 2, 3, 4, 5] {
+    if x == 2 { continue; }
+    if x == 4 { break; }
+    seen += x;
+}
+seen
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(4)));
+}
+
+#[test]
+fn labeled_jumps_target_the_outer_loop() {
+    let src = r#"
+var pairs = 0;
+'outer: for i in [1, 2, 3, 4] {
+    var j = 0;
+    while true {
+        j += 1;
+        if j > i { continue 'outer; }
+        if i == 3 { break 'outer; }
+        pairs += 1;
+    }
+}
+pairs
+"#;
+    // i = 1 -> 1 pair, i = 2 -> 2 pairs, i = 3 stops everything.
+    assert_eq!(run_value(src), Some(ArtValue::Int(3)));
+}
+
+#[test]
+fn break_inside_try_and_iterator_loops() {
+    let src = r#"
+var n = 0;
+func gen() {
+    n += 1;
+    return Option.Some(n);
+}
+var last = 0;
+for x in gen {
+    try {
+        if x == 4 { break; }
+    } catch e {
+        println(e);
+    }
+    last = x;
+}
+last
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(3)));
+}
+
+#[test]
+fn return_inside_loop_still_returns() {
+    let src = r#"
+func first_even(xs) {
+    for x in xs {
+        if x == 0 { continue; }
+        if x / 2 * 2 == x { return x; }
+    }
+    return -1;
+}
+first_even([0, 3, 5, 6, 8])
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(6)));
+}
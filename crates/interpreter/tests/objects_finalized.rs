@@ -12,9 +12,11 @@ fn objects_finalized_increment_on_scope_exit_chain() {
         statements: vec![Stmt::Let {
             pattern: core::ast::MatchPattern::Variable(core::Token::dummy("a")),
             ty: None,
+            mutable: false,
             initializer: Expr::Array(vec![Expr::StructInit {
                 name: core::Token::dummy("Point"),
                 fields: vec![],
+                base: None,
             }]),
         }],
     }];
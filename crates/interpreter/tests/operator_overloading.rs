@@ -0,0 +1,142 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+use std::sync::Arc;
+
+const VEC2: &str = r#"
+struct Vec2 { x: Int, y: Int }
+impl Vec2 {
+    func add(self, o: Vec2) -> Vec2 {
+        let v = Vec2 { x: self.x + o.x, y: self.y + o.y };
+        return v;
+    }
+    func mul(self, k: Int) -> Vec2 {
+        let v = Vec2 { x: self.x * k, y: self.y * k };
+        return v;
+    }
+    func neg(self) -> Vec2 {
+        let v = Vec2 { x: -self.x, y: -self.y };
+        return v;
+    }
+    func eq(self, o: Vec2) -> Bool { return self.x == o.x; }
+    func cmp(self, o: Vec2) -> Int { return self.x - o.x; }
+    func to_string(self) -> String { return f"<{self.x}, {self.y}>"; }
+}
+let a = Vec2 { x: 1, y: 2 };
+let b = Vec2 { x: 3, y: 4 };
+"#;
+
+fn run(src: &str) -> (Option<ArtValue>, Vec<String>) {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    let messages = interp
+        .take_diagnostics()
+        .into_iter()
+        .map(|d| d.message)
+        .collect();
+    (interp.last_value, messages)
+}
+
+fn run_value(src: &str) -> Option<ArtValue> {
+    let (value, diags) = run(src);
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    value
+}
+
+fn string(s: &str) -> Option<ArtValue> {
+    Some(ArtValue::String(Arc::from(s)))
+}
+
+#[test]
+fn arithmetic_operators_call_the_methods() {
+    let src = format!("{}f\"{{a + b}} {{a * 3}} {{-b}} {{a + b + a}}\"", VEC2);
+    assert_eq!(run_value(&src), string("<4, 6> <3, 6> <-3, -4> <5, 8>"));
+}
+
+#[test]
+fn comparisons_use_eq_and_cmp() {
+    let src = format!(
+        "{}let c = Vec2 {{ x: 1, y: 9 }};\nf\"{{a == c}} {{a != c}} {{a == b}} {{a < b}} {{a <= c}} {{b > a}} {{a >= b}}\"",
+        VEC2
+    );
+    assert_eq!(
+        run_value(&src),
+        string("true false false true true true false")
+    );
+}
+
+#[test]
+fn debug_format_and_other_types_are_left_alone() {
+    // `:debug` shows the instance, not `to_string`; numbers keep their operators.
+    let (value, diags) = run(&format!("{}f\"{{a:debug}}\"", VEC2));
+    assert!(diags.is_empty(), "{:?}", diags);
+    let Some(ArtValue::String(s)) = value else {
+        panic!("expected a string");
+    };
+    assert!(!s.contains("<1, 2>"), "got {}", s);
+    assert_eq!(
+        run_value(&format!("{}1 + 2 * 3", VEC2)),
+        Some(ArtValue::Int(7))
+    );
+    // Without `eq`, `==` still compares identity.
+    assert_eq!(
+        run_value(
+            "struct P { x: Int }\nlet p = P { x: 1 };\nlet q = P { x: 1 };\nf\"{p == p} {p == q}\""
+        ),
+        string("true false")
+    );
+}
+
+#[test]
+fn enums_and_trait_defaults_overload_too() {
+    let src = r#"
+trait Ranked {
+    func rank(self) -> Int
+    func cmp(self, o: Level) -> Int { return self.rank() - o.rank(); }
+}
+enum Level { Low, High }
+impl Ranked for Level {
+    func rank(self) -> Int {
+        match self {
+            case .Low: return 0
+            case .High: return 1
+        }
+    }
+}
+Level.High > Level.Low
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Bool(true)));
+}
+
+#[test]
+fn wrong_return_type_is_a_runtime_error() {
+    let (_, diags) = run(
+        "struct P { x: Int }\nimpl P { func cmp(self, o: P) -> String { return \"x\"; } }\nlet p = P { x: 1 };\np < p",
+    );
+    assert_eq!(
+        diags,
+        vec!["Operator '<' expects 'cmp' to return Int, got x."]
+    );
+}
+
+#[test]
+fn struct_arguments_survive_the_call() {
+    let src = "struct V { x: Int }\nfunc get(v: V) -> Int { return v.x; }\nlet v = V { x: 7 };\nget(v) + get(v) + v.x";
+    assert_eq!(run_value(src), Some(ArtValue::Int(21)));
+}
+
+#[test]
+fn temporary_arguments_do_not_leak() {
+    let src = "func noop(v) { return 0; }\nstruct V { x: Int }\nlet v = V { x: 1 };\n\
+               let before = gc_stats().heap_objects;\nvar live = before;\n\
+               for i in 0..10 { noop([i, 2, 3]); noop(v); live = gc_stats().heap_objects; }\n\
+               live - before + v.x";
+    assert_eq!(run_value(src), Some(ArtValue::Int(1)));
+}
@@ -0,0 +1,130 @@
+use core::ast::ArtValue;
+use diagnostics::DiagnosticKind;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn run(src: &str) -> Interpreter {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    let mut p = Parser::new(tokens);
+    let (program, diags) = p.parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    interp
+}
+
+fn run_value(src: &str) -> Option<ArtValue> {
+    let mut interp = run(src);
+    let diags = interp.take_diagnostics();
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    interp.last_value
+}
+
+fn int(n: i64) -> Option<ArtValue> {
+    Some(ArtValue::Int(n))
+}
+
+#[test]
+fn integer_division_truncates_and_remainder_follows_dividend() {
+    assert_eq!(run_value("7 / 2"), int(3));
+    assert_eq!(run_value("-7 / 2"), int(-3));
+    assert_eq!(run_value("-7 % 3"), int(-1));
+    assert_eq!(run_value("7 % -3"), int(1));
+    assert_eq!(run_value("2 + 7 % 4 * 3"), int(11));
+}
+
+#[test]
+fn integer_division_is_exact_for_large_values() {
+    // Going through f64 would round 2^62 + 3 before dividing.
+    assert_eq!(
+        run_value("((1 << 62) + 3) / 2"),
+        int(((1i64 << 62) + 3) / 2)
+    );
+    assert_eq!(run_value("((1 << 62) + 3) % 4"), int(3));
+}
+
+#[test]
+fn large_integer_literals_keep_full_precision() {
+    assert_eq!(
+        run_value("9007199254740993 - 1"),
+        int(9_007_199_254_740_992)
+    );
+    assert_eq!(run_value("0x7FFF_FFFF_FFFF_FFFF"), int(i64::MAX));
+    assert_eq!(run_value("-9_223_372_036_854_775_807 - 1"), int(i64::MIN));
+}
+
+#[test]
+fn float_division_and_remainder() {
+    assert_eq!(run_value("7.5 % 2.0"), Some(ArtValue::Float(1.5)));
+    assert_eq!(run_value("3 / 1.5"), Some(ArtValue::Float(2.0)));
+}
+
+#[test]
+fn bitwise_operators() {
+    assert_eq!(run_value("12 & 10"), int(8));
+    assert_eq!(run_value("12 | 10"), int(14));
+    assert_eq!(run_value("12 ^ 10"), int(6));
+    assert_eq!(run_value("~12"), int(-13));
+    assert_eq!(run_value("1 << 63"), int(i64::MIN));
+    assert_eq!(run_value("-16 >> 2"), int(-4));
+}
+
+#[test]
+fn bitwise_precedence() {
+    // Comparison binds looser than `&`, unlike C.
+    assert_eq!(run_value("6 & 3 == 2"), Some(ArtValue::Bool(true)));
+    // `|` < `^` < `&` < shifts < `+`.
+    assert_eq!(run_value("1 | 2 ^ 3 & 1 << 1 + 1"), int(3));
+}
+
+fn runtime_messages(src: &str) -> Vec<(String, usize, usize)> {
+    run(src)
+        .take_diagnostics()
+        .into_iter()
+        .filter(|d| d.kind == DiagnosticKind::Runtime)
+        .map(|d| (d.message, d.span.line, d.span.col))
+        .collect()
+}
+
+#[test]
+fn division_by_zero_reports_diagnostic_at_operator() {
+    for src in ["let x = 1;\nlet y = x / 0;", "let x = 1;\nlet y = x % 0;"] {
+        let diags = runtime_messages(src);
+        assert_eq!(diags.len(), 1, "{:?}", diags);
+        assert_eq!(diags[0].0, "Division by zero");
+        assert_eq!((diags[0].1, diags[0].2), (2, 11));
+    }
+    let diags = runtime_messages("let y = 1.0 / 0.0;");
+    assert!(diags.iter().any(|(m, _, _)| m == "Division by zero"));
+}
+
+#[test]
+fn invalid_bitwise_operands_report_diagnostics() {
+    let cases = [
+        ("let x = 1 << 64;", "Shift amount 64 out of range 0..=63."),
+        ("let x = 1 >> -1;", "Shift amount -1 out of range 0..=63."),
+        (
+            "let x = 1.5 & 1;",
+            "Operator '&' requires Int operands, got Float and Int.",
+        ),
+        (
+            "let x = ~true;",
+            "Operator '~' requires an Int operand, got Bool.",
+        ),
+    ];
+    for (src, expected) in cases {
+        let diags = runtime_messages(src);
+        assert!(
+            diags.iter().any(|(m, _, _)| m == expected),
+            "{}: {:?}",
+            src,
+            diags
+        );
+    }
+}
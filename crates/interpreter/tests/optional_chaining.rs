@@ -0,0 +1,77 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+use std::sync::Arc;
+
+const USERS: &str = r#"
+struct Address { city: String }
+struct User { name: String, address: Option }
+impl User {
+    func greet(self, greeting: String) -> String { return f"{greeting}, {self.name}"; }
+}
+let addr = Address { city: "Lisboa" };
+let ana = User { name: "Ana", address: Option.Some(addr) };
+let bob = User { name: "Bob", address: Option.None };
+let some_ana = Option.Some(ana);
+let nobody = Option.None;
+"#;
+
+fn run_value(src: &str) -> Option<ArtValue> {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    let diags = interp.take_diagnostics();
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    interp.last_value
+}
+
+fn string(s: &str) -> Option<ArtValue> {
+    Some(ArtValue::String(Arc::from(s)))
+}
+
+#[test]
+fn chains_unwrap_present_values_and_stop_at_absent_ones() {
+    let src = format!(
+        "{}f\"{{some_ana?.name}} {{nobody?.name}} {{some_ana?.address?.city}} {{bob.address?.city}}\"",
+        USERS
+    );
+    assert_eq!(
+        run_value(&src),
+        string("Option.Some(Ana) Option.None Option.Some(Lisboa) Option.None")
+    );
+    // `none` stays `none`; a plain receiver behaves like `.`.
+    assert_eq!(
+        run_value(&format!("{}f\"{{none?.name}} {{ana?.name}}\"", USERS)),
+        string("None Ana")
+    );
+}
+
+#[test]
+fn method_calls_short_circuit_their_arguments() {
+    let src = format!(
+        "{}var calls = 0;\nfunc arg() -> String {{ calls = calls + 1; return \"Oi\"; }}\nlet a = some_ana?.greet(arg()) ?? \"-\";\nlet b = nobody?.greet(arg()) ?? \"-\";\nf\"{{a}} {{b}} {{calls}}\"",
+        USERS
+    );
+    assert_eq!(run_value(&src), string("Oi, Ana - 1"));
+}
+
+#[test]
+fn coalesce_only_evaluates_the_default_when_absent() {
+    let src = "var calls = 0;\nfunc fallback() -> Int { calls = calls + 1; return 9; }\nlet a = Option.Some(1) ?? fallback();\nlet b = none ?? fallback();\nlet c = 3 ?? fallback();\nlet d = none ?? Option.None ?? 7;\nf\"{a} {b} {c} {d} {calls}\"";
+    assert_eq!(run_value(src), string("1 9 3 7 1"));
+}
+
+#[test]
+fn weak_references_are_upgraded_first() {
+    let src = format!(
+        "{}let w = weak ana;\nlet name = w?.name ?? \"gone\";\nlet upgraded = w? ?? ana;\nf\"{{name}} {{upgraded.name}}\"",
+        USERS
+    );
+    assert_eq!(run_value(&src), string("Ana Ana"));
+}
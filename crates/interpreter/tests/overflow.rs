@@ -0,0 +1,126 @@
+use core::OverflowMode;
+use core::ast::ArtValue;
+use diagnostics::DiagnosticKind;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+const MAX: &str = "9223372036854775807";
+const MIN: &str = "(-9223372036854775807 - 1)";
+
+fn run(src: &str, mode: OverflowMode) -> Interpreter {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+
+    let mut interp = Interpreter::with_prelude();
+    interp.set_overflow_mode(mode);
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    interp
+}
+
+fn value(src: &str, mode: OverflowMode) -> Option<ArtValue> {
+    let mut interp = run(src, mode);
+    let diags = interp.take_diagnostics();
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    interp.last_value
+}
+
+fn int(n: i64) -> Option<ArtValue> {
+    Some(ArtValue::Int(n))
+}
+
+#[test]
+fn overflow_traps_by_default() {
+    let cases = [
+        format!("{} + 1", MAX),
+        format!("{} - 1", MIN),
+        format!("{} * 2", MAX),
+        format!("{} / -1", MIN),
+        format!("-{}", MIN),
+        format!("var x = {};\nx += 1;\nx", MAX),
+        format!("[{}, 1].sum", MAX),
+        "math_pow(2, 63)".to_string(),
+    ];
+    for src in &cases {
+        let mut interp = run(src, OverflowMode::Trap);
+        let diags = interp.take_diagnostics();
+        assert!(
+            diags
+                .iter()
+                .any(|d| d.kind == DiagnosticKind::Runtime
+                    && d.message.starts_with("Integer overflow:")),
+            "{}: {:?}",
+            src,
+            diags
+        );
+        assert_eq!(interp.last_value, Some(ArtValue::none()), "{}", src);
+    }
+}
+
+#[test]
+fn wrap_mode_uses_twos_complement() {
+    let wrap = OverflowMode::Wrap;
+    assert_eq!(value(&format!("{} + 1", MAX), wrap), int(i64::MIN));
+    assert_eq!(value(&format!("{} - 1", MIN), wrap), int(i64::MAX));
+    assert_eq!(value(&format!("{} * 2", MAX), wrap), int(-2));
+    assert_eq!(value(&format!("{} / -1", MIN), wrap), int(i64::MIN));
+    assert_eq!(value(&format!("-{}", MIN), wrap), int(i64::MIN));
+    assert_eq!(value(&format!("math_abs({})", MIN), wrap), int(i64::MIN));
+}
+
+#[test]
+fn in_range_results_are_unaffected_by_the_policy() {
+    for mode in [OverflowMode::Trap, OverflowMode::Wrap] {
+        assert_eq!(value(&format!("{} - 1 + 1", MAX), mode), int(i64::MAX));
+        assert_eq!(value(&format!("{} % -1", MIN), mode), int(0));
+        assert_eq!(
+            value("1.5e308 * 10.0 > 0.0", mode),
+            Some(ArtValue::Bool(true))
+        );
+    }
+}
+
+#[test]
+fn explicit_arithmetic_builtins_ignore_the_policy() {
+    for mode in [OverflowMode::Trap, OverflowMode::Wrap] {
+        assert_eq!(
+            value(&format!("wrapping_add({}, 1)", MAX), mode),
+            int(i64::MIN)
+        );
+        assert_eq!(value(&format!("wrapping_mul({}, 2)", MAX), mode), int(-2));
+        assert_eq!(
+            value(&format!("saturating_add({}, 1)", MAX), mode),
+            int(i64::MAX)
+        );
+        assert_eq!(
+            value(&format!("saturating_sub({}, 1)", MIN), mode),
+            int(i64::MIN)
+        );
+        assert_eq!(
+            value(&format!("saturating_mul({}, -2)", MAX), mode),
+            int(i64::MIN)
+        );
+        assert_eq!(value("checked_mul(6, 7).unwrap_or(-1)", mode), int(42));
+        assert_eq!(
+            value(&format!("checked_sub({}, 1).is_none()", MIN), mode),
+            Some(ArtValue::Bool(true))
+        );
+    }
+}
+
+#[test]
+fn arithmetic_builtins_require_two_ints() {
+    let mut interp = run("wrapping_add(1, 2.0)", OverflowMode::Trap);
+    let diags = interp.take_diagnostics();
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.message == "wrapping_add expects two Int arguments."),
+        "{:?}",
+        diags
+    );
+}
@@ -19,14 +19,17 @@ fn candidate_owner_edges_detects_parent_field() {
         Stmt::Let {
             pattern: core::ast::MatchPattern::Variable(core::Token::dummy("p")),
             ty: None,
+            mutable: false,
             initializer: Expr::StructInit {
                 name: core::Token::dummy("Parent"),
                 fields: vec![],
+                base: None,
             },
         },
         Stmt::Let {
             pattern: core::ast::MatchPattern::Variable(core::Token::dummy("c")),
             ty: None,
+            mutable: false,
             initializer: Expr::StructInit {
                 name: core::Token::dummy("Child"),
                 fields: vec![(
@@ -35,6 +38,7 @@ fn candidate_owner_edges_detects_parent_field() {
                         name: core::Token::dummy("p"),
                     },
                 )],
+                base: None,
             },
         },
     ];
@@ -0,0 +1,129 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn run(src: &str) -> (Option<ArtValue>, Vec<String>) {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    let mut p = Parser::new(tokens);
+    let (program, diags) = p.parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    let diags = interp
+        .take_diagnostics()
+        .into_iter()
+        .map(|d| d.message)
+        .collect();
+    (interp.last_value, diags)
+}
+
+fn run_value(src: &str) -> Option<ArtValue> {
+    let (value, diags) = run(src);
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    value
+}
+
+fn string(s: &str) -> Option<ArtValue> {
+    Some(ArtValue::String(std::sync::Arc::from(s)))
+}
+
+#[test]
+fn struct_patterns_match_fields() {
+    let prelude = r#"
+struct Point { x: Int, y: Int }
+func describe(p) {
+    match p {
+        case Point { x: 0, y }: return f"y={y}";
+        case Point { x, y: 0 }: return f"x={x}";
+        case Point { x, y }: return f"{x},{y}";
+    }
+}
+"#;
+    for (point, expected) in [("0, y: 5", "y=5"), ("3, y: 0", "x=3"), ("1, y: 2", "1,2")] {
+        let src = format!("{}let p = Point {{ x: {} }};\ndescribe(p);", prelude, point);
+        assert_eq!(run_value(&src), string(expected), "{}", point);
+    }
+}
+
+#[test]
+fn array_patterns_bind_prefix_rest_and_suffix() {
+    let prelude = r#"
+func shape(xs) {
+    match xs {
+        case []: return "vazio";
+        case [only]: return f"um {only}";
+        case [first, ..rest, last]: return f"{first} {len(rest)} {last}";
+    }
+}
+"#;
+    for (xs, expected) in [("[]", "vazio"), ("[9]", "um 9"), ("[1, 2, 3, 4]", "1 2 4")] {
+        let src = format!("{}shape({});", prelude, xs);
+        assert_eq!(run_value(&src), string(expected), "{}", xs);
+    }
+    // `let` também desestrutura arrays.
+    assert_eq!(
+        run_value("let [a, ..r] = [1, 2, 3];\na + len(r);"),
+        Some(ArtValue::Int(3))
+    );
+}
+
+#[test]
+fn or_range_and_at_patterns() {
+    let prelude = r#"
+func grade(n) {
+    match n {
+        case 0 | 1: return "pouco";
+        case small @ 2..=9: return f"digito {small}";
+        case -5..0: return "negativo";
+        case _: return "grande";
+    }
+}
+"#;
+    for (n, expected) in [
+        ("1", "pouco"),
+        ("9", "digito 9"),
+        ("-3", "negativo"),
+        ("0 - 5", "negativo"),
+        ("10", "grande"),
+    ] {
+        let src = format!("{}grade({});", prelude, n);
+        assert_eq!(run_value(&src), string(expected), "{}", n);
+    }
+}
+
+#[test]
+fn or_pattern_binds_from_the_matching_alternative() {
+    let src = r#"
+func pick(t) {
+    match t {
+        case (0, x) | (x, 0): return x;
+        case _: return -1;
+    }
+}
+pick((0, 4)) * 100 + pick((6, 0)) * 10 + pick((1, 1));
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(459)));
+}
+
+#[test]
+fn struct_pattern_with_unknown_field_reports() {
+    let src = r#"
+struct Point { x: Int, y: Int }
+let p = Point { x: 1, y: 2 };
+match p { case Point { z }: println(z) }
+"#;
+    let (_, diags) = run(src);
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.contains("Struct 'Point' has no field 'z' in pattern")),
+        "{:?}",
+        diags
+    );
+}
@@ -0,0 +1,115 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn run(src: &str) -> (Option<ArtValue>, Vec<String>) {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    let mut p = Parser::new(tokens);
+    let (program, diags) = p.parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    let diags = interp
+        .take_diagnostics()
+        .into_iter()
+        .map(|d| d.message)
+        .collect();
+    (interp.last_value, diags)
+}
+
+fn run_value(src: &str) -> Option<ArtValue> {
+    let (value, diags) = run(src);
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    value
+}
+
+fn string(s: &str) -> Option<ArtValue> {
+    Some(ArtValue::String(std::sync::Arc::from(s)))
+}
+
+#[test]
+fn for_over_exclusive_and_inclusive_ranges() {
+    let src = r#"
+var sum = 0;
+for i in 0..4 { sum += i; }
+for i in 1..=3 { sum += i * 10; }
+for i in 5..5 { sum += 1000; }
+sum
+"#;
+    // 0+1+2+3 + 10+20+30; `5..5` is empty
+    assert_eq!(run_value(src), Some(ArtValue::Int(66)));
+}
+
+#[test]
+fn range_is_a_lazy_value() {
+    let src = r#"
+let r = 2..=1000000000000;
+var n = 0;
+for i in r {
+    if i > 4 { break; }
+    n += i;
+}
+n
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(2 + 3 + 4)));
+    assert_eq!(run_value("f\"{0..3} {1..=2}\""), string("0..3 1..=2"));
+}
+
+#[test]
+fn range_bounds_must_be_int() {
+    let (value, diags) = run("let r = 0..\"x\";\nr");
+    assert_eq!(value, Some(ArtValue::none()));
+    assert_eq!(
+        diags,
+        vec!["Range bounds must be Int, found Int and String."]
+    );
+}
+
+#[test]
+fn for_over_map_yields_sorted_key_value_tuples() {
+    let src = r#"
+let m = map_new();
+map_set(m, "b", 2);
+map_set(m, "a", 1);
+var out = "";
+for (k, v) in m { out = out + f"{k}={v};"; }
+for entry in m { out = out + f"{entry}"; }
+out
+"#;
+    assert_eq!(run_value(src), string("a=1;b=2;(a, 1)(b, 2)"));
+}
+
+#[test]
+fn for_over_set_deque_string_and_buffer() {
+    let src = r#"
+let s = set_new();
+set_add(s, 3);
+set_add(s, 1);
+let d = deque_new();
+deque_push_back(d, 5);
+deque_push_front(d, 4);
+var out = "";
+for x in s { out = out + f"{x}"; }
+for x in d { out = out + f"{x}"; }
+for c in "hé" { out = out + c + "|"; }
+for b in buffer_new(2) { out = out + f"{b}"; }
+out
+"#;
+    assert_eq!(run_value(src), string("3145h|é|00"));
+}
+
+#[test]
+fn for_destructures_array_of_tuples() {
+    let src = r#"
+var sum = 0;
+for (a, b) in [(1, 2), (3, 4)] { sum += a * b; }
+sum
+"#;
+    assert_eq!(run_value(src), Some(ArtValue::Int(14)));
+}
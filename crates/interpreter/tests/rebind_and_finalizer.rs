@@ -16,16 +16,19 @@ fn rebind_decrements_strong_and_runs_finalizer() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("flag2")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Literal(core::ast::ArtValue::Int(42)),
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         Stmt::Block {
             statements: vec![
                 Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("x")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Array(vec![Expr::Literal(core::ast::ArtValue::Int(1))]),
                 },
                 // registrar finalizer
@@ -47,6 +50,7 @@ fn rebind_decrements_strong_and_runs_finalizer() {
                 Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("x")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Array(vec![]),
                 },
             ],
@@ -1,4 +1,4 @@
-use core::ast::ArtValue;
+use core::ast::{ArtValue, MapKey};
 use interpreter::Interpreter;
 use lexer::Lexer;
 use parser::Parser;
@@ -69,7 +69,7 @@ fn test_serialize_complex() {
     let map_val = inter.resolve_composite(&map_val_ref).clone();
     if let ArtValue::Map(map_ref) = map_val {
         let m = map_ref.0.lock().unwrap();
-        assert_eq!(m.get("key"), Some(&ArtValue::Int(99)));
+        assert_eq!(m.get(&MapKey::from("key")), Some(&ArtValue::Int(99)));
     } else {
         panic!("Expected Map");
     }
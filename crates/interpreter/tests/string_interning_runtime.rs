@@ -9,6 +9,7 @@ fn type_of_reuses_interned_runtime_string() {
         Stmt::Let {
             pattern: MatchPattern::Variable(core::Token::dummy("a")),
             ty: None,
+            mutable: false,
             initializer: Expr::Call {
                 type_args: None,
                 callee: Box::new(Expr::Variable {
@@ -20,6 +21,7 @@ fn type_of_reuses_interned_runtime_string() {
         Stmt::Let {
             pattern: MatchPattern::Variable(core::Token::dummy("b")),
             ty: None,
+            mutable: false,
             initializer: Expr::Call {
                 type_args: None,
                 callee: Box::new(Expr::Variable {
@@ -0,0 +1,115 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+use std::sync::Arc;
+
+fn run(src: &str) -> Interpreter {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    interp
+}
+
+fn value(src: &str) -> Option<ArtValue> {
+    let mut interp = run(src);
+    let diags = interp.take_diagnostics();
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    interp.last_value
+}
+
+fn string(s: &str) -> Option<ArtValue> {
+    Some(ArtValue::String(Arc::from(s)))
+}
+
+const CONFIG: &str =
+    "struct Config { host: String = \"localhost\", port: Int = 80, debug: Bool = false }\n";
+
+#[test]
+fn omitted_fields_take_their_defaults() {
+    let src = format!(
+        "{}let c = Config {{ port: 8080 }};\nf\"{{c.host}}:{{c.port}} {{c.debug}}\"",
+        CONFIG
+    );
+    assert_eq!(value(&src), string("localhost:8080 false"));
+}
+
+#[test]
+fn update_syntax_copies_the_unwritten_fields() {
+    let src = format!(
+        "{}let base = Config {{ host: \"db\", debug: true }};\n\
+         let c = Config {{ port: 5432, ..base }};\n\
+         f\"{{c.host}}:{{c.port}} {{c.debug}} {{base.port}}\"",
+        CONFIG
+    );
+    assert_eq!(value(&src), string("db:5432 true 80"));
+}
+
+#[test]
+fn update_base_must_be_the_same_struct() {
+    let src = format!(
+        "{}struct Other {{ port: Int }}\nlet o = Other {{ port: 1 }};\nlet c = Config {{ ..o }};",
+        CONFIG
+    );
+    let mut interp = run(&src);
+    let diags = interp.take_diagnostics();
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.message == "Update base of 'Config' must be a 'Config', got Other."),
+        "{:?}",
+        diags
+    );
+}
+
+#[test]
+fn tuple_structs_are_built_positionally_and_read_with_dot_index() {
+    let src = "struct Rgb(Int, Int, Int)\nlet red = Rgb(255, 10, 0);\nlet t = ((1, 2), 3);\n\
+               f\"{red.0} {red.1} {red.2} {t.0.1} {t.1}\"";
+    assert_eq!(value(src), string("255 10 0 2 3"));
+
+    let mut interp = run("struct Rgb(Int, Int, Int)\nlet c = Rgb(1, 2);");
+    let diags = interp.take_diagnostics();
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.message == "Tuple struct 'Rgb' takes 3 argument(s), got 2."),
+        "{:?}",
+        diags
+    );
+}
+
+#[test]
+fn tuple_structs_round_trip_through_serialize_and_idl() {
+    let src = "struct Rgb(Int, Int, Int)\nlet back = deserialize(serialize(Rgb(1, 2, 3)));\n\
+               let ok = idl_validate(back, \"Rgb\");\n\
+               let last = map_get(idl_schema(\"Rgb\"), \"2\");\n\
+               f\"{back} {ok} {last}\"";
+    assert_eq!(value(src), string("Rgb(1, 2, 3) true Some(Int)"));
+}
+
+#[test]
+fn deserialize_fills_defaults_missing_from_older_messages() {
+    // A mensagem foi gravada quando `Config` só tinha `port`.
+    let old = "struct Config { port: Int }\nlet m = Config { port: 9 };\nserialize(m)";
+    let Some(buf) = value(old) else {
+        panic!("serialize returned nothing");
+    };
+    let mut interp = run(CONFIG);
+    interp.debug_define_global("buf", buf);
+    let tokens = Lexer::new(
+        "let c = deserialize(buf);\nlet ok = idl_validate(c, \"Config\");\nf\"{c.host}:{c.port} {ok}\""
+            .to_string(),
+    )
+    .scan_tokens()
+    .expect("lex ok");
+    let (program, _) = Parser::new(tokens).parse();
+    assert!(interp.interpret(program).is_ok());
+    assert!(interp.take_diagnostics().is_empty());
+    assert_eq!(interp.last_value, string("localhost:9 true"));
+}
@@ -110,7 +110,7 @@ fn test_tracer_writes_checkpoint_event() {
     if let core::ast::ArtValue::Map(m) = payload {
         let map = m.0.lock().unwrap();
         assert!(
-            map.get("rng_state").is_some(),
+            map.get(&core::ast::MapKey::from("rng_state")).is_some(),
             "checkpoint deve armazenar rng_state"
         );
     } else {
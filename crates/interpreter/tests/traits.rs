@@ -0,0 +1,120 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+use std::sync::Arc;
+
+const SHOW: &str = r#"
+trait Show {
+    func show(self) -> String
+    func shout(self) -> String {
+        return self.show() + "!";
+    }
+}
+struct Point { x: Int, y: Int }
+enum Color { Red, Green }
+impl Show for Point {
+    func show(self) -> String { return f"({self.x}, {self.y})"; }
+}
+impl Show for Color {
+    func show(self) -> String {
+        match self {
+            case .Red: return "red"
+            case .Green: return "green"
+        }
+    }
+    func shout(self) -> String { return "COLOR"; }
+}
+let p = Point { x: 1, y: 2 };
+"#;
+
+fn run(src: &str) -> (Option<ArtValue>, Vec<String>) {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    let messages = interp
+        .take_diagnostics()
+        .into_iter()
+        .map(|d| d.message)
+        .collect();
+    (interp.last_value, messages)
+}
+
+fn run_value(src: &str) -> Option<ArtValue> {
+    let (value, diags) = run(src);
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    value
+}
+
+fn string(s: &str) -> Option<ArtValue> {
+    Some(ArtValue::String(Arc::from(s)))
+}
+
+#[test]
+fn impl_methods_dispatch_on_the_runtime_type() {
+    let src = format!(
+        "{}var out = \"\";\nfor it in [p, Color.Green] {{ out = out + it.show() + \" \"; }}\nout",
+        SHOW
+    );
+    assert_eq!(run_value(&src), string("(1, 2) green "));
+}
+
+#[test]
+fn default_method_is_used_unless_overridden() {
+    assert_eq!(run_value(&format!("{}p.shout()", SHOW)), string("(1, 2)!"));
+    assert_eq!(
+        run_value(&format!("{}Color.Red.shout()", SHOW)),
+        string("COLOR")
+    );
+}
+
+#[test]
+fn generic_bound_accepts_implementors() {
+    let src = format!(
+        "{}func display<T: Show>(v: T) -> String {{ return v.shout(); }}\ndisplay(p) + display(Color.Red)",
+        SHOW
+    );
+    assert_eq!(run_value(&src), string("(1, 2)!COLOR"));
+    // Os bounds embutidos continuam valendo para os primitivos.
+    assert_eq!(
+        run_value("func twice<T: Numeric>(a: T) -> T { return a + a; }\ntwice(21)"),
+        Some(ArtValue::Int(42))
+    );
+}
+
+#[test]
+fn generic_bound_rejects_types_without_impl() {
+    let src = format!(
+        "{}struct Other {{ v: Int }}\nlet o = Other {{ v: 1 }};\nfunc display<T: Show>(v: T) -> String {{ return \"x\"; }}\ndisplay(o)",
+        SHOW
+    );
+    let (_, diags) = run(&src);
+    assert_eq!(
+        diags,
+        vec!["Type 'Other' does not satisfy constraint 'Show' for type parameter 'T'".to_string()]
+    );
+}
+
+#[test]
+fn incomplete_or_unknown_impls_are_reported() {
+    let src = r#"
+trait Show { func show(self) -> String }
+struct Point { x: Int }
+impl Show for Point { func shw(self) -> String { return "p"; } }
+impl Debug for Point { }
+"#;
+    let (_, diags) = run(src);
+    assert_eq!(
+        diags,
+        vec![
+            "Type 'Point' does not implement method 'show' required by trait 'Show'.".to_string(),
+            "Method 'shw' is not a member of trait 'Show'.".to_string(),
+            "Unknown trait 'Debug'.".to_string(),
+        ]
+    );
+}
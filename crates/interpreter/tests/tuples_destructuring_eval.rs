@@ -14,6 +14,7 @@ fn tuple_destructuring_assignment() {
             MatchPattern::Variable(core::Token::dummy("b")),
         ]),
         ty: None,
+        mutable: false,
         initializer: Expr::Tuple(vec![
             Expr::Literal(ArtValue::Int(1)),
             Expr::Literal(ArtValue::Int(2)),
@@ -50,6 +51,7 @@ fn nested_tuple_destructuring_assignment() {
             ]),
         ]),
         ty: None,
+        mutable: false,
         initializer: Expr::Tuple(vec![
             Expr::Literal(ArtValue::Int(1)),
             Expr::Tuple(vec![
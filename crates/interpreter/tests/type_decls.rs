@@ -0,0 +1,54 @@
+use core::ast::ArtValue;
+use interpreter::interpreter::Interpreter;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn run(src: &str) -> Interpreter {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let mut interp = Interpreter::with_prelude();
+    assert!(
+        interp.interpret(program).is_ok(),
+        "interpreter should not fail"
+    );
+    interp
+}
+
+fn value(src: &str) -> Option<ArtValue> {
+    let mut interp = run(src);
+    let diags = interp.take_diagnostics();
+    assert!(diags.is_empty(), "runtime diagnostics: {:?}", diags);
+    interp.last_value
+}
+
+#[test]
+fn newtype_values_are_their_representation() {
+    let src = "newtype Meters(Float)\nlet d = Meters(1.5) + Meters(2.0);\nd as Float";
+    assert_eq!(value(src), Some(ArtValue::Float(3.5)));
+    assert_eq!(
+        value("newtype Id(Int)\nlet ids = [Id(1), Id(2)];\nids[1]"),
+        Some(ArtValue::Int(2))
+    );
+}
+
+#[test]
+fn generic_annotations_see_through_aliases_and_newtypes() {
+    let src = "type UserId = Int\nnewtype Score(Int)\n\
+               func tag<T>(x: T, id: UserId, s: Score) { return id + s; }\n\
+               tag(\"a\", 40, Score(2))";
+    assert_eq!(value(src), Some(ArtValue::Int(42)));
+}
+
+#[test]
+fn newtype_wrap_takes_one_argument() {
+    let mut interp = run("newtype Meters(Float)\nMeters(1.0, 2.0)");
+    let diags = interp.take_diagnostics();
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.message == "Newtype 'Meters' takes exactly one argument."),
+        "{:?}",
+        diags
+    );
+}
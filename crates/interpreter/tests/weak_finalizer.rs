@@ -24,10 +24,12 @@ fn finalizer_runs_and_object_stays_while_weak() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("created")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Array(vec![Expr::Literal(ArtValue::Int(2))]),
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         Stmt::Expression(Expr::Call {
             type_args: None,
@@ -142,12 +144,14 @@ fn finalizer_creates_handle_preserved() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("saved")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Variable {
                         name: core::Token::dummy("owner"),
                     },
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         Stmt::Expression(Expr::Call {
             type_args: None,
@@ -215,12 +219,14 @@ fn finalizer_promotes_handles_across_arenas() {
                 statements: vec![Stmt::Let {
                     pattern: core::ast::MatchPattern::Variable(core::Token::dummy("promoted")),
                     ty: None,
+                    mutable: false,
                     initializer: Expr::Variable {
                         name: core::Token::dummy("outside"),
                     },
                 }],
             }),
             method_owner: None,
+            public: false,
         },
         Stmt::Expression(Expr::Call {
             type_args: None,
@@ -39,6 +39,7 @@ fn finalizer_creates_multiple_handles() {
                     Stmt::Let {
                         pattern: core::ast::MatchPattern::Variable(core::Token::dummy("saved1")),
                         ty: None,
+                        mutable: false,
                         initializer: Expr::Variable {
                             name: core::Token::dummy("owner1"),
                         },
@@ -46,6 +47,7 @@ fn finalizer_creates_multiple_handles() {
                     Stmt::Let {
                         pattern: core::ast::MatchPattern::Variable(core::Token::dummy("saved2")),
                         ty: None,
+                        mutable: false,
                         initializer: Expr::Variable {
                             name: core::Token::dummy("owner2"),
                         },
@@ -53,6 +55,7 @@ fn finalizer_creates_multiple_handles() {
                 ],
             }),
             method_owner: None,
+            public: false,
         },
         Stmt::Expression(Expr::Call {
             type_args: None,
@@ -129,6 +132,7 @@ fn finalizer_promotes_multiple_to_root_from_arena() {
                     Stmt::Let {
                         pattern: core::ast::MatchPattern::Variable(core::Token::dummy("p1")),
                         ty: None,
+                        mutable: false,
                         initializer: Expr::Variable {
                             name: core::Token::dummy("outside"),
                         },
@@ -136,6 +140,7 @@ fn finalizer_promotes_multiple_to_root_from_arena() {
                     Stmt::Let {
                         pattern: core::ast::MatchPattern::Variable(core::Token::dummy("p2")),
                         ty: None,
+                        mutable: false,
                         initializer: Expr::Variable {
                             name: core::Token::dummy("outside"),
                         },
@@ -143,6 +148,7 @@ fn finalizer_promotes_multiple_to_root_from_arena() {
                 ],
             }),
             method_owner: None,
+            public: false,
         },
         Stmt::Expression(Expr::Call {
             type_args: None,
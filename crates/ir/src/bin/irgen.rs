@@ -35,6 +35,7 @@ fn build_add() -> (String, String) {
         }),
         method_owner: None,
         is_async: false,
+        public: false,
     };
     let mut irf = lower_stmt(&func).expect("lower failed");
     ssa::rename_temps(&mut irf);
@@ -70,6 +71,7 @@ fn build_sub() -> (String, String) {
         }),
         method_owner: None,
         is_async: false,
+        public: false,
     };
     let mut irf = lower_stmt(&func).expect("lower failed");
     ssa::rename_temps(&mut irf);
@@ -101,6 +103,7 @@ fn build_if() -> (String, String) {
         }),
         method_owner: None,
         is_async: false,
+        public: false,
     };
     let mut irf = lower_stmt(&func).expect("lower failed");
     ssa::rename_temps(&mut irf);
@@ -1,4 +1,5 @@
 use crate::{Function, Instr};
+use core::OverflowMode;
 
 /// C keywords that must not be used as function names.
 const C_KEYWORDS: &[&str] = &[
@@ -54,7 +55,9 @@ fn sanitize_fname(raw: &str) -> String {
     name
 }
 
-pub fn emit_c_program(funcs: &[Function], entry_func: &str) -> String {
+/// Emits a C program for `funcs`. `Int` `+ - * /` follow `overflow`: `Trap`
+/// aborts like a failed guard, `Wrap` computes in two's complement.
+pub fn emit_c_program(funcs: &[Function], entry_func: &str, overflow: OverflowMode) -> String {
     let mut out = String::new();
 
     out.push_str("#include <stdint.h>\n");
@@ -94,7 +97,7 @@ pub fn emit_c_program(funcs: &[Function], entry_func: &str) -> String {
     out.push('\n');
 
     for f in funcs {
-        out.push_str(&emit_c_function(f));
+        out.push_str(&emit_c_function(f, overflow));
         out.push('\n');
     }
 
@@ -114,7 +117,7 @@ pub fn emit_c_program(funcs: &[Function], entry_func: &str) -> String {
     out
 }
 
-fn emit_c_function(f: &Function) -> String {
+fn emit_c_function(f: &Function, overflow: OverflowMode) -> String {
     let mut out = String::new();
 
     let ret_type = match &f.ret {
@@ -147,6 +150,7 @@ fn emit_c_function(f: &Function) -> String {
     out.push_str(") {\n");
 
     let mut locals: std::collections::HashSet<String> = std::collections::HashSet::new();
+    let mut arrays: Vec<(String, usize)> = Vec::new();
     let sanitize = |s: &str| -> String { s.replace("%", "v_").replace(".", "_") };
     let sanitize_lbl = |s: &str| -> String { s.replace(".", "_") };
 
@@ -159,7 +163,13 @@ fn emit_c_function(f: &Function) -> String {
             Instr::Add(dest, _, _)
             | Instr::Sub(dest, _, _)
             | Instr::Mul(dest, _, _)
-            | Instr::Div(dest, _, _) => {
+            | Instr::Div(dest, _, _)
+            | Instr::Rem(dest, _, _)
+            | Instr::And(dest, _, _)
+            | Instr::Or(dest, _, _)
+            | Instr::Xor(dest, _, _)
+            | Instr::Shl(dest, _, _)
+            | Instr::Shr(dest, _, _) => {
                 locals.insert(sanitize(dest));
             }
             Instr::Call(dest, _, _) => {
@@ -174,9 +184,12 @@ fn emit_c_function(f: &Function) -> String {
             Instr::Alloca(slot) => {
                 locals.insert(sanitize(slot));
             }
-            Instr::Load(dest, _) => {
+            Instr::Load(dest, _) | Instr::LoadElem(dest, _, _) => {
                 locals.insert(sanitize(dest));
             }
+            Instr::AllocaArray(slot, len) => {
+                arrays.push((sanitize(slot), *len));
+            }
             _ => {}
         }
     }
@@ -184,6 +197,9 @@ fn emit_c_function(f: &Function) -> String {
     for loc in &locals {
         out.push_str(&format!("    int64_t {} = 0;\n", loc));
     }
+    for (arr, len) in &arrays {
+        out.push_str(&format!("    int64_t {}[{}] = {{0}};\n", arr, len));
+    }
 
     // We use a custom string-based previous block tracker to satisfy SSA Phi semantics trivially
     out.push_str("    const char* _prev_block = \"\";\n");
@@ -206,44 +222,115 @@ fn emit_c_function(f: &Function) -> String {
                 out.push_str(&format!("    _curr_block = \"{}\";\n", s_lbl));
                 out.push_str(&format!("L_{}:\n", s_lbl));
             }
+            // `-9223372036854775808LL` negates a literal that does not fit in
+            // `long long`, so the smallest value is spelled `INT64_MIN`.
+            Instr::ConstI64(dest, i64::MIN) => {
+                out.push_str(&format!("    {} = INT64_MIN;\n", resolve(dest)));
+            }
             Instr::ConstI64(dest, val) => {
                 out.push_str(&format!("    {} = {}LL;\n", resolve(dest), val));
             }
-            Instr::Add(dest, a, b) => {
+            // Signed overflow is undefined in C, so `Trap` uses the checked
+            // builtins (gcc/clang) and `Wrap` goes through uint64_t.
+            Instr::Add(dest, a, b) | Instr::Sub(dest, a, b) | Instr::Mul(dest, a, b) => {
+                let (builtin, op) = match instr {
+                    Instr::Add(..) => ("add", "+"),
+                    Instr::Sub(..) => ("sub", "-"),
+                    _ => ("mul", "*"),
+                };
+                match overflow {
+                    OverflowMode::Trap => out.push_str(&format!(
+                        "    if (__builtin_{}_overflow({}, {}, &{})) {{ printf(\"integer overflow\\n\"); exit(1); }}\n",
+                        builtin,
+                        resolve(a),
+                        resolve(b),
+                        resolve(dest)
+                    )),
+                    OverflowMode::Wrap => out.push_str(&format!(
+                        "    {} = (int64_t)((uint64_t){} {} (uint64_t){});\n",
+                        resolve(dest),
+                        resolve(a),
+                        op,
+                        resolve(b)
+                    )),
+                }
+            }
+            // `INT64_MIN / -1` overflows and `INT64_MIN % -1` traps on x86, so a
+            // `-1` divisor is handled without the hardware instruction.
+            Instr::Div(dest, a, b) => {
                 out.push_str(&format!(
-                    "    {} = {} + {};\n",
-                    resolve(dest),
-                    resolve(a),
+                    "    if ({} == 0) {{ printf(\"div by zero\\n\"); exit(1); }}\n",
                     resolve(b)
                 ));
-            }
-            Instr::Sub(dest, a, b) => {
+                if overflow == OverflowMode::Trap {
+                    out.push_str(&format!(
+                        "    if ({} == INT64_MIN && {} == -1) {{ printf(\"integer overflow\\n\"); exit(1); }}\n",
+                        resolve(a),
+                        resolve(b)
+                    ));
+                }
                 out.push_str(&format!(
-                    "    {} = {} - {};\n",
+                    "    {} = {} == -1 ? (int64_t)(0 - (uint64_t){}) : {} / {};\n",
                     resolve(dest),
+                    resolve(b),
+                    resolve(a),
                     resolve(a),
                     resolve(b)
                 ));
             }
-            Instr::Mul(dest, a, b) => {
+            Instr::Rem(dest, a, b) => {
+                out.push_str(&format!(
+                    "    if ({} == 0) {{ printf(\"div by zero\\n\"); exit(1); }}\n",
+                    resolve(b)
+                ));
                 out.push_str(&format!(
-                    "    {} = {} * {};\n",
+                    "    {} = {} == -1 ? 0 : {} % {};\n",
                     resolve(dest),
+                    resolve(b),
                     resolve(a),
                     resolve(b)
                 ));
             }
-            Instr::Div(dest, a, b) => {
+            Instr::And(dest, a, b) | Instr::Or(dest, a, b) | Instr::Xor(dest, a, b) => {
+                let op = match instr {
+                    Instr::And(..) => "&",
+                    Instr::Or(..) => "|",
+                    _ => "^",
+                };
                 out.push_str(&format!(
-                    "    if ({} == 0) {{ printf(\"div by zero\\n\"); exit(1); }}\n",
+                    "    {} = {} {} {};\n",
+                    resolve(dest),
+                    resolve(a),
+                    op,
                     resolve(b)
                 ));
+            }
+            // Shifting a negative value left is undefined for signed types in C,
+            // so `shl` goes through uint64_t; `>>` on int64_t is arithmetic on
+            // every compiler we target. A count outside `0..=63` is undefined in
+            // C, so it traps like the interpreter does.
+            Instr::Shl(dest, a, b) | Instr::Shr(dest, a, b) => {
                 out.push_str(&format!(
-                    "    {} = {} / {};\n",
-                    resolve(dest),
-                    resolve(a),
+                    "    if ({} < 0 || {} > 63) {{ printf(\"shift out of range\\n\"); exit(1); }}\n",
+                    resolve(b),
                     resolve(b)
                 ));
+                let line = if matches!(instr, Instr::Shl(..)) {
+                    format!(
+                        "    {} = (int64_t)((uint64_t){} << {});\n",
+                        resolve(dest),
+                        resolve(a),
+                        resolve(b)
+                    )
+                } else {
+                    format!(
+                        "    {} = {} >> {};\n",
+                        resolve(dest),
+                        resolve(a),
+                        resolve(b)
+                    )
+                };
+                out.push_str(&line);
             }
             Instr::Call(dest, target, args) => {
                 let args_str = args
@@ -280,6 +367,25 @@ fn emit_c_function(f: &Function) -> String {
             Instr::Store(slot, val) => {
                 out.push_str(&format!("    {} = {};\n", resolve(slot), resolve(val)));
             }
+            Instr::AllocaArray(_, _) => {
+                // Declared with the function-scope locals above.
+            }
+            Instr::LoadElem(dest, slot, idx) => {
+                out.push_str(&format!(
+                    "    {} = {}[{}];\n",
+                    resolve(dest),
+                    resolve(slot),
+                    resolve(idx)
+                ));
+            }
+            Instr::StoreElem(slot, idx, val) => {
+                out.push_str(&format!(
+                    "    {}[{}] = {};\n",
+                    resolve(slot),
+                    resolve(idx),
+                    resolve(val)
+                ));
+            }
             Instr::Br(target) => {
                 out.push_str(&format!("    goto L_{};\n", sanitize_lbl(target)));
             }
@@ -42,11 +42,20 @@ pub enum Instr {
     Sub(String, String, String),
     Mul(String, String, String),
     Div(String, String, String),
+    Rem(String, String, String), // signed remainder (sign of the dividend)
+    And(String, String, String),
+    Or(String, String, String),
+    Xor(String, String, String),
+    Shl(String, String, String),
+    Shr(String, String, String), // arithmetic (sign-propagating) shift
     ICmp(String, CmpPred, String, String), // dest (0/1), pred, a, b
-    Call(String, String, Vec<String>),     // dest, fn, args
-    Alloca(String),                        // slot — stack-allocated i64 cell
-    Load(String, String),                  // dest, slot
-    Store(String, String),                 // slot, value
+    Call(String, String, Vec<String>), // dest, fn, args
+    Alloca(String),              // slot — stack-allocated i64 cell
+    Load(String, String),        // dest, slot
+    Store(String, String),       // slot, value
+    AllocaArray(String, usize),  // slot, len — stack-allocated [len x i64]
+    LoadElem(String, String, String), // dest, array slot, index
+    StoreElem(String, String, String), // array slot, index, value
     Label(String),
     Br(String),
     BrCond(String, String, String), // pred, if_true, if_false
@@ -79,7 +88,7 @@ pub mod trampolines;
 // Keep existing name `lower_stmt` exported; if the module implements fallback
 // we re-export the top-level dispatcher.
 pub use cache::ArtCache;
-pub use lowering::lower_stmt;
+pub use lowering::{lower_stmt, lower_stmt_with_consts};
 pub use trampolines::{Sig, call_jit_fn};
 
 /// Parses the signature out of textual IR: `func @name(params) -> ret`.
@@ -157,6 +166,24 @@ impl Function {
                 Instr::Div(dest, a, b) => {
                     body.push_str(&format!("  {} = div i64 {}, {}\n", dest, a, b))
                 }
+                Instr::Rem(dest, a, b) => {
+                    body.push_str(&format!("  {} = rem i64 {}, {}\n", dest, a, b))
+                }
+                Instr::And(dest, a, b) => {
+                    body.push_str(&format!("  {} = and i64 {}, {}\n", dest, a, b))
+                }
+                Instr::Or(dest, a, b) => {
+                    body.push_str(&format!("  {} = or i64 {}, {}\n", dest, a, b))
+                }
+                Instr::Xor(dest, a, b) => {
+                    body.push_str(&format!("  {} = xor i64 {}, {}\n", dest, a, b))
+                }
+                Instr::Shl(dest, a, b) => {
+                    body.push_str(&format!("  {} = shl i64 {}, {}\n", dest, a, b))
+                }
+                Instr::Shr(dest, a, b) => {
+                    body.push_str(&format!("  {} = shr i64 {}, {}\n", dest, a, b))
+                }
                 Instr::ICmp(dest, pred, a, b) => body.push_str(&format!(
                     "  {} = icmp {} i64 {}, {}\n",
                     dest,
@@ -171,6 +198,15 @@ impl Function {
                 Instr::Store(slot, val) => {
                     body.push_str(&format!("  store i64 {}, {}\n", val, slot))
                 }
+                Instr::AllocaArray(slot, len) => {
+                    body.push_str(&format!("  {} = alloca [{} x i64]\n", slot, len))
+                }
+                Instr::LoadElem(dest, slot, idx) => {
+                    body.push_str(&format!("  {} = load_elem i64 {}, {}\n", dest, slot, idx))
+                }
+                Instr::StoreElem(slot, idx, val) => {
+                    body.push_str(&format!("  store_elem i64 {}, {}, {}\n", val, slot, idx))
+                }
                 Instr::Call(dest, fnname, args) => body.push_str(&format!(
                     "  {} = call {}({})\n",
                     dest,
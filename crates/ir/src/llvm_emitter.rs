@@ -8,8 +8,18 @@
 //! The IR produced by the lowering pass is in SSA form for the currently
 //! supported constructs (arithmetic, calls, `if`, `match`), so temporaries map
 //! 1:1 onto LLVM SSA registers and `Instr::Phi` maps directly onto LLVM `phi`.
+//!
+//! `Int` `+ - *` follow the overflow policy: `Wrap` uses plain `add`/`sub`/
+//! `mul` (two's complement), `Trap` uses the `llvm.s*.with.overflow`
+//! intrinsics and calls `@__art_overflow`, which traps when the flag is set.
+//! Checking through a call keeps the caller's blocks (and its `phi` edges)
+//! untouched. A `-1` divisor never reaches `sdiv`/`srem`, where
+//! `INT64_MIN / -1` is undefined. A zero divisor and a shift count outside
+//! `0..=63` trap through `@__art_check` under both policies, as in the C
+//! backend.
 
 use crate::{Function, Instr, Type};
+use core::OverflowMode;
 use std::collections::HashSet;
 use std::fmt::Write as _;
 
@@ -46,12 +56,40 @@ fn operand(s: &str) -> String {
 /// Emit a complete LLVM IR module for `funcs`. When `entry_func` matches one of
 /// the functions, a C-ABI `main` wrapper is appended that calls it and prints
 /// the result (mirroring the C backend's observable behaviour).
-pub fn emit_llvm_module(funcs: &[Function], entry_func: &str) -> String {
+pub fn emit_llvm_module(funcs: &[Function], entry_func: &str, overflow: OverflowMode) -> String {
     let mut out = String::new();
     out.push_str("; ModuleID = 'artcode'\n");
     out.push_str("@.fmt = private unnamed_addr constant [6 x i8] c\"%lld\\0A\\00\"\n");
     out.push_str("declare i32 @printf(ptr, ...)\n");
-    out.push_str("declare void @llvm.trap()\n\n");
+    out.push_str("declare void @llvm.trap()\n");
+    out.push_str("define internal void @__art_check(i1 %bad) {\n");
+    out.push_str("entry:\n");
+    out.push_str("  br i1 %bad, label %trap, label %ok\n");
+    out.push_str("trap:\n");
+    out.push_str("  call void @llvm.trap()\n");
+    out.push_str("  unreachable\n");
+    out.push_str("ok:\n");
+    out.push_str("  ret void\n");
+    out.push_str("}\n");
+    if overflow == OverflowMode::Trap {
+        for op in ["sadd", "ssub", "smul"] {
+            let _ = writeln!(
+                out,
+                "declare {{ i64, i1 }} @llvm.{}.with.overflow.i64(i64, i64)",
+                op
+            );
+        }
+        out.push_str("define internal void @__art_overflow(i1 %o) {\n");
+        out.push_str("entry:\n");
+        out.push_str("  br i1 %o, label %trap, label %ok\n");
+        out.push_str("trap:\n");
+        out.push_str("  call void @llvm.trap()\n");
+        out.push_str("  unreachable\n");
+        out.push_str("ok:\n");
+        out.push_str("  ret void\n");
+        out.push_str("}\n");
+    }
+    out.push('\n');
 
     // Forward-declare any callee that is not defined in this module.
     let defined: HashSet<String> = funcs.iter().map(|f| sanitize_fname(&f.name)).collect();
@@ -72,7 +110,7 @@ pub fn emit_llvm_module(funcs: &[Function], entry_func: &str) -> String {
     }
 
     for f in funcs {
-        out.push_str(&emit_llvm_function(f));
+        out.push_str(&emit_llvm_function(f, overflow));
         out.push('\n');
     }
 
@@ -90,7 +128,7 @@ pub fn emit_llvm_module(funcs: &[Function], entry_func: &str) -> String {
     out
 }
 
-fn emit_llvm_function(f: &Function) -> String {
+fn emit_llvm_function(f: &Function, overflow: OverflowMode) -> String {
     let mut out = String::new();
     let ret_ty = llvm_type(&f.ret);
     let fname = sanitize_fname(&f.name);
@@ -135,38 +173,121 @@ fn emit_llvm_function(f: &Function) -> String {
                 // SSA register (LLVM has no standalone const-define instruction).
                 let _ = writeln!(out, "  {} = add i64 0, {}", operand(dest), val);
             }
-            Instr::Add(dest, a, b) => {
+            Instr::Add(dest, a, b) | Instr::Sub(dest, a, b) | Instr::Mul(dest, a, b) => {
+                let (op, intrinsic) = match instr {
+                    Instr::Add(..) => ("add", "sadd"),
+                    Instr::Sub(..) => ("sub", "ssub"),
+                    _ => ("mul", "smul"),
+                };
+                match overflow {
+                    OverflowMode::Wrap => {
+                        let _ = writeln!(
+                            out,
+                            "  {} = {} i64 {}, {}",
+                            operand(dest),
+                            op,
+                            operand(a),
+                            operand(b)
+                        );
+                    }
+                    OverflowMode::Trap => {
+                        let pair = format!("%ovf.{}", cmp_id);
+                        cmp_id += 1;
+                        let _ = writeln!(
+                            out,
+                            "  {} = call {{ i64, i1 }} @llvm.{}.with.overflow.i64(i64 {}, i64 {})",
+                            pair,
+                            intrinsic,
+                            operand(a),
+                            operand(b)
+                        );
+                        let _ = writeln!(
+                            out,
+                            "  {} = extractvalue {{ i64, i1 }} {}, 0",
+                            operand(dest),
+                            pair
+                        );
+                        let _ = writeln!(
+                            out,
+                            "  {}.bit = extractvalue {{ i64, i1 }} {}, 1",
+                            pair, pair
+                        );
+                        let _ = writeln!(out, "  call void @__art_overflow(i1 {}.bit)", pair);
+                    }
+                }
+            }
+            Instr::Div(dest, a, b) | Instr::Rem(dest, a, b) => {
+                let div = matches!(instr, Instr::Div(..));
+                let tag = format!("%div.{}", cmp_id);
+                cmp_id += 1;
+                let _ = writeln!(out, "  {}.z = icmp eq i64 {}, 0", tag, operand(b));
+                let _ = writeln!(out, "  call void @__art_check(i1 {}.z)", tag);
+                let _ = writeln!(out, "  {}.m1 = icmp eq i64 {}, -1", tag, operand(b));
+                if div && overflow == OverflowMode::Trap {
+                    let _ = writeln!(
+                        out,
+                        "  {}.min = icmp eq i64 {}, -9223372036854775808",
+                        tag,
+                        operand(a)
+                    );
+                    let _ = writeln!(out, "  {}.ovf = and i1 {}.min, {}.m1", tag, tag, tag);
+                    let _ = writeln!(out, "  call void @__art_overflow(i1 {}.ovf)", tag);
+                }
+                // `x / -1` is `0 - x` (wrapping) and `x % -1` is 0.
                 let _ = writeln!(
                     out,
-                    "  {} = add i64 {}, {}",
-                    operand(dest),
-                    operand(a),
+                    "  {}.d = select i1 {}.m1, i64 1, i64 {}",
+                    tag,
+                    tag,
                     operand(b)
                 );
-            }
-            Instr::Sub(dest, a, b) => {
                 let _ = writeln!(
                     out,
-                    "  {} = sub i64 {}, {}",
-                    operand(dest),
+                    "  {}.q = {} i64 {}, {}.d",
+                    tag,
+                    if div { "sdiv" } else { "srem" },
                     operand(a),
-                    operand(b)
+                    tag
                 );
-            }
-            Instr::Mul(dest, a, b) => {
+                let on_m1 = if div {
+                    let _ = writeln!(out, "  {}.neg = sub i64 0, {}", tag, operand(a));
+                    format!("{}.neg", tag)
+                } else {
+                    "0".to_string()
+                };
                 let _ = writeln!(
                     out,
-                    "  {} = mul i64 {}, {}",
+                    "  {} = select i1 {}.m1, i64 {}, i64 {}.q",
                     operand(dest),
-                    operand(a),
-                    operand(b)
+                    tag,
+                    on_m1,
+                    tag
                 );
             }
-            Instr::Div(dest, a, b) => {
+            Instr::And(dest, a, b)
+            | Instr::Or(dest, a, b)
+            | Instr::Xor(dest, a, b)
+            | Instr::Shl(dest, a, b)
+            | Instr::Shr(dest, a, b) => {
+                if matches!(instr, Instr::Shl(..) | Instr::Shr(..)) {
+                    // `ugt 63` also catches negative counts.
+                    let tag = format!("%shift.{}", cmp_id);
+                    cmp_id += 1;
+                    let _ = writeln!(out, "  {}.bad = icmp ugt i64 {}, 63", tag, operand(b));
+                    let _ = writeln!(out, "  call void @__art_check(i1 {}.bad)", tag);
+                }
+                let op = match instr {
+                    Instr::And(..) => "and",
+                    Instr::Or(..) => "or",
+                    Instr::Xor(..) => "xor",
+                    Instr::Shl(..) => "shl",
+                    _ => "ashr",
+                };
                 let _ = writeln!(
                     out,
-                    "  {} = sdiv i64 {}, {}",
+                    "  {} = {} i64 {}, {}",
                     operand(dest),
+                    op,
                     operand(a),
                     operand(b)
                 );
@@ -216,6 +337,33 @@ fn emit_llvm_function(f: &Function) -> String {
             Instr::Store(slot, val) => {
                 let _ = writeln!(out, "  store i64 {}, ptr {}", operand(val), operand(slot));
             }
+            Instr::AllocaArray(slot, len) => {
+                let _ = writeln!(out, "  {} = alloca [{} x i64]", operand(slot), len);
+            }
+            Instr::LoadElem(dest, slot, idx) => {
+                let ptr = format!("%elem.{}", cmp_id);
+                cmp_id += 1;
+                let _ = writeln!(
+                    out,
+                    "  {} = getelementptr inbounds i64, ptr {}, i64 {}",
+                    ptr,
+                    operand(slot),
+                    operand(idx)
+                );
+                let _ = writeln!(out, "  {} = load i64, ptr {}", operand(dest), ptr);
+            }
+            Instr::StoreElem(slot, idx, val) => {
+                let ptr = format!("%elem.{}", cmp_id);
+                cmp_id += 1;
+                let _ = writeln!(
+                    out,
+                    "  {} = getelementptr inbounds i64, ptr {}, i64 {}",
+                    ptr,
+                    operand(slot),
+                    operand(idx)
+                );
+                let _ = writeln!(out, "  store i64 {}, ptr {}", operand(val), ptr);
+            }
             Instr::Br(target) => {
                 let _ = writeln!(out, "  br label %{}", target);
             }
@@ -175,6 +175,25 @@ pub fn parse_ir_file(path: &Path) -> Option<IrAnalysis> {
                     continue;
                 }
             }
+            // remainder / bitwise: `op i64 a, b`
+            let parts: Vec<&str> = rhs.split_whitespace().collect();
+            if parts.len() >= 4 {
+                let a = parts[2].trim_end_matches(',').to_string();
+                let b = parts[3].to_string();
+                let instr = match parts[0] {
+                    "rem" => Some(Instr::Rem(dest.clone(), a, b)),
+                    "and" => Some(Instr::And(dest.clone(), a, b)),
+                    "or" => Some(Instr::Or(dest.clone(), a, b)),
+                    "xor" => Some(Instr::Xor(dest.clone(), a, b)),
+                    "shl" => Some(Instr::Shl(dest.clone(), a, b)),
+                    "shr" => Some(Instr::Shr(dest.clone(), a, b)),
+                    _ => None,
+                };
+                if let Some(instr) = instr {
+                    body.push(instr);
+                    continue;
+                }
+            }
 
             // phi: phi TYPE [ v, bb ], [ v2, bb2 ]
             if rhs.starts_with("phi ") {
@@ -286,10 +305,19 @@ pub fn parse_ir_file(path: &Path) -> Option<IrAnalysis> {
             | Instr::Sub(_, _, _)
             | Instr::Mul(_, _, _)
             | Instr::Div(_, _, _)
+            | Instr::Rem(_, _, _)
+            | Instr::And(_, _, _)
+            | Instr::Or(_, _, _)
+            | Instr::Xor(_, _, _)
+            | Instr::Shl(_, _, _)
+            | Instr::Shr(_, _, _)
             | Instr::ICmp(_, _, _, _)
             | Instr::Alloca(_)
             | Instr::Load(_, _)
             | Instr::Store(_, _)
+            | Instr::AllocaArray(_, _)
+            | Instr::LoadElem(_, _, _)
+            | Instr::StoreElem(_, _, _)
             | Instr::Br(_)
             | Instr::BrCond(_, _, _)
             | Instr::Phi(_, _, _)
@@ -1,15 +1,31 @@
 //! General recursive lowering of a function body to IR.
 //!
-//! Covers the procedural AOT subset: `let` bindings, `if`/`else`, `while`,
-//! `return`, and nested calls. Uses an alloca/load/store memory model so
-//! `clang -O2` (mem2reg) promotes slots to registers in the output binary.
+//! Covers the procedural AOT subset: `let`/`var` bindings, assignment to
+//! locals, `if`/`else`, `while` (with labeled `break`/`continue`), `return`,
+//! and nested calls. Uses an
+//! alloca/load/store memory model so `clang -O2` (mem2reg) promotes slots to
+//! registers in the output binary.
 //!
-//! `for` loops and collection iteration are outside the current AOT subset
-//! (Artcode has no integer range syntax). Any unsupported construct causes
-//! `lower_function` to return `None`, keeping the function out of AOT.
+//! Local `Int` array literals live in stack arrays (`alloca [N x i64]`) and
+//! support `a[i]` and `a[i] = v`. Negative indexes count from the end; a
+//! dynamic index outside the array deopts, while a literal one keeps the
+//! function out of AOT so the interpreter reports it.
+//!
+//! Integer `%`, `&`, `|`, `^`, `<<`, `>>` and `~` map to dedicated
+//! instructions. A zero divisor or a shift amount outside `0..64` deopts.
+//!
+//! `for i in a..b` / `for i in a..=b` lower to counted loops: both bounds are
+//! evaluated once and `continue` jumps to the increment. Iterating anything
+//! other than an integer range (arrays, maps, strings, ...) is outside the
+//! current AOT subset. Any unsupported construct causes `lower_function` to
+//! return `None`, keeping the function out of AOT.
+//!
+//! Reads of top-level `Int`/`Bool` constants are replaced by their folded
+//! value (`lower_function_with_consts`); other constants are not lowered.
 
 use crate::{CmpPred, Function, Instr, Type};
 use core::ast::{ArtValue, Expr, FunctionParam, MatchPattern, Stmt};
+use core::const_eval::ConstEnv;
 use std::collections::HashMap;
 
 struct Lowerer {
@@ -19,6 +35,12 @@ struct Lowerer {
     next_tmp: usize,
     next_lbl: usize,
     slots: HashMap<String, String>,
+    /// Local arrays: name -> (array slot, length).
+    arrays: HashMap<String, (String, usize)>,
+    /// Enclosing loops, innermost last: (label, continue target, break target).
+    loops: Vec<(Option<String>, String, String)>,
+    /// Scalar constants: name -> folded operand.
+    consts: HashMap<String, String>,
     terminated: bool,
 }
 
@@ -31,6 +53,9 @@ impl Lowerer {
             next_tmp: 0,
             next_lbl: 0,
             slots: HashMap::new(),
+            arrays: HashMap::new(),
+            loops: Vec::new(),
+            consts: HashMap::new(),
             terminated: false,
         }
     }
@@ -57,6 +82,13 @@ impl Lowerer {
         slot
     }
 
+    /// A slot invisible to user code (loop counters and bounds).
+    fn hidden_slot(&mut self, name: &str) -> String {
+        let slot = format!("%{}.addr", name);
+        self.allocas.push(Instr::Alloca(slot.clone()));
+        slot
+    }
+
     fn push(&mut self, instr: Instr) {
         self.body.push(instr);
     }
@@ -72,23 +104,177 @@ impl Lowerer {
         self.terminated = false;
     }
 
+    /// Lowers `index` into a position inside `0..len`, normalising negative
+    /// indexes. A dynamic index out of range branches to a `deopt` block.
+    fn lower_index(&mut self, index: &Expr, len: usize) -> Option<String> {
+        if let Some(i) = literal_int(index) {
+            let pos = if i < 0 { i + len as i64 } else { i };
+            return (0..len as i64).contains(&pos).then(|| pos.to_string());
+        }
+        let i = self.lower_expr(index)?;
+        let len = len.to_string();
+        let neg = self.tmp();
+        self.push(Instr::ICmp(
+            neg.clone(),
+            CmpPred::Lt,
+            i.clone(),
+            "0".to_string(),
+        ));
+        let adj = self.tmp();
+        self.push(Instr::Mul(adj.clone(), neg, len.clone()));
+        let pos = self.tmp();
+        self.push(Instr::Add(pos.clone(), i, adj));
+        let lo = self.tmp();
+        self.push(Instr::ICmp(
+            lo.clone(),
+            CmpPred::Ge,
+            pos.clone(),
+            "0".to_string(),
+        ));
+        let hi = self.tmp();
+        self.push(Instr::ICmp(hi.clone(), CmpPred::Lt, pos.clone(), len));
+        let ok = self.tmp();
+        self.push(Instr::Mul(ok.clone(), lo, hi));
+        self.guard(ok, "idx");
+        Some(pos)
+    }
+
+    /// Continues only when `ok` (0/1) holds; otherwise branches to a `deopt`
+    /// block so the interpreter can report the failure.
+    fn guard(&mut self, ok: String, kind: &str) {
+        let ok_bb = self.label(&format!("{}_ok", kind));
+        let fail_bb = self.label(&format!("{}_fail", kind));
+        self.push(Instr::BrCond(ok, ok_bb.clone(), fail_bb.clone()));
+        self.terminated = true;
+        self.start_block(&fail_bb);
+        self.push(Instr::Deopt);
+        self.terminated = true;
+        self.start_block(&ok_bb);
+    }
+
+    /// Emits `l op r` for the integer operators. `/` and `%` guard against a
+    /// zero divisor and shifts against amounts outside `0..64`; a literal
+    /// operand that would fail keeps the function out of AOT instead.
+    fn lower_binop(&mut self, op: &str, l: String, r: String) -> Option<String> {
+        let literal = r.parse::<i64>().ok();
+        match op {
+            "/" | "%" => match literal {
+                Some(0) => return None,
+                Some(_) => {}
+                None => {
+                    let nz = self.tmp();
+                    self.push(Instr::ICmp(
+                        nz.clone(),
+                        CmpPred::Ne,
+                        r.clone(),
+                        "0".to_string(),
+                    ));
+                    self.guard(nz, "div");
+                }
+            },
+            "<<" | ">>" => match literal {
+                Some(n) if !(0..64).contains(&n) => return None,
+                Some(_) => {}
+                None => {
+                    let lo = self.tmp();
+                    self.push(Instr::ICmp(
+                        lo.clone(),
+                        CmpPred::Ge,
+                        r.clone(),
+                        "0".to_string(),
+                    ));
+                    let hi = self.tmp();
+                    self.push(Instr::ICmp(
+                        hi.clone(),
+                        CmpPred::Lt,
+                        r.clone(),
+                        "64".to_string(),
+                    ));
+                    let ok = self.tmp();
+                    self.push(Instr::And(ok.clone(), lo, hi));
+                    self.guard(ok, "shift");
+                }
+            },
+            _ => {}
+        }
+        let dest = self.tmp();
+        self.push(match op {
+            "+" => Instr::Add(dest.clone(), l, r),
+            "-" => Instr::Sub(dest.clone(), l, r),
+            "*" => Instr::Mul(dest.clone(), l, r),
+            "/" => Instr::Div(dest.clone(), l, r),
+            "%" => Instr::Rem(dest.clone(), l, r),
+            "&" => Instr::And(dest.clone(), l, r),
+            "|" => Instr::Or(dest.clone(), l, r),
+            "^" => Instr::Xor(dest.clone(), l, r),
+            "<<" => Instr::Shl(dest.clone(), l, r),
+            ">>" => Instr::Shr(dest.clone(), l, r),
+            "<" => Instr::ICmp(dest.clone(), CmpPred::Lt, l, r),
+            "<=" => Instr::ICmp(dest.clone(), CmpPred::Le, l, r),
+            ">" => Instr::ICmp(dest.clone(), CmpPred::Gt, l, r),
+            ">=" => Instr::ICmp(dest.clone(), CmpPred::Ge, l, r),
+            "==" => Instr::ICmp(dest.clone(), CmpPred::Eq, l, r),
+            "!=" => Instr::ICmp(dest.clone(), CmpPred::Ne, l, r),
+            _ => return None,
+        });
+        Some(dest)
+    }
+
+    /// The loop a `break`/`continue` jumps out of: the innermost one, or the
+    /// one carrying `label`.
+    fn loop_target(
+        &self,
+        label: &Option<core::Token>,
+    ) -> Option<&(Option<String>, String, String)> {
+        match label {
+            None => self.loops.last(),
+            Some(l) => self
+                .loops
+                .iter()
+                .rev()
+                .find(|(name, _, _)| name.as_deref() == Some(l.lexeme.as_str())),
+        }
+    }
+
+    fn array_of(&self, object: &Expr) -> Option<(String, usize)> {
+        match object {
+            Expr::Variable { name } => self.arrays.get(&name.lexeme).cloned(),
+            _ => None,
+        }
+    }
+
     fn lower_expr(&mut self, e: &Expr) -> Option<String> {
         match e {
             Expr::Literal(ArtValue::Int(n)) => Some(n.to_string()),
             Expr::Literal(ArtValue::Bool(b)) => Some(if *b { "1" } else { "0" }.to_string()),
             Expr::Variable { name } => {
-                let slot = self.slots.get(&name.lexeme)?.clone();
+                let Some(slot) = self.slots.get(&name.lexeme).cloned() else {
+                    return self.consts.get(&name.lexeme).cloned();
+                };
                 let dest = self.tmp();
                 self.push(Instr::Load(dest.clone(), slot));
                 Some(dest)
             }
             Expr::Grouping { expression } => self.lower_expr(expression),
+            Expr::Index { object, index, .. } => {
+                let (arr, len) = self.array_of(object)?;
+                let pos = self.lower_index(index, len)?;
+                let dest = self.tmp();
+                self.push(Instr::LoadElem(dest.clone(), arr, pos));
+                Some(dest)
+            }
             Expr::Unary { operator, right } if operator.lexeme == "-" => {
                 let r = self.lower_expr(right)?;
                 let dest = self.tmp();
                 self.push(Instr::Sub(dest.clone(), "0".to_string(), r));
                 Some(dest)
             }
+            Expr::Unary { operator, right } if operator.lexeme == "~" => {
+                let r = self.lower_expr(right)?;
+                let dest = self.tmp();
+                self.push(Instr::Xor(dest.clone(), r, "-1".to_string()));
+                Some(dest)
+            }
             Expr::Binary {
                 left,
                 operator,
@@ -96,23 +282,7 @@ impl Lowerer {
             } => {
                 let l = self.lower_expr(left)?;
                 let r = self.lower_expr(right)?;
-                let dest = self.tmp();
-                let op = operator.lexeme.as_str();
-                let instr = match op {
-                    "+" => Instr::Add(dest.clone(), l, r),
-                    "-" => Instr::Sub(dest.clone(), l, r),
-                    "*" => Instr::Mul(dest.clone(), l, r),
-                    "/" => Instr::Div(dest.clone(), l, r),
-                    "<" => Instr::ICmp(dest.clone(), CmpPred::Lt, l, r),
-                    "<=" => Instr::ICmp(dest.clone(), CmpPred::Le, l, r),
-                    ">" => Instr::ICmp(dest.clone(), CmpPred::Gt, l, r),
-                    ">=" => Instr::ICmp(dest.clone(), CmpPred::Ge, l, r),
-                    "==" => Instr::ICmp(dest.clone(), CmpPred::Eq, l, r),
-                    "!=" => Instr::ICmp(dest.clone(), CmpPred::Ne, l, r),
-                    _ => return None,
-                };
-                self.push(instr);
-                Some(dest)
+                self.lower_binop(&operator.lexeme, l, r)
             }
             Expr::Call {
                 callee, arguments, ..
@@ -154,11 +324,65 @@ impl Lowerer {
                     MatchPattern::Binding(tok) => tok.lexeme.clone(),
                     _ => return None,
                 };
+                if let Expr::Array(items) = initializer {
+                    let mut values = Vec::new();
+                    for item in items {
+                        values.push(self.lower_expr(item)?);
+                    }
+                    let arr = format!("%{}.arr{}", var_name, self.next_lbl);
+                    self.next_lbl += 1;
+                    self.allocas
+                        .push(Instr::AllocaArray(arr.clone(), values.len()));
+                    for (i, v) in values.into_iter().enumerate() {
+                        self.push(Instr::StoreElem(arr.clone(), i.to_string(), v));
+                    }
+                    self.slots.remove(&var_name);
+                    self.arrays.insert(var_name, (arr, items.len()));
+                    return Some(());
+                }
                 let v = self.lower_expr(initializer)?;
+                self.arrays.remove(&var_name);
                 let slot = self.slot_for(&var_name);
                 self.push(Instr::Store(slot, v));
                 Some(())
             }
+            Stmt::Assign {
+                target: Expr::Variable { name },
+                operator,
+                value,
+            } => {
+                let slot = self.slots.get(&name.lexeme)?.clone();
+                let rhs = self.lower_expr(value)?;
+                let v = match operator.lexeme.as_str() {
+                    "=" => rhs,
+                    op => {
+                        let cur = self.tmp();
+                        self.push(Instr::Load(cur.clone(), slot.clone()));
+                        self.lower_binop(op.strip_suffix('=')?, cur, rhs)?
+                    }
+                };
+                self.push(Instr::Store(slot, v));
+                Some(())
+            }
+            Stmt::Assign {
+                target: Expr::Index { object, index, .. },
+                operator,
+                value,
+            } => {
+                let (arr, len) = self.array_of(object)?;
+                let pos = self.lower_index(index, len)?;
+                let rhs = self.lower_expr(value)?;
+                let v = match operator.lexeme.as_str() {
+                    "=" => rhs,
+                    op => {
+                        let cur = self.tmp();
+                        self.push(Instr::LoadElem(cur.clone(), arr.clone(), pos.clone()));
+                        self.lower_binop(op.strip_suffix('=')?, cur, rhs)?
+                    }
+                };
+                self.push(Instr::StoreElem(arr, pos, v));
+                Some(())
+            }
             Stmt::Expression(e) => {
                 self.lower_expr(e)?;
                 Some(())
@@ -201,7 +425,11 @@ impl Lowerer {
                 self.start_block(&merge_bb);
                 Some(())
             }
-            Stmt::While { condition, body } => {
+            Stmt::While {
+                condition,
+                body,
+                label,
+            } => {
                 let head_bb = self.label("while_head");
                 let body_bb = self.label("while_body");
                 let exit_bb = self.label("while_exit");
@@ -213,15 +441,114 @@ impl Lowerer {
                 self.terminated = true;
 
                 self.start_block(&body_bb);
-                self.lower_stmt(body)?;
+                self.loops.push((
+                    label.as_ref().map(|l| l.lexeme.clone()),
+                    head_bb.clone(),
+                    exit_bb.clone(),
+                ));
+                let lowered = self.lower_stmt(body);
+                self.loops.pop();
+                lowered?;
+                self.br_to(&head_bb);
+
+                self.start_block(&exit_bb);
+                Some(())
+            }
+            Stmt::Break { label, .. } => {
+                let exit_bb = self.loop_target(label)?.2.clone();
+                self.br_to(&exit_bb);
+                self.terminated = true;
+                Some(())
+            }
+            Stmt::Continue { label, .. } => {
+                let head_bb = self.loop_target(label)?.1.clone();
+                self.br_to(&head_bb);
+                self.terminated = true;
+                Some(())
+            }
+            Stmt::For {
+                element,
+                pattern: None,
+                iterator:
+                    Expr::Range {
+                        start,
+                        end,
+                        inclusive,
+                        ..
+                    },
+                body,
+                label,
+            } => {
+                if self.arrays.contains_key(&element.lexeme) {
+                    return None;
+                }
+                let from = self.lower_expr(start)?;
+                let to = self.lower_expr(end)?;
+                // The element gets its own slot per loop, apart from the counter,
+                // so sequential or nested loops reusing a name never share state.
+                let id = self.next_lbl;
+                let cur_slot = self.hidden_slot(&format!("for{}.cur", id));
+                let end_slot = self.hidden_slot(&format!("for{}.end", id));
+                let elem_slot = self.hidden_slot(&format!("for{}.{}", id, element.lexeme));
+                self.push(Instr::Store(cur_slot.clone(), from));
+                self.push(Instr::Store(end_slot.clone(), to));
+                let head_bb = self.label("for_head");
+                let body_bb = self.label("for_body");
+                let step_bb = self.label("for_step");
+                let exit_bb = self.label("for_exit");
+                self.br_to(&head_bb);
+
+                self.start_block(&head_bb);
+                let (cur, last) = (self.tmp(), self.tmp());
+                self.push(Instr::Load(cur.clone(), cur_slot.clone()));
+                self.push(Instr::Load(last.clone(), end_slot.clone()));
+                let cond = self.tmp();
+                let pred = if *inclusive { CmpPred::Le } else { CmpPred::Lt };
+                self.push(Instr::ICmp(cond.clone(), pred, cur, last));
+                self.push(Instr::BrCond(cond, body_bb.clone(), exit_bb.clone()));
+                self.terminated = true;
+
+                self.start_block(&body_bb);
+                let cur = self.tmp();
+                self.push(Instr::Load(cur.clone(), cur_slot.clone()));
+                self.push(Instr::Store(elem_slot.clone(), cur));
+                let shadowed = self.slots.insert(element.lexeme.clone(), elem_slot);
+                self.loops.push((
+                    label.as_ref().map(|l| l.lexeme.clone()),
+                    step_bb.clone(),
+                    exit_bb.clone(),
+                ));
+                let lowered = self.lower_stmt(body);
+                self.loops.pop();
+                match shadowed {
+                    Some(slot) => self.slots.insert(element.lexeme.clone(), slot),
+                    None => self.slots.remove(&element.lexeme),
+                };
+                lowered?;
+                self.br_to(&step_bb);
+
+                self.start_block(&step_bb);
+                let cur = self.tmp();
+                self.push(Instr::Load(cur.clone(), cur_slot.clone()));
+                if *inclusive {
+                    // Stop before incrementing past the end: `a..=i64::MAX` must not overflow.
+                    let last = self.tmp();
+                    self.push(Instr::Load(last.clone(), end_slot));
+                    let done = self.tmp();
+                    self.push(Instr::ICmp(done.clone(), CmpPred::Eq, cur.clone(), last));
+                    let inc_bb = self.label("for_inc");
+                    self.push(Instr::BrCond(done, exit_bb.clone(), inc_bb.clone()));
+                    self.terminated = true;
+                    self.start_block(&inc_bb);
+                }
+                let next = self.tmp();
+                self.push(Instr::Add(next.clone(), cur, "1".to_string()));
+                self.push(Instr::Store(cur_slot, next));
                 self.br_to(&head_bb);
 
                 self.start_block(&exit_bb);
                 Some(())
             }
-            // For-loops iterate over collections — no integer range syntax in Artcode.
-            // AOT lowering for collection iteration is not yet implemented.
-            Stmt::For { .. } => None,
             _ => None,
         }
     }
@@ -236,9 +563,81 @@ impl Lowerer {
     }
 }
 
+/// An `Int` literal, optionally negated (`-1` parses as unary minus).
+fn literal_int(e: &Expr) -> Option<i64> {
+    match e {
+        Expr::Literal(ArtValue::Int(n)) => Some(*n),
+        Expr::Unary { operator, right } if operator.lexeme == "-" => literal_int(right).map(|n| -n),
+        Expr::Grouping { expression } => literal_int(expression),
+        _ => None,
+    }
+}
+
+/// `true` if `stmt` reads one of `consts` by name.
+pub(crate) fn reads_const(stmt: &Stmt, consts: &ConstEnv) -> bool {
+    let expr = |e: &Expr| expr_reads_const(e, consts);
+    let stmt_reads = |s: &Stmt| reads_const(s, consts);
+    match stmt {
+        Stmt::Function { body, .. } => stmt_reads(body),
+        Stmt::Block { statements } => statements.iter().any(stmt_reads),
+        Stmt::Expression(e)
+        | Stmt::Let { initializer: e, .. }
+        | Stmt::Return { value: Some(e) } => expr(e),
+        Stmt::Assign { target, value, .. } => expr(target) || expr(value),
+        Stmt::If {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            expr(condition)
+                || stmt_reads(then_branch)
+                || else_branch.as_deref().is_some_and(stmt_reads)
+        }
+        Stmt::While {
+            condition, body, ..
+        } => expr(condition) || stmt_reads(body),
+        Stmt::For { iterator, body, .. } => expr(iterator) || stmt_reads(body),
+        Stmt::Match {
+            expr: subject,
+            cases,
+            ..
+        } => {
+            expr(subject)
+                || cases
+                    .iter()
+                    .any(|(_, guard, body)| guard.as_ref().is_some_and(expr) || stmt_reads(body))
+        }
+        _ => false,
+    }
+}
+
+fn expr_reads_const(e: &Expr, consts: &ConstEnv) -> bool {
+    let reads = |e: &Expr| expr_reads_const(e, consts);
+    match e {
+        Expr::Variable { name } => consts.contains_key(&name.lexeme),
+        Expr::Grouping { expression: inner } | Expr::Unary { right: inner, .. } => reads(inner),
+        Expr::Binary { left, right, .. } | Expr::Logical { left, right, .. } => {
+            reads(left) || reads(right)
+        }
+        Expr::Index { object, index, .. } => reads(object) || reads(index),
+        Expr::Range { start, end, .. } => reads(start) || reads(end),
+        Expr::Call {
+            callee, arguments, ..
+        } => reads(callee) || arguments.iter().any(reads),
+        Expr::Array(items) | Expr::Tuple(items) => items.iter().any(reads),
+        _ => false,
+    }
+}
+
 /// Lower a `Stmt::Function` to an IR `Function` using the general engine.
 /// Returns `None` if the body contains any construct outside the AOT subset.
 pub fn lower_function(stmt: &Stmt) -> Option<Function> {
+    lower_function_with_consts(stmt, &ConstEnv::new())
+}
+
+/// [`lower_function`] with the program's folded `const`s in scope. A local
+/// binding of the same name wins over the constant.
+pub fn lower_function_with_consts(stmt: &Stmt, consts: &ConstEnv) -> Option<Function> {
     let Stmt::Function {
         name, params, body, ..
     } = stmt
@@ -253,6 +652,14 @@ pub fn lower_function(stmt: &Stmt) -> Option<Function> {
         .collect();
 
     let mut lw = Lowerer::new(&func_name);
+    lw.consts = consts
+        .iter()
+        .filter_map(|(name, value)| match value {
+            ArtValue::Int(n) => Some((name.clone(), n.to_string())),
+            ArtValue::Bool(b) => Some((name.clone(), (*b as i64).to_string())),
+            _ => None,
+        })
+        .collect();
     // Materialise parameters into stack slots so they can be read uniformly.
     for (pname, _) in &ir_params {
         let slot = lw.slot_for(pname);
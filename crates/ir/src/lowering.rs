@@ -1,5 +1,6 @@
 use crate::{Function, Instr, Type};
 use core::ast::{Expr, Stmt};
+use core::const_eval::ConstEnv;
 use std::collections::HashMap;
 
 /// Unwrap a `Block` that holds exactly one statement, recursively. The parser
@@ -30,6 +31,7 @@ pub fn lower_plain(stmt: &Stmt) -> Option<Function> {
             body,
             method_owner: _,
             is_async: _,
+            public: _,
         } => {
             // Only support function bodies that are a block with a single Return
             // or a direct Return statement.
@@ -198,6 +200,7 @@ pub fn lower_if_function(stmt: &Stmt) -> Option<Function> {
         body,
         method_owner: _,
         is_async: _,
+        public: _,
     } = stmt
     {
         let func_name = name.lexeme.clone();
@@ -399,12 +402,89 @@ pub fn lower_stmt(stmt: &Stmt) -> Option<Function> {
     None
 }
 
+/// `lower_stmt` for a program with top-level `const`s. A function that reads
+/// a constant goes straight to the general engine, which inlines the folded
+/// value; the specialised lowerers take every name for a parameter.
+pub fn lower_stmt_with_consts(stmt: &Stmt, consts: &ConstEnv) -> Option<Function> {
+    if crate::lower_fn::reads_const(stmt, consts) {
+        return crate::lower_fn::lower_function_with_consts(stmt, consts);
+    }
+    lower_stmt(stmt)
+}
+
 // Very small lowering for `match` expressions used in golden tests.
 // Currently supports a function whose body is a Block with a single Match
 // statement with two arms: a literal arm and a wildcard arm. It lowers to
 // a br_cond on the matched expression (treating non-zero as true) and
 // produces then/else labels, materializes constants in each arm, and
 // merges with a phi.
+/// Lower the test of a scalar pattern (`Int` literal, range, or-pattern,
+/// `name @ pattern`) against `subject` into `body`. Returns the temp holding
+/// 1 when the pattern matches and 0 otherwise, or `None` for patterns that
+/// need a heap value (structs, arrays, enum payloads).
+fn lower_pattern_test(
+    pattern: &core::ast::MatchPattern,
+    subject: &str,
+    mktemp: &mut dyn FnMut() -> String,
+    body: &mut Vec<Instr>,
+) -> Option<String> {
+    use crate::CmpPred;
+    use core::ast::{ArtValue, MatchPattern};
+    match pattern {
+        MatchPattern::Literal(ArtValue::Int(lit)) => {
+            let t = mktemp();
+            body.push(Instr::ICmp(
+                t.clone(),
+                CmpPred::Eq,
+                subject.to_string(),
+                lit.to_string(),
+            ));
+            Some(t)
+        }
+        MatchPattern::Range {
+            start: ArtValue::Int(lo),
+            end: ArtValue::Int(hi),
+            inclusive,
+        } => {
+            let low = mktemp();
+            body.push(Instr::ICmp(
+                low.clone(),
+                CmpPred::Ge,
+                subject.to_string(),
+                lo.to_string(),
+            ));
+            let high = mktemp();
+            let pred = if *inclusive { CmpPred::Le } else { CmpPred::Lt };
+            body.push(Instr::ICmp(
+                high.clone(),
+                pred,
+                subject.to_string(),
+                hi.to_string(),
+            ));
+            let t = mktemp();
+            body.push(Instr::And(t.clone(), low, high));
+            Some(t)
+        }
+        MatchPattern::Or(alts) => {
+            let mut acc: Option<String> = None;
+            for alt in alts {
+                let c = lower_pattern_test(alt, subject, mktemp, body)?;
+                acc = Some(match acc {
+                    None => c,
+                    Some(prev) => {
+                        let t = mktemp();
+                        body.push(Instr::Or(t.clone(), prev, c));
+                        t
+                    }
+                });
+            }
+            acc
+        }
+        MatchPattern::At { pattern, .. } => lower_pattern_test(pattern, subject, mktemp, body),
+        _ => None,
+    }
+}
+
 pub fn lower_match_function(stmt: &Stmt) -> Option<Function> {
     if let Stmt::Function {
         name,
@@ -414,6 +494,7 @@ pub fn lower_match_function(stmt: &Stmt) -> Option<Function> {
         body,
         method_owner: _,
         is_async: _,
+        public: _,
     } = stmt
     {
         let func_name = name.lexeme.clone();
@@ -427,7 +508,7 @@ pub fn lower_match_function(stmt: &Stmt) -> Option<Function> {
             if statements.len() != 1 {
                 return None;
             }
-            if let Stmt::Match { expr, cases } = &statements[0] {
+            if let Stmt::Match { expr, cases, .. } = &statements[0] {
                 // only support simple variable match and exactly two cases
                 if cases.len() != 2 {
                     return None;
@@ -486,6 +567,10 @@ pub fn lower_match_function(stmt: &Stmt) -> Option<Function> {
                         }
                     }
                 }
+                // `n @ 1..=9` binds the whole operand.
+                if let core::ast::MatchPattern::At { name, .. } = &cases[0].0 {
+                    binding_map.insert(name.lexeme.clone(), match_var.clone());
+                }
 
                 // updated lower_arm: recognizes variable returns and maps bound names
                 let lower_arm = |s: &Stmt,
@@ -626,6 +711,19 @@ pub fn lower_match_function(stmt: &Stmt) -> Option<Function> {
                             }
                         }
                     }
+                    core::ast::MatchPattern::Range { .. }
+                    | core::ast::MatchPattern::Or(_)
+                    | core::ast::MatchPattern::At { .. } => {
+                        // ranges and or-patterns of Int literals compare with icmp
+                        let cond =
+                            lower_pattern_test(&cases[0].0, &match_var, &mut mktemp, &mut body)?;
+                        body.push(Instr::BrCond(cond, then_bb.clone(), else_bb.clone()));
+                    }
+                    core::ast::MatchPattern::Struct { .. }
+                    | core::ast::MatchPattern::Array { .. } => {
+                        // structs and arrays are heap values: not lowered here
+                        return None;
+                    }
                     _ => {
                         // default: branch on truthiness of match_var (non-zero true)
                         body.push(Instr::BrCond(
@@ -32,6 +32,12 @@ pub fn rename_temps(func: &mut Function) {
             | Instr::Sub(dest, _, _)
             | Instr::Mul(dest, _, _)
             | Instr::Div(dest, _, _)
+            | Instr::Rem(dest, _, _)
+            | Instr::And(dest, _, _)
+            | Instr::Or(dest, _, _)
+            | Instr::Xor(dest, _, _)
+            | Instr::Shl(dest, _, _)
+            | Instr::Shr(dest, _, _)
                 if is_candidate(dest) && seen.insert(dest.clone()) =>
             {
                 defs.push(dest.clone());
@@ -69,7 +75,13 @@ pub fn rename_temps(func: &mut Function) {
             Instr::Add(dest, a, b)
             | Instr::Sub(dest, a, b)
             | Instr::Mul(dest, a, b)
-            | Instr::Div(dest, a, b) => {
+            | Instr::Div(dest, a, b)
+            | Instr::Rem(dest, a, b)
+            | Instr::And(dest, a, b)
+            | Instr::Or(dest, a, b)
+            | Instr::Xor(dest, a, b)
+            | Instr::Shl(dest, a, b)
+            | Instr::Shr(dest, a, b) => {
                 *dest = replace(dest, &map);
                 *a = replace(a, &map);
                 *b = replace(b, &map);
@@ -104,6 +116,17 @@ pub fn rename_temps(func: &mut Function) {
                 *slot = replace(slot, &map);
                 *val = replace(val, &map);
             }
+            Instr::AllocaArray(_, _) => {}
+            Instr::LoadElem(dest, slot, idx) => {
+                *dest = replace(dest, &map);
+                *slot = replace(slot, &map);
+                *idx = replace(idx, &map);
+            }
+            Instr::StoreElem(slot, idx, val) => {
+                *slot = replace(slot, &map);
+                *idx = replace(idx, &map);
+                *val = replace(val, &map);
+            }
             Instr::Ret(opt) => {
                 if let Some(v) = opt {
                     *v = replace(v, &map);
@@ -201,6 +224,12 @@ pub fn insert_phi_nodes(func: &mut Function) {
                 | Instr::Sub(dest, _, _)
                 | Instr::Mul(dest, _, _)
                 | Instr::Div(dest, _, _)
+                | Instr::Rem(dest, _, _)
+                | Instr::And(dest, _, _)
+                | Instr::Or(dest, _, _)
+                | Instr::Xor(dest, _, _)
+                | Instr::Shl(dest, _, _)
+                | Instr::Shr(dest, _, _)
                 | Instr::Call(dest, _, _)
                 | Instr::Phi(dest, _, _) => {
                     if is_candidate(dest) {
@@ -276,7 +305,13 @@ pub fn insert_phi_nodes(func: &mut Function) {
                 Instr::Add(_d, a, b)
                 | Instr::Sub(_d, a, b)
                 | Instr::Mul(_d, a, b)
-                | Instr::Div(_d, a, b) => {
+                | Instr::Div(_d, a, b)
+                | Instr::Rem(_d, a, b)
+                | Instr::And(_d, a, b)
+                | Instr::Or(_d, a, b)
+                | Instr::Xor(_d, a, b)
+                | Instr::Shl(_d, a, b)
+                | Instr::Shr(_d, a, b) => {
                     if is_candidate(a) && incoming.iter().any(|(v, _)| v == a) {
                         *a = phi_dest.clone();
                     }
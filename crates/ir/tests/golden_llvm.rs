@@ -1,5 +1,5 @@
-use core::Token;
 use core::ast::{ArtValue, Expr, FunctionParam, Stmt};
+use core::{OverflowMode, Token};
 use ir::{Function, lower_stmt};
 
 fn var(name: &str) -> Expr {
@@ -35,6 +35,7 @@ fn function(name: &str, params: &[&str], body: Stmt) -> Function {
         return_type: Some("i64".to_string()),
         body: std::rc::Rc::new(body),
         method_owner: None,
+        public: false,
     };
     lower_stmt(&func).expect("lowering failed")
 }
@@ -50,9 +51,9 @@ fn block(stmts: Vec<Stmt>) -> Stmt {
 #[test]
 fn emits_valid_llvm_text() {
     let f = function("add", &["a", "b"], ret(bin(var("a"), "+", var("b"))));
-    let module = ir::llvm_emitter::emit_llvm_module(&[f], "add");
+    let module = ir::llvm_emitter::emit_llvm_module(&[f], "add", OverflowMode::Trap);
     assert!(module.contains("define i64 @add(i64 %a, i64 %b)"));
-    assert!(module.contains("add i64 %a, %b"));
+    assert!(module.contains("@llvm.sadd.with.overflow.i64(i64 %a, i64 %b)"));
     assert!(module.contains("ret i64"));
     assert!(module.contains("define i32 @main()"));
     assert!(module.contains("@printf"));
@@ -98,7 +99,7 @@ fn compile_and_run(module: &str, tag: &str) -> Option<String> {
 fn roundtrip_arithmetic() {
     // func answer() -> i64 { return 40 + 2 }
     let f = function("answer", &[], ret(bin(int(40), "+", int(2))));
-    let module = ir::llvm_emitter::emit_llvm_module(&[f], "answer");
+    let module = ir::llvm_emitter::emit_llvm_module(&[f], "answer", OverflowMode::Trap);
     if let Some(out) = compile_and_run(&module, "arith") {
         assert_eq!(out, "42");
     }
@@ -113,7 +114,7 @@ fn roundtrip_if_else() {
         else_branch: Some(Box::new(block(vec![ret(int(20))]))),
     };
     let f = function("main", &[], block(vec![if_stmt]));
-    let module = ir::llvm_emitter::emit_llvm_module(&[f], "main");
+    let module = ir::llvm_emitter::emit_llvm_module(&[f], "main", OverflowMode::Trap);
     assert!(module.contains("phi i64"), "if/else must emit a phi node");
     if let Some(out) = compile_and_run(&module, "ifelse") {
         assert_eq!(out, "10");
@@ -134,7 +135,7 @@ fn roundtrip_function_call() {
             arguments: vec![int(21)],
         }),
     );
-    let module = ir::llvm_emitter::emit_llvm_module(&[dbl, main], "main");
+    let module = ir::llvm_emitter::emit_llvm_module(&[dbl, main], "main", OverflowMode::Trap);
     assert!(module.contains("call i64 @dbl"));
     if let Some(out) = compile_and_run(&module, "call") {
         assert_eq!(out, "42");
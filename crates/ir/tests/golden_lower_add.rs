@@ -36,6 +36,7 @@ fn golden_lower_add() {
             value: Some(ret_expr),
         }),
         method_owner: None,
+        public: false,
     };
 
     let irf = lower_stmt(&func).expect("lowering failed");
@@ -32,6 +32,7 @@ fn golden_lower_call_literals() {
             value: Some(call_expr),
         }),
         method_owner: None,
+        public: false,
     };
 
     let irf = lower_stmt(&func).expect("lowering failed");
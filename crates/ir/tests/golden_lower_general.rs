@@ -1,10 +1,10 @@
-use core::Token;
 /// Golden tests for the general lowering engine in `lower_fn`.
 ///
 /// These cover AOT lowering of procedural constructs — `let` bindings,
 /// `while` loops, `if`/`else` with locals, and comparisons — which are
 /// outside the narrow subset handled by `lower_plain` / `lower_if_function`.
 use core::ast::{ArtValue, Expr, FunctionParam, MatchPattern, Stmt};
+use core::{OverflowMode, Token};
 use ir::llvm_emitter::emit_llvm_module;
 use ir::lower_fn::lower_function;
 
@@ -30,6 +30,7 @@ fn let_bind(name: &str, init: Expr) -> Stmt {
     Stmt::Let {
         pattern: MatchPattern::Variable(Token::dummy(name)),
         ty: None,
+        mutable: false,
         initializer: init,
     }
 }
@@ -57,6 +58,7 @@ fn make_fn(name: &str, params: &[&str], body: Stmt) -> Stmt {
         body: std::rc::Rc::new(body),
         method_owner: None,
         is_async: false,
+        public: false,
     }
 }
 
@@ -125,7 +127,7 @@ fn roundtrip_let_add() {
     let main_fn = make_fn("main", &[], main_body);
     let main_ir = lower_function(&main_fn).expect("lower main");
 
-    let module = emit_llvm_module(&[add_ir, main_ir], "main");
+    let module = emit_llvm_module(&[add_ir, main_ir], "main", OverflowMode::Trap);
     if let Some(out) = compile_and_run(&module, "let_add") {
         assert_eq!(out, "42");
     }
@@ -145,6 +147,7 @@ fn roundtrip_while_count() {
         Stmt::While {
             condition: bin(var("i"), "<", var("n")),
             body: Box::new(while_body),
+            label: None,
         },
         ret(var("i")),
     ]);
@@ -159,7 +162,7 @@ fn roundtrip_while_count() {
     let main_fn = make_fn("main", &[], main_body);
     let main_ir = lower_function(&main_fn).expect("lower main");
 
-    let module = emit_llvm_module(&[count_ir, main_ir], "main");
+    let module = emit_llvm_module(&[count_ir, main_ir], "main", OverflowMode::Trap);
     if let Some(out) = compile_and_run(&module, "while_count") {
         assert_eq!(out, "7");
     }
@@ -195,8 +198,422 @@ fn roundtrip_if_with_let() {
     let main_fn = make_fn("main", &[], main_body);
     let main_ir = lower_function(&main_fn).expect("lower main");
 
-    let module = emit_llvm_module(&[fn_ir, main_ir], "main");
+    let module = emit_llvm_module(&[fn_ir, main_ir], "main", OverflowMode::Trap);
     if let Some(out) = compile_and_run(&module, "if_let") {
         assert_eq!(out, "0");
     }
 }
+
+fn index(arr: &str, i: Expr) -> Expr {
+    Expr::Index {
+        object: Box::new(var(arr)),
+        index: Box::new(i),
+        bracket: Token::dummy("["),
+    }
+}
+
+/// Local array literal: stack array, element stores and a bounds-checked load.
+#[test]
+fn lower_array_index() {
+    // func pick(i) { let a = [10, 20, 30]; a[-1] = 5; return a[i] }
+    let body = block(vec![
+        let_bind("a", Expr::Array(vec![int(10), int(20), int(30)])),
+        Stmt::Assign {
+            target: index("a", int(-1)),
+            operator: Token::dummy("="),
+            value: int(5),
+        },
+        ret(index("a", var("i"))),
+    ]);
+    let f = lower_function(&make_fn("pick", &["i"], body)).expect("lower_fn failed");
+    assert!(
+        f.body
+            .iter()
+            .any(|i| matches!(i, ir::Instr::AllocaArray(_, 3)))
+    );
+    // `a[-1]` resolves statically to slot 2.
+    assert!(
+        f.body
+            .iter()
+            .any(|i| matches!(i, ir::Instr::StoreElem(_, idx, v) if idx == "2" && v == "5"))
+    );
+    // The dynamic index goes through a deopt guard.
+    assert!(f.body.iter().any(|i| matches!(i, ir::Instr::Deopt)));
+    assert!(f.body.iter().any(|i| matches!(i, ir::Instr::LoadElem(..))));
+}
+
+/// A literal index outside the array keeps the function out of AOT.
+#[test]
+fn lower_array_literal_index_out_of_range_is_rejected() {
+    let body = block(vec![
+        let_bind("a", Expr::Array(vec![int(1), int(2)])),
+        ret(index("a", int(2))),
+    ]);
+    assert!(lower_function(&make_fn("oob", &[], body)).is_none());
+}
+
+/// Roundtrip: sum of [1, 2, 3, 4] via a while loop with `a[i]` -> 10
+#[test]
+fn roundtrip_array_sum() {
+    let while_body = block(vec![
+        let_bind("s", bin(var("s"), "+", index("a", var("i")))),
+        let_bind("i", bin(var("i"), "+", int(1))),
+    ]);
+    let body = block(vec![
+        let_bind("a", Expr::Array(vec![int(1), int(2), int(3), int(4)])),
+        let_bind("s", int(0)),
+        let_bind("i", int(0)),
+        Stmt::While {
+            condition: bin(var("i"), "<", int(4)),
+            body: Box::new(while_body),
+            label: None,
+        },
+        ret(var("s")),
+    ]);
+    let main_ir = lower_function(&make_fn("main", &[], body)).expect("lower main");
+    let module = emit_llvm_module(&[main_ir], "main", OverflowMode::Trap);
+    assert!(module.contains("alloca [4 x i64]"), "{}", module);
+    if let Some(out) = compile_and_run(&module, "array_sum") {
+        assert_eq!(out, "10");
+    }
+}
+
+fn assign(name: &str, op: &str, value: Expr) -> Stmt {
+    Stmt::Assign {
+        target: var(name),
+        operator: Token::dummy(op),
+        value,
+    }
+}
+
+fn if_then(cond: Expr, then: Stmt) -> Stmt {
+    Stmt::If {
+        condition: cond,
+        then_branch: Box::new(then),
+        else_branch: None,
+    }
+}
+
+fn labeled_while(label: Option<&str>, cond: Expr, body: Vec<Stmt>) -> Stmt {
+    Stmt::While {
+        condition: cond,
+        body: Box::new(block(body)),
+        label: label.map(Token::dummy),
+    }
+}
+
+fn jump(keyword: &str, label: Option<&str>) -> Stmt {
+    let keyword = Token::dummy(keyword);
+    let label = label.map(Token::dummy);
+    if keyword.lexeme == "break" {
+        Stmt::Break { keyword, label }
+    } else {
+        Stmt::Continue { keyword, label }
+    }
+}
+
+/// func main() {
+///   var total = 0; var i = 0;
+///   'outer: while i < 10 {
+///     i += 1;
+///     if i == 2 { continue; }
+///     var j = 0;
+///     while true {
+///       j += 1;
+///       if j > i { continue 'outer; }
+///       if i == 5 { break 'outer; }
+///       total += 1;
+///     }
+///   }
+///   return total;   // 1 + 3 + 4 = 8
+/// }
+fn labeled_loops_fn() -> Stmt {
+    let inner = labeled_while(
+        None,
+        Expr::Literal(ArtValue::Bool(true)),
+        vec![
+            assign("j", "+=", int(1)),
+            if_then(
+                bin(var("j"), ">", var("i")),
+                jump("continue", Some("'outer")),
+            ),
+            if_then(bin(var("i"), "==", int(5)), jump("break", Some("'outer"))),
+            assign("total", "+=", int(1)),
+        ],
+    );
+    let outer = labeled_while(
+        Some("'outer"),
+        bin(var("i"), "<", int(10)),
+        vec![
+            assign("i", "+=", int(1)),
+            if_then(bin(var("i"), "==", int(2)), jump("continue", None)),
+            let_bind("j", int(0)),
+            inner,
+        ],
+    );
+    make_fn(
+        "main",
+        &[],
+        block(vec![
+            let_bind("total", int(0)),
+            let_bind("i", int(0)),
+            outer,
+            ret(var("total")),
+        ]),
+    )
+}
+
+/// `break`/`continue` jump to the exit/head of the targeted loop; labeled
+/// forms skip past the inner loop.
+#[test]
+fn lower_labeled_break_and_continue() {
+    let f = lower_function(&labeled_loops_fn()).expect("lower labeled loops");
+    let branches_to = |target: &str| {
+        f.body
+            .iter()
+            .filter(|i| matches!(i, ir::Instr::Br(l) if l == target))
+            .count()
+    };
+    // Outer loop blocks are created first: head 0, body 1, exit 2; the inner
+    // loop (after the `if` blocks 3/4) gets head 5, body 6, exit 7.
+    // Outer head: entry, `continue`, `continue 'outer`, and the back-edge.
+    assert_eq!(branches_to("main_while_head_0"), 4);
+    // `break 'outer` jumps straight out of both loops.
+    assert_eq!(branches_to("main_while_exit_2"), 1);
+    assert_eq!(branches_to("main_while_exit_7"), 0);
+}
+
+/// `break` or `continue` naming a loop that does not enclose it stays out of AOT.
+#[test]
+fn lower_break_without_target_is_rejected() {
+    let body = block(vec![labeled_while(
+        None,
+        int(1),
+        vec![jump("break", Some("'missing"))],
+    )]);
+    assert!(lower_function(&make_fn("bad", &[], body)).is_none());
+}
+
+/// Roundtrip: labeled `continue`/`break` across nested loops -> 8
+#[test]
+fn roundtrip_labeled_loops() {
+    let main_ir = lower_function(&labeled_loops_fn()).expect("lower main");
+    let module = emit_llvm_module(&[main_ir], "main", OverflowMode::Trap);
+    if let Some(out) = compile_and_run(&module, "labeled_loops") {
+        assert_eq!(out, "8");
+    }
+}
+
+fn unary(op: &str, e: Expr) -> Expr {
+    Expr::Unary {
+        operator: Token::dummy(op),
+        right: Box::new(e),
+    }
+}
+
+/// `%`, bitwise operators and `~`: one instruction each, plus deopt guards for a
+/// dynamic divisor and a dynamic shift amount.
+#[test]
+fn lower_remainder_and_bitwise_ops() {
+    // func bits(a, b) { return ((a % b) & (a | 3)) ^ (~a << b) }
+    let body = block(vec![ret(bin(
+        bin(
+            bin(var("a"), "%", var("b")),
+            "&",
+            bin(var("a"), "|", int(3)),
+        ),
+        "^",
+        bin(unary("~", var("a")), "<<", var("b")),
+    ))]);
+    let f = lower_function(&make_fn("bits", &["a", "b"], body)).expect("lower_fn failed");
+    let text = f.emit_text();
+    for op in ["rem i64", "and i64", "or i64", "xor i64", "shl i64"] {
+        assert!(text.contains(op), "missing `{}`:\n{}", op, text);
+    }
+    // `~a` is `a xor -1`.
+    assert!(
+        f.body
+            .iter()
+            .any(|i| matches!(i, ir::Instr::Xor(_, _, m) if m == "-1"))
+    );
+    assert!(text.contains("div_fail"), "{}", text);
+    assert!(text.contains("shift_fail"), "{}", text);
+    assert_eq!(
+        f.body
+            .iter()
+            .filter(|i| matches!(i, ir::Instr::Deopt))
+            .count(),
+        2
+    );
+}
+
+/// Literal operands that always fail keep the function out of AOT.
+#[test]
+fn lower_literal_zero_divisor_and_bad_shift_are_rejected() {
+    for (op, rhs) in [("/", 0), ("%", 0), ("<<", 64), (">>", -1)] {
+        let body = block(vec![ret(bin(var("a"), op, int(rhs)))]);
+        assert!(
+            lower_function(&make_fn("bad", &["a"], body)).is_none(),
+            "`a {} {}` should not lower",
+            op,
+            rhs
+        );
+    }
+}
+
+/// Roundtrip: (12 & 10 | (12 ^ 10) << 2) + -7 % 3 + (1 << 62 >> 60) + ~12 -> 14
+#[test]
+fn roundtrip_bitwise_ops() {
+    let body = block(vec![
+        let_bind("a", int(12)),
+        let_bind("b", int(10)),
+        let_bind(
+            "x",
+            bin(
+                bin(var("a"), "&", var("b")),
+                "|",
+                bin(bin(var("a"), "^", var("b")), "<<", int(2)),
+            ),
+        ),
+        let_bind("y", bin(int(-7), "%", int(3))),
+        let_bind("z", bin(bin(int(1), "<<", int(62)), ">>", int(60))),
+        ret(bin(
+            bin(bin(var("x"), "+", var("y")), "+", var("z")),
+            "+",
+            unary("~", var("a")),
+        )),
+    ]);
+    let main_ir = lower_function(&make_fn("main", &[], body)).expect("lower main");
+    let module = emit_llvm_module(&[main_ir], "main", OverflowMode::Trap);
+    assert!(module.contains("srem i64"), "{}", module);
+    assert!(module.contains("ashr i64"), "{}", module);
+    if let Some(out) = compile_and_run(&module, "bitwise_ops") {
+        assert_eq!(out, "14");
+    }
+}
+
+fn for_range(elem: &str, from: Expr, to: Expr, inclusive: bool, body: Vec<Stmt>) -> Stmt {
+    Stmt::For {
+        element: Token::dummy(elem),
+        pattern: None,
+        iterator: Expr::Range {
+            start: Box::new(from),
+            end: Box::new(to),
+            inclusive,
+            operator: Token::dummy(if inclusive { "..=" } else { ".." }),
+        },
+        body: Box::new(block(body)),
+        label: None,
+    }
+}
+
+/// func main() {
+///   let total = 0;
+///   for i in 1..=4 { if i == 2 { continue; } total += i; }
+///   for i in 0..3 { total += 10; }
+///   return total;   // 1 + 3 + 4 + 30 = 38
+/// }
+fn for_ranges_fn() -> Stmt {
+    make_fn(
+        "main",
+        &[],
+        block(vec![
+            let_bind("total", int(0)),
+            for_range(
+                "i",
+                int(1),
+                int(4),
+                true,
+                vec![
+                    if_then(bin(var("i"), "==", int(2)), jump("continue", None)),
+                    assign("total", "+=", var("i")),
+                ],
+            ),
+            for_range(
+                "i",
+                int(0),
+                int(3),
+                false,
+                vec![assign("total", "+=", int(10))],
+            ),
+            ret(var("total")),
+        ]),
+    )
+}
+
+/// Integer ranges become counted loops; `continue` jumps to the increment,
+/// not back to the bounds check.
+#[test]
+fn lower_for_range_counted_loop() {
+    let f = lower_function(&for_ranges_fn()).expect("lower for ranges");
+    let branches_to = |target: &str| {
+        f.body
+            .iter()
+            .filter(|i| matches!(i, ir::Instr::Br(l) if l == target))
+            .count()
+    };
+    // First loop: head 0, body 1, step 2, exit 3 (if blocks 4/5, increment 6).
+    // Step: `continue` and the fallthrough at the end of the body.
+    assert_eq!(branches_to("main_for_step_2"), 2);
+    assert_eq!(branches_to("main_for_head_0"), 2);
+    let preds: Vec<&ir::CmpPred> = f
+        .body
+        .iter()
+        .filter_map(|i| match i {
+            ir::Instr::ICmp(_, pred, _, _) => Some(pred),
+            _ => None,
+        })
+        .collect();
+    assert!(preds.contains(&&ir::CmpPred::Le), "{:?}", f.body);
+    assert!(preds.contains(&&ir::CmpPred::Lt), "{:?}", f.body);
+}
+
+/// Iterating anything but an integer range stays out of AOT.
+#[test]
+fn lower_for_over_collection_is_rejected() {
+    let body = block(vec![Stmt::For {
+        element: Token::dummy("x"),
+        pattern: None,
+        iterator: var("xs"),
+        body: Box::new(block(vec![])),
+        label: None,
+    }]);
+    assert!(lower_function(&make_fn("bad", &["xs"], body)).is_none());
+}
+
+/// Roundtrip: inclusive and exclusive ranges with `continue` -> 38
+#[test]
+fn roundtrip_for_ranges() {
+    let main_ir = lower_function(&for_ranges_fn()).expect("lower main");
+    let module = emit_llvm_module(&[main_ir], "main", OverflowMode::Trap);
+    if let Some(out) = compile_and_run(&module, "for_ranges") {
+        assert_eq!(out, "38");
+    }
+}
+
+/// Reads of a scalar `const` are lowered to the folded literal; a function that
+/// reads any other constant stays out of AOT.
+#[test]
+fn lower_inlines_scalar_constants() {
+    let mut consts = core::const_eval::ConstEnv::new();
+    consts.insert("K".to_string(), ArtValue::Int(3));
+    consts.insert("NAME".to_string(), ArtValue::String("art".into()));
+
+    let scale = make_fn(
+        "scale",
+        &["x"],
+        block(vec![ret(bin(var("x"), "*", var("K")))]),
+    );
+    let f = ir::lower_stmt_with_consts(&scale, &consts).expect("lower scale");
+    assert_eq!(f.params.len(), 1, "{:?}", f.params);
+    assert!(
+        f.body
+            .iter()
+            .any(|i| matches!(i, ir::Instr::Mul(_, _, k) if k == "3")),
+        "{:?}",
+        f.body
+    );
+
+    let named = make_fn("named", &[], block(vec![ret(var("NAME"))]));
+    assert!(ir::lower_stmt_with_consts(&named, &consts).is_none());
+}
@@ -25,6 +25,7 @@ fn golden_lower_intrinsic_call() {
             value: Some(call_expr),
         }),
         method_owner: None,
+        public: false,
     };
 
     let irf = lower_stmt(&func).expect("lowering failed");
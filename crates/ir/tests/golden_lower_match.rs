@@ -44,10 +44,12 @@ fn golden_lower_match_literals() {
         body: std::rc::Rc::new(Stmt::Block {
             statements: vec![Stmt::Match {
                 expr: match_expr,
+                start: Token::dummy("x"),
                 cases,
             }],
         }),
         method_owner: None,
+        public: false,
     };
 
     let irf = lower_stmt(&func).expect("lowering failed");
@@ -0,0 +1,84 @@
+use core::Token;
+use core::ast::{ArtValue, Expr, FunctionParam, MatchPattern, Stmt};
+use ir::lower_stmt;
+
+// Range, or- and @-patterns over an Int lower to icmp/and/or before the branch.
+// func grade(n) -> i64 { match n { d @ (1..=9 | 20): return d, _ => return 0 } }
+fn grade_fn(first: MatchPattern, arm0: Expr) -> Stmt {
+    let n = Token::dummy("n");
+    let cases = vec![
+        (first, None, Stmt::Return { value: Some(arm0) }),
+        (
+            MatchPattern::Wildcard,
+            None,
+            Stmt::Return {
+                value: Some(Expr::Literal(ArtValue::Int(0))),
+            },
+        ),
+    ];
+    Stmt::Function {
+        type_params: None,
+        is_async: false,
+        name: Token::dummy("grade"),
+        params: vec![FunctionParam {
+            name: n.clone(),
+            ty: None,
+        }],
+        return_type: Some("i64".to_string()),
+        body: std::rc::Rc::new(Stmt::Block {
+            statements: vec![Stmt::Match {
+                expr: Expr::Variable { name: n },
+                start: Token::dummy("n"),
+                cases,
+            }],
+        }),
+        method_owner: None,
+        public: false,
+    }
+}
+
+#[test]
+fn golden_lower_match_range_or_at() {
+    let pattern = MatchPattern::At {
+        name: Token::dummy("d"),
+        pattern: Box::new(MatchPattern::Or(vec![
+            MatchPattern::Range {
+                start: ArtValue::Int(1),
+                end: ArtValue::Int(9),
+                inclusive: true,
+            },
+            MatchPattern::Literal(ArtValue::Int(20)),
+        ])),
+    };
+    let arm0 = Expr::Variable {
+        name: Token::dummy("d"),
+    };
+    let text = lower_stmt(&grade_fn(pattern, arm0))
+        .expect("lowering failed")
+        .emit_text();
+    for expected in [
+        "%grade_1 = icmp ge i64 n, 1",
+        "%grade_2 = icmp le i64 n, 9",
+        "%t0 = and i64 %grade_1, %grade_2",
+        "%grade_4 = icmp eq i64 n, 20",
+        "%t1 = or i64 %t0, %grade_4",
+        "br_cond %t1, grade_case0, grade_case1",
+        "phi i64 [ n, grade_case0 ]",
+    ] {
+        assert!(
+            text.contains(expected),
+            "missing `{}` in:\n{}",
+            expected,
+            text
+        );
+    }
+}
+
+#[test]
+fn golden_lower_match_struct_pattern_is_rejected() {
+    let pattern = MatchPattern::Struct {
+        name: Token::dummy("Point"),
+        fields: vec![],
+    };
+    assert!(lower_stmt(&grade_fn(pattern, Expr::Literal(ArtValue::Int(1)))).is_none());
+}
@@ -55,10 +55,12 @@ fn golden_lower_match_variant() {
         body: std::rc::Rc::new(Stmt::Block {
             statements: vec![Stmt::Match {
                 expr: match_expr,
+                start: Token::dummy("e"),
                 cases,
             }],
         }),
         method_owner: None,
+        public: false,
     };
 
     let irf = lower_stmt(&func).expect("lowering failed");
@@ -33,6 +33,7 @@ fn golden_lower_sub() {
             value: Some(ret_expr),
         }),
         method_owner: None,
+        public: false,
     };
     let irf = lower_stmt(&func).expect("lowering failed");
     let text = irf.emit_text();
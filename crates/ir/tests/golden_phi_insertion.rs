@@ -29,6 +29,7 @@ fn golden_phi_insertion() {
             statements: vec![if_stmt],
         }),
         method_owner: None,
+        public: false,
     };
     let irf = lower_stmt(&func).expect("lowering failed");
     let text = irf.emit_text();
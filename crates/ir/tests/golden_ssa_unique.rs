@@ -26,6 +26,7 @@ fn golden_ssa_unique() {
             value: Some(ret_expr),
         }),
         method_owner: None,
+        public: false,
     };
 
     let irf = lower_stmt(&func).expect("lowering failed");
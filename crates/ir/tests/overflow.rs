@@ -0,0 +1,143 @@
+use core::OverflowMode;
+use ir::c_emitter::emit_c_program;
+use ir::llvm_emitter::emit_llvm_module;
+use ir::{Function, Instr, Type};
+
+/// func @f(i64 a, i64 b) -> i64 { t0 = a + b; t1 = t0 * b; t2 = t1 / b; ret t2 }
+fn arith_fn() -> Function {
+    Function {
+        name: "f".to_string(),
+        params: vec![("a".to_string(), Type::I64), ("b".to_string(), Type::I64)],
+        ret: Some(Type::I64),
+        body: vec![
+            Instr::Add("%t0".into(), "a".into(), "b".into()),
+            Instr::Mul("%t1".into(), "%t0".into(), "b".into()),
+            Instr::Div("%t2".into(), "%t1".into(), "b".into()),
+            Instr::Ret(Some("%t2".into())),
+        ],
+    }
+}
+
+#[test]
+fn llvm_trap_checks_every_int_op() {
+    let module = emit_llvm_module(&[arith_fn()], "f", OverflowMode::Trap);
+    assert!(module.contains("define internal void @__art_overflow(i1 %o)"));
+    assert!(module.contains("call { i64, i1 } @llvm.sadd.with.overflow.i64(i64 %a, i64 %b)"));
+    assert!(module.contains("call { i64, i1 } @llvm.smul.with.overflow.i64(i64 %t0, i64 %b)"));
+    assert!(module.contains("icmp eq i64 %t1, -9223372036854775808"));
+    assert_eq!(module.matches("call void @__art_overflow(").count(), 3);
+    // `-1` nunca chega ao `sdiv`.
+    assert!(
+        module.contains("select i1 %div.2.m1, i64 1, i64 %b"),
+        "{}",
+        module
+    );
+}
+
+#[test]
+fn llvm_wrap_uses_plain_ops() {
+    let module = emit_llvm_module(&[arith_fn()], "f", OverflowMode::Wrap);
+    assert!(module.contains("%t0 = add i64 %a, %b"));
+    assert!(module.contains("%t1 = mul i64 %t0, %b"));
+    assert!(!module.contains("with.overflow"));
+    assert!(!module.contains("@__art_overflow"));
+}
+
+#[test]
+fn c_trap_and_wrap() {
+    let trap = emit_c_program(&[arith_fn()], "f", OverflowMode::Trap);
+    assert!(
+        trap.contains("if (__builtin_add_overflow(a, b, &v_t0))"),
+        "{}",
+        trap
+    );
+    assert!(
+        trap.contains("if (v_t1 == INT64_MIN && b == -1)"),
+        "{}",
+        trap
+    );
+
+    let wrap = emit_c_program(&[arith_fn()], "f", OverflowMode::Wrap);
+    assert!(
+        wrap.contains("v_t0 = (int64_t)((uint64_t)a + (uint64_t)b);"),
+        "{}",
+        wrap
+    );
+    assert!(!wrap.contains("__builtin_"), "{}", wrap);
+    assert!(!wrap.contains("INT64_MIN"), "{}", wrap);
+}
+
+/// func @g(i64 a, i64 b) -> i64 { t0 = a << b; t1 = t0 >> b; t2 = t1 % b; ret t2 }
+fn shift_fn() -> Function {
+    Function {
+        name: "g".to_string(),
+        params: vec![("a".to_string(), Type::I64), ("b".to_string(), Type::I64)],
+        ret: Some(Type::I64),
+        body: vec![
+            Instr::Shl("%t0".into(), "a".into(), "b".into()),
+            Instr::Shr("%t1".into(), "%t0".into(), "b".into()),
+            Instr::Rem("%t2".into(), "%t1".into(), "b".into()),
+            Instr::Ret(Some("%t2".into())),
+        ],
+    }
+}
+
+#[test]
+fn llvm_checks_zero_divisor_and_shift_range() {
+    for mode in [OverflowMode::Trap, OverflowMode::Wrap] {
+        let module = emit_llvm_module(&[arith_fn(), shift_fn()], "f", mode);
+        assert!(module.contains("define internal void @__art_check(i1 %bad)"));
+        // `Div` em `f` e `Rem` em `g`.
+        assert!(
+            module.contains("%div.2.z = icmp eq i64 %b, 0"),
+            "{}",
+            module
+        );
+        assert!(
+            module.contains("%div.2.z = icmp eq i64 %b, 0\n  call void @__art_check(i1 %div.2.z)")
+        );
+        assert_eq!(module.matches("icmp eq i64 %b, 0").count(), 2, "{}", module);
+        assert!(module.contains("icmp ugt i64 %b, 63"), "{}", module);
+        assert_eq!(
+            module.matches("call void @__art_check(").count(),
+            4,
+            "{}",
+            module
+        );
+        // O guard vem antes do shift.
+        let guard = module.find("icmp ugt i64 %b, 63").unwrap();
+        assert!(guard < module.find("shl i64 %a, %b").unwrap());
+    }
+}
+
+#[test]
+fn c_checks_shift_range() {
+    let c = emit_c_program(&[shift_fn()], "g", OverflowMode::Wrap);
+    assert_eq!(
+        c.matches("if (b < 0 || b > 63) { printf(\"shift out of range\\n\"); exit(1); }")
+            .count(),
+        2,
+        "{}",
+        c
+    );
+    assert!(
+        c.contains("if (b == 0) { printf(\"div by zero\\n\"); exit(1); }"),
+        "{}",
+        c
+    );
+}
+
+#[test]
+fn c_spells_the_smallest_int_as_int64_min() {
+    let f = Function {
+        name: "m".to_string(),
+        params: vec![],
+        ret: Some(Type::I64),
+        body: vec![
+            Instr::ConstI64("%t0".into(), i64::MIN),
+            Instr::Ret(Some("%t0".into())),
+        ],
+    };
+    let c = emit_c_program(&[f], "m", OverflowMode::Trap);
+    assert!(c.contains("v_t0 = INT64_MIN;"), "{}", c);
+}
@@ -4,6 +4,8 @@ use std::collections::HashMap;
 pub fn make_keywords() -> HashMap<String, TokenType> {
     let mut keywords = HashMap::new();
     keywords.insert("let".to_string(), TokenType::Let);
+    keywords.insert("var".to_string(), TokenType::Var);
+    keywords.insert("const".to_string(), TokenType::Const);
     keywords.insert("if".to_string(), TokenType::If);
     keywords.insert("else".to_string(), TokenType::Else);
     keywords.insert("true".to_string(), TokenType::True);
@@ -12,10 +14,16 @@ pub fn make_keywords() -> HashMap<String, TokenType> {
     keywords.insert("or".to_string(), TokenType::Or);
     keywords.insert("struct".to_string(), TokenType::Struct);
     keywords.insert("enum".to_string(), TokenType::Enum);
+    keywords.insert("type".to_string(), TokenType::Type);
+    keywords.insert("newtype".to_string(), TokenType::Newtype);
+    keywords.insert("pub".to_string(), TokenType::Pub);
     keywords.insert("match".to_string(), TokenType::Match);
     keywords.insert("case".to_string(), TokenType::Case);
     keywords.insert("func".to_string(), TokenType::Func);
+    keywords.insert("async".to_string(), TokenType::Async);
+    keywords.insert("await".to_string(), TokenType::Await);
     keywords.insert("return".to_string(), TokenType::Return);
+    keywords.insert("defer".to_string(), TokenType::Defer);
     keywords.insert("weak".to_string(), TokenType::Weak);
     keywords.insert("unowned".to_string(), TokenType::Unowned);
     keywords.insert("performant".to_string(), TokenType::Performant);
@@ -23,9 +31,13 @@ pub fn make_keywords() -> HashMap<String, TokenType> {
     keywords.insert("actor".to_string(), TokenType::Actor);
     keywords.insert("while".to_string(), TokenType::While);
     keywords.insert("for".to_string(), TokenType::For);
+    keywords.insert("break".to_string(), TokenType::Break);
+    keywords.insert("continue".to_string(), TokenType::Continue);
     keywords.insert("in".to_string(), TokenType::In);
     keywords.insert("try".to_string(), TokenType::Try);
     keywords.insert("catch".to_string(), TokenType::Catch);
+    keywords.insert("impl".to_string(), TokenType::Impl);
+    keywords.insert("trait".to_string(), TokenType::Trait);
     keywords.insert("component".to_string(), TokenType::Component);
     keywords.insert("view".to_string(), TokenType::View);
     keywords.insert("state".to_string(), TokenType::State);
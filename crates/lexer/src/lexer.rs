@@ -40,16 +40,37 @@ impl Lexer {
             '[' => self.add_token(TokenType::LeftBracket),
             ']' => self.add_token(TokenType::RightBracket),
             '$' => self.add_token(TokenType::Dollar),
+            '@' => self.add_token(TokenType::At),
             ',' => self.add_token(TokenType::Comma),
-            '.' => self.add_token(TokenType::Dot),
+            '.' => {
+                let token = if self.match_char('.') {
+                    if self.match_char('=') {
+                        TokenType::DotDotEqual
+                    } else {
+                        TokenType::DotDot
+                    }
+                } else {
+                    TokenType::Dot
+                };
+                self.add_token(token)
+            }
             '-' => {
                 if self.match_char('>') {
                     self.add_token(TokenType::Arrow);
+                } else if self.match_char('=') {
+                    self.add_token(TokenType::MinusEqual);
                 } else {
                     self.add_token(TokenType::Minus);
                 }
             }
-            '+' => self.add_token(TokenType::Plus),
+            '+' => {
+                let token = if self.match_char('=') {
+                    TokenType::PlusEqual
+                } else {
+                    TokenType::Plus
+                };
+                self.add_token(token);
+            }
             ';' => self.add_token(TokenType::Semicolon),
             ':' => {
                 let token = if self.match_char(':') {
@@ -59,8 +80,29 @@ impl Lexer {
                 };
                 self.add_token(token);
             }
-            '*' => self.add_token(TokenType::Star),
-            '?' => self.add_token(TokenType::Question),
+            '*' => {
+                let token = if self.match_char('=') {
+                    TokenType::StarEqual
+                } else {
+                    TokenType::Star
+                };
+                self.add_token(token);
+            }
+            '?' => {
+                // `?.` só quando seguido de identificador; nos demais casos
+                // fica o `?` pós-fixo de `w?`.
+                let token = if self.match_char('?') {
+                    TokenType::QuestionQuestion
+                } else if self.peek() == '.'
+                    && (self.peek_next().is_alphabetic() || self.peek_next() == '_')
+                {
+                    self.advance();
+                    TokenType::QuestionDot
+                } else {
+                    TokenType::Question
+                };
+                self.add_token(token);
+            }
             '_' => {
                 // If underscore is followed by alphanumeric, treat as identifier (e.g. _tmp).
                 if self.peek().is_alphanumeric() {
@@ -88,34 +130,47 @@ impl Lexer {
             '<' => {
                 let token = if self.match_char('=') {
                     TokenType::LessEqual
+                } else if self.match_char('<') {
+                    TokenType::LessLess
                 } else {
                     TokenType::Less
                 };
                 self.add_token(token);
             }
             '>' => {
+                // `>>` também fecha dois genéricos (`Array<Array<Int>>`); o parser
+                // divide o token quando espera um `>` de tipo.
                 let token = if self.match_char('=') {
                     TokenType::GreaterEqual
+                } else if self.match_char('>') {
+                    TokenType::GreaterGreater
                 } else {
                     TokenType::Greater
                 };
                 self.add_token(token);
             }
+            '%' => self.add_token(TokenType::Percent),
+            '&' => self.add_token(TokenType::Ampersand),
+            '^' => self.add_token(TokenType::Caret),
+            '~' => self.add_token(TokenType::Tilde),
             '/' => {
                 if self.match_char('/') {
                     while self.peek() != '\n' && !self.is_at_end() {
                         self.advance();
                     }
+                } else if self.match_char('=') {
+                    self.add_token(TokenType::SlashEqual);
                 } else {
                     self.add_token(TokenType::Slash);
                 }
             }
             '|' => {
-                if self.match_char('>') {
-                    self.add_token(TokenType::PipeGreater);
+                let token = if self.match_char('>') {
+                    TokenType::PipeGreater
                 } else {
-                    return Err(self.error_current("Unexpected '|' (did you mean '|>'?)"));
-                }
+                    TokenType::Pipe
+                };
+                self.add_token(token);
             }
             ' ' | '\r' | '\t' => (),
             '\n' => {
@@ -123,6 +178,17 @@ impl Lexer {
                 self.line_start = self.current;
             }
             '"' => self.string()?,
+            '\'' => {
+                // Rótulo de loop (`'outer`). Não há literal de char na linguagem.
+                if self.peek().is_alphabetic() || self.peek() == '_' {
+                    while self.peek().is_alphanumeric() || self.peek() == '_' {
+                        self.advance();
+                    }
+                    self.add_token(TokenType::Label);
+                } else {
+                    return Err(self.error_current("Expect label name after `'`"));
+                }
+            }
             'f' => {
                 // <<< LÓGICA PARA 'f'
                 if self.peek() == '"' {
@@ -183,41 +249,131 @@ impl Lexer {
         Ok(())
     }
 
+    /// Literal numérico: inteiros (`42`, `1_000`, `0xFF`, `0o17`, `0b1010`) viram
+    /// `Int` com precisão total de i64; com parte fracionária ou expoente
+    /// (`1.5`, `2e10`, `6.02E-23`), `Float`. `_` separa dígitos e é ignorado.
     fn number(&mut self) -> DiagResult<()> {
-        while self.peek().is_ascii_digit() {
-            self.advance();
+        if self.source[self.start] == '0' {
+            let radix = match self.peek() {
+                'x' | 'X' => Some(16),
+                'o' | 'O' => Some(8),
+                'b' | 'B' => Some(2),
+                _ => None,
+            };
+            if let Some(radix) = radix {
+                self.advance();
+                return self.radix_number(radix);
+            }
         }
 
-        if self.peek() == '.' && self.peek_next().is_ascii_digit() {
+        self.digits(10);
+        // Depois de `.` o número é um campo posicional: `t.0.1` são dois acessos.
+        let after_dot = self
+            .tokens
+            .last()
+            .is_some_and(|t| t.token_type == TokenType::Dot);
+        let mut is_float = false;
+        if !after_dot && self.peek() == '.' && self.peek_next().is_ascii_digit() {
+            is_float = true;
             self.advance();
-            while self.peek().is_ascii_digit() {
+            self.digits(10);
+        }
+        if !after_dot && matches!(self.peek(), 'e' | 'E') {
+            let signed = matches!(self.peek_next(), '+' | '-');
+            let first_digit = self.source.get(self.current + 1 + usize::from(signed));
+            if first_digit.is_some_and(|c| c.is_ascii_digit()) {
+                is_float = true;
                 self.advance();
+                if signed {
+                    self.advance();
+                }
+                self.digits(10);
             }
         }
 
-        let value: String = self.source[self.start..self.current].iter().collect();
-        match value.parse::<f64>() {
+        let text: String = self.source[self.start..self.current]
+            .iter()
+            .filter(|c| **c != '_')
+            .collect();
+        if is_float {
+            match text.parse::<f64>() {
+                Ok(num) if num.is_finite() => self.add_token(TokenType::Float(num)),
+                _ => return Err(self.error_literal("Float literal out of range")),
+            }
+        } else {
+            match text.parse::<i64>() {
+                Ok(num) => self.add_token(TokenType::Int(num)),
+                Err(_) if self.negated_min(text.parse::<u64>().ok()) => {
+                    self.add_token(TokenType::Int(i64::MIN))
+                }
+                Err(_) => return Err(self.error_literal("Integer literal out of range for Int")),
+            }
+        }
+        Ok(())
+    }
+
+    /// Corpo de `0x`/`0o`/`0b`; o prefixo já foi consumido.
+    fn radix_number(&mut self, radix: u32) -> DiagResult<()> {
+        let digits_start = self.current;
+        self.digits(radix);
+        if self.peek().is_ascii_alphanumeric() {
+            self.advance();
+            return Err(self.error_literal(&format!(
+                "Invalid digit '{}' in base-{} literal",
+                self.source[self.current - 1],
+                radix
+            )));
+        }
+        let text: String = self.source[digits_start..self.current]
+            .iter()
+            .filter(|c| **c != '_')
+            .collect();
+        if text.is_empty() {
+            return Err(self.error_literal("Expected digits after number prefix"));
+        }
+        match i64::from_str_radix(&text, radix) {
             Ok(num) => {
-                self.add_token(TokenType::Number(num));
+                self.add_token(TokenType::Int(num));
                 Ok(())
             }
-            Err(_) => {
-                // Valor capturado corresponde ao padrão de dígitos (e opcional ponto + dígitos), então erro aqui é raro;
-                // ainda assim, geramos diagnóstico ao invés de panic para robustez.
-                let col = if self.start >= self.line_start {
-                    self.start - self.line_start + 1
-                } else {
-                    1
-                };
-                Err(Diagnostic::new(
-                    DiagnosticKind::Lex,
-                    "Invalid number literal",
-                    Span::new(self.start, self.current, self.line, col),
-                ))
+            Err(_) if self.negated_min(u64::from_str_radix(&text, radix).ok()) => {
+                self.add_token(TokenType::Int(i64::MIN));
+                Ok(())
             }
+            Err(_) => Err(self.error_literal("Integer literal out of range for Int")),
         }
     }
 
+    /// Magnitude 2^63 logo após um `-`: vira `Int(i64::MIN)` e o parser junta
+    /// com o `-` unário (`-9223372036854775808`). Sem o `-` unário na frente, o
+    /// parser reporta o literal como fora da faixa.
+    fn negated_min(&self, magnitude: Option<u64>) -> bool {
+        magnitude == Some(1 << 63)
+            && self
+                .tokens
+                .last()
+                .is_some_and(|t| t.token_type == TokenType::Minus)
+    }
+
+    fn digits(&mut self, radix: u32) {
+        while self.peek().is_digit(radix) || self.peek() == '_' {
+            self.advance();
+        }
+    }
+
+    fn error_literal(&self, msg: &str) -> Diagnostic {
+        let col = if self.start >= self.line_start {
+            self.start - self.line_start + 1
+        } else {
+            1
+        };
+        Diagnostic::new(
+            DiagnosticKind::Lex,
+            msg,
+            Span::new(self.start, self.current, self.line, col),
+        )
+    }
+
     fn identifier(&mut self) {
         while self.peek().is_alphanumeric() || self.peek() == '_' {
             self.advance();
@@ -225,20 +381,28 @@ impl Lexer {
         let text: String = self.source[self.start..self.current].iter().collect();
         let token_type = match text.as_str() {
             "let" => TokenType::Let,
+            "var" => TokenType::Var,
+            "const" => TokenType::Const,
             "if" => TokenType::If,
             "else" => TokenType::Else,
             "true" => TokenType::True,
             "false" => TokenType::False,
             "struct" => TokenType::Struct,
             "enum" => TokenType::Enum,
+            "type" => TokenType::Type,
+            "newtype" => TokenType::Newtype,
+            "pub" => TokenType::Pub,
             "and" => TokenType::And,
             "or" => TokenType::Or,
             "match" => TokenType::Match,
             "case" => TokenType::Case,
             "func" => TokenType::Func,
+            "async" => TokenType::Async,
+            "await" => TokenType::Await,
             "performant" => TokenType::Performant,
             "return" => TokenType::Return,
             "yield" => TokenType::Yield,
+            "defer" => TokenType::Defer,
             "import" => TokenType::Import,
             "none" => TokenType::None,
             "as" => TokenType::As,
@@ -248,10 +412,13 @@ impl Lexer {
             "actor" => TokenType::Actor,
             "while" => TokenType::While,
             "for" => TokenType::For,
+            "break" => TokenType::Break,
+            "continue" => TokenType::Continue,
             "in" => TokenType::In,
             "try" => TokenType::Try,
             "catch" => TokenType::Catch,
             "impl" => TokenType::Impl,
+            "trait" => TokenType::Trait,
             "component" => TokenType::Component,
             "view" => TokenType::View,
             "state" => TokenType::State,
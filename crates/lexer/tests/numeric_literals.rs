@@ -0,0 +1,88 @@
+use core::TokenType;
+use diagnostics::DiagnosticKind;
+use lexer::lexer::Lexer;
+
+fn lex(src: &str) -> Vec<TokenType> {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lexer should succeed");
+    tokens.into_iter().map(|t| t.token_type).collect()
+}
+
+fn lex_error(src: &str) -> String {
+    let mut lx = Lexer::new(src.to_string());
+    let err = lx.scan_tokens().expect_err("should err");
+    assert_eq!(err.kind, DiagnosticKind::Lex);
+    err.message
+}
+
+#[test]
+fn integers_and_floats_are_lexed_separately() {
+    assert_eq!(
+        lex("42 1.0 9007199254740993 9223372036854775807"),
+        vec![
+            TokenType::Int(42),
+            TokenType::Float(1.0),
+            TokenType::Int(9_007_199_254_740_993),
+            TokenType::Int(i64::MAX),
+            TokenType::Eof,
+        ]
+    );
+    // `..` de intervalo não é parte decimal.
+    assert_eq!(
+        lex("1..5"),
+        vec![
+            TokenType::Int(1),
+            TokenType::DotDot,
+            TokenType::Int(5),
+            TokenType::Eof
+        ]
+    );
+}
+
+#[test]
+fn radix_prefixes_separators_and_exponents() {
+    assert_eq!(
+        lex("0xFF 0o17 0b1010 1_000_000 0xdead_BEEF 2e3 1.5E-2 6e+1 1_0.2_5"),
+        vec![
+            TokenType::Int(255),
+            TokenType::Int(15),
+            TokenType::Int(10),
+            TokenType::Int(1_000_000),
+            TokenType::Int(0xdead_beef),
+            TokenType::Float(2000.0),
+            TokenType::Float(0.015),
+            TokenType::Float(60.0),
+            TokenType::Float(10.25),
+            TokenType::Eof,
+        ]
+    );
+    // Sem dígitos depois do `e`, ele começa um identificador.
+    assert_eq!(
+        lex("3em"),
+        vec![TokenType::Int(3), TokenType::Identifier, TokenType::Eof]
+    );
+}
+
+#[test]
+fn invalid_literals_are_lex_errors() {
+    assert_eq!(
+        lex_error("9223372036854775808"),
+        "Integer literal out of range for Int"
+    );
+    assert_eq!(
+        lex_error("0x1_0000_0000_0000_0000"),
+        "Integer literal out of range for Int"
+    );
+    // So logo apos `-` a magnitude 2^63 vira `Int(i64::MIN)`; o parser junta.
+    assert_eq!(
+        lex("-9223372036854775808"),
+        vec![TokenType::Minus, TokenType::Int(i64::MIN), TokenType::Eof]
+    );
+    assert_eq!(
+        lex_error("-9223372036854775809"),
+        "Integer literal out of range for Int"
+    );
+    assert_eq!(lex_error("1e400"), "Float literal out of range");
+    assert_eq!(lex_error("0b102"), "Invalid digit '2' in base-2 literal");
+    assert_eq!(lex_error("0x;"), "Expected digits after number prefix");
+}
@@ -26,14 +26,18 @@ pub fn parse_precedence(parser: &mut Parser, precedence: u8) -> Expr {
 pub fn parse_prefix(parser: &mut Parser) -> Expr {
     let token = parser.advance();
     match token.token_type {
-        TokenType::Number(n) => {
-            let art_val = if n.fract() == 0.0 {
-                core::ast::ArtValue::Int(n as i64)
-            } else {
-                core::ast::ArtValue::Float(n)
-            };
-            Expr::Literal(art_val)
+        // `Int(i64::MIN)` só sai do lexer como a magnitude 2^63 depois de um
+        // `-`; aqui o `-` era binário (`a - 9223372036854775808`).
+        TokenType::Int(i64::MIN) => {
+            parser.diagnostics.push(diagnostics::Diagnostic::new(
+                diagnostics::DiagnosticKind::Parse,
+                "Integer literal out of range for Int".to_string(),
+                diagnostics::Span::new(token.start, token.end, token.line, token.col),
+            ));
+            Expr::Literal(core::ast::ArtValue::Int(i64::MIN))
         }
+        TokenType::Int(n) => Expr::Literal(core::ast::ArtValue::Int(n)),
+        TokenType::Float(n) => Expr::Literal(core::ast::ArtValue::Float(n)),
         TokenType::String(s) => Expr::Literal(core::ast::ArtValue::String(core::intern_arc(&s))),
         TokenType::InterpolatedString(s) => parser.parse_interpolated_string(s),
         TokenType::True => Expr::Literal(core::ast::ArtValue::Bool(true)),
@@ -97,7 +101,9 @@ pub fn parse_prefix(parser: &mut Parser) -> Expr {
                 expression: Box::new(expr),
             }
         }
-        TokenType::Identifier => Expr::Variable { name: token },
+        TokenType::Identifier => Expr::Variable {
+            name: parser.unqualify(token),
+        },
         TokenType::Spawn => {
             // parse spawn actor { ... } as an expression returning an actor id
             // consume 'actor' and the block
@@ -106,7 +112,7 @@ pub fn parse_prefix(parser: &mut Parser) -> Expr {
                 // consume 'actor'
                 parser.advance();
                 parser.consume(TokenType::LeftBrace, "Expect '{' to start actor body.");
-                let body = crate::statements::block(parser);
+                let body = parser.in_function(false, crate::statements::block);
                 Expr::SpawnActor { body }
             } else {
                 parser.diagnostics.push(diagnostics::Diagnostic::new(
@@ -126,7 +132,33 @@ pub fn parse_prefix(parser: &mut Parser) -> Expr {
             let inner = parse_precedence(parser, Precedence::Unary as u8);
             Expr::Unowned(Box::new(inner))
         }
-        TokenType::Bang | TokenType::Minus => {
+        TokenType::Await => {
+            let value = parse_precedence(parser, Precedence::Unary as u8);
+            match parser.await_context() {
+                None => {}
+                // Um `defer` roda na saída do escopo, onde a task não pode suspender.
+                Some(true) if parser.reject_in_defer(&token) => {}
+                Some(true) => parser.awaits.push(token.clone()),
+                Some(false) => {
+                    parser.diagnostics.push(diagnostics::Diagnostic::new(
+                        diagnostics::DiagnosticKind::Parse,
+                        "'await' outside of an async function.".to_string(),
+                        diagnostics::Span::new(token.start, token.end, token.line, token.col),
+                    ));
+                }
+            }
+            Expr::Await {
+                keyword: token,
+                value: Box::new(value),
+            }
+        }
+        // `-9223372036854775808` é o menor `Int`, não a negação de um literal
+        // fora da faixa.
+        TokenType::Minus if parser.peek().token_type == TokenType::Int(i64::MIN) => {
+            parser.advance();
+            Expr::Literal(core::ast::ArtValue::Int(i64::MIN))
+        }
+        TokenType::Bang | TokenType::Minus | TokenType::Tilde => {
             let right = parse_precedence(parser, Precedence::Unary as u8);
             Expr::Unary {
                 operator: token,
@@ -134,6 +166,7 @@ pub fn parse_prefix(parser: &mut Parser) -> Expr {
             }
         }
         TokenType::Less => parse_template_expr(parser, token),
+        TokenType::Pipe | TokenType::Func => lambda(parser, token),
         TokenType::Dot => {
             let variant_name =
                 parser.consume(TokenType::Identifier, "Expect enum variant name after '.'");
@@ -215,7 +248,18 @@ pub fn parse_infix(parser: &mut Parser, left: Expr, operator: Token) -> Expr {
             }
         }
         TokenType::LeftParen => finish_call(parser, left),
+        TokenType::LeftBracket => finish_index(parser, left, operator),
         TokenType::Dot => {
+            // `t.0`: campo posicional de tupla ou struct tupla.
+            if let TokenType::Int(n) = parser.peek().token_type {
+                let mut field = parser.advance();
+                field.token_type = TokenType::Identifier;
+                field.lexeme = n.to_string();
+                return Expr::FieldAccess {
+                    object: Box::new(left),
+                    field,
+                };
+            }
             let ident = parser.consume(TokenType::Identifier, "Expect identifier after '.'");
             // Se left é Variable e próximo é '(' trata como EnumInit nomeado
             if let Expr::Variable {
@@ -227,7 +271,8 @@ pub fn parse_infix(parser: &mut Parser, left: Expr, operator: Token) -> Expr {
                     .chars()
                     .next()
                     .map(|c| c.is_uppercase())
-                    .unwrap_or(false);
+                    .unwrap_or(false)
+                    && !parser.const_names.contains(&enum_name_tok.lexeme);
                 if is_type_like {
                     if parser.check(&TokenType::LeftParen) {
                         parser.advance(); // consume '('
@@ -269,6 +314,31 @@ pub fn parse_infix(parser: &mut Parser, left: Expr, operator: Token) -> Expr {
                 field: ident,
             }
         }
+        TokenType::QuestionDot => {
+            let field = parser.consume(TokenType::Identifier, "Expect identifier after '?.'");
+            let arguments = if parser.match_token(TokenType::LeftParen) {
+                match finish_call(parser, Expr::Literal(core::ast::ArtValue::none())) {
+                    Expr::Call { arguments, .. } => Some(arguments),
+                    _ => Some(Vec::new()),
+                }
+            } else {
+                None
+            };
+            Expr::OptionalChain {
+                object: Box::new(left),
+                field,
+                arguments,
+            }
+        }
+        TokenType::QuestionQuestion => {
+            // Associa à direita: `a ?? b ?? c` é `a ?? (b ?? c)`.
+            let right = parse_precedence(parser, precedence - 1);
+            Expr::Coalesce {
+                left: Box::new(left),
+                operator,
+                right: Box::new(right),
+            }
+        }
         TokenType::Question => {
             // Se left é Weak(...) ou já produziu algo que deve virar WeakUpgrade
             Expr::WeakUpgrade(Box::new(left))
@@ -292,6 +362,15 @@ pub fn parse_infix(parser: &mut Parser, left: Expr, operator: Token) -> Expr {
                 right: Box::new(right),
             }
         }
+        TokenType::DotDot | TokenType::DotDotEqual => {
+            let end = parse_precedence(parser, precedence);
+            Expr::Range {
+                start: Box::new(left),
+                end: Box::new(end),
+                inclusive: operator.token_type == TokenType::DotDotEqual,
+                operator,
+            }
+        }
         TokenType::PipeGreater => {
             let right = parse_precedence(parser, precedence);
             match right {
@@ -343,6 +422,76 @@ pub fn finish_call(parser: &mut Parser, callee: Expr) -> Expr {
     }
 }
 
+/// `object[index]` ou `object[start..end]`; o `[` já foi consumido.
+fn finish_index(parser: &mut Parser, object: Expr, bracket: Token) -> Expr {
+    let start = if parser.check(&TokenType::DotDot) {
+        None
+    } else {
+        // Acima de `Range`, para que `a[1..3]` continue sendo um slice.
+        let index = parse_precedence(parser, Precedence::Range as u8);
+        if !parser.check(&TokenType::DotDot) {
+            parser.consume(TokenType::RightBracket, "Expect ']' after index.");
+            return Expr::Index {
+                object: Box::new(object),
+                index: Box::new(index),
+                bracket,
+            };
+        }
+        Some(Box::new(index))
+    };
+    parser.advance(); // consome '..'
+    let end = if parser.check(&TokenType::RightBracket) {
+        None
+    } else {
+        Some(Box::new(expression(parser)))
+    };
+    parser.consume(TokenType::RightBracket, "Expect ']' after slice range.");
+    Expr::Slice {
+        object: Box::new(object),
+        start,
+        end,
+        bracket,
+    }
+}
+
+/// `|a, b| expr`, `|a| { ... }`, `|a| -> T { ... }` ou `func(a) { ... }`.
+/// `keyword` é o `|` ou `func` já consumido. O corpo é uma fronteira de loops,
+/// como o de `func`.
+fn lambda(parser: &mut Parser, keyword: Token) -> Expr {
+    let params = if keyword.token_type == TokenType::Pipe {
+        parser.parameter_list(TokenType::Pipe, "Expect '|' after lambda parameters.")
+    } else {
+        parser.consume(TokenType::LeftParen, "Expect '(' after 'func' in lambda.");
+        parser.parameter_list(TokenType::RightParen, "Expect ')' after parameters.")
+    };
+    // No fim da entrada `advance` devolve de novo o `|`, e o corpo reentraria
+    // aqui até o limite de profundidade; o erro já foi reportado acima.
+    if parser.is_at_end() {
+        return Expr::Literal(core::ast::ArtValue::none());
+    }
+    let return_type = if parser.match_token(TokenType::Arrow) {
+        Some(parser.parse_type())
+    } else {
+        None
+    };
+    let statements = if keyword.token_type == TokenType::Pipe
+        && return_type.is_none()
+        && !parser.check(&TokenType::LeftBrace)
+    {
+        let value = parser.in_function(false, expression);
+        vec![core::ast::Stmt::Return { value: Some(value) }]
+    } else {
+        parser.consume(TokenType::LeftBrace, "Expect '{' before lambda body.");
+        parser.in_function(false, crate::statements::block)
+    };
+    Expr::Lambda {
+        keyword,
+        params,
+        return_type,
+        body: std::rc::Rc::new(core::ast::Stmt::Block { statements }),
+    }
+}
+
 // ── ArtML template parsing ────────────────────────────────────────────────────
 
 /// Parse ArtML template nodes until `}` is the next token (used by `view {}`).
@@ -473,7 +622,8 @@ fn parse_attrs(parser: &mut Parser) -> Vec<TemplateAttr> {
         && !parser.check(&TokenType::Greater)
         && !parser.check(&TokenType::Slash)
     {
-        if !parser.check(&TokenType::Identifier) {
+        // `type` é palavra-chave, mas é um atributo HTML comum.
+        if !parser.check(&TokenType::Identifier) && !parser.check(&TokenType::Type) {
             break;
         }
         let name_tok = parser.advance();
@@ -77,7 +77,15 @@ impl Parser {
                     }
                 };
                 let mut sub_parser = Parser::new(tokens);
+                sub_parser.functions = self.functions.clone();
                 let expr = sub_parser.expression();
+                if let Some(keyword) = sub_parser.awaits.first() {
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::Parse,
+                        "'await' must be the whole value of a let, assignment, return or expression statement.",
+                        Span::new(keyword.start, keyword.end, keyword.line, keyword.col),
+                    ));
+                }
                 parts.push(InterpolatedPart::Expr {
                     expr: Box::new(expr),
                     format: fmt_opt,
@@ -117,7 +125,7 @@ impl Parser {
 use crate::expressions;
 use crate::precedence::Precedence;
 use crate::statements;
-use core::ast::{Expr, Program, Stmt, TemplateNode};
+use core::ast::{Expr, Program, Stmt, StructField, TemplateNode};
 use core::{Token, TokenType};
 use diagnostics::{Diagnostic, DiagnosticKind, Span};
 use std::collections::HashSet;
@@ -130,6 +138,23 @@ pub struct Parser {
     current: usize,
     pub diagnostics: Vec<Diagnostic>,
     depth: usize,
+    /// Loops envolventes (rótulo opcional de cada um), do mais externo ao mais interno.
+    /// Zerado ao entrar em corpo de função/ator: `break` não atravessa essa fronteira.
+    loops: Vec<Option<Token>>,
+    /// Funções envolventes (`true` para `async func`), da mais externa à mais interna.
+    /// Vazia no topo do arquivo, onde `await` bloqueia até a task terminar.
+    functions: Vec<bool>,
+    /// `await`s já parseados que ainda não viraram o valor de um statement.
+    pub(crate) awaits: Vec<Token>,
+    /// Dentro do corpo de um `defer` (fora de funções aninhadas nele).
+    deferring: bool,
+    /// Nomes declarados com `const` no arquivo, colhidos antes do parse para que
+    /// `LIMITE.campo` seja acesso a campo e não uma variante de enum.
+    pub(crate) const_names: HashSet<String>,
+    /// Apelidos de `import m as h`, também colhidos antes do parse: `h.x` é lido
+    /// como `x` e o `x` vai para os `items` do import (ver `unqualify`).
+    pub(crate) import_aliases: HashSet<String>,
+    qualified: Vec<(String, Token)>,
 }
 
 impl Parser {
@@ -142,11 +167,79 @@ impl Parser {
         if !matches!(tokens.last().map(|t| &t.token_type), Some(TokenType::Eof)) {
             tokens.push(Token::new(TokenType::Eof, String::new(), 0, 0, 0, 0));
         }
+        let const_names = tokens
+            .windows(2)
+            .filter(|w| w[0].token_type == TokenType::Const)
+            .map(|w| w[1].lexeme.clone())
+            .collect();
+        let mut import_aliases = HashSet::new();
+        let mut in_import = false;
+        for w in tokens.windows(2) {
+            match w[0].token_type {
+                TokenType::Import => in_import = true,
+                TokenType::Semicolon => in_import = false,
+                TokenType::As if in_import && w[1].token_type == TokenType::Identifier => {
+                    import_aliases.insert(w[1].lexeme.clone());
+                }
+                _ => {}
+            }
+        }
         Parser {
             tokens,
             current: 0,
             diagnostics: Vec::new(),
             depth: 0,
+            loops: Vec::new(),
+            functions: Vec::new(),
+            awaits: Vec::new(),
+            deferring: false,
+            const_names,
+            import_aliases,
+            qualified: Vec::new(),
+        }
+    }
+
+    /// `import m as h` passa a listar em `items` o que o arquivo usa como `h.x`,
+    /// na ordem do primeiro uso; é por eles que a visibilidade é conferida.
+    fn record_qualified_items(&mut self, statements: &mut [Stmt]) {
+        for stmt in statements {
+            if let Stmt::Import {
+                items,
+                alias: Some(alias),
+                ..
+            } = stmt
+            {
+                for (_, item) in self.qualified.iter().filter(|(a, _)| *a == alias.lexeme) {
+                    let used = items.get_or_insert_with(Vec::new);
+                    if !used.iter().any(|u| u.lexeme == item.lexeme) {
+                        used.push(item.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Usos `h.x` lidos até aqui: o apelido e o token do `x`, que é o token do
+    /// nome no AST. O resolver usa a posição para saber de qual módulo é cada `x`.
+    pub fn qualified_uses(&self) -> &[(String, Token)] {
+        &self.qualified
+    }
+
+    /// `h.x`, com `h` apelido de import, vira só `x`; `name` é o `h` já consumido.
+    pub(crate) fn unqualify(&mut self, name: Token) -> Token {
+        let qualified = self.import_aliases.contains(&name.lexeme)
+            && self.check(&TokenType::Dot)
+            && matches!(
+                self.tokens.get(self.current + 1).map(|t| &t.token_type),
+                Some(TokenType::Identifier)
+            );
+        if qualified {
+            self.advance();
+            let item = self.advance();
+            self.qualified.push((name.lexeme, item.clone()));
+            item
+        } else {
+            name
         }
     }
 
@@ -174,39 +267,166 @@ impl Parser {
         }
     }
 
+    /// Parseia o corpo de um loop com `label` empilhado como alvo de `break`/`continue`.
+    pub(crate) fn loop_body(&mut self, label: Option<Token>) -> Stmt {
+        self.loops.push(label);
+        let body = self.statement();
+        self.loops.pop();
+        body
+    }
+
+    /// Parseia um corpo de função ou ator, onde os loops de fora não são visíveis.
+    pub(crate) fn outside_loops<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        let saved = std::mem::take(&mut self.loops);
+        let result = f(self);
+        self.loops = saved;
+        result
+    }
+
+    /// Parseia um corpo de função (`async` ou não), lambda ou ator.
+    pub(crate) fn in_function<T>(&mut self, is_async: bool, f: impl FnOnce(&mut Self) -> T) -> T {
+        self.functions.push(is_async);
+        let deferring = std::mem::replace(&mut self.deferring, false);
+        let result = self.outside_loops(f);
+        self.deferring = deferring;
+        self.functions.pop();
+        result
+    }
+
+    /// Parseia o corpo de um `defer`, que roda na saída do escopo: não há loop
+    /// para `break`/`continue` nem função para `return`/`yield` sair.
+    pub(crate) fn in_defer<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        let deferring = std::mem::replace(&mut self.deferring, true);
+        let result = self.outside_loops(f);
+        self.deferring = deferring;
+        result
+    }
+
+    /// Reporta `keyword` (`return`, `yield` ou `await`) dentro de um `defer`.
+    pub(crate) fn reject_in_defer(&mut self, keyword: &Token) -> bool {
+        if self.deferring {
+            self.report(
+                keyword.start,
+                keyword.end,
+                keyword.line,
+                keyword.col,
+                DiagnosticKind::Parse,
+                format!(
+                    "'{}' is not allowed inside a 'defer' block.",
+                    keyword.lexeme
+                ),
+            );
+        }
+        self.deferring
+    }
+
+    /// Onde um `await` pode aparecer: `None` fora de funções (bloqueia, em qualquer
+    /// posição), `Some(true)` numa `async func`, `Some(false)` nas demais funções,
+    /// lambdas e atores.
+    pub(crate) fn await_context(&self) -> Option<bool> {
+        self.functions.last().copied()
+    }
+
+    /// Aceita o `await` que é o valor inteiro de um `let`, atribuição, `return` ou
+    /// expression statement; numa `async func` os demais são rejeitados por
+    /// `report_stray_awaits`, pois só nesses pontos a task consegue suspender.
+    pub(crate) fn claim_await(&mut self, value: &Expr) {
+        if let Expr::Await { keyword, .. } = value {
+            self.awaits.retain(|t| t.start != keyword.start);
+        }
+    }
+
+    pub(crate) fn report_stray_awaits(&mut self) {
+        for keyword in std::mem::take(&mut self.awaits) {
+            self.report(
+                keyword.start,
+                keyword.end,
+                keyword.line,
+                keyword.col,
+                DiagnosticKind::Parse,
+                "'await' must be the whole value of a let, assignment, return or expression statement."
+                    .to_string(),
+            );
+        }
+    }
+
+    /// Valida o alvo de `break`/`continue`: precisa haver um loop envolvente e,
+    /// se rotulado, um loop com aquele rótulo.
+    pub(crate) fn check_loop_target(&mut self, keyword: &Token, label: Option<&Token>) {
+        let message = match label {
+            _ if self.loops.is_empty() => format!("'{}' outside of a loop.", keyword.lexeme),
+            Some(l)
+                if !self
+                    .loops
+                    .iter()
+                    .any(|open| open.as_ref().is_some_and(|o| o.lexeme == l.lexeme)) =>
+            {
+                format!("Unknown loop label {} in '{}'.", l.lexeme, keyword.lexeme)
+            }
+            _ => return,
+        };
+        let at = label.unwrap_or(keyword);
+        self.report(
+            at.start,
+            at.end,
+            at.line,
+            at.col,
+            DiagnosticKind::Parse,
+            message,
+        );
+    }
+
     pub fn parse(&mut self) -> (Program, Vec<Diagnostic>) {
         let mut statements = Vec::new();
         while !self.is_at_end() {
-            statements.push(self.declaration());
+            let stmt = if self.check(&TokenType::Pub) {
+                statements::pub_declaration(self, true)
+            } else if self.check(&TokenType::Const) {
+                statements::const_declaration(self, true)
+            } else if self.check(&TokenType::At) {
+                statements::derive_declaration(self, true)
+            } else {
+                self.declaration()
+            };
+            statements.push(stmt);
         }
+        self.report_stray_awaits();
+        self.record_qualified_items(&mut statements);
         check_component_imports(&statements, &mut self.diagnostics);
         (statements, std::mem::take(&mut self.diagnostics))
     }
 
     pub fn declaration(&mut self) -> Stmt {
+        let stmt = self.declaration_kind();
+        self.report_stray_awaits();
+        stmt
+    }
+
+    fn declaration_kind(&mut self) -> Stmt {
         if self.match_token(TokenType::Struct) {
             self.struct_declaration()
         } else if self.match_token(TokenType::Enum) {
             self.enum_declaration()
+        } else if self.match_token(TokenType::Type) {
+            statements::type_alias_declaration(self)
+        } else if self.match_token(TokenType::Newtype) {
+            statements::newtype_declaration(self)
         } else if self.match_token(TokenType::Let) {
             self.let_declaration()
+        } else if self.match_token(TokenType::Var) {
+            self.var_declaration()
+        } else if self.check(&TokenType::Pub) {
+            statements::pub_declaration(self, false)
+        } else if self.check(&TokenType::Const) {
+            statements::const_declaration(self, false)
+        } else if self.check(&TokenType::At) {
+            statements::derive_declaration(self, false)
         } else if self.match_token(TokenType::Func) {
             self.function_declaration()
+        } else if self.check(&TokenType::Async) {
+            self.async_function_declaration()
         } else if self.match_token(TokenType::Import) {
-            // parse dotted path: identifier ( '.' identifier )* ';'
-            let mut path = Vec::new();
-            // Expect at least one identifier
-            let first = self.consume(TokenType::Identifier, "Expect module name after 'import'.");
-            path.push(first);
-            while self.match_token(TokenType::Dot) {
-                let part = self.consume(
-                    TokenType::Identifier,
-                    "Expect identifier after '.' in import path.",
-                );
-                path.push(part);
-            }
-            self.consume(TokenType::Semicolon, "Expect ';' after import path.");
-            Stmt::Import { path }
+            statements::import_declaration(self)
         } else if self.match_token(TokenType::Performant) {
             // performant { ... }
             self.consume(TokenType::LeftBrace, "Expect '{' after performant.");
@@ -214,6 +434,8 @@ impl Parser {
             Stmt::Performant { statements }
         } else if self.match_token(TokenType::Impl) {
             self.impl_block()
+        } else if self.match_token(TokenType::Trait) {
+            self.trait_declaration()
         } else if self.match_token(TokenType::Component) {
             self.component_block()
         } else {
@@ -223,12 +445,25 @@ impl Parser {
 
     fn impl_block(&mut self) -> Stmt {
         let name_token = self.consume(TokenType::Identifier, "Expect type name after 'impl'.");
+        // `impl Trait for Tipo`: o primeiro nome é o trait.
+        let (trait_name, name_token) = if self.match_token(TokenType::For) {
+            let ty = self.consume(TokenType::Identifier, "Expect type name after 'for'.");
+            (Some(name_token), ty)
+        } else {
+            (None, name_token)
+        };
         let type_name = name_token.lexeme.clone();
         self.consume(TokenType::LeftBrace, "Expect '{' after impl type name.");
         let mut methods = Vec::new();
         while !self.is_at_end() && !self.check(&TokenType::RightBrace) {
-            if self.match_token(TokenType::Func) {
-                let mut method = self.function_declaration();
+            let method = if self.match_token(TokenType::Func) {
+                Some(self.function_declaration())
+            } else if self.check(&TokenType::Async) {
+                Some(self.async_function_declaration())
+            } else {
+                None
+            };
+            if let Some(mut method) = method {
                 // Inject type_name as method_owner if not already set
                 if let Stmt::Function {
                     ref mut method_owner,
@@ -244,7 +479,57 @@ impl Parser {
             }
         }
         self.consume(TokenType::RightBrace, "Expect '}' after impl block.");
-        Stmt::ImplBlock { type_name, methods }
+        Stmt::ImplBlock {
+            type_name,
+            trait_name,
+            methods,
+        }
+    }
+
+    fn trait_declaration(&mut self) -> Stmt {
+        let name = self.consume(TokenType::Identifier, "Expect trait name after 'trait'.");
+        self.consume(TokenType::LeftBrace, "Expect '{' after trait name.");
+        let mut methods = Vec::new();
+        while !self.is_at_end() && !self.check(&TokenType::RightBrace) {
+            if !self.match_token(TokenType::Func) {
+                let tok = self.peek();
+                self.report(
+                    tok.start,
+                    tok.end,
+                    tok.line,
+                    tok.col,
+                    DiagnosticKind::Parse,
+                    "Expect 'func' in trait body.".to_string(),
+                );
+                self.advance();
+                continue;
+            }
+            let method_name = self.consume(TokenType::Identifier, "Expect method name.");
+            self.consume(TokenType::LeftParen, "Expect '(' after method name.");
+            let params = self.parameter_list(TokenType::RightParen, "Expect ')' after parameters.");
+            let return_type = if self.match_token(TokenType::Arrow) {
+                Some(self.parse_type())
+            } else {
+                None
+            };
+            // Sem corpo é um método obrigatório; com corpo, a implementação default.
+            let body = if self.match_token(TokenType::LeftBrace) {
+                Some(Rc::new(Stmt::Block {
+                    statements: self.in_function(false, |p| p.block()),
+                }))
+            } else {
+                self.match_token(TokenType::Semicolon);
+                None
+            };
+            methods.push(core::ast::TraitMethod {
+                name: method_name,
+                params,
+                return_type,
+                body,
+            });
+        }
+        self.consume(TokenType::RightBrace, "Expect '}' after trait body.");
+        Stmt::TraitDecl { name, methods }
     }
 
     fn component_block(&mut self) -> Stmt {
@@ -293,6 +578,9 @@ impl Parser {
                 let nodes = expressions::parse_template_nodes(self);
                 self.consume(TokenType::RightBrace, "Expect '}' after view body.");
                 view = nodes;
+            } else if self.match_token(TokenType::Func) {
+                // handlers locais do componente (ex.: `func inc() { count += 1 }`)
+                bindings.push(self.function_declaration());
             } else {
                 self.advance();
             }
@@ -305,8 +593,43 @@ impl Parser {
         }
     }
 
+    /// `struct Nome { campo: T, outro: T = default }` ou `struct Nome(T, U)`.
     pub fn struct_declaration(&mut self) -> Stmt {
         let name = self.consume(TokenType::Identifier, "Expect struct name.");
+        if self.match_token(TokenType::LeftParen) {
+            let mut fields = Vec::new();
+            while !self.check(&TokenType::RightParen) && !self.is_at_end() {
+                let at = self.peek();
+                let ty = self.parse_type();
+                fields.push(StructField {
+                    name: Token::new(
+                        TokenType::Identifier,
+                        fields.len().to_string(),
+                        at.line,
+                        at.col,
+                        at.start,
+                        at.end,
+                    ),
+                    ty,
+                    default: None,
+                });
+                if !self.match_token(TokenType::Comma) {
+                    break;
+                }
+            }
+            self.consume(
+                TokenType::RightParen,
+                "Expect ')' after tuple struct fields.",
+            );
+            self.match_token(TokenType::Semicolon);
+            return Stmt::StructDecl {
+                name,
+                fields,
+                tuple: true,
+                derives: Vec::new(),
+                public: false,
+            };
+        }
         self.consume(TokenType::LeftBrace, "Expect '{' after struct name.");
         let mut fields = Vec::new();
         if !self.check(&TokenType::RightBrace) {
@@ -314,14 +637,29 @@ impl Parser {
                 let field_name = self.consume(TokenType::Identifier, "Expect field name.");
                 self.consume(TokenType::Colon, "Expect ':' after field name.");
                 let ty = self.parse_type();
-                fields.push((field_name, ty));
+                let default = if self.match_token(TokenType::Equal) {
+                    Some(self.expression())
+                } else {
+                    None
+                };
+                fields.push(StructField {
+                    name: field_name,
+                    ty,
+                    default,
+                });
                 if !self.match_token(TokenType::Comma) || self.check(&TokenType::RightBrace) {
                     break;
                 }
             }
         }
         self.consume(TokenType::RightBrace, "Expect '}' after struct fields.");
-        Stmt::StructDecl { name, fields }
+        Stmt::StructDecl {
+            name,
+            fields,
+            tuple: false,
+            derives: Vec::new(),
+            public: false,
+        }
     }
 
     pub fn enum_declaration(&mut self) -> Stmt {
@@ -351,7 +689,12 @@ impl Parser {
             }
         }
         self.consume(TokenType::RightBrace, "Expect '}' after enum variants.");
-        Stmt::EnumDecl { name, variants }
+        Stmt::EnumDecl {
+            name,
+            variants,
+            derives: Vec::new(),
+            public: false,
+        }
     }
 
     pub fn statement(&mut self) -> Stmt {
@@ -362,6 +705,10 @@ impl Parser {
         statements::let_declaration(self)
     }
 
+    pub fn var_declaration(&mut self) -> Stmt {
+        statements::var_declaration(self)
+    }
+
     pub fn if_statement(&mut self) -> Stmt {
         statements::if_statement(self)
     }
@@ -397,6 +744,7 @@ impl Parser {
     pub fn token_precedence(&self, token_type: &TokenType) -> u8 {
         match token_type {
             TokenType::PipeGreater => Precedence::Pipeline as u8,
+            TokenType::DotDot | TokenType::DotDotEqual => Precedence::Range as u8,
             TokenType::And => Precedence::And as u8,
             TokenType::Or => Precedence::Or as u8,
             TokenType::EqualEqual | TokenType::BangEqual => Precedence::Equality as u8,
@@ -404,9 +752,18 @@ impl Parser {
             | TokenType::GreaterEqual
             | TokenType::Less
             | TokenType::LessEqual => Precedence::Comparison as u8,
+            TokenType::QuestionQuestion => Precedence::Coalesce as u8,
+            TokenType::Pipe => Precedence::BitOr as u8,
+            TokenType::Caret => Precedence::BitXor as u8,
+            TokenType::Ampersand => Precedence::BitAnd as u8,
+            TokenType::LessLess | TokenType::GreaterGreater => Precedence::Shift as u8,
             TokenType::Plus | TokenType::Minus => Precedence::Term as u8,
-            TokenType::Star | TokenType::Slash => Precedence::Factor as u8,
-            TokenType::LeftParen | TokenType::Dot | TokenType::ColonColon => Precedence::Call as u8,
+            TokenType::Star | TokenType::Slash | TokenType::Percent => Precedence::Factor as u8,
+            TokenType::LeftParen
+            | TokenType::LeftBracket
+            | TokenType::Dot
+            | TokenType::QuestionDot
+            | TokenType::ColonColon => Precedence::Call as u8,
             TokenType::As => Precedence::Call as u8,
             TokenType::Question => Precedence::Try as u8,
             TokenType::Bang => Precedence::Call as u8, // tratar 'expr!' como postfix acesso unowned
@@ -506,6 +863,23 @@ impl Parser {
         &self.tokens
     }
 
+    /// Consome um `>` que fecha argumentos de tipo. Um `>>` (lexado como
+    /// shift) fecha dois níveis: o primeiro `>` é consumido e o token vira `>`.
+    fn match_closing_angle(&mut self) -> bool {
+        if self.match_token(TokenType::Greater) {
+            return true;
+        }
+        if !self.check(&TokenType::GreaterGreater) {
+            return false;
+        }
+        let tok = &mut self.tokens[self.current];
+        tok.token_type = TokenType::Greater;
+        tok.lexeme = ">".to_string();
+        tok.start += 1;
+        tok.col += 1;
+        true
+    }
+
     pub fn parse_type(&mut self) -> String {
         let mut type_str = String::new();
         if self.match_token(TokenType::LeftBracket) {
@@ -515,6 +889,7 @@ impl Parser {
                 TokenType::RightBracket,
                 "Expect ']' after array element type.",
             );
+            type_str.push(']');
         } else if self.match_token(TokenType::LeftParen) {
             let mut types = Vec::new();
             if !self.check(&TokenType::RightParen) {
@@ -534,6 +909,7 @@ impl Parser {
             type_str.push(')');
         } else {
             let type_name = self.consume(TokenType::Identifier, "Expect type name.");
+            let type_name = self.unqualify(type_name);
             type_str.push_str(&type_name.lexeme);
             if self.match_token(TokenType::LeftBracket) {
                 type_str.push('[');
@@ -550,7 +926,7 @@ impl Parser {
                     type_str.push_str(&self.parse_type());
                     if self.match_token(TokenType::Comma) {
                         type_str.push_str(", ");
-                    } else if self.match_token(TokenType::Greater) {
+                    } else if self.match_closing_angle() {
                         type_str.push('>');
                         break;
                     } else {
@@ -571,7 +947,47 @@ impl Parser {
         type_str
     }
 
+    /// `nome[: Tipo], ...` até `close` (consumido). Compartilhado por `func`
+    /// e pelos lambdas (`|a, b|` e `func(a, b)`).
+    pub(crate) fn parameter_list(
+        &mut self,
+        close: TokenType,
+        message: &str,
+    ) -> Vec<core::ast::FunctionParam> {
+        let mut params = Vec::new();
+        if !self.check(&close) {
+            loop {
+                let param_name = self.consume(TokenType::Identifier, "Expect parameter name.");
+                let param_type = if self.match_token(TokenType::Colon) {
+                    Some(self.parse_type())
+                } else {
+                    None
+                };
+                params.push(core::ast::FunctionParam {
+                    name: param_name,
+                    ty: param_type,
+                });
+                if !self.match_token(TokenType::Comma) {
+                    break;
+                }
+            }
+        }
+        self.consume(close, message);
+        params
+    }
+
     pub fn function_declaration(&mut self) -> Stmt {
+        self.function_with(false)
+    }
+
+    /// `async func nome(...) { ... }`, com o `async` ainda por consumir.
+    pub fn async_function_declaration(&mut self) -> Stmt {
+        self.consume(TokenType::Async, "Expect 'async'.");
+        self.consume(TokenType::Func, "Expect 'func' after 'async'.");
+        self.function_with(true)
+    }
+
+    fn function_with(&mut self, is_async: bool) -> Stmt {
         let first_ident = self.consume(TokenType::Identifier, "Expect function name.");
         let (name, method_owner) = if self.match_token(TokenType::Dot) {
             if self.check(&TokenType::Identifier) {
@@ -609,25 +1025,7 @@ impl Parser {
         }
 
         self.consume(TokenType::LeftParen, "Expect '(' after function name.");
-        let mut params = Vec::new();
-        if !self.check(&TokenType::RightParen) {
-            loop {
-                let param_name = self.consume(TokenType::Identifier, "Expect parameter name.");
-                let param_type = if self.match_token(TokenType::Colon) {
-                    Some(self.parse_type())
-                } else {
-                    None
-                };
-                params.push(core::ast::FunctionParam {
-                    name: param_name,
-                    ty: param_type,
-                });
-                if !self.match_token(TokenType::Comma) {
-                    break;
-                }
-            }
-        }
-        self.consume(TokenType::RightParen, "Expect ')' after parameters.");
+        let params = self.parameter_list(TokenType::RightParen, "Expect ')' after parameters.");
         let return_type = if self.match_token(TokenType::Arrow) {
             Some(self.parse_type())
         } else {
@@ -635,8 +1033,18 @@ impl Parser {
         };
         self.consume(TokenType::LeftBrace, "Expect '{' before function body.");
         let body = Rc::new(Stmt::Block {
-            statements: self.block(),
+            statements: self.in_function(is_async, |p| p.block()),
         });
+        if is_async && body.contains_yield() {
+            self.report(
+                name.start,
+                name.end,
+                name.line,
+                name.col,
+                DiagnosticKind::Parse,
+                "An async function cannot contain 'yield'.".to_string(),
+            );
+        }
         Stmt::Function {
             name,
             type_params,
@@ -644,7 +1052,8 @@ impl Parser {
             return_type,
             body,
             method_owner,
-            is_async: false,
+            is_async,
+            public: false,
         }
     }
 }
@@ -657,9 +1066,11 @@ fn collect_known_names(stmts: &[Stmt]) -> HashSet<String> {
     let mut known = HashSet::new();
     for stmt in stmts {
         match stmt {
-            Stmt::Import { path } => {
-                if let Some(last) = path.last() {
-                    known.insert(last.lexeme.clone());
+            Stmt::Import { path, items, alias } => {
+                if let Some(items) = items {
+                    known.extend(items.iter().map(|t| t.lexeme.clone()));
+                } else if let Some(name) = alias.as_ref().or(path.last()) {
+                    known.insert(name.lexeme.clone());
                 }
             }
             Stmt::StructDecl { name, .. } => {
@@ -747,6 +1158,7 @@ fn find_template_components_in_stmt(stmt: &Stmt, out: &mut Vec<(String, Span)>)
             find_template_components_in_expr(e, out);
         }
         Stmt::Let { initializer, .. } => find_template_components_in_expr(initializer, out),
+        Stmt::Assign { value, .. } => find_template_components_in_expr(value, out),
         Stmt::Block { statements } => {
             for s in statements {
                 find_template_components_in_stmt(s, out);
@@ -771,7 +1183,9 @@ fn find_template_components_in_stmt(stmt: &Stmt, out: &mut Vec<(String, Span)>)
                 find_template_components_in_stmt(eb, out);
             }
         }
-        Stmt::While { condition, body } => {
+        Stmt::While {
+            condition, body, ..
+        } => {
             find_template_components_in_expr(condition, out);
             find_template_components_in_stmt(body, out);
         }
@@ -3,10 +3,16 @@ pub enum Precedence {
     None,
     Assignment,
     Pipeline,
+    Range,
     Or,
     And,
     Equality,
     Comparison,
+    Coalesce,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Shift,
     Term,
     Factor,
     Unary,
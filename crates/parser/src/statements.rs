@@ -1,6 +1,6 @@
 use crate::parser::Parser;
-use core::TokenType;
 use core::ast::{ArtValue, Expr, MatchPattern, Stmt};
+use core::{Token, TokenType};
 
 pub fn statement(parser: &mut Parser) -> Stmt {
     if parser.check(&TokenType::Dollar) {
@@ -11,7 +11,7 @@ pub fn statement(parser: &mut Parser) -> Stmt {
         parser.advance();
         parser.consume(TokenType::Actor, "Expect 'actor' after 'spawn'.");
         parser.consume(TokenType::LeftBrace, "Expect '{' to start actor body.");
-        let body = block(parser);
+        let body = parser.in_function(false, block);
         return Stmt::SpawnActor { body };
     }
     if parser.check(&TokenType::Match) {
@@ -29,11 +29,20 @@ pub fn statement(parser: &mut Parser) -> Stmt {
     if parser.check(&TokenType::Yield) {
         return yield_statement(parser);
     }
+    if parser.check(&TokenType::Defer) {
+        return defer_statement(parser);
+    }
     if parser.check(&TokenType::While) {
-        return while_statement(parser);
+        return while_statement(parser, None);
     }
     if parser.check(&TokenType::For) {
-        return for_statement(parser);
+        return for_statement(parser, None);
+    }
+    if parser.check(&TokenType::Label) {
+        return labeled_loop(parser);
+    }
+    if parser.check(&TokenType::Break) || parser.check(&TokenType::Continue) {
+        return loop_jump(parser);
     }
     if parser.check(&TokenType::LeftBrace) {
         parser.advance();
@@ -44,30 +53,20 @@ pub fn statement(parser: &mut Parser) -> Stmt {
 
     let expr = parser.expression();
 
+    if matches!(
+        parser.peek().token_type,
+        TokenType::Equal
+            | TokenType::PlusEqual
+            | TokenType::MinusEqual
+            | TokenType::StarEqual
+            | TokenType::SlashEqual
+    ) {
+        return assignment(parser, expr);
+    }
+
     if parser.match_token(TokenType::LeftBrace) {
         if let Expr::Variable { name } = expr {
-            let mut fields = Vec::new();
-            while !parser.is_at_end() && !parser.check(&TokenType::RightBrace) {
-                if parser.check(&TokenType::Identifier) {
-                    let field_name = parser.advance();
-                    parser.consume(TokenType::Colon, "Expect ':' after field name.");
-                    let value = parser.expression();
-                    fields.push((field_name, value));
-                    if !parser.check(&TokenType::RightBrace) {
-                        parser.match_token(TokenType::Comma);
-                    }
-                } else {
-                    let p = parser.peek();
-                    parser.diagnostics.push(diagnostics::Diagnostic::new(
-                        diagnostics::DiagnosticKind::Parse,
-                        format!("Expected field name, got {:?}", p.token_type),
-                        diagnostics::Span::new(p.start, p.end, p.line, p.col),
-                    ));
-                    break;
-                }
-            }
-            parser.consume(TokenType::RightBrace, "Expect '}' after struct fields.");
-            let struct_init_expr = Expr::StructInit { name, fields };
+            let struct_init_expr = struct_init_fields(parser, name);
             return Stmt::Expression(struct_init_expr);
         } else {
             let p = parser.peek();
@@ -79,10 +78,80 @@ pub fn statement(parser: &mut Parser) -> Stmt {
         }
     }
 
+    parser.claim_await(&expr);
     parser.match_token(TokenType::Semicolon);
     Stmt::Expression(expr)
 }
 
+/// Corpo de `Nome { campo: valor, ..base }` depois do `{` já consumido.
+fn struct_init_fields(parser: &mut Parser, name: core::Token) -> Expr {
+    let mut fields = Vec::new();
+    let mut base = None;
+    while !parser.is_at_end() && !parser.check(&TokenType::RightBrace) {
+        if parser.match_token(TokenType::DotDot) {
+            base = Some(Box::new(parser.expression()));
+            parser.match_token(TokenType::Comma);
+            if !parser.check(&TokenType::RightBrace) {
+                let p = parser.peek();
+                parser.diagnostics.push(diagnostics::Diagnostic::new(
+                    diagnostics::DiagnosticKind::Parse,
+                    "'..base' must be the last item of a struct initializer.".to_string(),
+                    diagnostics::Span::new(p.start, p.end, p.line, p.col),
+                ));
+            }
+            break;
+        }
+        if parser.check(&TokenType::Identifier) {
+            let field_name = parser.advance();
+            parser.consume(TokenType::Colon, "Expect ':' after field name.");
+            let value = parser.expression();
+            fields.push((field_name, value));
+            if !parser.check(&TokenType::RightBrace) {
+                parser.match_token(TokenType::Comma);
+            }
+        } else {
+            let p = parser.peek();
+            parser.diagnostics.push(diagnostics::Diagnostic::new(
+                diagnostics::DiagnosticKind::Parse,
+                format!("Expected field name, got {:?}", p.token_type),
+                diagnostics::Span::new(p.start, p.end, p.line, p.col),
+            ));
+            break;
+        }
+    }
+    parser.consume(TokenType::RightBrace, "Expect '}' after struct fields.");
+    Expr::StructInit { name, fields, base }
+}
+
+/// `alvo = valor` / `alvo op= valor`. O alvo já foi parseado como expressão;
+/// aqui só validamos que ele denota um lugar atribuível.
+fn assignment(parser: &mut Parser, target: Expr) -> Stmt {
+    let operator = parser.advance();
+    if !matches!(
+        target,
+        Expr::Variable { .. } | Expr::FieldAccess { .. } | Expr::Index { .. }
+    ) {
+        parser.diagnostics.push(diagnostics::Diagnostic::new(
+            diagnostics::DiagnosticKind::Parse,
+            format!("Invalid assignment target before '{}'.", operator.lexeme),
+            diagnostics::Span::new(operator.start, operator.end, operator.line, operator.col),
+        ));
+    }
+    let mut value = parser.expression();
+    if let Expr::Variable { name } = &value
+        && parser.match_token(TokenType::LeftBrace)
+    {
+        value = struct_init_fields(parser, name.clone());
+    }
+    parser.claim_await(&value);
+    parser.match_token(TokenType::Semicolon);
+    Stmt::Assign {
+        target,
+        operator,
+        value,
+    }
+}
+
 fn shell_statement(parser: &mut Parser) -> Stmt {
     let dollar = parser.consume(TokenType::Dollar, "Expect '$' before shell command.");
     let line = dollar.line;
@@ -105,16 +174,25 @@ fn shell_statement(parser: &mut Parser) -> Stmt {
         let (piece, force_new_arg) = match tok.token_type {
             TokenType::String(s) | TokenType::InterpolatedString(s) => (s, true),
             TokenType::Identifier
-            | TokenType::Number(_)
+            | TokenType::Int(_)
+            | TokenType::Float(_)
             | TokenType::Dot
+            | TokenType::DotDot
+            | TokenType::DotDotEqual
             | TokenType::Slash
             | TokenType::Colon
             | TokenType::ColonColon
             | TokenType::Equal
             | TokenType::Less
             | TokenType::Greater
+            | TokenType::LessLess
+            | TokenType::GreaterGreater
+            | TokenType::Percent
+            | TokenType::Tilde
             | TokenType::Bang
             | TokenType::Question
+            | TokenType::QuestionDot
+            | TokenType::QuestionQuestion
             | TokenType::Underscore
             | TokenType::Minus
             | TokenType::Plus
@@ -177,6 +255,239 @@ fn shell_statement(parser: &mut Parser) -> Stmt {
 }
 
 pub fn let_declaration(parser: &mut Parser) -> Stmt {
+    binding_declaration(parser, false)
+}
+
+/// `const NOME: Tipo = expr`. Fora do topo do arquivo é erro, mas a declaração
+/// ainda é lida inteira para o parser seguir em frente.
+pub fn const_declaration(parser: &mut Parser, top_level: bool) -> Stmt {
+    let keyword = parser.consume(TokenType::Const, "Expect 'const'.");
+    if !top_level {
+        parser.diagnostics.push(diagnostics::Diagnostic::new(
+            diagnostics::DiagnosticKind::Parse,
+            "'const' is only allowed at the top level.".to_string(),
+            diagnostics::Span::new(keyword.start, keyword.end, keyword.line, keyword.col),
+        ));
+    }
+    let name = parser.consume(TokenType::Identifier, "Expect constant name.");
+    parser.consume(
+        TokenType::Colon,
+        "Expect ':' and a type after constant name.",
+    );
+    let ty = parser.parse_type();
+    parser.consume(TokenType::Equal, "Expect '=' after constant type.");
+    let mut value = parser.expression();
+    if let Expr::Variable {
+        name: struct_name_tok,
+    } = &value
+        && parser.match_token(TokenType::LeftBrace)
+    {
+        value = struct_init_fields(parser, struct_name_tok.clone());
+    }
+    parser.match_token(TokenType::Semicolon);
+    Stmt::Const {
+        name,
+        ty,
+        value,
+        public: false,
+    }
+}
+
+/// `pub func|struct|enum|const ...`: marca o item como exportado pelo módulo.
+/// Como `const`, só vale no topo do arquivo.
+pub fn pub_declaration(parser: &mut Parser, top_level: bool) -> Stmt {
+    let keyword = parser.consume(TokenType::Pub, "Expect 'pub'.");
+    if !top_level {
+        parser.diagnostics.push(diagnostics::Diagnostic::new(
+            diagnostics::DiagnosticKind::Parse,
+            "'pub' is only allowed at the top level.".to_string(),
+            diagnostics::Span::new(keyword.start, keyword.end, keyword.line, keyword.col),
+        ));
+    }
+    let mut stmt = if parser.match_token(TokenType::Func) {
+        parser.function_declaration()
+    } else if parser.check(&TokenType::Async) {
+        parser.async_function_declaration()
+    } else if parser.match_token(TokenType::Struct) {
+        parser.struct_declaration()
+    } else if parser.match_token(TokenType::Enum) {
+        parser.enum_declaration()
+    } else if parser.check(&TokenType::Const) {
+        const_declaration(parser, top_level)
+    } else {
+        let tok = parser.peek();
+        parser.diagnostics.push(diagnostics::Diagnostic::new(
+            diagnostics::DiagnosticKind::Parse,
+            "Expect 'func', 'struct', 'enum' or 'const' after 'pub'.".to_string(),
+            diagnostics::Span::new(tok.start, tok.end, tok.line, tok.col),
+        ));
+        return parser.declaration();
+    };
+    match &mut stmt {
+        Stmt::Function { public, .. }
+        | Stmt::StructDecl { public, .. }
+        | Stmt::EnumDecl { public, .. }
+        | Stmt::Const { public, .. } => *public = true,
+        _ => {}
+    }
+    stmt
+}
+
+/// `@derive(Eq, Hash, Ord, Debug, Clone)` seguido de `struct` ou `enum`
+/// (com `pub` opcional). `Hash` e `Ord` pedem `Eq` na mesma lista.
+pub fn derive_declaration(parser: &mut Parser, top_level: bool) -> Stmt {
+    parser.consume(TokenType::At, "Expect '@'.");
+    let attr = parser.consume(TokenType::Identifier, "Expect 'derive' after '@'.");
+    if attr.lexeme != "derive" {
+        report(
+            parser,
+            &attr,
+            format!("Unknown annotation '@{}'; expected '@derive'.", attr.lexeme),
+        );
+    }
+    parser.consume(TokenType::LeftParen, "Expect '(' after 'derive'.");
+    let mut derives: Vec<Token> = Vec::new();
+    if !parser.check(&TokenType::RightParen) {
+        loop {
+            let derive = parser.consume(TokenType::Identifier, "Expect derive name.");
+            if core::types::derive_bound(&derive.lexeme).is_none() {
+                let known: Vec<&str> = core::types::DERIVES.iter().map(|(d, _)| *d).collect();
+                report(
+                    parser,
+                    &derive,
+                    format!(
+                        "Unknown derive '{}'; expected one of {}.",
+                        derive.lexeme,
+                        known.join(", ")
+                    ),
+                );
+            } else if derives.iter().any(|d| d.lexeme == derive.lexeme) {
+                report(
+                    parser,
+                    &derive,
+                    format!("Duplicate derive '{}'.", derive.lexeme),
+                );
+            } else {
+                derives.push(derive);
+            }
+            if !parser.match_token(TokenType::Comma) || parser.check(&TokenType::RightParen) {
+                break;
+            }
+        }
+    }
+    parser.consume(TokenType::RightParen, "Expect ')' after derive list.");
+    let has = |name: &str| derives.iter().any(|d| d.lexeme == name);
+    for needs_eq in ["Hash", "Ord"] {
+        if has(needs_eq) && !has("Eq") {
+            let at = derives.iter().find(|d| d.lexeme == needs_eq).cloned();
+            if let Some(at) = at {
+                report(
+                    parser,
+                    &at,
+                    format!("derive({}) requires derive(Eq) too.", needs_eq),
+                );
+            }
+        }
+    }
+    let mut stmt = if parser.check(&TokenType::Pub) {
+        pub_declaration(parser, top_level)
+    } else if parser.match_token(TokenType::Struct) {
+        parser.struct_declaration()
+    } else if parser.match_token(TokenType::Enum) {
+        parser.enum_declaration()
+    } else {
+        let tok = parser.peek();
+        report(
+            parser,
+            &tok,
+            "Expect 'struct' or 'enum' after '@derive(...)'.".to_string(),
+        );
+        return parser.declaration();
+    };
+    match &mut stmt {
+        Stmt::StructDecl { derives: slot, .. } | Stmt::EnumDecl { derives: slot, .. } => {
+            *slot = derives;
+        }
+        _ => report(
+            parser,
+            &attr,
+            "'@derive' only applies to 'struct' and 'enum'.".to_string(),
+        ),
+    }
+    stmt
+}
+
+fn report(parser: &mut Parser, at: &Token, message: String) {
+    parser.diagnostics.push(diagnostics::Diagnostic::new(
+        diagnostics::DiagnosticKind::Parse,
+        message,
+        diagnostics::Span::new(at.start, at.end, at.line, at.col),
+    ));
+}
+
+/// `import a.b;`, `import a.b.{x, y};` ou `import a.b as m;` (o `import` já
+/// foi consumido).
+pub fn import_declaration(parser: &mut Parser) -> Stmt {
+    let mut path =
+        vec![parser.consume(TokenType::Identifier, "Expect module name after 'import'.")];
+    let mut items = None;
+    while parser.match_token(TokenType::Dot) {
+        if parser.match_token(TokenType::LeftBrace) {
+            let mut names = Vec::new();
+            while !parser.check(&TokenType::RightBrace) && !parser.is_at_end() {
+                names.push(
+                    parser.consume(TokenType::Identifier, "Expect item name in import list."),
+                );
+                if !parser.match_token(TokenType::Comma) {
+                    break;
+                }
+            }
+            parser.consume(TokenType::RightBrace, "Expect '}' after import list.");
+            items = Some(names);
+            break;
+        }
+        path.push(parser.consume(
+            TokenType::Identifier,
+            "Expect identifier after '.' in import path.",
+        ));
+    }
+    let alias = if items.is_none() && parser.match_token(TokenType::As) {
+        Some(parser.consume(TokenType::Identifier, "Expect alias name after 'as'."))
+    } else {
+        None
+    };
+    parser.consume(TokenType::Semicolon, "Expect ';' after import path.");
+    Stmt::Import { path, items, alias }
+}
+
+/// `type Nome = T`.
+pub fn type_alias_declaration(parser: &mut Parser) -> Stmt {
+    let name = parser.consume(TokenType::Identifier, "Expect type alias name.");
+    parser.consume(TokenType::Equal, "Expect '=' after type alias name.");
+    let target = parser.parse_type();
+    parser.match_token(TokenType::Semicolon);
+    Stmt::TypeAlias { name, target }
+}
+
+/// `newtype Nome(T)`.
+pub fn newtype_declaration(parser: &mut Parser) -> Stmt {
+    let name = parser.consume(TokenType::Identifier, "Expect newtype name.");
+    parser.consume(TokenType::LeftParen, "Expect '(' after newtype name.");
+    let inner = parser.parse_type();
+    parser.consume(
+        TokenType::RightParen,
+        "Expect ')' after newtype representation type.",
+    );
+    parser.match_token(TokenType::Semicolon);
+    Stmt::Newtype { name, inner }
+}
+
+/// `var x = expr` — mesma gramática de `let`, mas o binding aceita reatribuição.
+pub fn var_declaration(parser: &mut Parser) -> Stmt {
+    binding_declaration(parser, true)
+}
+
+fn binding_declaration(parser: &mut Parser, mutable: bool) -> Stmt {
     let pattern = parse_pattern(parser);
 
     let ty = if parser.match_token(TokenType::Colon) {
@@ -193,32 +504,9 @@ pub fn let_declaration(parser: &mut Parser) -> Stmt {
     } = &initializer
         && parser.match_token(TokenType::LeftBrace)
     {
-        let mut fields = Vec::new();
-        while !parser.is_at_end() && !parser.check(&TokenType::RightBrace) {
-            if parser.check(&TokenType::Identifier) {
-                let field_name = parser.advance();
-                parser.consume(TokenType::Colon, "Expect ':' after field name.");
-                let value = parser.expression();
-                fields.push((field_name, value));
-                if !parser.check(&TokenType::RightBrace) {
-                    parser.match_token(TokenType::Comma);
-                }
-            } else {
-                let p = parser.peek();
-                parser.diagnostics.push(diagnostics::Diagnostic::new(
-                    diagnostics::DiagnosticKind::Parse,
-                    format!("Expected field name, got {:?}", p.token_type),
-                    diagnostics::Span::new(p.start, p.end, p.line, p.col),
-                ));
-                break;
-            }
-        }
-        parser.consume(TokenType::RightBrace, "Expect '}' after struct fields.");
-        initializer = Expr::StructInit {
-            name: struct_name_tok.clone(),
-            fields,
-        };
+        initializer = struct_init_fields(parser, struct_name_tok.clone());
     }
+    parser.claim_await(&initializer);
     parser.match_token(TokenType::Semicolon);
 
     // Fallback: If pattern is a simple variable, we use it directly as the name.
@@ -226,6 +514,7 @@ pub fn let_declaration(parser: &mut Parser) -> Stmt {
         Stmt::Let {
             pattern: MatchPattern::Variable(name),
             ty,
+            mutable,
             initializer,
         }
     } else {
@@ -238,6 +527,7 @@ pub fn let_declaration(parser: &mut Parser) -> Stmt {
         Stmt::Let {
             pattern,
             ty,
+            mutable,
             initializer,
         }
     }
@@ -312,6 +602,7 @@ pub fn block(parser: &mut Parser) -> Vec<Stmt> {
 
 pub fn match_statement(parser: &mut Parser) -> Stmt {
     parser.consume(TokenType::Match, "Expect 'match'.");
+    let start = parser.peek();
     let expr = parser.expression();
     parser.consume(TokenType::LeftBrace, "Expect '{' after match expression.");
     let mut cases = Vec::new();
@@ -329,10 +620,166 @@ pub fn match_statement(parser: &mut Parser) -> Stmt {
         cases.push((pattern, guard, stmt));
     }
     parser.consume(TokenType::RightBrace, "Expect '}' after match cases.");
-    Stmt::Match { expr, cases }
+    Stmt::Match { expr, start, cases }
 }
 
+/// Padrão completo: alternativas `A | B`, cada uma com `nome @` opcional.
 pub fn parse_pattern(parser: &mut Parser) -> MatchPattern {
+    let first = parse_at_pattern(parser);
+    if !parser.check(&TokenType::Pipe) {
+        return first;
+    }
+    let pipe = parser.peek();
+    let mut alts = vec![first];
+    while parser.match_token(TokenType::Pipe) {
+        alts.push(parse_at_pattern(parser));
+    }
+    let names = |p: &MatchPattern| {
+        let mut names: Vec<String> = p.bindings().iter().map(|t| t.lexeme.clone()).collect();
+        names.sort();
+        names
+    };
+    let expected = names(&alts[0]);
+    if alts.iter().skip(1).any(|alt| names(alt) != expected) {
+        parser.diagnostics.push(diagnostics::Diagnostic::new(
+            diagnostics::DiagnosticKind::Parse,
+            "All alternatives of an or-pattern must bind the same names.".to_string(),
+            diagnostics::Span::new(pipe.start, pipe.end, pipe.line, pipe.col),
+        ));
+    }
+    MatchPattern::Or(alts)
+}
+
+/// `nome @ padrão` ou um padrão simples.
+fn parse_at_pattern(parser: &mut Parser) -> MatchPattern {
+    let next_is_at = parser
+        .tokens_ref()
+        .get(parser.current_pos() + 1)
+        .is_some_and(|t| t.token_type == TokenType::At);
+    if parser.check(&TokenType::Identifier) && next_is_at {
+        let name = parser.advance();
+        parser.advance(); // consome '@'
+        let pattern = parse_at_pattern(parser);
+        return MatchPattern::At {
+            name,
+            pattern: Box::new(pattern),
+        };
+    }
+    parse_simple_pattern(parser)
+}
+
+/// Número com sinal opcional; `None` (com diagnóstico) se não houver número.
+fn pattern_number(parser: &mut Parser) -> Option<ArtValue> {
+    let negative = parser.match_token(TokenType::Minus);
+    let tok = parser.peek();
+    let value = match tok.token_type {
+        // `-i64::MIN` já chega como `Int(i64::MIN)`; `wrapping_neg` o mantém.
+        TokenType::Int(n) => ArtValue::Int(if negative { n.wrapping_neg() } else { n }),
+        TokenType::Float(n) => ArtValue::Float(if negative { -n } else { n }),
+        _ => {
+            parser.diagnostics.push(diagnostics::Diagnostic::new(
+                diagnostics::DiagnosticKind::Parse,
+                "Expect number in pattern.".to_string(),
+                diagnostics::Span::new(tok.start, tok.end, tok.line, tok.col),
+            ));
+            return None;
+        }
+    };
+    parser.advance();
+    Some(value)
+}
+
+/// `[a, ..rest, z]`; o `[` já foi consumido.
+fn array_pattern(parser: &mut Parser) -> MatchPattern {
+    let mut prefix = Vec::new();
+    let mut rest: Option<Option<Token>> = None;
+    let mut suffix = Vec::new();
+    if !parser.check(&TokenType::RightBracket) {
+        loop {
+            if parser.check(&TokenType::DotDot) {
+                let dots = parser.advance();
+                let name = if parser.check(&TokenType::Identifier) {
+                    Some(parser.advance())
+                } else {
+                    None
+                };
+                if rest.is_some() {
+                    parser.diagnostics.push(diagnostics::Diagnostic::new(
+                        diagnostics::DiagnosticKind::Parse,
+                        "Only one rest pattern is allowed in an array pattern.".to_string(),
+                        diagnostics::Span::new(dots.start, dots.end, dots.line, dots.col),
+                    ));
+                }
+                rest = Some(name);
+            } else if rest.is_some() {
+                suffix.push(parse_pattern(parser));
+            } else {
+                prefix.push(parse_pattern(parser));
+            }
+            if !parser.match_token(TokenType::Comma) || parser.check(&TokenType::RightBracket) {
+                break;
+            }
+        }
+    }
+    parser.consume(TokenType::RightBracket, "Expect ']' after array pattern.");
+    MatchPattern::Array {
+        prefix,
+        rest,
+        suffix,
+    }
+}
+
+/// `Nome { x, y: padrão }`; o `{` já foi consumido.
+fn struct_pattern(parser: &mut Parser, name: Token) -> MatchPattern {
+    let mut fields = Vec::new();
+    while !parser.check(&TokenType::RightBrace) && !parser.is_at_end() {
+        let field = parser.consume(
+            TokenType::Identifier,
+            "Expect field name in struct pattern.",
+        );
+        let pattern = if parser.match_token(TokenType::Colon) {
+            parse_pattern(parser)
+        } else {
+            MatchPattern::Variable(field.clone())
+        };
+        fields.push((field, pattern));
+        if !parser.match_token(TokenType::Comma) {
+            break;
+        }
+    }
+    parser.consume(TokenType::RightBrace, "Expect '}' after struct pattern.");
+    MatchPattern::Struct { name, fields }
+}
+
+fn parse_simple_pattern(parser: &mut Parser) -> MatchPattern {
+    if parser.check(&TokenType::Minus)
+        || matches!(
+            parser.peek().token_type,
+            TokenType::Int(_) | TokenType::Float(_)
+        )
+    {
+        let Some(start) = pattern_number(parser) else {
+            return MatchPattern::Wildcard;
+        };
+        let inclusive = if parser.match_token(TokenType::DotDotEqual) {
+            true
+        } else if parser.match_token(TokenType::DotDot) {
+            false
+        } else {
+            return MatchPattern::Literal(start);
+        };
+        let Some(end) = pattern_number(parser) else {
+            return MatchPattern::Wildcard;
+        };
+        return MatchPattern::Range {
+            start,
+            end,
+            inclusive,
+        };
+    }
+    if parser.match_token(TokenType::LeftBracket) {
+        return array_pattern(parser);
+    }
     if parser.match_token(TokenType::Dot) {
         // Padrão shorthand: .variant
         let variant = parser.consume(TokenType::Identifier, "Expect variant name after '.'");
@@ -363,13 +810,8 @@ pub fn parse_pattern(parser: &mut Parser) -> MatchPattern {
     } else if is_literal_token(&parser.peek().token_type) {
         let token = parser.advance();
         match token.token_type {
-            TokenType::Number(n) => {
-                if n.fract() == 0.0 {
-                    MatchPattern::Literal(ArtValue::Int(n as i64))
-                } else {
-                    MatchPattern::Literal(ArtValue::Float(n))
-                }
-            }
+            TokenType::Int(n) => MatchPattern::Literal(ArtValue::Int(n)),
+            TokenType::Float(n) => MatchPattern::Literal(ArtValue::Float(n)),
             TokenType::String(s) => MatchPattern::Literal(ArtValue::String(core::intern_arc(&s))),
             TokenType::True => MatchPattern::Literal(ArtValue::Bool(true)),
             TokenType::False => MatchPattern::Literal(ArtValue::Bool(false)),
@@ -424,6 +866,10 @@ pub fn parse_pattern(parser: &mut Parser) -> MatchPattern {
                 variant: name,
                 params: Some(param_list),
             }
+        } else if name.lexeme.starts_with(|c: char| c.is_uppercase())
+            && parser.match_token(TokenType::LeftBrace)
+        {
+            struct_pattern(parser, name)
         } else {
             MatchPattern::Variable(name)
         }
@@ -453,7 +899,8 @@ pub fn parse_pattern(parser: &mut Parser) -> MatchPattern {
 fn is_literal_token(token_type: &TokenType) -> bool {
     matches!(
         token_type,
-        TokenType::Number(_)
+        TokenType::Int(_)
+            | TokenType::Float(_)
             | TokenType::String(_)
             | TokenType::True
             | TokenType::False
@@ -462,51 +909,107 @@ fn is_literal_token(token_type: &TokenType) -> bool {
 }
 
 pub fn return_statement(parser: &mut Parser) -> Stmt {
-    parser.consume(TokenType::Return, "Expect 'return'.");
+    let keyword = parser.consume(TokenType::Return, "Expect 'return'.");
+    parser.reject_in_defer(&keyword);
     let value = if parser.check(&TokenType::Semicolon) {
         None
     } else {
         Some(parser.expression())
     };
+    if let Some(value) = &value {
+        parser.claim_await(value);
+    }
     parser.match_token(TokenType::Semicolon);
     Stmt::Return { value }
 }
 
 pub fn yield_statement(parser: &mut Parser) -> Stmt {
-    parser.consume(TokenType::Yield, "Expect 'yield'.");
+    let keyword = parser.consume(TokenType::Yield, "Expect 'yield'.");
+    parser.reject_in_defer(&keyword);
     let value = parser.expression();
     parser.match_token(TokenType::Semicolon);
+    Stmt::Yield { keyword, value }
+}
 
-    // Sugar: `yield expr` => `return Option.Some(expr)`.
-    Stmt::Return {
-        value: Some(Expr::EnumInit {
-            name: Some(core::Token::dummy("Option")),
-            variant: core::Token::dummy("Some"),
-            values: vec![value],
-        }),
+pub fn defer_statement(parser: &mut Parser) -> Stmt {
+    let keyword = parser.consume(TokenType::Defer, "Expect 'defer'.");
+    parser.consume(TokenType::LeftBrace, "Expect '{' after 'defer'.");
+    let statements = parser.in_defer(block);
+    Stmt::Defer {
+        keyword,
+        body: Box::new(Stmt::Block { statements }),
     }
 }
 
-pub fn while_statement(parser: &mut Parser) -> Stmt {
+pub fn while_statement(parser: &mut Parser, label: Option<core::Token>) -> Stmt {
     parser.consume(TokenType::While, "Expect 'while'.");
     let condition = parser.expression();
-    let body = Box::new(statement(parser));
+    let body = Box::new(parser.loop_body(label.clone()));
 
-    Stmt::While { condition, body }
+    Stmt::While {
+        condition,
+        body,
+        label,
+    }
 }
 
-pub fn for_statement(parser: &mut Parser) -> Stmt {
+pub fn for_statement(parser: &mut Parser, label: Option<core::Token>) -> Stmt {
     parser.consume(TokenType::For, "Expect 'for'.");
-    let element = parser.consume(TokenType::Identifier, "Expect element name after 'for'.");
+    // `for (k, v) in m`: o `(` serve de token do elemento para spans.
+    let (element, pattern) = if parser.check(&TokenType::LeftParen) {
+        let paren = parser.peek();
+        (paren, Some(parse_pattern(parser)))
+    } else {
+        let element = parser.consume(TokenType::Identifier, "Expect element name after 'for'.");
+        (element, None)
+    };
 
     parser.consume(TokenType::In, "Expect 'in' after for loop element.");
     let iterator = parser.expression();
 
-    let body = Box::new(statement(parser));
+    let body = Box::new(parser.loop_body(label.clone()));
 
     Stmt::For {
         element,
+        pattern,
         iterator,
         body,
+        label,
+    }
+}
+
+/// `'rotulo: while ...` / `'rotulo: for ...`.
+fn labeled_loop(parser: &mut Parser) -> Stmt {
+    let label = parser.advance();
+    parser.consume(TokenType::Colon, "Expect ':' after loop label.");
+    if parser.check(&TokenType::While) {
+        while_statement(parser, Some(label))
+    } else if parser.check(&TokenType::For) {
+        for_statement(parser, Some(label))
+    } else {
+        let p = parser.peek();
+        parser.diagnostics.push(diagnostics::Diagnostic::new(
+            diagnostics::DiagnosticKind::Parse,
+            format!("Expect 'while' or 'for' after loop label {}.", label.lexeme),
+            diagnostics::Span::new(p.start, p.end, p.line, p.col),
+        ));
+        statement(parser)
+    }
+}
+
+/// `break` / `continue`, com rótulo opcional.
+fn loop_jump(parser: &mut Parser) -> Stmt {
+    let keyword = parser.advance();
+    let label = if parser.check(&TokenType::Label) {
+        Some(parser.advance())
+    } else {
+        None
+    };
+    parser.check_loop_target(&keyword, label.as_ref());
+    parser.match_token(TokenType::Semicolon);
+    if keyword.token_type == TokenType::Break {
+        Stmt::Break { keyword, label }
+    } else {
+        Stmt::Continue { keyword, label }
     }
 }
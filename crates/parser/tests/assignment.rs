@@ -0,0 +1,60 @@
+use core::ast::{Expr, Stmt};
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn parse(src: &str) -> (Vec<Stmt>, Vec<diagnostics::Diagnostic>) {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    Parser::new(tokens).parse()
+}
+
+#[test]
+fn parses_var_declaration_as_mutable_let() {
+    let (program, diags) = parse("var x = 1;");
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    match &program[0] {
+        Stmt::Let { mutable, .. } => assert!(*mutable),
+        other => panic!("expected let statement, got {:?}", other),
+    }
+    let (program, _) = parse("let x = 1;");
+    assert!(matches!(&program[0], Stmt::Let { mutable: false, .. }));
+}
+
+#[test]
+fn parses_compound_assignment() {
+    let (program, diags) = parse("var x = 1;\nx += 2;");
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    match &program[1] {
+        Stmt::Assign {
+            target, operator, ..
+        } => {
+            assert!(matches!(target, Expr::Variable { name } if name.lexeme == "x"));
+            assert_eq!(operator.lexeme, "+=");
+        }
+        other => panic!("expected assignment, got {:?}", other),
+    }
+}
+
+#[test]
+fn parses_field_assignment() {
+    let (program, diags) = parse("p.x = 3;");
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    match &program[0] {
+        Stmt::Assign { target, .. } => {
+            assert!(matches!(target, Expr::FieldAccess { field, .. } if field.lexeme == "x"))
+        }
+        other => panic!("expected assignment, got {:?}", other),
+    }
+}
+
+#[test]
+fn invalid_assignment_target_reports_diagnostic() {
+    let (_, diags) = parse("f() = 3;");
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.message.contains("Invalid assignment target")),
+        "{:?}",
+        diags
+    );
+}
@@ -0,0 +1,85 @@
+use core::ast::{Expr, Stmt};
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn parse(src: &str) -> (Vec<Stmt>, Vec<diagnostics::Diagnostic>) {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    Parser::new(tokens).parse()
+}
+
+#[test]
+fn parses_async_functions_and_await() {
+    let (program, diags) = parse(
+        "async func load(ms) { let x = await sleep(ms); return x; }\nfunc plain() { return 1; }",
+    );
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    match &program[0] {
+        Stmt::Function { is_async, body, .. } => {
+            assert!(*is_async);
+            assert!(body.contains_await());
+            match body.as_ref() {
+                Stmt::Block { statements } => match statements[0].awaited() {
+                    Some(Expr::Await { keyword, value }) => {
+                        assert_eq!(keyword.lexeme, "await");
+                        assert!(matches!(**value, Expr::Call { .. }));
+                    }
+                    other => panic!("expected awaited let, got {:?}", other),
+                },
+                other => panic!("expected function block, got {:?}", other),
+            }
+        }
+        other => panic!("expected function declaration, got {:?}", other),
+    }
+    assert!(matches!(
+        &program[1],
+        Stmt::Function {
+            is_async: false,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn await_placement_errors_are_reported() {
+    let cases = [
+        (
+            "func f() { await sleep(1); }",
+            "'await' outside of an async function.",
+        ),
+        (
+            "async func f() { let g = |x| await x; }",
+            "'await' outside of an async function.",
+        ),
+        (
+            "spawn actor { let x = await sleep(1); }",
+            "'await' outside of an async function.",
+        ),
+        (
+            "async func f() { println(await sleep(1)); }",
+            "'await' must be the whole value of a let, assignment, return or expression statement.",
+        ),
+        (
+            "async func f() { if await g() { return 1; } }",
+            "'await' must be the whole value of a let, assignment, return or expression statement.",
+        ),
+        (
+            "async func f() { yield 1; }",
+            "An async function cannot contain 'yield'.",
+        ),
+    ];
+    for (src, expected) in cases {
+        let (_, diags) = parse(src);
+        assert!(
+            diags.iter().any(|d| d.message == expected),
+            "{}: {:?}",
+            src,
+            diags
+        );
+    }
+    // No topo do arquivo o `await` bloqueia e pode aparecer em qualquer posição.
+    let (_, diags) = parse(
+        "println(await sleep(1));\nimpl T { async func m(self) { var n = 0; n += await sleep(1); } }",
+    );
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+}
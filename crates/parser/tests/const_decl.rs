@@ -0,0 +1,55 @@
+use core::ast::{Expr, Stmt};
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn parse(src: &str) -> (Vec<Stmt>, Vec<diagnostics::Diagnostic>) {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    Parser::new(tokens).parse()
+}
+
+#[test]
+fn parses_top_level_const() {
+    let (program, diags) = parse("const LIMIT: Int = 10 * 10;");
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    match &program[0] {
+        Stmt::Const {
+            name, ty, value, ..
+        } => {
+            assert_eq!(name.lexeme, "LIMIT");
+            assert_eq!(ty, "Int");
+            assert!(matches!(value, Expr::Binary { .. }));
+        }
+        other => panic!("expected const, got {:?}", other),
+    }
+}
+
+#[test]
+fn uppercase_const_field_access_is_not_an_enum_variant() {
+    let (program, diags) =
+        parse("struct P { x: Int, y: Int }\nconst ORIGIN: P = P { x: 1, y: 2 };\nORIGIN.x");
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    assert!(
+        matches!(&program[2], Stmt::Expression(Expr::FieldAccess { field, .. }) if field.lexeme == "x"),
+        "got {:?}",
+        program[2]
+    );
+}
+
+#[test]
+fn const_requires_type_and_top_level() {
+    let (_, diags) = parse("const N = 1;");
+    assert!(
+        diags.iter().any(|d| d.message.contains("Expect ':'")),
+        "{:?}",
+        diags
+    );
+    let (_, diags) = parse("func f() { const N: Int = 1; }");
+    assert!(
+        diags.iter().any(|d| d
+            .message
+            .contains("'const' is only allowed at the top level")),
+        "{:?}",
+        diags
+    );
+}
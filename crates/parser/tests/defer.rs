@@ -0,0 +1,64 @@
+use core::ast::Stmt;
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn parse(src: &str) -> (Vec<Stmt>, Vec<diagnostics::Diagnostic>) {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    Parser::new(tokens).parse()
+}
+
+#[test]
+fn parses_defer_blocks() {
+    let (program, diags) = parse("func f() { defer { close(h); } return 1; }");
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let Stmt::Function { body, .. } = &program[0] else {
+        panic!("expected function, got {:?}", program[0]);
+    };
+    let Stmt::Block { statements } = body.as_ref() else {
+        panic!("expected block body");
+    };
+    match &statements[0] {
+        Stmt::Defer { keyword, body } => {
+            assert_eq!(keyword.lexeme, "defer");
+            assert!(matches!(body.as_ref(), Stmt::Block { statements } if statements.len() == 1));
+        }
+        other => panic!("expected defer, got {:?}", other),
+    }
+}
+
+#[test]
+fn defer_bodies_cannot_leave_the_scope() {
+    let cases = [
+        (
+            "func f() { defer { return 1; } }",
+            "'return' is not allowed inside a 'defer' block.",
+        ),
+        (
+            "func g() { defer { yield 1; } }",
+            "'yield' is not allowed inside a 'defer' block.",
+        ),
+        (
+            "async func h() { defer { let x = await sleep(1); } }",
+            "'await' is not allowed inside a 'defer' block.",
+        ),
+        (
+            "while true { defer { break; } }",
+            "'break' outside of a loop.",
+        ),
+        ("defer close(h);", "Expect '{' after 'defer'."),
+    ];
+    for (src, expected) in cases {
+        let (_, diags) = parse(src);
+        assert!(
+            diags.iter().any(|d| d.message.starts_with(expected)),
+            "{}: {:?}",
+            src,
+            diags
+        );
+    }
+    // Loops e funções dentro do `defer` têm seus próprios alvos.
+    let (_, diags) =
+        parse("func f() { defer { for x in xs { if x { break; } } let g = || { return 1; }; } }");
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+}
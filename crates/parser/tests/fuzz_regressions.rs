@@ -53,3 +53,12 @@ fn malformed_while_header_terminates() {
 fn empty_input_parses_without_panicking() {
     assert_eq!(lex_and_parse(b""), 0);
 }
+
+/// Found by `fuzzing_parser_symmetry_and_stability`: a lambda opened at the end
+/// of the input re-read its own `|` until the depth guard, overflowing the
+/// 2 MiB test thread stack before getting there.
+#[test]
+fn lambda_opened_at_end_of_input_terminates() {
+    lex_and_parse(b"i %|");
+    lex_and_parse(b"func(");
+}
@@ -0,0 +1,48 @@
+use core::ast::{Expr, Stmt};
+use lexer::Lexer;
+use parser::Parser;
+
+fn parse(src: &str) -> Vec<Stmt> {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    program
+}
+
+#[test]
+fn generic_signature_keeps_bounds_and_full_param_types() {
+    let program = parse("func f<T: Numeric, U>(xs: [T], m: Map<String, [U]>) -> (T, U) { }");
+    let Some(Stmt::Function {
+        type_params,
+        params,
+        return_type,
+        ..
+    }) = program.first()
+    else {
+        panic!("expected function, got {:?}", program);
+    };
+    assert_eq!(
+        type_params.as_deref(),
+        Some(
+            &[
+                ("T".to_string(), Some("Numeric".to_string())),
+                ("U".to_string(), None)
+            ][..]
+        )
+    );
+    let types: Vec<_> = params.iter().map(|p| p.ty.as_deref()).collect();
+    assert_eq!(types, vec![Some("[T]"), Some("Map<String, [U]>")]);
+    assert_eq!(return_type.as_deref(), Some("(T, U)"));
+}
+
+#[test]
+fn turbofish_sets_call_type_args() {
+    let program = parse("f::<Int, [String]>(1, []);");
+    let Some(Stmt::Expression(Expr::Call { type_args, .. })) = program.first() else {
+        panic!("expected call, got {:?}", program);
+    };
+    assert_eq!(
+        type_args.as_deref(),
+        Some(&["Int".to_string(), "[String]".to_string()][..])
+    );
+}
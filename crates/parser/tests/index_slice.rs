@@ -0,0 +1,67 @@
+use core::ast::{Expr, Stmt};
+use lexer::Lexer;
+use parser::Parser;
+
+fn parse(src: &str) -> (Vec<Stmt>, Vec<diagnostics::Diagnostic>) {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    Parser::new(tokens).parse()
+}
+
+fn initializer(src: &str) -> Expr {
+    let (program, diags) = parse(src);
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    match program.into_iter().next() {
+        Some(Stmt::Let { initializer, .. }) => initializer,
+        other => panic!("expected let statement, got {:?}", other),
+    }
+}
+
+#[test]
+fn parses_chained_index_after_call() {
+    match initializer("let x = f()[0][1];") {
+        Expr::Index { object, .. } => {
+            assert!(matches!(*object, Expr::Index { .. }), "{:?}", object)
+        }
+        other => panic!("expected index, got {:?}", other),
+    }
+}
+
+#[test]
+fn parses_slices_with_optional_bounds() {
+    let bounds = |src: &str| match initializer(src) {
+        Expr::Slice { start, end, .. } => (start.is_some(), end.is_some()),
+        other => panic!("expected slice, got {:?}", other),
+    };
+    assert_eq!(bounds("let x = a[1..3];"), (true, true));
+    assert_eq!(bounds("let x = a[..3];"), (false, true));
+    assert_eq!(bounds("let x = a[1..];"), (true, false));
+    assert_eq!(bounds("let x = a[..];"), (false, false));
+}
+
+#[test]
+fn parses_index_assignment() {
+    let (program, diags) = parse("a[i + 1] -= 2;");
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    match &program[0] {
+        Stmt::Assign {
+            target, operator, ..
+        } => {
+            assert!(matches!(target, Expr::Index { .. }));
+            assert_eq!(operator.lexeme, "-=");
+        }
+        other => panic!("expected assignment, got {:?}", other),
+    }
+}
+
+#[test]
+fn slice_is_not_an_assignment_target() {
+    let (_, diags) = parse("a[0..1] = b;");
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.message.contains("Invalid assignment target")),
+        "{:?}",
+        diags
+    );
+}
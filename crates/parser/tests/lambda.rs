@@ -0,0 +1,107 @@
+use core::ast::{Expr, Stmt};
+use lexer::Lexer;
+use parser::Parser;
+
+fn parse(src: &str) -> (Vec<Stmt>, Vec<diagnostics::Diagnostic>) {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    Parser::new(tokens).parse()
+}
+
+fn initializer(src: &str) -> Expr {
+    let (program, diags) = parse(src);
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    match program.into_iter().next() {
+        Some(Stmt::Let { initializer, .. }) => initializer,
+        other => panic!("expected let statement, got {:?}", other),
+    }
+}
+
+fn body_statements(e: &Expr) -> &[Stmt] {
+    match e {
+        Expr::Lambda { body, .. } => match body.as_ref() {
+            Stmt::Block { statements } => statements,
+            other => panic!("lambda body must be a block, got {:?}", other),
+        },
+        other => panic!("expected lambda, got {:?}", other),
+    }
+}
+
+#[test]
+fn pipe_lambda_with_expression_body_desugars_to_return() {
+    let e = initializer("let f = |a, b| a + b;");
+    match &e {
+        Expr::Lambda {
+            keyword, params, ..
+        } => {
+            assert_eq!(keyword.lexeme, "|");
+            let names: Vec<&str> = params.iter().map(|p| p.name.lexeme.as_str()).collect();
+            assert_eq!(names, ["a", "b"]);
+        }
+        other => panic!("expected lambda, got {:?}", other),
+    }
+    assert!(matches!(
+        body_statements(&e),
+        [Stmt::Return {
+            value: Some(Expr::Binary { .. })
+        }]
+    ));
+}
+
+#[test]
+fn empty_pipes_and_block_body() {
+    let e = initializer("let f = || { let x = 1; println(x); };");
+    assert_eq!(body_statements(&e).len(), 2);
+}
+
+#[test]
+fn func_lambda_with_types_and_return_type() {
+    match initializer("let f = func(x: Int) -> Int { return x * 2; };") {
+        Expr::Lambda {
+            keyword,
+            params,
+            return_type,
+            ..
+        } => {
+            assert_eq!(keyword.lexeme, "func");
+            assert_eq!(params[0].ty.as_deref(), Some("Int"));
+            assert_eq!(return_type.as_deref(), Some("Int"));
+        }
+        other => panic!("expected lambda, got {:?}", other),
+    }
+}
+
+#[test]
+fn lambda_as_call_argument() {
+    match initializer("let ys = map(xs, |x| x * 2);") {
+        Expr::Call { arguments, .. } => {
+            assert_eq!(arguments.len(), 2);
+            assert!(matches!(arguments[1], Expr::Lambda { .. }));
+        }
+        other => panic!("expected call, got {:?}", other),
+    }
+}
+
+#[test]
+fn lambda_body_is_a_loop_boundary() {
+    let (_, diags) = parse("while true { let f = || { break; }; }");
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.message.contains("'break' outside of a loop.")),
+        "{:?}",
+        diags
+    );
+}
+
+#[test]
+fn return_type_requires_block_body() {
+    let (_, diags) = parse("let f = |x| -> Int x;");
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.message.contains("Expect '{' before lambda body.")),
+        "{:?}",
+        diags
+    );
+}
@@ -0,0 +1,65 @@
+use core::ast::Stmt;
+use lexer::Lexer;
+use parser::Parser;
+
+fn parse(src: &str) -> (Vec<Stmt>, Vec<diagnostics::Diagnostic>) {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    Parser::new(tokens).parse()
+}
+
+fn messages(src: &str) -> Vec<String> {
+    parse(src).1.into_iter().map(|d| d.message).collect()
+}
+
+#[test]
+fn parses_labeled_loops_and_jumps() {
+    let src =
+        "'outer: for x in xs {\n  while true {\n    if x { continue 'outer; }\n    break;\n  }\n}";
+    let (program, diags) = parse(src);
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let Stmt::For { label, body, .. } = &program[0] else {
+        panic!("expected for, got {:?}", program[0]);
+    };
+    assert_eq!(label.as_ref().map(|l| l.lexeme.as_str()), Some("'outer"));
+    let Stmt::Block { statements } = &**body else {
+        panic!("expected block body");
+    };
+    let Stmt::While { label, body, .. } = &statements[0] else {
+        panic!("expected while, got {:?}", statements[0]);
+    };
+    assert!(label.is_none());
+    let Stmt::Block { statements } = &**body else {
+        panic!("expected block body");
+    };
+    assert!(matches!(&statements[1], Stmt::Break { label: None, .. }));
+}
+
+#[test]
+fn break_outside_loop_is_diagnosed() {
+    assert_eq!(messages("break;"), vec!["'break' outside of a loop."]);
+    assert_eq!(
+        messages("if true { continue; }"),
+        vec!["'continue' outside of a loop."]
+    );
+}
+
+#[test]
+fn loops_do_not_cross_function_boundaries() {
+    let msgs = messages("while true {\n  func f() { break; }\n}");
+    assert_eq!(msgs, vec!["'break' outside of a loop."]);
+}
+
+#[test]
+fn unknown_label_is_diagnosed_at_the_label() {
+    let (_, diags) = parse("'a: while true {\n  break 'b;\n}");
+    assert_eq!(diags.len(), 1, "{:?}", diags);
+    assert_eq!(diags[0].message, "Unknown loop label 'b in 'break'.");
+    assert_eq!((diags[0].span.line, diags[0].span.col), (2, 9));
+}
+
+#[test]
+fn label_must_precede_a_loop() {
+    let msgs = messages("'a: x = 1;");
+    assert_eq!(msgs, vec!["Expect 'while' or 'for' after loop label 'a."]);
+}
@@ -0,0 +1,111 @@
+use core::ast::{Expr, Stmt};
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn parse(src: &str) -> (Vec<Stmt>, Vec<diagnostics::Diagnostic>) {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    Parser::new(tokens).parse()
+}
+
+fn names(tokens: &Option<Vec<core::Token>>) -> Option<Vec<&str>> {
+    tokens
+        .as_ref()
+        .map(|ts| ts.iter().map(|t| t.lexeme.as_str()).collect())
+}
+
+#[test]
+fn pub_marks_items_as_public() {
+    let (program, diags) = parse(
+        "pub func f() { }\nfunc g() { }\npub struct P { x: Int }\npub enum E { A }\npub const N: Int = 1;\npub async func h() { }",
+    );
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let public: Vec<bool> = program
+        .iter()
+        .map(|s| match s {
+            Stmt::Function { public, .. }
+            | Stmt::StructDecl { public, .. }
+            | Stmt::EnumDecl { public, .. }
+            | Stmt::Const { public, .. } => *public,
+            other => panic!("unexpected {:?}", other),
+        })
+        .collect();
+    assert_eq!(public, vec![true, false, true, true, true, true]);
+}
+
+#[test]
+fn pub_needs_a_top_level_item() {
+    let (_, diags) = parse("pub let x = 1;");
+    assert!(
+        diags.iter().any(|d| d.message.contains("after 'pub'")),
+        "{:?}",
+        diags
+    );
+    let (_, diags) = parse("func f() { pub func g() { } }");
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.message == "'pub' is only allowed at the top level."),
+        "{:?}",
+        diags
+    );
+}
+
+#[test]
+fn parses_selective_and_aliased_imports() {
+    let (program, diags) = parse("import util.{parse, Token};\nimport net.http as h;\nimport lib;");
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    match &program[0] {
+        Stmt::Import { path, items, alias } => {
+            assert_eq!(path.len(), 1);
+            assert_eq!(names(items), Some(vec!["parse", "Token"]));
+            assert!(alias.is_none());
+        }
+        other => panic!("expected import, got {:?}", other),
+    }
+    match &program[1] {
+        Stmt::Import { path, items, alias } => {
+            assert_eq!(path.len(), 2);
+            // Unused alias: nothing imported through it.
+            assert!(items.is_none());
+            assert_eq!(alias.as_ref().map(|a| a.lexeme.as_str()), Some("h"));
+        }
+        other => panic!("expected import, got {:?}", other),
+    }
+    assert!(matches!(
+        &program[2],
+        Stmt::Import {
+            items: None,
+            alias: None,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn qualified_access_reads_as_the_plain_item() {
+    let (program, diags) = parse(
+        "import net.http as h;\nlet r = h.get(\"/\");\nlet p = h.Point { x: 1 };\nlet c = h.Color.Red;\nlet n: h.Id = h.get(\"/a\");",
+    );
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    match &program[0] {
+        Stmt::Import { items, .. } => {
+            assert_eq!(names(items), Some(vec!["get", "Point", "Color", "Id"]));
+        }
+        other => panic!("expected import, got {:?}", other),
+    }
+    assert!(matches!(
+        &program[1],
+        Stmt::Let { initializer: Expr::Call { callee, .. }, .. }
+            if matches!(callee.as_ref(), Expr::Variable { name } if name.lexeme == "get")
+    ));
+    assert!(matches!(
+        &program[2],
+        Stmt::Let { initializer: Expr::StructInit { name, .. }, .. } if name.lexeme == "Point"
+    ));
+    assert!(matches!(
+        &program[3],
+        Stmt::Let { initializer: Expr::EnumInit { name: Some(name), .. }, .. } if name.lexeme == "Color"
+    ));
+    assert!(matches!(&program[4], Stmt::Let { ty: Some(ty), .. } if ty == "Id"));
+}
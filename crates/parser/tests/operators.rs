@@ -0,0 +1,80 @@
+use core::ast::{Expr, Stmt};
+use lexer::Lexer;
+use parser::Parser;
+
+fn parse(src: &str) -> (Vec<Stmt>, Vec<diagnostics::Diagnostic>) {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    Parser::new(tokens).parse()
+}
+
+fn initializer(src: &str) -> Expr {
+    let (program, diags) = parse(src);
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    match program.into_iter().next() {
+        Some(Stmt::Let { initializer, .. }) => initializer,
+        other => panic!("expected let statement, got {:?}", other),
+    }
+}
+
+/// Renders the tree with explicit parentheses around every binary node.
+fn shape(e: &Expr) -> String {
+    match e {
+        Expr::Binary {
+            left,
+            operator,
+            right,
+        } => format!("({} {} {})", shape(left), operator.lexeme, shape(right)),
+        Expr::Unary { operator, right } => format!("{}{}", operator.lexeme, shape(right)),
+        Expr::Variable { name } => name.lexeme.clone(),
+        other => format!("{:?}", other),
+    }
+}
+
+#[test]
+fn bitwise_precedence_ladder() {
+    let cases = [
+        ("let x = a | b ^ c & d;", "(a | (b ^ (c & d)))"),
+        ("let x = a & b << c + d;", "(a & (b << (c + d)))"),
+        ("let x = a & b == c;", "((a & b) == c)"),
+        ("let x = a < b | c;", "(a < (b | c))"),
+        ("let x = a + b % c;", "(a + (b % c))"),
+        ("let x = a >> b >> c;", "((a >> b) >> c)"),
+        ("let x = ~a & b;", "(~a & b)"),
+    ];
+    for (src, expected) in cases {
+        assert_eq!(shape(&initializer(src)), expected, "{}", src);
+    }
+}
+
+#[test]
+fn nested_generic_closes_with_shift_token() {
+    let (program, diags) = parse("let grid: Array<Array<Int>> = [[1]];\nlet y = grid >> 1;");
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    match &program[0] {
+        Stmt::Let { ty, .. } => assert_eq!(ty.as_deref(), Some("Array<Array<Int>>")),
+        other => panic!("expected let statement, got {:?}", other),
+    }
+}
+
+#[test]
+fn single_pipe_is_an_operator() {
+    assert_eq!(shape(&initializer("let x = a | b;")), "(a | b)");
+}
+
+#[test]
+fn negated_min_int_literal_folds_into_one_literal() {
+    use core::ast::ArtValue;
+    let min = Expr::Literal(ArtValue::Int(i64::MIN));
+    assert_eq!(initializer("let x = -9223372036854775808;"), min);
+    assert_eq!(initializer("let x = -0x8000000000000000;"), min);
+    assert_eq!(
+        shape(&initializer("let x = 1 - -9223372036854775808;")),
+        format!("({:?} - {:?})", Expr::Literal(ArtValue::Int(1)), min)
+    );
+    // Com `-` binário, a magnitude 2^63 continua fora da faixa.
+    let (_, diags) = parse("let x = a - 9223372036854775808;");
+    assert_eq!(diags.len(), 1, "{:?}", diags);
+    assert_eq!(diags[0].message, "Integer literal out of range for Int");
+    assert_eq!((diags[0].span.line, diags[0].span.col), (1, 13));
+}
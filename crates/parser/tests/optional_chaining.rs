@@ -0,0 +1,58 @@
+use core::ast::{Expr, Stmt};
+use lexer::Lexer;
+use parser::Parser;
+
+fn initializer(src: &str) -> Expr {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    match program.into_iter().next() {
+        Some(Stmt::Let { initializer, .. }) => initializer,
+        other => panic!("expected let statement, got {:?}", other),
+    }
+}
+
+#[test]
+fn optional_chain_nests_to_the_left_and_takes_call_arguments() {
+    let e = initializer("let x = a?.b?.greet(1, 2);");
+    let Expr::OptionalChain {
+        object,
+        field,
+        arguments,
+    } = e
+    else {
+        panic!("expected optional chain, got {:?}", e);
+    };
+    assert_eq!(field.lexeme, "greet");
+    assert_eq!(arguments.map(|a| a.len()), Some(2));
+    assert!(matches!(
+        *object,
+        Expr::OptionalChain { ref field, arguments: None, .. } if field.lexeme == "b"
+    ));
+}
+
+#[test]
+fn coalesce_is_right_associative_and_binds_tighter_than_comparison() {
+    let e = initializer("let x = a ?? b ?? c + 1 == 2;");
+    let Expr::Binary { left, .. } = e else {
+        panic!("expected comparison at the top, got {:?}", e);
+    };
+    let Expr::Coalesce { left, right, .. } = *left else {
+        panic!("expected coalesce");
+    };
+    assert!(matches!(*left, Expr::Variable { .. }));
+    assert!(matches!(
+        *right,
+        Expr::Coalesce { ref right, .. } if matches!(**right, Expr::Binary { .. })
+    ));
+}
+
+#[test]
+fn weak_upgrade_is_still_a_postfix_question_mark() {
+    assert!(matches!(initializer("let x = w?;"), Expr::WeakUpgrade(_)));
+    assert!(matches!(
+        initializer("let x = (w?).name;"),
+        Expr::FieldAccess { ref object, .. }
+            if matches!(**object, Expr::Grouping { ref expression } if matches!(**expression, Expr::WeakUpgrade(_)))
+    ));
+}
@@ -0,0 +1,132 @@
+use core::ast::{ArtValue, MatchPattern, Stmt};
+use lexer::Lexer;
+use parser::Parser;
+
+fn parse(src: &str) -> (Vec<Stmt>, Vec<diagnostics::Diagnostic>) {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    Parser::new(tokens).parse()
+}
+
+/// Patterns of the `match` in `src`, in case order.
+fn case_patterns(src: &str) -> Vec<MatchPattern> {
+    let (program, diags) = parse(src);
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    match program.into_iter().next() {
+        Some(Stmt::Match { cases, .. }) => cases.into_iter().map(|(p, _, _)| p).collect(),
+        other => panic!("expected match statement, got {:?}", other),
+    }
+}
+
+fn names(pattern: &MatchPattern) -> Vec<String> {
+    pattern
+        .bindings()
+        .into_iter()
+        .map(|t| t.lexeme.clone())
+        .collect()
+}
+
+#[test]
+fn parses_struct_patterns_with_shorthand_fields() {
+    let pats = case_patterns("match p { case Point { x, y: 0 }: println(x) }");
+    match &pats[0] {
+        MatchPattern::Struct { name, fields } => {
+            assert_eq!(name.lexeme, "Point");
+            assert_eq!(fields.len(), 2);
+            assert!(matches!(fields[0].1, MatchPattern::Variable(_)));
+            assert!(matches!(
+                fields[1].1,
+                MatchPattern::Literal(ArtValue::Int(0))
+            ));
+        }
+        other => panic!("expected struct pattern, got {:?}", other),
+    }
+    assert_eq!(names(&pats[0]), vec!["x"]);
+}
+
+#[test]
+fn parses_array_patterns_with_rest() {
+    let pats = case_patterns(
+        "match xs { case []: println(0) case [first, ..rest, last]: println(first) case [_, ..]: println(1) }",
+    );
+    assert!(matches!(
+        &pats[0],
+        MatchPattern::Array { prefix, rest: None, suffix } if prefix.is_empty() && suffix.is_empty()
+    ));
+    match &pats[1] {
+        MatchPattern::Array {
+            prefix,
+            rest: Some(Some(rest)),
+            suffix,
+        } => {
+            assert_eq!((prefix.len(), suffix.len()), (1, 1));
+            assert_eq!(rest.lexeme, "rest");
+        }
+        other => panic!("expected array pattern, got {:?}", other),
+    }
+    assert!(matches!(
+        &pats[2],
+        MatchPattern::Array {
+            rest: Some(None),
+            ..
+        }
+    ));
+    assert_eq!(names(&pats[1]), vec!["first", "rest", "last"]);
+}
+
+#[test]
+fn parses_or_range_and_at_patterns() {
+    let pats = case_patterns(
+        "match n { case 0 | 1: println(n) case small @ 2..=9: println(small) case -5..0: println(n) }",
+    );
+    assert!(matches!(&pats[0], MatchPattern::Or(alts) if alts.len() == 2));
+    match &pats[1] {
+        MatchPattern::At { name, pattern } => {
+            assert_eq!(name.lexeme, "small");
+            assert!(matches!(
+                **pattern,
+                MatchPattern::Range {
+                    start: ArtValue::Int(2),
+                    end: ArtValue::Int(9),
+                    inclusive: true
+                }
+            ));
+        }
+        other => panic!("expected @-binding, got {:?}", other),
+    }
+    assert!(matches!(
+        &pats[2],
+        MatchPattern::Range {
+            start: ArtValue::Int(-5),
+            end: ArtValue::Int(0),
+            inclusive: false
+        }
+    ));
+}
+
+#[test]
+fn pattern_errors_are_reported() {
+    let cases = [
+        (
+            "match t { case (0, x) | (y, 0): println(1) }",
+            "All alternatives of an or-pattern must bind the same names.",
+        ),
+        (
+            "match xs { case [a, ..b, ..c]: println(a) }",
+            "Only one rest pattern is allowed in an array pattern.",
+        ),
+        (
+            "match n { case 1..x: println(n) }",
+            "Expect number in pattern.",
+        ),
+    ];
+    for (src, expected) in cases {
+        let (_, diags) = parse(src);
+        assert!(
+            diags.iter().any(|d| d.message.contains(expected)),
+            "{}: {:?}",
+            src,
+            diags
+        );
+    }
+}
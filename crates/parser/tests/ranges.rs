@@ -0,0 +1,82 @@
+use core::ast::{Expr, MatchPattern, Stmt};
+use lexer::Lexer;
+use parser::Parser;
+
+fn parse(src: &str) -> (Vec<Stmt>, Vec<diagnostics::Diagnostic>) {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    Parser::new(tokens).parse()
+}
+
+fn initializer(src: &str) -> Expr {
+    let (program, diags) = parse(src);
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    match program.into_iter().next() {
+        Some(Stmt::Let { initializer, .. }) => initializer,
+        other => panic!("expected let statement, got {:?}", other),
+    }
+}
+
+#[test]
+fn parses_exclusive_and_inclusive_ranges() {
+    match initializer("let r = 0..10;") {
+        Expr::Range { inclusive, .. } => assert!(!inclusive),
+        other => panic!("expected range, got {:?}", other),
+    }
+    match initializer("let r = 1..=n;") {
+        Expr::Range { inclusive, end, .. } => {
+            assert!(inclusive);
+            assert!(matches!(*end, Expr::Variable { .. }), "{:?}", end);
+        }
+        other => panic!("expected range, got {:?}", other),
+    }
+}
+
+#[test]
+fn range_binds_looser_than_arithmetic() {
+    match initializer("let r = a + 1..b * 2;") {
+        Expr::Range { start, end, .. } => {
+            assert!(matches!(*start, Expr::Binary { .. }), "{:?}", start);
+            assert!(matches!(*end, Expr::Binary { .. }), "{:?}", end);
+        }
+        other => panic!("expected range, got {:?}", other),
+    }
+}
+
+#[test]
+fn slices_are_not_ranges() {
+    assert!(matches!(
+        initializer("let s = a[1..3];"),
+        Expr::Slice { .. }
+    ));
+}
+
+#[test]
+fn parses_for_with_tuple_pattern() {
+    let (program, diags) = parse("for (k, v) in m {\n  println(k);\n}");
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let Stmt::For {
+        pattern: Some(MatchPattern::Tuple(items)),
+        ..
+    } = &program[0]
+    else {
+        panic!("expected destructuring for, got {:?}", program[0]);
+    };
+    assert_eq!(items.len(), 2);
+}
+
+#[test]
+fn parses_for_over_range() {
+    let (program, diags) = parse("for i in 0..n { }");
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let Stmt::For {
+        element,
+        pattern: None,
+        iterator: Expr::Range { .. },
+        ..
+    } = &program[0]
+    else {
+        panic!("expected for over a range, got {:?}", program[0]);
+    };
+    assert_eq!(element.lexeme, "i");
+}
@@ -0,0 +1,79 @@
+use core::ast::Stmt;
+use lexer::Lexer;
+use parser::Parser;
+
+fn parse(src: &str) -> (Vec<Stmt>, Vec<diagnostics::Diagnostic>) {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex ok");
+    Parser::new(tokens).parse()
+}
+
+#[test]
+fn trait_declares_required_and_default_methods() {
+    let (program, diags) = parse(
+        "trait Show {\n    func show(self) -> String\n    func shout(self, n: Int) -> String { return self.show(); }\n}",
+    );
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let Some(Stmt::TraitDecl { name, methods }) = program.first() else {
+        panic!("expected trait declaration, got {:?}", program);
+    };
+    assert_eq!(name.lexeme, "Show");
+    let shape: Vec<_> = methods
+        .iter()
+        .map(|m| {
+            (
+                m.name.lexeme.as_str(),
+                m.params.len(),
+                m.return_type.as_deref(),
+                m.body.is_some(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        shape,
+        vec![
+            ("show", 1, Some("String"), false),
+            ("shout", 2, Some("String"), true)
+        ]
+    );
+}
+
+#[test]
+fn impl_for_records_trait_and_method_owner() {
+    let (program, diags) = parse("impl Show for Point { func show(self) { return \"p\"; } }");
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let Some(Stmt::ImplBlock {
+        type_name,
+        trait_name,
+        methods,
+    }) = program.first()
+    else {
+        panic!("expected impl block, got {:?}", program);
+    };
+    assert_eq!(type_name, "Point");
+    assert_eq!(trait_name.as_ref().map(|t| t.lexeme.as_str()), Some("Show"));
+    assert!(matches!(
+        methods.as_slice(),
+        [Stmt::Function { method_owner: Some(owner), .. }] if owner == "Point"
+    ));
+
+    let (program, _) = parse("impl Point { }");
+    assert!(matches!(
+        program.first(),
+        Some(Stmt::ImplBlock {
+            trait_name: None,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn trait_body_only_accepts_methods() {
+    let (_, diags) = parse("trait Show { let x = 1; }");
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.message == "Expect 'func' in trait body."),
+        "{:?}",
+        diags
+    );
+}
@@ -0,0 +1,151 @@
+use core::ast::{Expr, Stmt, TemplateNode};
+use lexer::lexer::Lexer;
+use parser::parser::Parser;
+
+fn parse(src: &str) -> (Vec<Stmt>, Vec<diagnostics::Diagnostic>) {
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    Parser::new(tokens).parse()
+}
+
+#[test]
+fn parses_type_aliases_and_newtypes() {
+    let (program, diags) =
+        parse("type UserId = Int\ntype Row = (UserId, [String]);\nnewtype Meters(Float)");
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    assert!(
+        matches!(&program[0], Stmt::TypeAlias { name, target } if name.lexeme == "UserId" && target == "Int")
+    );
+    assert!(
+        matches!(&program[1], Stmt::TypeAlias { target, .. } if target == "(UserId, [String])"),
+        "{:?}",
+        program[1]
+    );
+    assert!(
+        matches!(&program[2], Stmt::Newtype { name, inner } if name.lexeme == "Meters" && inner == "Float")
+    );
+}
+
+#[test]
+fn newtype_requires_parenthesized_representation() {
+    let (_, diags) = parse("newtype Meters = Float");
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.message.contains("Expect '(' after newtype name")),
+        "{:?}",
+        diags
+    );
+}
+
+#[test]
+fn type_is_still_an_html_attribute() {
+    let (program, diags) = parse(r#"let v = <input type="text" />;"#);
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let Stmt::Let {
+        initializer: Expr::Template(nodes),
+        ..
+    } = &program[0]
+    else {
+        panic!("expected template, got {:?}", program[0]);
+    };
+    assert!(
+        matches!(&nodes[0], TemplateNode::Element { attrs, .. } if attrs[0].name == "type"),
+        "{:?}",
+        nodes
+    );
+}
+
+#[test]
+fn parses_struct_defaults_update_syntax_and_tuple_structs() {
+    let (program, diags) = parse(
+        "struct Config { host: String = \"localhost\", port: Int }\n\
+         struct Rgb(Int, Int, Int)\n\
+         let c = Config { port: 1, ..base };\nlet g = c.0.1;",
+    );
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    assert!(matches!(
+        &program[0],
+        Stmt::StructDecl { fields, tuple: false, .. }
+            if fields[0].default.is_some() && fields[1].default.is_none()
+    ));
+    assert!(matches!(
+        &program[1],
+        Stmt::StructDecl { fields, tuple: true, .. }
+            if fields.iter().map(|f| f.name.lexeme.as_str()).eq(["0", "1", "2"])
+    ));
+    assert!(matches!(
+        &program[2],
+        Stmt::Let { initializer: Expr::StructInit { fields, base: Some(_), .. }, .. } if fields.len() == 1
+    ));
+    assert!(matches!(
+        &program[3],
+        Stmt::Let { initializer: Expr::FieldAccess { object, field }, .. }
+            if field.lexeme == "1"
+                && matches!(&**object, Expr::FieldAccess { field, .. } if field.lexeme == "0")
+    ));
+}
+
+#[test]
+fn update_base_must_close_the_initializer() {
+    let (_, diags) = parse("let c = Config { ..base, port: 1 };");
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.message.contains("'..base' must be the last item")),
+        "{:?}",
+        diags
+    );
+}
+
+#[test]
+fn parses_derive_annotations_on_structs_and_enums() {
+    let (program, diags) = parse(
+        "@derive(Eq, Hash)\nstruct Point { x: Int, y: Int }\n\
+         @derive(Eq, Ord, Debug)\npub enum Shape { Dot, Circle(Float) }",
+    );
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    assert!(matches!(
+        &program[0],
+        Stmt::StructDecl { derives, .. }
+            if derives.iter().map(|d| d.lexeme.as_str()).eq(["Eq", "Hash"])
+    ));
+    assert!(matches!(
+        &program[1],
+        Stmt::EnumDecl { derives, public: true, .. } if derives.len() == 3
+    ));
+}
+
+#[test]
+fn derive_rejects_unknown_names_and_missing_eq() {
+    for (src, message) in [
+        (
+            "@derive(Copy)\nstruct P { x: Int }",
+            "Unknown derive 'Copy'",
+        ),
+        (
+            "@derive(Hash)\nstruct P { x: Int }",
+            "derive(Hash) requires derive(Eq) too",
+        ),
+        (
+            "@derive(Eq, Eq)\nstruct P { x: Int }",
+            "Duplicate derive 'Eq'",
+        ),
+        (
+            "@derive(Eq)\nfunc f() {}",
+            "Expect 'struct' or 'enum' after '@derive(...)'",
+        ),
+        (
+            "@inline\nstruct P { x: Int }",
+            "Unknown annotation '@inline'",
+        ),
+    ] {
+        let (_, diags) = parse(src);
+        assert!(
+            diags.iter().any(|d| d.message.contains(message)),
+            "{}: {:?}",
+            src,
+            diags
+        );
+    }
+}
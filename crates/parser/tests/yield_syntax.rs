@@ -3,7 +3,7 @@ use lexer::lexer::Lexer;
 use parser::parser::Parser;
 
 #[test]
-fn parses_yield_as_return_option_some() {
+fn parses_yield_statement_and_marks_generator_body() {
     let src = "func gen() { yield 7; return Option.None; }";
     let mut lx = Lexer::new(src.to_string());
     let tokens = lx.scan_tokens().expect("lex ok");
@@ -12,25 +12,42 @@ fn parses_yield_as_return_option_some() {
     assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
 
     match &program[0] {
-        Stmt::Function { body, .. } => match body.as_ref() {
-            Stmt::Block { statements } => match &statements[0] {
-                Stmt::Return {
-                    value:
-                        Some(Expr::EnumInit {
-                            name: Some(name),
-                            variant,
-                            values,
-                        }),
-                } => {
-                    assert_eq!(name.lexeme, "Option");
-                    assert_eq!(variant.lexeme, "Some");
-                    assert_eq!(values.len(), 1);
-                    assert_eq!(values[0], Expr::Literal(ArtValue::Int(7)));
-                }
-                other => panic!("expected desugared return Option.Some, got {:?}", other),
-            },
-            other => panic!("expected function block, got {:?}", other),
-        },
+        Stmt::Function { body, .. } => {
+            assert!(body.contains_yield());
+            match body.as_ref() {
+                Stmt::Block { statements } => match &statements[0] {
+                    Stmt::Yield { keyword, value } => {
+                        assert_eq!(keyword.lexeme, "yield");
+                        assert_eq!(*value, Expr::Literal(ArtValue::Int(7)));
+                    }
+                    other => panic!("expected yield statement, got {:?}", other),
+                },
+                other => panic!("expected function block, got {:?}", other),
+            }
+        }
         other => panic!("expected function declaration, got {:?}", other),
     }
 }
+
+#[test]
+fn yield_in_nested_function_does_not_make_outer_a_generator() {
+    let src = "func outer() { func inner() { yield 1; } while true { if x { yield 2; } } }\nfunc plain() { return 1; }";
+    let mut lx = Lexer::new(src.to_string());
+    let tokens = lx.scan_tokens().expect("lex ok");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+
+    let bodies: Vec<_> = program
+        .iter()
+        .map(|s| match s {
+            Stmt::Function { body, .. } => body.clone(),
+            other => panic!("expected function, got {:?}", other),
+        })
+        .collect();
+    assert!(bodies[0].contains_yield(), "yield inside while/if counts");
+    assert!(!bodies[1].contains_yield());
+    match bodies[0].as_ref() {
+        Stmt::Block { statements } => assert!(!statements[0].contains_yield()),
+        other => panic!("expected block, got {:?}", other),
+    }
+}
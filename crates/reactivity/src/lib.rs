@@ -337,10 +337,16 @@ fn collect_expr_refs(expr: &Expr) -> Vec<String> {
 fn collect_expr_refs_into(expr: &Expr, out: &mut Vec<String>) {
     match expr {
         Expr::Variable { name } => out.push(name.lexeme.clone()),
-        Expr::Binary { left, right, .. } | Expr::Logical { left, right, .. } => {
+        Expr::Binary { left, right, .. }
+        | Expr::Logical { left, right, .. }
+        | Expr::Coalesce { left, right, .. } => {
             collect_expr_refs_into(left, out);
             collect_expr_refs_into(right, out);
         }
+        Expr::Range { start, end, .. } => {
+            collect_expr_refs_into(start, out);
+            collect_expr_refs_into(end, out);
+        }
         Expr::Unary { right, .. } => collect_expr_refs_into(right, out),
         Expr::Grouping { expression } => collect_expr_refs_into(expression, out),
         Expr::Call {
@@ -352,6 +358,26 @@ fn collect_expr_refs_into(expr: &Expr, out: &mut Vec<String>) {
             }
         }
         Expr::FieldAccess { object, .. } => collect_expr_refs_into(object, out),
+        Expr::OptionalChain {
+            object, arguments, ..
+        } => {
+            collect_expr_refs_into(object, out);
+            for a in arguments.iter().flatten() {
+                collect_expr_refs_into(a, out);
+            }
+        }
+        Expr::Index { object, index, .. } => {
+            collect_expr_refs_into(object, out);
+            collect_expr_refs_into(index, out);
+        }
+        Expr::Slice {
+            object, start, end, ..
+        } => {
+            collect_expr_refs_into(object, out);
+            for bound in [start, end].into_iter().flatten() {
+                collect_expr_refs_into(bound, out);
+            }
+        }
         Expr::Array(elems) | Expr::Tuple(elems) => {
             for e in elems {
                 collect_expr_refs_into(e, out);
@@ -362,13 +388,17 @@ fn collect_expr_refs_into(expr: &Expr, out: &mut Vec<String>) {
         | Expr::Weak(e)
         | Expr::Unowned(e)
         | Expr::WeakUpgrade(e)
-        | Expr::UnownedAccess(e) => {
+        | Expr::UnownedAccess(e)
+        | Expr::Await { value: e, .. } => {
             collect_expr_refs_into(e, out);
         }
-        Expr::StructInit { fields, .. } => {
+        Expr::StructInit { fields, base, .. } => {
             for (_, e) in fields {
                 collect_expr_refs_into(e, out);
             }
+            if let Some(b) = base {
+                collect_expr_refs_into(b, out);
+            }
         }
         Expr::InterpolatedString(parts) => {
             use core::ast::InterpolatedPart;
@@ -381,6 +411,9 @@ fn collect_expr_refs_into(expr: &Expr, out: &mut Vec<String>) {
         Expr::Template(nodes) => {
             out.extend(collect_template_refs(nodes));
         }
+        // The body only runs when the lambda is called, so reading a state
+        // there is not a dependency of the expression that builds it.
+        Expr::Lambda { .. } => {}
         Expr::SpawnActor { .. } | Expr::Literal(_) | Expr::EnumInit { .. } => {}
     }
 }
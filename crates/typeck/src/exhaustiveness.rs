@@ -0,0 +1,374 @@
+//! Exhaustiveness and reachability of `match` arms.
+//!
+//! Patterns are lowered to a small constructor language (enum variants,
+//! `true`/`false`, tuples) and checked with the usefulness algorithm from
+//! Maranget, "Warnings for pattern matching": an arm is unreachable when it is
+//! not useful against the unguarded arms above it, and the match is
+//! non-exhaustive when a wildcard is still useful after every unguarded arm.
+//!
+//! Literals of infinite types (Int, Float, String), ranges, struct and array
+//! patterns become opaque constructors: they only ever cover themselves, so a
+//! match over them needs a catch-all. Missing arms are only reported when the
+//! scrutinee is built from enums or `Bool`; a `match` over plain Ints is left
+//! alone.
+
+use core::ast::{ArtValue, MatchPattern};
+use std::collections::HashMap;
+
+/// Enum name -> `(variant, arity)` in declaration order.
+pub(crate) type EnumTable = HashMap<String, Vec<(String, usize)>>;
+
+/// Witness lists are truncated to this many entries at every level so that
+/// wide tuples of enums cannot blow up the search.
+const MAX_WITNESSES: usize = 16;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Ctor {
+    Variant {
+        enum_name: String,
+        name: String,
+    },
+    Bool(bool),
+    Tuple(usize),
+    /// Literal, range, struct or array pattern; equal only to the same key.
+    Opaque(String),
+}
+
+#[derive(Clone, Debug)]
+enum Pat {
+    Wild,
+    Ctor(Ctor, Vec<Pat>),
+    Or(Vec<Pat>),
+}
+
+/// Result of analysing one `match`.
+pub(crate) struct MatchReport {
+    /// Example patterns not covered by any unguarded arm, rendered as source.
+    pub missing: Vec<String>,
+    /// Indexes of arms that earlier unguarded arms already cover.
+    pub unreachable: Vec<usize>,
+}
+
+struct Lowering<'a> {
+    enums: &'a EnumTable,
+    next_opaque: usize,
+    /// Some variant could not be tied to a single known enum.
+    unresolved: bool,
+    /// Some pattern names an enum variant or a `Bool`.
+    enumerable: bool,
+}
+
+impl Lowering<'_> {
+    fn opaque(&mut self) -> Pat {
+        self.next_opaque += 1;
+        Pat::Ctor(Ctor::Opaque(format!("#{}", self.next_opaque)), vec![])
+    }
+
+    fn lower(&mut self, pattern: &MatchPattern) -> Pat {
+        match pattern {
+            MatchPattern::Wildcard | MatchPattern::Variable(_) | MatchPattern::Binding(_) => {
+                Pat::Wild
+            }
+            MatchPattern::Literal(ArtValue::Bool(b)) => {
+                self.enumerable = true;
+                Pat::Ctor(Ctor::Bool(*b), vec![])
+            }
+            MatchPattern::Literal(v @ (ArtValue::Int(_) | ArtValue::String(_))) => {
+                Pat::Ctor(Ctor::Opaque(format!("{:?}", v)), vec![])
+            }
+            MatchPattern::Literal(_) | MatchPattern::Range { .. } => self.opaque(),
+            MatchPattern::Tuple(items) => Pat::Ctor(
+                Ctor::Tuple(items.len()),
+                items.iter().map(|p| self.lower(p)).collect(),
+            ),
+            MatchPattern::Or(alts) => Pat::Or(alts.iter().map(|p| self.lower(p)).collect()),
+            MatchPattern::At { pattern, .. } => self.lower(pattern),
+            // A struct or array pattern that cannot fail covers every value of
+            // its shape; anything else is treated as opaque.
+            MatchPattern::Struct { fields, .. } => {
+                let parts: Vec<Pat> = fields.iter().map(|(_, p)| self.lower(p)).collect();
+                if parts.iter().all(|p| matches!(p, Pat::Wild)) {
+                    Pat::Wild
+                } else {
+                    self.opaque()
+                }
+            }
+            MatchPattern::Array {
+                prefix,
+                rest: Some(_),
+                suffix,
+            } if prefix.is_empty() && suffix.is_empty() => Pat::Wild,
+            MatchPattern::Array { .. } => self.opaque(),
+            MatchPattern::EnumVariant {
+                enum_name,
+                variant,
+                params,
+            } => {
+                let found = match enum_name {
+                    Some(e) => self.enums.get(&e.lexeme).and_then(|vs| {
+                        vs.iter()
+                            .find(|(v, _)| *v == variant.lexeme)
+                            .map(|(_, arity)| (e.lexeme.clone(), *arity))
+                    }),
+                    None => {
+                        let mut owners = self.enums.iter().filter_map(|(e, vs)| {
+                            vs.iter()
+                                .find(|(v, _)| *v == variant.lexeme)
+                                .map(|(_, arity)| (e.clone(), *arity))
+                        });
+                        match (owners.next(), owners.next()) {
+                            (Some(owner), None) => Some(owner),
+                            _ => None,
+                        }
+                    }
+                };
+                let Some((enum_name, arity)) = found else {
+                    self.unresolved = true;
+                    return self.opaque();
+                };
+                let args: Vec<Pat> = params.iter().flatten().map(|p| self.lower(p)).collect();
+                // Wrong arity never matches at runtime (and is reported elsewhere).
+                if args.len() != arity {
+                    return self.opaque();
+                }
+                self.enumerable = true;
+                Pat::Ctor(
+                    Ctor::Variant {
+                        enum_name,
+                        name: variant.lexeme.clone(),
+                    },
+                    args,
+                )
+            }
+        }
+    }
+}
+
+type Row = Vec<Pat>;
+
+fn head_ctors(rows: &[Row]) -> Vec<Ctor> {
+    fn collect(p: &Pat, out: &mut Vec<Ctor>) {
+        match p {
+            Pat::Wild => {}
+            Pat::Ctor(c, _) => {
+                if !out.contains(c) {
+                    out.push(c.clone());
+                }
+            }
+            Pat::Or(alts) => alts.iter().for_each(|a| collect(a, out)),
+        }
+    }
+    let mut out = Vec::new();
+    for row in rows {
+        collect(&row[0], &mut out);
+    }
+    out
+}
+
+/// Every constructor of the type the head constructors belong to, with its
+/// arity; `None` for types with infinitely many values.
+fn signature(enums: &EnumTable, heads: &[Ctor]) -> Option<Vec<(Ctor, usize)>> {
+    let typed = heads.iter().find(|c| !matches!(c, Ctor::Opaque(_)))?;
+    match typed {
+        Ctor::Variant { enum_name, .. } => Some(
+            enums
+                .get(enum_name)?
+                .iter()
+                .map(|(name, arity)| {
+                    (
+                        Ctor::Variant {
+                            enum_name: enum_name.clone(),
+                            name: name.clone(),
+                        },
+                        *arity,
+                    )
+                })
+                .collect(),
+        ),
+        Ctor::Bool(_) => Some(vec![(Ctor::Bool(false), 0), (Ctor::Bool(true), 0)]),
+        Ctor::Tuple(n) => Some(vec![(Ctor::Tuple(*n), *n)]),
+        Ctor::Opaque(_) => None,
+    }
+}
+
+/// Rows whose head can match `ctor`, with the head replaced by its arguments.
+fn specialize(rows: &[Row], ctor: &Ctor, arity: usize) -> Vec<Row> {
+    fn push(head: &Pat, rest: &[Pat], ctor: &Ctor, arity: usize, out: &mut Vec<Row>) {
+        match head {
+            Pat::Wild => {
+                let mut row = vec![Pat::Wild; arity];
+                row.extend_from_slice(rest);
+                out.push(row);
+            }
+            Pat::Ctor(c, args) if c == ctor => {
+                let mut row = args.clone();
+                row.extend_from_slice(rest);
+                out.push(row);
+            }
+            Pat::Ctor(..) => {}
+            Pat::Or(alts) => alts.iter().for_each(|a| push(a, rest, ctor, arity, out)),
+        }
+    }
+    let mut out = Vec::new();
+    for row in rows {
+        push(&row[0], &row[1..], ctor, arity, &mut out);
+    }
+    out
+}
+
+/// Rows whose head is a wildcard, without that head.
+fn default_rows(rows: &[Row]) -> Vec<Row> {
+    fn push(head: &Pat, rest: &[Pat], out: &mut Vec<Row>) {
+        match head {
+            Pat::Wild => out.push(rest.to_vec()),
+            Pat::Ctor(..) => {}
+            Pat::Or(alts) => alts.iter().for_each(|a| push(a, rest, out)),
+        }
+    }
+    let mut out = Vec::new();
+    for row in rows {
+        push(&row[0], &row[1..], &mut out);
+    }
+    out
+}
+
+/// Is there a value matched by `q` and by none of `rows`?
+fn useful(enums: &EnumTable, rows: &[Row], q: &[Pat]) -> bool {
+    let Some(head) = q.first() else {
+        return rows.is_empty();
+    };
+    match head {
+        Pat::Or(alts) => alts.iter().any(|alt| {
+            let mut q2 = vec![alt.clone()];
+            q2.extend_from_slice(&q[1..]);
+            useful(enums, rows, &q2)
+        }),
+        Pat::Ctor(ctor, args) => {
+            let mut q2 = args.clone();
+            q2.extend_from_slice(&q[1..]);
+            useful(enums, &specialize(rows, ctor, args.len()), &q2)
+        }
+        Pat::Wild => {
+            let heads = head_ctors(rows);
+            match signature(enums, &heads) {
+                Some(all) if all.iter().all(|(c, _)| heads.contains(c)) => {
+                    all.iter().any(|(ctor, arity)| {
+                        let mut q2 = vec![Pat::Wild; *arity];
+                        q2.extend_from_slice(&q[1..]);
+                        useful(enums, &specialize(rows, ctor, *arity), &q2)
+                    })
+                }
+                _ => useful(enums, &default_rows(rows), &q[1..]),
+            }
+        }
+    }
+}
+
+/// Value vectors of width `width` that no row matches (the matrix `rows`
+/// has `width` columns).
+fn witnesses(enums: &EnumTable, rows: &[Row], width: usize) -> Vec<Row> {
+    if width == 0 {
+        return if rows.is_empty() {
+            vec![vec![]]
+        } else {
+            vec![]
+        };
+    }
+    let heads = head_ctors(rows);
+    let mut out = Vec::new();
+    let Some(all) = signature(enums, &heads) else {
+        // No constructor at all, or an infinite type: only `_` can be missing.
+        for tail in witnesses(enums, &default_rows(rows), width - 1) {
+            let mut row = vec![Pat::Wild];
+            row.extend(tail);
+            out.push(row);
+        }
+        out.truncate(MAX_WITNESSES);
+        return out;
+    };
+    // Finite type: look inside the constructors some arm names, and report
+    // the ones no arm names with wildcard arguments.
+    let mut tails: Option<Vec<Row>> = None;
+    for (ctor, arity) in &all {
+        if heads.contains(ctor) {
+            let sub = specialize(rows, ctor, *arity);
+            for mut w in witnesses(enums, &sub, arity + width - 1) {
+                let rest = w.split_off(*arity);
+                let mut row = vec![Pat::Ctor(ctor.clone(), w)];
+                row.extend(rest);
+                out.push(row);
+            }
+        } else {
+            let tails =
+                tails.get_or_insert_with(|| witnesses(enums, &default_rows(rows), width - 1));
+            for tail in tails.iter() {
+                let mut row = vec![Pat::Ctor(ctor.clone(), vec![Pat::Wild; *arity])];
+                row.extend_from_slice(tail);
+                out.push(row);
+            }
+        }
+    }
+    out.truncate(MAX_WITNESSES);
+    out
+}
+
+fn render(p: &Pat) -> String {
+    match p {
+        Pat::Wild => "_".to_string(),
+        Pat::Or(alts) => alts.iter().map(render).collect::<Vec<_>>().join(" | "),
+        Pat::Ctor(Ctor::Bool(b), _) => b.to_string(),
+        Pat::Ctor(Ctor::Tuple(_), args) => {
+            format!(
+                "({})",
+                args.iter().map(render).collect::<Vec<_>>().join(", ")
+            )
+        }
+        Pat::Ctor(Ctor::Variant { name, .. }, args) if args.is_empty() => format!(".{}", name),
+        Pat::Ctor(Ctor::Variant { name, .. }, args) => format!(
+            ".{}({})",
+            name,
+            args.iter().map(render).collect::<Vec<_>>().join(", ")
+        ),
+        Pat::Ctor(Ctor::Opaque(_), _) => "_".to_string(),
+    }
+}
+
+/// Analyse the arms of one `match`. `arms` holds each pattern and whether it
+/// has a guard; guarded arms never count towards covering later arms or the
+/// whole match.
+pub(crate) fn check_match(enums: &EnumTable, arms: &[(&MatchPattern, bool)]) -> MatchReport {
+    let mut lowering = Lowering {
+        enums,
+        next_opaque: 0,
+        unresolved: false,
+        enumerable: false,
+    };
+    let lowered: Vec<(Pat, bool)> = arms
+        .iter()
+        .map(|(p, guarded)| (lowering.lower(p), *guarded))
+        .collect();
+
+    let mut rows: Vec<Row> = Vec::new();
+    let mut unreachable = Vec::new();
+    for (i, (pat, guarded)) in lowered.into_iter().enumerate() {
+        if !useful(enums, &rows, std::slice::from_ref(&pat)) {
+            unreachable.push(i);
+        }
+        if !guarded {
+            rows.push(vec![pat]);
+        }
+    }
+
+    let missing = if lowering.enumerable && !lowering.unresolved {
+        witnesses(enums, &rows, 1)
+            .iter()
+            .map(|w| render(&w[0]))
+            .collect()
+    } else {
+        Vec::new()
+    };
+    MatchReport {
+        missing,
+        unreachable,
+    }
+}
@@ -15,10 +15,17 @@
 //! keeping them in one crate; until that lands the two entry points stay
 //! separate and callers run both.
 
+mod exhaustiveness;
 pub mod type_infer;
 
-use core::ast::{ArtValue, Expr, MatchPattern, Stmt, TemplateAttrValue, TemplateNode};
-use core::types::Type;
+use core::ast::{
+    ArtValue, Expr, FunctionParam, MatchPattern, Stmt, TemplateAttrValue, TemplateNode,
+};
+use core::const_eval::{ConstEnv, eval_const};
+use core::methods;
+use core::operators::{NEG_METHOD, OPERATOR_METHODS, TO_STRING_METHOD, binary_method};
+use core::types::{Type, TypeDecls};
+use core::{Token, TokenType};
 use diagnostics::{Diagnostic, DiagnosticKind, Span};
 use std::collections::HashMap;
 
@@ -30,9 +37,28 @@ use std::collections::HashMap;
 /// - Parametric type inference (`func f<T>(x: T)` → T inferred from arguments)
 pub struct TypeChecker {
     functions: HashMap<String, FuncSig>,
+    /// Declared enums (plus the prelude `Option`/`Result`), for `match` exhaustiveness.
+    enums: exhaustiveness::EnumTable,
+    /// Values of the `const`s folded so far, in declaration order.
+    consts: ConstEnv,
+    /// `type` and `newtype` declarations, minus the ones that refer to themselves.
+    types: TypeDecls,
+    /// Operator methods each type defines in an `impl`, with their return types.
+    operators: HashMap<String, HashMap<String, Type>>,
+    /// Declared structs, for field types, defaults and tuple-struct calls.
+    structs: HashMap<String, StructSig>,
+    /// Names in each struct's or enum's `@derive(...)`.
+    derives: HashMap<String, Vec<String>>,
     pub diagnostics: Vec<Diagnostic>,
 }
 
+#[derive(Clone)]
+struct StructSig {
+    /// `(name, type, has_default)` in declaration order; tuple structs use `0`, `1`, ...
+    fields: Vec<(String, Type, bool)>,
+    tuple: bool,
+}
+
 #[derive(Clone)]
 struct FuncSig {
     params: Vec<(String, Type)>,
@@ -40,8 +66,22 @@ struct FuncSig {
     type_params: Vec<(String, Option<String>)>,
 }
 
+/// How a name was introduced; decides whether `name = ...` is accepted.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum BindingKind {
+    /// `let`, parameters, loop variables, pattern bindings.
+    Immutable,
+    /// `var`, component `state` and `ref`.
+    Mutable,
+    /// Component `prop`: owned by the parent, read-only inside the component.
+    Prop,
+    /// Top-level `const`: read-only and never shadowed.
+    Const,
+}
+
+#[derive(Clone)]
 struct Env {
-    scopes: Vec<HashMap<String, Type>>,
+    scopes: Vec<HashMap<String, (Type, BindingKind)>>,
 }
 
 impl Env {
@@ -62,14 +102,25 @@ impl Env {
     }
 
     fn set(&mut self, name: &str, ty: Type) {
+        self.set_with_kind(name, ty, BindingKind::Immutable);
+    }
+
+    fn set_with_kind(&mut self, name: &str, ty: Type, kind: BindingKind) {
         if let Some(scope) = self.scopes.last_mut() {
-            scope.insert(name.to_string(), ty);
+            scope.insert(name.to_string(), (ty, kind));
         }
     }
 
+    fn kind_of(&self, name: &str) -> Option<BindingKind> {
+        self.scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(name).map(|(_, k)| *k))
+    }
+
     fn get(&self, name: &str) -> Option<&Type> {
         for scope in self.scopes.iter().rev() {
-            if let Some(t) = scope.get(name) {
+            if let Some((t, _)) = scope.get(name) {
                 return Some(t);
             }
         }
@@ -85,8 +136,23 @@ impl Default for TypeChecker {
 
 impl TypeChecker {
     pub fn new() -> Self {
+        let mut enums = exhaustiveness::EnumTable::new();
+        enums.insert(
+            "Option".to_string(),
+            vec![("Some".to_string(), 1), ("None".to_string(), 0)],
+        );
+        enums.insert(
+            "Result".to_string(),
+            vec![("Ok".to_string(), 1), ("Err".to_string(), 1)],
+        );
         Self {
             functions: HashMap::new(),
+            enums,
+            consts: ConstEnv::new(),
+            types: TypeDecls::default(),
+            operators: HashMap::new(),
+            structs: HashMap::new(),
+            derives: HashMap::new(),
             diagnostics: Vec::new(),
         }
     }
@@ -94,15 +160,41 @@ impl TypeChecker {
     /// Run type checking over a program. Returns slice of collected diagnostics.
     pub fn check(&mut self, program: &[Stmt]) -> &[Diagnostic] {
         let mut env = Env::new();
+        self.collect_types(program);
         for stmt in program {
             self.collect_decl(stmt, &mut env);
         }
+        self.reject_duplicate_consts(program);
         for stmt in program {
             self.check_stmt(stmt, &mut env);
         }
         &self.diagnostics
     }
 
+    /// Registers `type`/`newtype` before any annotation is parsed. A declaration
+    /// that reaches itself is reported and dropped, so `parse_type` terminates.
+    fn collect_types(&mut self, program: &[Stmt]) {
+        self.types = TypeDecls::collect(program);
+        for stmt in program {
+            let (Stmt::TypeAlias { name, .. } | Stmt::Newtype { name, .. }) = stmt else {
+                continue;
+            };
+            if self.types.is_recursive(&name.lexeme) {
+                self.diagnostics.push(
+                    Diagnostic::new(
+                        DiagnosticKind::Type,
+                        format!("type '{}' refers to itself", name.lexeme),
+                        Span::new(name.start, name.end, name.line, name.col),
+                    )
+                    .note("use a struct or enum for recursive types"),
+                );
+            }
+        }
+        let types = self.types.clone();
+        self.types.aliases.retain(|n, _| !types.is_recursive(n));
+        self.types.newtypes.retain(|n, _| !types.is_recursive(n));
+    }
+
     /// First pass: register all top-level function signatures so call sites can
     /// be verified even when a function is called before its declaration.
     fn collect_decl(&mut self, stmt: &Stmt, env: &mut Env) {
@@ -112,6 +204,8 @@ impl TypeChecker {
                 params,
                 return_type,
                 type_params,
+                body,
+                is_async,
                 ..
             } => {
                 let param_types: Vec<(String, Type)> = params
@@ -124,8 +218,11 @@ impl TypeChecker {
                         (p.name.lexeme.clone(), ty)
                     })
                     .collect();
+                // Chamar um generator devolve o objeto generator, e uma `async func`
+                // devolve uma task, não o tipo declarado.
                 let ret = return_type
                     .as_deref()
+                    .filter(|_| !*is_async && !body.contains_yield())
                     .map(|s| self.parse_type(s))
                     .unwrap_or(Type::Unknown);
                 let tparams = type_params.clone().unwrap_or_default();
@@ -145,21 +242,310 @@ impl TypeChecker {
                     ),
                 );
             }
-            Stmt::ImplBlock { methods, .. } => {
+            Stmt::ImplBlock {
+                type_name, methods, ..
+            } => {
                 for m in methods {
                     self.collect_decl(m, env);
+                    if let Stmt::Function {
+                        name, return_type, ..
+                    } = m
+                        && OPERATOR_METHODS.contains(&name.lexeme.as_str())
+                    {
+                        let ret = return_type
+                            .as_deref()
+                            .map(|s| self.parse_type(s))
+                            .unwrap_or(Type::Unknown);
+                        self.operators
+                            .entry(type_name.clone())
+                            .or_default()
+                            .insert(name.lexeme.clone(), ret);
+                    }
                 }
             }
+            Stmt::StructDecl {
+                name,
+                fields,
+                tuple,
+                derives,
+                ..
+            } => {
+                self.record_derives(name, derives);
+                let fields = fields
+                    .iter()
+                    .map(|f| {
+                        (
+                            f.name.lexeme.clone(),
+                            self.parse_type(&f.ty),
+                            f.default.is_some(),
+                        )
+                    })
+                    .collect();
+                self.structs.insert(
+                    name.lexeme.clone(),
+                    StructSig {
+                        fields,
+                        tuple: *tuple,
+                    },
+                );
+            }
+            Stmt::EnumDecl {
+                name,
+                variants,
+                derives,
+                ..
+            } => {
+                self.record_derives(name, derives);
+                let variants = variants
+                    .iter()
+                    .map(|(v, params)| (v.lexeme.clone(), params.as_ref().map_or(0, Vec::len)))
+                    .collect();
+                self.enums.insert(name.lexeme.clone(), variants);
+            }
+            Stmt::Const { name, ty, .. } => {
+                let ty = self.parse_type(ty);
+                env.set_with_kind(&name.lexeme, ty, BindingKind::Const);
+            }
             Stmt::ComponentBlock { .. } | Stmt::QualifiedBinding { .. } => {}
             _ => {}
         }
     }
 
+    fn record_derives(&mut self, name: &Token, derives: &[Token]) {
+        if !derives.is_empty() {
+            self.derives.insert(
+                name.lexeme.clone(),
+                derives.iter().map(|d| d.lexeme.clone()).collect(),
+            );
+        }
+    }
+
+    /// Name of a struct or enum declared by the program (not `Option`/`Result`).
+    fn user_type_name<'t>(&self, ty: &'t Type) -> Option<&'t str> {
+        let (Type::Struct(name) | Type::Enum(name) | Type::EnumInstance(name, _)) = ty else {
+            return None;
+        };
+        let declared = self.structs.contains_key(name)
+            || (self.enums.contains_key(name) && name != "Option" && name != "Result");
+        declared.then_some(name.as_str())
+    }
+
+    fn type_derives(&self, name: &str, derive: &str) -> bool {
+        self.derives
+            .get(name)
+            .is_some_and(|ds| ds.iter().any(|d| d == derive))
+    }
+
+    /// The declared type inside `ty` that lacks `derive`, if any.
+    fn missing_derive(&self, ty: &Type, derive: &str) -> Option<String> {
+        match ty {
+            Type::Array(inner) => self.missing_derive(inner, derive),
+            Type::Tuple(items) => items.iter().find_map(|t| self.missing_derive(t, derive)),
+            Type::EnumInstance(_, params) if self.user_type_name(ty).is_none() => {
+                params.iter().find_map(|t| self.missing_derive(t, derive))
+            }
+            _ => {
+                let name = self.user_type_name(ty)?;
+                (!self.type_derives(name, derive)).then(|| name.to_string())
+            }
+        }
+    }
+
+    /// Every field (or variant payload) of a type with `@derive(X)` must have a
+    /// type that derives `X` too.
+    fn check_derived_members(&mut self, derives: &[Token], members: &[(String, Type)]) {
+        for derive in derives {
+            for (label, ty) in members {
+                if let Some(missing) = self.missing_derive(ty, &derive.lexeme) {
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::Type,
+                        format!(
+                            "{} has type '{}', which does not derive {}",
+                            label, missing, derive.lexeme
+                        ),
+                        Span::new(derive.start, derive.end, derive.line, derive.col),
+                    ));
+                }
+            }
+        }
+    }
+
+    /// `a < b` and `xs.sort()` over instances need `@derive(Ord)` or `cmp`.
+    fn require_ordered(&mut self, ty: &Type, at: &Token) {
+        let Some(name) = self.user_type_name(ty) else {
+            return;
+        };
+        if self.type_derives(name, "Ord") || self.operator_return(ty, "cmp").is_some() {
+            return;
+        }
+        self.diagnostics.push(Diagnostic::new(
+            DiagnosticKind::Type,
+            format!(
+                "type '{}' is not ordered: add @derive(Ord) or define 'cmp'",
+                name
+            ),
+            Span::new(at.start, at.end, at.line, at.col),
+        ));
+    }
+
+    /// An `impl` method named after an operator must fit the call the operator
+    /// makes: `a + b` runs `a.add(b)`, `a < b` compares `a.cmp(b)` with 0.
+    fn check_operator_method(&mut self, type_name: &str, method: &Stmt) {
+        let Stmt::Function {
+            name,
+            params,
+            return_type,
+            ..
+        } = method
+        else {
+            return;
+        };
+        let (arity, returns, usage) = match name.lexeme.as_str() {
+            "eq" => (1, Some(Type::Bool), "`a == b` calls `a.eq(b)`"),
+            "cmp" => (
+                1,
+                Some(Type::Int),
+                "`a < b` calls `a.cmp(b)` and compares the result with 0",
+            ),
+            NEG_METHOD => (0, None, "`-a` calls `a.neg()`"),
+            TO_STRING_METHOD => (0, Some(Type::String), "`f\"{a}\"` calls `a.to_string()`"),
+            "add" => (1, None, "`a + b` calls `a.add(b)`"),
+            "sub" => (1, None, "`a - b` calls `a.sub(b)`"),
+            "mul" => (1, None, "`a * b` calls `a.mul(b)`"),
+            "div" => (1, None, "`a / b` calls `a.div(b)`"),
+            _ => return,
+        };
+        let qualified = format!("{}.{}", type_name, name.lexeme);
+        let mut problems = Vec::new();
+        if params.first().is_none_or(|p| p.name.lexeme != "self") {
+            problems.push(format!(
+                "operator method '{}' must take 'self' as its first parameter",
+                qualified
+            ));
+        } else if params.len() - 1 != arity {
+            problems.push(format!(
+                "operator method '{}' must take {} besides 'self', found {}",
+                qualified,
+                if arity == 0 {
+                    "no arguments"
+                } else {
+                    "one argument"
+                },
+                params.len() - 1
+            ));
+        }
+        if let (Some(expected), Some(declared)) = (returns, return_type.as_deref()) {
+            let declared = self.parse_type(declared);
+            if !self.types_compatible(&expected, &declared) {
+                problems.push(format!(
+                    "operator method '{}' must return {}, found {}",
+                    qualified,
+                    expected.name(),
+                    declared.name()
+                ));
+            }
+        }
+        for message in problems {
+            self.diagnostics.push(
+                Diagnostic::new(
+                    DiagnosticKind::Type,
+                    message,
+                    Span::new(name.start, name.end, name.line, name.col),
+                )
+                .note(usage),
+            );
+        }
+    }
+
+    /// Return type of `method` when `ty` overloads it in an `impl`.
+    fn operator_return(&self, ty: &Type, method: &str) -> Option<Type> {
+        let (Type::Struct(name) | Type::Enum(name) | Type::EnumInstance(name, _)) = ty else {
+            return None;
+        };
+        self.operators.get(name)?.get(method).cloned()
+    }
+
+    /// Backends inline constants without tracking scopes, so no local binding
+    /// may reuse a constant's name.
+    fn reject_const_shadow(&mut self, name: &Token, env: &Env) {
+        if env.kind_of(&name.lexeme) == Some(BindingKind::Const) {
+            self.diagnostics.push(
+                Diagnostic::new(
+                    DiagnosticKind::Type,
+                    format!("'{}' shadows a constant", name.lexeme),
+                    Span::new(name.start, name.end, name.line, name.col),
+                )
+                .note("constants are inlined where they are read; rename the binding"),
+            );
+        }
+    }
+
+    /// Two top-level `const`s with the same name: a backend would keep only one
+    /// of the values.
+    fn reject_duplicate_consts(&mut self, stmts: &[Stmt]) {
+        let mut seen: HashMap<&str, &Token> = HashMap::new();
+        for stmt in stmts {
+            let Stmt::Const { name, .. } = stmt else {
+                continue;
+            };
+            if let Some(first) = seen.get(name.lexeme.as_str()) {
+                self.diagnostics.push(
+                    Diagnostic::new(
+                        DiagnosticKind::Type,
+                        format!("constant '{}' is already declared", name.lexeme),
+                        Span::new(name.start, name.end, name.line, name.col),
+                    )
+                    .note(format!("first declared at {}:{}", first.line, first.col)),
+                );
+            } else {
+                seen.insert(&name.lexeme, name);
+            }
+        }
+    }
+
+    /// `const NAME: T = value`: folds `value` and checks it against `T`.
+    fn check_const(&mut self, name: &Token, ty: &str, value: &Expr, env: &Env) {
+        match eval_const(value, &self.consts) {
+            Ok(folded) => {
+                self.consts.insert(name.lexeme.clone(), folded);
+            }
+            Err(err) => {
+                let at = err.token.as_ref().unwrap_or(name);
+                self.diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Type,
+                    format!(
+                        "'{}' is not a compile-time constant: {}",
+                        name.lexeme, err.message
+                    ),
+                    Span::new(at.start, at.end, at.line, at.col),
+                ));
+                return;
+            }
+        }
+        let declared = self.parse_type(ty);
+        let inferred = self.infer_expr(value, env);
+        if !matches!(inferred, Type::Unknown) && !self.types_compatible(&declared, &inferred) {
+            self.diagnostics.push(Diagnostic::new(
+                DiagnosticKind::Type,
+                format!(
+                    "type mismatch: constant declared as {}, value has type {}",
+                    declared.name(),
+                    inferred.name()
+                ),
+                Span::new(name.start, name.end, name.line, name.col),
+            ));
+        }
+    }
+
     fn check_stmt(&mut self, stmt: &Stmt, env: &mut Env) {
         match stmt {
+            Stmt::Const {
+                name, ty, value, ..
+            } => self.check_const(name, ty, value, env),
             Stmt::Let {
                 pattern,
                 ty,
+                mutable,
                 initializer,
             } => {
                 let inferred = self.infer_expr(initializer, env);
@@ -183,7 +569,94 @@ impl TypeChecker {
                 } else {
                     inferred
                 };
-                self.bind_pattern(pattern, &actual_ty, env);
+                let kind = if *mutable {
+                    BindingKind::Mutable
+                } else {
+                    BindingKind::Immutable
+                };
+                self.bind_pattern(pattern, &actual_ty, kind, env);
+            }
+            Stmt::Assign {
+                target,
+                operator,
+                value,
+            } => {
+                let value_ty = self.infer_expr(value, env);
+                match target {
+                    Expr::Variable { name } => {
+                        let span = Span::new(name.start, name.end, name.line, name.col);
+                        match env.kind_of(&name.lexeme) {
+                            Some(BindingKind::Immutable) => {
+                                self.diagnostics.push(
+                                    Diagnostic::new(
+                                        DiagnosticKind::Type,
+                                        format!(
+                                            "cannot assign to immutable binding '{}'",
+                                            name.lexeme
+                                        ),
+                                        span,
+                                    )
+                                    .note("declare it with `var` to allow reassignment"),
+                                );
+                            }
+                            Some(BindingKind::Prop) => {
+                                self.diagnostics.push(
+                                    Diagnostic::new(
+                                        DiagnosticKind::Type,
+                                        format!("cannot assign to prop '{}'", name.lexeme),
+                                        span,
+                                    )
+                                    .note("props are owned by the parent component; use a `state` binding for local changes"),
+                                );
+                            }
+                            Some(BindingKind::Mutable) if operator.lexeme == "=" => {
+                                let declared =
+                                    env.get(&name.lexeme).cloned().unwrap_or(Type::Unknown);
+                                if !matches!(declared, Type::Unknown)
+                                    && !matches!(value_ty, Type::Unknown)
+                                    && !self.types_compatible(&declared, &value_ty)
+                                {
+                                    self.diagnostics.push(Diagnostic::new(
+                                        DiagnosticKind::Type,
+                                        format!(
+                                            "type mismatch: '{}' has type {}, assigned value has type {}",
+                                            name.lexeme,
+                                            declared.name(),
+                                            value_ty.name()
+                                        ),
+                                        self.expr_span(value),
+                                    ));
+                                }
+                            }
+                            Some(BindingKind::Const) => {
+                                self.diagnostics.push(Diagnostic::new(
+                                    DiagnosticKind::Type,
+                                    format!("cannot assign to constant '{}'", name.lexeme),
+                                    span,
+                                ));
+                            }
+                            Some(BindingKind::Mutable) => {
+                                let declared =
+                                    env.get(&name.lexeme).cloned().unwrap_or(Type::Unknown);
+                                self.check_compound_assign(
+                                    &format!("'{}'", name.lexeme),
+                                    &declared,
+                                    operator,
+                                    &value_ty,
+                                    value,
+                                );
+                            }
+                            // Unknown names are reported by the interpreter.
+                            None => {}
+                        }
+                    }
+                    other => {
+                        let target_ty = self.infer_expr(other, env);
+                        self.check_compound_assign(
+                            "target", &target_ty, operator, &value_ty, value,
+                        );
+                    }
+                }
             }
             Stmt::Function {
                 params,
@@ -193,6 +666,7 @@ impl TypeChecker {
             } => {
                 env.push();
                 for p in params {
+                    self.reject_const_shadow(&p.name, env);
                     let ty =
                         p.ty.as_deref()
                             .map(|s| self.parse_type(s))
@@ -212,6 +686,7 @@ impl TypeChecker {
                 }
                 env.pop();
             }
+            Stmt::Defer { body, .. } => self.check_stmt(body, env),
             Stmt::If {
                 condition,
                 then_branch,
@@ -237,22 +712,28 @@ impl TypeChecker {
                     self.check_stmt(eb, env);
                 }
             }
-            Stmt::While { condition, body } => {
+            Stmt::While {
+                condition, body, ..
+            } => {
                 self.infer_expr(condition, env);
                 self.check_stmt(body, env);
             }
             Stmt::For {
                 element,
+                pattern,
                 iterator,
                 body,
+                ..
             } => {
-                let iter_ty = self.infer_expr(iterator, env);
-                let elem_ty = match iter_ty {
-                    Type::Array(inner) => *inner,
-                    _ => Type::Unknown,
-                };
+                let elem_ty = iter_element_type(&self.infer_expr(iterator, env));
                 env.push();
-                env.set(&element.lexeme, elem_ty);
+                match pattern {
+                    Some(p) => self.bind_pattern(p, &elem_ty, BindingKind::Immutable, env),
+                    None => {
+                        self.reject_const_shadow(element, env);
+                        env.set(&element.lexeme, elem_ty)
+                    }
+                }
                 self.check_stmt(body, env);
                 env.pop();
             }
@@ -267,8 +748,9 @@ impl TypeChecker {
                 self.check_stmt(catch_branch, env);
                 env.pop();
             }
-            Stmt::Match { expr, cases } => {
+            Stmt::Match { expr, start, cases } => {
                 self.infer_expr(expr, env);
+                self.check_match_arms(expr, start, cases);
                 for (_, guard, body) in cases {
                     env.push();
                     if let Some(g) = guard {
@@ -278,11 +760,29 @@ impl TypeChecker {
                     env.pop();
                 }
             }
-            Stmt::ImplBlock { methods, .. } => {
+            Stmt::ImplBlock {
+                type_name, methods, ..
+            } => {
                 for m in methods {
+                    self.check_operator_method(type_name, m);
                     self.check_stmt(m, env);
                 }
             }
+            Stmt::TraitDecl { methods, .. } => {
+                for m in methods {
+                    let Some(body) = &m.body else { continue };
+                    env.push();
+                    for p in &m.params {
+                        let ty =
+                            p.ty.as_deref()
+                                .map(|s| self.parse_type(s))
+                                .unwrap_or(Type::Unknown);
+                        env.set(&p.name.lexeme, ty);
+                    }
+                    self.check_stmt(body, env);
+                    env.pop();
+                }
+            }
             Stmt::Performant { statements } => {
                 env.push();
                 for s in statements {
@@ -295,6 +795,9 @@ impl TypeChecker {
                     self.infer_expr(v, env);
                 }
             }
+            Stmt::Yield { value, .. } => {
+                self.infer_expr(value, env);
+            }
             Stmt::Expression(e) => {
                 self.infer_expr(e, env);
             }
@@ -305,9 +808,69 @@ impl TypeChecker {
                 }
                 env.pop();
             }
-            Stmt::StructDecl { .. }
-            | Stmt::EnumDecl { .. }
+            Stmt::StructDecl {
+                name,
+                fields,
+                derives,
+                ..
+            } => {
+                let members: Vec<(String, Type)> = fields
+                    .iter()
+                    .map(|f| {
+                        let label = format!("field '{}.{}'", name.lexeme, f.name.lexeme);
+                        (label, self.parse_type(&f.ty))
+                    })
+                    .collect();
+                self.check_derived_members(derives, &members);
+                for field in fields {
+                    let Some(default) = &field.default else {
+                        continue;
+                    };
+                    let expected = self.parse_type(&field.ty);
+                    let actual = self.infer_expr(default, env);
+                    if !self.types_compatible(&expected, &actual) {
+                        self.diagnostics.push(Diagnostic::new(
+                            DiagnosticKind::Type,
+                            format!(
+                                "default of field '{}.{}': expected {}, got {}",
+                                name.lexeme,
+                                field.name.lexeme,
+                                expected.name(),
+                                actual.name()
+                            ),
+                            Span::new(
+                                field.name.start,
+                                field.name.end,
+                                field.name.line,
+                                field.name.col,
+                            ),
+                        ));
+                    }
+                }
+            }
+            Stmt::EnumDecl {
+                name,
+                variants,
+                derives,
+                ..
+            } => {
+                let members: Vec<(String, Type)> = variants
+                    .iter()
+                    .flat_map(|(variant, params)| {
+                        params.iter().flatten().map(move |ty| {
+                            let label = format!("payload of '{}.{}'", name.lexeme, variant.lexeme);
+                            (label, ty)
+                        })
+                    })
+                    .map(|(label, ty)| (label, self.parse_type(ty)))
+                    .collect();
+                self.check_derived_members(derives, &members);
+            }
+            Stmt::TypeAlias { .. }
+            | Stmt::Newtype { .. }
             | Stmt::Import { .. }
+            | Stmt::Break { .. }
+            | Stmt::Continue { .. }
             | Stmt::ShellCommand { .. } => {}
             Stmt::ComponentBlock { bindings, .. } => {
                 self.check_component_bindings(bindings, env);
@@ -401,7 +964,18 @@ impl TypeChecker {
                     }
                     BindingQualifier::State | BindingQualifier::Prop | BindingQualifier::Ref => {}
                 }
-                env.set(&name.lexeme, binding_ty);
+                let kind = match qualifier {
+                    BindingQualifier::State | BindingQualifier::Ref => BindingKind::Mutable,
+                    BindingQualifier::Prop => BindingKind::Prop,
+                    BindingQualifier::Memo => BindingKind::Immutable,
+                };
+                env.set_with_kind(&name.lexeme, binding_ty, kind);
+            }
+        }
+        // Component-local handlers see every binding above, whatever its order.
+        for b in bindings {
+            if matches!(b, Stmt::Function { .. }) {
+                self.check_stmt(b, env);
             }
         }
         env.pop();
@@ -429,6 +1003,19 @@ impl TypeChecker {
             } => {
                 let lt = self.infer_expr(left, env);
                 let rt = self.infer_expr(right, env);
+                if let Some(method) = binary_method(&operator.token_type)
+                    && let Some(ret) = self.operator_return(&lt, method)
+                {
+                    return if matches!(method, "eq" | "cmp") {
+                        Type::Bool
+                    } else {
+                        ret
+                    };
+                }
+                self.check_newtype_operands(operator, &lt, &rt);
+                if matches!(operator.lexeme.as_str(), "<" | "<=" | ">" | ">=") {
+                    self.require_ordered(&lt, operator);
+                }
                 match operator.lexeme.as_str() {
                     "==" | "!=" | "<" | "<=" | ">" | ">=" => Type::Bool,
                     "+" => {
@@ -445,15 +1032,22 @@ impl TypeChecker {
                             lt
                         }
                     }
+                    "&" | "|" | "^" | "<<" | ">>" => Type::Int,
                     _ => Type::Unknown,
                 }
             }
             Expr::Logical { .. } => Type::Bool,
             Expr::Unary { operator, right } => {
-                if operator.lexeme == "!" {
-                    Type::Bool
-                } else {
-                    self.infer_expr(right, env)
+                let inner = self.infer_expr(right, env);
+                if operator.lexeme == "-"
+                    && let Some(ret) = self.operator_return(&inner, NEG_METHOD)
+                {
+                    return ret;
+                }
+                match operator.lexeme.as_str() {
+                    "!" => Type::Bool,
+                    "~" => Type::Int,
+                    _ => inner,
                 }
             }
             Expr::Grouping { expression } => self.infer_expr(expression, env),
@@ -462,17 +1056,131 @@ impl TypeChecker {
                 arguments,
                 type_args,
             } => self.infer_call(callee, arguments, type_args, env),
-            Expr::FieldAccess { field, .. } => match field.lexeme.as_str() {
-                "len" | "size" | "count" => Type::Int,
-                _ => Type::Unknown,
-            },
+            Expr::FieldAccess { object, field } => {
+                let object = self.infer_expr(object, env);
+                // O valor de um newtype em runtime é a própria representação:
+                // não há `.0` para ler, só `as`.
+                if let Type::Newtype(name, inner) = &object {
+                    self.diagnostics.push(
+                        Diagnostic::new(
+                            DiagnosticKind::Type,
+                            format!("newtype '{}' has no field '{}'", name, field.lexeme),
+                            Span::new(field.start, field.end, field.line, field.col),
+                        )
+                        .note(format!("unwrap the value with `as {}`", inner.name())),
+                    );
+                    return Type::Unknown;
+                }
+                if let Some(ty) = self.field_type(&object, &field.lexeme) {
+                    return ty;
+                }
+                match field.lexeme.as_str() {
+                    "len" | "size" | "count" => Type::Int,
+                    _ => Type::Unknown,
+                }
+            }
+            Expr::OptionalChain {
+                object,
+                field,
+                arguments,
+            } => {
+                let receiver = self.infer_expr(object, env);
+                for a in arguments.iter().flatten() {
+                    self.infer_expr(a, env);
+                }
+                // Mesmas regras de `.`, aplicadas ao valor presente.
+                let present = match &receiver {
+                    Type::EnumInstance(name, params) if name == "Option" => {
+                        params.first().cloned().unwrap_or(Type::Unknown)
+                    }
+                    other => other.clone(),
+                };
+                let inner = match arguments {
+                    None => self.field_type(&present, &field.lexeme).unwrap_or(
+                        match field.lexeme.as_str() {
+                            "len" | "size" | "count" => Type::Int,
+                            _ => Type::Unknown,
+                        },
+                    ),
+                    Some(_) => methods::type_kind(&present)
+                        .and_then(|kind| methods::lookup(kind, &field.lexeme))
+                        .map(|method| method.returns.to_type(&present))
+                        .unwrap_or(Type::Unknown),
+                };
+                // Receptor comum se comporta como `.`; opcional ou desconhecido
+                // pode estar ausente.
+                match receiver {
+                    Type::Unknown | Type::None => option_of(inner),
+                    Type::Enum(name) | Type::EnumInstance(name, _) if name == "Option" => {
+                        option_of(inner)
+                    }
+                    _ => inner,
+                }
+            }
+            Expr::Coalesce {
+                left,
+                operator,
+                right,
+            } => {
+                let lt = self.infer_expr(left, env);
+                let rt = self.infer_expr(right, env);
+                let present = match lt {
+                    Type::EnumInstance(name, params) if name == "Option" => {
+                        params.into_iter().next().unwrap_or(Type::Unknown)
+                    }
+                    Type::Enum(name) if name == "Option" => Type::Unknown,
+                    Type::None => Type::Unknown,
+                    other => other,
+                };
+                if matches!(present, Type::Unknown) {
+                    return rt;
+                }
+                if !matches!(rt, Type::Unknown) && !self.types_compatible(&present, &rt) {
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::Type,
+                        format!(
+                            "'??' default has type {}, expected {}",
+                            rt.name(),
+                            present.name()
+                        ),
+                        Span::new(operator.start, operator.end, operator.line, operator.col),
+                    ));
+                }
+                present
+            }
+            Expr::Index { object, index, .. } => {
+                let container = self.infer_expr(object, env);
+                self.infer_expr(index, env);
+                indexed_type(&container, literal_int(index))
+            }
+            Expr::Slice {
+                object, start, end, ..
+            } => {
+                for bound in [start, end].into_iter().flatten() {
+                    self.infer_expr(bound, env);
+                }
+                sliced_type(&self.infer_expr(object, env))
+            }
+            Expr::Range { start, end, .. } => {
+                self.infer_expr(start, env);
+                self.infer_expr(end, env);
+                Type::Struct("Range".to_string())
+            }
             Expr::Cast { target_type, .. } => self.parse_type(target_type),
             Expr::Try(inner)
             | Expr::Weak(inner)
             | Expr::Unowned(inner)
             | Expr::WeakUpgrade(inner)
             | Expr::UnownedAccess(inner) => self.infer_expr(inner, env),
-            Expr::StructInit { name, .. } => Type::Struct(name.lexeme.clone()),
+            // O tipo do resultado de uma task não é rastreado.
+            Expr::Await { value, .. } => {
+                self.infer_expr(value, env);
+                Type::Unknown
+            }
+            Expr::StructInit { name, fields, base } => {
+                self.check_struct_init(name, fields, base.as_deref(), env);
+                Type::Struct(name.lexeme.clone())
+            }
             Expr::EnumInit { name, variant, .. } => {
                 let n = name
                     .as_ref()
@@ -481,6 +1189,12 @@ impl TypeChecker {
                 Type::Enum(n)
             }
             Expr::SpawnActor { .. } => Type::Unknown,
+            Expr::Lambda {
+                params,
+                return_type,
+                body,
+                ..
+            } => self.infer_lambda(params, return_type.as_deref(), body, env),
             Expr::Template(nodes) => {
                 for node in nodes {
                     self.check_template_node(node, env);
@@ -490,6 +1204,168 @@ impl TypeChecker {
         }
     }
 
+    /// `alvo op= v` vale `alvo = alvo op v`: o operador precisa se aplicar aos
+    /// dois tipos e o resultado precisa caber no tipo do alvo (`+=` de `Float`
+    /// num `Int` é erro). `=` é conferido por quem chama.
+    fn check_compound_assign(
+        &mut self,
+        target: &str,
+        target_ty: &Type,
+        operator: &Token,
+        value_ty: &Type,
+        value: &Expr,
+    ) {
+        let op = match operator.token_type {
+            TokenType::PlusEqual => TokenType::Plus,
+            TokenType::MinusEqual => TokenType::Minus,
+            TokenType::StarEqual => TokenType::Star,
+            TokenType::SlashEqual => TokenType::Slash,
+            _ => return,
+        };
+        if let Some(method) = binary_method(&op)
+            && self.operator_return(target_ty, method).is_some()
+        {
+            return;
+        }
+        self.check_newtype_operands(operator, target_ty, value_ty);
+        // Só tipos embutidos têm regra fixa; structs, enums e newtypes ficam com
+        // as sobrecargas e com `check_newtype_operands`.
+        let builtin = |t: &Type| {
+            matches!(
+                t,
+                Type::Int
+                    | Type::Float
+                    | Type::Bool
+                    | Type::String
+                    | Type::Buffer
+                    | Type::None
+                    | Type::Array(_)
+                    | Type::Tuple(_)
+                    | Type::Function(..)
+            )
+        };
+        if !builtin(target_ty) || !builtin(value_ty) {
+            return;
+        }
+        let numeric = |t: &Type| matches!(t, Type::Int | Type::Float);
+        let result = match (target_ty, value_ty) {
+            (Type::String, Type::String) if matches!(op, TokenType::Plus) => Type::String,
+            (l, r) if numeric(l) && numeric(r) => {
+                if matches!(l, Type::Float) || matches!(r, Type::Float) {
+                    Type::Float
+                } else {
+                    Type::Int
+                }
+            }
+            _ => {
+                self.diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Type,
+                    format!(
+                        "operator '{}' cannot be applied to {} and {}",
+                        operator.lexeme,
+                        target_ty.name(),
+                        value_ty.name()
+                    ),
+                    Span::new(operator.start, operator.end, operator.line, operator.col),
+                ));
+                return;
+            }
+        };
+        if !self.types_compatible(target_ty, &result) {
+            self.diagnostics.push(Diagnostic::new(
+                DiagnosticKind::Type,
+                format!(
+                    "type mismatch: {} has type {}, assigned value has type {}",
+                    target,
+                    target_ty.name(),
+                    result.name()
+                ),
+                self.expr_span(value),
+            ));
+        }
+    }
+
+    /// A newtype only meets its own type in a binary operator; mixing it with
+    /// the representation type is what the newtype exists to prevent.
+    fn check_newtype_operands(&mut self, operator: &Token, lt: &Type, rt: &Type) {
+        let opaque = |t: &Type| matches!(t, Type::Unknown | Type::GenericParam(_));
+        if lt == rt || opaque(lt) || opaque(rt) {
+            return;
+        }
+        let Some(newtype) = [lt, rt]
+            .into_iter()
+            .find(|t| matches!(t, Type::Newtype(..)))
+        else {
+            return;
+        };
+        self.diagnostics.push(
+            Diagnostic::new(
+                DiagnosticKind::Type,
+                format!(
+                    "operator '{}' cannot be applied to {} and {}",
+                    operator.lexeme,
+                    lt.name(),
+                    rt.name()
+                ),
+                Span::new(operator.start, operator.end, operator.line, operator.col),
+            )
+            .note(format!(
+                "wrap the other operand with `{}(...)` or unwrap with `as`",
+                newtype.name()
+            )),
+        );
+    }
+
+    /// `infer_expr` only borrows the environment, so the body is checked on a
+    /// copy with the parameters in scope. An expression-bodied lambda (a body
+    /// that is a single `return`) yields the type of that expression.
+    fn infer_lambda(
+        &mut self,
+        params: &[FunctionParam],
+        return_type: Option<&str>,
+        body: &Stmt,
+        env: &Env,
+    ) -> Type {
+        let mut local = env.clone();
+        local.push();
+        let param_types: Vec<Type> = params
+            .iter()
+            .map(|p| {
+                let ty =
+                    p.ty.as_deref()
+                        .map(|s| self.parse_type(s))
+                        .unwrap_or(Type::Unknown);
+                self.reject_const_shadow(&p.name, env);
+                local.set(&p.name.lexeme, ty.clone());
+                ty
+            })
+            .collect();
+        let declared = return_type.map(|s| self.parse_type(s));
+        let ret = if let Stmt::Block { statements } = body
+            && let [Stmt::Return { value: Some(value) }] = statements.as_slice()
+        {
+            let actual = self.infer_expr(value, &local);
+            if let Some(expected) = &declared
+                && !self.types_compatible(expected, &actual)
+            {
+                self.diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Type,
+                    format!(
+                        "type mismatch: lambda declared to return {}, body has type {}",
+                        expected.name(),
+                        actual.name()
+                    ),
+                    self.expr_span(value),
+                ));
+            }
+            actual
+        } else {
+            self.check_stmt(body, &mut local);
+            Type::Unknown
+        };
+        Type::Function(param_types, Box::new(declared.unwrap_or(ret)))
+    }
+
     fn check_template_node(&mut self, node: &TemplateNode, env: &Env) {
         match node {
             TemplateNode::Element {
@@ -570,6 +1446,113 @@ impl TypeChecker {
 
         let arg_types: Vec<Type> = args.iter().map(|a| self.infer_expr(a, env)).collect();
 
+        // `Nome(x)` wraps a value of the representation type into the newtype.
+        if let Expr::Variable { name } = callee
+            && env.get(&name.lexeme).is_none()
+            && let Some(inner) = self.types.newtypes.get(&name.lexeme).cloned()
+        {
+            let inner = self.parse_type(&inner);
+            let span = self.callee_span(callee);
+            match arg_types.as_slice() {
+                [arg] if !self.types_compatible(&inner, arg) => {
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::Type,
+                        format!(
+                            "argument 1 of newtype '{}': expected {}, got {}",
+                            name.lexeme,
+                            inner.name(),
+                            arg.name()
+                        ),
+                        span,
+                    ));
+                }
+                [_] => {}
+                _ => self.diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Type,
+                    format!(
+                        "newtype '{}' takes exactly one argument, got {}",
+                        name.lexeme,
+                        args.len()
+                    ),
+                    span,
+                )),
+            }
+            return Type::Newtype(name.lexeme.clone(), Box::new(inner));
+        }
+
+        // `Rgb(1, 2, 3)` builds a tuple struct.
+        if let Expr::Variable { name } = callee
+            && env.get(&name.lexeme).is_none()
+            && let Some(sig) = self.structs.get(&name.lexeme).filter(|s| s.tuple).cloned()
+        {
+            let span = self.callee_span(callee);
+            if sig.fields.len() != args.len() {
+                self.diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Type,
+                    format!(
+                        "tuple struct '{}' takes {} argument{}, got {}",
+                        name.lexeme,
+                        sig.fields.len(),
+                        if sig.fields.len() == 1 { "" } else { "s" },
+                        args.len()
+                    ),
+                    span,
+                ));
+            }
+            for (i, ((_, expected, _), actual)) in sig.fields.iter().zip(&arg_types).enumerate() {
+                if !self.types_compatible(expected, actual) {
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::Type,
+                        format!(
+                            "argument {} of tuple struct '{}': expected {}, got {}",
+                            i + 1,
+                            name.lexeme,
+                            expected.name(),
+                            actual.name()
+                        ),
+                        span,
+                    ));
+                }
+            }
+            return Type::Struct(name.lexeme.clone());
+        }
+
+        // Builtin methods (`s.split(",")`) take their type from the shared table.
+        if let Expr::FieldAccess { object, field } = callee {
+            let receiver = self.infer_expr(object, env);
+            // `x.clone()` generated by `@derive(Clone)`.
+            if field.lexeme == "clone"
+                && args.is_empty()
+                && self
+                    .user_type_name(&receiver)
+                    .is_some_and(|name| self.type_derives(name, "Clone"))
+            {
+                return receiver;
+            }
+            if let Some(method) =
+                methods::type_kind(&receiver).and_then(|kind| methods::lookup(kind, &field.lexeme))
+            {
+                if let (Type::Array(inner), "sort") = (&receiver, method.name) {
+                    self.require_ordered(inner, field);
+                }
+                if method.params.len() != args.len() {
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::Type,
+                        format!(
+                            "method '{}.{}' takes {} argument{}, found {}",
+                            method.receiver,
+                            method.name,
+                            method.params.len(),
+                            if method.params.len() == 1 { "" } else { "s" },
+                            args.len()
+                        ),
+                        Span::new(field.start, field.end, field.line, field.col),
+                    ));
+                }
+                return method.returns.to_type(&receiver);
+            }
+        }
+
         if let Some(name) = &func_name
             && let Some(sig) = self.functions.get(name).cloned()
         {
@@ -609,6 +1592,34 @@ impl TypeChecker {
             return self.substitute(&sig.return_type, &bindings);
         }
 
+        // A binding holding a function value (e.g. a lambda): the arguments are
+        // checked against the annotated parameters, as for named functions.
+        if let Expr::Variable { name } = callee
+            && let Some(Type::Function(params, ret)) = env.get(&name.lexeme)
+        {
+            let all_annotated = params.iter().all(|t| !matches!(t, Type::Unknown));
+            if all_annotated && params.len() == args.len() {
+                let span = self.callee_span(callee);
+                for (i, (param_ty, arg_ty)) in params.iter().zip(&arg_types).enumerate() {
+                    if !self.types_compatible(param_ty, arg_ty) && !matches!(arg_ty, Type::Unknown)
+                    {
+                        self.diagnostics.push(Diagnostic::new(
+                            DiagnosticKind::Type,
+                            format!(
+                                "argument {} of '{}': expected {}, got {}",
+                                i + 1,
+                                name.lexeme,
+                                param_ty.name(),
+                                arg_ty.name()
+                            ),
+                            span,
+                        ));
+                    }
+                }
+            }
+            return (**ret).clone();
+        }
+
         Type::Unknown
     }
 
@@ -671,6 +1682,90 @@ impl TypeChecker {
         }
     }
 
+    /// Declared type of `object.field`: a struct field or a tuple position.
+    fn field_type(&self, object: &Type, field: &str) -> Option<Type> {
+        match object {
+            Type::Struct(name) => self
+                .structs
+                .get(name)?
+                .fields
+                .iter()
+                .find(|(f, _, _)| f == field)
+                .map(|(_, ty, _)| ty.clone()),
+            Type::Tuple(items) => items.get(field.parse::<usize>().ok()?).cloned(),
+            _ => None,
+        }
+    }
+
+    /// `Nome { campo: valor, ..base }`: known fields with the declared types,
+    /// a base of the same struct, and every field without a default present.
+    fn check_struct_init(
+        &mut self,
+        name: &Token,
+        fields: &[(Token, Expr)],
+        base: Option<&Expr>,
+        env: &Env,
+    ) {
+        let values: Vec<Type> = fields
+            .iter()
+            .map(|(_, e)| self.infer_expr(e, env))
+            .collect();
+        let base_ty = base.map(|b| self.infer_expr(b, env));
+        let Some(sig) = self.structs.get(&name.lexeme).cloned() else {
+            return;
+        };
+        let struct_ty = Type::Struct(name.lexeme.clone());
+        for ((field, _), actual) in fields.iter().zip(&values) {
+            let span = Span::new(field.start, field.end, field.line, field.col);
+            match sig.fields.iter().find(|(f, _, _)| *f == field.lexeme) {
+                None => self.diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Type,
+                    format!("struct '{}' has no field '{}'", name.lexeme, field.lexeme),
+                    span,
+                )),
+                Some((_, expected, _)) if !self.types_compatible(expected, actual) => {
+                    self.diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::Type,
+                        format!(
+                            "field '{}.{}': expected {}, got {}",
+                            name.lexeme,
+                            field.lexeme,
+                            expected.name(),
+                            actual.name()
+                        ),
+                        span,
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+        let span = Span::new(name.start, name.end, name.line, name.col);
+        if let Some(base_ty) = &base_ty {
+            if !self.types_compatible(&struct_ty, base_ty) {
+                self.diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Type,
+                    format!(
+                        "update base of '{}' must be {}, got {}",
+                        name.lexeme,
+                        struct_ty.name(),
+                        base_ty.name()
+                    ),
+                    span,
+                ));
+            }
+            return;
+        }
+        for (field, _, has_default) in &sig.fields {
+            if !has_default && !fields.iter().any(|(f, _)| f.lexeme == *field) {
+                self.diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::Type,
+                    format!("missing field '{}' in '{}'", field, name.lexeme),
+                    span,
+                ));
+            }
+        }
+    }
+
     fn types_compatible(&self, expected: &Type, actual: &Type) -> bool {
         if expected == actual {
             return true;
@@ -681,6 +1776,19 @@ impl TypeChecker {
             // Int coerces to Float in arithmetic
             (Type::Float, Type::Int) => true,
             (Type::Array(a), Type::Array(b)) => self.types_compatible(a, b),
+            // Anotação nua (`Array`, `Option`, `Result`) aceita qualquer forma
+            // parametrizada ou inferida do mesmo tipo, e vice-versa.
+            (Type::Struct(n), Type::Array(_)) | (Type::Array(_), Type::Struct(n)) => n == "Array",
+            (Type::Struct(a) | Type::Enum(a), Type::Enum(b) | Type::EnumInstance(b, _))
+            | (Type::Enum(a) | Type::EnumInstance(a, _), Type::Struct(b) | Type::Enum(b)) => a == b,
+            (Type::EnumInstance(a, pa), Type::EnumInstance(b, pb)) => {
+                a == b
+                    && pa.len() == pb.len()
+                    && pa
+                        .iter()
+                        .zip(pb.iter())
+                        .all(|(x, y)| self.types_compatible(x, y))
+            }
             (Type::Tuple(a), Type::Tuple(b)) => {
                 a.len() == b.len()
                     && a.iter()
@@ -691,10 +1799,17 @@ impl TypeChecker {
         }
     }
 
-    fn bind_pattern(&mut self, pattern: &MatchPattern, ty: &Type, env: &mut Env) {
+    fn bind_pattern(
+        &mut self,
+        pattern: &MatchPattern,
+        ty: &Type,
+        kind: BindingKind,
+        env: &mut Env,
+    ) {
         match pattern {
             MatchPattern::Variable(tok) | MatchPattern::Binding(tok) => {
-                env.set(&tok.lexeme, ty.clone());
+                self.reject_const_shadow(tok, env);
+                env.set_with_kind(&tok.lexeme, ty.clone(), kind);
             }
             MatchPattern::Tuple(pats) => {
                 let types = match ty {
@@ -702,17 +1817,51 @@ impl TypeChecker {
                     _ => vec![Type::Unknown; pats.len()],
                 };
                 for (p, t) in pats.iter().zip(types.iter()) {
-                    self.bind_pattern(p, t, env);
+                    self.bind_pattern(p, t, kind, env);
                 }
             }
             MatchPattern::EnumVariant {
                 params: Some(pats), ..
             } => {
                 for p in pats {
-                    self.bind_pattern(p, &Type::Unknown, env);
+                    self.bind_pattern(p, &Type::Unknown, kind, env);
+                }
+            }
+            MatchPattern::Struct { fields, .. } => {
+                for (_, p) in fields {
+                    self.bind_pattern(p, &Type::Unknown, kind, env);
                 }
             }
+            MatchPattern::Array {
+                prefix,
+                rest,
+                suffix,
+            } => {
+                let (elem, array) = match ty {
+                    Type::Array(t) => ((**t).clone(), ty.clone()),
+                    _ => (Type::Unknown, Type::Array(Box::new(Type::Unknown))),
+                };
+                for p in prefix.iter().chain(suffix) {
+                    self.bind_pattern(p, &elem, kind, env);
+                }
+                if let Some(Some(tok)) = rest {
+                    self.reject_const_shadow(tok, env);
+                    env.set_with_kind(&tok.lexeme, array, kind);
+                }
+            }
+            // As alternativas ligam os mesmos nomes; basta a primeira.
+            MatchPattern::Or(alts) => {
+                if let Some(first) = alts.first() {
+                    self.bind_pattern(first, ty, kind, env);
+                }
+            }
+            MatchPattern::At { name, pattern } => {
+                self.reject_const_shadow(name, env);
+                env.set_with_kind(&name.lexeme, ty.clone(), kind);
+                self.bind_pattern(pattern, ty, kind, env);
+            }
             MatchPattern::Literal(_)
+            | MatchPattern::Range { .. }
             | MatchPattern::Wildcard
             | MatchPattern::EnumVariant { params: None, .. } => {}
         }
@@ -721,6 +1870,12 @@ impl TypeChecker {
     /// Convert a type annotation string (from the AST) to a `Type`.
     pub fn parse_type(&self, s: &str) -> Type {
         let s = s.trim();
+        if let Some(target) = self.types.aliases.get(s) {
+            return self.parse_type(target);
+        }
+        if let Some(inner) = self.types.newtypes.get(s) {
+            return Type::Newtype(s.to_string(), Box::new(self.parse_type(inner)));
+        }
         match s {
             "Int" | "i64" | "int" => Type::Int,
             "Float" | "f64" | "float" => Type::Float,
@@ -734,6 +1889,11 @@ impl TypeChecker {
             _ if s.starts_with('[') && s.ends_with(']') => {
                 Type::Array(Box::new(self.parse_type(&s[1..s.len() - 1])))
             }
+            _ if s.starts_with('(') && s.ends_with(')') => Type::Tuple(
+                split_type_list(&s[1..s.len() - 1])
+                    .map(|t| self.parse_type(t))
+                    .collect(),
+            ),
             _ if s.starts_with("Result<") && s.ends_with('>') => {
                 let inner = &s[7..s.len() - 1];
                 let params = inner
@@ -780,6 +1940,68 @@ impl TypeChecker {
         }
     }
 
+    /// Non-exhaustive matches are type errors; arms covered by earlier arms are lints.
+    fn check_match_arms(
+        &mut self,
+        expr: &Expr,
+        start: &Token,
+        cases: &[(MatchPattern, Option<Expr>, Stmt)],
+    ) {
+        let arms: Vec<(&MatchPattern, bool)> =
+            cases.iter().map(|(p, g, _)| (p, g.is_some())).collect();
+        let report = exhaustiveness::check_match(&self.enums, &arms);
+        // Literais e tuplas não guardam token: vale o início do escrutínio.
+        let mut match_span = self.expr_span(expr);
+        if match_span.line == 0 {
+            match_span = Span::new(start.start, start.end, start.line, start.col);
+        }
+        for i in report.unreachable {
+            let span = pattern_span(&cases[i].0).unwrap_or(match_span);
+            self.diagnostics.push(Diagnostic::new(
+                DiagnosticKind::Lint,
+                format!(
+                    "unreachable match arm {}: earlier arms already cover every value it matches",
+                    i + 1
+                ),
+                span,
+            ));
+        }
+        if !report.missing.is_empty() {
+            const SHOWN: usize = 3;
+            let mut listed: Vec<String> = report
+                .missing
+                .iter()
+                .take(SHOWN)
+                .map(|w| format!("`{}`", w))
+                .collect();
+            let list = if report.missing.len() > SHOWN {
+                format!(
+                    "{} and {} more",
+                    listed.join(", "),
+                    report.missing.len() - SHOWN
+                )
+            } else if listed.len() > 1 {
+                let last = listed.pop().unwrap_or_default();
+                format!("{} and {}", listed.join(", "), last)
+            } else {
+                listed.join("")
+            };
+            let noun = if report.missing.len() == 1 {
+                "pattern"
+            } else {
+                "patterns"
+            };
+            self.diagnostics.push(
+                Diagnostic::new(
+                    DiagnosticKind::Type,
+                    format!("non-exhaustive match: {} {} not covered", noun, list),
+                    match_span,
+                )
+                .note("add the missing arms or a `case _:` arm; guarded arms do not count"),
+            );
+        }
+    }
+
     fn expr_span(&self, expr: &Expr) -> Span {
         match expr {
             Expr::Variable { name } => Span::new(name.start, name.end, name.line, name.col),
@@ -787,6 +2009,18 @@ impl TypeChecker {
             Expr::FieldAccess { field, .. } => {
                 Span::new(field.start, field.end, field.line, field.col)
             }
+            Expr::Index { bracket, .. } | Expr::Slice { bracket, .. } => {
+                Span::new(bracket.start, bracket.end, bracket.line, bracket.col)
+            }
+            Expr::StructInit { name, .. } => Span::new(name.start, name.end, name.line, name.col),
+            Expr::OptionalChain { field, .. } => {
+                Span::new(field.start, field.end, field.line, field.col)
+            }
+            Expr::Binary { operator, .. }
+            | Expr::Coalesce { operator, .. }
+            | Expr::Range { operator, .. } => {
+                Span::new(operator.start, operator.end, operator.line, operator.col)
+            }
             _ => Span::dummy(),
         }
     }
@@ -802,6 +2036,97 @@ impl TypeChecker {
     }
 }
 
+/// `Option<inner>`, the type of an optional-chaining access.
+fn option_of(inner: Type) -> Type {
+    Type::EnumInstance("Option".to_string(), vec![inner])
+}
+
+/// The comma-separated annotations in `list`, ignoring commas nested in
+/// `<>`, `[]` or `()`.
+fn split_type_list(list: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0i32;
+    let mut start = 0;
+    let mut parts = Vec::new();
+    for (i, c) in list.char_indices() {
+        match c {
+            '<' | '[' | '(' => depth += 1,
+            '>' | ']' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&list[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&list[start..]);
+    parts.into_iter().map(str::trim).filter(|p| !p.is_empty())
+}
+
+/// Span of the first token inside `pattern`, if it has one.
+fn pattern_span(pattern: &MatchPattern) -> Option<Span> {
+    let tok = |t: &core::Token| Span::new(t.start, t.end, t.line, t.col);
+    match pattern {
+        MatchPattern::Variable(t) | MatchPattern::Binding(t) => Some(tok(t)),
+        MatchPattern::EnumVariant {
+            enum_name, variant, ..
+        } => Some(tok(enum_name.as_ref().unwrap_or(variant))),
+        MatchPattern::Struct { name, .. } | MatchPattern::At { name, .. } => Some(tok(name)),
+        MatchPattern::Tuple(items) | MatchPattern::Or(items) => items.iter().find_map(pattern_span),
+        MatchPattern::Array { prefix, suffix, .. } => {
+            prefix.iter().chain(suffix).find_map(pattern_span)
+        }
+        MatchPattern::Literal(_) | MatchPattern::Range { .. } | MatchPattern::Wildcard => None,
+    }
+}
+
+/// Element type of `container[index]`, shared by both inference passes.
+/// `literal_index` is the index when it is an `Int` literal, which is what
+/// lets a tuple position resolve to its own type.
+pub(crate) fn indexed_type(container: &Type, literal_index: Option<i64>) -> Type {
+    match container {
+        Type::Array(inner) => (**inner).clone(),
+        Type::String => Type::String,
+        Type::Buffer => Type::Int,
+        Type::Tuple(items) => literal_index
+            .map(|i| if i < 0 { i + items.len() as i64 } else { i })
+            .and_then(|i| usize::try_from(i).ok())
+            .and_then(|i| items.get(i).cloned())
+            .unwrap_or(Type::Unknown),
+        _ => Type::Unknown,
+    }
+}
+
+/// Type of `container[start..end]`: same kind as the container, except
+/// tuples, whose element types depend on the bounds.
+pub(crate) fn sliced_type(container: &Type) -> Type {
+    match container {
+        Type::Array(_) | Type::String | Type::Buffer => container.clone(),
+        _ => Type::Unknown,
+    }
+}
+
+/// Type of `x` in `for x in iterable`. Ranges and buffers yield `Int`,
+/// strings yield one-character `String`s.
+pub(crate) fn iter_element_type(iterable: &Type) -> Type {
+    match iterable {
+        Type::Array(inner) => (**inner).clone(),
+        Type::Struct(name) if name == "Range" => Type::Int,
+        Type::Buffer => Type::Int,
+        Type::String => Type::String,
+        _ => Type::Unknown,
+    }
+}
+
+/// The index as an `Int` literal, accepting a leading minus.
+pub(crate) fn literal_int(expr: &Expr) -> Option<i64> {
+    match expr {
+        Expr::Literal(ArtValue::Int(i)) => Some(*i),
+        Expr::Unary { operator, right } if operator.lexeme == "-" => literal_int(right).map(|i| -i),
+        Expr::Grouping { expression } => literal_int(expression),
+        _ => None,
+    }
+}
+
 fn expr_var_refs(expr: &Expr) -> Vec<String> {
     let mut out = Vec::new();
     collect_refs(expr, &mut out);
@@ -811,7 +2136,9 @@ fn expr_var_refs(expr: &Expr) -> Vec<String> {
 fn collect_refs(expr: &Expr, out: &mut Vec<String>) {
     match expr {
         Expr::Variable { name } => out.push(name.lexeme.clone()),
-        Expr::Binary { left, right, .. } | Expr::Logical { left, right, .. } => {
+        Expr::Binary { left, right, .. }
+        | Expr::Logical { left, right, .. }
+        | Expr::Coalesce { left, right, .. } => {
             collect_refs(left, out);
             collect_refs(right, out);
         }
@@ -830,6 +2157,30 @@ fn collect_refs(expr: &Expr, out: &mut Vec<String>) {
             }
         }
         Expr::FieldAccess { object, .. } => collect_refs(object, out),
+        Expr::OptionalChain {
+            object, arguments, ..
+        } => {
+            collect_refs(object, out);
+            for a in arguments.iter().flatten() {
+                collect_refs(a, out);
+            }
+        }
+        Expr::Index { object, index, .. } => {
+            collect_refs(object, out);
+            collect_refs(index, out);
+        }
+        Expr::Range { start, end, .. } => {
+            collect_refs(start, out);
+            collect_refs(end, out);
+        }
+        Expr::Slice {
+            object, start, end, ..
+        } => {
+            collect_refs(object, out);
+            for bound in [start, end].into_iter().flatten() {
+                collect_refs(bound, out);
+            }
+        }
         _ => {}
     }
 }
@@ -899,6 +2250,56 @@ greet(42)"#,
         assert!(tc.diagnostics[0].message.contains("String"));
     }
 
+    #[test]
+    fn test_lambda_call_uses_inferred_return_type() {
+        let mut tc = TypeChecker::new();
+        let prog = parse("let double = |x: Int| x * 2\nlet s: String = double(1)");
+        tc.check(&prog);
+        assert_eq!(tc.diagnostics.len(), 1, "{:?}", tc.diagnostics);
+        assert!(
+            tc.diagnostics[0]
+                .message
+                .contains("declared as String, initializer has type Int"),
+            "{:?}",
+            tc.diagnostics
+        );
+    }
+
+    #[test]
+    fn test_lambda_call_checks_annotated_arguments() {
+        let mut tc = TypeChecker::new();
+        let prog =
+            parse("let g = |a: Int, b: Int| a + b\ng(\"s\", 1)\ng(1, 2)\nlet h = |x| x\nh(\"s\")");
+        tc.check(&prog);
+        assert_eq!(tc.diagnostics.len(), 1, "{:?}", tc.diagnostics);
+        assert_eq!(
+            tc.diagnostics[0].message,
+            "argument 1 of 'g': expected Int, got String"
+        );
+        assert_eq!(
+            (tc.diagnostics[0].span.line, tc.diagnostics[0].span.col),
+            (2, 1)
+        );
+    }
+
+    #[test]
+    fn test_lambda_return_type_mismatch() {
+        let mut tc = TypeChecker::new();
+        let prog = parse(
+            r#"let f = |x: Int| -> String { return x }
+let g = func(x: Int) -> Int { return x + 1 }"#,
+        );
+        tc.check(&prog);
+        assert_eq!(tc.diagnostics.len(), 1, "{:?}", tc.diagnostics);
+        assert!(
+            tc.diagnostics[0]
+                .message
+                .contains("lambda declared to return String, body has type Int"),
+            "{:?}",
+            tc.diagnostics
+        );
+    }
+
     #[test]
     fn test_generic_identity_inference() {
         let mut tc = TypeChecker::new();
@@ -1219,4 +2620,79 @@ greet(42)"#,
             panic!("Expected ComponentBlock");
         }
     }
+
+    fn type_errors(src: &str) -> Vec<Diagnostic> {
+        let mut tc = TypeChecker::new();
+        tc.check(&parse(src));
+        tc.diagnostics
+            .into_iter()
+            .filter(|d| d.kind == DiagnosticKind::Type)
+            .collect()
+    }
+
+    #[test]
+    fn assign_to_let_binding_errors() {
+        let errors = type_errors("let x = 1\nx = 2");
+        assert_eq!(errors.len(), 1, "{:?}", errors);
+        assert!(errors[0].message.contains("immutable binding 'x'"));
+    }
+
+    #[test]
+    fn assign_to_var_binding_ok() {
+        let errors = type_errors("var x = 1\nx = 2\nx += 3");
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn assign_to_var_with_other_type_errors() {
+        let errors = type_errors("var x = 1\nx = \"s\"");
+        assert_eq!(errors.len(), 1, "{:?}", errors);
+        assert!(errors[0].message.contains("type mismatch"));
+    }
+
+    #[test]
+    fn compound_assign_checks_the_result_type() {
+        let cases = [
+            (
+                "var s = \"a\"\ns += 1",
+                "operator '+=' cannot be applied to String and Int",
+            ),
+            (
+                "var n = 1\nn -= \"x\"",
+                "operator '-=' cannot be applied to Int and String",
+            ),
+            (
+                "var n = 1\nn *= 2.5",
+                "type mismatch: 'n' has type Int, assigned value has type Float",
+            ),
+            (
+                "struct P { a: Int }\nlet p = P { a: 1 }\np.a /= 0.5",
+                "type mismatch: target has type Int, assigned value has type Float",
+            ),
+        ];
+        for (src, expected) in cases {
+            let errors = type_errors(src);
+            assert_eq!(errors.len(), 1, "{}: {:?}", src, errors);
+            assert_eq!(errors[0].message, expected, "{}", src);
+        }
+        let ok = type_errors("var f = 1.5\nf += 1\nvar s = \"a\"\ns += \"b\"");
+        assert!(ok.is_empty(), "{:?}", ok);
+    }
+
+    #[test]
+    fn assign_to_prop_inside_component_handler_errors() {
+        let errors = type_errors(
+            "component Label {\n  prop text: String\n  func reset() { text = \"\" }\n  view { <span>{text}</span> }\n}",
+        );
+        assert_eq!(errors.len(), 1, "{:?}", errors);
+        assert!(errors[0].message.contains("cannot assign to prop 'text'"));
+    }
+
+    #[test]
+    fn assign_to_state_inside_component_handler_ok() {
+        let errors = type_errors(
+            "component Counter {\n  state count: Int = 0\n  func inc() { count += 1 }\n  view { <p>{count}</p> }\n}",
+        );
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
 }
@@ -1,4 +1,7 @@
-use core::{ArtValue, Expr, InterpolatedPart, Program, Stmt, Type};
+use core::methods;
+use core::operators::{OPERATOR_METHODS, binary_method};
+use core::types::TypeDecls;
+use core::{ArtValue, Expr, InterpolatedPart, MatchPattern, Program, Stmt, Type};
 use diagnostics::{Diagnostic, DiagnosticKind, Span};
 use std::collections::{HashMap, HashSet};
 
@@ -33,7 +36,7 @@ impl TypeEnv {
 }
 
 type FuncEntry = (
-    Vec<String>,
+    Vec<core::ast::FunctionParam>,
     std::rc::Rc<Stmt>,
     Option<Vec<(String, Option<String>)>>,
 );
@@ -52,11 +55,23 @@ pub struct TypeInfer<'a> {
     visiting_functions: HashSet<String>,
     moved_capability_vars: HashMap<String, bool>,
     moved_capability_bindings: Vec<Vec<(String, Option<bool>)>>,
+    // trait -> método -> tem implementação default
+    traits: HashMap<String, HashMap<String, bool>>,
+    // trait -> declarações dos métodos, para conferir as assinaturas do `impl`
+    trait_methods: HashMap<String, Vec<core::ast::TraitMethod>>,
+    // (trait, tipo) -> `impl` já visto, para rejeitar o segundo
+    seen_impls: HashMap<(String, String), core::Token>,
+    // tipo -> traits implementados (inclui os embutidos dos primitivos)
+    trait_impls: HashMap<String, HashSet<String>>,
+    // `type`/`newtype` do topo, para expandir aliases nos type args
+    type_decls: TypeDecls,
+    // tipo -> métodos de operador (do `impl` ou default de trait)
+    operator_methods: HashMap<String, HashSet<String>>,
 }
 
 impl<'a> TypeInfer<'a> {
     pub fn new(tenv: &'a mut TypeEnv) -> Self {
-        Self {
+        let mut this = Self {
             diags: Vec::new(),
             tenv,
             enums: HashMap::new(),
@@ -66,6 +81,273 @@ impl<'a> TypeInfer<'a> {
             visiting_functions: HashSet::new(),
             moved_capability_vars: HashMap::new(),
             moved_capability_bindings: vec![Vec::new()],
+            traits: HashMap::new(),
+            trait_methods: HashMap::new(),
+            seen_impls: HashMap::new(),
+            trait_impls: HashMap::new(),
+            type_decls: TypeDecls::default(),
+            operator_methods: HashMap::new(),
+        };
+        for (name, types) in core::types::BUILTIN_TRAITS {
+            this.traits.insert(name.to_string(), HashMap::new());
+            for ty in *types {
+                this.trait_impls
+                    .entry(ty.to_string())
+                    .or_default()
+                    .insert(name.to_string());
+            }
+        }
+        this
+    }
+
+    /// Registra traits, `impl Trait for Tipo` e derives do nível superior antes da
+    /// visita, para que bounds genéricos valham independentemente da ordem.
+    fn collect_traits(&mut self, program: &Program) {
+        for stmt in program {
+            match stmt {
+                Stmt::TraitDecl { name, methods } => {
+                    self.trait_methods
+                        .insert(name.lexeme.clone(), methods.clone());
+                    let methods = methods
+                        .iter()
+                        .map(|m| (m.name.lexeme.clone(), m.body.is_some()))
+                        .collect();
+                    self.traits.insert(name.lexeme.clone(), methods);
+                }
+                Stmt::ImplBlock {
+                    type_name,
+                    trait_name,
+                    methods,
+                } => {
+                    let operators = methods.iter().filter_map(|m| match m {
+                        Stmt::Function { name, .. }
+                            if OPERATOR_METHODS.contains(&name.lexeme.as_str()) =>
+                        {
+                            Some(name.lexeme.clone())
+                        }
+                        _ => None,
+                    });
+                    self.operator_methods
+                        .entry(type_name.clone())
+                        .or_default()
+                        .extend(operators);
+                    if let Some(t) = trait_name {
+                        self.trait_impls
+                            .entry(type_name.clone())
+                            .or_default()
+                            .insert(t.lexeme.clone());
+                    }
+                }
+                // `@derive(Eq)` satisfaz o bound `Eq`, `@derive(Ord)` o `Comparable`.
+                Stmt::StructDecl { name, derives, .. } | Stmt::EnumDecl { name, derives, .. } => {
+                    let bounds = derives
+                        .iter()
+                        .filter_map(|d| core::types::derive_bound(&d.lexeme).flatten());
+                    self.trait_impls
+                        .entry(name.lexeme.clone())
+                        .or_default()
+                        .extend(bounds.map(str::to_string));
+                }
+                _ => {}
+            }
+        }
+        for (ty, operators) in self.operator_methods.iter_mut() {
+            let defaults = self
+                .trait_impls
+                .get(ty)
+                .into_iter()
+                .flatten()
+                .filter_map(|t| self.traits.get(t))
+                .flatten()
+                .filter(|(m, has_default)| **has_default && OPERATOR_METHODS.contains(&m.as_str()));
+            operators.extend(defaults.map(|(m, _)| m.clone()));
+        }
+    }
+
+    /// `true` se o tipo `ty` sobrecarrega o operador via `method`.
+    fn overloads(&self, ty: &Type, method: &str) -> bool {
+        let (Type::Struct(name) | Type::Enum(name) | Type::EnumInstance(name, _)) = ty else {
+            return false;
+        };
+        self.operator_methods
+            .get(name)
+            .is_some_and(|ms| ms.contains(method))
+    }
+
+    /// Confere os métodos de `impl Trait for Tipo` com a declaração do trait.
+    fn check_trait_impl(&mut self, trait_name: &core::Token, type_name: &str, methods: &[Stmt]) {
+        let span = Span::new(
+            trait_name.start,
+            trait_name.end,
+            trait_name.line,
+            trait_name.col,
+        );
+        let Some(declared) = self.traits.get(&trait_name.lexeme) else {
+            self.diags.push(Diagnostic::new(
+                DiagnosticKind::Type,
+                format!("Unknown trait '{}'", trait_name.lexeme),
+                span,
+            ));
+            return;
+        };
+        let defined: Vec<&core::Token> = methods
+            .iter()
+            .filter_map(|m| match m {
+                Stmt::Function { name, .. } => Some(name),
+                _ => None,
+            })
+            .collect();
+        let mut missing: Vec<&String> = declared
+            .iter()
+            .filter(|(m, has_default)| !**has_default && !defined.iter().any(|d| &d.lexeme == *m))
+            .map(|(m, _)| m)
+            .collect();
+        missing.sort();
+        let mut found = Vec::new();
+        for m in missing {
+            found.push(Diagnostic::new(
+                DiagnosticKind::Type,
+                format!(
+                    "Type '{}' does not implement method '{}' required by trait '{}'",
+                    type_name, m, trait_name.lexeme
+                ),
+                span,
+            ));
+        }
+        for d in defined {
+            if !declared.contains_key(&d.lexeme) {
+                found.push(Diagnostic::new(
+                    DiagnosticKind::Type,
+                    format!(
+                        "Method '{}' is not a member of trait '{}'",
+                        d.lexeme, trait_name.lexeme
+                    ),
+                    Span::new(d.start, d.end, d.line, d.col),
+                ));
+            }
+        }
+        let key = (trait_name.lexeme.clone(), type_name.to_string());
+        if let Some(first) = self.seen_impls.get(&key) {
+            found.push(
+                Diagnostic::new(
+                    DiagnosticKind::Type,
+                    format!(
+                        "Trait '{}' is already implemented for type '{}'",
+                        trait_name.lexeme, type_name
+                    ),
+                    span,
+                )
+                .note(format!("first implemented at {}:{}", first.line, first.col)),
+            );
+        } else {
+            self.seen_impls.insert(key, trait_name.clone());
+        }
+        for m in methods {
+            if let Some(diag) = self.check_trait_signature(&trait_name.lexeme, m) {
+                found.push(diag);
+            }
+        }
+        self.diags.extend(found);
+    }
+
+    /// Um método do `impl` precisa da mesma aridade e das mesmas anotações que
+    /// a declaração no trait. Anotações são comparadas depois de expandir
+    /// aliases; um lado sem anotação não é comparado.
+    fn check_trait_signature(&self, trait_name: &str, method: &Stmt) -> Option<Diagnostic> {
+        let Stmt::Function {
+            name,
+            params,
+            return_type,
+            ..
+        } = method
+        else {
+            return None;
+        };
+        let declared = self
+            .trait_methods
+            .get(trait_name)?
+            .iter()
+            .find(|m| m.name.lexeme == name.lexeme)?;
+        let span = Span::new(name.start, name.end, name.line, name.col);
+        let normalize = |t: &str| -> String {
+            self.type_decls
+                .expand(t)
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect()
+        };
+        let differs = |a: &Option<String>, b: &Option<String>| match (a, b) {
+            (Some(a), Some(b)) => normalize(a) != normalize(b),
+            _ => false,
+        };
+        let mismatch = |detail: String| {
+            Diagnostic::new(
+                DiagnosticKind::Type,
+                format!(
+                    "Method '{}' does not match its declaration in trait '{}': {}",
+                    name.lexeme, trait_name, detail
+                ),
+                span,
+            )
+        };
+        if declared.params.len() != params.len() {
+            return Some(mismatch(format!(
+                "expected {} parameter{}, found {}",
+                declared.params.len(),
+                if declared.params.len() == 1 { "" } else { "s" },
+                params.len()
+            )));
+        }
+        for (expected, actual) in declared.params.iter().zip(params) {
+            if differs(&expected.ty, &actual.ty) {
+                return Some(mismatch(format!(
+                    "parameter '{}' expected {}, found {}",
+                    actual.name.lexeme,
+                    expected.ty.as_deref().unwrap_or_default(),
+                    actual.ty.as_deref().unwrap_or_default()
+                )));
+            }
+        }
+        // Trait que declara retorno exige o mesmo retorno no `impl`.
+        match (&declared.return_type, return_type) {
+            (Some(expected), None) => Some(mismatch(format!(
+                "expected return type {}, found none",
+                expected
+            ))),
+            (expected, actual) if differs(expected, actual) => Some(mismatch(format!(
+                "expected return type {}, found {}",
+                expected.as_deref().unwrap_or_default(),
+                actual.as_deref().unwrap_or_default()
+            ))),
+            _ => None,
+        }
+    }
+
+    /// Valida `T: Bound` para um tipo concreto (`Unknown`/genéricos passam).
+    fn check_bound(&mut self, ty: &str, bound: &str, param: &str, at: &core::Token) {
+        let span = Span::new(at.start, at.end, at.line, at.col);
+        if !self.traits.contains_key(bound) {
+            self.diags.push(Diagnostic::new(
+                DiagnosticKind::Type,
+                format!(
+                    "Unknown trait '{}' in bound of type parameter '{}'",
+                    bound, param
+                ),
+                span,
+            ));
+        } else if !self
+            .trait_impls
+            .get(ty)
+            .is_some_and(|traits| traits.contains(bound))
+        {
+            self.diags.push(Diagnostic::new(
+                DiagnosticKind::Type,
+                format!(
+                    "Type argument '{}' does not satisfy constraint '{}' for type parameter '{}'",
+                    ty, bound, param
+                ),
+                span,
+            ));
         }
     }
 
@@ -148,6 +430,8 @@ impl<'a> TypeInfer<'a> {
     }
 
     pub fn run(&mut self, program: &Program) -> Result<(), Vec<Diagnostic>> {
+        self.collect_traits(program);
+        self.type_decls = TypeDecls::collect(program);
         for stmt in program {
             self.visit_stmt(stmt);
         }
@@ -206,6 +490,16 @@ impl<'a> TypeInfer<'a> {
                 };
                 self.bind_pattern_type(pattern, &t);
             }
+            Stmt::Const { name, value, .. } => {
+                let t = self.infer_expr(value);
+                self.bind_pattern_type(&MatchPattern::Variable(name.clone()), &t);
+            }
+            Stmt::Assign { target, value, .. } => {
+                self.infer_expr(value);
+                if let Expr::FieldAccess { object, .. } = target {
+                    self.infer_expr(object);
+                }
+            }
             Stmt::Block { statements } => {
                 self.push_scope();
                 for s in statements {
@@ -213,6 +507,7 @@ impl<'a> TypeInfer<'a> {
                 }
                 self.pop_scope();
             }
+            Stmt::Defer { body, .. } => self.visit_stmt(body),
             Stmt::If {
                 condition,
                 then_branch,
@@ -251,18 +546,29 @@ impl<'a> TypeInfer<'a> {
                 self.visit_stmt(catch_branch);
                 self.pop_scope();
             }
-            Stmt::EnumDecl { name, variants } => {
+            Stmt::EnumDecl { name, variants, .. } => {
                 let mut map = HashMap::new();
                 for (v, params) in variants {
                     map.insert(v.lexeme.clone(), params.as_ref().map(|p| p.len()));
                 }
                 self.enums.insert(name.lexeme.clone(), map);
             }
+            Stmt::Return { value } => {
+                if let Some(v) = value {
+                    self.infer_expr(v);
+                }
+            }
+            Stmt::Yield { value, .. } => {
+                self.infer_expr(value);
+            }
             Stmt::StructDecl { .. }
-            | Stmt::Return { .. }
+            | Stmt::TypeAlias { .. }
+            | Stmt::Newtype { .. }
             | Stmt::Match { .. }
             | Stmt::ShellCommand { .. }
             | Stmt::Import { .. }
+            | Stmt::Break { .. }
+            | Stmt::Continue { .. }
             | Stmt::ComponentBlock { .. }
             | Stmt::QualifiedBinding { .. } => {}
             Stmt::Function {
@@ -273,13 +579,12 @@ impl<'a> TypeInfer<'a> {
                 body,
                 method_owner: _,
                 is_async: _,
+                public: _,
             } => {
-                // record simple top-level function for callsite simulation: store param names and body
-                let param_names: Vec<String> =
-                    params.iter().map(|p| p.name.lexeme.clone()).collect();
+                // record simple top-level function for callsite simulation: store params and body
                 self.functions.insert(
                     name.lexeme.clone(),
-                    (param_names, body.clone(), type_params.clone()),
+                    (params.clone(), body.clone(), type_params.clone()),
                 );
             }
             Stmt::Performant { statements } => {
@@ -306,27 +611,57 @@ impl<'a> TypeInfer<'a> {
                     }
                 }
             }
-            Stmt::While { condition, body } => {
+            Stmt::While {
+                condition, body, ..
+            } => {
                 self.infer_expr(condition);
                 self.visit_stmt(body);
             }
             Stmt::For {
                 element,
+                pattern,
                 iterator,
                 body,
+                ..
             } => {
-                self.infer_expr(iterator);
+                let elem_ty = crate::iter_element_type(&self.infer_expr(iterator));
                 self.push_scope();
-                self.declare_var(&element.lexeme);
-                // type of element is inferred automatically at runtime in this version,
-                // but we might want to infer `element` type based on `iterator` array inner type later
+                match pattern {
+                    Some(p) => self.bind_pattern_type(p, &elem_ty),
+                    None => self.bind_pattern_type(
+                        &core::ast::MatchPattern::Variable(element.clone()),
+                        &elem_ty,
+                    ),
+                }
                 self.visit_stmt(body);
                 self.pop_scope();
             }
-            Stmt::ImplBlock { methods, .. } => {
+            Stmt::ImplBlock {
+                type_name,
+                trait_name,
+                methods,
+            } => {
                 for method in methods {
                     self.visit_stmt(method);
                 }
+                if let Some(t) = trait_name {
+                    self.check_trait_impl(t, type_name, methods);
+                }
+            }
+            Stmt::TraitDecl { methods, .. } => {
+                // Só os defaults têm corpo; parâmetros ficam Unknown, como em lambdas.
+                for method in methods {
+                    if let Some(body) = &method.body {
+                        self.push_scope();
+                        for p in &method.params {
+                            self.record_var_binding(&p.name.lexeme);
+                            self.tenv.set_var(&p.name.lexeme, Type::Unknown);
+                            self.declare_var(&p.name.lexeme);
+                        }
+                        self.visit_stmt(body);
+                        self.pop_scope();
+                    }
+                }
             }
         }
     }
@@ -351,9 +686,42 @@ impl<'a> TypeInfer<'a> {
                     } else {
                         // Error on destructuring mismatch happens later during type check phase
                     }
+                } else if *ty == Type::Unknown {
+                    // Ex.: `for (k, v) in m` sobre um Map; as variáveis existem, sem tipo.
+                    for p in patterns {
+                        self.bind_pattern_type(p, &Type::Unknown);
+                    }
                 }
             }
-            // For now only variable and tuple patterns make sense in `let` expressions.
+            core::ast::MatchPattern::Array {
+                prefix,
+                rest,
+                suffix,
+            } => {
+                let (elem, array) = match ty {
+                    Type::Array(t) => ((**t).clone(), ty.clone()),
+                    _ => (Type::Unknown, Type::Array(Box::new(Type::Unknown))),
+                };
+                for p in prefix.iter().chain(suffix) {
+                    self.bind_pattern_type(p, &elem);
+                }
+                if let Some(Some(name)) = rest {
+                    self.bind_pattern_type(
+                        &core::ast::MatchPattern::Variable(name.clone()),
+                        &array,
+                    );
+                }
+            }
+            core::ast::MatchPattern::Struct { fields, .. } => {
+                for (_, p) in fields {
+                    self.bind_pattern_type(p, &Type::Unknown);
+                }
+            }
+            core::ast::MatchPattern::At { name, pattern } => {
+                self.bind_pattern_type(&core::ast::MatchPattern::Variable(name.clone()), ty);
+                self.bind_pattern_type(pattern, ty);
+            }
+            // Literais, intervalos e alternativas nao introduzem nomes em `let`.
             _ => {}
         }
     }
@@ -384,6 +752,13 @@ impl<'a> TypeInfer<'a> {
                     Span::new(0,0,0,0),
                 ));
             }
+            Yield { keyword, .. } => {
+                self.diags.push(Diagnostic::new(
+                    DiagnosticKind::Type,
+                    "`yield` is not allowed inside `performant` blocks: a suspended generator would outlive the arena".to_string(),
+                    Span::new(keyword.start, keyword.end, keyword.line, keyword.col),
+                ));
+            }
             Function { name, .. } => {
                 self.diags.push(Diagnostic::new(
                     DiagnosticKind::Type,
@@ -440,6 +815,15 @@ impl<'a> TypeInfer<'a> {
                     self.declare_var(n);
                 }
 
+                // Um lambda guardado em binding pode sobreviver à arena, como um `func` aninhado.
+                // Passá-lo direto como argumento (`map(xs, |x| ...)`) continua permitido.
+                if let Expr::Lambda { keyword, .. } = initializer {
+                    self.diags.push(Diagnostic::new(
+                        DiagnosticKind::Type,
+                        "Lambda bound inside `performant` blocks is not allowed: closures may capture arena values and escape".to_string(),
+                        Span::new(keyword.start, keyword.end, keyword.line, keyword.col),
+                    ));
+                }
                 // Se inicializador é potencialmente composto, emitir aviso conservador.
                 // Suprimir para bindings que começam com '_' (convencionalmente temporários).
                 match initializer {
@@ -488,6 +872,21 @@ impl<'a> TypeInfer<'a> {
                     }
                 }
             }
+            Assign { target, value, .. } => {
+                // Writing an outer binding from inside the arena is the direct form of the
+                // escape that the `let` shadowing rule above approximates.
+                if let Expr::Variable { name } = target
+                    && outer_vars.contains(&name.lexeme)
+                {
+                    self.diags.push(Diagnostic::new(
+                        DiagnosticKind::Type,
+                        format!("Assignment to outer-scope variable '{}' inside `performant` is not allowed: may extend lifetime of arena values", name.lexeme),
+                        Span::new(name.start, name.end, name.line, name.col),
+                    ));
+                }
+                let current_locals = self.visible_vars();
+                self.expr_uses_outer_vars(value, &current_locals, outer_vars);
+            }
             Block { statements } => {
                 // New block introduces nested lexical scope within performant
                 self.push_scope();
@@ -506,11 +905,13 @@ impl<'a> TypeInfer<'a> {
                     self.check_performant_stmt(e, outer_vars);
                 }
             }
-            Match { expr: _, cases } => {
+            Match { cases, .. } => {
                 for (_pat, _guard, body) in cases {
                     self.check_performant_stmt(body, outer_vars);
                 }
             }
+            // Roda antes de a arena ser finalizada: mesmas regras do bloco.
+            Defer { body, .. } => self.check_performant_stmt(body, outer_vars),
             Performant { statements } => {
                 // Nested performant: create a fresh scope and recurse
                 self.push_scope();
@@ -526,7 +927,9 @@ impl<'a> TypeInfer<'a> {
                     Span::new(0, 0, 0, 0),
                 ));
             }
-            While { condition, body } => {
+            While {
+                condition, body, ..
+            } => {
                 // conservatively check conditions and body
                 self.expr_uses_outer_vars(condition, &self.visible_vars(), outer_vars); // will just walk
                 self.check_performant_stmt(body, outer_vars);
@@ -535,6 +938,7 @@ impl<'a> TypeInfer<'a> {
                 element: _,
                 iterator,
                 body,
+                ..
             } => {
                 self.expr_uses_outer_vars(iterator, &self.visible_vars(), outer_vars);
                 self.push_scope(); // lexical scope for loop element
@@ -543,8 +947,13 @@ impl<'a> TypeInfer<'a> {
             }
             StructDecl { .. }
             | EnumDecl { .. }
+            | TypeAlias { .. }
+            | Newtype { .. }
+            | Const { .. }
             | Expression(_)
             | Import { .. }
+            | Break { .. }
+            | Continue { .. }
             | ShellCommand { .. } => { /* allowed */ }
             ImplBlock { .. } => {
                 self.diags.push(diagnostics::Diagnostic::new(
@@ -553,6 +962,13 @@ impl<'a> TypeInfer<'a> {
                     diagnostics::Span::new(0, 0, 0, 0),
                 ));
             }
+            TraitDecl { name, .. } => {
+                self.diags.push(diagnostics::Diagnostic::new(
+                    diagnostics::DiagnosticKind::Type,
+                    "trait declaration is not allowed inside `performant` blocks".to_string(),
+                    diagnostics::Span::new(name.start, name.end, name.line, name.col),
+                ));
+            }
             ComponentBlock { .. } | QualifiedBinding { .. } => { /* allowed — compile-time only */
             }
         }
@@ -587,7 +1003,7 @@ impl<'a> TypeInfer<'a> {
                 found.extend(self.expr_uses_outer_vars(left, current_locals, outer_vars));
                 found.extend(self.expr_uses_outer_vars(right, current_locals, outer_vars));
             }
-            Logical { left, right, .. } => {
+            Logical { left, right, .. } | Coalesce { left, right, .. } => {
                 found.extend(self.expr_uses_outer_vars(left, current_locals, outer_vars));
                 found.extend(self.expr_uses_outer_vars(right, current_locals, outer_vars));
             }
@@ -601,10 +1017,13 @@ impl<'a> TypeInfer<'a> {
                     found.extend(self.expr_uses_outer_vars(a, current_locals, outer_vars));
                 }
             }
-            StructInit { fields, .. } => {
+            StructInit { fields, base, .. } => {
                 for (_k, v) in fields {
                     found.extend(self.expr_uses_outer_vars(v, current_locals, outer_vars));
                 }
+                if let Some(b) = base {
+                    found.extend(self.expr_uses_outer_vars(b, current_locals, outer_vars));
+                }
             }
             EnumInit { values, .. } => {
                 for v in values {
@@ -614,8 +1033,36 @@ impl<'a> TypeInfer<'a> {
             FieldAccess { object, .. } => {
                 found.extend(self.expr_uses_outer_vars(object, current_locals, outer_vars));
             }
-            Weak(inner) | Unowned(inner) | WeakUpgrade(inner) | UnownedAccess(inner)
-            | Try(inner) => {
+            OptionalChain {
+                object, arguments, ..
+            } => {
+                found.extend(self.expr_uses_outer_vars(object, current_locals, outer_vars));
+                for a in arguments.iter().flatten() {
+                    found.extend(self.expr_uses_outer_vars(a, current_locals, outer_vars));
+                }
+            }
+            Index { object, index, .. } => {
+                found.extend(self.expr_uses_outer_vars(object, current_locals, outer_vars));
+                found.extend(self.expr_uses_outer_vars(index, current_locals, outer_vars));
+            }
+            Range { start, end, .. } => {
+                found.extend(self.expr_uses_outer_vars(start, current_locals, outer_vars));
+                found.extend(self.expr_uses_outer_vars(end, current_locals, outer_vars));
+            }
+            Slice {
+                object, start, end, ..
+            } => {
+                found.extend(self.expr_uses_outer_vars(object, current_locals, outer_vars));
+                for bound in [start, end].into_iter().flatten() {
+                    found.extend(self.expr_uses_outer_vars(bound, current_locals, outer_vars));
+                }
+            }
+            Weak(inner)
+            | Unowned(inner)
+            | WeakUpgrade(inner)
+            | UnownedAccess(inner)
+            | Try(inner)
+            | Await { value: inner, .. } => {
                 found.extend(self.expr_uses_outer_vars(inner, current_locals, outer_vars));
             }
             Array(elements) => {
@@ -651,6 +1098,8 @@ impl<'a> TypeInfer<'a> {
                 // (body is a sequence of statements; deeper analysis can be added later)
             }
             Expr::Template(_) => {}
+            // Lambdas inside `performant` are rejected by `check_performant_stmt`.
+            Expr::Lambda { .. } => {}
             Literal(_) => {}
         }
         // Deduplicate
@@ -680,14 +1129,17 @@ impl<'a> TypeInfer<'a> {
             },
             Array(elements) => elements.iter().all(|e| self.is_send_safe_expr(e)),
             Tuple(elements) => elements.iter().all(|e| self.is_send_safe_expr(e)),
-            StructInit { fields, .. } => fields.iter().all(|(_n, e)| self.is_send_safe_expr(e)),
+            StructInit { fields, base, .. } => {
+                fields.iter().all(|(_n, e)| self.is_send_safe_expr(e))
+                    && base.as_deref().is_none_or(|b| self.is_send_safe_expr(b))
+            }
             EnumInit { values, .. } => values.iter().all(|e| self.is_send_safe_expr(e)),
             Grouping { expression } => self.is_send_safe_expr(expression),
             Unary { right, .. } => self.is_send_safe_expr(right),
             Binary { left, right, .. } => {
                 self.is_send_safe_expr(left) && self.is_send_safe_expr(right)
             }
-            Logical { left, right, .. } => {
+            Logical { left, right, .. } | Coalesce { left, right, .. } => {
                 self.is_send_safe_expr(left) && self.is_send_safe_expr(right)
             }
             Call {
@@ -708,6 +1160,22 @@ impl<'a> TypeInfer<'a> {
                 }
             }
             FieldAccess { object, .. } => self.is_send_safe_expr(object),
+            OptionalChain {
+                object, arguments, ..
+            } => {
+                self.is_send_safe_expr(object)
+                    && arguments
+                        .iter()
+                        .flatten()
+                        .all(|a| self.is_send_safe_expr(a))
+            }
+            Index { object, index, .. } => {
+                self.is_send_safe_expr(object) && self.is_send_safe_expr(index)
+            }
+            Slice { object, .. } => self.is_send_safe_expr(object),
+            Range { start, end, .. } => {
+                self.is_send_safe_expr(start) && self.is_send_safe_expr(end)
+            }
             InterpolatedString(parts) => parts.iter().all(|p| match p {
                 InterpolatedPart::Literal(_) => true,
                 InterpolatedPart::Expr { expr, .. } => self.is_send_safe_expr(expr),
@@ -718,8 +1186,10 @@ impl<'a> TypeInfer<'a> {
             | Unowned(_)
             | WeakUpgrade(_)
             | UnownedAccess(_)
+            | Await { .. }
             | SpawnActor { .. }
-            | Template(_) => false,
+            | Template(_)
+            | Lambda { .. } => false,
         }
     }
 
@@ -733,12 +1203,59 @@ impl<'a> TypeInfer<'a> {
         }
     }
 
+    /// Bitwise operators only accept `Int`. Unknown operands stay silent, as
+    /// for the arithmetic operators.
+    fn require_int_operands(&mut self, operator: &core::Token, operands: &[&Type]) {
+        if operands
+            .iter()
+            .all(|t| matches!(t, Type::Int | Type::Unknown))
+        {
+            return;
+        }
+        let found: Vec<String> = operands.iter().map(|t| t.name()).collect();
+        let expected = if operands.len() == 1 {
+            "an Int operand"
+        } else {
+            "Int operands"
+        };
+        self.diags.push(Diagnostic::new(
+            DiagnosticKind::Type,
+            format!(
+                "Operator '{}' requires {}, found {}",
+                operator.lexeme,
+                expected,
+                found.join(" and ")
+            ),
+            Span::new(operator.start, operator.end, operator.line, operator.col),
+        ));
+    }
+
+    /// Return type of `object.method(..)` when `method` is a builtin method of
+    /// the (already inferred) receiver type; see `core::methods`.
+    fn builtin_method_type(&self, object: &Expr, method: &str) -> Type {
+        let Some(receiver) = self.tenv.get(object) else {
+            return Type::Unknown;
+        };
+        methods::type_kind(receiver)
+            .and_then(|kind| methods::lookup(kind, method))
+            .map(|m| m.returns.to_type(receiver))
+            .unwrap_or(Type::Unknown)
+    }
+
     fn infer_expr(&mut self, expr: &Expr) -> Type {
         use Expr::*;
         let t = match expr {
             Literal(v) => value_type(v),
             Grouping { expression } => self.infer_expr(expression),
-            Unary { right, .. } => self.infer_expr(right),
+            Unary { operator, right } => {
+                let rt = self.infer_expr(right);
+                if operator.lexeme == "~" {
+                    self.require_int_operands(operator, &[&rt]);
+                    Type::Int
+                } else {
+                    rt
+                }
+            }
             Binary {
                 left,
                 operator,
@@ -747,12 +1264,30 @@ impl<'a> TypeInfer<'a> {
                 let lt = self.infer_expr(left);
                 let rt = self.infer_expr(right);
 
+                // Overloaded operators return whatever the method returns;
+                // `TypeChecker` tracks that type.
+                if let Some(method) = binary_method(&operator.token_type)
+                    && self.overloads(&lt, method)
+                {
+                    let t = if matches!(method, "eq" | "cmp") {
+                        Type::Bool
+                    } else {
+                        Type::Unknown
+                    };
+                    self.tenv.set(expr, t.clone());
+                    return t;
+                }
+
                 // The operator decides the result type before the operands do.
                 // Matching on the operand pair alone typed `a < b` over two
                 // Ints as `Int`, which is what every comparison in the language
                 // used to infer.
                 match operator.lexeme.as_str() {
                     "==" | "!=" | "<" | "<=" | ">" | ">=" => Type::Bool,
+                    "&" | "|" | "^" | "<<" | ">>" => {
+                        self.require_int_operands(operator, &[&lt, &rt]);
+                        Type::Int
+                    }
                     // `+` doubles as concatenation: a String on either side
                     // widens the result, matching `TypeChecker`.
                     "+" if matches!(lt, Type::String) || matches!(rt, Type::String) => Type::String,
@@ -864,31 +1399,32 @@ impl<'a> TypeInfer<'a> {
                 if let Expr::Variable { name } = &**callee
                     && let Some(entry) = self.functions.get(&name.lexeme).cloned()
                 {
-                    let (param_names, body, type_params) = entry;
+                    let (params, body, type_params) = entry;
 
-                    // Check simple generic constraints
-                    if let (Some(t_args), Some(t_params)) = (type_args, &type_params)
-                        && t_args.len() == t_params.len()
-                    {
-                        for (i, t_arg) in t_args.iter().enumerate() {
-                            if let Some(bound) = &t_params[i].1 {
-                                let ok = match bound.as_str() {
-                                    "Numeric" => t_arg == "Int" || t_arg == "Float",
-                                    "Eq" | "Hash" => {
-                                        t_arg == "Int"
-                                            || t_arg == "Float"
-                                            || t_arg == "String"
-                                            || t_arg == "Bool"
-                                    }
-                                    _ => true,
-                                };
-                                if !ok {
-                                    self.diags.push(Diagnostic::new(
-                                                DiagnosticKind::Type,
-                                                format!("Type argument '{}' does not satisfy constraint '{}' for type parameter '{}'", t_arg, bound, t_params[i].0),
-                                                Span::new(name.start, name.end, name.line, name.col),
-                                            ));
+                    // Generic bounds: explicit type args, or the inferred type of the
+                    // first argument whose parameter is annotated with the type param.
+                    if let Some(t_params) = &type_params {
+                        for (i, (tname, bound)) in t_params.iter().enumerate() {
+                            let Some(bound) = bound else { continue };
+                            let concrete = match type_args {
+                                Some(t_args) if t_args.len() == t_params.len() => {
+                                    Some(self.type_decls.expand(&t_args[i]))
                                 }
+                                Some(_) => None,
+                                None => params
+                                    .iter()
+                                    .position(|p| p.ty.as_deref() == Some(tname.as_str()))
+                                    .and_then(|idx| arguments.get(idx))
+                                    .map(|arg| self.infer_expr(arg))
+                                    .filter(|t| !matches!(t, Type::Unknown | Type::GenericParam(_)))
+                                    .map(|t| match t {
+                                        // Bounds são nominais: `Option<Int>` implementa o que `Option` implementa.
+                                        Type::EnumInstance(n, _) => n,
+                                        other => other.name(),
+                                    }),
+                            };
+                            if let Some(concrete) = concrete {
+                                self.check_bound(&concrete, bound, tname, name);
                             }
                         }
                     }
@@ -897,12 +1433,12 @@ impl<'a> TypeInfer<'a> {
                     if self.visiting_functions.insert(name.lexeme.clone()) {
                         // create a temporary scope for params
                         self.push_scope();
-                        for (i, p) in param_names.iter().enumerate() {
+                        for (i, p) in params.iter().enumerate() {
                             if i < arguments.len() {
                                 let arg = &arguments[i];
                                 let ty = self.infer_expr(arg);
-                                self.record_var_binding(p);
-                                self.tenv.set_var(p, ty);
+                                self.record_var_binding(&p.name.lexeme);
+                                self.tenv.set_var(&p.name.lexeme, ty);
                             }
                         }
                         // Optionally infer the body to propagate types inside function (cheap simulation)
@@ -915,7 +1451,12 @@ impl<'a> TypeInfer<'a> {
                 for a in arguments {
                     self.infer_expr(a);
                 }
-                Type::Unknown
+                match &**callee {
+                    FieldAccess { object, field } => {
+                        self.builtin_method_type(object, &field.lexeme)
+                    }
+                    _ => Type::Unknown,
+                }
             }
             StructInit { name, .. } => Type::Struct(name.lexeme.clone()),
             EnumInit {
@@ -1010,7 +1551,78 @@ impl<'a> TypeInfer<'a> {
                     }
                 }
             }
-            FieldAccess { object, .. } => self.infer_expr(object),
+            // Field types are not tracked here; the object's type is not the field's.
+            FieldAccess { object, .. } => {
+                self.infer_expr(object);
+                Type::Unknown
+            }
+            // `TypeChecker` tracks the resulting `Option<T>`.
+            OptionalChain {
+                object, arguments, ..
+            } => {
+                self.infer_expr(object);
+                for a in arguments.iter().flatten() {
+                    self.infer_expr(a);
+                }
+                Type::Unknown
+            }
+            Coalesce { left, right, .. } => {
+                let lt = self.infer_expr(left);
+                let rt = self.infer_expr(right);
+                match lt {
+                    Type::EnumInstance(name, params) if name == "Option" => params
+                        .into_iter()
+                        .next()
+                        .filter(|t| !matches!(t, Type::Unknown))
+                        .unwrap_or(rt),
+                    Type::Enum(name) if name == "Option" => rt,
+                    Type::None | Type::Unknown => rt,
+                    other => other,
+                }
+            }
+            Index {
+                object,
+                index,
+                bracket,
+            } => {
+                let container = self.infer_expr(object);
+                let index_ty = self.infer_expr(index);
+                let positional = matches!(
+                    container,
+                    Type::Array(_) | Type::String | Type::Buffer | Type::Tuple(_)
+                );
+                if positional && !matches!(index_ty, Type::Int | Type::Unknown) {
+                    self.diags.push(Diagnostic::new(
+                        DiagnosticKind::Type,
+                        format!(
+                            "Index into {} must be Int, found {}",
+                            container.name(),
+                            index_ty.name()
+                        ),
+                        Span::new(bracket.start, bracket.end, bracket.line, bracket.col),
+                    ));
+                }
+                crate::indexed_type(&container, crate::literal_int(index))
+            }
+            Range {
+                start,
+                end,
+                operator,
+                ..
+            } => {
+                let st = self.infer_expr(start);
+                let et = self.infer_expr(end);
+                self.require_int_operands(operator, &[&st, &et]);
+                Type::Struct("Range".to_string())
+            }
+            Slice {
+                object, start, end, ..
+            } => {
+                for bound in [start, end].into_iter().flatten() {
+                    self.infer_expr(bound);
+                }
+                crate::sliced_type(&self.infer_expr(object))
+            }
             Try(inner) => self.infer_expr(inner), // legado
             Weak(inner) => {
                 self.infer_expr(inner);
@@ -1028,6 +1640,10 @@ impl<'a> TypeInfer<'a> {
                 self.infer_expr(inner);
                 Type::Unknown
             }
+            Await { value, .. } => {
+                self.infer_expr(value);
+                Type::Unknown
+            }
             Array(elements) => {
                 if let Some(first) = elements.first() {
                     Type::Array(Box::new(self.infer_expr(first)))
@@ -1045,9 +1661,32 @@ impl<'a> TypeInfer<'a> {
                 let _ = body; // body not deeply type-checked here
                 Type::Unknown
             }
-            Cast { target_type, .. } => Type::Struct(target_type.clone()),
+            Cast { target_type, .. } => {
+                let target = self.type_decls.expand(target_type);
+                match target.as_str() {
+                    "Int" => Type::Int,
+                    "Float" => Type::Float,
+                    "Bool" => Type::Bool,
+                    "String" => Type::String,
+                    // Newtypes are `TypeChecker`'s business.
+                    _ if self.type_decls.newtypes.contains_key(&target) => Type::Unknown,
+                    _ => Type::Struct(target),
+                }
+            }
             InterpolatedString(_) => Type::String,
             Template(_) => Type::Unknown,
+            Lambda { params, body, .. } => {
+                // Parâmetros ficam Unknown; o corpo é visitado uma vez, no próprio escopo.
+                self.push_scope();
+                for p in params {
+                    self.record_var_binding(&p.name.lexeme);
+                    self.tenv.set_var(&p.name.lexeme, Type::Unknown);
+                    self.declare_var(&p.name.lexeme);
+                }
+                self.visit_stmt(body);
+                self.pop_scope();
+                Type::Function(vec![Type::Unknown; params.len()], Box::new(Type::Unknown))
+            }
         };
         self.tenv.set(expr, t.clone());
         t
@@ -1105,6 +1744,7 @@ mod tests {
         let let_outer = Stmt::Let {
             pattern: core::ast::MatchPattern::Variable(name.clone()),
             ty: None,
+            mutable: false,
             initializer: Expr::Literal(core::ast::ArtValue::Int(1)),
         };
         let inner_name = name.clone();
@@ -1112,6 +1752,7 @@ mod tests {
             statements: vec![Stmt::Let {
                 pattern: core::ast::MatchPattern::Variable(inner_name.clone()),
                 ty: None,
+                mutable: false,
                 initializer: Expr::Literal(core::ast::ArtValue::Int(2)),
             }],
         };
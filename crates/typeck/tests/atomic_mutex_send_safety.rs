@@ -9,6 +9,7 @@ fn actor_send_non_send_payload_emits_diag() {
     let call_arr = Stmt::Let {
         pattern: core::ast::MatchPattern::Variable(Token::dummy("arr")),
         ty: None,
+        mutable: false,
         initializer: Expr::Array(vec![Expr::Literal(ArtValue::Int(1))]),
     };
     let send_call = Stmt::Expression(Expr::Call {
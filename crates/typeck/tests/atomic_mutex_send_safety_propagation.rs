@@ -9,11 +9,13 @@ fn actor_send_propagation_allows_forwarded_array() {
     let let_a = Stmt::Let {
         pattern: core::ast::MatchPattern::Variable(Token::dummy("a")),
         ty: None,
+        mutable: false,
         initializer: Expr::Array(vec![Expr::Literal(ArtValue::Int(1))]),
     };
     let let_b = Stmt::Let {
         pattern: core::ast::MatchPattern::Variable(Token::dummy("b")),
         ty: None,
+        mutable: false,
         initializer: Expr::Variable {
             name: Token::dummy("a"),
         },
@@ -0,0 +1,47 @@
+use lexer::Lexer;
+use parser::Parser;
+use typeck::TypeChecker;
+
+fn type_errors(src: &str) -> Vec<String> {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    TypeChecker::new()
+        .check(&program)
+        .iter()
+        .map(|d| d.message.clone())
+        .collect()
+}
+
+#[test]
+fn builtin_methods_have_table_return_types() {
+    let src = r#"
+let s = "a,b";
+let parts: [String] = s.split(",");
+let n: Int = parts.len();
+let joined: String = parts.join("-");
+let ok: Bool = s.contains("a");
+let abs: Int = -3.abs();
+let bad: Int = s.split(",");
+"#;
+    assert_eq!(
+        type_errors(src),
+        vec!["type mismatch: declared as Int, initializer has type [String]"]
+    );
+}
+
+#[test]
+fn builtin_method_arity_is_checked() {
+    let src = r#"
+let s = "abc";
+let a = s.contains("a", "b");
+let b = s.len(1);
+"#;
+    assert_eq!(
+        type_errors(src),
+        vec![
+            "method 'String.contains' takes 1 argument, found 2",
+            "method 'String.len' takes 0 arguments, found 1",
+        ]
+    );
+}
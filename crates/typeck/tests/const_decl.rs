@@ -0,0 +1,55 @@
+use lexer::Lexer;
+use parser::Parser;
+use typeck::TypeChecker;
+
+fn type_errors(src: &str) -> Vec<String> {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    TypeChecker::new()
+        .check(&program)
+        .iter()
+        .map(|d| d.message.clone())
+        .collect()
+}
+
+#[test]
+fn well_typed_constants_pass() {
+    let errs = type_errors(
+        "const N: Int = 4 * 8;\nconst NAME: String = \"a\" + \"b\";\nconst ON: Bool = N > 10;\nlet m = N + 1;",
+    );
+    assert!(errs.is_empty(), "{:?}", errs);
+}
+
+#[test]
+fn constant_errors_are_type_errors() {
+    let cases = [
+        (
+            "const N: String = 1;",
+            "type mismatch: constant declared as String, value has type Int",
+        ),
+        (
+            "func g() { return 1; }\nconst N: Int = g();",
+            "'N' is not a compile-time constant",
+        ),
+        ("const N: Int = 1;\nN = 2;", "cannot assign to constant 'N'"),
+        (
+            "const N: Int = 1;\nfunc f(N: Int) { return N; }",
+            "'N' shadows a constant",
+        ),
+        ("const N: Int = 1;\nlet N = 2;", "'N' shadows a constant"),
+        (
+            "const N: Int = 1;\nconst N: Int = 2;",
+            "constant 'N' is already declared",
+        ),
+    ];
+    for (src, expected) in cases {
+        let errs = type_errors(src);
+        assert!(
+            errs.iter().any(|m| m.contains(expected)),
+            "{}: {:?}",
+            src,
+            errs
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use lexer::Lexer;
+use parser::Parser;
+use typeck::TypeChecker;
+use typeck::type_infer::{TypeEnv, TypeInfer};
+
+fn parse(src: &str) -> Vec<core::ast::Stmt> {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    program
+}
+
+fn type_errors(src: &str) -> Vec<String> {
+    TypeChecker::new()
+        .check(&parse(src))
+        .iter()
+        .map(|d| d.message.clone())
+        .collect()
+}
+
+#[test]
+fn derived_members_must_derive_the_same_behavior() {
+    let src = "struct Point { x: Int }\n\
+               @derive(Eq, Debug)\nstruct Line { a: Point, tags: [String] }\n\
+               @derive(Debug)\nenum E { A(Point), B((Int, Point)), C(Option<Int>) }";
+    assert_eq!(
+        type_errors(src),
+        vec![
+            "field 'Line.a' has type 'Point', which does not derive Eq",
+            "field 'Line.a' has type 'Point', which does not derive Debug",
+            "payload of 'E.A' has type 'Point', which does not derive Debug",
+            "payload of 'E.B' has type 'Point', which does not derive Debug",
+        ]
+    );
+}
+
+#[test]
+fn comparisons_and_sort_need_an_order() {
+    let src = "struct P { x: Int }\n\
+               @derive(Eq, Ord)\nstruct Q { x: Int }\n\
+               let p = P { x: 1 };\nlet q = Q { x: 1 };\n\
+               let a = q < q;\nlet b = [q].sort();\nlet c = p < p;\nlet d = [p].sort();";
+    assert_eq!(
+        type_errors(src),
+        vec![
+            "type 'P' is not ordered: add @derive(Ord) or define 'cmp'",
+            "type 'P' is not ordered: add @derive(Ord) or define 'cmp'",
+        ]
+    );
+}
+
+#[test]
+fn derived_clone_keeps_the_type_and_derives_satisfy_bounds() {
+    let src = "@derive(Eq, Hash, Clone)\nstruct Key { id: Int }\n\
+               func same<T: Eq>(a: T, b: T) -> Bool { return a == b; }\n\
+               let k = Key { id: 1 };\nlet c: Key = k.clone();\nlet s = same(k, c);";
+    assert!(type_errors(src).is_empty(), "{:?}", type_errors(src));
+    let mut tenv = TypeEnv::new();
+    let inferred = TypeInfer::new(&mut tenv).run(&parse(src));
+    assert!(inferred.is_ok(), "{:?}", inferred);
+}
@@ -0,0 +1,138 @@
+use diagnostics::DiagnosticKind;
+use lexer::Lexer;
+use parser::Parser;
+use typeck::TypeChecker;
+
+/// `(kind, message)` of every diagnostic about `match` arms.
+fn match_diags(src: &str) -> Vec<(DiagnosticKind, String)> {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    TypeChecker::new()
+        .check(&program)
+        .iter()
+        .filter(|d| d.message.contains("match"))
+        .map(|d| (d.kind.clone(), d.message.clone()))
+        .collect()
+}
+
+fn missing(src: &str) -> Vec<String> {
+    match_diags(src)
+        .into_iter()
+        .filter(|(k, _)| *k == DiagnosticKind::Type)
+        .map(|(_, m)| m)
+        .collect()
+}
+
+const COLOR: &str = "enum Color { Red, Green, Rgb(Int, Int, Int) }\nlet c = Color.Red;\n";
+
+#[test]
+fn missing_enum_variants_are_reported_with_witnesses() {
+    assert_eq!(
+        missing(&format!(
+            "{}match c {{ case .Red: println(1) case .Rgb(0, _, _): println(2) }}",
+            COLOR
+        )),
+        vec!["non-exhaustive match: patterns `.Green` and `.Rgb(_, _, _)` not covered"]
+    );
+    assert!(
+        missing(&format!(
+            "{}match c {{ case .Red | .Green: println(1) case Color.Rgb(r, g, b): println(r) }}",
+            COLOR
+        ))
+        .is_empty()
+    );
+    assert!(
+        missing(&format!(
+            "{}match c {{ case .Red: println(1) case _: println(2) }}",
+            COLOR
+        ))
+        .is_empty()
+    );
+}
+
+#[test]
+fn bool_option_result_and_tuples() {
+    assert_eq!(
+        missing("let b = true;\nmatch b { case true: println(1) }"),
+        vec!["non-exhaustive match: pattern `false` not covered"]
+    );
+    assert_eq!(
+        missing("let o = Option.Some(1);\nmatch o { case .None: println(0) }"),
+        vec!["non-exhaustive match: pattern `.Some(_)` not covered"]
+    );
+    assert!(
+        missing(
+            "let r = Result.Ok(1);\nmatch r { case .Ok(v): println(v) case .Err(e): println(e) }"
+        )
+        .is_empty()
+    );
+    assert_eq!(
+        missing(&format!(
+            "{}let b = true;\nmatch (b, c) {{ case (true, _): println(1) case (false, .Red): println(2) }}",
+            COLOR
+        )),
+        vec![
+            "non-exhaustive match: patterns `(false, .Green)` and `(false, .Rgb(_, _, _))` not covered"
+        ]
+    );
+}
+
+#[test]
+fn guarded_arms_do_not_count() {
+    assert_eq!(
+        missing(
+            "let o = Option.Some(1);\nmatch o { case .Some(x) if x > 0: println(x) case .None: println(0) }"
+        ),
+        vec!["non-exhaustive match: pattern `.Some(_)` not covered"]
+    );
+}
+
+#[test]
+fn matches_over_open_types_are_not_reported() {
+    // Ints, strings and variants of enums we cannot see have no finite set of cases.
+    assert!(missing("let n = 3;\nmatch n { case 1: println(1) case 2: println(2) }").is_empty());
+    assert!(missing("let v = x;\nmatch v { case .Unknown: println(1) }").is_empty());
+}
+
+#[test]
+fn unreachable_arms_are_warned() {
+    let diags = match_diags(&format!(
+        "{}match c {{ case _: println(0) case .Red: println(1) }}\nmatch c {{ case .Red | .Green: println(1) case .Green: println(2) case .Rgb(x, _, _) if x > 0: println(3) case .Rgb(_, _, _): println(4) }}\nlet n = 1;\nmatch n {{ case 1: println(1) case 1: println(2) case _: println(3) }}",
+        COLOR
+    ));
+    assert_eq!(
+        diags,
+        vec![
+            (
+                DiagnosticKind::Lint,
+                "unreachable match arm 2: earlier arms already cover every value it matches"
+                    .to_string()
+            ),
+            (
+                DiagnosticKind::Lint,
+                "unreachable match arm 2: earlier arms already cover every value it matches"
+                    .to_string()
+            ),
+            (
+                DiagnosticKind::Lint,
+                "unreachable match arm 2: earlier arms already cover every value it matches"
+                    .to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn literal_scrutinee_gives_the_diagnostic_its_position() {
+    let src = "let n = 1;\nmatch true { case true: println(n) }\nmatch (n, false) { case (_, true): println(n) }";
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, _) = Parser::new(tokens).parse();
+    let spans: Vec<(usize, usize)> = TypeChecker::new()
+        .check(&program)
+        .iter()
+        .filter(|d| d.message.starts_with("non-exhaustive match"))
+        .map(|d| (d.span.line, d.span.col))
+        .collect();
+    assert_eq!(spans, vec![(2, 7), (3, 7)]);
+}
@@ -0,0 +1,83 @@
+use core::types::Type;
+use lexer::Lexer;
+use parser::Parser;
+use typeck::TypeChecker;
+use typeck::type_infer::{TypeEnv, TypeInfer};
+
+const MONEY: &str = r#"
+struct Money { cents: Int }
+impl Money {
+    func add(self, o: Money) -> Money { return self; }
+    func eq(self, o: Money) -> Bool { return self.cents == o.cents; }
+    func cmp(self, o: Money) -> Int { return self.cents - o.cents; }
+    func neg(self) -> Money { return self; }
+    func to_string(self) -> String { return "m"; }
+}
+let a = Money { cents: 1 };
+let b = Money { cents: 2 };
+"#;
+
+fn parse(src: &str) -> Vec<core::ast::Stmt> {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    program
+}
+
+fn type_errors(src: &str) -> Vec<String> {
+    TypeChecker::new()
+        .check(&parse(src))
+        .iter()
+        .map(|d| d.message.clone())
+        .collect()
+}
+
+#[test]
+fn overloaded_operators_take_the_method_types() {
+    let src = format!(
+        "{}let sum: Money = a + b;\nlet same: Bool = a == b;\nlet less: Bool = a < b;\nlet neg: Money = -a;\nlet bad: Int = a + b;",
+        MONEY
+    );
+    assert_eq!(
+        type_errors(&src),
+        vec!["type mismatch: declared as Int, initializer has type Money"]
+    );
+
+    // The runtime-facing pass accepts instances as operands once the type
+    // overloads the operator.
+    let program = parse(&format!("{}let c = a + b;\nlet d = a >= b;", MONEY));
+    let mut tenv = TypeEnv::new();
+    let mut infer = TypeInfer::new(&mut tenv);
+    assert!(infer.run(&program).is_ok(), "{:?}", infer.diags);
+    assert_eq!(tenv.get_var("d"), Some(&Type::Bool));
+
+    let program = parse("struct P { x: Int }\nlet p = P { x: 1 };\nlet q = p + p;");
+    let mut tenv = TypeEnv::new();
+    assert!(TypeInfer::new(&mut tenv).run(&program).is_err());
+}
+
+#[test]
+fn operator_method_signatures_are_checked() {
+    let errs = type_errors(
+        r#"
+struct M { c: Int }
+impl M {
+    func eq(self, o: M) -> Int { return 1; }
+    func add(self, o: M, extra: Int) -> M { return self; }
+    func neg() -> M { return 0; }
+    func to_string(self) -> Int { return 1; }
+    func cmp(self, o: M) -> Int { return 0; }
+    func helper(a: Int, b: Int) -> Int { return a; }
+}
+"#,
+    );
+    assert_eq!(
+        errs,
+        vec![
+            "operator method 'M.eq' must return Bool, found Int",
+            "operator method 'M.add' must take one argument besides 'self', found 2",
+            "operator method 'M.neg' must take 'self' as its first parameter",
+            "operator method 'M.to_string' must return String, found Int",
+        ]
+    );
+}
@@ -0,0 +1,75 @@
+use lexer::Lexer;
+use parser::Parser;
+use typeck::TypeChecker;
+
+fn type_errors(src: &str) -> Vec<String> {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    TypeChecker::new()
+        .check(&program)
+        .iter()
+        .map(|d| d.message.clone())
+        .collect()
+}
+
+#[test]
+fn optional_chain_and_coalesce_infer_their_types() {
+    let src = r#"
+func find(id: Int) -> Option<String> { return Option.None; }
+let name = find(1);
+let len: Option<Int> = name?.len;
+let n: Int = len ?? 0;
+let s: String = name ?? "anon";
+let bad: String = name?.len;
+"#;
+    assert_eq!(
+        type_errors(src),
+        vec!["type mismatch: declared as String, initializer has type Option<Int>"]
+    );
+}
+
+#[test]
+fn coalesce_default_must_match_the_present_type() {
+    let src = r#"
+func find(id: Int) -> Option<String> { return Option.None; }
+let s = find(1) ?? 0;
+let t = none ?? 0;
+let port: Int = Option.Some(8080) ?? 0;
+"#;
+    assert_eq!(
+        type_errors(src),
+        vec!["'??' default has type Int, expected String"]
+    );
+}
+
+#[test]
+fn optional_chain_reads_declared_field_and_method_types() {
+    let src = r#"
+struct User { name: String, age: Int }
+func find(id: Int) -> Option<User> { return Option.None; }
+let u = find(1);
+let name: Option<String> = u?.name;
+let age: Option<Int> = u?.age;
+let upper: Option<String> = name?.to_upper();
+let bad: Option<Int> = u?.name;
+"#;
+    assert_eq!(
+        type_errors(src),
+        vec!["type mismatch: declared as Option<Int>, initializer has type Option<String>"]
+    );
+}
+
+#[test]
+fn mismatches_point_at_operator_and_field_tokens() {
+    let src = "struct P { x: Int }\nlet q = P { x: 1 }\nlet p = Option.Some(q)\nvar s = \"a\"\ns = 1 + 2\ns = p?.x\ns = p?.x ?? 0\ns = 1..3";
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let positions: Vec<(usize, usize)> = TypeChecker::new()
+        .check(&program)
+        .iter()
+        .map(|d| (d.span.line, d.span.col))
+        .collect();
+    assert_eq!(positions, vec![(5, 7), (6, 8), (7, 10), (8, 6)]);
+}
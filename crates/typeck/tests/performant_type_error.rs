@@ -8,6 +8,7 @@ fn performant_return_is_type_error() {
     let let_stmt = Stmt::Let {
         pattern: core::ast::MatchPattern::Variable(Token::dummy("x")),
         ty: None,
+        mutable: false,
         initializer: Expr::Array(vec![
             Expr::Literal(ArtValue::Int(1)),
             Expr::Literal(ArtValue::Int(2)),
@@ -0,0 +1,87 @@
+use lexer::Lexer;
+use parser::Parser;
+use typeck::TypeChecker;
+
+fn type_errors(src: &str) -> Vec<String> {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    TypeChecker::new()
+        .check(&program)
+        .iter()
+        .map(|d| d.message.clone())
+        .collect()
+}
+
+const CONFIG: &str = "struct Config { host: String = \"localhost\", port: Int }\n";
+
+#[test]
+fn initializers_need_every_field_without_default() {
+    let ok = format!(
+        "{}let a = Config {{ port: 80 }};\nlet b = Config {{ host: \"x\", ..a }};\nlet p: Int = b.port;",
+        CONFIG
+    );
+    assert!(type_errors(&ok).is_empty(), "{:?}", type_errors(&ok));
+    assert_eq!(
+        type_errors(&format!("{}let a = Config {{ host: \"x\" }};", CONFIG)),
+        vec!["missing field 'port' in 'Config'"]
+    );
+}
+
+#[test]
+fn field_values_defaults_and_bases_are_checked() {
+    let src = format!(
+        "{}struct Bad {{ n: Int = \"zero\" }}\n\
+         let a = Config {{ port: \"80\", user: 1 }};\n\
+         let b = Config {{ ..5 }};\n\
+         let h: Int = a.host;",
+        CONFIG
+    );
+    assert_eq!(
+        type_errors(&src),
+        vec![
+            "default of field 'Bad.n': expected Int, got String",
+            "field 'Config.port': expected Int, got String",
+            "struct 'Config' has no field 'user'",
+            "update base of 'Config' must be Config, got Int",
+            "type mismatch: declared as Int, initializer has type String",
+        ]
+    );
+}
+
+#[test]
+fn tuple_structs_check_arguments_and_positions() {
+    let src = "struct Rgb(Int, Int, Int)\nlet c = Rgb(1, \"2\");\nlet r: String = c.0;\n\
+               let t = (1, \"a\");\nlet s: Int = t.1;";
+    assert_eq!(
+        type_errors(src),
+        vec![
+            "tuple struct 'Rgb' takes 3 arguments, got 2",
+            "argument 2 of tuple struct 'Rgb': expected Int, got String",
+            "type mismatch: declared as String, initializer has type Int",
+            "type mismatch: declared as Int, initializer has type String",
+        ]
+    );
+}
+
+#[test]
+fn bare_option_result_and_array_fields_accept_any_form() {
+    let src = "struct Address { city: String }\n\
+               struct S {\n\
+                 a: Option, b: Option<Int>, c: Result, d: Array, e: [Int],\n\
+                 f: Option = Option.None, g: Array = [1]\n\
+               }\n\
+               let home = Address { city: \"Lisboa\" };\n\
+               let s = S { a: Option.Some(home), b: Option.Some(1), c: Result.Ok(1),\n\
+                           d: [1, 2], e: [3] };\n\
+               let t = S { a: Option.None, b: Option.None, c: Result.Err(\"x\"),\n\
+                           d: [], e: [], ..s };";
+    assert!(type_errors(src).is_empty(), "{:?}", type_errors(src));
+    assert_eq!(
+        type_errors("struct S { d: Array, o: Option }\nlet s = S { d: 1, o: [1] };"),
+        vec![
+            "field 'S.d': expected Array, got Int",
+            "field 'S.o': expected Option, got [Int]",
+        ]
+    );
+}
@@ -0,0 +1,99 @@
+use lexer::Lexer;
+use parser::Parser;
+use typeck::TypeChecker;
+
+fn type_errors(src: &str) -> Vec<String> {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    TypeChecker::new()
+        .check(&program)
+        .iter()
+        .map(|d| d.message.clone())
+        .collect()
+}
+
+const UNITS: &str = "newtype Meters(Float)\nnewtype Seconds(Float)\n";
+
+#[test]
+fn aliases_resolve_to_their_target() {
+    let errs = type_errors(
+        "type UserId = Int\ntype Row = (UserId, String)\ntype Ids = [UserId]\n\
+         func get(id: UserId, row: Row) -> UserId { return id; }\n\
+         let ids: Ids = [1, 2];\nlet n: Int = get(7, (1, \"a\"));",
+    );
+    assert!(errs.is_empty(), "{:?}", errs);
+    assert_eq!(
+        type_errors("type UserId = Int\nlet id: UserId = \"x\";"),
+        vec!["type mismatch: declared as Int, initializer has type String"]
+    );
+}
+
+#[test]
+fn newtypes_only_mix_with_themselves() {
+    let errs = type_errors(&format!(
+        "{}let d = Meters(1.5);\nlet total = d + Meters(2.0);\nlet raw: Float = (d as Float) * 2.0;",
+        UNITS
+    ));
+    assert!(errs.is_empty(), "{:?}", errs);
+
+    let cases = [
+        (
+            "let x = Meters(1.0) + 1.0;",
+            "operator '+' cannot be applied to Meters and Float",
+        ),
+        (
+            "let x = Meters(1.0) < Seconds(1.0);",
+            "operator '<' cannot be applied to Meters and Seconds",
+        ),
+        (
+            "func f(d: Meters) { return d; }\nf(2.0);",
+            "argument 1 ('d') of 'f': expected Meters, got Float",
+        ),
+        (
+            "let x: Float = Meters(1.0);",
+            "declared as Float, initializer has type Meters",
+        ),
+        (
+            "let x = Meters(\"a\");",
+            "argument 1 of newtype 'Meters': expected Float, got String",
+        ),
+        (
+            "let x = Meters(1.0, 2.0);",
+            "newtype 'Meters' takes exactly one argument, got 2",
+        ),
+    ];
+    for (src, expected) in cases {
+        let errs = type_errors(&format!("{}{}", UNITS, src));
+        assert!(
+            errs.iter().any(|m| m.contains(expected)),
+            "{}: {:?}",
+            src,
+            errs
+        );
+    }
+}
+
+#[test]
+fn newtype_fields_are_rejected() {
+    let src = format!(
+        "{}let d = Meters(1.0);\nlet r = d.0;\nfunc f(m: Meters) {{ return m.0; }}",
+        UNITS
+    );
+    assert_eq!(
+        type_errors(&src),
+        vec![
+            "newtype 'Meters' has no field '0'",
+            "newtype 'Meters' has no field '0'",
+        ]
+    );
+}
+
+#[test]
+fn self_referential_declarations_are_rejected() {
+    let errs = type_errors("type A = [B]\ntype B = A\nnewtype N((Int, N))\nlet x: A = 1;");
+    for name in ["A", "B", "N"] {
+        let expected = format!("type '{}' refers to itself", name);
+        assert!(errs.contains(&expected), "{:?}", errs);
+    }
+}
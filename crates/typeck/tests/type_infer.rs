@@ -31,3 +31,197 @@ fn infer_numeric_promotion() {
 fn infer_interpolated_string_type() {
     let _tenv = infer("let s = f\"ok={1+2}\";");
 }
+
+#[test]
+fn infer_index_and_slice_types() {
+    use core::types::Type;
+    let tenv = infer(
+        "let a = [1, 2]; let x = a[0]; let t = (1, \"s\"); let y = t[-1]; let z = \"abc\"[1..];",
+    );
+    assert_eq!(tenv.get_var("x"), Some(&Type::Int));
+    assert_eq!(tenv.get_var("y"), Some(&Type::String));
+    assert_eq!(tenv.get_var("z"), Some(&Type::String));
+}
+
+#[test]
+fn non_int_index_into_array_is_a_type_error() {
+    let tokens = Lexer::new("let a = [1, 2]; let x = a[\"0\"];".to_string())
+        .scan_tokens()
+        .expect("lex");
+    let (program, _) = Parser::new(tokens).parse();
+    let mut tenv = TypeEnv::new();
+    let diags = TypeInfer::new(&mut tenv)
+        .run(&program)
+        .expect_err("string index must be rejected");
+    assert!(
+        diags[0].message.contains("Index into [Int] must be Int"),
+        "{:?}",
+        diags
+    );
+}
+
+#[test]
+fn bitwise_operators_infer_int() {
+    use core::types::Type;
+    let tenv = infer("let a = 12 & 10 | 1 << 3; let b = ~a; let c = 7 % 2;");
+    assert_eq!(tenv.get_var("a"), Some(&Type::Int));
+    assert_eq!(tenv.get_var("b"), Some(&Type::Int));
+    assert_eq!(tenv.get_var("c"), Some(&Type::Int));
+}
+
+#[test]
+fn bitwise_operators_reject_non_int_operands() {
+    let cases = [
+        (
+            "let x = 1.5 & 2;",
+            "Operator '&' requires Int operands, found Float and Int",
+        ),
+        (
+            "let x = \"a\" << 1;",
+            "Operator '<<' requires Int operands, found String and Int",
+        ),
+        (
+            "let x = ~true;",
+            "Operator '~' requires an Int operand, found Bool",
+        ),
+    ];
+    for (src, expected) in cases {
+        let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+        let (program, _) = Parser::new(tokens).parse();
+        let mut tenv = TypeEnv::new();
+        let diags = TypeInfer::new(&mut tenv)
+            .run(&program)
+            .expect_err("non-Int operand must be rejected");
+        assert_eq!(diags[0].message, expected, "{}", src);
+    }
+}
+
+#[test]
+fn lambda_body_is_checked_and_params_do_not_leak() {
+    use core::types::Type;
+    let tokens = Lexer::new("let x = 1.5; let f = |x| x & 1; let g = |y| ~\"s\";".to_string())
+        .scan_tokens()
+        .expect("lex");
+    let (program, _) = Parser::new(tokens).parse();
+    let mut tenv = TypeEnv::new();
+    let diags = TypeInfer::new(&mut tenv)
+        .run(&program)
+        .expect_err("body of g must be rejected");
+    // `x` inside `f` is the (Unknown) parameter, not the outer Float.
+    assert_eq!(diags.len(), 1, "{:?}", diags);
+    assert_eq!(
+        diags[0].message,
+        "Operator '~' requires an Int operand, found String"
+    );
+    assert_eq!(tenv.get_var("x"), Some(&Type::Float));
+    assert!(matches!(tenv.get_var("f"), Some(Type::Function(..))));
+}
+
+#[test]
+fn lambda_bound_inside_performant_is_rejected() {
+    let tokens = Lexer::new("performant { let _f = |x| x + 1; }".to_string())
+        .scan_tokens()
+        .expect("lex");
+    let (program, _) = Parser::new(tokens).parse();
+    let mut tenv = TypeEnv::new();
+    let diags = TypeInfer::new(&mut tenv)
+        .run(&program)
+        .expect_err("lambda binding must be rejected");
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.message.starts_with("Lambda bound inside `performant`")),
+        "{:?}",
+        diags
+    );
+}
+
+fn infer_errors(src: &str) -> Vec<String> {
+    let tokens = Lexer::new(src.to_string()).scan_tokens().expect("lex");
+    let (program, diags) = Parser::new(tokens).parse();
+    assert!(diags.is_empty(), "parse diagnostics: {:?}", diags);
+    let mut tenv = TypeEnv::new();
+    match TypeInfer::new(&mut tenv).run(&program) {
+        Ok(()) => Vec::new(),
+        Err(diags) => diags.into_iter().map(|d| d.message).collect(),
+    }
+}
+
+#[test]
+fn generic_bounds_are_checked_against_trait_impls() {
+    let prelude = "trait Show { func show(self) -> String }\nstruct Point { x: Int }\nstruct Other { x: Int }\nimpl Show for Point { func show(self) -> String { return \"p\"; } }\nfunc display<T: Show>(v: T) -> String { return v.show(); }\nlet p = Point { x: 1 };\nlet o = Other { x: 1 };\n";
+    assert!(infer_errors(&format!("{}let a = display(p);", prelude)).is_empty());
+    assert_eq!(
+        infer_errors(&format!("{}let a = display(o);", prelude)),
+        vec!["Type argument 'Other' does not satisfy constraint 'Show' for type parameter 'T'"]
+    );
+    assert_eq!(
+        infer_errors(&format!("{}let a = display::<Int>(1);", prelude)),
+        vec!["Type argument 'Int' does not satisfy constraint 'Show' for type parameter 'T'"]
+    );
+    assert_eq!(
+        infer_errors("func f<T: Nope>(v: T) { }\nf(1);"),
+        vec!["Unknown trait 'Nope' in bound of type parameter 'T'"]
+    );
+    // Builtin bounds keep working without a declaration.
+    assert!(
+        infer_errors("func twice<T: Numeric>(a: T) -> T { return a + a; }\nlet x = twice(2);")
+            .is_empty()
+    );
+}
+
+#[test]
+fn impl_must_match_its_trait() {
+    let errors = infer_errors(
+        "trait Show {\n    func show(self) -> String\n    func shout(self) -> String { return self.show(); }\n}\nstruct Point { x: Int }\nimpl Show for Point { func shw(self) -> String { return \"p\"; } }\nimpl Debug for Point { }",
+    );
+    assert_eq!(
+        errors,
+        vec![
+            "Type 'Point' does not implement method 'show' required by trait 'Show'",
+            "Method 'shw' is not a member of trait 'Show'",
+            "Unknown trait 'Debug'",
+        ]
+    );
+}
+
+#[test]
+fn impl_signatures_match_the_trait_and_impls_are_unique() {
+    let prelude = "trait Show {\n    func show(self) -> String\n    func pad(self, n: Int) -> String\n}\ntype Text = String;\nstruct Pt { x: Int }\n";
+    assert!(
+        infer_errors(&format!(
+            "{}impl Show for Pt {{\n    func show(self) -> Text {{ return \"p\"; }}\n    func pad(self, n: Int) -> String {{ return \"p\"; }}\n}}",
+            prelude
+        ))
+        .is_empty()
+    );
+    assert_eq!(
+        infer_errors(&format!(
+            "{}impl Show for Pt {{\n    func show(self) -> Int {{ return 1; }}\n    func pad(self, n: String) -> String {{ return n; }}\n}}\nimpl Show for Pt {{\n    func show(self) {{ }}\n    func pad(self) -> String {{ return \"p\"; }}\n}}",
+            prelude
+        )),
+        vec![
+            "Method 'show' does not match its declaration in trait 'Show': expected return type String, found Int",
+            "Method 'pad' does not match its declaration in trait 'Show': parameter 'n' expected Int, found String",
+            "Trait 'Show' is already implemented for type 'Pt'",
+            "Method 'show' does not match its declaration in trait 'Show': expected return type String, found none",
+            "Method 'pad' does not match its declaration in trait 'Show': expected 2 parameters, found 1",
+        ]
+    );
+}
+
+#[test]
+fn ranges_need_int_bounds_and_yield_ints() {
+    use core::types::Type;
+    let tenv = infer("let r = 0..3;");
+    assert_eq!(tenv.get_var("r"), Some(&Type::Struct("Range".to_string())));
+    assert_eq!(
+        infer_errors("let r = 0..\"a\";"),
+        vec!["Operator '..' requires Int operands, found Int and String"]
+    );
+    // O elemento de `for i in a..b` é Int.
+    assert_eq!(
+        infer_errors("for i in 1..=3 { let x = i << 1.5; }"),
+        vec!["Operator '<<' requires Int operands, found Int and Float"]
+    );
+}
@@ -8,11 +8,13 @@ fn propagation_rebind_allows_send() {
     let let_a = Stmt::Let {
         pattern: core::ast::MatchPattern::Variable(Token::dummy("a")),
         ty: None,
+        mutable: false,
         initializer: Expr::Array(vec![Expr::Literal(ArtValue::Int(1))]),
     };
     let let_b = Stmt::Let {
         pattern: core::ast::MatchPattern::Variable(Token::dummy("b")),
         ty: None,
+        mutable: false,
         initializer: Expr::Variable {
             name: Token::dummy("a"),
         },
@@ -52,12 +54,14 @@ fn shadowing_restores_outer_type() {
     let let_a = Stmt::Let {
         pattern: core::ast::MatchPattern::Variable(Token::dummy("a")),
         ty: None,
+        mutable: false,
         initializer: Expr::Array(vec![Expr::Literal(ArtValue::Int(1))]),
     };
     let block = Stmt::Block {
         statements: vec![Stmt::Let {
             pattern: core::ast::MatchPattern::Variable(Token::dummy("a")),
             ty: None,
+            mutable: false,
             initializer: Expr::Literal(ArtValue::Int(42)),
         }],
     };
@@ -120,6 +124,7 @@ fn function_param_propagation() {
         return_type: None,
         body: Rc::new(fn_body),
         method_owner: None,
+        public: false,
     };
     let call = Stmt::Expression(Expr::Call {
         type_args: None,
@@ -12,6 +12,7 @@ fn typeinfer_restores_outer_bindings_on_shadow() {
     let let_outer = Stmt::Let {
         pattern: core::ast::MatchPattern::Variable(name.clone()),
         ty: None,
+        mutable: false,
         initializer: Expr::Literal(core::ast::ArtValue::Int(1)),
     };
     let inner_name = name.clone();
@@ -19,6 +20,7 @@ fn typeinfer_restores_outer_bindings_on_shadow() {
         statements: vec![Stmt::Let {
             pattern: core::ast::MatchPattern::Variable(inner_name.clone()),
             ty: None,
+            mutable: false,
             initializer: Expr::Literal(core::ast::ArtValue::Int(2)),
         }],
     };
@@ -162,6 +162,22 @@ fn collect_examples(dir: &PathBuf, out: &mut Vec<PathBuf>) {
     }
 }
 
+/// Examples that are expected to stop at a static (lex/parse/type) diagnostic.
+///
+/// `art run` aborts before executing anything when the checkers report an
+/// error, yet still exits 0, so without this check a typecheck regression
+/// turns an example into a silent no-op. Each entry says why it is exempt.
+const STATIC_ERROR_EXAMPLES: &[(&str, &str)] = &[
+    (
+        "19_performant_arena.art",
+        "demonstrates the `performant` escape check",
+    ),
+    (
+        "20_actors_simple.art",
+        "TypeInfer rejects the envelope payload as not send-safe (predates the examples job)",
+    ),
+];
+
 /// Executes every example and fails if any of them regresses.
 ///
 /// Ported from `scripts/test_examples.sh` so the gate runs on Windows too,
@@ -210,6 +226,13 @@ fn run_examples() {
             Some("panic on stderr".to_string())
         } else if stderr.contains("thread '") {
             Some("thread crash on stderr".to_string())
+        } else if stderr.lines().any(|l| {
+            ["lex error", "parse error", "type error"]
+                .iter()
+                .any(|p| l.starts_with(p))
+        }) && !STATIC_ERROR_EXAMPLES.iter().any(|(n, _)| *n == name)
+        {
+            Some("static diagnostic on stderr (the program never ran)".to_string())
         } else {
             None
         };